@@ -0,0 +1,51 @@
+use barter_data::clock::{received_time, CachedClock};
+use chrono::Utc;
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const CALLS: u32 = 10_000_000;
+
+/// Compares the per-message cost of timestamping with the precise `Utc::now()` against the
+/// coarse [`CachedClock`] time.
+///
+/// Run with: `cargo bench --bench cached_clock`
+fn main() {
+    let precise = bench(|| {
+        black_box(Utc::now());
+    });
+    let precise_received_time = bench(|| {
+        black_box(received_time());
+    });
+
+    let clock = CachedClock::start(Duration::from_millis(1));
+    let cached = bench(|| {
+        black_box(received_time());
+    });
+    drop(clock);
+
+    println!("calls: {CALLS}");
+    print_result("Utc::now()", precise);
+    print_result("received_time() (precise)", precise_received_time);
+    print_result("received_time() (CachedClock 1ms)", cached);
+    println!(
+        "speedup: {:.2}x",
+        precise.as_secs_f64() / cached.as_secs_f64()
+    );
+}
+
+fn bench(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..CALLS {
+        f();
+    }
+    start.elapsed()
+}
+
+fn print_result(name: &str, elapsed: Duration) {
+    println!(
+        "{name:<40} total: {elapsed:>10.2?}  per call: {:>8.2?}",
+        elapsed / CALLS
+    );
+}
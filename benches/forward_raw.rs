@@ -0,0 +1,96 @@
+use barter_data::{
+    exchange::binance::{
+        spot::BinanceSpot,
+        trade::{BinanceTrade, BinanceTradeRoute},
+    },
+    protocol::{RawWebSocketParser, WebSocketParser, WsFrame},
+    subscription::{
+        forward::{ForwardRaw, RoutedFrame},
+        intern::intern,
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+};
+use barter_integration::{
+    model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        SubscriptionId,
+    },
+    protocol::{websocket::WsMessage, StreamParser},
+    Transformer,
+};
+use serde::Deserialize;
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+use tokio::sync::mpsc;
+
+const FRAMES: usize = 1_000_000;
+
+const FRAME: &str = r#"{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1000000000,"p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,"T":1749354825200,"m":false,"M":true}"#;
+
+/// Compares the per-frame latency of fully normalising Binance trade frames into
+/// `MarketEvent<PublicTrade>`s against forwarding them as raw frames via [`ForwardRaw`].
+///
+/// Run with: `cargo bench --bench forward_raw`
+#[tokio::main]
+async fn main() {
+    let instrument_map = || {
+        Map::from_iter([(
+            SubscriptionId::from("@trade|BTCUSDT"),
+            intern(&Instrument::from(("btc", "usdt", InstrumentKind::Spot))),
+        )])
+    };
+
+    let mut normalised = StatelessTransformer::<BinanceSpot, PublicTrades, BinanceTrade>::new(
+        mpsc::unbounded_channel().0,
+        instrument_map(),
+    )
+    .await
+    .unwrap();
+
+    let mut forwarded = StatelessTransformer::<
+        BinanceSpot,
+        ForwardRaw<PublicTrades, BinanceTradeRoute>,
+        RoutedFrame<BinanceTradeRoute>,
+    >::new(mpsc::unbounded_channel().0, instrument_map())
+    .await
+    .unwrap();
+
+    let normalised_elapsed = bench::<WebSocketParser, _>(&mut normalised);
+    let forwarded_elapsed = bench::<RawWebSocketParser, _>(&mut forwarded);
+
+    println!("Binance trade frames: {FRAMES}");
+    print_result("normalised MarketEvent<PublicTrade>", normalised_elapsed);
+    print_result("forwarded MarketEvent<RawFrame>", forwarded_elapsed);
+    println!(
+        "speedup: {:.2}x",
+        normalised_elapsed.as_secs_f64() / forwarded_elapsed.as_secs_f64()
+    );
+}
+
+/// Parse and transform [`FRAMES`] frames exactly as the `ExchangeStream` of each would.
+fn bench<Parser, T>(transformer: &mut T) -> Duration
+where
+    Parser: StreamParser<Message = WsFrame>,
+    T: Transformer,
+    T::Input: for<'de> Deserialize<'de>,
+{
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        let message = Ok(WsFrame::from(WsMessage::Text(black_box(FRAME).to_owned())));
+        if let Some(Ok(input)) = Parser::parse::<T::Input>(message) {
+            black_box(transformer.transform(input));
+        }
+    }
+    start.elapsed()
+}
+
+fn print_result(name: &str, elapsed: Duration) {
+    println!(
+        "{name:<40} total: {elapsed:>10.2?}  per frame: {:>8.2?}",
+        elapsed / FRAMES as u32
+    );
+}
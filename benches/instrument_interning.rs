@@ -0,0 +1,95 @@
+use barter_data::{
+    exchange::binance::{spot::BinanceSpot, trade::BinanceTrade},
+    subscription::{intern::intern, trade::PublicTrades, Map},
+    transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+};
+use barter_integration::{
+    model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        SubscriptionId,
+    },
+    Transformer,
+};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::mpsc;
+
+const EVENTS: usize = 100_000;
+
+const FRAME: &str = r#"{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1000000000,"p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,"T":1749354825200,"m":false,"M":true}"#;
+
+/// [`GlobalAlloc`] that counts every allocation made by the benchmark.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Compares the allocations per event of carrying an owned [`Instrument`] in each
+/// `MarketEvent` against carrying an interned [`Arc<Instrument>`].
+///
+/// Run with: `cargo bench --bench instrument_interning`
+#[tokio::main]
+async fn main() {
+    let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+    let interned = intern(&instrument);
+
+    let owned_clone = allocations_per_event(|| {
+        black_box(instrument.clone());
+    });
+    let interned_clone = allocations_per_event(|| {
+        black_box(Arc::clone(&interned));
+    });
+
+    let mut transformer = StatelessTransformer::<BinanceSpot, PublicTrades, BinanceTrade>::new(
+        mpsc::unbounded_channel().0,
+        Map::from_iter([(SubscriptionId::from("@trade|BTCUSDT"), interned)]),
+    )
+    .await
+    .unwrap();
+    let trade = serde_json::from_str::<BinanceTrade>(FRAME).unwrap();
+    let transform = allocations_per_event(|| {
+        black_box(transformer.transform(trade.clone()));
+    });
+
+    println!("events: {EVENTS}");
+    println!(
+        "{:<40} allocations per event: {owned_clone:.2}",
+        "Instrument::clone"
+    );
+    println!(
+        "{:<40} allocations per event: {interned_clone:.2}",
+        "Arc<Instrument>::clone"
+    );
+    println!(
+        "{:<40} allocations per event: {transform:.2}",
+        "BinanceTrade -> MarketEvent<PublicTrade>"
+    );
+}
+
+/// Average number of allocations made by each of [`EVENTS`] calls of the provided closure.
+fn allocations_per_event(mut f: impl FnMut()) -> f64 {
+    let start = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..EVENTS {
+        f();
+    }
+    (ALLOCATIONS.load(Ordering::Relaxed) - start) as f64 / EVENTS as f64
+}
@@ -0,0 +1,66 @@
+use barter_data::subscription::Map;
+use barter_integration::model::SubscriptionId;
+use std::{
+    collections::HashMap,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const SUBSCRIPTIONS: usize = 500;
+const LOOKUPS: usize = 2_000_000;
+
+/// Compares the per-message [`SubscriptionId`] lookup cost of the default SipHash `HashMap`
+/// against the `SubscriptionIdBuildHasher` backed [`Map`].
+///
+/// Run with: `cargo bench --bench subscription_id_lookup`
+fn main() {
+    let ids = (0..SUBSCRIPTIONS)
+        .map(|index| SubscriptionId(format!("@aggTrade|COIN{index}USDT")))
+        .collect::<Vec<_>>();
+
+    let sip = ids
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(index, id)| (id, index))
+        .collect::<HashMap<_, _>>();
+
+    let fx = ids
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(index, id)| (id, index))
+        .collect::<Map<_>>();
+
+    let sip_elapsed = bench(&ids, |id| sip.get(id).copied());
+    let fx_elapsed = bench(&ids, |id| fx.find(id).ok());
+
+    println!("SubscriptionId lookups: {LOOKUPS} over {SUBSCRIPTIONS} subscriptions");
+    print_result("HashMap<SubscriptionId, T> (SipHash)", sip_elapsed);
+    print_result("Map<T> (SubscriptionIdHasher)", fx_elapsed);
+    println!(
+        "speedup: {:.2}x",
+        sip_elapsed.as_secs_f64() / fx_elapsed.as_secs_f64()
+    );
+
+    // Sanity check both hashers agree on every lookup
+    assert!(ids.iter().all(|id| sip.get(id) == fx.0.get(id)));
+}
+
+fn bench<F>(ids: &[SubscriptionId], mut lookup: F) -> Duration
+where
+    F: FnMut(&SubscriptionId) -> Option<usize>,
+{
+    let start = Instant::now();
+    for index in 0..LOOKUPS {
+        black_box(lookup(black_box(&ids[index % ids.len()])));
+    }
+    start.elapsed()
+}
+
+fn print_result(name: &str, elapsed: Duration) {
+    println!(
+        "{name:<40} {:>8.2} ns/lookup",
+        elapsed.as_nanos() as f64 / LOOKUPS as f64
+    );
+}
@@ -0,0 +1,54 @@
+use barter_data::{
+    event::MarketIter,
+    exchange::{binance::trade::BinanceTrade, ExchangeId},
+    subscription::trade::{PublicTrade, TradeFields},
+};
+use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+use std::{
+    hint::black_box,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+const MESSAGES: u32 = 1_000_000;
+
+const TRADE: &str = r#"{"e":"trade","E":1649324825173,"s":"ETHUSDT","t":1000000000,"p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,"T":1749354825200,"m":false,"M":true}"#;
+
+/// Compares the per-message cost of normalising a Binance trade with every [`TradeFields`]
+/// populated against skipping the trade id.
+///
+/// Run with: `cargo bench --bench trade_fields`
+fn main() {
+    let trade = serde_json::from_str::<BinanceTrade>(TRADE).unwrap();
+    let instrument = Arc::new(Instrument::from(("eth", "usdt", InstrumentKind::Spot)));
+
+    let all = TradeFields::default().scope(|| bench(&trade, &instrument));
+    let skip_id = TradeFields { id: false }.scope(|| bench(&trade, &instrument));
+
+    println!("messages: {MESSAGES}");
+    print_result("all fields", all);
+    print_result("id skipped", skip_id);
+    println!("speedup: {:.2}x", all.as_secs_f64() / skip_id.as_secs_f64());
+}
+
+fn bench(trade: &BinanceTrade, instrument: &Arc<Instrument>) -> Duration {
+    // Clone the input trades up front so only the normalisation is timed
+    let trades = vec![trade.clone(); MESSAGES as usize];
+
+    let start = Instant::now();
+    for trade in trades {
+        black_box(MarketIter::<PublicTrade>::from((
+            ExchangeId::BinanceSpot,
+            Arc::clone(instrument),
+            black_box(trade),
+        )));
+    }
+    start.elapsed()
+}
+
+fn print_result(name: &str, elapsed: Duration) {
+    println!(
+        "{name:<20} total: {elapsed:>10.2?}  per message: {:>8.2?}",
+        elapsed / MESSAGES
+    );
+}
@@ -0,0 +1,313 @@
+use super::Adapter;
+use crate::{
+    event::MarketEvent,
+    subscription::{book::OrderBook, intern::intern},
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    time::Duration,
+};
+
+/// [`Exchange`] identifier used for [`MarketEvent<AggregatedBook>`]s generated by the
+/// [`AggregatedBookAdapter`].
+pub const EXCHANGE_AGGREGATED: &str = "aggregated";
+
+/// Synthetic [`OrderBook`] aggregating the levels of several exchange [`OrderBook`]s for the same
+/// [`Instrument`].
+///
+/// Bids are sorted best (highest) first, and asks are sorted best (lowest) first.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct AggregatedBook {
+    pub last_update_time: DateTime<Utc>,
+    pub bids: Vec<AggregatedLevel>,
+    pub asks: Vec<AggregatedLevel>,
+}
+
+/// [`AggregatedBook`] price level containing the total amount at the (bucketed) price, as well as
+/// the amount contributed by each source exchange.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct AggregatedLevel {
+    pub price: f64,
+    pub amount: f64,
+    pub sources: Vec<LevelSource>,
+}
+
+/// Amount contributed to an [`AggregatedLevel`] by a source exchange.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct LevelSource {
+    pub exchange: Exchange,
+    pub amount: f64,
+}
+
+/// [`Adapter`] that consumes [`OrderBook`] [`MarketEvent`]s from several exchanges and emits a
+/// [`MarketEvent<AggregatedBook>`] for the configured canonical [`Instrument`] every time one of
+/// the source books updates.
+///
+/// ### Notes
+/// - Exchanges often have differing tick sizes, so levels are bucketed to the configured
+///   `tick_size`. Bids are rounded down and asks are rounded up to the nearest bucket, so the
+///   bucketed book is never more aggressive than the source books.
+/// - A source book is considered stale, and is excluded from the [`AggregatedBook`], if it has
+///   not updated within `stale_after` of the most recent source book update.
+/// - [`MarketEvent`]s for other [`Instrument`]s are ignored.
+#[derive(Clone, Debug)]
+pub struct AggregatedBookAdapter {
+    instrument: Arc<Instrument>,
+    tick_size: f64,
+    stale_after: Duration,
+    books: HashMap<Exchange, SourceBook>,
+}
+
+/// Latest [`OrderBook`] of a source exchange, and the time it was last updated.
+#[derive(Clone, Debug)]
+struct SourceBook {
+    time: DateTime<Utc>,
+    received_time: DateTime<Utc>,
+    book: OrderBook,
+}
+
+impl AggregatedBookAdapter {
+    /// Construct a new [`Self`] that aggregates the [`OrderBook`]s of the provided [`Instrument`].
+    pub fn new(instrument: Instrument, tick_size: f64, stale_after: Duration) -> Self {
+        Self {
+            instrument: intern(&instrument),
+            tick_size,
+            stale_after,
+            books: HashMap::new(),
+        }
+    }
+
+    /// Generate an [`AggregatedBook`] from all source books that are not stale relative to the
+    /// provided `now`.
+    pub fn aggregate(&self, now: DateTime<Utc>) -> AggregatedBook {
+        let stale_after =
+            chrono::Duration::from_std(self.stale_after).unwrap_or(chrono::Duration::MAX);
+
+        // Bucket keys are the price divided by the tick_size
+        let mut bids = BTreeMap::<i64, BTreeMap<Exchange, f64>>::new();
+        let mut asks = BTreeMap::<i64, BTreeMap<Exchange, f64>>::new();
+
+        self.books
+            .iter()
+            .filter(|(_, source)| now.signed_duration_since(source.time) <= stale_after)
+            .for_each(|(exchange, source)| {
+                for level in &source.book.bids.levels {
+                    let bucket = bucket_down(level.price, self.tick_size);
+                    *bids
+                        .entry(bucket)
+                        .or_default()
+                        .entry(exchange.clone())
+                        .or_default() += level.amount;
+                }
+                for level in &source.book.asks.levels {
+                    let bucket = bucket_up(level.price, self.tick_size);
+                    *asks
+                        .entry(bucket)
+                        .or_default()
+                        .entry(exchange.clone())
+                        .or_default() += level.amount;
+                }
+            });
+
+        AggregatedBook {
+            last_update_time: now,
+            bids: bids
+                .into_iter()
+                .rev()
+                .map(|(bucket, sources)| aggregated_level(bucket, self.tick_size, sources))
+                .collect(),
+            asks: asks
+                .into_iter()
+                .map(|(bucket, sources)| aggregated_level(bucket, self.tick_size, sources))
+                .collect(),
+        }
+    }
+}
+
+impl Adapter<MarketEvent<OrderBook>> for AggregatedBookAdapter {
+    type Output = MarketEvent<AggregatedBook>;
+
+    fn adapt(&mut self, input: MarketEvent<OrderBook>) -> Option<Self::Output> {
+        if input.instrument != self.instrument {
+            return None;
+        }
+
+        self.books.insert(
+            input.exchange,
+            SourceBook {
+                time: input.exchange_time,
+                received_time: input.received_time,
+                book: input.kind,
+            },
+        );
+
+        // Use the most recent source book update as the reference time for staleness
+        let now = self.books.values().map(|source| source.time).max()?;
+        let received_time = self
+            .books
+            .values()
+            .map(|source| source.received_time)
+            .max()?;
+
+        Some(MarketEvent {
+            exchange_time: now,
+            received_time,
+            exchange: Exchange::from(EXCHANGE_AGGREGATED),
+            instrument: self.instrument.clone(),
+            kind: self.aggregate(now),
+        })
+    }
+}
+
+/// Tolerance applied when bucketing prices so that floating point error (eg/ 100.1 / 0.1 =
+/// 1000.9999999999999) does not push a price into the neighbouring bucket.
+const BUCKET_EPSILON: f64 = 1e-9;
+
+/// Round the price down to the nearest tick_size bucket.
+fn bucket_down(price: f64, tick_size: f64) -> i64 {
+    (price / tick_size + BUCKET_EPSILON).floor() as i64
+}
+
+/// Round the price up to the nearest tick_size bucket.
+fn bucket_up(price: f64, tick_size: f64) -> i64 {
+    (price / tick_size - BUCKET_EPSILON).ceil() as i64
+}
+
+fn aggregated_level(
+    bucket: i64,
+    tick_size: f64,
+    sources: BTreeMap<Exchange, f64>,
+) -> AggregatedLevel {
+    AggregatedLevel {
+        price: bucket as f64 * tick_size,
+        amount: sources.values().sum(),
+        sources: sources
+            .into_iter()
+            .map(|(exchange, amount)| LevelSource { exchange, amount })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn book_event(
+        exchange: &'static str,
+        secs: i64,
+        bids: Vec<(f64, f64)>,
+        asks: Vec<(f64, f64)>,
+    ) -> MarketEvent<OrderBook> {
+        MarketEvent {
+            exchange: Exchange::from(exchange),
+            ..fixtures::book_event(fixtures::secs(secs), bids, asks)
+        }
+    }
+
+    fn level(price: f64, sources: Vec<(&'static str, f64)>) -> AggregatedLevel {
+        AggregatedLevel {
+            price,
+            amount: sources.iter().map(|(_, amount)| amount).sum(),
+            sources: sources
+                .into_iter()
+                .map(|(exchange, amount)| LevelSource {
+                    exchange: Exchange::from(exchange),
+                    amount,
+                })
+                .collect(),
+        }
+    }
+
+    fn assert_levels_eq(actual: &[AggregatedLevel], expected: &[AggregatedLevel], tc: &str) {
+        assert_eq!(actual.len(), expected.len(), "{tc} failed level count");
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!(
+                (actual.price - expected.price).abs() < 1e-9,
+                "{tc} failed price: {actual:?} != {expected:?}"
+            );
+            assert!(
+                (actual.amount - expected.amount).abs() < 1e-9,
+                "{tc} failed amount: {actual:?} != {expected:?}"
+            );
+            assert_eq!(actual.sources, expected.sources, "{tc} failed sources");
+        }
+    }
+
+    #[test]
+    fn test_aggregated_book_adapter() {
+        struct TestCase {
+            input: MarketEvent<OrderBook>,
+            expected_bids: Vec<AggregatedLevel>,
+            expected_asks: Vec<AggregatedLevel>,
+        }
+
+        let mut adapter = AggregatedBookAdapter::new(
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            1.0,
+            Duration::from_secs(5),
+        );
+
+        let tests = vec![
+            TestCase {
+                // TC0: single exchange book
+                input: book_event(
+                    "binance",
+                    0,
+                    vec![(100.0, 1.0), (99.0, 2.0)],
+                    vec![(101.0, 1.0)],
+                ),
+                expected_bids: vec![
+                    level(100.0, vec![("binance", 1.0)]),
+                    level(99.0, vec![("binance", 2.0)]),
+                ],
+                expected_asks: vec![level(101.0, vec![("binance", 1.0)])],
+            },
+            TestCase {
+                // TC1: second exchange with finer tick size is bucketed & summed
+                input: book_event(
+                    "kraken",
+                    1,
+                    vec![(100.5, 3.0), (100.2, 1.0), (99.0, 1.0)],
+                    vec![(100.5, 0.5), (101.0, 2.0)],
+                ),
+                expected_bids: vec![
+                    level(100.0, vec![("binance", 1.0), ("kraken", 4.0)]),
+                    level(99.0, vec![("binance", 2.0), ("kraken", 1.0)]),
+                ],
+                expected_asks: vec![level(101.0, vec![("binance", 1.0), ("kraken", 2.5)])],
+            },
+            TestCase {
+                // TC2: binance book is stale, so only kraken levels are aggregated
+                input: book_event("kraken", 10, vec![(99.0, 1.0)], vec![(100.5, 0.5)]),
+                expected_bids: vec![level(99.0, vec![("kraken", 1.0)])],
+                expected_asks: vec![level(101.0, vec![("kraken", 0.5)])],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = adapter.adapt(test.input).unwrap();
+            let tc = format!("TC{index}");
+            assert_eq!(actual.exchange, Exchange::from(EXCHANGE_AGGREGATED));
+            assert_levels_eq(&actual.kind.bids, &test.expected_bids, &tc);
+            assert_levels_eq(&actual.kind.asks, &test.expected_asks, &tc);
+        }
+    }
+
+    #[test]
+    fn test_aggregated_book_adapter_ignores_other_instruments() {
+        let mut adapter = AggregatedBookAdapter::new(
+            Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+            1.0,
+            Duration::from_secs(5),
+        );
+
+        let input = book_event("binance", 0, vec![(100.0, 1.0)], vec![(101.0, 1.0)]);
+        assert!(adapter.adapt(input).is_none());
+    }
+}
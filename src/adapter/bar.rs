@@ -0,0 +1,530 @@
+use super::Adapter;
+use crate::{
+    event::MarketEvent,
+    subscription::trade::{PublicTrade, TradeCondition},
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc};
+
+/// Cumulative threshold at which a [`BarAdapter`] closes each [`Bar`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BarThreshold {
+    /// Close a volume bar once the summed trade amount reaches the threshold.
+    Volume(f64),
+    /// Close a dollar bar once the summed trade notional (price * amount) reaches the threshold.
+    Dollar(f64),
+}
+
+impl BarThreshold {
+    fn value(&self) -> f64 {
+        match self {
+            Self::Volume(threshold) | Self::Dollar(threshold) => *threshold,
+        }
+    }
+}
+
+/// OHLCV [`Bar`] sampled on cumulative traded volume or dollar volume rather than time, alongside
+/// the volume weighted average price of its constituent [`PublicTrade`]s.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Bar {
+    pub open_time: DateTime<Utc>,
+    pub close_time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub dollar_volume: f64,
+    pub vwap: f64,
+    pub trade_count: u64,
+}
+
+impl Bar {
+    fn new(trade: &MarketEvent<PublicTrade>) -> Self {
+        Self {
+            open_time: trade.exchange_time,
+            close_time: trade.exchange_time,
+            open: trade.kind.price,
+            high: trade.kind.price,
+            low: trade.kind.price,
+            close: trade.kind.price,
+            volume: 0.0,
+            dollar_volume: 0.0,
+            vwap: trade.kind.price,
+            trade_count: 0,
+        }
+    }
+
+    fn push(&mut self, trade: &MarketEvent<PublicTrade>) {
+        self.push_amount(trade, trade.kind.amount)
+    }
+
+    /// Aggregate the provided portion of the [`PublicTrade`] amount into [`Self`].
+    fn push_amount(&mut self, trade: &MarketEvent<PublicTrade>, amount: f64) {
+        let price = trade.kind.price;
+        self.close_time = trade.exchange_time;
+        self.high = self.high.max(price);
+        self.low = self.low.min(price);
+        self.close = price;
+        self.volume += amount;
+        self.dollar_volume += price * amount;
+        self.trade_count += 1;
+        if self.volume > 0.0 {
+            self.vwap = self.dollar_volume / self.volume;
+        }
+    }
+
+    /// Amount that must still be traded at the provided price for [`Self`] to reach the
+    /// [`BarThreshold`].
+    fn remaining(&self, threshold: BarThreshold, price: f64) -> f64 {
+        match threshold {
+            BarThreshold::Volume(threshold) => threshold - self.volume,
+            BarThreshold::Dollar(threshold) => (threshold - self.dollar_volume) / price,
+        }
+    }
+
+    /// Set the cumulative value of [`Self`] to exactly the [`BarThreshold`], removing the
+    /// floating point error of closing it with the [`remaining`](Self::remaining) amount.
+    fn close(&mut self, threshold: BarThreshold) {
+        match threshold {
+            BarThreshold::Volume(threshold) => self.volume = threshold,
+            BarThreshold::Dollar(threshold) => self.dollar_volume = threshold,
+        }
+        if self.volume > 0.0 {
+            self.vwap = self.dollar_volume / self.volume;
+        }
+    }
+
+    fn reached(&self, threshold: BarThreshold) -> bool {
+        match threshold {
+            BarThreshold::Volume(threshold) => self.volume >= threshold,
+            BarThreshold::Dollar(threshold) => self.dollar_volume >= threshold,
+        }
+    }
+}
+
+/// [`Adapter`] that aggregates [`PublicTrade`] [`MarketEvent`]s into volume or dollar [`Bar`]s,
+/// emitting a [`MarketEvent<Bar>`] each time the cumulative [`BarThreshold`] is reached.
+///
+/// ### Notes
+/// - Bars are tracked independently for every exchange & instrument combination.
+/// - Trades are not split across bars, so the [`PublicTrade`] that reaches the threshold is
+///   included in full in the closing [`Bar`], which may therefore exceed the threshold. See
+///   [`SplitBarAdapter`] for bars that close exactly on the threshold.
+/// - Every [`PublicTrade`] is aggregated by default, including those flagged with a
+///   [`TradeCondition`]. See [`BarAdapter::excluding`].
+#[derive(Clone, Debug)]
+pub struct BarAdapter {
+    threshold: BarThreshold,
+    excluded: Vec<TradeCondition>,
+    bars: HashMap<(Exchange, Arc<Instrument>), Bar>,
+}
+
+impl BarAdapter {
+    /// Construct a new [`Self`] that closes a [`Bar`] each time the provided [`BarThreshold`]
+    /// is reached.
+    ///
+    /// ### Panics
+    /// Panics if the threshold is not positive.
+    pub fn new(threshold: BarThreshold) -> Self {
+        assert!(
+            threshold.value() > 0.0,
+            "BarAdapter threshold must be positive"
+        );
+        Self {
+            threshold,
+            excluded: Vec::new(),
+            bars: HashMap::new(),
+        }
+    }
+
+    /// Exclude every [`PublicTrade`] flagged with any of the provided [`TradeCondition`]s (eg/ a
+    /// [`TradeCondition::Block`] trade) from the OHLCV, volume & vwap of each [`Bar`].
+    pub fn excluding<Conditions>(mut self, conditions: Conditions) -> Self
+    where
+        Conditions: IntoIterator<Item = TradeCondition>,
+    {
+        self.excluded.extend(conditions);
+        self
+    }
+
+    fn excludes(&self, trade: &PublicTrade) -> bool {
+        trade
+            .conditions
+            .iter()
+            .any(|condition| self.excluded.contains(condition))
+    }
+}
+
+impl Adapter<MarketEvent<PublicTrade>> for BarAdapter {
+    type Output = MarketEvent<Bar>;
+
+    fn adapt(&mut self, input: MarketEvent<PublicTrade>) -> Option<Self::Output> {
+        if self.excludes(&input.kind) {
+            return None;
+        }
+
+        let key = (input.exchange.clone(), input.instrument.clone());
+        let bar = self.bars.entry(key).or_insert_with(|| Bar::new(&input));
+        bar.push(&input);
+
+        if !bar.reached(self.threshold) {
+            return None;
+        }
+
+        let bar = self
+            .bars
+            .remove(&(input.exchange.clone(), input.instrument.clone()))?;
+
+        Some(MarketEvent {
+            exchange_time: input.exchange_time,
+            received_time: input.received_time,
+            exchange: input.exchange,
+            instrument: input.instrument,
+            kind: bar,
+        })
+    }
+}
+
+/// [`Adapter`] that aggregates [`PublicTrade`] [`MarketEvent`]s into volume or dollar [`Bar`]s
+/// that close exactly on the [`BarThreshold`], emitting every [`MarketEvent<Bar>`] closed by
+/// each [`PublicTrade`].
+///
+/// ### Notes
+/// - The [`PublicTrade`] that crosses the threshold is split, with the amount required to reach
+///   the threshold closing the current [`Bar`] and the remainder carried over into the next, so
+///   no volume is lost. A large [`PublicTrade`] may therefore close several [`Bar`]s at once.
+/// - A split [`PublicTrade`] is counted in the `trade_count` of every [`Bar`] it contributes to.
+/// - Bars are tracked independently for every exchange & instrument combination.
+/// - See [`BarAdapter`] for bars that include the crossing [`PublicTrade`] in full.
+#[derive(Clone, Debug)]
+pub struct SplitBarAdapter {
+    threshold: BarThreshold,
+    excluded: Vec<TradeCondition>,
+    bars: HashMap<(Exchange, Arc<Instrument>), Bar>,
+}
+
+impl SplitBarAdapter {
+    /// Construct a new [`Self`] that closes a [`Bar`] each time the provided [`BarThreshold`]
+    /// is reached.
+    ///
+    /// ### Panics
+    /// Panics if the threshold is not positive.
+    pub fn new(threshold: BarThreshold) -> Self {
+        assert!(
+            threshold.value() > 0.0,
+            "SplitBarAdapter threshold must be positive"
+        );
+        Self {
+            threshold,
+            excluded: Vec::new(),
+            bars: HashMap::new(),
+        }
+    }
+
+    /// Exclude every [`PublicTrade`] flagged with any of the provided [`TradeCondition`]s (eg/ a
+    /// [`TradeCondition::Block`] trade) from the OHLCV, volume & vwap of each [`Bar`].
+    pub fn excluding<Conditions>(mut self, conditions: Conditions) -> Self
+    where
+        Conditions: IntoIterator<Item = TradeCondition>,
+    {
+        self.excluded.extend(conditions);
+        self
+    }
+}
+
+impl Adapter<MarketEvent<PublicTrade>> for SplitBarAdapter {
+    type Output = Vec<MarketEvent<Bar>>;
+
+    fn adapt(&mut self, input: MarketEvent<PublicTrade>) -> Option<Self::Output> {
+        if input
+            .kind
+            .conditions
+            .iter()
+            .any(|condition| self.excluded.contains(condition))
+        {
+            return None;
+        }
+
+        let key = (input.exchange.clone(), input.instrument.clone());
+        let mut remaining = input.kind.amount;
+        let mut closed = Vec::new();
+
+        loop {
+            let bar = self
+                .bars
+                .entry(key.clone())
+                .or_insert_with(|| Bar::new(&input));
+
+            let required = bar.remaining(self.threshold, input.kind.price);
+            if remaining < required {
+                bar.push_amount(&input, remaining);
+                break;
+            }
+
+            // Close the Bar with the required amount, carrying the remainder into the next
+            bar.push_amount(&input, required);
+            bar.close(self.threshold);
+            closed.extend(self.bars.remove(&key));
+
+            remaining -= required;
+            if remaining <= 0.0 {
+                break;
+            }
+        }
+
+        if closed.is_empty() {
+            return None;
+        }
+
+        Some(
+            closed
+                .into_iter()
+                .map(|bar| MarketEvent {
+                    exchange_time: input.exchange_time,
+                    received_time: input.received_time,
+                    exchange: input.exchange.clone(),
+                    instrument: input.instrument.clone(),
+                    kind: bar,
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use barter_integration::model::Side;
+
+    fn trade(secs: i64, price: f64, amount: f64) -> MarketEvent<PublicTrade> {
+        fixtures::trade(time(secs), &secs.to_string(), price, amount, Side::Buy)
+    }
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        DateTime::<Utc>::from_timestamp(secs, 0).unwrap()
+    }
+
+    #[test]
+    fn test_bar_adapter() {
+        struct TestCase {
+            threshold: BarThreshold,
+            input: Vec<MarketEvent<PublicTrade>>,
+            expected: Vec<Option<Bar>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: volume bar closes exactly when the volume threshold is hit
+                threshold: BarThreshold::Volume(3.0),
+                input: vec![
+                    trade(0, 100.0, 1.0),
+                    trade(1, 110.0, 1.0),
+                    trade(2, 90.0, 1.0),
+                    trade(3, 95.0, 2.0),
+                ],
+                expected: vec![
+                    None,
+                    None,
+                    Some(Bar {
+                        open_time: time(0),
+                        close_time: time(2),
+                        open: 100.0,
+                        high: 110.0,
+                        low: 90.0,
+                        close: 90.0,
+                        volume: 3.0,
+                        dollar_volume: 300.0,
+                        vwap: 100.0,
+                        trade_count: 3,
+                    }),
+                    None,
+                ],
+            },
+            TestCase {
+                // TC1: dollar bar includes the trade that exceeds the threshold in full
+                threshold: BarThreshold::Dollar(1_000.0),
+                input: vec![trade(0, 100.0, 4.0), trade(1, 200.0, 4.0)],
+                expected: vec![
+                    None,
+                    Some(Bar {
+                        open_time: time(0),
+                        close_time: time(1),
+                        open: 100.0,
+                        high: 200.0,
+                        low: 100.0,
+                        close: 200.0,
+                        volume: 8.0,
+                        dollar_volume: 1_200.0,
+                        vwap: 150.0,
+                        trade_count: 2,
+                    }),
+                ],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut adapter = BarAdapter::new(test.threshold);
+            let actual = test
+                .input
+                .into_iter()
+                .map(|trade| adapter.adapt(trade).map(|event| event.kind))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_bar_adapter_excluding_flagged_trades() {
+        let block_trade = MarketEvent {
+            kind: PublicTrade {
+                conditions: vec![TradeCondition::Block],
+                ..trade(1, 200.0, 2.0).kind
+            },
+            ..trade(1, 200.0, 2.0)
+        };
+        let input = [trade(0, 100.0, 1.0), block_trade, trade(2, 110.0, 1.0)];
+
+        struct TestCase {
+            adapter: BarAdapter,
+            expected: Option<Bar>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: flagged block trade is included by default
+                adapter: BarAdapter::new(BarThreshold::Volume(4.0)),
+                expected: Some(Bar {
+                    open_time: time(0),
+                    close_time: time(2),
+                    open: 100.0,
+                    high: 200.0,
+                    low: 100.0,
+                    close: 110.0,
+                    volume: 4.0,
+                    dollar_volume: 610.0,
+                    vwap: 152.5,
+                    trade_count: 3,
+                }),
+            },
+            TestCase {
+                // TC1: flagged block trade is excluded from the vwap when exclusion is enabled
+                adapter: BarAdapter::new(BarThreshold::Volume(2.0))
+                    .excluding([TradeCondition::Block]),
+                expected: Some(Bar {
+                    open_time: time(0),
+                    close_time: time(2),
+                    open: 100.0,
+                    high: 110.0,
+                    low: 100.0,
+                    close: 110.0,
+                    volume: 2.0,
+                    dollar_volume: 210.0,
+                    vwap: 105.0,
+                    trade_count: 2,
+                }),
+            },
+        ];
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            let actual = input
+                .iter()
+                .cloned()
+                .filter_map(|trade| test.adapter.adapt(trade))
+                .map(|event| event.kind)
+                .last();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_split_bar_adapter_carries_remainder() {
+        struct TestCase {
+            threshold: BarThreshold,
+            input: Vec<MarketEvent<PublicTrade>>,
+            expected: Vec<Bar>,
+            expected_remainder: f64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trades summing to two-and-a-bit volume thresholds close two exact bars
+                threshold: BarThreshold::Volume(2.0),
+                input: vec![
+                    trade(0, 100.0, 1.5),
+                    trade(1, 110.0, 1.0),
+                    trade(2, 90.0, 1.25),
+                    trade(3, 95.0, 0.75),
+                ],
+                expected: vec![
+                    Bar {
+                        open_time: time(0),
+                        close_time: time(1),
+                        open: 100.0,
+                        high: 110.0,
+                        low: 100.0,
+                        close: 110.0,
+                        volume: 2.0,
+                        dollar_volume: 205.0,
+                        vwap: 102.5,
+                        trade_count: 2,
+                    },
+                    Bar {
+                        open_time: time(1),
+                        close_time: time(3),
+                        open: 110.0,
+                        high: 110.0,
+                        low: 90.0,
+                        close: 95.0,
+                        volume: 2.0,
+                        dollar_volume: 191.25,
+                        vwap: 95.625,
+                        trade_count: 3,
+                    },
+                ],
+                expected_remainder: 0.5,
+            },
+            TestCase {
+                // TC1: a single large trade closes several dollar bars at once
+                threshold: BarThreshold::Dollar(1_000.0),
+                input: vec![trade(0, 100.0, 25.0)],
+                expected: vec![
+                    Bar {
+                        open_time: time(0),
+                        close_time: time(0),
+                        open: 100.0,
+                        high: 100.0,
+                        low: 100.0,
+                        close: 100.0,
+                        volume: 10.0,
+                        dollar_volume: 1_000.0,
+                        vwap: 100.0,
+                        trade_count: 1,
+                    };
+                    2
+                ],
+                expected_remainder: 5.0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut adapter = SplitBarAdapter::new(test.threshold);
+            let actual = test
+                .input
+                .into_iter()
+                .filter_map(|trade| adapter.adapt(trade))
+                .flatten()
+                .map(|event| event.kind)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+
+            // Remainder is carried over into the open Bar
+            let open = adapter.bars.values().map(|bar| bar.volume).sum::<f64>();
+            assert_eq!(open, test.expected_remainder, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use super::Adapter;
+use crate::{
+    event::MarketEvent,
+    subscription::candle::{Candle, ContinuousCandle},
+};
+
+/// [`Adapter`] that only yields the final update of each closed [`Candle`], suppressing the
+/// intra-candle updates of open [`Candle`]s.
+///
+/// Useful for indicator-driven consumers that only act on closed [`Candle`]s, since intra-candle
+/// updates make up the vast majority of [`Candle`] events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct ClosedCandles;
+
+impl Adapter<MarketEvent<Candle>> for ClosedCandles {
+    type Output = MarketEvent<Candle>;
+
+    fn adapt(&mut self, input: MarketEvent<Candle>) -> Option<Self::Output> {
+        input.kind.is_final.then_some(input)
+    }
+}
+
+impl Adapter<MarketEvent<ContinuousCandle>> for ClosedCandles {
+    type Output = MarketEvent<ContinuousCandle>;
+
+    fn adapt(&mut self, input: MarketEvent<ContinuousCandle>) -> Option<Self::Output> {
+        input.kind.candle.is_final.then_some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange,
+    };
+    use chrono::{TimeZone, Utc};
+
+    fn candle(close: f64, is_final: bool) -> MarketEvent<Candle> {
+        let close_time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 59).unwrap();
+
+        MarketEvent {
+            exchange_time: close_time,
+            received_time: close_time,
+            exchange: Exchange::from("exchange"),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)).into(),
+            kind: Candle {
+                close_time,
+                open: 100.0,
+                high: close.max(100.0),
+                low: close.min(100.0),
+                close,
+                volume: 1.0,
+                quote_volume: Some(100.0),
+                trade_count: 1,
+                is_final,
+            },
+        }
+    }
+
+    #[test]
+    fn test_closed_candles() {
+        let mut adapter = ClosedCandles;
+
+        // Intra-candle updates followed by the close of the Candle
+        let input = vec![
+            candle(101.0, false),
+            candle(99.0, false),
+            candle(102.0, false),
+            candle(103.0, true),
+        ];
+
+        let actual = input
+            .into_iter()
+            .filter_map(|candle| adapter.adapt(candle))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, vec![candle(103.0, true)]);
+    }
+}
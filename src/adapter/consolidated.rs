@@ -0,0 +1,304 @@
+use super::Adapter;
+use crate::{
+    event::MarketEvent,
+    exchange::ExchangeId,
+    subscription::{
+        book::{Level, OrderBook},
+        intern::intern,
+    },
+};
+use barter_integration::model::instrument::Instrument;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, collections::HashMap, sync::Arc, time::Duration};
+
+/// [`Level`] of a [`ConsolidatedBook`], tagged with the [`ExchangeId`] it was sourced from.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ConsolidatedLevel {
+    pub exchange: ExchangeId,
+    pub level: Level,
+}
+
+/// Consolidated best bid & offer across the exchanges of a [`ConsolidatedBook`], yielded by its
+/// [`Adapter`] implementation.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ConsolidatedBbo {
+    pub best_bid: Option<ConsolidatedLevel>,
+    pub best_ask: Option<ConsolidatedLevel>,
+}
+
+/// Merged view of the latest [`OrderBook`] of several exchanges for the same logical
+/// [`Instrument`], where each [`ConsolidatedLevel`] is tagged with its source [`ExchangeId`].
+///
+/// Unlike the [`AggregatedBookAdapter`](super::aggregated::AggregatedBookAdapter), levels are not
+/// bucketed or summed, so the best prices identify the exchange to trade on (eg/ for arbitrage).
+///
+/// ### Notes
+/// - Bids are sorted best (highest) first & asks best (lowest) first. Levels of equal price are
+///   sorted by descending amount.
+/// - A source book is considered stale, and its levels are dropped, if it has not updated within
+///   `stale_after` of the most recent source book update.
+/// - [`MarketEvent`]s for other [`Instrument`]s, or from unknown exchanges, are ignored.
+/// - Each [`MarketEvent<ConsolidatedBbo>`] yielded as an [`Adapter`] carries the exchange of the
+///   source book update that triggered it.
+#[derive(Clone, Debug)]
+pub struct ConsolidatedBook {
+    instrument: Arc<Instrument>,
+    stale_after: Duration,
+    books: HashMap<ExchangeId, SourceBook>,
+    last_update_time: DateTime<Utc>,
+    bids: Vec<ConsolidatedLevel>,
+    asks: Vec<ConsolidatedLevel>,
+}
+
+/// Latest [`OrderBook`] of a source exchange, and the time it was last updated.
+#[derive(Clone, Debug)]
+struct SourceBook {
+    time: DateTime<Utc>,
+    book: OrderBook,
+}
+
+impl ConsolidatedBook {
+    /// Construct a new empty [`Self`] that consolidates the [`OrderBook`]s of the provided
+    /// [`Instrument`].
+    pub fn new(instrument: Instrument, stale_after: Duration) -> Self {
+        Self {
+            instrument: intern(&instrument),
+            stale_after,
+            books: HashMap::new(),
+            last_update_time: DateTime::<Utc>::default(),
+            bids: Vec::new(),
+            asks: Vec::new(),
+        }
+    }
+
+    /// Ingest the latest [`OrderBook`] of an exchange & re-consolidate [`Self`], returning `false`
+    /// if the [`MarketEvent`] is ignored.
+    pub fn update(&mut self, event: &MarketEvent<OrderBook>) -> bool {
+        if event.instrument != self.instrument {
+            return false;
+        }
+        let Ok(exchange) = event.exchange.to_string().parse::<ExchangeId>() else {
+            return false;
+        };
+
+        self.books.insert(
+            exchange,
+            SourceBook {
+                time: event.exchange_time,
+                book: event.kind.clone(),
+            },
+        );
+        self.consolidate();
+        true
+    }
+
+    /// Time of the most recent source [`OrderBook`] update.
+    pub fn last_update_time(&self) -> DateTime<Utc> {
+        self.last_update_time
+    }
+
+    /// Consolidated bids across every fresh source book, sorted best (highest) first.
+    pub fn bids(&self) -> &[ConsolidatedLevel] {
+        &self.bids
+    }
+
+    /// Consolidated asks across every fresh source book, sorted best (lowest) first.
+    pub fn asks(&self) -> &[ConsolidatedLevel] {
+        &self.asks
+    }
+
+    /// Best bid [`Level`] across every fresh source book, and the [`ExchangeId`] quoting it.
+    pub fn best_bid(&self) -> Option<(Level, ExchangeId)> {
+        self.bids.first().map(|bid| (bid.level, bid.exchange))
+    }
+
+    /// Best ask [`Level`] across every fresh source book, and the [`ExchangeId`] quoting it.
+    pub fn best_ask(&self) -> Option<(Level, ExchangeId)> {
+        self.asks.first().map(|ask| (ask.level, ask.exchange))
+    }
+
+    /// Consolidated best bid & offer of [`Self`].
+    pub fn bbo(&self) -> ConsolidatedBbo {
+        ConsolidatedBbo {
+            best_bid: self.bids.first().copied(),
+            best_ask: self.asks.first().copied(),
+        }
+    }
+
+    /// Re-merge the levels of every source book that is not stale relative to the most recent
+    /// source book update.
+    fn consolidate(&mut self) {
+        let Some(now) = self.books.values().map(|source| source.time).max() else {
+            return;
+        };
+        let stale_after =
+            chrono::Duration::from_std(self.stale_after).unwrap_or(chrono::Duration::MAX);
+
+        self.last_update_time = now;
+        self.bids.clear();
+        self.asks.clear();
+        for (exchange, source) in self
+            .books
+            .iter()
+            .filter(|(_, source)| now.signed_duration_since(source.time) <= stale_after)
+        {
+            let tag = |level: &Level| ConsolidatedLevel {
+                exchange: *exchange,
+                level: *level,
+            };
+            self.bids.extend(source.book.bids.levels.iter().map(tag));
+            self.asks.extend(source.book.asks.levels.iter().map(tag));
+        }
+
+        self.bids.sort_by(|a, b| {
+            b.level
+                .price
+                .total_cmp(&a.level.price)
+                .then_with(|| by_amount(a, b))
+        });
+        self.asks.sort_by(|a, b| {
+            a.level
+                .price
+                .total_cmp(&b.level.price)
+                .then_with(|| by_amount(a, b))
+        });
+    }
+}
+
+/// Order [`ConsolidatedLevel`]s of equal price by descending amount, then by [`ExchangeId`] so
+/// the consolidation is deterministic.
+fn by_amount(a: &ConsolidatedLevel, b: &ConsolidatedLevel) -> Ordering {
+    b.level
+        .amount
+        .total_cmp(&a.level.amount)
+        .then_with(|| a.exchange.cmp(&b.exchange))
+}
+
+impl Adapter<MarketEvent<OrderBook>> for ConsolidatedBook {
+    type Output = MarketEvent<ConsolidatedBbo>;
+
+    fn adapt(&mut self, input: MarketEvent<OrderBook>) -> Option<Self::Output> {
+        if !self.update(&input) {
+            return None;
+        }
+
+        Some(MarketEvent {
+            exchange_time: self.last_update_time,
+            received_time: input.received_time,
+            exchange: input.exchange,
+            instrument: self.instrument.clone(),
+            kind: self.bbo(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Exchange};
+
+    fn book_event(
+        exchange: ExchangeId,
+        secs: i64,
+        bids: Vec<(f64, f64)>,
+        asks: Vec<(f64, f64)>,
+    ) -> MarketEvent<OrderBook> {
+        MarketEvent {
+            exchange: Exchange::from(exchange),
+            ..fixtures::book_event(fixtures::secs(secs), bids, asks)
+        }
+    }
+
+    #[test]
+    fn test_consolidated_book() {
+        struct TestCase {
+            input: MarketEvent<OrderBook>,
+            expected_best_bid: Option<(Level, ExchangeId)>,
+            expected_best_ask: Option<(Level, ExchangeId)>,
+            expected_levels: (usize, usize),
+        }
+
+        let mut book = ConsolidatedBook::new(
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            Duration::from_secs(5),
+        );
+
+        let tests = vec![
+            TestCase {
+                // TC0: single exchange book
+                input: book_event(
+                    ExchangeId::BinanceSpot,
+                    0,
+                    vec![(100.0, 1.0), (99.0, 2.0)],
+                    vec![(101.0, 1.0), (102.0, 1.0)],
+                ),
+                expected_best_bid: Some((Level::new(100.0, 1.0), ExchangeId::BinanceSpot)),
+                expected_best_ask: Some((Level::new(101.0, 1.0), ExchangeId::BinanceSpot)),
+                expected_levels: (2, 2),
+            },
+            TestCase {
+                // TC1: second exchange has the best bid, whilst binance keeps the best ask
+                input: book_event(
+                    ExchangeId::Kraken,
+                    1,
+                    vec![(100.5, 3.0), (99.0, 1.0)],
+                    vec![(101.5, 0.5)],
+                ),
+                expected_best_bid: Some((Level::new(100.5, 3.0), ExchangeId::Kraken)),
+                expected_best_ask: Some((Level::new(101.0, 1.0), ExchangeId::BinanceSpot)),
+                expected_levels: (4, 3),
+            },
+            TestCase {
+                // TC2: equal best ask prices are sourced from the exchange with the larger amount
+                input: book_event(
+                    ExchangeId::Kraken,
+                    2,
+                    vec![(100.5, 3.0)],
+                    vec![(101.0, 4.0)],
+                ),
+                expected_best_bid: Some((Level::new(100.5, 3.0), ExchangeId::Kraken)),
+                expected_best_ask: Some((Level::new(101.0, 4.0), ExchangeId::Kraken)),
+                expected_levels: (3, 3),
+            },
+            TestCase {
+                // TC3: binance book is stale, so only kraken levels are consolidated
+                input: book_event(
+                    ExchangeId::Kraken,
+                    10,
+                    vec![(99.0, 1.0)],
+                    vec![(102.0, 1.0)],
+                ),
+                expected_best_bid: Some((Level::new(99.0, 1.0), ExchangeId::Kraken)),
+                expected_best_ask: Some((Level::new(102.0, 1.0), ExchangeId::Kraken)),
+                expected_levels: (1, 1),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = book.adapt(test.input).unwrap();
+            assert_eq!(book.best_bid(), test.expected_best_bid, "TC{index} failed");
+            assert_eq!(book.best_ask(), test.expected_best_ask, "TC{index} failed");
+            assert_eq!(
+                (book.bids().len(), book.asks().len()),
+                test.expected_levels,
+                "TC{index} failed"
+            );
+            assert_eq!(actual.kind, book.bbo(), "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_consolidated_book_ignores_other_instruments() {
+        let mut book = ConsolidatedBook::new(
+            Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+            Duration::from_secs(5),
+        );
+
+        let input = book_event(ExchangeId::Okx, 0, vec![(100.0, 1.0)], vec![(101.0, 1.0)]);
+        assert!(book.adapt(input).is_none());
+        assert_eq!(book.best_bid(), None);
+        assert_eq!(book.best_ask(), None);
+    }
+}
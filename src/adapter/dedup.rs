@@ -0,0 +1,240 @@
+use super::Adapter;
+use crate::{
+    event::{DataKind, MarketEvent},
+    subscription::trade::PublicTrade,
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+use tracing::debug;
+
+/// Window of recently seen [`PublicTrade`]s within which a [`TradeDedupAdapter`] drops
+/// duplicates.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum DedupWindow {
+    /// Remember the most recent `n` distinct trades.
+    Count(usize),
+    /// Remember the trades with an `exchange_time` within the provided [`Duration`] of the
+    /// latest trade.
+    Time(Duration),
+}
+
+/// Compound key of a [`PublicTrade`], since some exchanges reuse trade ids across instruments.
+type TradeKey = (Exchange, Arc<Instrument>, String);
+
+/// [`Adapter`] that drops duplicate [`PublicTrade`]s (eg/ re-sent after a re-connection, or by
+/// overlapping combined streams) seen within a [`DedupWindow`], guarding volume aggregations.
+///
+/// ### Notes
+/// - Trades are keyed on their exchange, instrument & [`PublicTrade::id`].
+/// - Remembered trades are held in a ring buffer evicted according to the [`DedupWindow`], so
+///   memory is bounded.
+/// - Non [`DataKind::Trade`] events of a [`MarketEvent<DataKind>`] stream pass through untouched.
+#[derive(Clone, Debug)]
+pub struct TradeDedupAdapter {
+    window: DedupWindow,
+    seen: HashSet<TradeKey>,
+    ring: VecDeque<(TradeKey, DateTime<Utc>)>,
+    latest: Option<DateTime<Utc>>,
+}
+
+impl TradeDedupAdapter {
+    /// Construct a new [`Self`] that drops duplicate trades seen within the provided
+    /// [`DedupWindow`].
+    pub fn new(window: DedupWindow) -> Self {
+        Self {
+            window,
+            seen: HashSet::new(),
+            ring: VecDeque::new(),
+            latest: None,
+        }
+    }
+
+    /// Determine if the trade has already been seen within the [`DedupWindow`], remembering it
+    /// if not.
+    fn is_duplicate(
+        &mut self,
+        exchange: &Exchange,
+        instrument: &Arc<Instrument>,
+        time: DateTime<Utc>,
+        trade: &PublicTrade,
+    ) -> bool {
+        let key = (exchange.clone(), instrument.clone(), trade.id.clone());
+        if self.seen.contains(&key) {
+            debug!(
+                %exchange,
+                %instrument,
+                id = %trade.id,
+                "dropping duplicate PublicTrade"
+            );
+            return true;
+        }
+
+        self.seen.insert(key.clone());
+        self.ring.push_back((key, time));
+        self.latest = Some(self.latest.map_or(time, |latest| latest.max(time)));
+        self.evict();
+        false
+    }
+
+    /// Forget the trades that fell out of the [`DedupWindow`].
+    fn evict(&mut self) {
+        while let Some((key, time)) = self.ring.front() {
+            let expired = match self.window {
+                DedupWindow::Count(count) => self.ring.len() > count,
+                DedupWindow::Time(window) => self
+                    .latest
+                    .zip(chrono::Duration::from_std(window).ok())
+                    .is_some_and(|(latest, window)| *time < latest - window),
+            };
+            if !expired {
+                break;
+            }
+
+            self.seen.remove(key);
+            self.ring.pop_front();
+        }
+    }
+}
+
+impl Adapter<MarketEvent<PublicTrade>> for TradeDedupAdapter {
+    type Output = MarketEvent<PublicTrade>;
+
+    fn adapt(&mut self, input: MarketEvent<PublicTrade>) -> Option<Self::Output> {
+        let duplicate = self.is_duplicate(
+            &input.exchange,
+            &input.instrument,
+            input.exchange_time,
+            &input.kind,
+        );
+        (!duplicate).then_some(input)
+    }
+}
+
+impl Adapter<MarketEvent<DataKind>> for TradeDedupAdapter {
+    type Output = MarketEvent<DataKind>;
+
+    fn adapt(&mut self, input: MarketEvent<DataKind>) -> Option<Self::Output> {
+        let DataKind::Trade(trade) = &input.kind else {
+            return Some(input);
+        };
+
+        let duplicate = self.is_duplicate(
+            &input.exchange,
+            &input.instrument,
+            input.exchange_time,
+            trade,
+        );
+        (!duplicate).then_some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, subscription::index::IndexPrice};
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use chrono::TimeZone;
+
+    fn time(second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, second).unwrap()
+    }
+
+    fn trade(base: &str, id: &str, second: u32) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)).into(),
+            ..fixtures::trade(time(second), id, 1.0, 1.0, Side::Buy)
+        }
+    }
+
+    #[test]
+    fn test_trade_dedup_adapter() {
+        struct TestCase {
+            input: MarketEvent<PublicTrade>,
+            expected: bool,
+        }
+
+        let mut adapter = TradeDedupAdapter::new(DedupWindow::Count(2));
+
+        let tests = vec![
+            TestCase {
+                // TC0: first trade passes through
+                input: trade("btc", "1", 0),
+                expected: true,
+            },
+            TestCase {
+                // TC1: duplicate trade within the window is dropped
+                input: trade("btc", "1", 0),
+                expected: false,
+            },
+            TestCase {
+                // TC2: same trade id of another instrument does not collide
+                input: trade("eth", "1", 1),
+                expected: true,
+            },
+            TestCase {
+                // TC3: new trade evicts the oldest trade from the window
+                input: trade("btc", "2", 2),
+                expected: true,
+            },
+            TestCase {
+                // TC4: duplicate of a trade that left the window passes through
+                input: trade("btc", "1", 3),
+                expected: true,
+            },
+            TestCase {
+                // TC5: duplicate trade still within the window is dropped
+                input: trade("btc", "2", 3),
+                expected: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = adapter.adapt(test.input).is_some();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_trade_dedup_adapter_time_window() {
+        let mut adapter = TradeDedupAdapter::new(DedupWindow::Time(Duration::from_secs(5)));
+
+        assert!(adapter.adapt(trade("btc", "1", 0)).is_some());
+        assert!(adapter.adapt(trade("btc", "1", 4)).is_none());
+
+        // Trade older than the window is forgotten once a later trade arrives
+        assert!(adapter.adapt(trade("btc", "2", 10)).is_some());
+        assert!(adapter.adapt(trade("btc", "1", 10)).is_some());
+        assert_eq!(adapter.ring.len(), 2);
+    }
+
+    #[test]
+    fn test_trade_dedup_adapter_passes_through_non_trades() {
+        let mut adapter = TradeDedupAdapter::new(DedupWindow::Count(10));
+
+        let index_price = |second| MarketEvent {
+            exchange_time: time(second),
+            received_time: time(second),
+            exchange: Exchange::from("exchange"),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)).into(),
+            kind: DataKind::IndexPrice(IndexPrice {
+                price: 1.0,
+                time: time(second),
+            }),
+        };
+
+        assert!(adapter.adapt(index_price(0)).is_some());
+        assert!(adapter.adapt(index_price(0)).is_some());
+        assert!(adapter
+            .adapt(MarketEvent::<DataKind>::from(trade("btc", "1", 0)))
+            .is_some());
+        assert!(adapter
+            .adapt(MarketEvent::<DataKind>::from(trade("btc", "1", 0)))
+            .is_none());
+    }
+}
@@ -0,0 +1,218 @@
+use super::Adapter;
+use crate::{event::MarketEvent, subscription::book::OrderBook};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+/// Total bid & ask amounts resting within `bps` basis points of an instrument's mid price.
+///
+/// See [`OrderBook::depth_within_bps`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BookDepth {
+    pub bps: f64,
+    pub bid_size: f64,
+    pub ask_size: f64,
+}
+
+/// [`Adapter`] that consumes [`OrderBook`] [`MarketEvent`]s and emits the [`BookDepth`] within
+/// the configured band of basis points at most once every `interval` per instrument.
+///
+/// Useful as a liquidity gauge (eg/ "how much size is within 1% of mid on each side").
+///
+/// ### Notes
+/// - Depth is sampled independently for every exchange & instrument combination, starting with
+///   the first [`OrderBook`] of each.
+/// - Intervals elapse by [`OrderBook`] `exchange_time`s, with each sample measuring the first
+///   [`OrderBook`] at least `interval` after the previous sample.
+/// - Intervals also elapse by the time provided to [`Adapter::tick`], which samples the latest
+///   [`OrderBook`] of each instrument at that time. Use
+///   [`AdapterExt::adapt_with_ticks`](super::AdapterExt::adapt_with_ticks) with the `interval` as
+///   the period so the depth of a book without updates is still sampled every `interval`.
+/// - Empty books have zero depth on both sides.
+#[derive(Clone, Debug)]
+pub struct DepthAdapter {
+    bps: f64,
+    interval: Duration,
+    samples: HashMap<(Exchange, Arc<Instrument>), DepthSample>,
+}
+
+/// Latest [`OrderBook`] of a single exchange & instrument combination, alongside the time of the
+/// previous sample.
+#[derive(Clone, Debug)]
+struct DepthSample {
+    last_sample: DateTime<Utc>,
+    latest: OrderBook,
+}
+
+impl DepthAdapter {
+    /// Construct a new [`Self`] that emits the [`BookDepth`] within `bps` basis points of the mid
+    /// price (eg/ 100bps for 1%) every `interval` (eg/ 1s).
+    pub fn new(bps: f64, interval: Duration) -> Self {
+        Self {
+            bps,
+            interval,
+            samples: HashMap::new(),
+        }
+    }
+}
+
+impl Adapter<MarketEvent<OrderBook>> for DepthAdapter {
+    type Output = MarketEvent<BookDepth>;
+
+    fn adapt(&mut self, input: MarketEvent<OrderBook>) -> Option<Self::Output> {
+        let interval = chrono::Duration::from_std(self.interval).unwrap_or(chrono::Duration::MAX);
+        let key = (input.exchange.clone(), input.instrument.clone());
+
+        // Only sample once the interval since the previous sample has elapsed, otherwise keep the
+        // latest book for the next tick
+        if let Some(sample) = self.samples.get_mut(&key) {
+            if input
+                .exchange_time
+                .signed_duration_since(sample.last_sample)
+                < interval
+            {
+                sample.latest = input.kind;
+                return None;
+            }
+        }
+
+        let (bid_size, ask_size) = input.kind.depth_within_bps(self.bps);
+        self.samples.insert(
+            key,
+            DepthSample {
+                last_sample: input.exchange_time,
+                latest: input.kind,
+            },
+        );
+
+        Some(MarketEvent {
+            exchange_time: input.exchange_time,
+            received_time: input.received_time,
+            exchange: input.exchange,
+            instrument: input.instrument,
+            kind: BookDepth {
+                bps: self.bps,
+                bid_size,
+                ask_size,
+            },
+        })
+    }
+
+    fn tick(&mut self, now: DateTime<Utc>) -> Vec<Self::Output> {
+        let interval = chrono::Duration::from_std(self.interval).unwrap_or(chrono::Duration::MAX);
+
+        let mut output = vec![];
+        for ((exchange, instrument), sample) in self.samples.iter_mut() {
+            if now.signed_duration_since(sample.last_sample) < interval {
+                continue;
+            }
+            sample.last_sample = now;
+
+            let (bid_size, ask_size) = sample.latest.depth_within_bps(self.bps);
+            output.push(MarketEvent {
+                exchange_time: now,
+                received_time: now,
+                exchange: exchange.clone(),
+                instrument: instrument.clone(),
+                kind: BookDepth {
+                    bps: self.bps,
+                    bid_size,
+                    ask_size,
+                },
+            });
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{book_event, millis};
+
+    #[test]
+    fn test_depth_adapter() {
+        struct TestCase {
+            input: MarketEvent<OrderBook>,
+            expected: Option<(f64, f64)>,
+        }
+
+        let mut adapter = DepthAdapter::new(100.0, Duration::from_secs(1));
+
+        let tests = vec![
+            TestCase {
+                // TC0: first book is sampled, with depth within 1% of the 100.0 mid price
+                input: book_event(
+                    millis(0),
+                    vec![(99.5, 1.0), (99.0, 2.0), (98.0, 4.0)],
+                    vec![(100.5, 1.5), (102.0, 2.5)],
+                ),
+                expected: Some((3.0, 1.5)),
+            },
+            TestCase {
+                // TC1: book within the interval of the previous sample is not sampled
+                input: book_event(millis(500), vec![(99.5, 10.0)], vec![(100.5, 10.0)]),
+                expected: None,
+            },
+            TestCase {
+                // TC2: book once the interval has elapsed is sampled
+                input: book_event(
+                    millis(1_000),
+                    vec![(99.5, 2.0)],
+                    vec![(100.5, 3.0), (101.0, 1.0)],
+                ),
+                expected: Some((2.0, 4.0)),
+            },
+            TestCase {
+                // TC3: empty book once the interval has elapsed has zero depth
+                input: book_event(millis(2_500), vec![], vec![]),
+                expected: Some((0.0, 0.0)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = adapter
+                .adapt(test.input)
+                .map(|event| (event.kind.bid_size, event.kind.ask_size));
+            match (actual, test.expected) {
+                (Some(actual), Some(expected)) => {
+                    assert!(
+                        (actual.0 - expected.0).abs() < 1e-9 && (actual.1 - expected.1).abs() < 1e-9,
+                        "TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n"
+                    );
+                }
+                (None, None) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+
+        // TC4: latest book within the interval of the previous sample is only sampled by a
+        // tick once the interval has elapsed, without a further book
+        let input = book_event(millis(3_000), vec![(99.5, 5.0)], vec![(100.5, 6.0)]);
+        assert!(adapter.adapt(input).is_none(), "TC4 failed");
+        assert!(adapter.tick(millis(3_400)).is_empty(), "TC4 failed");
+
+        let actual = adapter
+            .tick(millis(3_500))
+            .into_iter()
+            .map(|event| {
+                (
+                    event.exchange_time,
+                    event.kind.bid_size,
+                    event.kind.ask_size,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(actual, vec![(millis(3_500), 5.0, 6.0)], "TC4 failed");
+
+        // TC5: unchanged book is sampled again by a tick once the interval has elapsed
+        assert!(adapter.tick(millis(4_000)).is_empty(), "TC5 failed");
+        assert_eq!(adapter.tick(millis(4_500)).len(), 1, "TC5 failed");
+    }
+}
@@ -0,0 +1,274 @@
+use super::Adapter;
+use crate::event::MarketEvent;
+use barter_integration::model::Exchange;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+use tracing::warn;
+
+/// Apparent clock drift of an exchange, relative to every exchange consumed by the
+/// [`DriftAdapter`].
+///
+/// Latency refers to the median `received_time - exchange_time` of the exchange's
+/// [`MarketEvent`]s in the configured window, in milliseconds.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct ClockDrift {
+    pub latency_ms: f64,
+    pub cross_exchange_latency_ms: f64,
+    pub drift_ms: f64,
+    pub drifted: bool,
+}
+
+/// [`Adapter`] that consumes the [`MarketEvent`]s of several exchanges (eg/ a merged trade
+/// stream) and emits a [`MarketEvent<ClockDrift>`] every time an exchange's apparent latency
+/// drifts beyond, or returns within, the configured `threshold` of the cross-exchange latency.
+///
+/// Surfaces both exchange clock issues, and local clock (eg/ NTP) issues, which skew the
+/// latency of every exchange but one.
+///
+/// ### Notes
+/// - The cross-exchange latency is the median of every exchange's median latency, so at least
+///   three exchanges are required to attribute drift to a single exchange.
+/// - An exchange is only evaluated once it has a full `window` of latency samples, and at least
+///   one other exchange has too.
+#[derive(Clone, Debug)]
+pub struct DriftAdapter {
+    threshold: Duration,
+    window: usize,
+    latencies: HashMap<Exchange, LatencyWindow>,
+}
+
+/// Latency samples of a single exchange within the window, alongside their cached median.
+#[derive(Clone, Debug, Default)]
+struct LatencyWindow {
+    samples: VecDeque<f64>,
+    median: Option<f64>,
+    drifted: bool,
+}
+
+impl LatencyWindow {
+    /// Insert a new latency sample, evicting the oldest sample if the window is full, and
+    /// re-calculate the median once the window is full.
+    fn update(&mut self, latency_ms: f64, window: usize) {
+        if self.samples.len() == window {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(latency_ms);
+
+        if self.samples.len() == window {
+            self.median = median(self.samples.iter().copied().collect());
+        }
+    }
+}
+
+impl DriftAdapter {
+    /// Construct a new [`Self`] that flags exchanges whose median latency over the last
+    /// `window` [`MarketEvent`]s drifts further than `threshold` from the cross-exchange latency.
+    pub fn new(threshold: Duration, window: usize) -> Self {
+        Self {
+            threshold,
+            window: window.max(1),
+            latencies: HashMap::new(),
+        }
+    }
+}
+
+impl<T> Adapter<MarketEvent<T>> for DriftAdapter {
+    type Output = MarketEvent<ClockDrift>;
+
+    fn adapt(&mut self, input: MarketEvent<T>) -> Option<Self::Output> {
+        let latency_ms = input
+            .received_time
+            .signed_duration_since(input.exchange_time)
+            .num_microseconds()? as f64
+            / 1_000.0;
+
+        let window = self.latencies.entry(input.exchange.clone()).or_default();
+        window.update(latency_ms, self.window);
+        let latency_ms = window.median?;
+
+        // Determine the cross-exchange latency, requiring at least one other exchange
+        let medians = self
+            .latencies
+            .values()
+            .filter_map(|window| window.median)
+            .collect::<Vec<_>>();
+        if medians.len() < 2 {
+            return None;
+        }
+        let cross_exchange_latency_ms = median(medians)?;
+
+        let drift_ms = latency_ms - cross_exchange_latency_ms;
+        let drifted = drift_ms.abs() > self.threshold.as_secs_f64() * 1_000.0;
+
+        // Only emit if the drift state of this exchange has changed
+        let window = self.latencies.get_mut(&input.exchange)?;
+        if window.drifted == drifted {
+            return None;
+        }
+        window.drifted = drifted;
+
+        if drifted {
+            warn!(
+                exchange = %input.exchange,
+                latency_ms,
+                cross_exchange_latency_ms,
+                drift_ms,
+                "exchange latency drifted from the cross-exchange latency"
+            );
+        }
+
+        Some(MarketEvent {
+            exchange_time: input.exchange_time,
+            received_time: input.received_time,
+            exchange: input.exchange,
+            instrument: input.instrument,
+            kind: ClockDrift {
+                latency_ms,
+                cross_exchange_latency_ms,
+                drift_ms,
+                drifted,
+            },
+        })
+    }
+}
+
+/// Calculate the median of the provided values.
+pub(crate) fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let len = values.len();
+    let (lower, upper, _) = values.select_nth_unstable_by(len / 2, f64::total_cmp);
+    let upper = *upper;
+
+    if len % 2 == 1 {
+        Some(upper)
+    } else {
+        lower
+            .iter()
+            .copied()
+            .max_by(f64::total_cmp)
+            .map(|lower| (lower + upper) / 2.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+    use chrono::{DateTime, Utc};
+
+    fn event(exchange: &'static str, millis: i64, latency_ms: i64) -> MarketEvent<()> {
+        let exchange_time = DateTime::<Utc>::from_timestamp_millis(millis).unwrap();
+        MarketEvent {
+            exchange_time,
+            received_time: exchange_time + chrono::Duration::milliseconds(latency_ms),
+            exchange: Exchange::from(exchange),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)).into(),
+            kind: (),
+        }
+    }
+
+    #[test]
+    fn test_drift_adapter_flags_drifting_exchange() {
+        struct TestCase {
+            drift_ms: i64,
+            expected: Vec<(&'static str, bool)>,
+        }
+
+        let mut adapter = DriftAdapter::new(Duration::from_millis(500), 5);
+
+        let tests = vec![
+            TestCase {
+                // TC0: consistent latencies across exchanges are not flagged
+                drift_ms: 0,
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: injected drift on one exchange is flagged once its window fills
+                drift_ms: 1_000,
+                expected: vec![("kraken", true)],
+            },
+            TestCase {
+                // TC2: drift persisting is not re-flagged
+                drift_ms: 1_000,
+                expected: vec![],
+            },
+            TestCase {
+                // TC3: drift removal clears the flag once drifted samples leave the window
+                drift_ms: 0,
+                expected: vec![("kraken", false)],
+            },
+        ];
+
+        let mut millis = 0;
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut actual = vec![];
+            for _ in 0..5 {
+                for (exchange, latency_ms) in [
+                    ("binance", 50),
+                    ("coinbase", 80),
+                    ("kraken", 60 + test.drift_ms),
+                ] {
+                    millis += 10;
+                    if let Some(drift) = adapter.adapt(event(exchange, millis, latency_ms)) {
+                        actual.push((drift.exchange, drift.kind));
+                    }
+                }
+            }
+
+            assert_eq!(
+                actual.len(),
+                test.expected.len(),
+                "TC{index} failed: {actual:?}"
+            );
+            for ((exchange, drift), (expected_exchange, expected_drifted)) in
+                actual.iter().zip(test.expected)
+            {
+                assert_eq!(
+                    exchange,
+                    &Exchange::from(expected_exchange),
+                    "TC{index} failed"
+                );
+                assert_eq!(
+                    drift.drifted, expected_drifted,
+                    "TC{index} failed: {drift:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_median() {
+        struct TestCase {
+            input: Vec<f64>,
+            expected: Option<f64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: empty
+                input: vec![],
+                expected: None,
+            },
+            TestCase {
+                // TC1: odd number of values
+                input: vec![3.0, 1.0, 2.0],
+                expected: Some(2.0),
+            },
+            TestCase {
+                // TC2: even number of values
+                input: vec![4.0, 1.0, 3.0, 2.0],
+                expected: Some(2.5),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(median(test.input), test.expected, "TC{index} failed");
+        }
+    }
+}
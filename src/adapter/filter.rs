@@ -0,0 +1,150 @@
+use super::Adapter;
+use crate::{
+    event::{DataKind, MarketEvent},
+    subscription::SubKindId,
+};
+use barter_integration::model::instrument::Instrument;
+use std::{collections::HashSet, fmt::Debug};
+use tracing::debug;
+
+/// [`Adapter`] that yields the `Ok` value of each `Result` item, discarding every error (eg/ a
+/// [`DataError::Unidentifiable`](crate::error::DataError::Unidentifiable) frame).
+///
+/// Useful for consumers that do not need to act on non-terminal errors. Each discarded error is
+/// logged at `debug` level. See [`AdapterExt::filter_ok`](super::AdapterExt::filter_ok).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct FilterOk;
+
+impl<T, E> Adapter<Result<T, E>> for FilterOk
+where
+    E: Debug,
+{
+    type Output = T;
+
+    fn adapt(&mut self, input: Result<T, E>) -> Option<Self::Output> {
+        input
+            .map_err(|error| debug!(?error, "discarding error yielded by stream"))
+            .ok()
+    }
+}
+
+/// [`Adapter`] that only yields the [`MarketEvent<DataKind>`]s generated by the provided
+/// [`SubKindId`] (see [`DataKind::kind`]), discarding every other event.
+///
+/// See [`AdapterExt::only_kind`](super::AdapterExt::only_kind).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct OnlyKind(pub SubKindId);
+
+impl Adapter<MarketEvent<DataKind>> for OnlyKind {
+    type Output = MarketEvent<DataKind>;
+
+    fn adapt(&mut self, input: MarketEvent<DataKind>) -> Option<Self::Output> {
+        (input.kind.kind() == Some(self.0)).then_some(input)
+    }
+}
+
+/// [`Adapter`] that only yields the [`MarketEvent<T>`]s of the provided [`Instrument`]s,
+/// discarding every other event.
+///
+/// See [`AdapterExt::only_instruments`](super::AdapterExt::only_instruments).
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct OnlyInstruments(pub HashSet<Instrument>);
+
+impl<T> Adapter<MarketEvent<T>> for OnlyInstruments {
+    type Output = MarketEvent<T>;
+
+    fn adapt(&mut self, input: MarketEvent<T>) -> Option<Self::Output> {
+        self.0.contains(&*input.instrument).then_some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adapter::AdapterExt,
+        error::DataError,
+        subscription::{index::IndexPrice, trade::PublicTrade},
+    };
+    use barter_integration::model::{
+        instrument::kind::InstrumentKind, Exchange, Side, SubscriptionId,
+    };
+    use chrono::Utc;
+    use futures::StreamExt;
+
+    fn event(instrument: &Instrument, kind: DataKind) -> MarketEvent<DataKind> {
+        MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from("exchange"),
+            instrument: instrument.clone().into(),
+            kind,
+        }
+    }
+
+    fn trade(price: f64) -> DataKind {
+        DataKind::Trade(PublicTrade {
+            id: price.to_string(),
+            price,
+            amount: 1.0,
+            side: Side::Buy,
+            conditions: vec![],
+        })
+    }
+
+    #[tokio::test]
+    async fn test_only_kind_and_instruments() {
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+        let book = || {
+            DataKind::IndexPrice(IndexPrice {
+                price: 1.0,
+                time: Utc::now(),
+            })
+        };
+
+        let events = vec![
+            event(&btc, trade(1.0)),
+            event(&btc, book()),
+            event(&eth, trade(2.0)),
+            event(&eth, book()),
+            event(&btc, trade(3.0)),
+        ];
+
+        // only_kind drops every non-matching DataKind, preserving the order of the rest
+        let actual = futures::stream::iter(events.clone())
+            .only_kind(SubKindId::PublicTrades)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            actual,
+            vec![events[0].clone(), events[2].clone(), events[4].clone()]
+        );
+
+        // only_instruments drops the events of every other Instrument
+        let actual = futures::stream::iter(events.clone())
+            .only_kind(SubKindId::PublicTrades)
+            .only_instruments(HashSet::from([btc]))
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual, vec![events[0].clone(), events[4].clone()]);
+    }
+
+    #[tokio::test]
+    async fn test_filter_ok() {
+        let items: Vec<Result<u64, DataError>> = vec![
+            Ok(1),
+            Err(DataError::Unidentifiable(SubscriptionId::from("unknown"))),
+            Ok(2),
+            Err(DataError::BookDesync),
+            Ok(3),
+        ];
+
+        let actual = futures::stream::iter(items)
+            .filter_ok()
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(actual, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,257 @@
+use super::Adapter;
+use crate::{event::MarketEvent, subscription::trade::PublicTrade};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+/// One-sided flow threshold that must be crossed within the [`FlowBurstAdapter`] window for a
+/// [`FlowBurst`] to be emitted.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum FlowThreshold {
+    /// Number of consecutive same-side trades.
+    Trades(usize),
+    /// Summed notional (price * amount) of consecutive same-side trades.
+    Notional(f64),
+}
+
+/// Burst of aggressive one-sided flow detected by the [`FlowBurstAdapter`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FlowBurst {
+    pub direction: Side,
+    pub notional: f64,
+    pub trades: usize,
+    pub window: Duration,
+}
+
+/// [`Adapter`] that consumes [`PublicTrade`] [`MarketEvent`]s and emits a
+/// [`MarketEvent<FlowBurst>`] when aggressive buying or selling spikes (eg/ N consecutive buys,
+/// or X notional of one-sided flow) within the configured `window`.
+///
+/// ### Notes
+/// - Flow is tracked independently for every exchange & instrument combination.
+/// - A trade on the opposite [`Side`] ends the current run of one-sided flow. Trades with an
+///   `exchange_time` outside the `window` of the latest trade are evicted from the run.
+/// - The run is reset once a [`FlowBurst`] is emitted, so each trade contributes to at most one
+///   [`FlowBurst`].
+#[derive(Clone, Debug)]
+pub struct FlowBurstAdapter {
+    window: Duration,
+    threshold: FlowThreshold,
+    runs: HashMap<(Exchange, Arc<Instrument>), FlowRun>,
+}
+
+/// Consecutive same-side trades of a single exchange & instrument combination within the window.
+#[derive(Clone, Debug)]
+struct FlowRun {
+    side: Side,
+    trades: VecDeque<(DateTime<Utc>, f64)>,
+    notional: f64,
+}
+
+impl FlowRun {
+    fn new(side: Side) -> Self {
+        Self {
+            side,
+            trades: VecDeque::new(),
+            notional: 0.0,
+        }
+    }
+
+    /// Insert a new trade, and evict trades that have fallen out of the window.
+    fn push(&mut self, time: DateTime<Utc>, notional: f64, window: Duration) {
+        self.trades.push_back((time, notional));
+        self.notional += notional;
+
+        let window = chrono::Duration::from_std(window).unwrap_or(chrono::Duration::MAX);
+        while let Some((oldest, _)) = self.trades.front() {
+            if time.signed_duration_since(*oldest) <= window {
+                break;
+            }
+            if let Some((_, evicted)) = self.trades.pop_front() {
+                self.notional -= evicted;
+            }
+        }
+    }
+
+    fn crosses(&self, threshold: FlowThreshold) -> bool {
+        match threshold {
+            FlowThreshold::Trades(trades) => self.trades.len() >= trades,
+            FlowThreshold::Notional(notional) => self.notional >= notional,
+        }
+    }
+
+    /// Duration between the first and last trade in the run.
+    fn span(&self) -> Duration {
+        match (self.trades.front(), self.trades.back()) {
+            (Some((first, _)), Some((last, _))) => last
+                .signed_duration_since(*first)
+                .to_std()
+                .unwrap_or_default(),
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+impl FlowBurstAdapter {
+    /// Construct a new [`Self`] that emits a [`FlowBurst`] when the provided [`FlowThreshold`]
+    /// is crossed within the provided `window` (eg/ 1s).
+    pub fn new(window: Duration, threshold: FlowThreshold) -> Self {
+        Self {
+            window,
+            threshold,
+            runs: HashMap::new(),
+        }
+    }
+}
+
+impl Adapter<MarketEvent<PublicTrade>> for FlowBurstAdapter {
+    type Output = MarketEvent<FlowBurst>;
+
+    fn adapt(&mut self, input: MarketEvent<PublicTrade>) -> Option<Self::Output> {
+        let key = (input.exchange.clone(), input.instrument.clone());
+        let side = input.kind.side;
+
+        let run = self.runs.entry(key).or_insert_with(|| FlowRun::new(side));
+
+        // Opposite side trade ends the current run of one-sided flow
+        if run.side != side {
+            *run = FlowRun::new(side);
+        }
+
+        run.push(
+            input.exchange_time,
+            input.kind.price * input.kind.amount,
+            self.window,
+        );
+
+        if !run.crosses(self.threshold) {
+            return None;
+        }
+
+        let burst = FlowBurst {
+            direction: side,
+            notional: run.notional,
+            trades: run.trades.len(),
+            window: run.span(),
+        };
+        *run = FlowRun::new(side);
+
+        Some(MarketEvent {
+            exchange_time: input.exchange_time,
+            received_time: input.received_time,
+            exchange: input.exchange,
+            instrument: input.instrument,
+            kind: burst,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn trade(millis: i64, side: Side, price: f64, amount: f64) -> MarketEvent<PublicTrade> {
+        fixtures::trade(
+            fixtures::millis(millis),
+            &millis.to_string(),
+            price,
+            amount,
+            side,
+        )
+    }
+
+    #[test]
+    fn test_flow_burst_adapter_trades_threshold() {
+        struct TestCase {
+            input: MarketEvent<PublicTrade>,
+            expected: Option<FlowBurst>,
+        }
+
+        let mut adapter =
+            FlowBurstAdapter::new(Duration::from_millis(100), FlowThreshold::Trades(3));
+
+        let tests = vec![
+            TestCase {
+                // TC0: first buy is below the threshold
+                input: trade(0, Side::Buy, 100.0, 1.0),
+                expected: None,
+            },
+            TestCase {
+                // TC1: second buy is below the threshold
+                input: trade(10, Side::Buy, 101.0, 1.0),
+                expected: None,
+            },
+            TestCase {
+                // TC2: sell ends the run of buys
+                input: trade(20, Side::Sell, 100.0, 1.0),
+                expected: None,
+            },
+            TestCase {
+                // TC3: buy starts a new run
+                input: trade(30, Side::Buy, 100.0, 1.0),
+                expected: None,
+            },
+            TestCase {
+                // TC4: buy outside the window of the previous buy evicts it
+                input: trade(200, Side::Buy, 100.0, 2.0),
+                expected: None,
+            },
+            TestCase {
+                // TC5: buy is below the threshold
+                input: trade(250, Side::Buy, 100.0, 1.0),
+                expected: None,
+            },
+            TestCase {
+                // TC6: third consecutive buy within the window emits a buy burst
+                input: trade(260, Side::Buy, 102.0, 1.0),
+                expected: Some(FlowBurst {
+                    direction: Side::Buy,
+                    notional: 200.0 + 100.0 + 102.0,
+                    trades: 3,
+                    window: Duration::from_millis(60),
+                }),
+            },
+            TestCase {
+                // TC7: run is reset after a burst is emitted
+                input: trade(270, Side::Buy, 100.0, 1.0),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = adapter.adapt(test.input).map(|event| event.kind);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_flow_burst_adapter_notional_threshold() {
+        let mut adapter =
+            FlowBurstAdapter::new(Duration::from_secs(1), FlowThreshold::Notional(1000.0));
+
+        // Sell flow below the notional threshold does not emit
+        assert_eq!(adapter.adapt(trade(0, Side::Sell, 100.0, 4.0)), None);
+        assert_eq!(adapter.adapt(trade(100, Side::Sell, 100.0, 5.0)), None);
+
+        // Sell flow crossing the notional threshold emits a sell burst
+        let actual = adapter
+            .adapt(trade(200, Side::Sell, 100.0, 1.0))
+            .unwrap()
+            .kind;
+        assert_eq!(
+            actual,
+            FlowBurst {
+                direction: Side::Sell,
+                notional: 1000.0,
+                trades: 3,
+                window: Duration::from_millis(200),
+            }
+        );
+    }
+}
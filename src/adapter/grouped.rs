@@ -0,0 +1,149 @@
+use super::Adapter;
+use crate::{
+    event::MarketEvent,
+    subscription::book::{Level, OrderBook, OrderBookSide},
+};
+use barter_integration::model::Side;
+use std::collections::BTreeMap;
+
+/// Tolerance applied when bucketing prices, so that a price lying exactly on a bucket boundary
+/// is not pushed into a neighbouring bucket by floating point error.
+const BUCKET_EPSILON: f64 = 1e-9;
+
+/// [`Adapter`] that groups the [`Level`]s of each [`OrderBook`] into price buckets of a
+/// configurable `increment`, summing the amount of every [`Level`] within a bucket.
+///
+/// Useful for analysing a price-grouped view of exchanges that do not offer native grouped
+/// books.
+///
+/// ### Notes
+/// - Bids are grouped down & asks are grouped up to the nearest `increment`, so a grouped book
+///   never appears tighter than the raw book.
+/// - Grouped [`OrderBookSide`]s are sorted (bids descending, asks ascending).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GroupedBookAdapter {
+    increment: f64,
+}
+
+impl GroupedBookAdapter {
+    /// Construct a new [`Self`] that groups [`Level`]s into buckets of the provided price
+    /// `increment`.
+    ///
+    /// ### Panics
+    /// Panics if the `increment` is not a positive finite number.
+    pub fn new(increment: f64) -> Self {
+        assert!(
+            increment.is_finite() && increment > 0.0,
+            "GroupedBookAdapter increment must be positive & finite, got: {increment}"
+        );
+        Self { increment }
+    }
+
+    /// Group the [`Level`]s of one [`Side`] of an [`OrderBook`] into sorted price buckets.
+    pub fn group(&self, side: Side, levels: &[Level]) -> OrderBookSide {
+        let mut buckets = BTreeMap::<i64, f64>::new();
+
+        for level in levels {
+            let bucket = level.price / self.increment;
+            let bucket = match side {
+                Side::Buy => (bucket + BUCKET_EPSILON).floor(),
+                Side::Sell => (bucket - BUCKET_EPSILON).ceil(),
+            };
+            *buckets.entry(bucket as i64).or_default() += level.amount;
+        }
+
+        let levels = buckets
+            .into_iter()
+            .map(|(bucket, amount)| Level::new(bucket as f64 * self.increment, amount));
+
+        let mut grouped = OrderBookSide::new(side, levels);
+        grouped.sort();
+        grouped
+    }
+}
+
+impl Adapter<MarketEvent<OrderBook>> for GroupedBookAdapter {
+    type Output = MarketEvent<OrderBook>;
+
+    fn adapt(&mut self, mut input: MarketEvent<OrderBook>) -> Option<Self::Output> {
+        input.kind = OrderBook {
+            last_update_time: input.kind.last_update_time,
+            bids: self.group(Side::Buy, &input.kind.bids.levels),
+            asks: self.group(Side::Sell, &input.kind.asks.levels),
+        };
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        approx::{ApproxEq, DEFAULT_EPSILON},
+        fixtures::{book_event, secs},
+    };
+
+    #[test]
+    fn test_grouped_book_adapter() {
+        struct TestCase {
+            increment: f64,
+            input: MarketEvent<OrderBook>,
+            expected_bids: Vec<Level>,
+            expected_asks: Vec<Level>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: levels within a bucket are combined, and the grouped book is sorted
+                increment: 1.0,
+                input: book_event(
+                    secs(0),
+                    vec![(98.2, 1.0), (99.5, 2.0), (99.1, 3.0), (97.9, 4.0)],
+                    vec![(101.5, 1.0), (100.2, 2.0), (100.9, 3.0), (102.0, 4.0)],
+                ),
+                expected_bids: vec![
+                    Level::new(99.0, 5.0),
+                    Level::new(98.0, 1.0),
+                    Level::new(97.0, 4.0),
+                ],
+                expected_asks: vec![Level::new(101.0, 5.0), Level::new(102.0, 5.0)],
+            },
+            TestCase {
+                // TC1: prices on a bucket boundary stay in that bucket
+                increment: 0.1,
+                input: book_event(
+                    secs(0),
+                    vec![(0.3, 1.0), (0.35, 1.0)],
+                    vec![(0.7, 1.0), (0.65, 1.0)],
+                ),
+                expected_bids: vec![Level::new(0.3, 2.0)],
+                expected_asks: vec![Level::new(0.7, 2.0)],
+            },
+            TestCase {
+                // TC2: empty book stays empty
+                increment: 10.0,
+                input: book_event(secs(0), vec![], vec![]),
+                expected_bids: vec![],
+                expected_asks: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut adapter = GroupedBookAdapter::new(test.increment);
+            let actual = adapter.adapt(test.input).unwrap().kind;
+
+            for (actual, expected) in [
+                (actual.bids.levels, test.expected_bids),
+                (actual.asks.levels, test.expected_asks),
+            ] {
+                assert_eq!(actual.len(), expected.len(), "TC{index} failed: {actual:?}");
+                for (actual, expected) in actual.iter().zip(expected.iter()) {
+                    assert!(
+                        actual.approx_eq(expected, DEFAULT_EPSILON),
+                        "TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{
+    event::{DataKind, MarketEvent},
+    subscription::SubKindId,
+};
+use barter_integration::model::instrument::Instrument;
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use std::{
+    collections::{HashSet, VecDeque},
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+/// [`Adapter`] that merges the [`OrderBook`](crate::subscription::book::OrderBook)s of several
+/// exchanges into a synthetic aggregated book.
+pub mod aggregated;
+
+/// [`Adapter`]s that aggregate [`PublicTrade`](crate::subscription::trade::PublicTrade)s into
+/// volume or dollar bars with a volume weighted average price.
+pub mod bar;
+
+/// [`Adapter`] that only yields closed [`Candle`](crate::subscription::candle::Candle)s,
+/// suppressing intra-candle updates.
+pub mod closed;
+
+/// [`ConsolidatedBook`](consolidated::ConsolidatedBook) that merges the
+/// [`OrderBook`](crate::subscription::book::OrderBook)s of several exchanges, tagging each level
+/// with its source exchange.
+pub mod consolidated;
+
+/// [`Adapter`] that drops duplicate [`PublicTrade`](crate::subscription::trade::PublicTrade)s
+/// seen within a sliding window.
+pub mod dedup;
+
+/// [`Adapter`] that periodically samples the depth within a band of basis points around the mid
+/// price of an [`OrderBook`](crate::subscription::book::OrderBook).
+pub mod depth;
+
+/// [`Adapter`] that detects exchanges whose apparent clock drifts relative to the other
+/// consumed exchanges.
+pub mod drift;
+
+/// [`Adapter`]s that discard items of a stream, such as every error, or the
+/// [`MarketEvent`](crate::event::MarketEvent)s of other kinds or instruments.
+pub mod filter;
+
+/// [`Adapter`] that detects bursts of aggressive one-sided
+/// [`PublicTrade`](crate::subscription::trade::PublicTrade) flow.
+pub mod flow;
+
+/// [`Adapter`] that groups the [`Level`](crate::subscription::book::Level)s of an
+/// [`OrderBook`](crate::subscription::book::OrderBook) into configurable price buckets.
+pub mod grouped;
+
+/// [`Adapter`] that enforces non-decreasing
+/// [`MarketEvent::exchange_time`](crate::event::MarketEvent)s per exchange & instrument.
+pub mod monotonic;
+
+/// [`Adapter`] that tags each event with the exchange-native symbol of its market (eg/
+/// `BTCUSDT`), alongside the normalised instrument.
+pub mod native;
+
+/// [`Adapter`] that aggregates [`PublicTrade`](crate::subscription::trade::PublicTrade)s into
+/// OHLCV [`Candle`](crate::subscription::candle::Candle)s of a fixed interval, with a buy & sell
+/// volume split.
+pub mod ohlcv;
+
+/// [`Adapter`] that applies per-instrument normalisation overrides (eg/ quantity scaling) on top
+/// of the default exchange transform.
+pub mod overrides;
+
+/// [`MapPrice`](price::MapPrice) [`Adapter`] that maps every price of a
+/// [`MarketEvent`](crate::event::MarketEvent) (eg/ into a different quote currency).
+pub mod price;
+
+/// [`Adapter`] that estimates rolling quantiles of
+/// [`PublicTrade`](crate::subscription::trade::PublicTrade) prices with bounded memory.
+pub mod quantile;
+
+/// [`Adapter`] that normalises [`PublicTrade`](crate::subscription::trade::PublicTrade) amounts to
+/// the base asset.
+pub mod quantity;
+
+/// [`Adapter`] that resamples [`Candle`](crate::subscription::candle::Candle)s of a fine
+/// interval into candles of a coarser interval.
+pub mod resample;
+
+/// [`Adapter`]s that tag each event with a process-global local receive sequence, or a
+/// per-stream sequence.
+pub mod sequence;
+
+/// [`Adapter`] that computes the rolling spread (in basis points) of an
+/// [`OrderBook`](crate::subscription::book::OrderBook).
+pub mod spread;
+
+/// [`Adapter`] that collapses consecutive [`PublicTrade`](crate::subscription::trade::PublicTrade)
+/// fills from the same aggressor into a single taker order.
+pub mod taker;
+
+/// [`Adapter`] that computes rolling time-and-sales tape statistics (trade rate & average trade
+/// size) from [`PublicTrade`](crate::subscription::trade::PublicTrade)s.
+pub mod tape;
+
+/// [`Adapter`] that periodically emits the time-weighted average mid price of an
+/// [`OrderBook`](crate::subscription::book::OrderBook) over a rolling window.
+pub mod twa;
+
+/// Defines how to derive an `Output` from each `Input` event consumed from a [`Stream`].
+///
+/// Returns `None` if the `Input` does not yield an `Output` (eg/ an aggregation window is
+/// still open, or the `Input` did not change the derived value).
+///
+/// ### Notes
+/// Implementations should derive their `Output` from event data (eg/
+/// [`MarketEvent::exchange_time`](crate::event::MarketEvent)) rather than the wall-clock, so
+/// that feeding the same sequence of `Input` events always yields the same `Output` events.
+/// Time only enters via the `now` provided to [`Adapter::tick`].
+pub trait Adapter<Input> {
+    type Output;
+
+    fn adapt(&mut self, input: Input) -> Option<Self::Output>;
+
+    /// Emit every `Output` of the intervals that have elapsed by `now` without a further `Input`
+    /// (eg/ the zero trade rate of a quiet market).
+    ///
+    /// Called on every tick of the timer of an [`AdaptedStream`] constructed via
+    /// [`AdapterExt::adapt_with_ticks`], with the current time.
+    ///
+    /// Defaults to no `Output`.
+    fn tick(&mut self, _now: DateTime<Utc>) -> Vec<Self::Output> {
+        Vec::new()
+    }
+
+    /// Drain every `Output` still pending within [`Self`] (eg/ an open aggregation window) once
+    /// the input [`Stream`] has ended.
+    ///
+    /// Defaults to no pending `Output`.
+    fn finish(&mut self) -> Vec<Self::Output> {
+        Vec::new()
+    }
+}
+
+/// [`Stream`] that applies an [`Adapter`] to each item yielded by the inner [`Stream`], yielding
+/// the resulting `Adapter::Output` items.
+///
+/// Once the inner [`Stream`] ends, every `Output` still pending within the [`Adapter`] is
+/// yielded (see [`Adapter::finish`]) before [`Self`] ends.
+///
+/// If constructed with a tick period (see [`AdaptedStream::with_ticks`]), the `Output`s of
+/// [`Adapter::tick`] are also yielded whenever the timer ticks while the inner [`Stream`] is idle.
+#[derive(Debug)]
+pub struct AdaptedStream<St, A>
+where
+    St: Stream,
+    A: Adapter<St::Item>,
+{
+    pub stream: St,
+    pub adapter: A,
+    ticks: Option<tokio::time::Interval>,
+    ticked: VecDeque<A::Output>,
+    finished: Option<VecDeque<A::Output>>,
+}
+
+impl<St, A> AdaptedStream<St, A>
+where
+    St: Stream,
+    A: Adapter<St::Item>,
+{
+    /// Construct a new [`Self`] using the provided inner [`Stream`] and [`Adapter`].
+    pub fn new(stream: St, adapter: A) -> Self {
+        Self {
+            stream,
+            adapter,
+            ticks: None,
+            ticked: VecDeque::new(),
+            finished: None,
+        }
+    }
+
+    /// Construct a new [`Self`] using the provided inner [`Stream`] and [`Adapter`], calling
+    /// [`Adapter::tick`] every `period` (eg/ the `Adapter` interval).
+    ///
+    /// ### Panics
+    /// Panics if the `period` is zero, or if called outside of a Tokio runtime.
+    pub fn with_ticks(stream: St, adapter: A, period: Duration) -> Self {
+        let mut ticks = tokio::time::interval(period);
+        ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        Self {
+            ticks: Some(ticks),
+            ..Self::new(stream, adapter)
+        }
+    }
+}
+
+impl<St, A> Stream for AdaptedStream<St, A>
+where
+    St: Stream + Unpin,
+    A: Adapter<St::Item> + Unpin,
+    A::Output: Unpin,
+{
+    type Item = A::Output;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            // Inner Stream has ended, so yield any remaining pending Outputs
+            if let Some(pending) = &mut this.finished {
+                break Poll::Ready(pending.pop_front());
+            }
+
+            // Yield any Outputs of the previous timer tick
+            if let Some(output) = this.ticked.pop_front() {
+                break Poll::Ready(Some(output));
+            }
+
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(input)) => {
+                    if let Some(output) = this.adapter.adapt(input) {
+                        break Poll::Ready(Some(output));
+                    }
+                }
+                Poll::Ready(None) => this.finished = Some(this.adapter.finish().into()),
+                Poll::Pending => {
+                    // Inner Stream is idle, so emit the Outputs of any elapsed intervals
+                    match this.ticks.as_mut().map(|ticks| ticks.poll_tick(cx)) {
+                        Some(Poll::Ready(_)) => this.ticked.extend(this.adapter.tick(Utc::now())),
+                        _ => break Poll::Pending,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Extension trait providing the [`AdapterExt::adapt`] combinator for every [`Stream`].
+pub trait AdapterExt: Stream + Sized {
+    /// Wrap [`Self`] in an [`AdaptedStream`] that yields the `Output` of the provided [`Adapter`].
+    fn adapt<A>(self, adapter: A) -> AdaptedStream<Self, A>
+    where
+        A: Adapter<Self::Item>,
+    {
+        AdaptedStream::new(self, adapter)
+    }
+
+    /// Wrap [`Self`] in an [`AdaptedStream`] that yields the `Output` of the provided [`Adapter`],
+    /// alongside the `Output` of [`Adapter::tick`] every `period`. See
+    /// [`AdaptedStream::with_ticks`].
+    fn adapt_with_ticks<A>(self, adapter: A, period: Duration) -> AdaptedStream<Self, A>
+    where
+        A: Adapter<Self::Item>,
+    {
+        AdaptedStream::with_ticks(self, adapter, period)
+    }
+
+    /// Wrap [`Self`] in an [`AdaptedStream`] that yields the `Ok` value of each `Result` item,
+    /// discarding every error. See [`FilterOk`](filter::FilterOk).
+    fn filter_ok<T, E>(self) -> AdaptedStream<Self, filter::FilterOk>
+    where
+        Self: Stream<Item = Result<T, E>>,
+        E: std::fmt::Debug,
+    {
+        AdaptedStream::new(self, filter::FilterOk)
+    }
+
+    /// Wrap [`Self`] in an [`AdaptedStream`] that only yields the [`MarketEvent<DataKind>`]s of
+    /// the provided [`SubKindId`]. See [`OnlyKind`](filter::OnlyKind).
+    fn only_kind(self, kind: SubKindId) -> AdaptedStream<Self, filter::OnlyKind>
+    where
+        Self: Stream<Item = MarketEvent<DataKind>>,
+    {
+        AdaptedStream::new(self, filter::OnlyKind(kind))
+    }
+
+    /// Wrap [`Self`] in an [`AdaptedStream`] that only yields the [`MarketEvent<T>`]s of the
+    /// provided [`Instrument`]s. See [`OnlyInstruments`](filter::OnlyInstruments).
+    fn only_instruments<T>(
+        self,
+        instruments: HashSet<Instrument>,
+    ) -> AdaptedStream<Self, filter::OnlyInstruments>
+    where
+        Self: Stream<Item = MarketEvent<T>>,
+    {
+        AdaptedStream::new(self, filter::OnlyInstruments(instruments))
+    }
+
+    /// Wrap [`Self`] in an [`AdaptedStream`] that maps every price of each [`MarketEvent<T>`]
+    /// with the provided closure. See [`MapPrice`](price::MapPrice).
+    fn map_price<T, F>(self, f: F) -> AdaptedStream<Self, price::MapPrice<F>>
+    where
+        Self: Stream<Item = MarketEvent<T>>,
+        T: price::MapPrices,
+        F: Fn(f64) -> f64,
+    {
+        AdaptedStream::new(self, price::MapPrice(f))
+    }
+}
+
+impl<St> AdapterExt for St where St: Stream {}
@@ -0,0 +1,151 @@
+use super::Adapter;
+use crate::{error::DataError, event::MarketEvent};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc};
+use tracing::warn;
+
+/// Action taken by the [`MonotonicTimeAdapter`] when a [`MarketEvent`] has an `exchange_time`
+/// earlier than the previous [`MarketEvent`] of the same exchange & instrument.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum MonotonicPolicy {
+    /// Clamp the `exchange_time` to the previous `exchange_time` and log a warning.
+    #[default]
+    Clamp,
+    /// Yield a [`DataError::NonMonotonicTime`] instead of the [`MarketEvent`].
+    Flag,
+}
+
+/// [`Adapter`] that guarantees that the `exchange_time` of each exchange & instrument stream of
+/// [`MarketEvent`]s is non-decreasing, guarding time-ordered consumers against exchange clock
+/// glitches.
+///
+/// ### Notes
+/// - Out-of-order events are handled according to the configured [`MonotonicPolicy`].
+/// - A flagged event does not update the previous `exchange_time`.
+#[derive(Clone, Debug, Default)]
+pub struct MonotonicTimeAdapter {
+    policy: MonotonicPolicy,
+    last_times: HashMap<(Exchange, Arc<Instrument>), DateTime<Utc>>,
+}
+
+impl MonotonicTimeAdapter {
+    /// Construct a new [`Self`] that handles out-of-order events with the provided
+    /// [`MonotonicPolicy`].
+    pub fn new(policy: MonotonicPolicy) -> Self {
+        Self {
+            policy,
+            last_times: HashMap::new(),
+        }
+    }
+}
+
+impl<T> Adapter<MarketEvent<T>> for MonotonicTimeAdapter {
+    type Output = Result<MarketEvent<T>, DataError>;
+
+    fn adapt(&mut self, mut input: MarketEvent<T>) -> Option<Self::Output> {
+        let key = (input.exchange.clone(), input.instrument.clone());
+
+        let previous = match self.last_times.get(&key) {
+            Some(previous) if input.exchange_time < *previous => *previous,
+            _ => {
+                self.last_times.insert(key, input.exchange_time);
+                return Some(Ok(input));
+            }
+        };
+
+        match self.policy {
+            MonotonicPolicy::Clamp => {
+                warn!(
+                    exchange = %input.exchange,
+                    instrument = %input.instrument,
+                    %previous,
+                    exchange_time = %input.exchange_time,
+                    action = "clamping to previous exchange_time",
+                    "consumed MarketEvent with non-monotonic exchange_time"
+                );
+                input.exchange_time = previous;
+                Some(Ok(input))
+            }
+            MonotonicPolicy::Flag => Some(Err(DataError::NonMonotonicTime {
+                exchange: input.exchange,
+                instrument: input.instrument,
+                previous,
+                exchange_time: input.exchange_time,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn event(secs: i64) -> MarketEvent<()> {
+        let time = DateTime::<Utc>::from_timestamp(secs, 0).unwrap();
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from("exchange"),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)).into(),
+            kind: (),
+        }
+    }
+
+    #[test]
+    fn test_monotonic_time_adapter() {
+        struct TestCase {
+            policy: MonotonicPolicy,
+            expected: Result<i64, ()>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: out-of-order exchange_time is clamped to the previous exchange_time
+                policy: MonotonicPolicy::Clamp,
+                expected: Ok(10),
+            },
+            TestCase {
+                // TC1: out-of-order exchange_time is flagged
+                policy: MonotonicPolicy::Flag,
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut adapter = MonotonicTimeAdapter::new(test.policy);
+
+            // In-order events pass through untouched
+            for secs in [5, 10, 10] {
+                let actual = adapter.adapt(event(secs)).unwrap().unwrap();
+                assert_eq!(actual.exchange_time.timestamp(), secs, "TC{index} failed");
+            }
+
+            let actual = adapter
+                .adapt(event(7))
+                .unwrap()
+                .map(|event| event.exchange_time.timestamp());
+
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(DataError::NonMonotonicTime { .. }), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+
+            // Subsequent in-order event is unaffected
+            let actual = adapter.adapt(event(11)).unwrap().unwrap();
+            assert_eq!(actual.exchange_time.timestamp(), 11, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use super::Adapter;
+use crate::{
+    event::MarketEvent,
+    exchange::Connector,
+    subscription::{SubKind, Subscription},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc};
+
+/// Normalised data tagged with the exchange-native symbol (eg/ `BTCUSDT`, `BTC-USDT`) of the
+/// market that produced it, assigned by a [`NativeSymbolAdapter`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct NativeSymbol<T> {
+    pub symbol: String,
+    pub kind: T,
+}
+
+/// [`Adapter`] that tags each [`MarketEvent`] with the exchange-native symbol of its market, so
+/// downstream systems can route on either the normalised [`Instrument`] or the exchange ticker.
+///
+/// The native symbol of each exchange & instrument is the [`Connector::Market`] its
+/// [`Subscription`] was actioned with, which is the market identifier the exchange sends.
+///
+/// ### Notes
+/// - Opt-in, since every tagged [`MarketEvent`] carries an extra `String`.
+/// - [`MarketEvent`]s of exchange & instrument combinations without a registered
+///   [`Subscription`] are discarded.
+#[derive(Clone, Debug, Default)]
+pub struct NativeSymbolAdapter {
+    symbols: HashMap<(Exchange, Arc<Instrument>), String>,
+}
+
+impl NativeSymbolAdapter {
+    /// Construct a new [`Self`] with no registered native symbols.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the native symbol of every provided [`Subscription`], replacing any previously
+    /// registered symbol of the same exchange & instrument.
+    pub fn with_subscriptions<Exchange, Kind>(
+        mut self,
+        subscriptions: &[Subscription<Exchange, Kind>],
+    ) -> Self
+    where
+        Exchange: Connector,
+        Kind: SubKind,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Market>,
+    {
+        self.symbols
+            .extend(subscriptions.iter().map(|subscription| {
+                let market: Exchange::Market = subscription.id();
+                (
+                    (
+                        barter_integration::model::Exchange::from(Exchange::ID),
+                        Arc::new(subscription.instrument.clone()),
+                    ),
+                    market.as_ref().to_owned(),
+                )
+            }));
+        self
+    }
+
+    /// Native symbol registered for the provided exchange & instrument.
+    pub fn symbol(&self, exchange: &Exchange, instrument: &Arc<Instrument>) -> Option<&str> {
+        self.symbols
+            .get(&(exchange.clone(), instrument.clone()))
+            .map(String::as_str)
+    }
+}
+
+impl<T> Adapter<MarketEvent<T>> for NativeSymbolAdapter {
+    type Output = MarketEvent<NativeSymbol<T>>;
+
+    fn adapt(&mut self, event: MarketEvent<T>) -> Option<Self::Output> {
+        let symbol = self.symbol(&event.exchange, &event.instrument)?.to_owned();
+
+        Some(MarketEvent {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: NativeSymbol {
+                symbol,
+                kind: event.kind,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{binance::spot::BinanceSpot, okx::Okx, ExchangeId},
+        fixtures,
+        subscription::trade::{PublicTrade, PublicTrades},
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use chrono::Utc;
+
+    fn trade(exchange: ExchangeId, instrument: &Instrument) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange: Exchange::from(exchange),
+            instrument: Arc::new(instrument.clone()),
+            ..fixtures::trade(Utc::now(), "id", 100.0, 1.0, Side::Buy)
+        }
+    }
+
+    #[test]
+    fn test_native_symbol_adapter() {
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        let mut adapter = NativeSymbolAdapter::new()
+            .with_subscriptions(&[Subscription::from((
+                BinanceSpot::default(),
+                btc.clone(),
+                PublicTrades,
+            ))])
+            .with_subscriptions(&[Subscription::from((Okx, btc.clone(), PublicTrades))]);
+
+        struct TestCase {
+            input: MarketEvent<PublicTrade>,
+            frame: &'static str,
+            pointer: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BinanceSpot trade tagged with the symbol of the Binance trade frame
+                input: trade(ExchangeId::BinanceSpot, &btc),
+                frame: r#"{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1000000000,"p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,"T":1749354825200,"m":false,"M":true}"#,
+                pointer: "/s",
+            },
+            TestCase {
+                // TC1: Okx trade tagged with the symbol of the Okx trade frame
+                input: trade(ExchangeId::Okx, &btc),
+                frame: r#"{"arg":{"channel":"trades","instId":"BTC-USDT"},"data":[{"instId":"BTC-USDT","tradeId":"130639474","px":"42219.9","sz":"0.12060306","side":"buy","ts":"1630048897897"}]}"#,
+                pointer: "/arg/instId",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let frame = serde_json::from_str::<serde_json::Value>(test.frame).unwrap();
+            let expected = frame.pointer(test.pointer).unwrap().as_str().unwrap();
+
+            let actual = adapter
+                .adapt(test.input)
+                .unwrap_or_else(|| panic!("TC{index} failed"));
+            assert_eq!(actual.kind.symbol, expected, "TC{index} failed");
+        }
+
+        // Events of an unregistered instrument are discarded
+        assert!(adapter.adapt(trade(ExchangeId::Okx, &eth)).is_none());
+    }
+}
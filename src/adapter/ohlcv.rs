@@ -0,0 +1,292 @@
+use super::Adapter;
+use crate::{
+    event::MarketEvent,
+    subscription::{
+        candle::{Candle, Interval},
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    time::Duration,
+};
+use tracing::debug;
+
+/// OHLCV [`Candle`] built locally from [`PublicTrade`]s by a [`CandleAggregator`], alongside the
+/// split of its volume by aggressor [`Side`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct TradeCandle {
+    pub candle: Candle,
+    pub buy_volume: f64,
+    pub sell_volume: f64,
+}
+
+/// [`Adapter`] that aggregates [`PublicTrade`] [`MarketEvent`]s into [`TradeCandle`]s aligned to
+/// the boundaries of a fixed [`Interval`], for exchanges that stream trades but not candles.
+///
+/// ### Notes
+/// - Candles are tracked independently for every exchange & instrument combination, starting
+///   from the [`Interval`] containing the first trade of each.
+/// - Boundaries are driven by trade `exchange_time`s, so a [`TradeCandle`] is emitted once a
+///   trade at least the allowed `lateness` after its close arrives. Trades up to the `lateness`
+///   out-of-order are aggregated into their own [`Interval`], while later trades of an already
+///   emitted [`TradeCandle`] are dropped.
+/// - A [`TradeCandle`] is emitted for every [`Interval`], including those without any trades,
+///   which carry forward the previous close with zero volume.
+/// - Each [`Candle`] has `is_final` set, a `close_time` 1ms before the next [`Interval`] starts,
+///   and a `quote_volume` summing the [`PublicTrade::quote_volume`] of its trades.
+#[derive(Clone, Debug)]
+pub struct CandleAggregator {
+    interval: Interval,
+    lateness: chrono::Duration,
+    series: HashMap<(Exchange, Arc<Instrument>), Series>,
+}
+
+/// Open [`Interval`] buckets of a single exchange & instrument combination.
+#[derive(Clone, Debug)]
+struct Series {
+    next: DateTime<Utc>,
+    latest: DateTime<Utc>,
+    last_close: Option<f64>,
+    buckets: BTreeMap<DateTime<Utc>, Bucket>,
+}
+
+/// Trades aggregated within a single [`Interval`].
+#[derive(Copy, Clone, Debug)]
+struct Bucket {
+    open: (DateTime<Utc>, f64),
+    close: (DateTime<Utc>, f64),
+    high: f64,
+    low: f64,
+    buy_volume: f64,
+    sell_volume: f64,
+    quote_volume: f64,
+    trade_count: u64,
+}
+
+impl Bucket {
+    fn new(time: DateTime<Utc>, price: f64) -> Self {
+        Self {
+            open: (time, price),
+            close: (time, price),
+            high: price,
+            low: price,
+            buy_volume: 0.0,
+            sell_volume: 0.0,
+            quote_volume: 0.0,
+            trade_count: 0,
+        }
+    }
+
+    fn push(&mut self, time: DateTime<Utc>, trade: &PublicTrade) {
+        if time < self.open.0 {
+            self.open = (time, trade.price);
+        }
+        if time >= self.close.0 {
+            self.close = (time, trade.price);
+        }
+        self.high = self.high.max(trade.price);
+        self.low = self.low.min(trade.price);
+        match trade.side {
+            Side::Buy => self.buy_volume += trade.amount,
+            Side::Sell => self.sell_volume += trade.amount,
+        }
+        self.quote_volume += trade.quote_volume();
+        self.trade_count += 1;
+    }
+
+    fn candle(&self, close_time: DateTime<Utc>) -> TradeCandle {
+        TradeCandle {
+            candle: Candle {
+                close_time,
+                open: self.open.1,
+                high: self.high,
+                low: self.low,
+                close: self.close.1,
+                volume: self.buy_volume + self.sell_volume,
+                quote_volume: Some(self.quote_volume),
+                trade_count: self.trade_count,
+                is_final: true,
+            },
+            buy_volume: self.buy_volume,
+            sell_volume: self.sell_volume,
+        }
+    }
+}
+
+impl CandleAggregator {
+    /// Construct a new [`Self`] that aggregates trades into [`TradeCandle`]s of the provided
+    /// [`Interval`], accepting trades up to `lateness` out-of-order (eg/ 2s).
+    ///
+    /// ### Panics
+    /// Panics if the [`Interval`] is an exchange specific [`Interval::Custom`], since its
+    /// boundaries are unknown.
+    pub fn new(interval: Interval, lateness: Duration) -> Self {
+        assert!(
+            !matches!(interval, Interval::Custom(_)),
+            "CandleAggregator interval must be a standard Interval"
+        );
+        Self {
+            interval,
+            lateness: chrono::Duration::from_std(lateness).unwrap_or(chrono::Duration::MAX),
+            series: HashMap::new(),
+        }
+    }
+}
+
+impl Adapter<MarketEvent<PublicTrade>> for CandleAggregator {
+    type Output = Vec<MarketEvent<TradeCandle>>;
+
+    fn adapt(&mut self, input: MarketEvent<PublicTrade>) -> Option<Self::Output> {
+        let time = input.exchange_time;
+        let start = self.interval.floor(time);
+
+        let series = self
+            .series
+            .entry((input.exchange.clone(), input.instrument.clone()))
+            .or_insert_with(|| Series {
+                next: start,
+                latest: time,
+                last_close: None,
+                buckets: BTreeMap::new(),
+            });
+
+        // Trades of an already emitted TradeCandle are too late to be aggregated
+        if start < series.next {
+            debug!(
+                exchange = %input.exchange,
+                instrument = %input.instrument,
+                %time,
+                next = %series.next,
+                "dropping trade received after its TradeCandle was emitted"
+            );
+            return None;
+        }
+
+        series
+            .buckets
+            .entry(start)
+            .or_insert_with(|| Bucket::new(time, input.kind.price))
+            .push(time, &input.kind);
+        series.latest = series.latest.max(time);
+
+        // Emit every TradeCandle whose Interval closed before the out-of-order window
+        let watermark = series
+            .latest
+            .checked_sub_signed(self.lateness)
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        let mut output = vec![];
+        loop {
+            let end = self.interval.ceil(series.next);
+            if end > watermark {
+                break;
+            }
+
+            let bucket = match (series.buckets.remove(&series.next), series.last_close) {
+                (Some(bucket), _) => bucket,
+                // Interval without any trades carries forward the previous close
+                (None, Some(last_close)) => Bucket::new(series.next, last_close),
+                (None, None) => unreachable!("first Interval of a Series always contains a trade"),
+            };
+            series.last_close = Some(bucket.close.1);
+            series.next = end;
+
+            let close_time = end - chrono::Duration::milliseconds(1);
+            output.push(MarketEvent {
+                exchange_time: close_time,
+                received_time: input.received_time,
+                exchange: input.exchange.clone(),
+                instrument: input.instrument.clone(),
+                kind: bucket.candle(close_time),
+            });
+        }
+
+        (!output.is_empty()).then_some(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use chrono::TimeZone;
+
+    fn time(minute: u32, second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2023, 1, 1, 0, minute, second).unwrap()
+    }
+
+    fn trade(time: DateTime<Utc>, price: f64, amount: f64, side: Side) -> MarketEvent<PublicTrade> {
+        fixtures::trade(
+            time,
+            &time.timestamp_millis().to_string(),
+            price,
+            amount,
+            side,
+        )
+    }
+
+    #[test]
+    fn test_candle_aggregator() {
+        let mut aggregator = CandleAggregator::new(Interval::Minute1, Duration::from_secs(5));
+
+        let trades = vec![
+            trade(time(0, 10), 100.0, 1.0, Side::Buy),
+            trade(time(0, 30), 105.0, 2.0, Side::Sell),
+            trade(time(0, 50), 95.0, 1.0, Side::Buy),
+            // Next Interval starts, but the previous Interval is within the out-of-order window
+            trade(time(1, 2), 102.0, 1.0, Side::Buy),
+            // Out-of-order trade is still aggregated into the previous Interval as the close
+            trade(time(0, 58), 99.0, 1.0, Side::Sell),
+            // Out-of-order window passes, so the first TradeCandle is emitted
+            trade(time(1, 20), 110.0, 1.0, Side::Buy),
+            // Second TradeCandle & the empty Interval without trades are emitted
+            trade(time(3, 30), 120.0, 1.0, Side::Sell),
+            // Trade of an already emitted TradeCandle is dropped
+            trade(time(0, 40), 1.0, 100.0, Side::Buy),
+        ];
+
+        let actual = trades
+            .into_iter()
+            .flat_map(|trade| aggregator.adapt(trade).unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        let candle =
+            |minute: u32, (open, high, low, close), (buy_volume, sell_volume), quote, count| {
+                let close_time = time(minute + 1, 0) - chrono::Duration::milliseconds(1);
+                TradeCandle {
+                    candle: Candle {
+                        close_time,
+                        open,
+                        high,
+                        low,
+                        close,
+                        volume: buy_volume + sell_volume,
+                        quote_volume: Some(quote),
+                        trade_count: count,
+                        is_final: true,
+                    },
+                    buy_volume,
+                    sell_volume,
+                }
+            };
+
+        let expected = vec![
+            candle(0, (100.0, 105.0, 95.0, 99.0), (2.0, 3.0), 504.0, 4),
+            candle(1, (102.0, 110.0, 102.0, 110.0), (2.0, 0.0), 212.0, 2),
+            candle(2, (110.0, 110.0, 110.0, 110.0), (0.0, 0.0), 0.0, 0),
+        ];
+
+        assert_eq!(actual.len(), expected.len());
+        for (index, (actual, expected)) in actual.into_iter().zip(expected).enumerate() {
+            assert_eq!(
+                actual.exchange_time, expected.candle.close_time,
+                "TC{index} failed"
+            );
+            assert_eq!(actual.kind, expected, "TC{index} failed");
+        }
+    }
+}
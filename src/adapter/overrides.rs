@@ -0,0 +1,251 @@
+use super::Adapter;
+use crate::{
+    event::MarketEvent,
+    subscription::{
+        book::{Level, OrderBook, OrderBookL1},
+        candle::Candle,
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc};
+
+/// Per-instrument adjustment applied on top of the default normalisation of an exchange (eg/ a
+/// non-standard contract multiplier, or an inverted direction convention on a single listing).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct NormalisationOverride {
+    /// Multiplier applied to every quantity (eg/ contracts => base asset).
+    pub quantity_scale: f64,
+    /// Multiplier applied to every price.
+    pub price_scale: f64,
+    /// Invert the aggressor [`Side`] of every [`PublicTrade`].
+    pub invert_side: bool,
+}
+
+impl Default for NormalisationOverride {
+    fn default() -> Self {
+        Self {
+            quantity_scale: 1.0,
+            price_scale: 1.0,
+            invert_side: false,
+        }
+    }
+}
+
+impl NormalisationOverride {
+    /// Construct a new [`Self`] that only scales quantities by the provided multiplier.
+    pub fn quantity_scale(quantity_scale: f64) -> Self {
+        Self {
+            quantity_scale,
+            ..Self::default()
+        }
+    }
+
+    /// Construct a new [`Self`] that only scales prices by the provided multiplier.
+    pub fn price_scale(price_scale: f64) -> Self {
+        Self {
+            price_scale,
+            ..Self::default()
+        }
+    }
+
+    /// Construct a new [`Self`] that only inverts the aggressor [`Side`] of [`PublicTrade`]s.
+    pub fn invert_side() -> Self {
+        Self {
+            invert_side: true,
+            ..Self::default()
+        }
+    }
+
+    fn level(&self, level: &mut Level) {
+        level.price *= self.price_scale;
+        level.amount *= self.quantity_scale;
+    }
+}
+
+/// Normalised data that a [`NormalisationOverride`] can be applied to.
+pub trait Overridable {
+    fn apply(&mut self, overrides: &NormalisationOverride);
+}
+
+impl Overridable for PublicTrade {
+    fn apply(&mut self, overrides: &NormalisationOverride) {
+        self.price *= overrides.price_scale;
+        self.amount *= overrides.quantity_scale;
+        if overrides.invert_side {
+            self.side = match self.side {
+                Side::Buy => Side::Sell,
+                Side::Sell => Side::Buy,
+            };
+        }
+    }
+}
+
+impl Overridable for OrderBookL1 {
+    fn apply(&mut self, overrides: &NormalisationOverride) {
+        overrides.level(&mut self.best_bid);
+        overrides.level(&mut self.best_ask);
+    }
+}
+
+impl Overridable for OrderBook {
+    fn apply(&mut self, overrides: &NormalisationOverride) {
+        self.bids
+            .levels
+            .iter_mut()
+            .chain(self.asks.levels.iter_mut())
+            .for_each(|level| overrides.level(level));
+    }
+}
+
+impl Overridable for Candle {
+    fn apply(&mut self, overrides: &NormalisationOverride) {
+        self.open *= overrides.price_scale;
+        self.high *= overrides.price_scale;
+        self.low *= overrides.price_scale;
+        self.close *= overrides.price_scale;
+        self.volume *= overrides.quantity_scale;
+        self.quote_volume = self
+            .quote_volume
+            .map(|quote_volume| quote_volume * overrides.price_scale * overrides.quantity_scale);
+    }
+}
+
+/// [`Adapter`] that applies the [`NormalisationOverride`] registered for the exchange &
+/// instrument of each [`MarketEvent`], after the default exchange transform.
+///
+/// ### Notes
+/// [`MarketEvent`]s of exchange & instrument combinations without a registered
+/// [`NormalisationOverride`] are passed through unchanged.
+#[derive(Clone, Debug, Default)]
+pub struct OverrideAdapter {
+    overrides: HashMap<(Exchange, Arc<Instrument>), NormalisationOverride>,
+}
+
+impl OverrideAdapter {
+    /// Construct a new [`Self`] with no registered [`NormalisationOverride`]s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the [`NormalisationOverride`] to apply to every [`MarketEvent`] of the provided
+    /// exchange & instrument, replacing any previously registered override.
+    pub fn with_override<E, I>(
+        mut self,
+        exchange: E,
+        instrument: I,
+        overrides: NormalisationOverride,
+    ) -> Self
+    where
+        E: Into<Exchange>,
+        I: Into<Instrument>,
+    {
+        self.overrides
+            .insert((exchange.into(), Arc::new(instrument.into())), overrides);
+        self
+    }
+}
+
+impl<T> Adapter<MarketEvent<T>> for OverrideAdapter
+where
+    T: Overridable,
+{
+    type Output = MarketEvent<T>;
+
+    fn adapt(&mut self, mut input: MarketEvent<T>) -> Option<Self::Output> {
+        if let Some(overrides) = self
+            .overrides
+            .get(&(input.exchange.clone(), input.instrument.clone()))
+        {
+            input.kind.apply(overrides);
+        }
+
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::ExchangeId, fixtures};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::Utc;
+
+    fn trade_event(exchange: ExchangeId, base: &str, amount: f64) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange: Exchange::from(exchange),
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Perpetual)).into(),
+            ..fixtures::trade(Utc::now(), "id", 100.0, amount, Side::Buy)
+        }
+    }
+
+    #[test]
+    fn test_override_adapter() {
+        let mut adapter = OverrideAdapter::new().with_override(
+            ExchangeId::Okx,
+            ("eth", "usdt", InstrumentKind::Perpetual),
+            NormalisationOverride::quantity_scale(0.1),
+        );
+
+        struct TestCase {
+            input: MarketEvent<PublicTrade>,
+            expected: f64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: overridden exchange & instrument quantity is scaled
+                input: trade_event(ExchangeId::Okx, "eth", 20.0),
+                expected: 2.0,
+            },
+            TestCase {
+                // TC1: other instrument of the same exchange is unchanged
+                input: trade_event(ExchangeId::Okx, "btc", 20.0),
+                expected: 20.0,
+            },
+            TestCase {
+                // TC2: same instrument of another exchange is unchanged
+                input: trade_event(ExchangeId::BybitPerpetualsUsd, "eth", 20.0),
+                expected: 20.0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = adapter.adapt(test.input).unwrap();
+            assert_eq!(actual.kind.amount, test.expected, "TC{index} failed");
+            assert_eq!(actual.kind.price, 100.0, "TC{index} failed");
+            assert_eq!(actual.kind.side, Side::Buy, "TC{index} failed");
+        }
+
+        // No-op when no override is registered
+        let input = trade_event(ExchangeId::Okx, "eth", 20.0);
+        let actual = OverrideAdapter::new().adapt(input.clone()).unwrap();
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn test_normalisation_override_apply() {
+        let overrides = NormalisationOverride {
+            quantity_scale: 10.0,
+            price_scale: 0.5,
+            invert_side: true,
+        };
+
+        let mut trade = trade_event(ExchangeId::Okx, "eth", 2.0).kind;
+        trade.apply(&overrides);
+        assert_eq!(
+            (trade.price, trade.amount, trade.side),
+            (50.0, 20.0, Side::Sell)
+        );
+
+        let mut book = OrderBookL1 {
+            last_update_time: Utc::now(),
+            best_bid: Level::new(100.0, 1.0),
+            best_ask: Level::new(102.0, 2.0),
+        };
+        book.apply(&overrides);
+        assert_eq!(book.best_bid, Level::new(50.0, 10.0));
+        assert_eq!(book.best_ask, Level::new(51.0, 20.0));
+    }
+}
@@ -0,0 +1,221 @@
+use super::Adapter;
+use crate::{
+    event::{DataKind, MarketEvent},
+    subscription::{
+        book::{Level, OrderBook, OrderBookL1},
+        candle::{Candle, ContinuousCandle},
+        funding::FundingRate,
+        index::IndexPrice,
+        liquidation::Liquidation,
+        status::InstrumentStatus,
+        ticker::Ticker,
+        trade::PublicTrade,
+    },
+};
+
+/// Normalised Barter event whose prices can be mapped in place (eg/ converted into a different
+/// quote currency), leaving every amount, volume & rate untouched.
+pub trait MapPrices {
+    fn map_prices<F>(&mut self, f: &F)
+    where
+        F: Fn(f64) -> f64;
+}
+
+impl MapPrices for PublicTrade {
+    fn map_prices<F>(&mut self, f: &F)
+    where
+        F: Fn(f64) -> f64,
+    {
+        self.price = f(self.price);
+    }
+}
+
+impl MapPrices for Level {
+    fn map_prices<F>(&mut self, f: &F)
+    where
+        F: Fn(f64) -> f64,
+    {
+        self.price = f(self.price);
+    }
+}
+
+impl MapPrices for OrderBookL1 {
+    fn map_prices<F>(&mut self, f: &F)
+    where
+        F: Fn(f64) -> f64,
+    {
+        self.best_bid.map_prices(f);
+        self.best_ask.map_prices(f);
+    }
+}
+
+impl MapPrices for OrderBook {
+    /// ### Notes
+    /// The [`Level`]s are not re-sorted, so the closure should be monotonically increasing (eg/
+    /// a positive conversion rate) to preserve the ordering of each side.
+    fn map_prices<F>(&mut self, f: &F)
+    where
+        F: Fn(f64) -> f64,
+    {
+        self.bids
+            .levels
+            .iter_mut()
+            .chain(self.asks.levels.iter_mut())
+            .for_each(|level| level.map_prices(f));
+    }
+}
+
+impl MapPrices for Candle {
+    fn map_prices<F>(&mut self, f: &F)
+    where
+        F: Fn(f64) -> f64,
+    {
+        self.open = f(self.open);
+        self.high = f(self.high);
+        self.low = f(self.low);
+        self.close = f(self.close);
+    }
+}
+
+impl MapPrices for ContinuousCandle {
+    fn map_prices<F>(&mut self, f: &F)
+    where
+        F: Fn(f64) -> f64,
+    {
+        self.candle.map_prices(f);
+    }
+}
+
+impl MapPrices for Liquidation {
+    fn map_prices<F>(&mut self, f: &F)
+    where
+        F: Fn(f64) -> f64,
+    {
+        self.price = f(self.price);
+    }
+}
+
+impl MapPrices for IndexPrice {
+    fn map_prices<F>(&mut self, f: &F)
+    where
+        F: Fn(f64) -> f64,
+    {
+        self.price = f(self.price);
+    }
+}
+
+impl MapPrices for FundingRate {
+    fn map_prices<F>(&mut self, f: &F)
+    where
+        F: Fn(f64) -> f64,
+    {
+        self.mark_price = f(self.mark_price);
+    }
+}
+
+impl MapPrices for Ticker {
+    fn map_prices<F>(&mut self, f: &F)
+    where
+        F: Fn(f64) -> f64,
+    {
+        self.last_price = f(self.last_price);
+        self.high_24h = f(self.high_24h);
+        self.low_24h = f(self.low_24h);
+    }
+}
+
+impl MapPrices for InstrumentStatus {
+    fn map_prices<F>(&mut self, _: &F)
+    where
+        F: Fn(f64) -> f64,
+    {
+    }
+}
+
+impl MapPrices for DataKind {
+    fn map_prices<F>(&mut self, f: &F)
+    where
+        F: Fn(f64) -> f64,
+    {
+        match self {
+            DataKind::Trade(trade) => trade.map_prices(f),
+            DataKind::OrderBookL1(book) => book.map_prices(f),
+            DataKind::OrderBook(book) => book.map_prices(f),
+            DataKind::Candle(candle) => candle.map_prices(f),
+            DataKind::ContinuousCandle(candle) => candle.map_prices(f),
+            DataKind::Liquidation(liquidation) => liquidation.map_prices(f),
+            DataKind::InstrumentStatus(status) => status.map_prices(f),
+            DataKind::IndexPrice(index) => index.map_prices(f),
+            DataKind::FundingRate(funding) => funding.map_prices(f),
+            DataKind::Ticker(ticker) => ticker.map_prices(f),
+        }
+    }
+}
+
+/// [`Adapter`] that maps every price of each [`MarketEvent<T>`] in place with the provided
+/// closure (eg/ converting USDT prices into USD), yielding every event.
+///
+/// See [`AdapterExt::map_price`](super::AdapterExt::map_price).
+#[derive(Copy, Clone, Debug)]
+pub struct MapPrice<F>(pub F);
+
+impl<T, F> Adapter<MarketEvent<T>> for MapPrice<F>
+where
+    T: MapPrices,
+    F: Fn(f64) -> f64,
+{
+    type Output = MarketEvent<T>;
+
+    fn adapt(&mut self, mut input: MarketEvent<T>) -> Option<Self::Output> {
+        input.kind.map_prices(&self.0);
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::AdapterExt;
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange, Side,
+    };
+    use chrono::Utc;
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn test_map_price() {
+        let trade = |price: f64| MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from("exchange"),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)).into(),
+            kind: PublicTrade {
+                id: price.to_string(),
+                price,
+                amount: 0.5,
+                side: Side::Sell,
+                conditions: vec![],
+            },
+        };
+
+        let input = vec![trade(100.0), trade(200.0)];
+        let actual = futures::stream::iter(input.clone())
+            .map_price(|price| price * 2.0)
+            .collect::<Vec<_>>()
+            .await;
+
+        // Only the price of each trade is mapped
+        let expected = input
+            .into_iter()
+            .map(|event| MarketEvent {
+                kind: PublicTrade {
+                    price: event.kind.price * 2.0,
+                    ..event.kind.clone()
+                },
+                ..event
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+    }
+}
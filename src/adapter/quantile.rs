@@ -0,0 +1,331 @@
+use super::Adapter;
+use crate::{event::MarketEvent, subscription::trade::PublicTrade};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+/// Estimated 1st, 50th & 99th percentile [`PublicTrade`] prices of an instrument over a
+/// [`QuantileAdapter`] `window`.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PriceQuantiles {
+    pub p1: f64,
+    pub p50: f64,
+    pub p99: f64,
+    pub window: Duration,
+    pub trade_count: u64,
+}
+
+/// [`Adapter`] that consumes [`PublicTrade`] [`MarketEvent`]s and emits the estimated
+/// [`PriceQuantiles`] of each closed `window` of trades per instrument.
+///
+/// Quantiles are estimated with the P² algorithm, so memory is bounded (five markers per
+/// quantile) regardless of the number of trades in a window.
+///
+/// See docs: <https://www.cse.wustl.edu/~jain/papers/ftp/psqr.pdf>
+///
+/// ### Notes
+/// - Windows are tracked independently for every exchange & instrument combination, with each
+///   window starting at the `exchange_time` of its first [`PublicTrade`].
+/// - A window is closed (and its [`PriceQuantiles`] emitted) by the first [`PublicTrade`] at
+///   least `window` after the window start, which then starts the next window.
+/// - A window is also closed by the time provided to [`Adapter::tick`] once it is at least
+///   `window` after the window start, with the next [`PublicTrade`] starting the next window. Use
+///   [`AdapterExt::adapt_with_ticks`](super::AdapterExt::adapt_with_ticks) so the
+///   [`PriceQuantiles`] of a window followed by a quiet period are emitted on time, rather than
+///   when the next trade arrives.
+#[derive(Clone, Debug)]
+pub struct QuantileAdapter {
+    window: Duration,
+    windows: HashMap<(Exchange, Arc<Instrument>), QuantileWindow>,
+}
+
+/// P² estimators of a single exchange & instrument combination for the current window.
+#[derive(Clone, Debug)]
+struct QuantileWindow {
+    start: DateTime<Utc>,
+    p1: P2Quantile,
+    p50: P2Quantile,
+    p99: P2Quantile,
+}
+
+impl QuantileWindow {
+    fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            start,
+            p1: P2Quantile::new(0.01),
+            p50: P2Quantile::new(0.5),
+            p99: P2Quantile::new(0.99),
+        }
+    }
+
+    fn insert(&mut self, price: f64) {
+        self.p1.insert(price);
+        self.p50.insert(price);
+        self.p99.insert(price);
+    }
+
+    fn quantiles(&self, window: Duration) -> Option<PriceQuantiles> {
+        Some(PriceQuantiles {
+            p1: self.p1.value()?,
+            p50: self.p50.value()?,
+            p99: self.p99.value()?,
+            window,
+            trade_count: self.p50.count,
+        })
+    }
+}
+
+impl QuantileAdapter {
+    /// Construct a new [`Self`] that emits the [`PriceQuantiles`] of every `window` (eg/ 60s) of
+    /// trades.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            windows: HashMap::new(),
+        }
+    }
+}
+
+impl Adapter<MarketEvent<PublicTrade>> for QuantileAdapter {
+    type Output = MarketEvent<PriceQuantiles>;
+
+    fn adapt(&mut self, input: MarketEvent<PublicTrade>) -> Option<Self::Output> {
+        let window = chrono::Duration::from_std(self.window).unwrap_or(chrono::Duration::MAX);
+        let current = self
+            .windows
+            .entry((input.exchange.clone(), input.instrument.clone()))
+            .or_insert_with(|| QuantileWindow::new(input.exchange_time));
+
+        // Close the current window if this trade falls outside it, starting the next window
+        let closed = match input.exchange_time.signed_duration_since(current.start) >= window {
+            true => Some(std::mem::replace(
+                current,
+                QuantileWindow::new(input.exchange_time),
+            )),
+            false => None,
+        };
+
+        current.insert(input.kind.price);
+
+        let quantiles = closed?.quantiles(self.window)?;
+        Some(MarketEvent {
+            exchange_time: input.exchange_time,
+            received_time: input.received_time,
+            exchange: input.exchange,
+            instrument: input.instrument,
+            kind: quantiles,
+        })
+    }
+
+    fn tick(&mut self, now: DateTime<Utc>) -> Vec<Self::Output> {
+        let window = chrono::Duration::from_std(self.window).unwrap_or(chrono::Duration::MAX);
+
+        // Close every window that has elapsed by now, leaving the next trade to start the next
+        let mut output = vec![];
+        self.windows.retain(|(exchange, instrument), current| {
+            if now.signed_duration_since(current.start) < window {
+                return true;
+            }
+            if let Some(quantiles) = current.quantiles(self.window) {
+                output.push(MarketEvent {
+                    exchange_time: now,
+                    received_time: now,
+                    exchange: exchange.clone(),
+                    instrument: instrument.clone(),
+                    kind: quantiles,
+                });
+            }
+            false
+        });
+        output
+    }
+}
+
+/// Streaming P² estimator of a single quantile `p`, maintaining five markers whose heights
+/// approximate the minimum, `p/2`, `p`, `(1+p)/2` quantiles, and maximum of the observations.
+#[derive(Clone, Debug)]
+struct P2Quantile {
+    p: f64,
+    count: u64,
+    heights: [f64; 5],
+    positions: [f64; 5],
+    desired: [f64; 5],
+    increments: [f64; 5],
+}
+
+impl P2Quantile {
+    fn new(p: f64) -> Self {
+        Self {
+            p,
+            count: 0,
+            heights: [0.0; 5],
+            positions: [0.0, 1.0, 2.0, 3.0, 4.0],
+            desired: [0.0, 2.0 * p, 4.0 * p, 2.0 + 2.0 * p, 4.0],
+            increments: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+        }
+    }
+
+    fn insert(&mut self, value: f64) {
+        // Collect the first five observations as the initial marker heights
+        if self.count < 5 {
+            self.heights[self.count as usize] = value;
+            self.count += 1;
+            if self.count == 5 {
+                self.heights.sort_by(f64::total_cmp);
+            }
+            return;
+        }
+        self.count += 1;
+
+        // Find the cell the observation falls into, extending the extreme markers if required
+        let cell = if value < self.heights[0] {
+            self.heights[0] = value;
+            0
+        } else if value >= self.heights[4] {
+            self.heights[4] = value;
+            3
+        } else {
+            (1..5)
+                .find(|&index| value < self.heights[index])
+                .map_or(3, |index| index - 1)
+        };
+
+        // Increment the positions of the markers above the cell, and every desired position
+        self.positions[cell + 1..]
+            .iter_mut()
+            .for_each(|position| *position += 1.0);
+        self.desired
+            .iter_mut()
+            .zip(self.increments)
+            .for_each(|(desired, increment)| *desired += increment);
+
+        // Adjust the heights of the middle markers if they are off their desired positions
+        for index in 1..4 {
+            let offset = self.desired[index] - self.positions[index];
+            let above = self.positions[index + 1] - self.positions[index];
+            let below = self.positions[index - 1] - self.positions[index];
+
+            if (offset >= 1.0 && above > 1.0) || (offset <= -1.0 && below < -1.0) {
+                let direction = offset.signum();
+                let parabolic = self.parabolic(index, direction);
+                self.heights[index] = match self.heights[index - 1] < parabolic
+                    && parabolic < self.heights[index + 1]
+                {
+                    true => parabolic,
+                    false => self.linear(index, direction),
+                };
+                self.positions[index] += direction;
+            }
+        }
+    }
+
+    /// Piecewise-parabolic (P²) prediction of the height of the marker at `index` once moved by
+    /// `direction`.
+    fn parabolic(&self, index: usize, direction: f64) -> f64 {
+        let (q, n) = (&self.heights, &self.positions);
+        q[index]
+            + direction / (n[index + 1] - n[index - 1])
+                * ((n[index] - n[index - 1] + direction) * (q[index + 1] - q[index])
+                    / (n[index + 1] - n[index])
+                    + (n[index + 1] - n[index] - direction) * (q[index] - q[index - 1])
+                        / (n[index] - n[index - 1]))
+    }
+
+    /// Linear prediction of the height of the marker at `index` once moved by `direction`.
+    fn linear(&self, index: usize, direction: f64) -> f64 {
+        let neighbour = if direction > 0.0 {
+            index + 1
+        } else {
+            index - 1
+        };
+        self.heights[index]
+            + direction * (self.heights[neighbour] - self.heights[index])
+                / (self.positions[neighbour] - self.positions[index])
+    }
+
+    /// Estimated quantile, or `None` if no observations have been inserted.
+    fn value(&self) -> Option<f64> {
+        match self.count {
+            0 => None,
+            count if count < 5 => {
+                // Nearest-rank quantile of the few observations collected so far
+                let mut observed = self.heights[..count as usize].to_vec();
+                observed.sort_by(f64::total_cmp);
+                let rank = (self.p * count as f64).ceil().max(1.0) as usize;
+                observed.get(rank - 1).copied()
+            }
+            _ => Some(self.heights[2]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use barter_integration::model::Side;
+
+    fn trade(millis: i64, price: f64) -> MarketEvent<PublicTrade> {
+        fixtures::trade(
+            fixtures::millis(millis),
+            &millis.to_string(),
+            price,
+            1.0,
+            Side::Buy,
+        )
+    }
+
+    #[test]
+    fn test_quantile_adapter() {
+        let mut adapter = QuantileAdapter::new(Duration::from_secs(1));
+
+        // Uniformly distributed prices 1..=1000, in a scrambled order within the first window
+        let prices = (0..1000).map(|index| ((index * 7919) % 1000 + 1) as f64);
+        let emitted = prices
+            .enumerate()
+            .filter_map(|(index, price)| adapter.adapt(trade(index as i64, price)))
+            .collect::<Vec<_>>();
+        assert!(emitted.is_empty(), "window closed early: {emitted:?}");
+
+        // First trade outside the window closes it
+        let actual = adapter.adapt(trade(1_000, 500.0)).unwrap().kind;
+        assert_eq!(actual.trade_count, 1000);
+        assert_eq!(actual.window, Duration::from_secs(1));
+
+        let tolerance = 1000.0 * 0.02;
+        for (name, actual, expected) in [
+            ("p1", actual.p1, 10.0),
+            ("p50", actual.p50, 500.0),
+            ("p99", actual.p99, 990.0),
+        ] {
+            assert!(
+                (actual - expected).abs() <= tolerance,
+                "{name} failed: {actual} not within {tolerance} of {expected}"
+            );
+        }
+
+        // Next window only contains the closing trade
+        let actual = adapter.adapt(trade(2_000, 600.0)).unwrap().kind;
+        assert_eq!(actual.trade_count, 1);
+        assert_eq!((actual.p1, actual.p50, actual.p99), (500.0, 500.0, 500.0));
+
+        // Tick before the window started by the 2s trade has elapsed does not close it
+        assert!(adapter.tick(fixtures::millis(2_500)).is_empty());
+
+        // Tick once the window has elapsed closes it without a further trade
+        let actual = adapter.tick(fixtures::millis(3_000));
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].exchange_time, fixtures::millis(3_000));
+        assert_eq!(actual[0].kind.trade_count, 1);
+        assert_eq!(actual[0].kind.p50, 600.0);
+
+        // Quiet period does not emit empty windows, and the next trade starts the next window
+        assert!(adapter.tick(fixtures::millis(4_000)).is_empty());
+        assert!(adapter.adapt(trade(4_500, 700.0)).is_none());
+        let actual = adapter.tick(fixtures::millis(5_500));
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].kind.trade_count, 1);
+        assert_eq!(actual[0].kind.p50, 700.0);
+    }
+}
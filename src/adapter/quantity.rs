@@ -0,0 +1,109 @@
+use super::Adapter;
+use crate::{
+    event::MarketEvent,
+    exchange::ExchangeId,
+    subscription::trade::{PublicTrade, QuantityUnit},
+};
+use tracing::debug;
+
+/// [`Adapter`] that normalises the amount of every [`PublicTrade`] [`MarketEvent`] to the base
+/// asset, so that volume is comparable across exchanges.
+///
+/// ### Notes
+/// - The raw amount unit of each exchange is determined by
+///   [`ExchangeId::trade_quantity_unit`].
+/// - Quote denominated amounts are converted to the base asset using the trade price.
+/// - Contract denominated amounts cannot be converted without the instrument contract size, so
+///   they are passed through unchanged, as are events from unrecognised exchanges.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct BaseQuantityAdapter;
+
+impl Adapter<MarketEvent<PublicTrade>> for BaseQuantityAdapter {
+    type Output = MarketEvent<PublicTrade>;
+
+    fn adapt(&mut self, mut input: MarketEvent<PublicTrade>) -> Option<Self::Output> {
+        let unit = input
+            .exchange
+            .to_string()
+            .parse::<ExchangeId>()
+            .map(|exchange| exchange.trade_quantity_unit(&input.instrument))
+            .unwrap_or(QuantityUnit::Base);
+
+        match input.kind.base_amount(unit) {
+            Some(amount) => input.kind.amount = amount,
+            None => debug!(
+                exchange = %input.exchange,
+                instrument = %input.instrument,
+                ?unit,
+                "unable to normalise PublicTrade amount to base asset, passing through unchanged"
+            ),
+        }
+
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange, Side,
+    };
+    use chrono::Utc;
+
+    fn trade_event(
+        exchange: ExchangeId,
+        quote: &str,
+        price: f64,
+        amount: f64,
+    ) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange: Exchange::from(exchange),
+            instrument: Instrument::from(("xbt", quote, InstrumentKind::Perpetual)).into(),
+            ..fixtures::trade(Utc::now(), "id", price, amount, Side::Sell)
+        }
+    }
+
+    #[test]
+    fn test_base_quantity_adapter() {
+        struct TestCase {
+            input: MarketEvent<PublicTrade>,
+            expected: f64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Bitmex inverse quote denominated amount is converted to base
+                input: trade_event(ExchangeId::Bitmex, "usd", 24564.5, 200.0),
+                expected: 200.0 / 24564.5,
+            },
+            TestCase {
+                // TC1: Bitmex linear contract denominated amount is passed through unchanged
+                input: trade_event(ExchangeId::Bitmex, "usdt", 24564.5, 1000.0),
+                expected: 1000.0,
+            },
+            TestCase {
+                // TC2: BinanceFuturesUsd base denominated amount is unchanged
+                input: trade_event(ExchangeId::BinanceFuturesUsd, "usdt", 24564.5, 0.5),
+                expected: 0.5,
+            },
+            TestCase {
+                // TC3: Okx contract denominated amount is passed through unchanged
+                input: trade_event(ExchangeId::Okx, "usdt", 24564.5, 3.0),
+                expected: 3.0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = BaseQuantityAdapter.adapt(test.input).unwrap();
+            assert!(
+                (actual.kind.amount - test.expected).abs() < 1e-12,
+                "TC{index} failed: {} != {}",
+                actual.kind.amount,
+                test.expected
+            );
+        }
+    }
+}
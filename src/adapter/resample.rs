@@ -0,0 +1,293 @@
+use super::Adapter;
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    subscription::candle::{Candle, Interval},
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Duration, Utc, Weekday};
+use std::{collections::HashMap, sync::Arc};
+use tracing::debug;
+
+/// [`Adapter`] that resamples closed [`Candle`]s of a `fine` [`Interval`] (eg/ 1m) into
+/// [`Candle`]s of a `coarse` [`Interval`] (eg/ 5m), avoiding the need to subscribe to every
+/// [`Interval`] of interest.
+///
+/// ### Notes
+/// - The coarse [`Candle`] uses the first open, max high, min low, last close, and the summed
+///   volume, quote volume & trade count of its constituent fine [`Candle`]s. The quote volume is
+///   only populated if it is reported for every constituent fine [`Candle`].
+/// - A coarse [`Candle`] is only emitted once every constituent fine [`Candle`] has been
+///   consumed. Coarse buckets with a gap (eg/ missed fine [`Candle`] during a re-connection) are
+///   discarded.
+/// - Intra-candle updates of open fine [`Candle`]s (ie/ not `is_final`) are ignored. The open
+///   time of each closed fine [`Candle`] is derived from its `close_time`.
+/// - Buckets are tracked independently for every exchange & instrument combination.
+/// - [`Interval::Week1`] buckets start on Monday 00:00 UTC unless configured otherwise with
+///   [`CandleResampler::with_week_start`].
+#[derive(Clone, Debug)]
+pub struct CandleResampler {
+    fine: Interval,
+    coarse: Interval,
+    week_start: Weekday,
+    buckets: HashMap<(Exchange, Arc<Instrument>), Bucket>,
+}
+
+/// In-progress coarse [`Candle`] bucket.
+#[derive(Clone, Debug)]
+struct Bucket {
+    start: DateTime<Utc>,
+    next: DateTime<Utc>,
+    event: MarketEvent<Candle>,
+}
+
+impl Bucket {
+    fn push(&mut self, input: MarketEvent<Candle>, next: DateTime<Utc>) {
+        let candle = &mut self.event.kind;
+        candle.close_time = input.kind.close_time;
+        candle.high = candle.high.max(input.kind.high);
+        candle.low = candle.low.min(input.kind.low);
+        candle.close = input.kind.close;
+        candle.volume += input.kind.volume;
+        candle.quote_volume = candle
+            .quote_volume
+            .zip(input.kind.quote_volume)
+            .map(|(quote_volume, next)| quote_volume + next);
+        candle.trade_count += input.kind.trade_count;
+
+        self.event.exchange_time = input.exchange_time;
+        self.event.received_time = input.received_time;
+        self.next = next;
+    }
+}
+
+impl CandleResampler {
+    /// Construct a new [`Self`] that resamples `fine` [`Interval`] [`Candle`]s into `coarse`
+    /// [`Interval`] [`Candle`]s.
+    ///
+    /// Returns a [`DataError::IncompatibleIntervals`] if the `coarse` [`Interval`] is not a
+    /// larger multiple of the `fine` [`Interval`]. [`Interval::Month1`] can be resampled from any
+    /// `fine` [`Interval`] that evenly divides a day.
+    pub fn new(fine: Interval, coarse: Interval) -> Result<Self, DataError> {
+        let fine_ms = fine.duration().map(|duration| duration.num_milliseconds());
+        let coarse_ms = coarse
+            .duration()
+            .or_else(|| (coarse == Interval::Month1).then(|| Duration::days(1)))
+            .map(|duration| duration.num_milliseconds());
+
+        let compatible = match (fine_ms, coarse_ms) {
+            (Some(fine_ms), Some(coarse_ms)) => {
+                fine != coarse && fine_ms <= coarse_ms && coarse_ms % fine_ms == 0
+            }
+            _ => false,
+        };
+
+        if !compatible {
+            return Err(DataError::IncompatibleIntervals { fine, coarse });
+        }
+
+        Ok(Self {
+            fine,
+            coarse,
+            week_start: Weekday::Mon,
+            buckets: HashMap::new(),
+        })
+    }
+
+    /// Align [`Interval::Week1`] buckets to start on the provided `week_start` day 00:00 UTC.
+    pub fn with_week_start(self, week_start: Weekday) -> Self {
+        Self { week_start, ..self }
+    }
+}
+
+impl Adapter<MarketEvent<Candle>> for CandleResampler {
+    type Output = MarketEvent<Candle>;
+
+    fn adapt(&mut self, input: MarketEvent<Candle>) -> Option<Self::Output> {
+        // Only closed fine Candles contribute to the coarse Candle
+        if !input.kind.is_final {
+            return None;
+        }
+
+        // Derive the open time of the fine Candle, supporting inclusive & exclusive close times
+        let open = self.fine.floor_with_week_start(
+            input.kind.close_time - Duration::milliseconds(1),
+            self.week_start,
+        );
+        let next = self.fine.ceil_with_week_start(open, self.week_start);
+        let start = self.coarse.floor_with_week_start(open, self.week_start);
+        let end = self.coarse.ceil_with_week_start(open, self.week_start);
+
+        let key = (input.exchange.clone(), input.instrument.clone());
+
+        let bucket = match self.buckets.remove(&key) {
+            // Fine Candle directly follows the previous fine Candle in the bucket
+            Some(mut bucket) if bucket.start == start && bucket.next == open => {
+                bucket.push(input, next);
+                bucket
+            }
+            // Fine Candle was already consumed (eg/ duplicate), so ignore it
+            Some(bucket) if bucket.start == start && open < bucket.next => {
+                self.buckets.insert(key, bucket);
+                return None;
+            }
+            previous => {
+                if let Some(previous) = previous {
+                    debug!(
+                        exchange = %input.exchange,
+                        instrument = %input.instrument,
+                        bucket_start = %previous.start,
+                        expected_open = %previous.next,
+                        open = %open,
+                        "discarding incomplete coarse Candle bucket due to gap in fine Candles"
+                    );
+                }
+
+                // Coarse Candle can only be built from the first constituent fine Candle
+                if open != start {
+                    return None;
+                }
+
+                Bucket {
+                    start,
+                    next,
+                    event: input,
+                }
+            }
+        };
+
+        if bucket.next >= end {
+            Some(bucket.event)
+        } else {
+            self.buckets.insert(key, bucket);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::TimeZone;
+
+    fn candle(minute: u32, open: f64, high: f64, low: f64, close: f64) -> MarketEvent<Candle> {
+        let close_time = Utc.with_ymd_and_hms(2023, 1, 1, 0, minute, 0).unwrap()
+            + Duration::minutes(1)
+            - Duration::milliseconds(1);
+
+        MarketEvent {
+            exchange_time: close_time,
+            received_time: close_time,
+            exchange: Exchange::from("exchange"),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)).into(),
+            kind: Candle {
+                close_time,
+                open,
+                high,
+                low,
+                close,
+                volume: 10.0,
+                quote_volume: Some(1000.0),
+                trade_count: 2,
+                is_final: true,
+            },
+        }
+    }
+
+    #[test]
+    fn test_candle_resampler_new() {
+        assert!(CandleResampler::new(Interval::Minute1, Interval::Minute5).is_ok());
+        assert!(CandleResampler::new(Interval::Hour1, Interval::Month1).is_ok());
+        assert!(CandleResampler::new(Interval::Minute5, Interval::Minute1).is_err());
+        assert!(CandleResampler::new(Interval::Day3, Interval::Week1).is_err());
+        assert!(CandleResampler::new(Interval::Month1, Interval::Month1).is_err());
+    }
+
+    #[test]
+    fn test_candle_resampler() {
+        let mut resampler = CandleResampler::new(Interval::Minute1, Interval::Minute5).unwrap();
+
+        // Five 1m Candles are resampled into a single 5m Candle
+        let fine = vec![
+            candle(0, 100.0, 105.0, 99.0, 104.0),
+            candle(1, 104.0, 110.0, 103.0, 108.0),
+            candle(2, 108.0, 109.0, 95.0, 96.0),
+            candle(3, 96.0, 101.0, 96.0, 100.0),
+            candle(4, 100.0, 102.0, 98.0, 101.0),
+        ];
+
+        let mut actual = fine
+            .into_iter()
+            .filter_map(|candle| resampler.adapt(candle))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual.len(), 1);
+        let actual = actual.remove(0).kind;
+        assert_eq!(
+            actual,
+            Candle {
+                close_time: Utc.with_ymd_and_hms(2023, 1, 1, 0, 5, 0).unwrap()
+                    - Duration::milliseconds(1),
+                open: 100.0,
+                high: 110.0,
+                low: 95.0,
+                close: 101.0,
+                volume: 50.0,
+                quote_volume: Some(5000.0),
+                trade_count: 10,
+                is_final: true,
+            }
+        );
+
+        // Bucket with a missing 1m Candle is discarded, and the next bucket is unaffected
+        let fine = vec![
+            candle(5, 1.0, 1.0, 1.0, 1.0),
+            candle(6, 1.0, 1.0, 1.0, 1.0),
+            candle(8, 1.0, 1.0, 1.0, 1.0),
+            candle(9, 1.0, 1.0, 1.0, 1.0),
+            candle(10, 2.0, 3.0, 1.0, 2.0),
+            candle(11, 2.0, 3.0, 1.0, 2.0),
+            candle(12, 2.0, 3.0, 1.0, 2.0),
+            candle(13, 2.0, 3.0, 1.0, 2.0),
+            candle(14, 2.0, 3.0, 1.0, 2.0),
+        ];
+
+        let actual = fine
+            .into_iter()
+            .filter_map(|candle| resampler.adapt(candle))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].kind.open, 2.0);
+        assert_eq!(actual[0].kind.volume, 50.0);
+    }
+
+    #[test]
+    fn test_candle_resampler_with_week_start() {
+        let mut resampler = CandleResampler::new(Interval::Day1, Interval::Week1)
+            .unwrap()
+            .with_week_start(Weekday::Sun);
+
+        // Sunday 2023-05-28 to Saturday 2023-06-03 are resampled into a single 1w Candle
+        let week_start = Utc.with_ymd_and_hms(2023, 5, 28, 0, 0, 0).unwrap();
+        let actual = (0..7)
+            .map(|day| {
+                let mut daily = candle(0, 100.0, 100.0 + day as f64, 100.0, 100.0);
+                let close_time = week_start + Duration::days(day + 1) - Duration::milliseconds(1);
+                daily.exchange_time = close_time;
+                daily.kind.close_time = close_time;
+                daily
+            })
+            .filter_map(|daily| resampler.adapt(daily))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(
+            actual[0].kind.close_time,
+            Utc.with_ymd_and_hms(2023, 6, 4, 0, 0, 0).unwrap() - Duration::milliseconds(1)
+        );
+        assert_eq!(actual[0].kind.high, 106.0);
+        assert_eq!(actual[0].kind.volume, 70.0);
+    }
+}
@@ -0,0 +1,269 @@
+use super::Adapter;
+use crate::{
+    event::{MarketEvent, StreamEvent},
+    exchange::ExchangeId,
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+/// Process-global counter of the next local receive sequence.
+static LOCAL_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// Next process-global local receive sequence, strictly greater than every sequence previously
+/// returned by this process.
+pub fn next_local_seq() -> u64 {
+    LOCAL_SEQUENCE.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Event tagged with the process-global local receive sequence assigned by a
+/// [`LocalSequenceAdapter`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct LocalSequenced<T> {
+    pub local_seq: u64,
+    pub event: T,
+}
+
+/// [`Adapter`] that tags each event with a process-global, strictly increasing local receive
+/// sequence, independent of any exchange provided ids or sequences.
+///
+/// Every [`LocalSequenceAdapter`] shares the same counter, so the `local_seq` of events adapted
+/// across all streams in the process forms a total order of everything the process received
+/// (eg/ for reproducible replay & debugging).
+///
+/// ### Notes
+/// Unlike most [`Adapter`]s, the `local_seq` is derived from the order events are adapted in
+/// rather than from event data, so it is not reproduced by feeding the same events again.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct LocalSequenceAdapter;
+
+impl<T> Adapter<T> for LocalSequenceAdapter {
+    type Output = LocalSequenced<T>;
+
+    fn adapt(&mut self, event: T) -> Option<Self::Output> {
+        Some(LocalSequenced {
+            local_seq: next_local_seq(),
+            event,
+        })
+    }
+}
+
+/// Normalised data tagged with the per-stream sequence assigned by a [`StreamSequenceAdapter`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct StreamSequenced<T> {
+    pub sequence: u64,
+    pub kind: T,
+}
+
+/// [`Adapter`] that tags each [`MarketEvent`] with a strictly increasing sequence, starting from
+/// zero for every exchange & instrument stream.
+///
+/// When adapting [`StreamEvent`]s, the sequences of every stream of an exchange are reset on each
+/// [`StreamEvent::Connected`] or [`StreamEvent::Reconnected`] marker.
+///
+/// ### Notes
+/// The sequence is monotonic within a single exchange & instrument stream, but is not
+/// comparable across streams. See [`LocalSequenceAdapter`] for a total order of every event
+/// received by the process.
+#[derive(Clone, Debug, Default)]
+pub struct StreamSequenceAdapter {
+    sequences: HashMap<(Exchange, Arc<Instrument>), u64>,
+}
+
+impl StreamSequenceAdapter {
+    /// Construct a new [`Self`] with every stream sequence starting from zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reset the sequence of every stream of the provided exchange to start from zero.
+    pub fn reset(&mut self, exchange: ExchangeId) {
+        let exchange = Exchange::from(exchange);
+        self.sequences.retain(|(key, _), _| *key != exchange);
+    }
+
+    fn tag<T>(&mut self, event: MarketEvent<T>) -> MarketEvent<StreamSequenced<T>> {
+        let sequence = self
+            .sequences
+            .entry((event.exchange.clone(), event.instrument.clone()))
+            .or_default();
+        let tagged = StreamSequenced {
+            sequence: *sequence,
+            kind: event.kind,
+        };
+        *sequence += 1;
+
+        MarketEvent {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: tagged,
+        }
+    }
+}
+
+impl<T> Adapter<MarketEvent<T>> for StreamSequenceAdapter {
+    type Output = MarketEvent<StreamSequenced<T>>;
+
+    fn adapt(&mut self, event: MarketEvent<T>) -> Option<Self::Output> {
+        Some(self.tag(event))
+    }
+}
+
+impl<T> Adapter<StreamEvent<T>> for StreamSequenceAdapter {
+    type Output = StreamEvent<StreamSequenced<T>>;
+
+    fn adapt(&mut self, event: StreamEvent<T>) -> Option<Self::Output> {
+        Some(match event {
+            StreamEvent::Connected { exchange, time } => {
+                self.reset(exchange);
+                StreamEvent::Connected { exchange, time }
+            }
+            StreamEvent::Reconnected { exchange, time } => {
+                self.reset(exchange);
+                StreamEvent::Reconnected { exchange, time }
+            }
+            StreamEvent::Heartbeat { exchange, time } => StreamEvent::Heartbeat { exchange, time },
+            StreamEvent::Maintenance {
+                exchange,
+                until,
+                time,
+            } => StreamEvent::Maintenance {
+                exchange,
+                until,
+                time,
+            },
+            StreamEvent::Failed {
+                exchange,
+                error,
+                time,
+            } => StreamEvent::Failed {
+                exchange,
+                error,
+                time,
+            },
+            StreamEvent::Market(event) => StreamEvent::Market(self.tag(event)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::AdapterExt;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::Utc;
+    use futures::StreamExt;
+    use std::collections::HashSet;
+
+    #[tokio::test]
+    async fn test_local_sequence_adapter_is_strictly_monotonic() {
+        // Two independently adapted streams with interleaved events
+        let mut first = LocalSequenceAdapter;
+        let mut second = LocalSequenceAdapter;
+
+        let actual = (0..100)
+            .map(|index| match index % 3 {
+                0 => first.adapt(("first", index)).unwrap(),
+                _ => second.adapt(("second", index)).unwrap(),
+            })
+            .collect::<Vec<_>>();
+
+        // Total order across both streams matches the order events were received
+        assert!(actual
+            .windows(2)
+            .all(|pair| pair[0].local_seq < pair[1].local_seq));
+
+        // Concurrently adapted streams never share a local_seq
+        let streams = (0..4)
+            .map(|_| {
+                tokio::spawn(
+                    futures::stream::iter(0..1_000)
+                        .adapt(LocalSequenceAdapter)
+                        .map(|sequenced| sequenced.local_seq)
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut seen = HashSet::new();
+        for stream in streams {
+            let local_seqs = stream.await.unwrap();
+            assert!(local_seqs.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(local_seqs
+                .into_iter()
+                .all(|local_seq| seen.insert(local_seq)));
+        }
+        assert_eq!(seen.len(), 4_000);
+    }
+
+    fn event(base: &str, id: u64) -> MarketEvent<u64> {
+        MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)).into(),
+            kind: id,
+        }
+    }
+
+    #[test]
+    fn test_stream_sequence_adapter() {
+        let mut adapter = StreamSequenceAdapter::new();
+
+        let sequences = |adapter: &mut StreamSequenceAdapter, input: Vec<StreamEvent<u64>>| {
+            input
+                .into_iter()
+                .filter_map(|event| match adapter.adapt(event)? {
+                    StreamEvent::Market(event) => Some(event.kind.sequence),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let reconnected = StreamEvent::Reconnected {
+            exchange: ExchangeId::BinanceSpot,
+            time: Utc::now(),
+        };
+
+        struct TestCase {
+            input: Vec<StreamEvent<u64>>,
+            expected: Vec<u64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: five events of one stream are sequenced 0..5
+                input: (0..5).map(|id| event("btc", id).into()).collect(),
+                expected: vec![0, 1, 2, 3, 4],
+            },
+            TestCase {
+                // TC1: another stream is sequenced independently from zero
+                input: vec![event("eth", 0).into(), event("btc", 5).into()],
+                expected: vec![0, 5],
+            },
+            TestCase {
+                // TC2: every stream of the exchange is reset after a re-connection
+                input: vec![
+                    reconnected,
+                    event("btc", 6).into(),
+                    event("eth", 1).into(),
+                    event("btc", 7).into(),
+                ],
+                expected: vec![0, 0, 1],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = sequences(&mut adapter, test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
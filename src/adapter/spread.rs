@@ -0,0 +1,210 @@
+use super::Adapter;
+use crate::{
+    event::MarketEvent,
+    subscription::book::{OrderBook, OrderBookL1},
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+/// Spread of an instrument's order book in basis points, alongside the average spread over the
+/// configured [`SpreadAdapter`] window.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct SpreadBps {
+    pub spread_bps: f64,
+    pub avg_spread_bps_window: f64,
+}
+
+/// [`Adapter`] that consumes [`OrderBook`] or [`OrderBookL1`] [`MarketEvent`]s and emits a
+/// [`MarketEvent<SpreadBps>`] every time an instrument's spread changes.
+///
+/// ### Notes
+/// - Spreads are tracked independently for every exchange & instrument combination.
+/// - The rolling average is the mean of the spread samples with an `exchange_time` within the
+///   configured `window` of the latest sample.
+/// - One-sided or empty books are skipped without emitting.
+#[derive(Clone, Debug)]
+pub struct SpreadAdapter {
+    window: Duration,
+    spreads: HashMap<(Exchange, Arc<Instrument>), SpreadWindow>,
+}
+
+/// Spread samples of a single exchange & instrument combination within the averaging window.
+#[derive(Clone, Debug, Default)]
+struct SpreadWindow {
+    samples: VecDeque<(DateTime<Utc>, f64)>,
+    sum: f64,
+}
+
+impl SpreadWindow {
+    /// Insert a new spread sample, evict samples that have fallen out of the window, and return
+    /// the new rolling average.
+    fn update(&mut self, time: DateTime<Utc>, spread_bps: f64, window: Duration) -> f64 {
+        self.samples.push_back((time, spread_bps));
+        self.sum += spread_bps;
+
+        let window = chrono::Duration::from_std(window).unwrap_or(chrono::Duration::MAX);
+        while let Some((oldest, _)) = self.samples.front() {
+            if time.signed_duration_since(*oldest) <= window {
+                break;
+            }
+            if let Some((_, evicted)) = self.samples.pop_front() {
+                self.sum -= evicted;
+            }
+        }
+
+        self.sum / self.samples.len() as f64
+    }
+
+    /// Latest spread sample, if any.
+    fn last(&self) -> Option<f64> {
+        self.samples.back().map(|(_, spread_bps)| *spread_bps)
+    }
+}
+
+impl SpreadAdapter {
+    /// Construct a new [`Self`] that averages spreads over the provided `window`.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            spreads: HashMap::new(),
+        }
+    }
+
+    fn update<T>(
+        &mut self,
+        event: MarketEvent<T>,
+        spread_bps: Option<f64>,
+    ) -> Option<MarketEvent<SpreadBps>> {
+        // Skip one-sided or empty books
+        let spread_bps = spread_bps?;
+
+        let spreads = self
+            .spreads
+            .entry((event.exchange.clone(), event.instrument.clone()))
+            .or_default();
+
+        // Only emit if the spread has changed
+        if spreads.last() == Some(spread_bps) {
+            return None;
+        }
+
+        let avg_spread_bps_window = spreads.update(event.exchange_time, spread_bps, self.window);
+
+        Some(MarketEvent {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: SpreadBps {
+                spread_bps,
+                avg_spread_bps_window,
+            },
+        })
+    }
+}
+
+impl Adapter<MarketEvent<OrderBook>> for SpreadAdapter {
+    type Output = MarketEvent<SpreadBps>;
+
+    fn adapt(&mut self, input: MarketEvent<OrderBook>) -> Option<Self::Output> {
+        let spread_bps = input.kind.spread_bps();
+        self.update(input, spread_bps)
+    }
+}
+
+impl Adapter<MarketEvent<OrderBookL1>> for SpreadAdapter {
+    type Output = MarketEvent<SpreadBps>;
+
+    fn adapt(&mut self, input: MarketEvent<OrderBookL1>) -> Option<Self::Output> {
+        let spread_bps = input.kind.spread_bps();
+        self.update(input, spread_bps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{book_event, secs};
+
+    #[test]
+    fn test_spread_adapter() {
+        struct TestCase {
+            input: MarketEvent<OrderBook>,
+            expected: Option<SpreadBps>,
+        }
+
+        let mut adapter = SpreadAdapter::new(Duration::from_secs(10));
+
+        let tests = vec![
+            TestCase {
+                // TC0: first spread sample of 10bps
+                input: book_event(secs(0), vec![(99.95, 1.0)], vec![(100.05, 1.0)]),
+                expected: Some(SpreadBps {
+                    spread_bps: 10.0,
+                    avg_spread_bps_window: 10.0,
+                }),
+            },
+            TestCase {
+                // TC1: unchanged spread is not emitted
+                input: book_event(secs(1), vec![(99.95, 2.0)], vec![(100.05, 3.0)]),
+                expected: None,
+            },
+            TestCase {
+                // TC2: spread widens to 30bps, average of 10bps & 30bps
+                input: book_event(secs(5), vec![(99.85, 1.0)], vec![(100.15, 1.0)]),
+                expected: Some(SpreadBps {
+                    spread_bps: 30.0,
+                    avg_spread_bps_window: 20.0,
+                }),
+            },
+            TestCase {
+                // TC3: one-sided book is skipped
+                input: book_event(secs(6), vec![(99.85, 1.0)], vec![]),
+                expected: None,
+            },
+            TestCase {
+                // TC4: empty book is skipped
+                input: book_event(secs(7), vec![], vec![]),
+                expected: None,
+            },
+            TestCase {
+                // TC5: spread tightens to 20bps, 10bps sample has fallen out of the window
+                input: book_event(secs(12), vec![(99.9, 1.0)], vec![(100.1, 1.0)]),
+                expected: Some(SpreadBps {
+                    spread_bps: 20.0,
+                    avg_spread_bps_window: 25.0,
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = adapter.adapt(test.input).map(|event| event.kind);
+            match (actual, test.expected) {
+                (Some(actual), Some(expected)) => {
+                    assert!(
+                        (actual.spread_bps - expected.spread_bps).abs() < 1e-9,
+                        "TC{index} failed spread_bps: {actual:?} != {expected:?}"
+                    );
+                    assert!(
+                        (actual.avg_spread_bps_window - expected.avg_spread_bps_window).abs()
+                            < 1e-9,
+                        "TC{index} failed avg_spread_bps_window: {actual:?} != {expected:?}"
+                    );
+                }
+                (None, None) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
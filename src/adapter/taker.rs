@@ -0,0 +1,264 @@
+use super::Adapter;
+use crate::{event::MarketEvent, subscription::trade::PublicTrade};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+/// [`Adapter`] that collapses consecutive [`PublicTrade`]s from the same aggressor into a single
+/// logical taker order [`PublicTrade`], reconstructing taker intent on exchanges that only
+/// publish individual fills.
+///
+/// ### Notes
+/// - Consecutive trades are grouped if they have the same [`Side`], each arrives within the
+///   configured `window` of the first trade in the group (so a group can never outlive the
+///   `window`), and their price does not move against the aggressor (ie/ buys walk up the book,
+///   sells walk down the book).
+/// - The aggregated [`PublicTrade`] has the summed amount, the volume weighted average price, and
+///   the id of the first trade in the group. It is emitted once a trade that cannot join the
+///   group arrives, via [`TakerOrderAdapter::flush`], or once the input stream of an
+///   [`AdaptedStream`](super::AdaptedStream) ends.
+/// - Groups are tracked independently for every exchange & instrument combination.
+#[derive(Clone, Debug)]
+pub struct TakerOrderAdapter {
+    window: Duration,
+    groups: HashMap<(Exchange, Arc<Instrument>), TakerOrder>,
+}
+
+/// Fills of a single logical taker order.
+#[derive(Clone, Debug)]
+struct TakerOrder {
+    first: MarketEvent<PublicTrade>,
+    last_price: f64,
+    amount: f64,
+    notional: f64,
+}
+
+impl TakerOrder {
+    fn new(trade: MarketEvent<PublicTrade>) -> Self {
+        Self {
+            last_price: trade.kind.price,
+            amount: trade.kind.amount,
+            notional: trade.kind.price * trade.kind.amount,
+            first: trade,
+        }
+    }
+
+    /// Determine if the provided trade was part of the same logical taker order.
+    fn accepts(&self, trade: &MarketEvent<PublicTrade>, window: chrono::Duration) -> bool {
+        let same_side = self.first.kind.side == trade.kind.side;
+        let within_window = trade
+            .exchange_time
+            .signed_duration_since(self.first.exchange_time)
+            <= window;
+        let crosses = match trade.kind.side {
+            Side::Buy => trade.kind.price >= self.last_price,
+            Side::Sell => trade.kind.price <= self.last_price,
+        };
+
+        same_side && within_window && crosses
+    }
+
+    fn push(&mut self, trade: &MarketEvent<PublicTrade>) {
+        self.last_price = trade.kind.price;
+        self.amount += trade.kind.amount;
+        self.notional += trade.kind.price * trade.kind.amount;
+    }
+
+    fn into_event(self) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange_time: self.first.exchange_time,
+            received_time: self.first.received_time,
+            exchange: self.first.exchange,
+            instrument: self.first.instrument,
+            kind: PublicTrade {
+                id: self.first.kind.id,
+                price: self.notional / self.amount,
+                amount: self.amount,
+                side: self.first.kind.side,
+                conditions: vec![],
+            },
+        }
+    }
+}
+
+impl TakerOrderAdapter {
+    /// Construct a new [`Self`] that groups trades arriving within the provided `window` of each
+    /// other (eg/ 1ms).
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            groups: HashMap::new(),
+        }
+    }
+
+    /// Emit all open taker order groups (eg/ at the end of a replay).
+    pub fn flush(&mut self) -> Vec<MarketEvent<PublicTrade>> {
+        self.groups
+            .drain()
+            .map(|(_, group)| group.into_event())
+            .collect()
+    }
+}
+
+impl Adapter<MarketEvent<PublicTrade>> for TakerOrderAdapter {
+    type Output = MarketEvent<PublicTrade>;
+
+    fn adapt(&mut self, input: MarketEvent<PublicTrade>) -> Option<Self::Output> {
+        let window = chrono::Duration::from_std(self.window).unwrap_or(chrono::Duration::MAX);
+        let key = (input.exchange.clone(), input.instrument.clone());
+
+        match self.groups.get_mut(&key) {
+            Some(group) if group.accepts(&input, window) => {
+                group.push(&input);
+                None
+            }
+            _ => self
+                .groups
+                .insert(key, TakerOrder::new(input))
+                .map(TakerOrder::into_event),
+        }
+    }
+
+    fn finish(&mut self) -> Vec<Self::Output> {
+        self.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adapter::AdapterExt,
+        approx::{ApproxEq, DEFAULT_EPSILON},
+        fixtures,
+    };
+    use chrono::{DateTime, Utc};
+    use futures::StreamExt;
+
+    fn trade(
+        id: &str,
+        micros: i64,
+        side: Side,
+        price: f64,
+        amount: f64,
+    ) -> MarketEvent<PublicTrade> {
+        let time = DateTime::<Utc>::from_timestamp_micros(micros).unwrap();
+        fixtures::trade(time, id, price, amount, side)
+    }
+
+    #[test]
+    fn test_taker_order_adapter() {
+        struct TestCase {
+            input: MarketEvent<PublicTrade>,
+            expected: Option<PublicTrade>,
+        }
+
+        let mut adapter = TakerOrderAdapter::new(Duration::from_millis(1));
+
+        let tests = vec![
+            TestCase {
+                // TC0: first buy fill opens a group
+                input: trade("1", 0, Side::Buy, 100.0, 1.0),
+                expected: None,
+            },
+            TestCase {
+                // TC1: buy fill walking up the book joins the group
+                input: trade("2", 200, Side::Buy, 101.0, 1.0),
+                expected: None,
+            },
+            TestCase {
+                // TC2: buy fill at the same price joins the group
+                input: trade("3", 400, Side::Buy, 101.0, 2.0),
+                expected: None,
+            },
+            TestCase {
+                // TC3: sell fill splits the group, emitting the aggregated buy taker order
+                input: trade("4", 500, Side::Sell, 100.5, 1.0),
+                expected: Some(PublicTrade {
+                    id: "1".to_string(),
+                    price: (100.0 + 101.0 + 202.0) / 4.0,
+                    amount: 4.0,
+                    side: Side::Buy,
+                    conditions: vec![],
+                }),
+            },
+            TestCase {
+                // TC4: sell fill outside the window starts a new group
+                input: trade("5", 2_000, Side::Sell, 100.0, 1.0),
+                expected: Some(PublicTrade {
+                    id: "4".to_string(),
+                    price: 100.5,
+                    amount: 1.0,
+                    side: Side::Sell,
+                    conditions: vec![],
+                }),
+            },
+            TestCase {
+                // TC5: sell fill moving against the aggressor starts a new group
+                input: trade("6", 2_100, Side::Sell, 100.2, 1.0),
+                expected: Some(PublicTrade {
+                    id: "5".to_string(),
+                    price: 100.0,
+                    amount: 1.0,
+                    side: Side::Sell,
+                    conditions: vec![],
+                }),
+            },
+            TestCase {
+                // TC6: sell fill within the window of the previous fill joins the group
+                input: trade("7", 2_900, Side::Sell, 100.2, 1.0),
+                expected: None,
+            },
+            TestCase {
+                // TC7: sell fill outside the window of the first fill starts a new group, even
+                // though it is within the window of the previous fill
+                input: trade("8", 3_700, Side::Sell, 100.2, 1.0),
+                expected: Some(PublicTrade {
+                    id: "6".to_string(),
+                    price: 100.2,
+                    amount: 2.0,
+                    side: Side::Sell,
+                    conditions: vec![],
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = adapter.adapt(test.input).map(|event| event.kind);
+            match (actual, test.expected) {
+                (Some(actual), Some(expected)) => {
+                    assert!(
+                        actual.approx_eq(&expected, DEFAULT_EPSILON),
+                        "TC{index} failed: {actual:?} != {expected:?}"
+                    );
+                }
+                (None, None) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+
+        // Open group is emitted on flush
+        let flushed = adapter.flush();
+        assert_eq!(flushed.len(), 1);
+        assert_eq!(flushed[0].kind.id, "8");
+    }
+
+    #[tokio::test]
+    async fn test_adapted_stream_emits_open_taker_order_on_end() {
+        let actual = futures::stream::iter([
+            trade("1", 0, Side::Buy, 100.0, 1.0),
+            trade("2", 200, Side::Buy, 101.0, 1.0),
+        ])
+        .adapt(TakerOrderAdapter::new(Duration::from_millis(1)))
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].kind.id, "1");
+        assert_eq!(actual[0].kind.amount, 2.0);
+    }
+}
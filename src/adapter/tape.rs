@@ -0,0 +1,329 @@
+use super::Adapter;
+use crate::{event::MarketEvent, subscription::trade::PublicTrade};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+/// Time-and-sales tape statistics of an instrument over the configured [`TapeStatsAdapter`]
+/// window.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct TapeStats {
+    pub trades_per_sec: f64,
+    pub avg_size: f64,
+    pub window: Duration,
+}
+
+/// [`Adapter`] that consumes [`PublicTrade`] [`MarketEvent`]s and emits a
+/// [`MarketEvent<TapeStats>`] for every elapsed `interval` of each instrument, describing the
+/// trade rate & average trade size over the rolling `window` ending at that interval.
+///
+/// Useful for activity monitoring (eg/ detecting when a market wakes up).
+///
+/// ### Notes
+/// - Tapes are tracked independently for every exchange & instrument combination, with
+///   intervals starting from the first trade of each.
+/// - Intervals elapse by trade `exchange_time`s, and by the time provided to
+///   [`Adapter::tick`]. Use [`AdapterExt::adapt_with_ticks`](super::AdapterExt::adapt_with_ticks)
+///   with the `interval` as the period so the [`TapeStats`] of a quiet period are emitted on
+///   time, rather than when the next trade arrives.
+/// - The rate falls to zero once the `window` contains no trades, after which further quiet
+///   intervals are skipped until the next trade.
+#[derive(Clone, Debug)]
+pub struct TapeStatsAdapter {
+    window: Duration,
+    interval: Duration,
+    tapes: HashMap<(Exchange, Arc<Instrument>), Tape>,
+}
+
+/// Trades of a single exchange & instrument combination within the window.
+#[derive(Clone, Debug)]
+struct Tape {
+    trades: VecDeque<(DateTime<Utc>, f64)>,
+    volume: f64,
+    next_interval: DateTime<Utc>,
+    quiet: bool,
+}
+
+impl Tape {
+    fn new(next_interval: DateTime<Utc>) -> Self {
+        Self {
+            trades: VecDeque::new(),
+            volume: 0.0,
+            next_interval,
+            quiet: false,
+        }
+    }
+
+    fn push(&mut self, time: DateTime<Utc>, amount: f64) {
+        self.trades.push_back((time, amount));
+        self.volume += amount;
+        self.quiet = false;
+    }
+
+    /// Return the interval end & [`TapeStats`] of every interval that has elapsed before the
+    /// provided time, only including the first interval of a quiet period.
+    fn elapse(
+        &mut self,
+        time: DateTime<Utc>,
+        window: Duration,
+        interval: Duration,
+    ) -> Vec<(DateTime<Utc>, TapeStats)> {
+        let lookback = chrono::Duration::from_std(window).unwrap_or(chrono::Duration::MAX);
+        let step = chrono::Duration::from_std(interval).unwrap_or(chrono::Duration::MAX);
+
+        let mut elapsed = vec![];
+        while self.next_interval < time {
+            let interval_end = self.next_interval;
+            self.evict(interval_end - lookback);
+            self.next_interval = interval_end + step;
+
+            if !self.quiet {
+                elapsed.push((interval_end, self.stats(window)));
+            }
+            self.quiet = self.trades.is_empty();
+
+            // Skip the remaining intervals of a quiet period, since their rate is also zero
+            if self.quiet && self.next_interval < time {
+                let remaining = (time - self.next_interval).num_nanoseconds();
+                let skipped = remaining
+                    .zip(step.num_nanoseconds())
+                    .map(|(remaining, step)| (remaining - 1) / step + 1)
+                    .and_then(|skipped| i32::try_from(skipped).ok())
+                    .unwrap_or(i32::MAX);
+                self.next_interval += step * skipped;
+            }
+        }
+        elapsed
+    }
+
+    /// Evict trades with an `exchange_time` at or before the provided cutoff.
+    fn evict(&mut self, cutoff: DateTime<Utc>) {
+        while let Some((oldest, _)) = self.trades.front() {
+            if *oldest > cutoff {
+                break;
+            }
+            if let Some((_, evicted)) = self.trades.pop_front() {
+                self.volume -= evicted;
+            }
+        }
+    }
+
+    fn stats(&self, window: Duration) -> TapeStats {
+        let trades = self.trades.len() as f64;
+        TapeStats {
+            trades_per_sec: trades / window.as_secs_f64(),
+            avg_size: if trades > 0.0 {
+                self.volume / trades
+            } else {
+                0.0
+            },
+            window,
+        }
+    }
+}
+
+impl TapeStatsAdapter {
+    /// Construct a new [`Self`] that emits [`TapeStats`] over the provided rolling `window`
+    /// (eg/ 60s) every `interval` (eg/ 1s).
+    ///
+    /// ### Panics
+    /// Panics if either the `window` or `interval` is zero.
+    pub fn new(window: Duration, interval: Duration) -> Self {
+        assert!(
+            !window.is_zero() && !interval.is_zero(),
+            "TapeStatsAdapter window & interval must be non-zero"
+        );
+        Self {
+            window,
+            interval,
+            tapes: HashMap::new(),
+        }
+    }
+}
+
+impl Adapter<MarketEvent<PublicTrade>> for TapeStatsAdapter {
+    type Output = Vec<MarketEvent<TapeStats>>;
+
+    fn adapt(&mut self, input: MarketEvent<PublicTrade>) -> Option<Self::Output> {
+        let interval = chrono::Duration::from_std(self.interval).unwrap_or(chrono::Duration::MAX);
+        let time = input.exchange_time;
+
+        let tape = self
+            .tapes
+            .entry((input.exchange.clone(), input.instrument.clone()))
+            .or_insert_with(|| Tape::new(time + interval));
+
+        // Emit the TapeStats of every interval that has elapsed before this trade
+        let output = tape
+            .elapse(time, self.window, self.interval)
+            .into_iter()
+            .map(|(interval_end, stats)| MarketEvent {
+                exchange_time: interval_end,
+                received_time: input.received_time,
+                exchange: input.exchange.clone(),
+                instrument: input.instrument.clone(),
+                kind: stats,
+            })
+            .collect::<Vec<_>>();
+
+        tape.push(time, input.kind.amount);
+
+        (!output.is_empty()).then_some(output)
+    }
+
+    fn tick(&mut self, now: DateTime<Utc>) -> Vec<Self::Output> {
+        self.tapes
+            .iter_mut()
+            .filter_map(|((exchange, instrument), tape)| {
+                let output = tape
+                    .elapse(now, self.window, self.interval)
+                    .into_iter()
+                    .map(|(interval_end, stats)| MarketEvent {
+                        exchange_time: interval_end,
+                        received_time: now,
+                        exchange: exchange.clone(),
+                        instrument: instrument.clone(),
+                        kind: stats,
+                    })
+                    .collect::<Vec<_>>();
+
+                (!output.is_empty()).then_some(output)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{adapter::AdapterExt, fixtures};
+    use barter_integration::model::Side;
+    use futures::StreamExt;
+
+    fn trade(millis: i64, amount: f64) -> MarketEvent<PublicTrade> {
+        fixtures::trade(
+            fixtures::millis(millis),
+            &millis.to_string(),
+            100.0,
+            amount,
+            Side::Buy,
+        )
+    }
+
+    fn stats(
+        outputs: impl IntoIterator<Item = Vec<MarketEvent<TapeStats>>>,
+    ) -> Vec<(i64, f64, f64)> {
+        outputs
+            .into_iter()
+            .flatten()
+            .map(|event| {
+                (
+                    event.exchange_time.timestamp_millis(),
+                    event.kind.trades_per_sec,
+                    event.kind.avg_size,
+                )
+            })
+            .collect()
+    }
+
+    fn adapt(
+        adapter: &mut TapeStatsAdapter,
+        trades: impl IntoIterator<Item = (i64, f64)>,
+    ) -> Vec<(i64, f64, f64)> {
+        stats(
+            trades
+                .into_iter()
+                .filter_map(|(millis, amount)| adapter.adapt(trade(millis, amount)))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn test_tape_stats_adapter_burst_then_quiet() {
+        struct TestCase {
+            actual: Vec<(i64, f64, f64)>,
+            expected: Vec<(i64, f64, f64)>,
+        }
+
+        let mut adapter = TapeStatsAdapter::new(Duration::from_secs(1), Duration::from_secs(1));
+
+        // Slow trades, followed by a burst of 9 trades
+        let burst = adapt(
+            &mut adapter,
+            [(0, 2.0), (800, 2.0)]
+                .into_iter()
+                .chain((1100..=1900).step_by(100).map(|millis| (millis, 1.0))),
+        );
+
+        // Quiet period without trades, with intervals elapsed by ticks
+        let quiet = stats(adapter.tick(fixtures::millis(3_500)));
+        let still_quiet = stats(adapter.tick(fixtures::millis(4_500)));
+
+        // Market wakes up again
+        let wake = adapt(
+            &mut adapter,
+            [(5_000, 3.0), (5_500, 3.0), (5_700, 3.0), (6_200, 3.0)],
+        );
+
+        let tests = vec![
+            TestCase {
+                // TC0: slow market, with a single trade in the window
+                actual: burst,
+                expected: vec![(1_000, 1.0, 2.0)],
+            },
+            TestCase {
+                // TC1: burst rate, followed by the zero rate of the quiet period without a trade
+                actual: quiet,
+                expected: vec![(2_000, 9.0, 1.0), (3_000, 0.0, 0.0)],
+            },
+            TestCase {
+                // TC2: further quiet intervals are skipped
+                actual: still_quiet,
+                expected: vec![],
+            },
+            TestCase {
+                // TC3: rate rises once the market wakes up again
+                actual: wake,
+                expected: vec![(5_000, 1.0, 3.0), (6_000, 2.0, 3.0)],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                test.actual.len(),
+                test.expected.len(),
+                "TC{index} failed. Actual: {:?}",
+                test.actual
+            );
+            for (actual, expected) in test.actual.into_iter().zip(test.expected) {
+                assert_eq!(actual.0, expected.0, "TC{index} failed");
+                assert!(
+                    (actual.1 - expected.1).abs() < 1e-9 && (actual.2 - expected.2).abs() < 1e-9,
+                    "TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n"
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_adapted_stream_ticks_quiet_tape() {
+        // Inner stream goes quiet after a single trade, without ending
+        let mut stream = futures::stream::iter([trade(500, 2.0)])
+            .chain(futures::stream::pending())
+            .adapt_with_ticks(
+                TapeStatsAdapter::new(Duration::from_secs(2), Duration::from_secs(1)),
+                Duration::from_millis(10),
+            );
+
+        let actual = tokio::time::timeout(Duration::from_secs(1), stream.next())
+            .await
+            .expect("quiet interval was not emitted by a tick");
+
+        assert_eq!(stats(actual), vec![(1_500, 0.5, 2.0), (2_500, 0.0, 0.0)]);
+    }
+}
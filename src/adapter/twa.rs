@@ -0,0 +1,314 @@
+use super::Adapter;
+use crate::{
+    event::MarketEvent,
+    subscription::book::{mid_price, OrderBook, OrderBookL1},
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+/// Time-weighted average mid price of an instrument's order book over the configured
+/// [`TwaMidAdapter`] `window`.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct TwaMid {
+    pub twa_mid: f64,
+    pub window: Duration,
+}
+
+/// [`Adapter`] that consumes [`OrderBook`] or [`OrderBookL1`] [`MarketEvent`]s and emits the
+/// [`TwaMid`] over the configured rolling `window` at most once every `interval` per instrument.
+///
+/// Each mid price is weighted by how long it was in effect (ie/ until the next mid change, or
+/// until the sample time for the latest mid).
+///
+/// ### Notes
+/// - Mids are tracked independently for every exchange & instrument combination.
+/// - Intervals & durations are driven by [`MarketEvent`] `exchange_time`s, with the first book of
+///   each instrument sampled immediately.
+/// - Intervals also elapse by the time provided to [`Adapter::tick`], which samples the
+///   [`TwaMid`] of each instrument up to that time, with the latest mid in effect until then. Use
+///   [`AdapterExt::adapt_with_ticks`](super::AdapterExt::adapt_with_ticks) with the `interval` as
+///   the period so the [`TwaMid`] of a book without updates is still sampled every `interval`.
+/// - Until a full `window` of mids has been observed, the average is taken over the observed
+///   duration. If no time has elapsed since the first mid, the [`TwaMid`] is that mid.
+/// - One-sided or empty books are skipped without updating the mid.
+#[derive(Clone, Debug)]
+pub struct TwaMidAdapter {
+    window: Duration,
+    interval: Duration,
+    mids: HashMap<(Exchange, Arc<Instrument>), MidWindow>,
+}
+
+/// Mid price changes of a single exchange & instrument combination, alongside the time of the
+/// previously emitted [`TwaMid`].
+#[derive(Clone, Debug, Default)]
+struct MidWindow {
+    changes: VecDeque<(DateTime<Utc>, f64)>,
+    last_sample: Option<DateTime<Utc>>,
+}
+
+impl MidWindow {
+    /// Record the mid in effect from the provided time, if it differs from the current mid.
+    fn update(&mut self, time: DateTime<Utc>, mid: f64) {
+        if self.changes.back().map(|(_, current)| *current) != Some(mid) {
+            self.changes.push_back((time, mid));
+        }
+    }
+
+    /// Evict mid changes no longer in effect within the `window` ending at `now`, and return the
+    /// time-weighted average mid over that window.
+    fn twa(&mut self, now: DateTime<Utc>, window: chrono::Duration) -> Option<f64> {
+        let start = now - window;
+
+        // Retain the latest mid change at or before the window start, since it remains in effect
+        while self.changes.len() > 1 && self.changes[1].0 <= start {
+            self.changes.pop_front();
+        }
+
+        let (mut weighted_sum, mut total_ms) = (0.0, 0.0);
+        for (index, (from, mid)) in self.changes.iter().enumerate() {
+            let from = (*from).max(start);
+            let until = self
+                .changes
+                .get(index + 1)
+                .map_or(now, |(next, _)| *next)
+                .min(now);
+
+            let duration_ms = until.signed_duration_since(from).num_milliseconds().max(0) as f64;
+            weighted_sum += mid * duration_ms;
+            total_ms += duration_ms;
+        }
+
+        match total_ms > 0.0 {
+            true => Some(weighted_sum / total_ms),
+            false => self.changes.back().map(|(_, mid)| *mid),
+        }
+    }
+}
+
+impl TwaMidAdapter {
+    /// Construct a new [`Self`] that emits the [`TwaMid`] over the rolling `window` (eg/ 60s)
+    /// every `interval` (eg/ 1s).
+    pub fn new(window: Duration, interval: Duration) -> Self {
+        Self {
+            window,
+            interval,
+            mids: HashMap::new(),
+        }
+    }
+
+    fn update<T>(
+        &mut self,
+        event: MarketEvent<T>,
+        mid: Option<f64>,
+    ) -> Option<MarketEvent<TwaMid>> {
+        // Skip one-sided or empty books
+        let mid = mid?;
+
+        let mids = self
+            .mids
+            .entry((event.exchange.clone(), event.instrument.clone()))
+            .or_default();
+        mids.update(event.exchange_time, mid);
+
+        // Only sample once the interval since the previous sample has elapsed
+        let interval = chrono::Duration::from_std(self.interval).unwrap_or(chrono::Duration::MAX);
+        if let Some(last_sample) = mids.last_sample {
+            if event.exchange_time.signed_duration_since(last_sample) < interval {
+                return None;
+            }
+        }
+        mids.last_sample = Some(event.exchange_time);
+
+        let window = chrono::Duration::from_std(self.window).unwrap_or(chrono::Duration::MAX);
+        let twa_mid = mids.twa(event.exchange_time, window)?;
+
+        Some(MarketEvent {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: TwaMid {
+                twa_mid,
+                window: self.window,
+            },
+        })
+    }
+
+    /// Sample the [`TwaMid`] up to `now` of every instrument whose interval since the previous
+    /// sample has elapsed.
+    fn sample(&mut self, now: DateTime<Utc>) -> Vec<MarketEvent<TwaMid>> {
+        let interval = chrono::Duration::from_std(self.interval).unwrap_or(chrono::Duration::MAX);
+        let window = chrono::Duration::from_std(self.window).unwrap_or(chrono::Duration::MAX);
+
+        let mut output = vec![];
+        for ((exchange, instrument), mids) in self.mids.iter_mut() {
+            if mids
+                .last_sample
+                .is_some_and(|last_sample| now.signed_duration_since(last_sample) < interval)
+            {
+                continue;
+            }
+            mids.last_sample = Some(now);
+
+            if let Some(twa_mid) = mids.twa(now, window) {
+                output.push(MarketEvent {
+                    exchange_time: now,
+                    received_time: now,
+                    exchange: exchange.clone(),
+                    instrument: instrument.clone(),
+                    kind: TwaMid {
+                        twa_mid,
+                        window: self.window,
+                    },
+                });
+            }
+        }
+        output
+    }
+}
+
+impl Adapter<MarketEvent<OrderBook>> for TwaMidAdapter {
+    type Output = MarketEvent<TwaMid>;
+
+    fn adapt(&mut self, input: MarketEvent<OrderBook>) -> Option<Self::Output> {
+        let mid = match (
+            input.kind.bids.levels.first(),
+            input.kind.asks.levels.first(),
+        ) {
+            (Some(best_bid), Some(best_ask)) => Some(mid_price(best_bid.price, best_ask.price)),
+            _ => None,
+        };
+        self.update(input, mid)
+    }
+
+    fn tick(&mut self, now: DateTime<Utc>) -> Vec<Self::Output> {
+        self.sample(now)
+    }
+}
+
+impl Adapter<MarketEvent<OrderBookL1>> for TwaMidAdapter {
+    type Output = MarketEvent<TwaMid>;
+
+    fn adapt(&mut self, input: MarketEvent<OrderBookL1>) -> Option<Self::Output> {
+        let mid = input.kind.mid_price();
+        self.update(input, Some(mid))
+    }
+
+    fn tick(&mut self, now: DateTime<Utc>) -> Vec<Self::Output> {
+        self.sample(now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{book_event, secs};
+
+    fn mid_event(seconds: i64, mid: f64) -> MarketEvent<OrderBook> {
+        book_event(
+            secs(seconds),
+            vec![(mid - 1.0, 1.0)],
+            vec![(mid + 1.0, 1.0)],
+        )
+    }
+
+    #[test]
+    fn test_twa_mid_adapter() {
+        struct TestCase {
+            input: MarketEvent<OrderBook>,
+            expected: Option<f64>,
+        }
+
+        let mut adapter = TwaMidAdapter::new(Duration::from_secs(10), Duration::from_secs(5));
+
+        let tests = vec![
+            TestCase {
+                // TC0: first mid is sampled immediately, with no elapsed time to weight
+                input: mid_event(0, 100.0),
+                expected: Some(100.0),
+            },
+            TestCase {
+                // TC1: mid change within the interval of the previous sample is not sampled
+                input: mid_event(2, 110.0),
+                expected: None,
+            },
+            TestCase {
+                // TC2: 100 for 2s & 110 for 3s over the 5s observed so far
+                input: mid_event(5, 110.0),
+                expected: Some((100.0 * 2.0 + 110.0 * 3.0) / 5.0),
+            },
+            TestCase {
+                // TC3: one-sided book is skipped
+                input: book_event(secs(11), vec![(99.0, 1.0)], vec![]),
+                expected: None,
+            },
+            TestCase {
+                // TC4: mid change within the interval of the previous sample is not sampled
+                input: mid_event(8, 90.0),
+                expected: None,
+            },
+            TestCase {
+                // TC5: window [2s, 12s]: 110 from 2s to 8s, then 90 from 8s to 12s
+                input: mid_event(12, 90.0),
+                expected: Some((110.0 * 6.0 + 90.0 * 4.0) / 10.0),
+            },
+            TestCase {
+                // TC6: mid change within the interval of the previous sample is not sampled
+                input: mid_event(15, 120.0),
+                expected: None,
+            },
+            TestCase {
+                // TC7: window [10s, 20s]: 90 from 10s to 15s, then 120 from 15s to 20s, with the
+                // mid at the sample time in effect for no time within the window
+                input: mid_event(20, 100.0),
+                expected: Some((90.0 * 5.0 + 120.0 * 5.0) / 10.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = adapter.adapt(test.input).map(|event| event.kind);
+            match (actual, test.expected) {
+                (Some(actual), Some(expected)) => {
+                    assert!(
+                        (actual.twa_mid - expected).abs() < 1e-9,
+                        "TC{index} failed twa_mid: {actual:?} != {expected:?}"
+                    );
+                    assert_eq!(actual.window, Duration::from_secs(10), "TC{index} failed");
+                }
+                (None, None) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+
+        // TC8: tick within the interval of the previous sample does not sample
+        assert!(
+            <TwaMidAdapter as Adapter<MarketEvent<OrderBook>>>::tick(&mut adapter, secs(24))
+                .is_empty(),
+            "TC8 failed"
+        );
+
+        // TC9: tick once the interval has elapsed samples without a further book, with the mid
+        // of 100 in effect from 20s until the tick. Window [15s, 25s]: 120 from 15s to 20s, then
+        // 100 from 20s to 25s
+        let actual =
+            <TwaMidAdapter as Adapter<MarketEvent<OrderBook>>>::tick(&mut adapter, secs(25));
+        assert_eq!(actual.len(), 1, "TC9 failed");
+        assert_eq!(actual[0].exchange_time, secs(25), "TC9 failed");
+        assert!(
+            (actual[0].kind.twa_mid - (120.0 * 5.0 + 100.0 * 5.0) / 10.0).abs() < 1e-9,
+            "TC9 failed twa_mid: {:?}",
+            actual[0].kind
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    event::MarketEvent,
+    subscription::{
+        book::{Level, OrderBook, OrderBookL1},
+        trade::PublicTrade,
+    },
+};
+
+/// Default tolerance used when comparing normalised prices & quantities (see [`approx_eq`]).
+pub const DEFAULT_EPSILON: f64 = 1e-9;
+
+/// Determine if the provided floats are equal within the absolute tolerance `epsilon`.
+///
+/// `NaN` is never approximately equal to anything, including itself.
+pub fn approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    (a - b).abs() <= epsilon
+}
+
+/// Tolerance based equality for normalised data containing `f64` prices & quantities, where the
+/// derived [`PartialEq`] is brittle (eg/ prices derived by arithmetic such as a VWAP).
+///
+/// Non-float fields (eg/ ids, sides & timestamps) must be exactly equal.
+pub trait ApproxEq {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool;
+}
+
+impl ApproxEq for f64 {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        approx_eq(*self, *other, epsilon)
+    }
+}
+
+impl<T> ApproxEq for Option<T>
+where
+    T: ApproxEq,
+{
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        match (self, other) {
+            (Some(this), Some(other)) => this.approx_eq(other, epsilon),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T> ApproxEq for [T]
+where
+    T: ApproxEq,
+{
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(this, other)| this.approx_eq(other, epsilon))
+    }
+}
+
+impl ApproxEq for PublicTrade {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.id == other.id
+            && self.side == other.side
+            && self.conditions == other.conditions
+            && approx_eq(self.price, other.price, epsilon)
+            && approx_eq(self.amount, other.amount, epsilon)
+    }
+}
+
+impl ApproxEq for Level {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        approx_eq(self.price, other.price, epsilon) && approx_eq(self.amount, other.amount, epsilon)
+    }
+}
+
+impl ApproxEq for OrderBookL1 {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.last_update_time == other.last_update_time
+            && self.best_bid.approx_eq(&other.best_bid, epsilon)
+            && self.best_ask.approx_eq(&other.best_ask, epsilon)
+    }
+}
+
+impl ApproxEq for OrderBook {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.last_update_time == other.last_update_time
+            && self.bids.levels.approx_eq(&other.bids.levels, epsilon)
+            && self.asks.levels.approx_eq(&other.asks.levels, epsilon)
+    }
+}
+
+impl<T> ApproxEq for MarketEvent<T>
+where
+    T: ApproxEq,
+{
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.exchange_time == other.exchange_time
+            && self.received_time == other.received_time
+            && self.exchange == other.exchange
+            && self.instrument == other.instrument
+            && self.kind.approx_eq(&other.kind, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::book;
+    use barter_integration::model::Side;
+    use chrono::Utc;
+
+    fn trade(price: f64, amount: f64) -> PublicTrade {
+        PublicTrade {
+            id: "id".to_string(),
+            price,
+            amount,
+            side: Side::Buy,
+            conditions: vec![],
+        }
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let time = Utc::now();
+
+        struct TestCase {
+            input: bool,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: arithmetic rounding error is within the default tolerance
+                input: trade(0.1 + 0.2, 1.0).approx_eq(&trade(0.3, 1.0), DEFAULT_EPSILON),
+                expected: true,
+            },
+            TestCase {
+                // TC1: which the derived PartialEq rejects
+                input: trade(0.1 + 0.2, 1.0) == trade(0.3, 1.0),
+                expected: false,
+            },
+            TestCase {
+                // TC2: difference larger than the tolerance
+                input: trade(100.0, 1.0).approx_eq(&trade(100.01, 1.0), DEFAULT_EPSILON),
+                expected: false,
+            },
+            TestCase {
+                // TC3: non-float fields must be exactly equal
+                input: trade(100.0, 1.0).approx_eq(
+                    &PublicTrade {
+                        side: Side::Sell,
+                        ..trade(100.0, 1.0)
+                    },
+                    DEFAULT_EPSILON,
+                ),
+                expected: false,
+            },
+            TestCase {
+                // TC4: NaN is never approximately equal, even to itself
+                input: trade(f64::NAN, 1.0).approx_eq(&trade(f64::NAN, 1.0), DEFAULT_EPSILON),
+                expected: false,
+            },
+            TestCase {
+                // TC5: books with near-equal levels
+                input: book(time, vec![(0.1 + 0.2, 1.0)], vec![(0.4, 3.0 * 0.1)]).approx_eq(
+                    &book(time, vec![(0.3, 1.0)], vec![(0.4, 0.3)]),
+                    DEFAULT_EPSILON,
+                ),
+                expected: true,
+            },
+            TestCase {
+                // TC6: books with a different number of levels
+                input: book(time, vec![(0.3, 1.0), (0.2, 1.0)], vec![])
+                    .approx_eq(&book(time, vec![(0.3, 1.0)], vec![]), DEFAULT_EPSILON),
+                expected: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(test.input, test.expected, "TC{index} failed");
+        }
+    }
+}
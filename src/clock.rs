@@ -0,0 +1,269 @@
+use crate::{adapter::drift::median, event::MarketEvent, exchange::ExchangeId};
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Utc};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicBool, AtomicI64, AtomicUsize, Ordering},
+        Arc,
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
+
+/// Number of running [`CachedClock`]s. The cached time is used while at least one is running.
+static CACHED_CLOCKS: AtomicUsize = AtomicUsize::new(0);
+
+/// Latest time stored by a running [`CachedClock`], in nanoseconds since the Unix epoch.
+static CACHED_TIME_NANOS: AtomicI64 = AtomicI64::new(0);
+
+/// Time used as the `received_time` of each normalised
+/// [`MarketEvent`](crate::event::MarketEvent).
+///
+/// Defaults to the precise [`Utc::now`]. While a [`CachedClock`] is running, the coarser time it
+/// last cached is used instead, avoiding a clock syscall per message.
+pub fn received_time() -> DateTime<Utc> {
+    if CACHED_CLOCKS.load(Ordering::Relaxed) > 0 {
+        DateTime::from_timestamp_nanos(CACHED_TIME_NANOS.load(Ordering::Relaxed))
+    } else {
+        Utc::now()
+    }
+}
+
+/// Opt-in coarse clock that caches [`Utc::now`] every `interval` on a background thread, used by
+/// [`received_time`] while it is running.
+///
+/// Trades sub-`interval` `received_time` precision for throughput on extremely high-rate feeds
+/// (eg/ tens of thousands of messages per second).
+///
+/// ### Notes
+/// The [`CachedClock`] is process-wide, and stops when dropped. The precise [`Utc::now`] is used
+/// again once every started [`CachedClock`] has stopped.
+#[derive(Debug)]
+pub struct CachedClock {
+    interval: Duration,
+    stop: Arc<AtomicBool>,
+    updater: Option<JoinHandle<()>>,
+}
+
+impl CachedClock {
+    /// Start a new [`CachedClock`] that caches the current time every `interval` (eg/ 1ms).
+    pub fn start(interval: Duration) -> Self {
+        store_now();
+        CACHED_CLOCKS.fetch_add(1, Ordering::SeqCst);
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let updater = std::thread::Builder::new()
+            .name("barter-data-cached-clock".to_string())
+            .spawn({
+                let stop = Arc::clone(&stop);
+                move || {
+                    while !stop.load(Ordering::Relaxed) {
+                        std::thread::sleep(interval);
+                        store_now();
+                    }
+                }
+            })
+            .expect("failed to spawn CachedClock updater thread");
+
+        Self {
+            interval,
+            stop,
+            updater: Some(updater),
+        }
+    }
+
+    /// Interval at which [`Self`] caches the current time.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+}
+
+impl Drop for CachedClock {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(updater) = self.updater.take() {
+            let _ = updater.join();
+        }
+        CACHED_CLOCKS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Cache the current time, never moving the cached time backwards.
+fn store_now() {
+    if let Some(now) = Utc::now().timestamp_nanos_opt() {
+        CACHED_TIME_NANOS.fetch_max(now, Ordering::Relaxed);
+    }
+}
+
+/// Rolling estimate of how far each exchange's clock leads (positive) or lags (negative) the
+/// local clock, updated from the `exchange_time` & `received_time` of each [`MarketEvent`].
+///
+/// Each sample is the `exchange_time - received_time` of a [`MarketEvent`]. Samples are median
+/// filtered over the last `window` events of the exchange, so occasional outliers (eg/ a stale
+/// replayed message) are rejected, before being smoothed by an EWMA with smoothing factor
+/// `alpha`.
+///
+/// ### Notes
+/// - The estimate includes the network latency from the exchange, so it is biased towards a
+///   lagging exchange clock by the one-way latency.
+#[derive(Clone, Debug)]
+pub struct ClockSkewEstimator {
+    alpha: f64,
+    window: usize,
+    skews: HashMap<ExchangeId, SkewWindow>,
+}
+
+/// Recent skew samples of a single exchange, alongside the smoothed skew estimate.
+#[derive(Clone, Debug, Default)]
+struct SkewWindow {
+    samples: VecDeque<f64>,
+    estimate_us: Option<f64>,
+}
+
+impl Default for ClockSkewEstimator {
+    fn default() -> Self {
+        Self::new(0.1, 5)
+    }
+}
+
+impl ClockSkewEstimator {
+    /// Construct a new [`Self`] that median filters skew samples over the last `window`
+    /// [`MarketEvent`]s of each exchange, smoothing the filtered skew with an EWMA of smoothing
+    /// factor `alpha` (clamped to `(0, 1]`).
+    pub fn new(alpha: f64, window: usize) -> Self {
+        Self {
+            alpha: alpha.clamp(f64::MIN_POSITIVE, 1.0),
+            window: window.max(1),
+            skews: HashMap::new(),
+        }
+    }
+
+    /// Update the skew estimate of the [`MarketEvent`]'s exchange.
+    pub fn update<T>(&mut self, event: &MarketEvent<T>) {
+        let Some(exchange) = ExchangeId::ALL
+            .into_iter()
+            .find(|exchange| Exchange::from(*exchange) == event.exchange)
+        else {
+            return;
+        };
+        let Some(skew_us) = event
+            .exchange_time
+            .signed_duration_since(event.received_time)
+            .num_microseconds()
+        else {
+            return;
+        };
+
+        let skews = self.skews.entry(exchange).or_default();
+        if skews.samples.len() == self.window {
+            skews.samples.pop_front();
+        }
+        skews.samples.push_back(skew_us as f64);
+
+        let Some(filtered_us) = median(skews.samples.iter().copied().collect()) else {
+            return;
+        };
+        skews.estimate_us = Some(match skews.estimate_us {
+            Some(estimate_us) => estimate_us + self.alpha * (filtered_us - estimate_us),
+            None => filtered_us,
+        });
+    }
+
+    /// Estimated skew of the provided exchange's clock relative to the local clock, positive if
+    /// the exchange clock leads. Returns `None` until a [`MarketEvent`] of the exchange has been
+    /// observed.
+    pub fn clock_skew(&self, exchange: ExchangeId) -> Option<chrono::Duration> {
+        self.skews
+            .get(&exchange)?
+            .estimate_us
+            .map(|estimate_us| chrono::Duration::microseconds(estimate_us.round() as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+
+    fn event(exchange: ExchangeId, received_ms: i64, skew_ms: i64) -> MarketEvent<()> {
+        let received_time = DateTime::<Utc>::from_timestamp_millis(received_ms).unwrap();
+        MarketEvent {
+            exchange_time: received_time + chrono::Duration::milliseconds(skew_ms),
+            received_time,
+            exchange: Exchange::from(exchange),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)).into(),
+            kind: (),
+        }
+    }
+
+    #[test]
+    fn test_clock_skew_estimator_converges_to_injected_skew() {
+        struct TestCase {
+            exchange: ExchangeId,
+            skew_ms: i64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: exchange clock leading the local clock
+                exchange: ExchangeId::BinanceSpot,
+                skew_ms: 250,
+            },
+            TestCase {
+                // TC1: exchange clock lagging the local clock
+                exchange: ExchangeId::Okx,
+                skew_ms: -120,
+            },
+        ];
+
+        let mut estimator = ClockSkewEstimator::default();
+        assert_eq!(estimator.clock_skew(ExchangeId::BinanceSpot), None);
+
+        for (index, test) in tests.into_iter().enumerate() {
+            for sample in 0..200_i64 {
+                // Jitter of +/- 10ms, with an occasional 10s outlier
+                let skew_ms = match sample % 25 {
+                    0 => test.skew_ms + 10_000,
+                    _ => test.skew_ms + (sample % 5 - 2) * 5,
+                };
+                estimator.update(&event(test.exchange, 1_000_000 + sample * 100, skew_ms));
+            }
+
+            let actual = estimator.clock_skew(test.exchange).unwrap();
+            let error = actual - chrono::Duration::milliseconds(test.skew_ms);
+            assert!(
+                error.num_milliseconds().abs() <= 5,
+                "TC{index} failed: estimate {actual} too far from {}ms",
+                test.skew_ms
+            );
+        }
+
+        assert_eq!(estimator.clock_skew(ExchangeId::Kraken), None);
+    }
+
+    #[test]
+    fn test_cached_clock_stays_within_interval_of_real_time() {
+        let interval = Duration::from_millis(5);
+        let clock = CachedClock::start(interval);
+
+        // Allow for scheduling jitter of the updater thread
+        let tolerance = chrono::Duration::from_std(interval * 10).unwrap();
+
+        for _ in 0..50 {
+            let before = Utc::now();
+            let cached = received_time();
+            let after = Utc::now();
+
+            assert!(cached <= after, "cached time {cached} is ahead of {after}");
+            assert!(
+                before - cached <= tolerance,
+                "cached time {cached} lags {before} by more than {tolerance}"
+            );
+
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_eq!(clock.interval(), interval);
+    }
+}
@@ -0,0 +1,241 @@
+use crate::error::DataError;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter},
+    io::{ErrorKind, Read, Write},
+    path::Path,
+};
+
+/// Serialisation format used to encode & decode [`MarketEvent<T>`](crate::event::MarketEvent)s
+/// when recording, replaying, or forwarding them to a sink.
+///
+/// ### Notes
+/// - [`Codec::Json`] is human-readable, but the largest & slowest.
+/// - [`Codec::MessagePack`] is a compact self-describing binary format.
+/// - [`Codec::Bincode`] is the most compact & fastest, but is not self-describing.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Codec {
+    #[default]
+    Json,
+    #[serde(alias = "msgpack")]
+    MessagePack,
+    Bincode,
+}
+
+impl Display for Codec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Codec::Json => "json",
+                Codec::MessagePack => "message_pack",
+                Codec::Bincode => "bincode",
+            }
+        )
+    }
+}
+
+impl Codec {
+    /// Maximum length of a frame read by [`Codec::read_frame`], guarding against allocating an
+    /// arbitrarily large buffer for a corrupt or untrusted length prefix.
+    pub const MAX_FRAME_LENGTH: u32 = 16 * 1024 * 1024;
+
+    /// Encode the provided value into bytes using this [`Codec`].
+    pub fn encode<T>(&self, value: &T) -> Result<Vec<u8>, DataError>
+    where
+        T: Serialize,
+    {
+        match self {
+            Codec::Json => serde_json::to_vec(value).map_err(|error| self.error(error)),
+            Codec::MessagePack => rmp_serde::to_vec(value).map_err(|error| self.error(error)),
+            Codec::Bincode => bincode::serialize(value).map_err(|error| self.error(error)),
+        }
+    }
+
+    /// Decode a value from the provided bytes using this [`Codec`].
+    pub fn decode<T>(&self, bytes: &[u8]) -> Result<T, DataError>
+    where
+        T: DeserializeOwned,
+    {
+        match self {
+            Codec::Json => serde_json::from_slice(bytes).map_err(|error| self.error(error)),
+            Codec::MessagePack => rmp_serde::from_slice(bytes).map_err(|error| self.error(error)),
+            Codec::Bincode => bincode::deserialize(bytes).map_err(|error| self.error(error)),
+        }
+    }
+
+    /// Encode the provided value and write it to the writer as a length-delimited frame.
+    ///
+    /// Each frame is prefixed by the encoded length as a little-endian `u32`, allowing frames
+    /// from every [`Codec`] to be read back with [`Codec::read_frame`].
+    pub fn write_frame<W, T>(&self, writer: &mut W, value: &T) -> Result<(), DataError>
+    where
+        W: Write,
+        T: Serialize,
+    {
+        let bytes = self.encode(value)?;
+        let length = u32::try_from(bytes.len()).map_err(|error| self.error(error))?;
+
+        writer
+            .write_all(&length.to_le_bytes())
+            .and_then(|_| writer.write_all(&bytes))
+            .map_err(|error| self.error(error))
+    }
+
+    /// Read the next length-delimited frame written by [`Codec::write_frame`] from the reader and
+    /// decode it.
+    ///
+    /// Returns `Ok(None)` if the reader is exhausted, and an error if the frame length exceeds
+    /// [`Codec::MAX_FRAME_LENGTH`].
+    pub fn read_frame<R, T>(&self, reader: &mut R) -> Result<Option<T>, DataError>
+    where
+        R: Read,
+        T: DeserializeOwned,
+    {
+        let mut length = [0u8; 4];
+        match reader.read_exact(&mut length) {
+            Ok(()) => {}
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(error) => return Err(self.error(error)),
+        }
+
+        let length = u32::from_le_bytes(length);
+        if length > Self::MAX_FRAME_LENGTH {
+            return Err(self.error(format!(
+                "frame length {length} exceeds maximum {}",
+                Self::MAX_FRAME_LENGTH
+            )));
+        }
+
+        let mut bytes = vec![0u8; length as usize];
+        reader
+            .read_exact(&mut bytes)
+            .map_err(|error| self.error(error))?;
+
+        self.decode(&bytes).map(Some)
+    }
+
+    /// Determine the [`Codec`] associated with a file extension (eg/ "recording.msgpack").
+    pub fn from_path<P>(path: P) -> Option<Self>
+    where
+        P: AsRef<Path>,
+    {
+        match path.as_ref().extension()?.to_str()? {
+            "json" | "jsonl" => Some(Codec::Json),
+            "msgpack" | "mpk" => Some(Codec::MessagePack),
+            "bincode" | "bin" => Some(Codec::Bincode),
+            _ => None,
+        }
+    }
+
+    /// Best-effort detection of the [`Codec`] used to encode the provided bytes.
+    ///
+    /// JSON objects & arrays are detected by their leading character, and MessagePack by its
+    /// map or array marker. Anything else is assumed to be [`Codec::Bincode`], since it is not
+    /// self-describing - prefer [`Codec::from_path`] or explicit configuration where possible.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        match bytes.iter().find(|byte| !byte.is_ascii_whitespace())? {
+            b'{' | b'[' => Some(Codec::Json),
+            0x80..=0x9f | 0xdc..=0xdf => Some(Codec::MessagePack),
+            _ => Some(Codec::Bincode),
+        }
+    }
+
+    fn error<E>(&self, error: E) -> DataError
+    where
+        E: Display,
+    {
+        DataError::Codec {
+            codec: *self,
+            error: error.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{event::MarketEvent, fixtures, subscription::trade::PublicTrade};
+    use barter_integration::model::{Exchange, Side};
+
+    fn trade_event() -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange: Exchange::from("binance_spot"),
+            ..fixtures::trade(
+                fixtures::millis(1_672_304_486_865),
+                "12345",
+                16578.5,
+                0.001,
+                Side::Buy,
+            )
+        }
+    }
+
+    #[test]
+    fn test_codec_round_trip() {
+        let event = trade_event();
+
+        for (index, codec) in [Codec::Json, Codec::MessagePack, Codec::Bincode]
+            .into_iter()
+            .enumerate()
+        {
+            let bytes = codec.encode(&event).unwrap();
+            let actual = codec.decode::<MarketEvent<PublicTrade>>(&bytes).unwrap();
+            assert_eq!(actual, event, "TC{} failed for {}", index, codec);
+            assert_eq!(Codec::detect(&bytes), Some(codec), "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_codec_frame_round_trip() {
+        let events = vec![trade_event(), trade_event()];
+
+        for (index, codec) in [Codec::Json, Codec::MessagePack, Codec::Bincode]
+            .into_iter()
+            .enumerate()
+        {
+            let mut buffer = Vec::new();
+            for event in &events {
+                codec.write_frame(&mut buffer, event).unwrap();
+            }
+
+            let mut reader = buffer.as_slice();
+            let mut actual = Vec::new();
+            while let Some(event) = codec
+                .read_frame::<_, MarketEvent<PublicTrade>>(&mut reader)
+                .unwrap()
+            {
+                actual.push(event);
+            }
+
+            assert_eq!(actual, events, "TC{} failed for {}", index, codec);
+        }
+    }
+
+    #[test]
+    fn test_codec_read_frame_rejects_oversized_length() {
+        let mut buffer = (Codec::MAX_FRAME_LENGTH + 1).to_le_bytes().to_vec();
+        buffer.extend_from_slice(b"{}");
+
+        let actual = Codec::Json.read_frame::<_, serde_json::Value>(&mut buffer.as_slice());
+        assert!(matches!(
+            actual,
+            Err(DataError::Codec {
+                codec: Codec::Json,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_codec_from_path() {
+        assert_eq!(Codec::from_path("events.jsonl"), Some(Codec::Json));
+        assert_eq!(Codec::from_path("events.msgpack"), Some(Codec::MessagePack));
+        assert_eq!(Codec::from_path("events.bincode"), Some(Codec::Bincode));
+        assert_eq!(Codec::from_path("events.csv"), None);
+    }
+}
@@ -0,0 +1,137 @@
+use serde::de::{Deserializer, Error, Visitor};
+use std::fmt;
+
+/// Deserialize a numeric field encoded as either a JSON string (eg/ `"1.23"`) or a JSON number
+/// (eg/ `1.23`) as an `f64`.
+///
+/// Most exchanges send prices & quantities as strings, but some send raw numbers, so every
+/// exchange numeric field uses this to tolerate both encodings.
+///
+/// Non-finite values (eg/ `"NaN"`, `"inf"`) are rejected, so they are surfaced as a
+/// deserialisation error rather than propagated into a normalised
+/// [`MarketEvent<T>`](crate::event::MarketEvent).
+pub fn de_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct F64Visitor;
+
+    impl<'de> Visitor<'de> for F64Visitor {
+        type Value = f64;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a number, or a string containing a number")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            value.parse().map_err(Error::custom).and_then(finite)
+        }
+
+        fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            finite(value)
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(value as f64)
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(value as f64)
+        }
+    }
+
+    deserializer.deserialize_any(F64Visitor)
+}
+
+fn finite<E>(value: f64) -> Result<f64, E>
+where
+    E: Error,
+{
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(Error::custom(format!("non-finite number: {value}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[test]
+    fn test_de_f64() {
+        #[derive(Debug, Deserialize)]
+        struct Level {
+            #[serde(deserialize_with = "de_f64")]
+            price: f64,
+        }
+
+        struct TestCase {
+            input: &'static str,
+            expected: Option<f64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: string encoded price
+                input: r#"{"price":"1.23"}"#,
+                expected: Some(1.23),
+            },
+            TestCase {
+                // TC1: number encoded price
+                input: r#"{"price":1.23}"#,
+                expected: Some(1.23),
+            },
+            TestCase {
+                // TC2: integer number encoded price
+                input: r#"{"price":5}"#,
+                expected: Some(5.0),
+            },
+            TestCase {
+                // TC3: string encoded price containing an escape sequence
+                input: r#"{"price":"\u0031.23"}"#,
+                expected: Some(1.23),
+            },
+            TestCase {
+                // TC4: non-numeric string is rejected
+                input: r#"{"price":"one"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC5: non-numeric type is rejected
+                input: r#"{"price":true}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC6: NaN string is rejected
+                input: r#"{"price":"NaN"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC7: infinite string is rejected
+                input: r#"{"price":"-inf"}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<Level>(test.input)
+                .ok()
+                .map(|level| level.price);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::subscription::{book::Level, trade::PublicTrade};
+use barter_integration::model::Side;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Convert an `f64` price or quantity into a [`Decimal`] via its shortest round-trip decimal
+/// representation.
+///
+/// Exchanges encode prices & quantities as decimal strings with at most 15 significant digits,
+/// which an `f64` round-trips exactly, so the [`Decimal`] has the same value as the original
+/// exchange string (excluding any trailing zeros, eg/ `"0.00010000"` -> `0.0001`).
+pub fn to_decimal(value: f64) -> Result<Decimal, rust_decimal::Error> {
+    Decimal::from_str(&value.to_string())
+}
+
+/// [`Decimal`] representation of an OrderBook [`Level`], used by accounting-sensitive consumers
+/// that must not accumulate `f64` rounding error (eg/ when summing notionals).
+///
+/// Deserialises directly from an exchange string-encoded level (eg/ `["0.00010000", "12.0"]`),
+/// preserving the exact exchange decimal.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DecimalLevel {
+    pub price: Decimal,
+    pub amount: Decimal,
+}
+
+impl TryFrom<Level> for DecimalLevel {
+    type Error = rust_decimal::Error;
+
+    fn try_from(level: Level) -> Result<Self, Self::Error> {
+        Ok(Self {
+            price: to_decimal(level.price)?,
+            amount: to_decimal(level.amount)?,
+        })
+    }
+}
+
+/// [`Decimal`] representation of a normalised [`PublicTrade`].
+///
+/// See [`DecimalLevel`] for rationale.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DecimalTrade {
+    pub id: String,
+    pub price: Decimal,
+    pub amount: Decimal,
+    pub side: Side,
+}
+
+impl TryFrom<&PublicTrade> for DecimalTrade {
+    type Error = rust_decimal::Error;
+
+    fn try_from(trade: &PublicTrade) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: trade.id.clone(),
+            price: to_decimal(trade.price)?,
+            amount: to_decimal(trade.amount)?,
+            side: trade.side,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::MarketEvent;
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange,
+    };
+    use chrono::Utc;
+
+    #[test]
+    fn test_de_decimal_level_without_precision_loss() {
+        let actual =
+            serde_json::from_str::<DecimalLevel>(r#"["0.00010000", "12.30000000"]"#).unwrap();
+
+        assert_eq!(actual.price.to_string(), "0.00010000");
+        assert_eq!(actual.amount.to_string(), "12.30000000");
+    }
+
+    #[test]
+    fn test_decimal_notional_does_not_drift() {
+        let trade = |price: f64| PublicTrade {
+            id: "1".to_string(),
+            price,
+            amount: 1.0,
+            side: Side::Buy,
+            conditions: vec![],
+        };
+
+        // f64 sum drifts, whereas the Decimal representation is exact
+        assert_ne!(0.1 + 0.2, 0.3);
+        let actual = [trade(0.1), trade(0.2)]
+            .iter()
+            .map(|trade| DecimalTrade::try_from(trade).unwrap())
+            .map(|trade| trade.price * trade.amount)
+            .sum::<Decimal>();
+        assert_eq!(actual, Decimal::from_str("0.3").unwrap());
+
+        // MarketEvent derives hold for Decimal kinds
+        let event = MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from("exchange"),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)).into(),
+            kind: DecimalTrade::try_from(&trade(0.1)).unwrap(),
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            serde_json::from_str::<MarketEvent<DecimalTrade>>(&json).unwrap(),
+            event
+        );
+    }
+}
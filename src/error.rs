@@ -1,11 +1,37 @@
-use barter_integration::error::SocketError;
+use crate::{
+    codec::Codec,
+    exchange::ExchangeId,
+    protocol::{decode_close_frame, decode_raw_frame},
+    subscription::candle::Interval,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Exchange, SubscriptionId},
+};
+use chrono::{DateTime, Utc};
+use std::{sync::Arc, time::Duration};
 use thiserror::Error;
 
 /// All errors generated in `barter-data`.
 #[derive(Debug, Error)]
 pub enum DataError {
     #[error("SocketError: {0}")]
-    Socket(#[from] SocketError),
+    Socket(Box<SocketError>),
+
+    #[error("SubscriptionError: {0}")]
+    Subscription(#[from] SubscriptionError),
+
+    #[error("Unidentifiable: SubscriptionId {0} is not associated with any Subscription")]
+    Unidentifiable(SubscriptionId),
+
+    #[error("Deserialise: failed to deserialise frame: {error} for payload: {payload}")]
+    Deserialise {
+        error: serde_json::Error,
+        payload: String,
+    },
+
+    #[error("RawFrame: {0}")]
+    RawFrame(#[from] RawFrameError),
 
     #[error(
         "\
@@ -17,6 +43,186 @@ pub enum DataError {
         prev_last_update_id: u64,
         first_update_id: u64,
     },
+
+    #[error(
+        "FundingTimeMismatch: exchange reported next funding time {reported} does not match \
+        the next scheduled funding time {expected}"
+    )]
+    FundingTimeMismatch {
+        expected: DateTime<Utc>,
+        reported: DateTime<Utc>,
+    },
+
+    #[error("Codec: failed to {codec} encode or decode: {error}")]
+    Codec { codec: Codec, error: String },
+
+    #[error(
+        "NonMonotonicTime: {exchange} {instrument} exchange_time {exchange_time} is earlier than \
+        the previous exchange_time {previous}"
+    )]
+    NonMonotonicTime {
+        exchange: Exchange,
+        instrument: Arc<Instrument>,
+        previous: DateTime<Utc>,
+        exchange_time: DateTime<Utc>,
+    },
+
+    #[error(
+        "IncompatibleIntervals: {coarse} candles cannot be resampled from {fine} candles, since \
+        {coarse} is not a larger multiple of {fine}"
+    )]
+    IncompatibleIntervals { fine: Interval, coarse: Interval },
+
+    #[error("ConnectionClosed: exchange closed the connection with code {code}: {reason}")]
+    ConnectionClosed { code: u16, reason: String },
+
+    #[error("ConsumerTerminated: {exchange} MarketStream consumer loop is no longer running")]
+    ConsumerTerminated { exchange: ExchangeId },
+
+    #[error("InvalidBaseUrl: {url} is not a ws:// or wss:// WebSocket url")]
+    InvalidBaseUrl { url: String },
+
+    #[error(
+        "ConnectionLimitExceeded: {requested} concurrent connections requested, exceeding the \
+        configured limit of {limit}"
+    )]
+    ConnectionLimitExceeded { requested: usize, limit: usize },
+
+    #[error("SubscriptionsNotLive: no events received within {timeout:?} for: {subscriptions:?}")]
+    SubscriptionsNotLive {
+        timeout: Duration,
+        subscriptions: Vec<String>,
+    },
+
+    #[error("Sink: EventSink failed to deliver event: {error}")]
+    Sink { error: String },
+
+    #[error("Record: failed to record MarketEvent: {error}")]
+    Record { error: String },
+
+    #[error("Replay: failed to replay MarketEvent on line {line}: {error}")]
+    Replay { line: usize, error: String },
+
+    #[error("BookDesync: received an OrderBook update before any OrderBook snapshot")]
+    BookDesync,
+
+    #[error(
+        "InvalidChecksum: exchange OrderBook checksum {expected} does not match the local \
+        OrderBook checksum {actual}"
+    )]
+    InvalidChecksum { expected: u32, actual: u32 },
+
+    #[error("InvalidTradeTick: failed to decode TradeTick: {error}")]
+    InvalidTradeTick { error: String },
+}
+
+/// Frame received from an exchange connection that failed to deserialise, carrying the raw text
+/// (truncated to the `max_raw_frame_len` of its [`FrameContext`](crate::protocol::FrameContext))
+/// for post-mortem bug reports.
+///
+/// The [`SubscriptionId`] is only known if the connection carries exactly one subscription.
+#[derive(Debug, Error)]
+#[error(
+    "{exchange} failed to deserialise frame of SubscriptionId {subscription_id:?}: {source} for \
+    payload: {raw}"
+)]
+pub struct RawFrameError {
+    pub exchange: ExchangeId,
+    pub subscription_id: Option<SubscriptionId>,
+    pub raw: String,
+    pub source: serde_json::Error,
+}
+
+/// Errors generated by an exchange server rejecting actioned
+/// [`Subscription`](crate::subscription::Subscription)s for a specific, actionable reason.
+///
+/// Generic subscription rejections (eg/ malformed request, unknown market) are still surfaced
+/// as a [`SocketError::Subscribe`].
+#[derive(Clone, Eq, PartialEq, Debug, Error)]
+pub enum SubscriptionError {
+    #[error(
+        "LimitExceeded: {exchange} rejected subscription with error code {code}: {message} \
+        (shed load rather than retry)"
+    )]
+    LimitExceeded {
+        exchange: ExchangeId,
+        code: String,
+        message: String,
+    },
+
+    #[error(
+        "Unsupported: {exchange} does not support the requested {kind} Subscriptions: {}",
+        UnsupportedSubscription::join(unsupported)
+    )]
+    Unsupported {
+        exchange: ExchangeId,
+        kind: &'static str,
+        unsupported: Vec<UnsupportedSubscription>,
+    },
+
+    #[error(
+        "Unconfirmed: {exchange} did not confirm the Subscriptions [{}]: {reason}",
+        unconfirmed.iter().map(SubscriptionId::as_ref).collect::<Vec<_>>().join(", ")
+    )]
+    Unconfirmed {
+        exchange: ExchangeId,
+        unconfirmed: Vec<SubscriptionId>,
+        reason: String,
+    },
+}
+
+/// [`Subscription`](crate::subscription::Subscription) [`Instrument`] rejected during validation,
+/// and the reason it is unsupported (eg/ "perpetual", "OrderBook depth 7").
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct UnsupportedSubscription {
+    pub instrument: Instrument,
+    pub reason: String,
+}
+
+impl UnsupportedSubscription {
+    fn join(unsupported: &[Self]) -> String {
+        unsupported
+            .iter()
+            .map(|subscription| format!("{} ({})", subscription.instrument, subscription.reason))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// WebSocket close code sent when an exchange closes a connection due to a policy violation
+/// (eg/ rate limit breached).
+pub const CLOSE_CODE_POLICY_VIOLATION: u16 = 1008;
+
+/// WebSocket close code sent when an exchange is temporarily unable to serve the connection (eg/
+/// overloaded or undergoing maintenance).
+pub const CLOSE_CODE_TRY_AGAIN_LATER: u16 = 1013;
+
+impl From<SocketError> for DataError {
+    fn from(error: SocketError) -> Self {
+        match error {
+            // Surface the code & reason of CloseFrames encoded by the crate WebSocketParser
+            SocketError::Terminated(message) => match decode_close_frame(&message) {
+                Some((code, reason)) => DataError::ConnectionClosed { code, reason },
+                None => DataError::Socket(Box::new(SocketError::Terminated(message))),
+            },
+            // Surface frames that were not routed to any Subscription, rather than a generic
+            // SocketError, so consumers can choose to log, count, or abort on them
+            SocketError::Unidentifiable(subscription_id) => {
+                DataError::Unidentifiable(subscription_id)
+            }
+            // Surface the FrameContext & raw frame encoded by the crate WebSocketParser
+            SocketError::Deserialise { error, payload } => match decode_raw_frame(&payload) {
+                Some((context, raw)) => DataError::RawFrame(RawFrameError {
+                    exchange: context.exchange,
+                    subscription_id: context.subscription_id,
+                    raw,
+                    source: error,
+                }),
+                None => DataError::Deserialise { error, payload },
+            },
+            error => DataError::Socket(Box::new(error)),
+        }
+    }
 }
 
 impl DataError {
@@ -25,9 +231,34 @@ impl DataError {
     pub fn is_terminal(&self) -> bool {
         match self {
             DataError::InvalidSequence { .. } => true,
+            DataError::ConnectionClosed { .. } => true,
+            DataError::BookDesync => true,
+            DataError::InvalidChecksum { .. } => true,
             _ => false,
         }
     }
+
+    /// Determine if an error communicates that an exchange subscription limit has been hit, in
+    /// which case re-initialising a [`MarketStream`](super::MarketStream) will not help.
+    pub fn is_limit_exceeded(&self) -> bool {
+        matches!(
+            self,
+            DataError::Subscription(SubscriptionError::LimitExceeded { .. })
+        )
+    }
+
+    /// Determine if an error communicates that the exchange closed the connection because it was
+    /// rate-limited, banned, or is temporarily unavailable, in which case re-connecting
+    /// immediately will only make things worse.
+    pub fn is_connection_throttled(&self) -> bool {
+        matches!(
+            self,
+            DataError::ConnectionClosed {
+                code: CLOSE_CODE_POLICY_VIOLATION | CLOSE_CODE_TRY_AGAIN_LATER,
+                ..
+            }
+        )
+    }
 }
 
 #[cfg(test)]
@@ -52,9 +283,39 @@ mod tests {
             },
             TestCase {
                 // TC1: is not terminal w/ DataError::Socket
-                input: DataError::Socket(SocketError::Sink),
+                input: DataError::Socket(Box::new(SocketError::Sink)),
                 expected: false,
             },
+            TestCase {
+                // TC2: is not terminal w/ DataError::Subscription
+                input: DataError::Subscription(SubscriptionError::LimitExceeded {
+                    exchange: ExchangeId::Okx,
+                    code: "60014".to_string(),
+                    message: "Requests too frequent".to_string(),
+                }),
+                expected: false,
+            },
+            TestCase {
+                // TC3: is terminal w/ DataError::ConnectionClosed
+                input: DataError::ConnectionClosed {
+                    code: CLOSE_CODE_TRY_AGAIN_LATER,
+                    reason: "maintenance".to_string(),
+                },
+                expected: true,
+            },
+            TestCase {
+                // TC4: is terminal w/ DataError::BookDesync
+                input: DataError::BookDesync,
+                expected: true,
+            },
+            TestCase {
+                // TC5: is terminal w/ DataError::InvalidChecksum
+                input: DataError::InvalidChecksum {
+                    expected: 974942666,
+                    actual: 1234,
+                },
+                expected: true,
+            },
         ];
 
         for (index, test) in tests.into_iter().enumerate() {
@@ -62,4 +323,44 @@ mod tests {
             assert_eq!(actual, test.expected, "TC{} failed", index);
         }
     }
+
+    #[test]
+    fn test_data_error_from_socket_error() {
+        let deserialise = |payload: &str| SocketError::Deserialise {
+            error: serde_json::from_str::<u64>(payload).unwrap_err(),
+            payload: payload.to_owned(),
+        };
+
+        // Unidentifiable frame is surfaced with its SubscriptionId
+        match DataError::from(SocketError::Unidentifiable(SubscriptionId::from(
+            "trade|BTC",
+        ))) {
+            DataError::Unidentifiable(subscription_id) => {
+                assert_eq!(subscription_id, SubscriptionId::from("trade|BTC"))
+            }
+            other => panic!("expected DataError::Unidentifiable, got: {other:?}"),
+        }
+
+        // Undeserialisable frame is surfaced with its raw payload
+        match DataError::from(deserialise("not json")) {
+            DataError::Deserialise { payload, .. } => assert_eq!(payload, "not json"),
+            other => panic!("expected DataError::Deserialise, got: {other:?}"),
+        }
+
+        // Encoded CloseFrame is surfaced with its code & reason
+        match DataError::from(SocketError::Terminated(
+            crate::protocol::encode_close_frame(1008, "banned"),
+        )) {
+            DataError::ConnectionClosed { code, reason } => {
+                assert_eq!((code, reason.as_str()), (1008, "banned"))
+            }
+            other => panic!("expected DataError::ConnectionClosed, got: {other:?}"),
+        }
+
+        // Every other SocketError is wrapped untouched
+        assert!(matches!(
+            DataError::from(SocketError::Sink),
+            DataError::Socket(error) if matches!(*error, SocketError::Sink)
+        ));
+    }
 }
@@ -1,15 +1,22 @@
 use crate::{
     error::DataError,
+    exchange::ExchangeId,
     subscription::{
         book::{OrderBook, OrderBookL1},
-        candle::Candle,
+        candle::{Candle, ContinuousCandle},
+        funding::FundingRate,
+        index::IndexPrice,
         liquidation::Liquidation,
+        status::InstrumentStatus,
+        ticker::Ticker,
         trade::PublicTrade,
+        SubKindId,
     },
 };
 use barter_integration::model::{instrument::Instrument, Exchange};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::{sync::Arc, time::Duration};
 
 /// Convenient new type containing a collection of [`MarketEvent<T>`](MarketEvent)s.
 #[derive(Debug)]
@@ -34,15 +41,165 @@ impl<T> FromIterator<Result<MarketEvent<T>, DataError>> for MarketIter<T> {
 /// - [`MarketEvent<PublicTrade>`](crate::subscription::trade::PublicTrade)
 /// - [`MarketEvent<OrderBookL1>`](crate::subscription::book::OrderBookL1)
 /// - [`MarketEvent<DataKind>`](DataKind)
+///
+/// The `instrument` is interned (see [`intern`](crate::subscription::intern)), so cloning a
+/// [`MarketEvent<T>`] only clones an [`Arc`] rather than the [`Instrument`] symbols.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
 pub struct MarketEvent<T> {
     pub exchange_time: DateTime<Utc>,
     pub received_time: DateTime<Utc>,
     pub exchange: Exchange,
-    pub instrument: Instrument,
+    pub instrument: Arc<Instrument>,
     pub kind: T,
 }
 
+impl<T> MarketEvent<T> {
+    /// Owned [`Instrument`] of the [`MarketEvent<T>`], for consumers that require one rather
+    /// than the interned [`Arc<Instrument>`].
+    pub fn owned_instrument(&self) -> Instrument {
+        Instrument::clone(&self.instrument)
+    }
+
+    /// Exchange-to-local latency of the [`MarketEvent<T>`] (ie/ `received_time - exchange_time`).
+    ///
+    /// Returns `None` if the `exchange_time` is after the `received_time` (eg/ due to clock skew).
+    pub fn latency(&self) -> Option<Duration> {
+        (self.received_time - self.exchange_time).to_std().ok()
+    }
+}
+
+/// Item distributed by a [`MarketStream`](crate::MarketStream) consumer loop that interleaves
+/// connection lifecycle markers with the consumed [`MarketEvent<T>`](MarketEvent)s.
+///
+/// Every marker is sent before any [`MarketEvent<T>`](MarketEvent) consumed from the associated
+/// connection, allowing stateful downstream consumers (eg/ candle aggregators, VWAP) to reset or
+/// flag any state derived from a previous connection.
+///
+/// ### Notes
+/// Initialise [`Streams<StreamEvent<T>>`](crate::streams::Streams) by constructing a
+/// [`StreamBuilder`](crate::streams::builder::StreamBuilder) with a [`StreamEvent<T>`] `Output`
+/// (eg/ `StreamBuilder::<PublicTrades, StreamEvent<PublicTrade>>::new()`).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum StreamEvent<T> {
+    /// First successful connection of the consumer loop.
+    Connected {
+        exchange: ExchangeId,
+        time: DateTime<Utc>,
+    },
+    /// Successful re-connection after a disconnection, meaning events may have been missed.
+    Reconnected {
+        exchange: ExchangeId,
+        time: DateTime<Utc>,
+    },
+    /// Periodic signal that the current connection is healthy but has not yielded a
+    /// [`MarketEvent<T>`](MarketEvent) for the configured heartbeat interval.
+    ///
+    /// Emission is opt-in (see
+    /// [`StreamBuilder::heartbeat`](crate::streams::builder::StreamBuilder::heartbeat)), and stops
+    /// whilst the connection is down.
+    Heartbeat {
+        exchange: ExchangeId,
+        time: DateTime<Utc>,
+    },
+    /// Connection is down and the exchange is within a scheduled maintenance window ending
+    /// `until`, so re-connection attempts are made at an extended interval.
+    ///
+    /// See [`MaintenanceSchedule`](crate::streams::maintenance::MaintenanceSchedule).
+    Maintenance {
+        exchange: ExchangeId,
+        until: DateTime<Utc>,
+        time: DateTime<Utc>,
+    },
+    /// Consumer loop gave up initialising the connection (eg/ the first connection failed), so
+    /// no further events will be received from it. Other exchange connections are unaffected.
+    Failed {
+        exchange: ExchangeId,
+        error: String,
+        time: DateTime<Utc>,
+    },
+    /// [`MarketEvent<T>`](MarketEvent) consumed from the current connection.
+    Market(MarketEvent<T>),
+}
+
+impl<T> From<MarketEvent<T>> for StreamEvent<T> {
+    fn from(event: MarketEvent<T>) -> Self {
+        Self::Market(event)
+    }
+}
+
+/// Defines the item a [`MarketStream`](crate::MarketStream) consumer loop distributes downstream
+/// for each consumed [`MarketEvent<T>`](MarketEvent) and (re)connection.
+pub trait StreamItem<T>
+where
+    Self: From<MarketEvent<T>>,
+{
+    /// Construct the item that marks a successful (re)connection with the exchange, or `None` if
+    /// [`Self`] does not represent connection lifecycle markers.
+    fn connected(exchange: ExchangeId, reconnected: bool) -> Option<Self>;
+
+    /// Construct the item that signals a healthy but quiet connection with the exchange, or
+    /// `None` if [`Self`] does not represent heartbeats.
+    fn heartbeat(exchange: ExchangeId) -> Option<Self>;
+
+    /// Construct the item that signals the exchange is within a scheduled maintenance window
+    /// ending `until`, or `None` if [`Self`] does not represent maintenance markers.
+    fn maintenance(exchange: ExchangeId, until: DateTime<Utc>) -> Option<Self>;
+
+    /// Construct the item that signals the consumer loop gave up on the exchange connection due
+    /// to the provided error, or `None` if [`Self`] does not represent failure markers.
+    fn failed(exchange: ExchangeId, error: String) -> Option<Self>;
+}
+
+impl<T> StreamItem<T> for MarketEvent<T> {
+    fn connected(_: ExchangeId, _: bool) -> Option<Self> {
+        None
+    }
+
+    fn heartbeat(_: ExchangeId) -> Option<Self> {
+        None
+    }
+
+    fn maintenance(_: ExchangeId, _: DateTime<Utc>) -> Option<Self> {
+        None
+    }
+
+    fn failed(_: ExchangeId, _: String) -> Option<Self> {
+        None
+    }
+}
+
+impl<T> StreamItem<T> for StreamEvent<T> {
+    fn connected(exchange: ExchangeId, reconnected: bool) -> Option<Self> {
+        let time = Utc::now();
+        Some(match reconnected {
+            true => Self::Reconnected { exchange, time },
+            false => Self::Connected { exchange, time },
+        })
+    }
+
+    fn heartbeat(exchange: ExchangeId) -> Option<Self> {
+        Some(Self::Heartbeat {
+            exchange,
+            time: Utc::now(),
+        })
+    }
+
+    fn maintenance(exchange: ExchangeId, until: DateTime<Utc>) -> Option<Self> {
+        Some(Self::Maintenance {
+            exchange,
+            until,
+            time: Utc::now(),
+        })
+    }
+    fn failed(exchange: ExchangeId, error: String) -> Option<Self> {
+        Some(Self::Failed {
+            exchange,
+            error,
+            time: Utc::now(),
+        })
+    }
+}
+
 /// Available kinds of normalised Barter [`MarketEvent<T>`](MarketEvent).
 ///
 /// ### Notes
@@ -59,7 +216,41 @@ pub enum DataKind {
     OrderBookL1(OrderBookL1),
     OrderBook(OrderBook),
     Candle(Candle),
+    ContinuousCandle(ContinuousCandle),
     Liquidation(Liquidation),
+    InstrumentStatus(InstrumentStatus),
+    IndexPrice(IndexPrice),
+    FundingRate(FundingRate),
+    Ticker(Ticker),
+}
+
+impl DataKind {
+    /// Determine the [`SubKindId`] of the [`SubKind`](crate::subscription::SubKind) that
+    /// generates this [`DataKind`], so events can be routed without matching every variant.
+    ///
+    /// The `exchange` & `instrument` of every [`MarketEvent<DataKind>`](MarketEvent) are already
+    /// available directly on the [`MarketEvent`].
+    ///
+    /// ### Notes
+    /// - [`DataKind::OrderBook`] is generated by both [`SubKindId::OrderBooksL2`] &
+    ///   [`SubKindId::OrderBooksL2Depth`], and is always identified as the former.
+    /// - Returns `None` for [`DataKind::InstrumentStatus`], which is polled (eg/
+    ///   [`BinanceStatusMonitor`](crate::exchange::binance::status::BinanceStatusMonitor)) rather
+    ///   than generated by a [`SubKind`](crate::subscription::SubKind).
+    pub fn kind(&self) -> Option<SubKindId> {
+        match self {
+            DataKind::Trade(_) => Some(SubKindId::PublicTrades),
+            DataKind::OrderBookL1(_) => Some(SubKindId::OrderBooksL1),
+            DataKind::OrderBook(_) => Some(SubKindId::OrderBooksL2),
+            DataKind::Candle(_) => Some(SubKindId::Candles),
+            DataKind::ContinuousCandle(_) => Some(SubKindId::ContinuousCandles),
+            DataKind::Liquidation(_) => Some(SubKindId::Liquidations),
+            DataKind::InstrumentStatus(_) => None,
+            DataKind::IndexPrice(_) => Some(SubKindId::IndexPrices),
+            DataKind::FundingRate(_) => Some(SubKindId::FundingRates),
+            DataKind::Ticker(_) => Some(SubKindId::Tickers),
+        }
+    }
 }
 
 impl From<MarketEvent<PublicTrade>> for MarketEvent<DataKind> {
@@ -110,6 +301,18 @@ impl From<MarketEvent<Candle>> for MarketEvent<DataKind> {
     }
 }
 
+impl From<MarketEvent<ContinuousCandle>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<ContinuousCandle>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::ContinuousCandle(event.kind),
+        }
+    }
+}
+
 impl From<MarketEvent<Liquidation>> for MarketEvent<DataKind> {
     fn from(event: MarketEvent<Liquidation>) -> Self {
         Self {
@@ -121,3 +324,340 @@ impl From<MarketEvent<Liquidation>> for MarketEvent<DataKind> {
         }
     }
 }
+
+impl From<MarketEvent<InstrumentStatus>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<InstrumentStatus>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::InstrumentStatus(event.kind),
+        }
+    }
+}
+
+impl From<MarketEvent<IndexPrice>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<IndexPrice>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::IndexPrice(event.kind),
+        }
+    }
+}
+
+impl From<MarketEvent<FundingRate>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<FundingRate>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::FundingRate(event.kind),
+        }
+    }
+}
+
+impl From<MarketEvent<Ticker>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<Ticker>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::Ticker(event.kind),
+        }
+    }
+}
+
+impl MarketEvent<DataKind> {
+    /// Serialise [`Self`] into the flat JSON schema of a [`FlatMarketEvent`], rather than the
+    /// nested structured form.
+    pub fn to_flat_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&FlatMarketEvent::from(self.clone()))
+    }
+
+    /// Deserialise [`Self`] from the flat JSON schema of a [`FlatMarketEvent`] (see
+    /// [`Self::to_flat_json`]).
+    pub fn from_flat_json(input: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str::<FlatMarketEvent>(input).map(MarketEvent::from)
+    }
+}
+
+/// Flat representation of a [`MarketEvent<DataKind>`](MarketEvent) for analytics tooling (eg/ jq,
+/// columnar loaders), with the [`DataKind`] fields inlined next to the event metadata and
+/// discriminated by a snake_case `kind` field.
+///
+/// eg/ `{"exchange_time":..,"received_time":..,"exchange":"binance_spot","instrument":{..},
+/// "kind":"trade","id":"1","price":1.0,"amount":1.0,..}`
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FlatMarketEvent {
+    pub exchange_time: DateTime<Utc>,
+    pub received_time: DateTime<Utc>,
+    pub exchange: Exchange,
+    pub instrument: Arc<Instrument>,
+    #[serde(flatten)]
+    pub kind: FlatDataKind,
+}
+
+/// Internally tagged mirror of the [`DataKind`] used by a [`FlatMarketEvent`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FlatDataKind {
+    Trade(PublicTrade),
+    OrderBookL1(OrderBookL1),
+    OrderBook(OrderBook),
+    Candle(Candle),
+    ContinuousCandle(ContinuousCandle),
+    Liquidation(Liquidation),
+    InstrumentStatus(InstrumentStatus),
+    IndexPrice(IndexPrice),
+    FundingRate(FundingRate),
+    Ticker(Ticker),
+}
+
+impl From<DataKind> for FlatDataKind {
+    fn from(kind: DataKind) -> Self {
+        match kind {
+            DataKind::Trade(trade) => Self::Trade(trade),
+            DataKind::OrderBookL1(book) => Self::OrderBookL1(book),
+            DataKind::OrderBook(book) => Self::OrderBook(book),
+            DataKind::Candle(candle) => Self::Candle(candle),
+            DataKind::ContinuousCandle(candle) => Self::ContinuousCandle(candle),
+            DataKind::Liquidation(liquidation) => Self::Liquidation(liquidation),
+            DataKind::InstrumentStatus(status) => Self::InstrumentStatus(status),
+            DataKind::IndexPrice(price) => Self::IndexPrice(price),
+            DataKind::FundingRate(rate) => Self::FundingRate(rate),
+            DataKind::Ticker(ticker) => Self::Ticker(ticker),
+        }
+    }
+}
+
+impl From<FlatDataKind> for DataKind {
+    fn from(kind: FlatDataKind) -> Self {
+        match kind {
+            FlatDataKind::Trade(trade) => Self::Trade(trade),
+            FlatDataKind::OrderBookL1(book) => Self::OrderBookL1(book),
+            FlatDataKind::OrderBook(book) => Self::OrderBook(book),
+            FlatDataKind::Candle(candle) => Self::Candle(candle),
+            FlatDataKind::ContinuousCandle(candle) => Self::ContinuousCandle(candle),
+            FlatDataKind::Liquidation(liquidation) => Self::Liquidation(liquidation),
+            FlatDataKind::InstrumentStatus(status) => Self::InstrumentStatus(status),
+            FlatDataKind::IndexPrice(price) => Self::IndexPrice(price),
+            FlatDataKind::FundingRate(rate) => Self::FundingRate(rate),
+            FlatDataKind::Ticker(ticker) => Self::Ticker(ticker),
+        }
+    }
+}
+
+impl From<MarketEvent<DataKind>> for FlatMarketEvent {
+    fn from(event: MarketEvent<DataKind>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: FlatDataKind::from(event.kind),
+        }
+    }
+}
+
+impl From<FlatMarketEvent> for MarketEvent<DataKind> {
+    fn from(event: FlatMarketEvent) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::from(event.kind),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{
+        book::{Level, OrderBookSide},
+        candle::ContractType,
+        status::TradingStatus,
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+
+    #[test]
+    fn test_data_kind_kind() {
+        struct TestCase {
+            input: DataKind,
+            expected: Option<SubKindId>,
+        }
+
+        let time = Utc::now();
+        let candle = Candle {
+            close_time: time,
+            open: 1.0,
+            high: 2.0,
+            low: 0.5,
+            close: 1.5,
+            volume: 10.0,
+            quote_volume: None,
+            trade_count: 5,
+            is_final: true,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: Trade is generated by PublicTrades
+                input: DataKind::Trade(PublicTrade {
+                    id: "1".to_string(),
+                    price: 1.0,
+                    amount: 1.0,
+                    side: Side::Buy,
+                    conditions: vec![],
+                }),
+                expected: Some(SubKindId::PublicTrades),
+            },
+            TestCase {
+                // TC1: OrderBookL1 is generated by OrderBooksL1
+                input: DataKind::OrderBookL1(OrderBookL1 {
+                    last_update_time: time,
+                    best_bid: Level::new(1.0, 1.0),
+                    best_ask: Level::new(2.0, 1.0),
+                }),
+                expected: Some(SubKindId::OrderBooksL1),
+            },
+            TestCase {
+                // TC2: OrderBook is identified as generated by OrderBooksL2
+                input: DataKind::OrderBook(OrderBook {
+                    last_update_time: time,
+                    bids: OrderBookSide::new(Side::Buy, vec![Level::new(1.0, 1.0)]),
+                    asks: OrderBookSide::new(Side::Sell, vec![Level::new(2.0, 1.0)]),
+                }),
+                expected: Some(SubKindId::OrderBooksL2),
+            },
+            TestCase {
+                // TC3: Candle is generated by Candles
+                input: DataKind::Candle(candle),
+                expected: Some(SubKindId::Candles),
+            },
+            TestCase {
+                // TC4: ContinuousCandle is generated by ContinuousCandles
+                input: DataKind::ContinuousCandle(ContinuousCandle {
+                    contract_type: ContractType::Perpetual,
+                    candle,
+                }),
+                expected: Some(SubKindId::ContinuousCandles),
+            },
+            TestCase {
+                // TC5: Liquidation is generated by Liquidations
+                input: DataKind::Liquidation(Liquidation {
+                    side: Side::Sell,
+                    price: 1.0,
+                    quantity: 1.0,
+                    time,
+                }),
+                expected: Some(SubKindId::Liquidations),
+            },
+            TestCase {
+                // TC6: InstrumentStatus is not generated by a SubKind
+                input: DataKind::InstrumentStatus(InstrumentStatus {
+                    status: TradingStatus::Halted,
+                    previous: TradingStatus::Trading,
+                    time,
+                }),
+                expected: None,
+            },
+            TestCase {
+                // TC7: IndexPrice is generated by IndexPrices
+                input: DataKind::IndexPrice(IndexPrice { price: 1.0, time }),
+                expected: Some(SubKindId::IndexPrices),
+            },
+            TestCase {
+                // TC8: FundingRate is generated by FundingRates
+                input: DataKind::FundingRate(FundingRate {
+                    rate: 0.0001,
+                    mark_price: 1.0,
+                    next_funding_time: time,
+                    time,
+                }),
+                expected: Some(SubKindId::FundingRates),
+            },
+            TestCase {
+                // TC9: Ticker is generated by Tickers
+                input: DataKind::Ticker(Ticker {
+                    last_price: 1.0,
+                    high_24h: 1.1,
+                    low_24h: 0.9,
+                    volume_24h: 100.0,
+                    price_change_pct: 2.5,
+                    time,
+                }),
+                expected: Some(SubKindId::Tickers),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(test.input.kind(), test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_market_event_flat_json_round_trip() {
+        struct TestCase {
+            input: MarketEvent<DataKind>,
+            expected_kind: &'static str,
+            expected_field: &'static str,
+        }
+
+        let time = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let event = |kind| MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: Arc::new(Instrument::from(("btc", "usdt", InstrumentKind::Spot))),
+            kind,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: PublicTrade fields are inlined next to the event metadata
+                input: event(DataKind::Trade(PublicTrade {
+                    id: "1".to_string(),
+                    price: 20000.0,
+                    amount: 0.5,
+                    side: Side::Buy,
+                    conditions: vec![],
+                })),
+                expected_kind: "trade",
+                expected_field: "price",
+            },
+            TestCase {
+                // TC1: OrderBook fields are inlined next to the event metadata
+                input: event(DataKind::OrderBook(OrderBook {
+                    last_update_time: time,
+                    bids: OrderBookSide::new(Side::Buy, vec![Level::new(19999.0, 1.0)]),
+                    asks: OrderBookSide::new(Side::Sell, vec![Level::new(20001.0, 2.0)]),
+                })),
+                expected_kind: "order_book",
+                expected_field: "bids",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let flat = test.input.to_flat_json().unwrap();
+
+            let value = serde_json::from_str::<serde_json::Value>(&flat).unwrap();
+            assert_eq!(value["kind"], test.expected_kind, "TC{index} failed");
+            assert_eq!(value["exchange"], "binance_spot", "TC{index} failed");
+            assert!(
+                value.get(test.expected_field).is_some(),
+                "TC{index} failed because {flat} is not flat"
+            );
+
+            let actual = MarketEvent::<DataKind>::from_flat_json(&flat).unwrap();
+            assert_eq!(actual, test.input, "TC{index} failed");
+        }
+    }
+}
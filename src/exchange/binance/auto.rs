@@ -0,0 +1,105 @@
+use super::{futures::BinanceFuturesUsd, spot::BinanceSpot};
+use crate::subscription::Subscription;
+use barter_integration::model::instrument::kind::InstrumentKind;
+use serde::{Deserialize, Serialize};
+
+/// [`Binance`](super::Binance) facade that selects the [`BinanceSpot`] or [`BinanceFuturesUsd`]
+/// server for each [`Subscription`] based on its [`InstrumentKind`].
+///
+/// Use [`StreamBuilder::subscribe_binance`](crate::streams::builder::StreamBuilder::subscribe_binance)
+/// to action mixed kind [`Subscription`]s, which opens one connection per required server.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct BinanceAuto;
+
+impl BinanceAuto {
+    /// Group the provided [`Subscription`]s by the server they route to:
+    /// - [`InstrumentKind::Spot`] routes to [`BinanceSpot`].
+    /// - Every other [`InstrumentKind`] (eg/ [`InstrumentKind::Perpetual`]) routes to
+    ///   [`BinanceFuturesUsd`].
+    #[allow(clippy::type_complexity)]
+    pub fn split<SubIter, Kind>(
+        subscriptions: SubIter,
+    ) -> (
+        Vec<Subscription<BinanceSpot, Kind>>,
+        Vec<Subscription<BinanceFuturesUsd, Kind>>,
+    )
+    where
+        SubIter: IntoIterator<Item = Subscription<BinanceAuto, Kind>>,
+    {
+        let mut spot = Vec::new();
+        let mut futures = Vec::new();
+
+        for Subscription {
+            instrument, kind, ..
+        } in subscriptions
+        {
+            match instrument.kind {
+                InstrumentKind::Spot => spot.push(Subscription {
+                    exchange: BinanceSpot::default(),
+                    instrument,
+                    kind,
+                }),
+                _ => futures.push(Subscription {
+                    exchange: BinanceFuturesUsd::default(),
+                    instrument,
+                    kind,
+                }),
+            }
+        }
+
+        (spot, futures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::trade::PublicTrades;
+    use barter_integration::model::instrument::Instrument;
+
+    #[test]
+    fn test_split() {
+        let (spot, futures) = BinanceAuto::split([
+            Subscription::from((
+                BinanceAuto,
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            )),
+            Subscription::from((
+                BinanceAuto,
+                "btc",
+                "usdt",
+                InstrumentKind::Perpetual,
+                PublicTrades,
+            )),
+            Subscription::from((
+                BinanceAuto,
+                "eth",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            )),
+        ]);
+
+        assert_eq!(
+            spot.into_iter()
+                .map(|sub| sub.instrument)
+                .collect::<Vec<_>>(),
+            vec![
+                Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+            ]
+        );
+        assert_eq!(
+            futures
+                .into_iter()
+                .map(|sub| sub.instrument)
+                .collect::<Vec<_>>(),
+            vec![Instrument::from(("btc", "usdt", InstrumentKind::Perpetual))]
+        );
+    }
+}
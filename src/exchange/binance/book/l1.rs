@@ -1,4 +1,5 @@
 use crate::{
+    clock,
     event::{MarketEvent, MarketIter},
     exchange::{binance::channel::BinanceChannel, subscription::ExchangeSub, ExchangeId},
     subscription::book::{Level, OrderBookL1},
@@ -7,6 +8,7 @@ use crate::{
 use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// [`Binance`](super::super::Binance) real-time OrderBook Level1 (top of book) message.
 ///
@@ -46,13 +48,13 @@ pub struct BinanceOrderBookL1 {
         default = "Utc::now"
     )]
     pub time: DateTime<Utc>,
-    #[serde(alias = "b", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(alias = "b", deserialize_with = "crate::de::de_f64")]
     pub best_bid_price: f64,
-    #[serde(alias = "B", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(alias = "B", deserialize_with = "crate::de::de_f64")]
     pub best_bid_amount: f64,
-    #[serde(alias = "a", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(alias = "a", deserialize_with = "crate::de::de_f64")]
     pub best_ask_price: f64,
-    #[serde(alias = "A", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(alias = "A", deserialize_with = "crate::de::de_f64")]
     pub best_ask_amount: f64,
 }
 
@@ -62,11 +64,13 @@ impl Identifier<Option<SubscriptionId>> for BinanceOrderBookL1 {
     }
 }
 
-impl From<(ExchangeId, Instrument, BinanceOrderBookL1)> for MarketIter<OrderBookL1> {
-    fn from((exchange_id, instrument, book): (ExchangeId, Instrument, BinanceOrderBookL1)) -> Self {
+impl From<(ExchangeId, Arc<Instrument>, BinanceOrderBookL1)> for MarketIter<OrderBookL1> {
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, Arc<Instrument>, BinanceOrderBookL1),
+    ) -> Self {
         Self(vec![Ok(MarketEvent {
             exchange_time: book.time,
-            received_time: Utc::now(),
+            received_time: clock::received_time(),
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: OrderBookL1 {
@@ -78,6 +82,21 @@ impl From<(ExchangeId, Instrument, BinanceOrderBookL1)> for MarketIter<OrderBook
     }
 }
 
+/// Minimal [`BinanceOrderBookL1`] route parsed by a
+/// [`ForwardRaw`](crate::subscription::forward::ForwardRaw) stream to determine the
+/// [`SubscriptionId`] of each raw top of book frame.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceOrderBookL1Route {
+    #[serde(alias = "s", deserialize_with = "de_ob_l1_subscription_id")]
+    pub subscription_id: SubscriptionId,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceOrderBookL1Route {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
 /// Deserialize a [`BinanceOrderBookL1`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`].
 ///
 /// eg/ "@bookTicker|BTCUSDT"
@@ -0,0 +1,245 @@
+use super::l2::BinanceOrderBookL2Snapshot;
+use crate::error::DataError;
+use barter_integration::error::SocketError;
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use std::{
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+use tracing::{debug, warn};
+
+/// [`Binance`](super::super::Binance) HTTP response header containing the request weight used
+/// by this IP in the current one minute window.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#limits>
+pub const HEADER_USED_WEIGHT_1M: &str = "x-mbx-used-weight-1m";
+
+/// Percentage of the [`WeightLimiter`] cap after which requests are proactively throttled.
+pub const THROTTLE_THRESHOLD_PERCENT: u32 = 80;
+
+/// Duration of a [`Binance`](super::super::Binance) request weight window.
+const WINDOW_MS: i64 = 60_000;
+
+/// [`BinanceSpot`](super::super::spot::BinanceSpot) shared [`WeightLimiter`].
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#limits>
+pub static WEIGHT_LIMITER_BINANCE_SPOT: WeightLimiter = WeightLimiter::new(6000);
+
+/// [`BinanceFuturesUsd`](super::super::futures::BinanceFuturesUsd) shared [`WeightLimiter`].
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#limits>
+pub static WEIGHT_LIMITER_BINANCE_FUTURES_USD: WeightLimiter = WeightLimiter::new(2400);
+
+/// Request weight limiter shared by every [`Binance`](super::super::Binance) OrderBook snapshot
+/// fetch, self-tuned by the [`HEADER_USED_WEIGHT_1M`] usage reported in each response.
+///
+/// ### Notes
+/// - The used weight reported by the exchange always overrides the local estimate, so the
+///   limiter adapts to the weight consumed by other processes sharing the IP, and to the actual
+///   cost of each request.
+/// - Once usage reaches the [`THROTTLE_THRESHOLD_PERCENT`] of the cap, requests are delayed in
+///   proportion to how close the window is to the cap. Requests that would exceed the cap wait
+///   until the next window.
+#[derive(Debug)]
+pub struct WeightLimiter {
+    cap: u32,
+    usage: Mutex<WeightUsage>,
+}
+
+/// Request weight used within a one minute window.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct WeightUsage {
+    window: i64,
+    used: u32,
+}
+
+impl WeightLimiter {
+    /// Construct a new [`Self`] with the provided request weight cap per one minute window.
+    pub const fn new(cap: u32) -> Self {
+        Self {
+            cap,
+            usage: Mutex::new(WeightUsage { window: 0, used: 0 }),
+        }
+    }
+
+    /// Record the used request weight reported by the exchange at the provided time.
+    pub fn record(&self, used: u32, time: DateTime<Utc>) {
+        let mut usage = self.usage.lock().unwrap_or_else(PoisonError::into_inner);
+        *usage = WeightUsage {
+            window: window(time),
+            used,
+        };
+    }
+
+    /// Used request weight in the window containing the provided time.
+    pub fn used(&self, time: DateTime<Utc>) -> u32 {
+        let usage = self.usage.lock().unwrap_or_else(PoisonError::into_inner);
+        if usage.window == window(time) {
+            usage.used
+        } else {
+            0
+        }
+    }
+
+    /// Determine the [`Duration`] to wait before sending a request of the provided weight at the
+    /// provided time.
+    pub fn delay(&self, weight: u32, time: DateTime<Utc>) -> Duration {
+        let projected = u64::from(self.used(time) + weight);
+        let cap = u64::from(self.cap);
+        let threshold = cap * u64::from(THROTTLE_THRESHOLD_PERCENT) / 100;
+        let until_next_window_ms =
+            (WINDOW_MS - time.timestamp_millis().rem_euclid(WINDOW_MS)) as u64;
+
+        if projected > cap {
+            // Request would exceed the cap, so wait for the next window
+            Duration::from_millis(until_next_window_ms)
+        } else if projected < threshold || cap == threshold {
+            Duration::ZERO
+        } else {
+            // Delay in proportion to how close the window is to the cap
+            Duration::from_millis(
+                until_next_window_ms * (projected - threshold) / (cap - threshold),
+            )
+        }
+    }
+
+    /// Wait until a request of the provided weight can be sent, and then add the weight to the
+    /// local estimate of the used request weight.
+    pub async fn acquire(&self, weight: u32) {
+        loop {
+            let delay = self.delay(weight, Utc::now());
+            if delay.is_zero() {
+                break;
+            }
+
+            warn!(
+                cap = self.cap,
+                used = self.used(Utc::now()),
+                weight,
+                ?delay,
+                "approaching Binance request weight cap, throttling request"
+            );
+            tokio::time::sleep(delay).await;
+
+            // Re-evaluate if the request would still exceed the cap (eg/ usage reported by a
+            // concurrent request whilst sleeping)
+            if self.used(Utc::now()) + weight <= self.cap {
+                break;
+            }
+        }
+
+        let now = Utc::now();
+        let mut usage = self.usage.lock().unwrap_or_else(PoisonError::into_inner);
+        if usage.window != window(now) {
+            *usage = WeightUsage {
+                window: window(now),
+                used: 0,
+            };
+        }
+        usage.used += weight;
+    }
+}
+
+/// Index of the one minute window containing the provided time.
+fn window(time: DateTime<Utc>) -> i64 {
+    time.timestamp_millis().div_euclid(WINDOW_MS)
+}
+
+/// Parse the [`HEADER_USED_WEIGHT_1M`] used request weight from the provided response headers.
+pub fn used_weight(headers: &HeaderMap) -> Option<u32> {
+    headers
+        .get(HEADER_USED_WEIGHT_1M)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// Fetch a [`BinanceOrderBookL2Snapshot`] of the provided request weight, throttled by the
+/// provided shared [`WeightLimiter`], and feed the reported used weight back into it.
+pub async fn fetch_snapshot(
+    url: String,
+    weight: u32,
+    limiter: &WeightLimiter,
+) -> Result<BinanceOrderBookL2Snapshot, DataError> {
+    limiter.acquire(weight).await;
+
+    let response = reqwest::get(url).await.map_err(SocketError::Http)?;
+
+    if let Some(used) = used_weight(response.headers()) {
+        debug!(used, "recording Binance used request weight");
+        limiter.record(used, Utc::now());
+    }
+
+    response
+        .json::<BinanceOrderBookL2Snapshot>()
+        .await
+        .map_err(|error| DataError::Socket(Box::new(SocketError::Http(error))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_used_weight() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(used_weight(&headers), None);
+
+        headers.insert(HEADER_USED_WEIGHT_1M, HeaderValue::from_static("1234"));
+        assert_eq!(used_weight(&headers), Some(1234));
+    }
+
+    #[test]
+    fn test_weight_limiter_throttles_as_cap_approaches() {
+        struct TestCase {
+            used: u32,
+            expected: Duration,
+        }
+
+        // 30s into the window, with a request weight of 50
+        let limiter = WeightLimiter::new(1000);
+        let time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 30).unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: low usage is not throttled
+                used: 100,
+                expected: Duration::ZERO,
+            },
+            TestCase {
+                // TC1: usage just below the throttle threshold is not throttled
+                used: 740,
+                expected: Duration::ZERO,
+            },
+            TestCase {
+                // TC2: usage above the throttle threshold is throttled
+                used: 850,
+                expected: Duration::from_secs(15),
+            },
+            TestCase {
+                // TC3: throttle increases as the cap approaches
+                used: 900,
+                expected: Duration::from_millis(22_500),
+            },
+            TestCase {
+                // TC4: usage that would exceed the cap waits for the next window
+                used: 960,
+                expected: Duration::from_secs(30),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            limiter.record(test.used, time);
+            assert_eq!(limiter.delay(50, time), test.expected, "TC{index} failed");
+        }
+
+        // Recorded usage expires with the window
+        let next_window = Utc.with_ymd_and_hms(2023, 1, 1, 0, 1, 0).unwrap();
+        assert_eq!(limiter.used(next_window), 0);
+        assert_eq!(limiter.delay(50, next_window), Duration::ZERO);
+    }
+}
@@ -7,6 +7,10 @@ pub mod l1;
 /// Level 2 OrderBook types (top of book).
 pub mod l2;
 
+/// Self-tuning request [`WeightLimiter`](limit::WeightLimiter) shared by every OrderBook snapshot
+/// fetch.
+pub mod limit;
+
 /// [`Binance`](super::Binance) OrderBook level.
 ///
 /// #### Raw Payload Examples
@@ -16,9 +20,9 @@ pub mod l2;
 /// ```
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct BinanceLevel {
-    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    #[serde(deserialize_with = "crate::de::de_f64")]
     pub price: f64,
-    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    #[serde(deserialize_with = "crate::de::de_f64")]
     pub amount: f64,
 }
 
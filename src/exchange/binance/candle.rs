@@ -0,0 +1,214 @@
+use super::BinanceChannel;
+use crate::{
+    clock,
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::candle::{Candle, Interval},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Binance real-time kline message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-streams>
+/// ```json
+/// {
+///     "e": "kline",
+///     "E": 1672515782136,
+///     "s": "BNBBTC",
+///     "k": {
+///         "t": 1672515780000,
+///         "T": 1672515839999,
+///         "s": "BNBBTC",
+///         "i": "1m",
+///         "f": 100,
+///         "L": 200,
+///         "o": "0.0010",
+///         "c": "0.0020",
+///         "h": "0.0025",
+///         "l": "0.0015",
+///         "v": "1000",
+///         "n": 100,
+///         "x": false,
+///         "q": "1.0000",
+///         "V": "500",
+///         "Q": "0.500",
+///         "B": "123456"
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceKlineMessage {
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "s")]
+    pub symbol: String,
+    #[serde(alias = "k")]
+    pub kline: BinanceKline,
+}
+
+/// Binance kline, common to the [`BinanceKlineMessage`] &
+/// [`BinanceContinuousKline`](super::futures::candle::BinanceContinuousKline) messages.
+///
+/// See [`BinanceKlineMessage`] for the raw payload example.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceKline {
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub close_time: DateTime<Utc>,
+    #[serde(alias = "i")]
+    pub interval: Interval,
+    #[serde(alias = "o", deserialize_with = "crate::de::de_f64")]
+    pub open: f64,
+    #[serde(alias = "h", deserialize_with = "crate::de::de_f64")]
+    pub high: f64,
+    #[serde(alias = "l", deserialize_with = "crate::de::de_f64")]
+    pub low: f64,
+    #[serde(alias = "c", deserialize_with = "crate::de::de_f64")]
+    pub close: f64,
+    #[serde(alias = "v", deserialize_with = "crate::de::de_f64")]
+    pub volume: f64,
+    #[serde(alias = "q", deserialize_with = "crate::de::de_f64")]
+    pub quote_volume: f64,
+    #[serde(alias = "n")]
+    pub trade_count: u64,
+    #[serde(alias = "x")]
+    pub closed: bool,
+}
+
+impl From<&BinanceKline> for Candle {
+    fn from(kline: &BinanceKline) -> Self {
+        Self {
+            close_time: kline.close_time,
+            open: kline.open,
+            high: kline.high,
+            low: kline.low,
+            close: kline.close,
+            volume: kline.volume,
+            quote_volume: Some(kline.quote_volume),
+            trade_count: kline.trade_count,
+            is_final: kline.closed,
+        }
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceKlineMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((BinanceChannel::kline(&self.kline.interval), &self.symbol)).id())
+    }
+}
+
+impl From<(ExchangeId, Arc<Instrument>, BinanceKlineMessage)> for MarketIter<Candle> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Arc<Instrument>, BinanceKlineMessage),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: message.time,
+            received_time: clock::received_time(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Candle::from(&message.kline),
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, model::instrument::kind::InstrumentKind,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_kline_message() {
+            let input = r#"
+            {
+                "e": "kline",
+                "E": 1672515782136,
+                "s": "BNBBTC",
+                "k": {
+                    "t": 1672515780000,
+                    "T": 1672515839999,
+                    "s": "BNBBTC",
+                    "i": "1m",
+                    "f": 100,
+                    "L": 200,
+                    "o": "0.0010",
+                    "c": "0.0020",
+                    "h": "0.0025",
+                    "l": "0.0015",
+                    "v": "1000",
+                    "n": 100,
+                    "x": true,
+                    "q": "1.0000",
+                    "V": "500",
+                    "Q": "0.500",
+                    "B": "123456"
+                }
+            }
+            "#;
+
+            let kline = serde_json::from_str::<BinanceKlineMessage>(input).unwrap();
+            assert_eq!(
+                kline,
+                BinanceKlineMessage {
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1672515782136)),
+                    symbol: "BNBBTC".to_string(),
+                    kline: BinanceKline {
+                        close_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515839999
+                        )),
+                        interval: Interval::Minute1,
+                        open: 0.0010,
+                        high: 0.0025,
+                        low: 0.0015,
+                        close: 0.0020,
+                        volume: 1000.0,
+                        quote_volume: 1.0,
+                        trade_count: 100,
+                        closed: true,
+                    },
+                }
+            );
+
+            assert_eq!(kline.id(), Some(SubscriptionId::from("@kline_1m|BNBBTC")));
+
+            let instrument = Instrument::from(("bnb", "btc", InstrumentKind::Spot));
+            let event =
+                MarketIter::<Candle>::from((ExchangeId::BinanceSpot, Arc::new(instrument), kline))
+                    .0
+                    .remove(0)
+                    .unwrap();
+            assert_eq!(
+                event.kind,
+                Candle {
+                    close_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1672515839999
+                    )),
+                    open: 0.0010,
+                    high: 0.0025,
+                    low: 0.0015,
+                    close: 0.0020,
+                    volume: 1000.0,
+                    quote_volume: Some(1.0),
+                    trade_count: 100,
+                    is_final: true,
+                }
+            );
+        }
+    }
+}
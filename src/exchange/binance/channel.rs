@@ -1,22 +1,29 @@
-use super::{futures::BinanceFuturesUsd, Binance};
+use super::{futures::BinanceFuturesUsd, spot::BinanceSpot, Binance};
 use crate::{
     subscription::{
-        book::{OrderBooksL1, OrderBooksL2},
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Depth, OrderBooksTop},
+        candle::{Candles, ContinuousCandles, ContractType, Interval},
+        forward::ForwardRaw,
+        funding::FundingRates,
+        index::IndexPrices,
         liquidation::Liquidations,
-        trade::PublicTrades,
+        raw::Raw,
+        ticker::Tickers,
+        trade::{PublicTrades, TaggedTrades, TradeKind},
         Subscription,
     },
     Identifier,
 };
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Type that defines how to translate a Barter [`Subscription`] into a [`Binance`](super::Binance)
 /// channel to be subscribed to.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
-pub struct BinanceChannel(pub &'static str);
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct BinanceChannel(pub Cow<'static, str>);
 
 impl BinanceChannel {
     /// [`Binance`](super::Binance) real-time trades channel name.
@@ -28,24 +35,70 @@ impl BinanceChannel {
     /// stream is undocumented.
     ///
     /// See discord: <https://discord.com/channels/910237311332151317/923160222711812126/975712874582388757>
-    pub const TRADES: Self = Self("@trade");
+    pub const TRADES: Self = Self(Cow::Borrowed("@trade"));
+
+    /// [`Binance`](super::Binance) real-time aggregated trades channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#aggregate-trade-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#aggregate-trade-streams>
+    pub const AGG_TRADES: Self = Self(Cow::Borrowed("@aggTrade"));
 
     /// [`Binance`](super::Binance) real-time OrderBook Level1 (top of book) channel name.
     ///
     /// See docs:<https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-book-ticker-streams>
     /// See docs:<https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-book-ticker-streams>
-    pub const ORDER_BOOK_L1: Self = Self("@bookTicker");
+    pub const ORDER_BOOK_L1: Self = Self(Cow::Borrowed("@bookTicker"));
 
     /// [`Binance`](super::Binance) OrderBook Level2 channel name (100ms delta updates).
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#diff-depth-stream>
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
-    pub const ORDER_BOOK_L2: Self = Self("@depth@100ms");
+    pub const ORDER_BOOK_L2: Self = Self(Cow::Borrowed("@depth@100ms"));
 
     /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) liquidation orders channel name.
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#liquidation-order-streams>
-    pub const LIQUIDATIONS: Self = Self("@forceOrder");
+    pub const LIQUIDATIONS: Self = Self(Cow::Borrowed("@forceOrder"));
+
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) mark price channel name (1s
+    /// updates), which carries the index price of each perpetual.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+    pub const INDEX_PRICES: Self = Self(Cow::Borrowed("@markPrice@1s"));
+
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) mark price channel name (3s
+    /// updates), which carries the funding rate & next funding time of each perpetual.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+    pub const FUNDING_RATES: Self = Self(Cow::Borrowed("@markPrice"));
+
+    /// [`BinanceSpot`](super::spot::BinanceSpot) 24h rolling window ticker statistics channel
+    /// name (1s updates).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-ticker-streams>
+    pub const TICKERS: Self = Self(Cow::Borrowed("@ticker"));
+
+    /// [`Binance`](super::Binance) kline channel name for the provided [`Interval`].
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-streams>
+    pub fn kline(interval: &Interval) -> Self {
+        Self(Cow::Owned(format!("@kline_{interval}")))
+    }
+
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) continuous contract kline channel
+    /// name for the provided [`ContractType`] & [`Interval`].
+    ///
+    /// Note that the contract type is part of the stream name (eg/
+    /// "btcusdt_perpetual@continuousKline_1m"), so it prefixes the channel that is appended to the
+    /// market by [`Binance::requests`](crate::exchange::Connector::requests).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#continuous-contract-kline-candlestick-streams>
+    pub fn continuous_kline(contract_type: ContractType, interval: &Interval) -> Self {
+        Self(Cow::Owned(format!(
+            "_{contract_type}@continuousKline_{interval}"
+        )))
+    }
 }
 
 impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, PublicTrades> {
@@ -54,6 +107,15 @@ impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, Public
     }
 }
 
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, TaggedTrades> {
+    fn id(&self) -> BinanceChannel {
+        match self.kind.0 {
+            TradeKind::Raw => BinanceChannel::TRADES,
+            TradeKind::Aggregated => BinanceChannel::AGG_TRADES,
+        }
+    }
+}
+
 impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderBooksL1> {
     fn id(&self) -> BinanceChannel {
         BinanceChannel::ORDER_BOOK_L1
@@ -66,14 +128,165 @@ impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderB
     }
 }
 
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderBooksL2Depth> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderBooksTop> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::ORDER_BOOK_L2
+    }
+}
+
 impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, Liquidations> {
     fn id(&self) -> BinanceChannel {
         BinanceChannel::LIQUIDATIONS
     }
 }
 
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, IndexPrices> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::INDEX_PRICES
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, FundingRates> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::FUNDING_RATES
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceSpot, Tickers> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::TICKERS
+    }
+}
+
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, Candles> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::kline(&self.kind.interval)
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, ContinuousCandles> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::continuous_kline(self.kind.contract_type, &self.kind.interval)
+    }
+}
+
+impl<Server, Dto> Identifier<BinanceChannel>
+    for Subscription<Binance<Server>, Raw<PublicTrades, Dto>>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::TRADES
+    }
+}
+
+impl<Server, Dto> Identifier<BinanceChannel>
+    for Subscription<Binance<Server>, Raw<OrderBooksL1, Dto>>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::ORDER_BOOK_L1
+    }
+}
+
+impl<Server, Route> Identifier<BinanceChannel>
+    for Subscription<Binance<Server>, ForwardRaw<PublicTrades, Route>>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::TRADES
+    }
+}
+
+impl<Server, Route> Identifier<BinanceChannel>
+    for Subscription<Binance<Server>, ForwardRaw<OrderBooksL1, Route>>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::ORDER_BOOK_L1
+    }
+}
+
 impl AsRef<str> for BinanceChannel {
     fn as_ref(&self) -> &str {
-        self.0
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{binance::market::BinanceMarket, Connector, ExchangeSub},
+        subscription::candle::ContinuousCandles,
+    };
+    use barter_integration::{
+        model::{instrument::kind::InstrumentKind, SubscriptionId},
+        protocol::websocket::WsMessage,
+    };
+
+    #[test]
+    fn test_continuous_kline_month_and_minute_map_to_distinct_channels() {
+        struct TestCase {
+            interval: Interval,
+            expected_channel: &'static str,
+            expected_stream: &'static str,
+            expected_subscription_id: SubscriptionId,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Interval::Minute1 maps to the lowercase "1m" kline channel
+                interval: Interval::Minute1,
+                expected_channel: "_perpetual@continuousKline_1m",
+                expected_stream: "btcusdt_perpetual@continuousKline_1m",
+                expected_subscription_id: SubscriptionId::from(
+                    "_perpetual@continuousKline_1m|BTCUSDT",
+                ),
+            },
+            TestCase {
+                // TC1: Interval::Month1 maps to the uppercase "1M" kline channel
+                interval: Interval::Month1,
+                expected_channel: "_perpetual@continuousKline_1M",
+                expected_stream: "btcusdt_perpetual@continuousKline_1M",
+                expected_subscription_id: SubscriptionId::from(
+                    "_perpetual@continuousKline_1M|BTCUSDT",
+                ),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let subscription = Subscription::<BinanceFuturesUsd, ContinuousCandles>::new(
+                BinanceFuturesUsd::default(),
+                ("btc", "usdt", InstrumentKind::Perpetual),
+                ContinuousCandles {
+                    contract_type: ContractType::Perpetual,
+                    interval: test.interval,
+                },
+            );
+
+            let exchange_sub = ExchangeSub::<BinanceChannel, BinanceMarket>::new(&subscription);
+            assert_eq!(
+                exchange_sub.channel.0, test.expected_channel,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                exchange_sub.id(),
+                test.expected_subscription_id,
+                "TC{index} failed"
+            );
+
+            let requests = BinanceFuturesUsd::requests(vec![exchange_sub]);
+            let WsMessage::Text(payload) = &requests[0] else {
+                panic!("TC{index} failed: unexpected request {requests:?}");
+            };
+            let payload = serde_json::from_str::<serde_json::Value>(payload).unwrap();
+            assert_eq!(
+                payload["params"],
+                serde_json::json!([test.expected_stream]),
+                "TC{index} failed"
+            );
+        }
     }
 }
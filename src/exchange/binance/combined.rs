@@ -0,0 +1,374 @@
+use super::{
+    book::l1::BinanceOrderBookL1, channel::BinanceChannel, futures::BinanceServerFuturesUsd,
+    market::BinanceMarket, spot::BinanceServerSpot, subscription::BinanceSubResponse,
+    trade::BinanceTrade, Binance,
+};
+use crate::{
+    event::MarketIter,
+    exchange::{
+        rate_limit::OutboundRateLimit, Connector, ExchangeId, ExchangeServer, ExchangeSub,
+        StreamSelector,
+    },
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{
+        book::{OrderBookL1, OrderBooksL1},
+        candle::Interval,
+        trade::{PublicTrade, PublicTrades},
+        Map, Subscription,
+    },
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream, Identifier,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use serde::Deserialize;
+use std::{fmt::Debug, marker::PhantomData, sync::Arc, time::Duration};
+use url::Url;
+
+/// Path of the [`Binance`] combined stream endpoint, which wraps every message in a
+/// [`BinanceCombinedFrame`] envelope.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
+pub const COMBINED_STREAM_PATH_BINANCE: &str = "/stream";
+
+/// [`BinanceCombined`] spot exchange.
+pub type BinanceSpotCombined = BinanceCombined<BinanceServerSpot>;
+
+/// [`BinanceCombined`] perpetual usd exchange.
+pub type BinanceFuturesUsdCombined = BinanceCombined<BinanceServerFuturesUsd>;
+
+/// Generic [`Binance<Server>`](Binance) exchange connected via the combined stream endpoint
+/// (eg/ `wss://stream.binance.com:9443/stream`), which multiplexes many channels over a single
+/// connection.
+///
+/// Subscriptions are actioned identically to [`Binance<Server>`](Binance), with a single
+/// `SUBSCRIBE` request of every stream name.
+///
+/// ### Notes
+/// Every message of the combined endpoint is wrapped in a [`BinanceCombinedFrame`] envelope,
+/// whose `stream` name is used as the [`SubscriptionId`] of the wrapped data.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct BinanceCombined<Server> {
+    server: PhantomData<Server>,
+}
+
+impl<Server> Connector for BinanceCombined<Server>
+where
+    Server: ExchangeServer,
+{
+    const ID: ExchangeId = Server::ID;
+    type Channel = BinanceChannel;
+    type Market = BinanceMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = BinanceSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        let mut url = Binance::<Server>::url()?;
+        url.set_path(COMBINED_STREAM_PATH_BINANCE);
+        Ok(url)
+    }
+
+    fn outbound_rate_limit() -> Option<OutboundRateLimit> {
+        Binance::<Server>::outbound_rate_limit()
+    }
+
+    fn idle_timeout() -> Option<Duration> {
+        Binance::<Server>::idle_timeout()
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        Binance::<Server>::requests(exchange_subs)
+    }
+
+    fn max_subscriptions_per_message() -> Option<usize> {
+        Binance::<Server>::max_subscriptions_per_message()
+    }
+
+    fn expected_responses(map: &Map<Arc<Instrument>>) -> usize {
+        Binance::<Server>::expected_responses(map)
+    }
+
+    fn book_depths() -> &'static [u16] {
+        Binance::<Server>::book_depths()
+    }
+
+    fn supported_intervals() -> &'static [Interval] {
+        Binance::<Server>::supported_intervals()
+    }
+
+    fn snapshot_weight(depth: Option<u16>) -> u32 {
+        Binance::<Server>::snapshot_weight(depth)
+    }
+}
+
+impl<Server> StreamSelector<PublicTrades> for BinanceCombined<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, PublicTrades, BinanceCombinedFrame<BinanceTrade>>,
+    >;
+}
+
+impl<Server> StreamSelector<OrderBooksL1> for BinanceCombined<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, OrderBooksL1, BinanceCombinedFrame<BinanceOrderBookL1>>,
+    >;
+}
+
+impl<Server, Kind> Identifier<BinanceChannel> for Subscription<BinanceCombined<Server>, Kind>
+where
+    Server: Default,
+    Kind: Clone,
+    Subscription<Binance<Server>, Kind>: Identifier<BinanceChannel>,
+{
+    fn id(&self) -> BinanceChannel {
+        Subscription::new(
+            Binance::<Server>::default(),
+            self.instrument.clone(),
+            self.kind.clone(),
+        )
+        .id()
+    }
+}
+
+impl<Server, Kind> Identifier<BinanceMarket> for Subscription<BinanceCombined<Server>, Kind>
+where
+    Server: Default,
+    Kind: Clone,
+    Subscription<Binance<Server>, Kind>: Identifier<BinanceMarket>,
+{
+    fn id(&self) -> BinanceMarket {
+        Subscription::new(
+            Binance::<Server>::default(),
+            self.instrument.clone(),
+            self.kind.clone(),
+        )
+        .id()
+    }
+}
+
+/// [`BinanceCombined`] message envelope wrapping the data of a single stream.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
+/// ```json
+/// {
+///     "stream": "btcusdt@trade",
+///     "data": {
+///         "e":"trade","E":1649324825173,"s":"BTCUSDT","t":1000000000,
+///         "p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,
+///         "T":1749354825200,"m":false,"M":true
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize)]
+pub struct BinanceCombinedFrame<T> {
+    #[serde(
+        rename = "stream",
+        deserialize_with = "de_combined_stream_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub data: T,
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for BinanceCombinedFrame<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl
+    From<(
+        ExchangeId,
+        Arc<Instrument>,
+        BinanceCombinedFrame<BinanceTrade>,
+    )> for MarketIter<PublicTrade>
+{
+    fn from(
+        (exchange_id, instrument, frame): (
+            ExchangeId,
+            Arc<Instrument>,
+            BinanceCombinedFrame<BinanceTrade>,
+        ),
+    ) -> Self {
+        Self::from((exchange_id, instrument, frame.data))
+    }
+}
+
+impl
+    From<(
+        ExchangeId,
+        Arc<Instrument>,
+        BinanceCombinedFrame<BinanceOrderBookL1>,
+    )> for MarketIter<OrderBookL1>
+{
+    fn from(
+        (exchange_id, instrument, frame): (
+            ExchangeId,
+            Arc<Instrument>,
+            BinanceCombinedFrame<BinanceOrderBookL1>,
+        ),
+    ) -> Self {
+        Self::from((exchange_id, instrument, frame.data))
+    }
+}
+
+/// Deserialize a [`BinanceCombinedFrame`] "stream" name (eg/ "btcusdt@trade") as the associated
+/// [`SubscriptionId`] (eg/ "@trade|BTCUSDT").
+pub fn de_combined_stream_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let stream = <&str as Deserialize>::deserialize(deserializer)?;
+    let (market, channel) = stream.split_once('@').ok_or_else(|| {
+        serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(stream),
+            &"<market>@<channel> stream name",
+        )
+    })?;
+
+    Ok(ExchangeSub::from((format!("@{channel}"), market.to_uppercase())).id())
+}
+
+impl<'de, Server> serde::Deserialize<'de> for BinanceCombined<Server>
+where
+    Server: ExchangeServer,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let input = <String as serde::Deserialize>::deserialize(deserializer)?;
+        let expected = Self::ID.as_str();
+
+        if input.as_str() == Self::ID.as_str() {
+            Ok(Self::default())
+        } else {
+            Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(input.as_str()),
+                &expected,
+            ))
+        }
+    }
+}
+
+impl<Server> serde::Serialize for BinanceCombined<Server>
+where
+    Server: ExchangeServer,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let exchange_id = Self::ID.as_str();
+        serializer.serialize_str(exchange_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::DataError, transformer::ExchangeTransformer};
+    use barter_integration::{model::instrument::kind::InstrumentKind, Transformer};
+    use tokio::sync::mpsc;
+
+    #[test]
+    fn test_binance_combined_url() {
+        assert_eq!(
+            BinanceSpotCombined::url().unwrap().as_str(),
+            "wss://stream.binance.com:9443/stream"
+        );
+        assert_eq!(
+            BinanceFuturesUsdCombined::url().unwrap().as_str(),
+            "wss://fstream.binance.com/stream"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_binance_combined_frame_routing() {
+        let subscriptions = ["btc", "eth"]
+            .into_iter()
+            .map(|base| {
+                Subscription::from((
+                    BinanceSpotCombined::default(),
+                    base,
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let instrument_map = subscriptions
+            .iter()
+            .map(|subscription| {
+                let exchange_sub = ExchangeSub::<BinanceChannel, BinanceMarket>::new(subscription);
+                (exchange_sub.id(), Arc::new(subscription.instrument.clone()))
+            })
+            .collect::<Map<_>>();
+
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer =
+            <StatelessTransformer<
+                BinanceSpotCombined,
+                PublicTrades,
+                BinanceCombinedFrame<BinanceTrade>,
+            > as ExchangeTransformer<_, _>>::new(ws_sink_tx, instrument_map)
+            .await
+            .unwrap();
+
+        struct TestCase {
+            input: &'static str,
+            expected: Result<(&'static str, &'static str), ()>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: btcusdt@trade envelope routed to the btc Subscription
+                input: r#"{"stream":"btcusdt@trade","data":{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1,"p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,"T":1749354825200,"m":false,"M":true}}"#,
+                expected: Ok(("btc", "1")),
+            },
+            TestCase {
+                // TC1: ethusdt@trade envelope routed to the eth Subscription
+                input: r#"{"stream":"ethusdt@trade","data":{"e":"trade","E":1649324825173,"s":"ETHUSDT","t":2,"p":"1000.19","q":"1.5","b":10108767791,"a":10108764858,"T":1749354825200,"m":true,"M":true}}"#,
+                expected: Ok(("eth", "2")),
+            },
+            TestCase {
+                // TC2: envelope of an unknown stream is unidentifiable
+                input: r#"{"stream":"solusdt@trade","data":{"e":"trade","E":1649324825173,"s":"SOLUSDT","t":3,"p":"10.0","q":"1.0","b":10108767791,"a":10108764858,"T":1749354825200,"m":true,"M":true}}"#,
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let frame = serde_json::from_str::<BinanceCombinedFrame<BinanceTrade>>(test.input)
+                .unwrap_or_else(|error| panic!("TC{index} failed: {error}"));
+            let actual = transformer.transform(frame);
+            assert_eq!(actual.len(), 1, "TC{index} failed");
+
+            match (&actual[0], test.expected) {
+                (Ok(event), Ok((base, id))) => {
+                    assert_eq!(event.instrument.base.as_ref(), base, "TC{index} failed");
+                    assert_eq!(event.kind.id, id, "TC{index} failed");
+                }
+                (Err(DataError::Unidentifiable(_)), Err(())) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use super::super::{candle::BinanceKline, BinanceChannel};
+use crate::{
+    clock,
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::candle::{Candle, ContinuousCandle, ContractType},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) continuous contract kline message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#continuous-contract-kline-candlestick-streams>
+/// ```json
+/// {
+///     "e": "continuous_kline",
+///     "E": 1607443058651,
+///     "ps": "BTCUSDT",
+///     "ct": "PERPETUAL",
+///     "k": {
+///         "t": 1607443020000,
+///         "T": 1607443079999,
+///         "i": "1m",
+///         "f": 116467658886,
+///         "L": 116468012423,
+///         "o": "18787.00",
+///         "c": "18804.04",
+///         "h": "18804.04",
+///         "l": "18786.54",
+///         "v": "197.664",
+///         "n": 543,
+///         "x": false,
+///         "q": "3715253.19494",
+///         "V": "184.769",
+///         "Q": "3472925.84746",
+///         "B": "0"
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceContinuousKline {
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "ps")]
+    pub pair: String,
+    #[serde(alias = "ct")]
+    pub contract_type: ContractType,
+    #[serde(alias = "k")]
+    pub kline: BinanceKline,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceContinuousKline {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(
+            ExchangeSub::from((
+                BinanceChannel::continuous_kline(self.contract_type, &self.kline.interval),
+                &self.pair,
+            ))
+            .id(),
+        )
+    }
+}
+
+impl From<(ExchangeId, Arc<Instrument>, BinanceContinuousKline)> for MarketIter<ContinuousCandle> {
+    fn from(
+        (exchange_id, instrument, kline): (ExchangeId, Arc<Instrument>, BinanceContinuousKline),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: kline.time,
+            received_time: clock::received_time(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: ContinuousCandle {
+                contract_type: kline.contract_type,
+                candle: Candle::from(&kline.kline),
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::subscription::candle::Interval;
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, model::instrument::kind::InstrumentKind,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_continuous_kline() {
+            let input = r#"
+            {
+                "e": "continuous_kline",
+                "E": 1607443058651,
+                "ps": "BTCUSDT",
+                "ct": "CURRENT_QUARTER",
+                "k": {
+                    "t": 1607443020000,
+                    "T": 1607443079999,
+                    "i": "1m",
+                    "f": 116467658886,
+                    "L": 116468012423,
+                    "o": "18787.00",
+                    "c": "18804.04",
+                    "h": "18804.04",
+                    "l": "18786.54",
+                    "v": "197.664",
+                    "n": 543,
+                    "x": false,
+                    "q": "3715253.19494",
+                    "V": "184.769",
+                    "Q": "3472925.84746",
+                    "B": "0"
+                }
+            }
+            "#;
+
+            let kline = serde_json::from_str::<BinanceContinuousKline>(input).unwrap();
+            assert_eq!(
+                kline,
+                BinanceContinuousKline {
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1607443058651)),
+                    pair: "BTCUSDT".to_string(),
+                    contract_type: ContractType::CurrentQuarter,
+                    kline: BinanceKline {
+                        close_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1607443079999
+                        )),
+                        interval: Interval::Minute1,
+                        open: 18787.00,
+                        high: 18804.04,
+                        low: 18786.54,
+                        close: 18804.04,
+                        volume: 197.664,
+                        quote_volume: 3715253.19494,
+                        trade_count: 543,
+                        closed: false,
+                    },
+                }
+            );
+
+            assert_eq!(
+                kline.id(),
+                Some(SubscriptionId::from(
+                    "_current_quarter@continuousKline_1m|BTCUSDT"
+                ))
+            );
+
+            let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+            let event = MarketIter::<ContinuousCandle>::from((
+                ExchangeId::BinanceFuturesUsd,
+                Arc::new(instrument),
+                kline,
+            ))
+            .0
+            .remove(0)
+            .unwrap();
+            assert_eq!(event.kind.contract_type, ContractType::CurrentQuarter);
+            assert_eq!(event.kind.candle.trade_count, 543);
+            assert_eq!(event.kind.candle.quote_volume, Some(3715253.19494));
+            assert!(!event.kind.candle.is_final);
+        }
+    }
+}
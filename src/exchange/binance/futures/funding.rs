@@ -0,0 +1,211 @@
+use super::super::BinanceChannel;
+use crate::{
+    clock,
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::funding::FundingRate,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) mark price message, used for the funding rate
+/// it contains.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+/// ```json
+/// {
+///     "e": "markPriceUpdate",
+///     "E": 1562305380000,
+///     "s": "BTCUSDT",
+///     "p": "11794.15000000",
+///     "i": "11784.62659091",
+///     "P": "11784.25641265",
+///     "r": "0.00038167",
+///     "T": 1562306400000
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceFundingRate {
+    #[serde(alias = "s", deserialize_with = "de_funding_rate_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "p", deserialize_with = "crate::de::de_f64")]
+    pub mark_price: f64,
+    #[serde(alias = "r", deserialize_with = "crate::de::de_f64")]
+    pub funding_rate: f64,
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceFundingRate {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// Transform a [`BinanceFundingRate`] into a [`FundingRate`], validated against the
+/// [`FundingSchedule`](crate::subscription::funding::FundingSchedule) of the exchange.
+///
+/// A reported next funding time that disagrees with the schedule (eg/ for a symbol with a
+/// non-standard funding interval) yields a [`DataError::FundingTimeMismatch`], followed by the
+/// [`FundingRate`] as reported.
+impl From<(ExchangeId, Arc<Instrument>, BinanceFundingRate)> for MarketIter<FundingRate> {
+    fn from(
+        (exchange_id, instrument, funding): (ExchangeId, Arc<Instrument>, BinanceFundingRate),
+    ) -> Self {
+        let reported = FundingRate {
+            rate: funding.funding_rate,
+            mark_price: funding.mark_price,
+            next_funding_time: funding.next_funding_time,
+            time: funding.time,
+        };
+
+        let event = |kind| MarketEvent {
+            exchange_time: funding.time,
+            received_time: clock::received_time(),
+            exchange: Exchange::from(exchange_id),
+            instrument: Arc::clone(&instrument),
+            kind,
+        };
+
+        match reported.validate(exchange_id) {
+            Ok(funding) => Self(vec![Ok(event(funding))]),
+            Err(error) => Self(vec![Err(error), Ok(event(reported))]),
+        }
+    }
+}
+
+/// Deserialize a [`BinanceFundingRate`] "s" (eg/ "BTCUSDT") as the associated
+/// [`SubscriptionId`] (eg/ "@markPrice|BTCUSDT").
+pub fn de_funding_rate_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::FUNDING_RATES, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binance_funding_rate_validated_against_schedule() {
+        use crate::error::DataError;
+        use barter_integration::model::instrument::kind::InstrumentKind;
+
+        struct TestCase {
+            next_funding_time: i64,
+            expected: Vec<Result<i64, ()>>,
+        }
+
+        // 2023-05-26 12:30:00 UTC, so the next eight-hourly settlement is 16:00:00 UTC
+        let time = DateTime::<Utc>::from_timestamp_millis(1_685_104_200_000).unwrap();
+        let settlement = 1_685_116_800_000;
+
+        let tests = vec![
+            TestCase {
+                // TC0: scheduled next funding time is normalised to the settlement time
+                next_funding_time: settlement + 5,
+                expected: vec![Ok(settlement)],
+            },
+            TestCase {
+                // TC1: unscheduled next funding time is flagged, and then yielded as reported
+                next_funding_time: settlement - 4 * 60 * 60 * 1000,
+                expected: vec![Err(()), Ok(settlement - 4 * 60 * 60 * 1000)],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let funding = BinanceFundingRate {
+                subscription_id: SubscriptionId::from("@markPrice|BTCUSDT"),
+                time,
+                mark_price: 26_500.0,
+                funding_rate: 0.0001,
+                next_funding_time: DateTime::<Utc>::from_timestamp_millis(test.next_funding_time)
+                    .unwrap(),
+            };
+            let instrument = Arc::new(Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)));
+
+            let MarketIter(actual) = MarketIter::<FundingRate>::from((
+                ExchangeId::BinanceFuturesUsd,
+                instrument,
+                funding,
+            ));
+            let actual = actual
+                .into_iter()
+                .map(|result| match result {
+                    Ok(event) => Ok(event.kind.next_funding_time.timestamp_millis()),
+                    Err(DataError::FundingTimeMismatch { .. }) => Err(()),
+                    Err(error) => panic!("TC{index} failed: {error}"),
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_funding_rate() {
+            struct TestCase {
+                input: &'static str,
+                expected: Option<BinanceFundingRate>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid mark price update w/ funding rate
+                    input: r#"
+                    {
+                        "e": "markPriceUpdate", "E": 1562305380000, "s": "BTCUSDT",
+                        "p": "11794.15000000", "i": "11784.62659091", "P": "11784.25641265",
+                        "r": "0.00038167", "T": 1562306400000
+                    }
+                    "#,
+                    expected: Some(BinanceFundingRate {
+                        subscription_id: SubscriptionId::from("@markPrice|BTCUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1562305380000,
+                        )),
+                        mark_price: 11794.15,
+                        funding_rate: 0.00038167,
+                        next_funding_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1562306400000,
+                        )),
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid mark price update w/o funding rate
+                    input: r#"
+                    {
+                        "e": "markPriceUpdate", "E": 1562305380000, "s": "BTCUSDT",
+                        "p": "11794.15000000", "i": "11784.62659091", "T": 1562306400000
+                    }
+                    "#,
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceFundingRate>(test.input).ok();
+                assert_eq!(actual, test.expected, "TC{index} failed");
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use super::super::BinanceChannel;
+use crate::{
+    clock,
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::index::IndexPrice,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) mark price message, used for the index price
+/// it contains.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+/// ```json
+/// {
+///     "e": "markPriceUpdate",
+///     "E": 1562305380000,
+///     "s": "BTCUSDT",
+///     "p": "11794.15000000",
+///     "i": "11784.62659091",
+///     "P": "11784.25641265",
+///     "r": "0.00038167",
+///     "T": 1562306400000
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceIndexPrice {
+    #[serde(alias = "s", deserialize_with = "de_index_price_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "i", deserialize_with = "crate::de::de_f64")]
+    pub index_price: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceIndexPrice {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Arc<Instrument>, BinanceIndexPrice)> for MarketIter<IndexPrice> {
+    fn from(
+        (exchange_id, instrument, index): (ExchangeId, Arc<Instrument>, BinanceIndexPrice),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: index.time,
+            received_time: clock::received_time(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: IndexPrice {
+                price: index.index_price,
+                time: index.time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceIndexPrice`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
+/// (eg/ "@markPrice@1s|BTCUSDT").
+pub fn de_index_price_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::INDEX_PRICES, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_index_price() {
+            struct TestCase {
+                input: &'static str,
+                expected: Option<BinanceIndexPrice>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid mark price update w/ index price
+                    input: r#"
+                    {
+                        "e": "markPriceUpdate", "E": 1562305380000, "s": "BTCUSDT",
+                        "p": "11794.15000000", "i": "11784.62659091", "P": "11784.25641265",
+                        "r": "0.00038167", "T": 1562306400000
+                    }
+                    "#,
+                    expected: Some(BinanceIndexPrice {
+                        subscription_id: SubscriptionId::from("@markPrice@1s|BTCUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1562305380000,
+                        )),
+                        index_price: 11784.62659091,
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid mark price update w/o index price
+                    input: r#"
+                    {
+                        "e": "markPriceUpdate", "E": 1562305380000, "s": "BTCUSDT",
+                        "p": "11794.15000000", "r": "0.00038167", "T": 1562306400000
+                    }
+                    "#,
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceIndexPrice>(test.input).ok();
+                assert_eq!(actual, test.expected, "TC{index} failed");
+            }
+        }
+    }
+}
@@ -1,19 +1,22 @@
-use super::super::book::{l2::BinanceOrderBookL2Snapshot, BinanceLevel};
+use super::super::book::{
+    limit::{fetch_snapshot, WEIGHT_LIMITER_BINANCE_FUTURES_USD},
+    BinanceLevel,
+};
 use crate::{
     error::DataError,
+    exchange::Connector,
     subscription::book::OrderBook,
     transformer::book::{InstrumentOrderBook, OrderBookUpdater},
     Identifier,
-    exchange::Connector,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    error::SocketError,
     model::{instrument::Instrument, SubscriptionId},
     protocol::websocket::WsMessage,
 };
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use tokio::sync::mpsc;
 
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) HTTP OrderBook L2 snapshot url.
@@ -21,6 +24,19 @@ use tokio::sync::mpsc;
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#order-book>
 pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT: &str = "https://fapi.binance.com/fapi/v1/depth";
 
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) HTTP OrderBook L2 snapshot request weight for
+/// the provided depth limit.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#order-book>
+pub fn snapshot_weight(limit: u16) -> u32 {
+    match limit {
+        0..=50 => 2,
+        51..=100 => 5,
+        101..=500 => 10,
+        _ => 20,
+    }
+}
+
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) OrderBook Level2 deltas WebSocket message.
 ///
 /// ### Raw Payload Examples
@@ -163,32 +179,36 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
 
     async fn init<Exchange, Kind>(
         _: mpsc::UnboundedSender<WsMessage>,
-        instrument: Instrument,
+        instrument: Arc<Instrument>,
+        depth: Option<u16>,
     ) -> Result<InstrumentOrderBook<Self>, DataError>
     where
         Exchange: Connector + Send,
         Kind: Send,
     {
         // Construct initial OrderBook snapshot GET url
+        let limit = depth.unwrap_or(100);
         let snapshot_url = format!(
-            "{}?symbol={}{}&limit=100",
+            "{}?symbol={}{}&limit={}",
             HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
             instrument.base.as_ref().to_uppercase(),
-            instrument.quote.as_ref().to_uppercase()
+            instrument.quote.as_ref().to_uppercase(),
+            limit
         );
 
-        // Fetch initial OrderBook snapshot via HTTP
-        let snapshot = reqwest::get(snapshot_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<BinanceOrderBookL2Snapshot>()
-            .await
-            .map_err(SocketError::Http)?;
+        // Fetch initial OrderBook snapshot via HTTP, throttled by the shared WeightLimiter
+        let snapshot = fetch_snapshot(
+            snapshot_url,
+            snapshot_weight(limit),
+            &WEIGHT_LIMITER_BINANCE_FUTURES_USD,
+        )
+        .await?;
 
         Ok(InstrumentOrderBook {
             instrument,
             updater: Self::new(snapshot.last_update_id),
             book: OrderBook::from(snapshot),
+            depth,
         })
     }
 
@@ -407,8 +427,8 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_first_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
+                    (Ok(()), Ok(())) => {
+                        // Test passed
                     }
                     (Err(_), Err(_)) => {
                         // Test passed
@@ -470,8 +490,8 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_next_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
+                    (Ok(()), Ok(())) => {
+                        // Test passed
                     }
                     (Err(_), Err(_)) => {
                         // Test passed
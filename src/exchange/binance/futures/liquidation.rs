@@ -1,5 +1,6 @@
 use super::super::BinanceChannel;
 use crate::{
+    clock,
     event::{MarketEvent, MarketIter},
     exchange::ExchangeId,
     subscription::liquidation::Liquidation,
@@ -8,6 +9,7 @@ use crate::{
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) Liquidation order message.
 ///
@@ -64,9 +66,9 @@ pub struct BinanceLiquidationOrder {
     pub subscription_id: SubscriptionId,
     #[serde(alias = "S")]
     pub side: Side,
-    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(alias = "p", deserialize_with = "crate::de::de_f64")]
     pub price: f64,
-    #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(alias = "q", deserialize_with = "crate::de::de_f64")]
     pub quantity: f64,
     #[serde(
         alias = "T",
@@ -81,13 +83,13 @@ impl Identifier<Option<SubscriptionId>> for BinanceLiquidation {
     }
 }
 
-impl From<(ExchangeId, Instrument, BinanceLiquidation)> for MarketIter<Liquidation> {
+impl From<(ExchangeId, Arc<Instrument>, BinanceLiquidation)> for MarketIter<Liquidation> {
     fn from(
-        (exchange_id, instrument, liquidation): (ExchangeId, Instrument, BinanceLiquidation),
+        (exchange_id, instrument, liquidation): (ExchangeId, Arc<Instrument>, BinanceLiquidation),
     ) -> Self {
         Self(vec![Ok(MarketEvent {
             exchange_time: liquidation.order.time,
-            received_time: Utc::now(),
+            received_time: clock::received_time(),
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: Liquidation {
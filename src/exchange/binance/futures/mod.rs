@@ -1,12 +1,33 @@
-use self::{l2::BinanceFuturesBookUpdater, liquidation::BinanceLiquidation};
+use self::{
+    candle::BinanceContinuousKline, funding::BinanceFundingRate, index::BinanceIndexPrice,
+    l2::BinanceFuturesBookUpdater, liquidation::BinanceLiquidation,
+};
 use super::{Binance, ExchangeServer};
 use crate::{
-    exchange::{ExchangeId, StreamSelector},
-    subscription::{book::OrderBooksL2, liquidation::Liquidations},
-    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
+    exchange::{rate_limit::OutboundRateLimit, ExchangeId, StreamSelector},
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL2Depth, OrderBooksTop},
+        candle::ContinuousCandles,
+        funding::FundingRates,
+        index::IndexPrices,
+        liquidation::Liquidations,
+    },
+    transformer::{
+        book::{MultiBookTransformer, TopOfBookTransformer},
+        stateless::StatelessTransformer,
+    },
     ExchangeWsStream,
 };
 
+/// Continuous contract kline types.
+pub mod candle;
+
+/// Funding rate types.
+pub mod funding;
+
+/// Index price types.
+pub mod index;
+
 /// Level 2 OrderBook types (top of book) and perpetual
 /// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
 pub mod l2;
@@ -32,6 +53,11 @@ impl ExchangeServer for BinanceServerFuturesUsd {
     fn websocket_url() -> &'static str {
         WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD
     }
+
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
+    fn outbound_rate_limit() -> Option<OutboundRateLimit> {
+        Some(OutboundRateLimit::per_second(10))
+    }
 }
 
 impl StreamSelector<OrderBooksL2> for BinanceFuturesUsd {
@@ -39,6 +65,28 @@ impl StreamSelector<OrderBooksL2> for BinanceFuturesUsd {
         ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceFuturesBookUpdater>>;
 }
 
+impl StreamSelector<OrderBooksL2Depth> for BinanceFuturesUsd {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2Depth, BinanceFuturesBookUpdater>>;
+}
+
+impl StreamSelector<OrderBooksTop> for BinanceFuturesUsd {
+    type Stream = ExchangeWsStream<TopOfBookTransformer<Self, BinanceFuturesBookUpdater>>;
+}
+
 impl StreamSelector<Liquidations> for BinanceFuturesUsd {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, Liquidations, BinanceLiquidation>>;
 }
+
+impl StreamSelector<FundingRates> for BinanceFuturesUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, FundingRates, BinanceFundingRate>>;
+}
+
+impl StreamSelector<IndexPrices> for BinanceFuturesUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, IndexPrices, BinanceIndexPrice>>;
+}
+
+impl StreamSelector<ContinuousCandles> for BinanceFuturesUsd {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, ContinuousCandles, BinanceContinuousKline>>;
+}
@@ -1,5 +1,10 @@
 use super::Binance;
-use crate::{subscription::Subscription, Identifier};
+use crate::{
+    exchange::market::{split_known_quote, MarketId},
+    subscription::Subscription,
+    Identifier,
+};
+use barter_integration::model::instrument::{symbol::Symbol, Instrument};
 use serde::{Deserialize, Serialize};
 
 /// Type that defines how to translate a Barter [`Subscription`] into a [`Binance`](super::Binance)
@@ -10,12 +15,23 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BinanceMarket(pub String);
 
-impl<Server, Kind> Identifier<BinanceMarket> for Subscription<Binance<Server>, Kind> {
-    fn id(&self) -> BinanceMarket {
+impl<Server> MarketId for Binance<Server> {
+    fn market_id(instrument: &Instrument) -> String {
         // Notes:
         // - Must be lowercase when subscribing (transformed to lowercase by Binance fn requests).
         // - Must be uppercase since Binance sends message with uppercase MARKET (eg/ BTCUSDT).
-        BinanceMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase())
+        format!("{}{}", instrument.base, instrument.quote).to_uppercase()
+    }
+
+    fn parse_market_id(market: &str) -> Option<(Symbol, Symbol)> {
+        split_known_quote(&market.to_uppercase())
+            .map(|(base, quote)| (Symbol::new(base), Symbol::new(quote)))
+    }
+}
+
+impl<Server, Kind> Identifier<BinanceMarket> for Subscription<Binance<Server>, Kind> {
+    fn id(&self) -> BinanceMarket {
+        BinanceMarket(Binance::<Server>::market_id(&self.instrument))
     }
 }
 
@@ -1,28 +1,55 @@
 use self::{
-    book::l1::BinanceOrderBookL1, channel::BinanceChannel, market::BinanceMarket,
-    subscription::BinanceSubResponse, trade::BinanceTrade,
+    book::l1::{BinanceOrderBookL1, BinanceOrderBookL1Route},
+    candle::BinanceKlineMessage,
+    channel::BinanceChannel,
+    market::BinanceMarket,
+    subscription::BinanceSubResponse,
+    trade::{BinanceTaggedTrade, BinanceTrade, BinanceTradeRoute},
 };
 use crate::{
-    exchange::{Connector, ExchangeId, ExchangeServer, ExchangeSub, StreamSelector},
+    exchange::{
+        rate_limit::OutboundRateLimit, Connector, ExchangeId, ExchangeServer, ExchangeSub,
+        StreamSelector,
+    },
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Map},
+    subscription::{
+        book::OrderBooksL1,
+        candle::{Candles, Interval},
+        forward::{ForwardRaw, RoutedFrame},
+        raw::Raw,
+        trade::{PublicTrades, TaggedTrades},
+        Map,
+    },
     transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    ExchangeRawWsStream, ExchangeWsStream,
 };
 use barter_integration::{
     error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
 };
-use std::{fmt::Debug, marker::PhantomData};
+use std::{fmt::Debug, marker::PhantomData, sync::Arc, time::Duration};
 use url::Url;
 
+/// [`BinanceAuto`](auto::BinanceAuto) facade that routes each
+/// [`Subscription`](crate::subscription::Subscription) to the [`BinanceSpot`](spot::BinanceSpot)
+/// or [`BinanceFuturesUsd`](futures::BinanceFuturesUsd) server based on its instrument kind.
+pub mod auto;
+
 /// OrderBook types common to both [`BinanceSpot`](spot::BinanceSpot) and
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod book;
 
+/// Kline types common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod candle;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// [`BinanceCombined<Server>`](combined::BinanceCombined) exchange that multiplexes many
+/// subscriptions over a single combined stream connection.
+pub mod combined;
+
 /// [`ExchangeServer`] and [`StreamSelector`] implementations for
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod futures;
@@ -35,6 +62,10 @@ pub mod market;
 /// [`BinanceSpot`](spot::BinanceSpot).
 pub mod spot;
 
+/// [`BinanceStatusMonitor`](status::BinanceStatusMonitor) that polls exchange information to
+/// detect instrument trading status changes (eg/ TRADING -> HALT).
+pub mod status;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration::Validator) common to both [`BinanceSpot`](spot::BinanceSpot)
 /// and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
@@ -44,6 +75,22 @@ pub mod subscription;
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod trade;
 
+/// [`Duration`] after which an idle [`Binance`] connection is considered dead.
+///
+/// Binance sends a protocol level ping every 20 seconds (spot) to 3 minutes (futures), so a
+/// connection that is silent for this long has been lost.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
+pub const IDLE_TIMEOUT_BINANCE: Duration = Duration::from_secs(300);
+
+/// Maximum number of streams [`Binance`] subscribes to in a single "SUBSCRIBE" request.
+///
+/// Larger requests are rejected, so bigger batches are chunked into multiple requests that are
+/// each acknowledged with their own response.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#live-subscribing-unsubscribing-to-streams>
+pub const MAX_SUBSCRIPTIONS_PER_MESSAGE_BINANCE: usize = 200;
+
 /// Generic [`Binance<Server>`](Binance) exchange.
 ///
 /// ### Notes
@@ -69,34 +116,86 @@ where
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
 
+    fn outbound_rate_limit() -> Option<OutboundRateLimit> {
+        Server::outbound_rate_limit()
+    }
+
+    fn idle_timeout() -> Option<Duration> {
+        Some(IDLE_TIMEOUT_BINANCE)
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        let stream_names = exchange_subs
-            .into_iter()
-            .map(|sub| {
-                // Note:
-                // Market must be lowercase when subscribing, but lowercase in general since
-                // Binance sends message with uppercase MARKET (eg/ BTCUSDT).
-                format!(
-                    "{}{}",
-                    sub.market.as_ref().to_lowercase(),
-                    sub.channel.as_ref()
-                )
-            })
-            .collect::<Vec<String>>();
-
-        vec![WsMessage::Text(
-            serde_json::json!({
-                "method": "SUBSCRIBE",
-                "params": stream_names,
-                "id": 1
-            })
-            .to_string(),
-        )]
+        vec![stream_request("SUBSCRIBE", exchange_subs)]
     }
 
-    fn expected_responses(_: &Map<Instrument>) -> usize {
-        1
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        vec![stream_request("UNSUBSCRIBE", exchange_subs)]
     }
+
+    fn max_subscriptions_per_message() -> Option<usize> {
+        Some(MAX_SUBSCRIPTIONS_PER_MESSAGE_BINANCE)
+    }
+
+    fn expected_responses(map: &Map<Arc<Instrument>>) -> usize {
+        map.0.len().div_ceil(MAX_SUBSCRIPTIONS_PER_MESSAGE_BINANCE)
+    }
+
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#order-book>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#order-book>
+    fn book_depths() -> &'static [u16] {
+        match Server::ID {
+            ExchangeId::BinanceFuturesUsd => &[5, 10, 20, 50, 100, 500, 1000],
+            _ => &[5, 10, 20, 50, 100, 500, 1000, 5000],
+        }
+    }
+
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#continuous-contract-kline-candlestick-streams>
+    fn supported_intervals() -> &'static [Interval] {
+        &Interval::STANDARD
+    }
+
+    fn snapshot_weight(depth: Option<u16>) -> u32 {
+        let limit = depth.unwrap_or(100);
+        match Server::ID {
+            ExchangeId::BinanceFuturesUsd => futures::l2::snapshot_weight(limit),
+            _ => spot::l2::snapshot_weight(limit),
+        }
+    }
+}
+
+/// Construct the [`Binance`] `method` (eg/ "SUBSCRIBE") [`WsMessage`] for the stream names of
+/// the provided [`ExchangeSub`]s.
+///
+/// eg/ {"method": "UNSUBSCRIBE", "params": ["btcusdt@trade"], "id": 1}
+fn stream_request(
+    method: &str,
+    exchange_subs: Vec<ExchangeSub<BinanceChannel, BinanceMarket>>,
+) -> WsMessage {
+    let stream_names = exchange_subs
+        .into_iter()
+        .map(|sub| {
+            // Note:
+            // Market must be lowercase when subscribing, but lowercase in general since
+            // Binance sends message with uppercase MARKET (eg/ BTCUSDT).
+            format!(
+                "{}{}",
+                sub.market.as_ref().to_lowercase(),
+                sub.channel.as_ref()
+            )
+        })
+        .collect::<Vec<String>>();
+
+    WsMessage::Text(
+        serde_json::json!({
+            "method": method,
+            "params": stream_names,
+            "id": 1
+        })
+        .to_string(),
+    )
 }
 
 impl<Server> StreamSelector<PublicTrades> for Binance<Server>
@@ -106,6 +205,13 @@ where
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BinanceTrade>>;
 }
 
+impl<Server> StreamSelector<TaggedTrades> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, TaggedTrades, BinanceTaggedTrade>>;
+}
+
 impl<Server> StreamSelector<OrderBooksL1> for Binance<Server>
 where
     Server: ExchangeServer + Debug + Send + Sync,
@@ -113,6 +219,56 @@ where
     type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, BinanceOrderBookL1>>;
 }
 
+impl<Server> StreamSelector<Candles> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Candles, BinanceKlineMessage>>;
+}
+
+impl<Server> StreamSelector<Raw<PublicTrades, BinanceTrade>> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Raw<PublicTrades, BinanceTrade>, BinanceTrade>>;
+}
+
+impl<Server> StreamSelector<Raw<OrderBooksL1, BinanceOrderBookL1>> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Raw<OrderBooksL1, BinanceOrderBookL1>, BinanceOrderBookL1>,
+    >;
+}
+
+impl<Server> StreamSelector<ForwardRaw<PublicTrades, BinanceTradeRoute>> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeRawWsStream<
+        StatelessTransformer<
+            Self,
+            ForwardRaw<PublicTrades, BinanceTradeRoute>,
+            RoutedFrame<BinanceTradeRoute>,
+        >,
+    >;
+}
+
+impl<Server> StreamSelector<ForwardRaw<OrderBooksL1, BinanceOrderBookL1Route>> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeRawWsStream<
+        StatelessTransformer<
+            Self,
+            ForwardRaw<OrderBooksL1, BinanceOrderBookL1Route>,
+            RoutedFrame<BinanceOrderBookL1Route>,
+        >,
+    >;
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Binance<Server>
 where
     Server: ExchangeServer,
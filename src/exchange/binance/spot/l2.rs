@@ -1,6 +1,14 @@
-use super::super::book::{l2::BinanceOrderBookL2Snapshot, BinanceLevel};
+use super::super::book::{
+    l2::BinanceOrderBookL2Snapshot,
+    limit::{fetch_snapshot, WEIGHT_LIMITER_BINANCE_SPOT},
+    BinanceLevel,
+};
 use crate::{
-    error::DataError, exchange::{Connector, ExchangeId}, subscription::book::OrderBook, transformer::book::{InstrumentOrderBook, OrderBookUpdater}, Identifier
+    error::DataError,
+    exchange::{Connector, ExchangeId},
+    subscription::book::OrderBook,
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
 };
 use async_trait::async_trait;
 use barter_integration::{
@@ -9,8 +17,11 @@ use barter_integration::{
     protocol::websocket::WsMessage,
 };
 use chrono::Utc;
+use futures::future::BoxFuture;
 use serde::{Deserialize, Serialize};
-use tokio::sync::mpsc;
+use std::{fmt::Debug, sync::Arc};
+use tokio::sync::{mpsc, oneshot};
+use tracing::warn;
 
 /// [`BinanceSpot`](super::BinanceSpot) HTTP OrderBook L2 snapshot url.
 ///
@@ -18,6 +29,28 @@ use tokio::sync::mpsc;
 pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/depth";
 pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCEUS_SPOT: &str = " https://www.binance.us/api/v1/depth";
 
+/// [`BinanceSpot`](super::BinanceSpot) HTTP OrderBook L2 snapshot request weight for the
+/// provided depth limit.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#order-book>
+pub fn snapshot_weight(limit: u16) -> u32 {
+    match limit {
+        0..=100 => 5,
+        101..=500 => 25,
+        501..=1000 => 50,
+        _ => 250,
+    }
+}
+
+/// Maximum number of consecutive times a [`BinanceSpotBookUpdater`] re-fetches a stale
+/// [`BinanceOrderBookL2Snapshot`] before yielding a terminal [`DataError::InvalidSequence`].
+pub const MAX_SNAPSHOT_REFETCHES: u32 = 3;
+
+/// Function that fetches a fresh [`BinanceOrderBookL2Snapshot`] of an instrument.
+pub type SnapshotFetcher = Arc<
+    dyn Fn() -> BoxFuture<'static, Result<BinanceOrderBookL2Snapshot, DataError>> + Send + Sync,
+>;
+
 /// [`BinanceSpot`](super::BinanceSpot) OrderBook Level2 deltas WebSocket message.
 ///
 /// ### Raw Payload Examples
@@ -81,13 +114,89 @@ impl Identifier<Option<SubscriptionId>> for BinanceSpotOrderBookL2Delta {
 ///  - Receiving an event that removes a price level that is not in your local order book can happen and is normal.
 ///  - Uppercase U => first_update_id
 ///  - Lowercase u => last_update_id,
+///  - During volatile periods Binance can return a stale snapshot that the buffered events have
+///    already surpassed (ie/ the first event has U > lastUpdateId+1). Rather than applying the
+///    gap, the snapshot is re-fetched up to [`MAX_SNAPSHOT_REFETCHES`] times, buffering events
+///    until the fresh snapshot arrives.
+///  - A gap between subsequent events (ie/ a dropped update) is never applied. The
+///    [`OrderBook`] is left untouched and a terminal [`DataError::InvalidSequence`] is yielded,
+///    so the consumer loop re-connects and re-initialises the [`OrderBook`] from a fresh
+///    snapshot rather than emitting a corrupt book.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#how-to-manage-a-local-order-book-correctly>
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[derive(Debug)]
 pub struct BinanceSpotBookUpdater {
     pub updates_processed: u64,
     pub last_update_id: u64,
     pub prev_last_update_id: u64,
+    refetch: Option<SnapshotRefetch>,
+}
+
+/// Stale [`BinanceOrderBookL2Snapshot`] re-fetch state of a [`BinanceSpotBookUpdater`].
+struct SnapshotRefetch {
+    fetch: SnapshotFetcher,
+    refetches: u32,
+    pending: Option<oneshot::Receiver<Result<BinanceOrderBookL2Snapshot, DataError>>>,
+    buffered: Vec<BinanceSpotOrderBookL2Delta>,
+}
+
+impl Debug for SnapshotRefetch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SnapshotRefetch")
+            .field("refetches", &self.refetches)
+            .field("pending", &self.pending.is_some())
+            .field("buffered", &self.buffered.len())
+            .finish()
+    }
+}
+
+impl SnapshotRefetch {
+    fn new(fetch: SnapshotFetcher) -> Self {
+        Self {
+            fetch,
+            refetches: 0,
+            pending: None,
+            buffered: Vec::new(),
+        }
+    }
+
+    fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Spawn a re-fetch of the snapshot, buffering the update that surpassed the stale snapshot.
+    fn start(&mut self, update: BinanceSpotOrderBookL2Delta) {
+        let (snapshot_tx, snapshot_rx) = oneshot::channel();
+        let fetch = (self.fetch)();
+        tokio::spawn(async move {
+            let _ = snapshot_tx.send(fetch.await);
+        });
+
+        self.refetches += 1;
+        self.pending = Some(snapshot_rx);
+        self.buffered.push(update);
+    }
+
+    /// Take the re-fetched snapshot if it has arrived.
+    fn try_take(&mut self) -> Result<Option<BinanceOrderBookL2Snapshot>, DataError> {
+        let Some(pending) = self.pending.as_mut() else {
+            return Ok(None);
+        };
+
+        match pending.try_recv() {
+            Ok(snapshot) => {
+                self.pending = None;
+                snapshot.map(Some)
+            }
+            Err(oneshot::error::TryRecvError::Empty) => Ok(None),
+            Err(oneshot::error::TryRecvError::Closed) => {
+                self.pending = None;
+                Err(DataError::Socket(Box::new(SocketError::Terminated(
+                    "OrderBook snapshot re-fetch task terminated".to_owned(),
+                ))))
+            }
+        }
+    }
 }
 
 impl BinanceSpotBookUpdater {
@@ -98,7 +207,106 @@ impl BinanceSpotBookUpdater {
             updates_processed: 0,
             prev_last_update_id: last_update_id,
             last_update_id,
+            refetch: None,
+        }
+    }
+
+    /// Re-fetch stale snapshots using the provided [`SnapshotFetcher`], rather than yielding a
+    /// terminal [`DataError::InvalidSequence`] immediately.
+    pub fn with_refetch(self, fetch: SnapshotFetcher) -> Self {
+        Self {
+            refetch: Some(SnapshotRefetch::new(fetch)),
+            ..self
+        }
+    }
+
+    /// Bootstrap the [`OrderBook`] of the provided instrument from a [`BinanceOrderBookL2Snapshot`]
+    /// fetched using the [`SnapshotFetcher`], which is also used to re-fetch stale snapshots.
+    ///
+    /// Deltas received whilst the snapshot is in flight are buffered by the socket, and replayed
+    /// on top of the snapshot via [`OrderBookUpdater::update`], dropping any the snapshot has
+    /// already surpassed. The first [`OrderBook`] yielded is therefore always a full book.
+    pub async fn bootstrap(
+        instrument: Arc<Instrument>,
+        depth: Option<u16>,
+        fetch: SnapshotFetcher,
+    ) -> Result<InstrumentOrderBook<Self>, DataError> {
+        let snapshot = fetch().await?;
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(snapshot.last_update_id).with_refetch(fetch),
+            book: OrderBook::from(snapshot),
+            depth,
+        })
+    }
+
+    /// Reset [`Self`] to the provided fresh snapshot `last_update_id`.
+    fn reset(&mut self, last_update_id: u64) {
+        self.updates_processed = 0;
+        self.prev_last_update_id = last_update_id;
+        self.last_update_id = last_update_id;
+    }
+
+    /// Apply the update to the [`OrderBook`], re-fetching the snapshot if the update surpassed
+    /// a stale snapshot.
+    fn apply(
+        &mut self,
+        book: &mut OrderBook,
+        update: BinanceSpotOrderBookL2Delta,
+    ) -> Result<Option<OrderBook>, DataError> {
+        // BinanceSpot: How To Manage A Local OrderBook Correctly
+        // See Self's Rust Docs for more information on each numbered step
+        // See docs: <https://binance-docs.github.io/apidocs/spot/en/#how-to-manage-a-local-order-book-correctly>
+
+        // 4. Drop any event where u is <= lastUpdateId in the snapshot:
+        if update.last_update_id <= self.last_update_id {
+            return Ok(None);
+        }
+
+        if self.is_first_update() {
+            // 5. The first processed event should have U <= lastUpdateId AND u >= lastUpdateId:
+            if let Err(error) = self.validate_first_update(&update) {
+                // Snapshot is stale if the buffered updates have already surpassed it
+                let stale = update.first_update_id > self.last_update_id + 1;
+                match self.refetch.as_mut() {
+                    Some(refetch) if stale && refetch.refetches < MAX_SNAPSHOT_REFETCHES => {
+                        warn!(
+                            subscription_id = %update.subscription_id,
+                            last_update_id = self.last_update_id,
+                            first_update_id = update.first_update_id,
+                            refetch = refetch.refetches + 1,
+                            action = "re-fetching snapshot",
+                            "BinanceSpot OrderBook snapshot is stale"
+                        );
+                        refetch.start(update);
+                        return Ok(None);
+                    }
+                    _ => return Err(error),
+                }
+            }
+
+            if let Some(refetch) = self.refetch.as_mut() {
+                refetch.refetches = 0;
+            }
+        } else {
+            // 6. Each new event's pu should be equal to the previous event's u:
+            self.validate_next_update(&update)?;
         }
+
+        // Update OrderBook metadata & Levels:
+        // 7. The data in each event is the absolute quantity for a price level.
+        // 8. If the quantity is 0, remove the price level.
+        book.last_update_time = Utc::now();
+        book.bids.upsert(update.bids);
+        book.asks.upsert(update.asks);
+
+        // Update OrderBookUpdater metadata
+        self.updates_processed += 1;
+        self.prev_last_update_id = self.last_update_id;
+        self.last_update_id = update.last_update_id;
+
+        Ok(Some(book.snapshot()))
     }
 
     /// BinanceSpot: How To Manage A Local OrderBook Correctly: Step 5:
@@ -156,34 +364,38 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
 
     async fn init<Exchange, Kind>(
         _: mpsc::UnboundedSender<WsMessage>,
-        instrument: Instrument,
+        instrument: Arc<Instrument>,
+        depth: Option<u16>,
     ) -> Result<InstrumentOrderBook<Self>, DataError>
     where
         Exchange: Connector + Send,
         Kind: Send,
     {
-
         // Construct initial OrderBook snapshot GET url
+        let limit = depth.unwrap_or(100);
         let snapshot_url = format!(
-            "{}?symbol={}{}&limit=100",
-            if Exchange::ID == ExchangeId::BinanceUSSpot {HTTP_BOOK_L2_SNAPSHOT_URL_BINANCEUS_SPOT} else {HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT},
+            "{}?symbol={}{}&limit={}",
+            if Exchange::ID == ExchangeId::BinanceUSSpot {
+                HTTP_BOOK_L2_SNAPSHOT_URL_BINANCEUS_SPOT
+            } else {
+                HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT
+            },
             instrument.base.as_ref().to_uppercase(),
-            instrument.quote.as_ref().to_uppercase()
+            instrument.quote.as_ref().to_uppercase(),
+            limit
         );
 
-        // Fetch initial OrderBook snapshot via HTTP
-        let snapshot = reqwest::get(snapshot_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<BinanceOrderBookL2Snapshot>()
-            .await
-            .map_err(SocketError::Http)?;
+        // Fetch initial OrderBook snapshot via HTTP, throttled by the shared WeightLimiter
+        let weight = snapshot_weight(limit);
+        let fetch: SnapshotFetcher = Arc::new(move || {
+            Box::pin(fetch_snapshot(
+                snapshot_url.clone(),
+                weight,
+                &WEIGHT_LIMITER_BINANCE_SPOT,
+            ))
+        });
 
-        Ok(InstrumentOrderBook {
-            instrument,
-            updater: Self::new(snapshot.last_update_id),
-            book: OrderBook::from(snapshot),
-        })
+        Self::bootstrap(instrument, depth, fetch).await
     }
 
     fn update(
@@ -191,36 +403,30 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
         book: &mut Self::OrderBook,
         update: Self::Update,
     ) -> Result<Option<Self::OrderBook>, DataError> {
-        // BinanceSpot: How To Manage A Local OrderBook Correctly
-        // See Self's Rust Docs for more information on each numbered step
-        // See docs: <https://binance-docs.github.io/apidocs/spot/en/#how-to-manage-a-local-order-book-correctly>
-
-        // 4. Drop any event where u is <= lastUpdateId in the snapshot:
-        if update.last_update_id <= self.last_update_id {
+        // Buffer updates whilst re-fetching a stale snapshot
+        let Some(refetch) = self.refetch.as_mut().filter(|refetch| refetch.is_pending()) else {
+            return self.apply(book, update);
+        };
+        refetch.buffered.push(update);
+        let Some(snapshot) = refetch.try_take()? else {
             return Ok(None);
-        }
-
-        if self.is_first_update() {
-            // 5. The first processed event should have U <= lastUpdateId AND u >= lastUpdateId:
-            self.validate_first_update(&update)?;
-        } else {
-            // 6. Each new event's pu should be equal to the previous event's u:
-            self.validate_next_update(&update)?;
-        }
+        };
 
-        // Update OrderBook metadata & Levels:
-        // 7. The data in each event is the absolute quantity for a price level.
-        // 8. If the quantity is 0, remove the price level.
-        book.last_update_time = Utc::now();
-        book.bids.upsert(update.bids);
-        book.asks.upsert(update.asks);
+        // Reset the OrderBook to the fresh snapshot, and replay the buffered updates
+        let buffered = std::mem::take(&mut refetch.buffered);
+        self.reset(snapshot.last_update_id);
+        *book = OrderBook::from(snapshot);
 
-        // Update OrderBookUpdater metadata
-        self.updates_processed += 1;
-        self.prev_last_update_id = self.last_update_id;
-        self.last_update_id = update.last_update_id;
+        let mut latest = None;
+        for update in buffered {
+            match self.refetch.as_mut() {
+                // Fresh snapshot was also stale, so keep buffering for the next re-fetch
+                Some(refetch) if refetch.is_pending() => refetch.buffered.push(update),
+                _ => latest = self.apply(book, update)?.or(latest),
+            }
+        }
 
-        Ok(Some(book.snapshot()))
+        Ok(latest)
     }
 }
 
@@ -273,7 +479,11 @@ mod tests {
     mod binance_spot_book_updater {
         use super::*;
         use crate::subscription::book::{Level, OrderBookSide};
-        use barter_integration::model::Side;
+        use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+        use std::{
+            sync::atomic::{AtomicU32, Ordering},
+            time::Duration,
+        };
 
         #[test]
         fn test_is_first_update() {
@@ -294,6 +504,7 @@ mod tests {
                         updates_processed: 10,
                         last_update_id: 100,
                         prev_last_update_id: 90,
+                        refetch: None,
                     },
                     expected: false,
                 },
@@ -324,6 +535,7 @@ mod tests {
                         updates_processed: 0,
                         last_update_id: 100,
                         prev_last_update_id: 90,
+                        refetch: None,
                     },
                     input: BinanceSpotOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -340,6 +552,7 @@ mod tests {
                         updates_processed: 0,
                         last_update_id: 100,
                         prev_last_update_id: 90,
+                        refetch: None,
                     },
                     input: BinanceSpotOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -359,6 +572,7 @@ mod tests {
                         updates_processed: 0,
                         last_update_id: 100,
                         prev_last_update_id: 90,
+                        refetch: None,
                     },
                     input: BinanceSpotOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -378,6 +592,7 @@ mod tests {
                         updates_processed: 0,
                         last_update_id: 100,
                         prev_last_update_id: 90,
+                        refetch: None,
                     },
                     input: BinanceSpotOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -396,8 +611,8 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_first_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
+                    (Ok(()), Ok(())) => {
+                        // Test passed
                     }
                     (Err(_), Err(_)) => {
                         // Test passed
@@ -425,6 +640,7 @@ mod tests {
                         updates_processed: 100,
                         last_update_id: 100,
                         prev_last_update_id: 100,
+                        refetch: None,
                     },
                     input: BinanceSpotOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -441,6 +657,7 @@ mod tests {
                         updates_processed: 100,
                         last_update_id: 100,
                         prev_last_update_id: 90,
+                        refetch: None,
                     },
                     input: BinanceSpotOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -459,8 +676,8 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_next_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
+                    (Ok(()), Ok(())) => {
+                        // Test passed
                     }
                     (Err(_), Err(_)) => {
                         // Test passed
@@ -491,6 +708,7 @@ mod tests {
                         updates_processed: 100,
                         last_update_id: 100,
                         prev_last_update_id: 0,
+                        refetch: None,
                     },
                     book: OrderBook {
                         last_update_time: time,
@@ -512,6 +730,7 @@ mod tests {
                         updates_processed: 100,
                         last_update_id: 100,
                         prev_last_update_id: 100,
+                        refetch: None,
                     },
                     book: OrderBook {
                         last_update_time: time,
@@ -597,5 +816,228 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_update_sequence_gap() {
+            let time = Utc::now();
+            let book = OrderBook {
+                last_update_time: time,
+                bids: OrderBookSide::new(Side::Buy, vec![Level::new(50, 1)]),
+                asks: OrderBookSide::new(Side::Sell, vec![Level::new(100, 1)]),
+            };
+            let mut updater = BinanceSpotBookUpdater {
+                updates_processed: 100,
+                last_update_id: 100,
+                prev_last_update_id: 90,
+                refetch: None,
+            };
+
+            // Update 101..=104 was dropped, so the next delta does not follow on from 100
+            let mut actual_book = book.clone();
+            let actual = updater.update(
+                &mut actual_book,
+                delta(105, 110, vec![(50.0, 0.0)], vec![(100.0, 5.0)]),
+            );
+
+            assert!(
+                matches!(
+                    actual,
+                    Err(DataError::InvalidSequence {
+                        prev_last_update_id: 100,
+                        first_update_id: 105,
+                    })
+                ),
+                "actual: {actual:?}"
+            );
+            assert!(actual.unwrap_err().is_terminal());
+
+            // Gap is not applied to the OrderBook or the updater sequence
+            assert_eq!(actual_book, book);
+            assert_eq!(updater.last_update_id, 100);
+            assert_eq!(updater.updates_processed, 100);
+        }
+
+        fn delta(
+            first_update_id: u64,
+            last_update_id: u64,
+            bids: Vec<(f64, f64)>,
+            asks: Vec<(f64, f64)>,
+        ) -> BinanceSpotOrderBookL2Delta {
+            let level = |(price, amount)| BinanceLevel { price, amount };
+            BinanceSpotOrderBookL2Delta {
+                subscription_id: SubscriptionId::from("subscription_id"),
+                first_update_id,
+                last_update_id,
+                bids: bids.into_iter().map(level).collect(),
+                asks: asks.into_iter().map(level).collect(),
+            }
+        }
+
+        fn fetcher(
+            snapshot: BinanceOrderBookL2Snapshot,
+            fetches: Arc<AtomicU32>,
+        ) -> SnapshotFetcher {
+            Arc::new(move || {
+                fetches.fetch_add(1, Ordering::SeqCst);
+                let snapshot = snapshot.clone();
+                Box::pin(async move { Ok(snapshot) })
+            })
+        }
+
+        #[tokio::test]
+        async fn test_bootstrap_replays_buffered_deltas() {
+            let level = |price, amount| BinanceLevel { price, amount };
+            let fetches = Arc::new(AtomicU32::new(0));
+            let snapshot = BinanceOrderBookL2Snapshot {
+                last_update_id: 100,
+                bids: vec![level(50.0, 1.0), level(49.0, 2.0), level(48.0, 3.0)],
+                asks: vec![level(51.0, 1.0), level(52.0, 2.0), level(53.0, 3.0)],
+            };
+
+            let InstrumentOrderBook {
+                mut updater,
+                mut book,
+                ..
+            } = BinanceSpotBookUpdater::bootstrap(
+                Arc::new(Instrument::from(("btc", "usdt", InstrumentKind::Spot))),
+                None,
+                fetcher(snapshot, Arc::clone(&fetches)),
+            )
+            .await
+            .unwrap();
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+            // Deltas buffered whilst the snapshot was in flight
+            let buffered = vec![
+                // Surpassed by the snapshot, so dropped
+                delta(90, 95, vec![(50.0, 9.0)], vec![]),
+                delta(96, 100, vec![], vec![(51.0, 9.0)]),
+                // Straddles the snapshot lastUpdateId, so is the first applied
+                delta(99, 103, vec![(50.0, 0.0)], vec![]),
+                delta(104, 106, vec![], vec![(51.5, 4.0)]),
+            ];
+
+            let actual = buffered
+                .into_iter()
+                .filter_map(|delta| updater.update(&mut book, delta).unwrap())
+                .collect::<Vec<_>>();
+
+            struct TestCase {
+                expected_bids: Vec<Level>,
+                expected_asks: Vec<Level>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: first OrderBook is the full snapshot w/ the first applied delta
+                    expected_bids: vec![Level::new(49, 2), Level::new(48, 3)],
+                    expected_asks: vec![Level::new(51, 1), Level::new(52, 2), Level::new(53, 3)],
+                },
+                TestCase {
+                    // TC1: subsequent delta is applied on top
+                    expected_bids: vec![Level::new(49, 2), Level::new(48, 3)],
+                    expected_asks: vec![
+                        Level::new(51, 1),
+                        Level::new(51.5, 4.0),
+                        Level::new(52, 2),
+                        Level::new(53, 3),
+                    ],
+                },
+            ];
+
+            assert_eq!(actual.len(), tests.len());
+            for (index, (actual, test)) in actual.into_iter().zip(tests).enumerate() {
+                assert_eq!(actual.bids.levels, test.expected_bids, "TC{index} failed");
+                assert_eq!(actual.asks.levels, test.expected_asks, "TC{index} failed");
+            }
+            assert_eq!(updater.last_update_id, 106);
+        }
+
+        #[tokio::test]
+        async fn test_update_refetches_stale_snapshot() {
+            let fetches = Arc::new(AtomicU32::new(0));
+            let fresh = BinanceOrderBookL2Snapshot {
+                last_update_id: 115,
+                bids: vec![BinanceLevel {
+                    price: 50.0,
+                    amount: 1.0,
+                }],
+                asks: vec![BinanceLevel {
+                    price: 60.0,
+                    amount: 1.0,
+                }],
+            };
+
+            // Stale snapshot with lastUpdateId 100, already surpassed by the first buffered update
+            let mut updater =
+                BinanceSpotBookUpdater::new(100).with_refetch(fetcher(fresh, fetches.clone()));
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, vec![Level::new(40, 1)]),
+                asks: OrderBookSide::new(Side::Sell, vec![Level::new(70, 1)]),
+            };
+
+            // Gap relative to the stale snapshot is not applied, and the snapshot is re-fetched
+            let actual = updater.update(&mut book, delta(111, 120, vec![(51.0, 2.0)], vec![]));
+            assert_eq!(actual.unwrap(), None);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+            // Buffered updates are replayed on top of the fresh snapshot
+            let actual = updater
+                .update(&mut book, delta(121, 130, vec![], vec![(61.0, 3.0)]))
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                actual.bids,
+                OrderBookSide::new(Side::Buy, vec![Level::new(51, 2), Level::new(50, 1)])
+            );
+            assert_eq!(
+                actual.asks,
+                OrderBookSide::new(Side::Sell, vec![Level::new(60, 1), Level::new(61, 3)])
+            );
+            assert_eq!(updater.last_update_id, 130);
+
+            // Subsequent updates apply as normal
+            let actual = updater.update(&mut book, delta(131, 140, vec![(50.0, 0.0)], vec![]));
+            assert_eq!(
+                actual.unwrap().unwrap().bids,
+                OrderBookSide::new(Side::Buy, vec![Level::new(51, 2)])
+            );
+        }
+
+        #[tokio::test]
+        async fn test_update_refetch_limit() {
+            let fetches = Arc::new(AtomicU32::new(0));
+            let stale = BinanceOrderBookL2Snapshot {
+                last_update_id: 100,
+                bids: vec![],
+                asks: vec![],
+            };
+
+            let mut updater =
+                BinanceSpotBookUpdater::new(100).with_refetch(fetcher(stale, fetches.clone()));
+            let mut book = OrderBook::from(BinanceOrderBookL2Snapshot {
+                last_update_id: 100,
+                bids: vec![],
+                asks: vec![],
+            });
+
+            // Every re-fetched snapshot is also stale, so the updater eventually gives up
+            let mut actual = Ok(None);
+            for first_update_id in (111..).step_by(10).take(10) {
+                actual = updater.update(
+                    &mut book,
+                    delta(first_update_id, first_update_id + 9, vec![], vec![]),
+                );
+                if actual.is_err() {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+
+            assert!(matches!(actual, Err(DataError::InvalidSequence { .. })));
+            assert_eq!(fetches.load(Ordering::SeqCst), MAX_SNAPSHOT_REFETCHES);
+        }
     }
 }
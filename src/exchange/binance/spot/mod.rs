@@ -1,9 +1,15 @@
-use self::l2::BinanceSpotBookUpdater;
+use self::{l2::BinanceSpotBookUpdater, ticker::BinanceTicker};
 use super::{Binance, ExchangeServer};
 use crate::{
-    exchange::{ExchangeId, StreamSelector},
-    subscription::book::OrderBooksL2,
-    transformer::book::MultiBookTransformer,
+    exchange::{rate_limit::OutboundRateLimit, ExchangeId, StreamSelector},
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL2Depth, OrderBooksTop},
+        ticker::Tickers,
+    },
+    transformer::{
+        book::{MultiBookTransformer, TopOfBookTransformer},
+        stateless::StatelessTransformer,
+    },
     ExchangeWsStream,
 };
 
@@ -11,19 +17,20 @@ use crate::{
 /// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
 pub mod l2;
 
+/// 24h rolling window ticker statistics types.
+pub mod ticker;
+
 /// [`BinanceSpot`] WebSocket server base url.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
 pub const WEBSOCKET_BASE_URL_BINANCE_SPOT: &str = "wss://stream.binance.com:9443/ws";
 
-
 /// [`Binance`](super::Binance) spot exchange.
 pub type BinanceSpot = Binance<BinanceServerSpot>;
 
 /// See docs: <https://docs.binance.us/#general-websocket-api-information>
 pub const WEBSOCKET_BASE_URL_BINANCEUS_SPOT: &str = "wss://stream.binance.us:9443/ws";
 
-
 pub type BinanceUSSpot = Binance<BinanceUSServerSpot>;
 
 /// [`Binance`](super::Binance) spot [`ExchangeServer`](super::super::ExchangeServer).
@@ -36,6 +43,11 @@ impl ExchangeServer for BinanceServerSpot {
     fn websocket_url() -> &'static str {
         WEBSOCKET_BASE_URL_BINANCE_SPOT
     }
+
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-limits>
+    fn outbound_rate_limit() -> Option<OutboundRateLimit> {
+        Some(OutboundRateLimit::per_second(5))
+    }
 }
 
 impl StreamSelector<OrderBooksL2> for BinanceSpot {
@@ -43,6 +55,19 @@ impl StreamSelector<OrderBooksL2> for BinanceSpot {
         ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceSpotBookUpdater>>;
 }
 
+impl StreamSelector<OrderBooksL2Depth> for BinanceSpot {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2Depth, BinanceSpotBookUpdater>>;
+}
+
+impl StreamSelector<OrderBooksTop> for BinanceSpot {
+    type Stream = ExchangeWsStream<TopOfBookTransformer<Self, BinanceSpotBookUpdater>>;
+}
+
+impl StreamSelector<Tickers> for BinanceSpot {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Tickers, BinanceTicker>>;
+}
+
 /// [`Binance`](super::Binance) spot [`ExchangeServer`](super::super::ExchangeServer).
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct BinanceUSServerSpot;
@@ -53,9 +78,23 @@ impl ExchangeServer for BinanceUSServerSpot {
     fn websocket_url() -> &'static str {
         WEBSOCKET_BASE_URL_BINANCEUS_SPOT
     }
+
+    /// See docs: <https://docs.binance.us/#websocket-limits>
+    fn outbound_rate_limit() -> Option<OutboundRateLimit> {
+        Some(OutboundRateLimit::per_second(5))
+    }
 }
 
 impl StreamSelector<OrderBooksL2> for BinanceUSSpot {
     type Stream =
         ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceSpotBookUpdater>>;
 }
+
+impl StreamSelector<OrderBooksL2Depth> for BinanceUSSpot {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2Depth, BinanceSpotBookUpdater>>;
+}
+
+impl StreamSelector<OrderBooksTop> for BinanceUSSpot {
+    type Stream = ExchangeWsStream<TopOfBookTransformer<Self, BinanceSpotBookUpdater>>;
+}
@@ -0,0 +1,161 @@
+use super::super::BinanceChannel;
+use crate::{
+    clock,
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// [`BinanceSpot`](super::BinanceSpot) 24h rolling window ticker statistics message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-ticker-streams>
+/// ```json
+/// {
+///     "e": "24hrTicker",
+///     "E": 1672515782136,
+///     "s": "BNBBTC",
+///     "p": "0.0015",
+///     "P": "250.00",
+///     "w": "0.0018",
+///     "x": "0.0009",
+///     "c": "0.0025",
+///     "Q": "10",
+///     "b": "0.0024",
+///     "B": "10",
+///     "a": "0.0026",
+///     "A": "100",
+///     "o": "0.0010",
+///     "h": "0.0025",
+///     "l": "0.0010",
+///     "v": "10000",
+///     "q": "18",
+///     "O": 0,
+///     "C": 86400000,
+///     "F": 0,
+///     "L": 18150,
+///     "n": 18151
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceTicker {
+    #[serde(alias = "s", deserialize_with = "de_ticker_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "c", deserialize_with = "crate::de::de_f64")]
+    pub last_price: f64,
+    #[serde(alias = "h", deserialize_with = "crate::de::de_f64")]
+    pub high: f64,
+    #[serde(alias = "l", deserialize_with = "crate::de::de_f64")]
+    pub low: f64,
+    #[serde(alias = "v", deserialize_with = "crate::de::de_f64")]
+    pub volume: f64,
+    #[serde(alias = "P", deserialize_with = "crate::de::de_f64")]
+    pub price_change_pct: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceTicker {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Arc<Instrument>, BinanceTicker)> for MarketIter<Ticker> {
+    fn from(
+        (exchange_id, instrument, ticker): (ExchangeId, Arc<Instrument>, BinanceTicker),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: clock::received_time(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Ticker {
+                last_price: ticker.last_price,
+                high_24h: ticker.high,
+                low_24h: ticker.low,
+                volume_24h: ticker.volume,
+                price_change_pct: ticker.price_change_pct,
+                time: ticker.time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceTicker`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
+/// (eg/ "@ticker|BTCUSDT").
+pub fn de_ticker_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::TICKERS, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_ticker() {
+            struct TestCase {
+                input: &'static str,
+                expected: Option<BinanceTicker>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid 24hrTicker
+                    input: r#"
+                    {
+                        "e": "24hrTicker", "E": 1672515782136, "s": "BNBBTC", "p": "0.0015",
+                        "P": "250.00", "w": "0.0018", "x": "0.0009", "c": "0.0025", "Q": "10",
+                        "b": "0.0024", "B": "10", "a": "0.0026", "A": "100", "o": "0.0010",
+                        "h": "0.0025", "l": "0.0010", "v": "10000", "q": "18", "O": 0,
+                        "C": 86400000, "F": 0, "L": 18150, "n": 18151
+                    }
+                    "#,
+                    expected: Some(BinanceTicker {
+                        subscription_id: SubscriptionId::from("@ticker|BNBBTC"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782136,
+                        )),
+                        last_price: 0.0025,
+                        high: 0.0025,
+                        low: 0.0010,
+                        volume: 10000.0,
+                        price_change_pct: 250.0,
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid 24hrTicker w/o last price
+                    input: r#"
+                    {
+                        "e": "24hrTicker", "E": 1672515782136, "s": "BNBBTC", "P": "250.00",
+                        "h": "0.0025", "l": "0.0010", "v": "10000"
+                    }
+                    "#,
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceTicker>(test.input).ok();
+                assert_eq!(actual, test.expected, "TC{index} failed");
+            }
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use crate::{
+    clock,
+    error::DataError,
+    event::MarketEvent,
+    exchange::ExchangeId,
+    subscription::{
+        intern::intern,
+        status::{InstrumentStatus, TradingStatus},
+    },
+};
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Exchange},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+/// [`BinanceSpot`](super::spot::BinanceSpot) HTTP exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/exchangeInfo";
+
+/// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) HTTP exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/exchangeInfo";
+
+/// [`Binance`](super::Binance) HTTP exchange information response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+/// ```json
+/// {
+///     "timezone": "UTC",
+///     "serverTime": 1565246363776,
+///     "symbols": [
+///         {
+///             "symbol": "ETHBTC",
+///             "status": "TRADING",
+///             "baseAsset": "ETH",
+///             "quoteAsset": "BTC"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct BinanceExchangeInfo {
+    #[serde(
+        alias = "serverTime",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub symbols: Vec<BinanceSymbolInfo>,
+}
+
+/// [`Binance`](super::Binance) symbol trading status, as contained in a
+/// [`BinanceExchangeInfo`] response.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct BinanceSymbolInfo {
+    pub symbol: String,
+    #[serde(alias = "contractStatus")]
+    pub status: BinanceSymbolStatus,
+}
+
+/// [`Binance`](super::Binance) spot & futures symbol status variants.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceSymbolStatus {
+    PreTrading,
+    PendingTrading,
+    Trading,
+    PostTrading,
+    EndOfDay,
+    Halt,
+    AuctionMatch,
+    Break,
+    PreDelivering,
+    Delivering,
+    Delivered,
+    PreSettle,
+    Settling,
+    Close,
+}
+
+impl From<BinanceSymbolStatus> for TradingStatus {
+    fn from(status: BinanceSymbolStatus) -> Self {
+        use BinanceSymbolStatus::*;
+
+        match status {
+            PreTrading | PendingTrading => TradingStatus::PreTrading,
+            Trading => TradingStatus::Trading,
+            PostTrading | EndOfDay => TradingStatus::PostTrading,
+            Halt | AuctionMatch | Break | PreDelivering | Delivering | PreSettle | Settling => {
+                TradingStatus::Halted
+            }
+            Delivered | Close => TradingStatus::Delisted,
+        }
+    }
+}
+
+/// Monitors the trading status of a collection of [`Binance`](super::Binance) [`Instrument`]s by
+/// polling the [`BinanceExchangeInfo`] endpoint, generating an [`InstrumentStatus`]
+/// [`MarketEvent`] every time a status changes (eg/ TRADING -> HALT).
+///
+/// ### Notes
+/// - The first [`BinanceExchangeInfo`] observed only establishes the baseline statuses.
+/// - An [`Instrument`] missing from a [`BinanceExchangeInfo`] response is considered
+///   [`TradingStatus::Delisted`].
+#[derive(Clone, Debug)]
+pub struct BinanceStatusMonitor {
+    exchange: ExchangeId,
+    instruments: HashMap<String, Arc<Instrument>>,
+    statuses: HashMap<String, TradingStatus>,
+}
+
+impl BinanceStatusMonitor {
+    /// Construct a new [`Self`] for the provided Binance [`ExchangeId`] & [`Instrument`]s.
+    pub fn new<Iter>(exchange: ExchangeId, instruments: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = Instrument>,
+    {
+        Self {
+            exchange,
+            instruments: instruments
+                .into_iter()
+                .map(|instrument| {
+                    let symbol = format!("{}{}", instrument.base, instrument.quote).to_uppercase();
+                    (symbol, intern(&instrument))
+                })
+                .collect(),
+            statuses: HashMap::new(),
+        }
+    }
+
+    /// HTTP exchange information url associated with the monitored [`ExchangeId`].
+    pub fn url(&self) -> Result<&'static str, DataError> {
+        match self.exchange {
+            ExchangeId::BinanceSpot => Ok(HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT),
+            ExchangeId::BinanceFuturesUsd => Ok(HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD),
+            exchange => Err(DataError::from(SocketError::Unsupported {
+                entity: "BinanceStatusMonitor",
+                item: exchange.to_string(),
+            })),
+        }
+    }
+
+    /// Update the monitored statuses using the provided [`BinanceExchangeInfo`], returning an
+    /// [`InstrumentStatus`] [`MarketEvent`] for each [`Instrument`] whose status changed.
+    pub fn update(&mut self, info: BinanceExchangeInfo) -> Vec<MarketEvent<InstrumentStatus>> {
+        let mut reported = info
+            .symbols
+            .into_iter()
+            .filter(|symbol| self.instruments.contains_key(&symbol.symbol))
+            .map(|symbol| (symbol.symbol, TradingStatus::from(symbol.status)))
+            .collect::<HashMap<_, _>>();
+
+        let mut events = Vec::new();
+        for (symbol, instrument) in &self.instruments {
+            let status = reported.remove(symbol).unwrap_or(TradingStatus::Delisted);
+
+            match self.statuses.insert(symbol.clone(), status) {
+                Some(previous) if previous != status => events.push(MarketEvent {
+                    exchange_time: info.time,
+                    received_time: clock::received_time(),
+                    exchange: Exchange::from(self.exchange),
+                    instrument: instrument.clone(),
+                    kind: InstrumentStatus {
+                        status,
+                        previous,
+                        time: info.time,
+                    },
+                }),
+                _ => {}
+            }
+        }
+
+        events
+    }
+
+    /// Poll the [`BinanceExchangeInfo`] endpoint every `interval`, sending every generated
+    /// [`InstrumentStatus`] [`MarketEvent`] via the provided `status_tx`.
+    ///
+    /// Runs until the receiver is dropped, or the monitored [`ExchangeId`] is not supported.
+    /// Failed polls are logged and retried on the next `interval`.
+    pub async fn run(
+        mut self,
+        interval: Duration,
+        status_tx: mpsc::UnboundedSender<MarketEvent<InstrumentStatus>>,
+    ) -> Result<(), DataError> {
+        let url = self.url()?;
+        let mut interval = tokio::time::interval(interval);
+
+        info!(exchange = %self.exchange, "BinanceStatusMonitor running");
+
+        loop {
+            interval.tick().await;
+
+            let info = match fetch_exchange_info(url).await {
+                Ok(info) => info,
+                Err(error) => {
+                    warn!(exchange = %self.exchange, %error, "failed to poll exchange info");
+                    continue;
+                }
+            };
+
+            for event in self.update(info) {
+                if status_tx.send(event).is_err() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+/// Fetch the [`BinanceExchangeInfo`] from the provided url.
+async fn fetch_exchange_info(url: &str) -> Result<BinanceExchangeInfo, DataError> {
+    reqwest::get(url)
+        .await
+        .map_err(SocketError::Http)?
+        .json::<BinanceExchangeInfo>()
+        .await
+        .map_err(|error| DataError::from(SocketError::Http(error)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn exchange_info(time: u64, btc_status: &str, eth_status: &str) -> BinanceExchangeInfo {
+        serde_json::from_str(&format!(
+            r#"
+            {{
+                "timezone": "UTC",
+                "serverTime": {time},
+                "symbols": [
+                    {{ "symbol": "BTCUSDT", "status": "{btc_status}", "baseAsset": "BTC" }},
+                    {{ "symbol": "ETHUSDT", "status": "{eth_status}", "baseAsset": "ETH" }},
+                    {{ "symbol": "XRPUSDT", "status": "TRADING", "baseAsset": "XRP" }}
+                ]
+            }}
+            "#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_binance_status_monitor() {
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+        let mut monitor =
+            BinanceStatusMonitor::new(ExchangeId::BinanceSpot, [btc.clone(), eth.clone()]);
+
+        // TC0: first exchange info establishes the baseline
+        let actual = monitor.update(exchange_info(1, "TRADING", "TRADING"));
+        assert!(actual.is_empty(), "TC0 failed: {actual:?}");
+
+        // TC1: unchanged statuses generate no events
+        let actual = monitor.update(exchange_info(2, "TRADING", "TRADING"));
+        assert!(actual.is_empty(), "TC1 failed: {actual:?}");
+
+        // TC2: TRADING -> HALT transition generates an InstrumentStatus event
+        let actual = monitor.update(exchange_info(3, "HALT", "TRADING"));
+        assert_eq!(actual.len(), 1, "TC2 failed: {actual:?}");
+        assert_eq!(*actual[0].instrument, btc, "TC2 failed");
+        assert_eq!(
+            actual[0].kind,
+            InstrumentStatus {
+                status: TradingStatus::Halted,
+                previous: TradingStatus::Trading,
+                time: DateTime::<Utc>::from_timestamp_millis(3).unwrap(),
+            },
+            "TC2 failed"
+        );
+
+        // TC3: BREAK is still halted, so no event is generated
+        let actual = monitor.update(exchange_info(4, "BREAK", "TRADING"));
+        assert!(actual.is_empty(), "TC3 failed: {actual:?}");
+
+        // TC4: symbol removed from the exchange info is delisted
+        let mut info = exchange_info(5, "TRADING", "TRADING");
+        info.symbols.retain(|symbol| symbol.symbol != "ETHUSDT");
+        let mut actual = monitor.update(info);
+        actual.sort_by(|a, b| a.instrument.cmp(&b.instrument));
+        assert_eq!(actual.len(), 2, "TC4 failed: {actual:?}");
+        assert_eq!(actual[0].kind.status, TradingStatus::Trading, "TC4 failed");
+        assert_eq!(*actual[1].instrument, eth, "TC4 failed");
+        assert_eq!(actual[1].kind.status, TradingStatus::Delisted, "TC4 failed");
+    }
+}
@@ -1,13 +1,15 @@
 use super::BinanceChannel;
 use crate::{
+    clock,
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, TaggedTrade, TradeFields, TradeKind},
     Identifier,
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Binance real-time trade message.
 ///
@@ -61,9 +63,9 @@ pub struct BinanceTrade {
     pub time: DateTime<Utc>,
     #[serde(alias = "t")]
     pub id: u64,
-    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(alias = "p", deserialize_with = "crate::de::de_f64")]
     pub price: f64,
-    #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(alias = "q", deserialize_with = "crate::de::de_f64")]
     pub amount: f64,
     #[serde(alias = "m", deserialize_with = "de_side_from_buyer_is_maker")]
     pub side: Side,
@@ -75,23 +77,143 @@ impl Identifier<Option<SubscriptionId>> for BinanceTrade {
     }
 }
 
-impl From<(ExchangeId, Instrument, BinanceTrade)> for MarketIter<PublicTrade> {
-    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, BinanceTrade)) -> Self {
+impl From<(ExchangeId, Arc<Instrument>, BinanceTrade)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Arc<Instrument>, BinanceTrade)) -> Self {
+        let fields = TradeFields::current();
         Self(vec![Ok(MarketEvent {
             exchange_time: trade.time,
-            received_time: Utc::now(),
+            received_time: clock::received_time(),
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: PublicTrade {
-                id: trade.id.to_string(),
+                id: fields.id(|| trade.id.to_string()),
                 price: trade.price,
                 amount: trade.amount,
                 side: trade.side,
+                conditions: vec![],
             },
         })])
     }
 }
 
+/// Binance real-time aggregated trade message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#aggregate-trade-streams>
+/// ```json
+/// {
+///     "e":"aggTrade",
+///     "E":1672515782136,
+///     "s":"BNBBTC",
+///     "a":12345,
+///     "p":"0.001",
+///     "q":"100",
+///     "f":100,
+///     "l":105,
+///     "T":1672515782136,
+///     "m":true,
+///     "M":true
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceAggTrade {
+    #[serde(alias = "s", deserialize_with = "de_agg_trade_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "a")]
+    pub id: u64,
+    #[serde(alias = "p", deserialize_with = "crate::de::de_f64")]
+    pub price: f64,
+    #[serde(alias = "q", deserialize_with = "crate::de::de_f64")]
+    pub amount: f64,
+    #[serde(alias = "m", deserialize_with = "de_side_from_buyer_is_maker")]
+    pub side: Side,
+}
+
+/// Binance raw or aggregated trade message, distinguished by the "e" event type field.
+///
+/// Used by [`TaggedTrades`](crate::subscription::trade::TaggedTrades) streams that multiplex the
+/// [`BinanceChannel::TRADES`] & [`BinanceChannel::AGG_TRADES`] channels over one connection.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "e")]
+pub enum BinanceTaggedTrade {
+    #[serde(rename = "trade")]
+    Trade(BinanceTrade),
+    #[serde(rename = "aggTrade")]
+    AggTrade(BinanceAggTrade),
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceTaggedTrade {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Trade(trade) => Some(trade.subscription_id.clone()),
+            Self::AggTrade(trade) => Some(trade.subscription_id.clone()),
+        }
+    }
+}
+
+impl From<(ExchangeId, Arc<Instrument>, BinanceTaggedTrade)> for MarketIter<TaggedTrade> {
+    fn from(
+        (exchange_id, instrument, trade): (ExchangeId, Arc<Instrument>, BinanceTaggedTrade),
+    ) -> Self {
+        let (kind, time, id, price, amount, side) = match trade {
+            BinanceTaggedTrade::Trade(trade) => (
+                TradeKind::Raw,
+                trade.time,
+                trade.id,
+                trade.price,
+                trade.amount,
+                trade.side,
+            ),
+            BinanceTaggedTrade::AggTrade(trade) => (
+                TradeKind::Aggregated,
+                trade.time,
+                trade.id,
+                trade.price,
+                trade.amount,
+                trade.side,
+            ),
+        };
+
+        let fields = TradeFields::current();
+        Self(vec![Ok(MarketEvent {
+            exchange_time: time,
+            received_time: clock::received_time(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: TaggedTrade {
+                kind,
+                trade: PublicTrade {
+                    id: fields.id(|| id.to_string()),
+                    price,
+                    amount,
+                    side,
+                    conditions: vec![],
+                },
+            },
+        })])
+    }
+}
+
+/// Minimal [`BinanceTrade`] route parsed by a
+/// [`ForwardRaw`](crate::subscription::forward::ForwardRaw) stream to determine the
+/// [`SubscriptionId`] of each raw trade frame.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceTradeRoute {
+    #[serde(alias = "s", deserialize_with = "de_trade_subscription_id")]
+    pub subscription_id: SubscriptionId,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceTradeRoute {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
 /// Deserialize a [`BinanceTrade`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
 /// (eg/ "@trade|BTCUSDT").
 pub fn de_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
@@ -102,6 +224,16 @@ where
         .map(|market| ExchangeSub::from((BinanceChannel::TRADES, market)).id())
 }
 
+/// Deserialize a [`BinanceAggTrade`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
+/// (eg/ "@aggTrade|BTCUSDT").
+pub fn de_agg_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::AGG_TRADES, market)).id())
+}
+
 /// Deserialize a [`BinanceTrade`] "buyer_is_maker" boolean field to a Barter [`Side`].
 ///
 /// Variants:
@@ -243,5 +375,217 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_binance_agg_trade() {
+            struct TestCase {
+                input: &'static str,
+                expected: Option<BinanceAggTrade>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: FuturePerpetual aggTrade w/ buyer maker
+                    input: r#"
+                    {
+                        "e": "aggTrade","E": 123456789,"s": "BTCUSDT","a": 5933014,
+                        "p": "0.001","q": "100","f": 100,"l": 105,"T": 123456785,"m": true
+                    }
+                    "#,
+                    expected: Some(BinanceAggTrade {
+                        subscription_id: SubscriptionId::from("@aggTrade|BTCUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(123456785)),
+                        id: 5933014,
+                        price: 0.001,
+                        amount: 100.0,
+                        side: Side::Sell,
+                    }),
+                },
+                TestCase {
+                    // TC1: Spot aggTrade w/ buyer taker & ignored "M" field
+                    input: r#"
+                    {
+                        "e": "aggTrade","E": 1672515782136,"s": "ETHUSDT","a": 12345,
+                        "p": "1200.50","q": "0.5","f": 100,"l": 105,"T": 1672515782136,
+                        "m": false,"M": true
+                    }
+                    "#,
+                    expected: Some(BinanceAggTrade {
+                        subscription_id: SubscriptionId::from("@aggTrade|ETHUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782136,
+                        )),
+                        id: 12345,
+                        price: 1200.50,
+                        amount: 0.5,
+                        side: Side::Buy,
+                    }),
+                },
+                TestCase {
+                    // TC2: aggTrade malformed w/o aggregate trade id
+                    input: r#"
+                    {
+                        "e": "aggTrade","E": 123456789,"s": "BTCUSDT",
+                        "p": "0.001","q": "100","T": 123456785,"m": true
+                    }
+                    "#,
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceAggTrade>(test.input).ok();
+                assert_eq!(actual, test.expected, "TC{index} failed");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_binance_trade_fields_are_per_transformer() {
+        use crate::{
+            exchange::binance::spot::BinanceSpot,
+            subscription::{
+                trade::{with_trade_fields, PublicTrades, TradeFields},
+                Map,
+            },
+            transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+        };
+        use barter_integration::{model::instrument::kind::InstrumentKind, Transformer};
+        use tokio::sync::mpsc;
+
+        let transformer = || {
+            let (ws_sink_tx, _) = mpsc::unbounded_channel();
+            StatelessTransformer::<BinanceSpot, PublicTrades, BinanceTrade>::new(
+                ws_sink_tx,
+                Map::from_iter([(
+                    SubscriptionId::from("@trade|BTCUSDT"),
+                    Arc::new(Instrument::from(("btc", "usdt", InstrumentKind::Spot))),
+                )]),
+            )
+        };
+
+        struct TestCase {
+            transformer: StatelessTransformer<BinanceSpot, PublicTrades, BinanceTrade>,
+            expected_id: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: transformer constructed without TradeFields populates every field
+                transformer: transformer().await.unwrap(),
+                expected_id: "1000000000",
+            },
+            TestCase {
+                // TC1: transformer constructed with skipped ids leaves the id empty
+                transformer: with_trade_fields(TradeFields { id: false }, transformer())
+                    .await
+                    .unwrap(),
+                expected_id: "",
+            },
+        ];
+
+        let input = r#"{
+            "e":"trade","E":1649324825173,"s":"BTCUSDT","t":1000000000,"p":"10000.19",
+            "q":"0.239000","b":10108767791,"a":10108764858,"T":1649324825173,"m":false,"M":true
+        }"#;
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            let input = serde_json::from_str::<BinanceTrade>(input).unwrap();
+            let actual = test.transformer.transform(input).remove(0).unwrap();
+            assert_eq!(actual.kind.id, test.expected_id, "TC{index} failed");
+
+            // received_time is always stamped by the clock
+            assert!(
+                actual.received_time > actual.exchange_time,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_binance_tagged_trades_share_one_connection() {
+        use crate::{
+            exchange::binance::spot::BinanceSpot,
+            streams::builder::StreamBuilder,
+            subscription::{intern::intern, trade::TaggedTrades, Map, Subscription},
+            transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+        };
+        use barter_integration::{model::instrument::kind::InstrumentKind, Transformer};
+        use tokio::sync::mpsc;
+
+        let subscription = |kind| {
+            Subscription::from((
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                TaggedTrades(kind),
+            ))
+        };
+        let raw = subscription(TradeKind::Raw);
+        let aggregated = subscription(TradeKind::Aggregated);
+
+        // Both TradeKinds of the same instrument are actioned over a single connection
+        let builder = StreamBuilder::<TaggedTrades>::new().subscribe([raw.clone(), aggregated]);
+        assert_eq!(builder.futures.len(), 1);
+
+        let (ws_sink_tx, _) = mpsc::unbounded_channel();
+        let mut transformer =
+            StatelessTransformer::<BinanceSpot, TaggedTrades, BinanceTaggedTrade>::new(
+                ws_sink_tx,
+                Map::from_iter([
+                    (
+                        SubscriptionId::from("@trade|BTCUSDT"),
+                        intern(&raw.instrument),
+                    ),
+                    (
+                        SubscriptionId::from("@aggTrade|BTCUSDT"),
+                        intern(&raw.instrument),
+                    ),
+                ]),
+            )
+            .await
+            .unwrap();
+
+        struct TestCase {
+            input: &'static str,
+            expected: (TradeKind, &'static str, Side),
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: raw trade is tagged TradeKind::Raw
+                input: r#"{
+                    "e":"trade","E":1649324825173,"s":"BTCUSDT","t":1000000000,
+                    "p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,
+                    "T":1649324825173,"m":false,"M":true
+                }"#,
+                expected: (TradeKind::Raw, "1000000000", Side::Buy),
+            },
+            TestCase {
+                // TC1: aggregated trade is tagged TradeKind::Aggregated
+                input: r#"{
+                    "e":"aggTrade","E":1649324825173,"s":"BTCUSDT","a":12345,
+                    "p":"10000.19","q":"1.5","f":100,"l":105,
+                    "T":1649324825173,"m":true,"M":true
+                }"#,
+                expected: (TradeKind::Aggregated, "12345", Side::Sell),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = serde_json::from_str::<BinanceTaggedTrade>(test.input).unwrap();
+            let actual = transformer.transform(input).remove(0).unwrap();
+            assert_eq!(*actual.instrument, raw.instrument, "TC{index} failed");
+            assert_eq!(
+                (
+                    actual.kind.kind,
+                    actual.kind.trade.id.as_str(),
+                    actual.kind.trade.side
+                ),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
     }
 }
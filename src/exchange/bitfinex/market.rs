@@ -1,5 +1,10 @@
 use super::Bitfinex;
-use crate::{subscription::Subscription, Identifier};
+use crate::{
+    exchange::market::{split_known_quote, split_separated, MarketId},
+    subscription::Subscription,
+    Identifier,
+};
+use barter_integration::model::instrument::{symbol::Symbol, Instrument};
 use serde::{Deserialize, Serialize};
 
 /// Type that defines how to translate a Barter [`Subscription`] into a
@@ -9,13 +14,28 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BitfinexMarket(pub String);
 
+impl MarketId for Bitfinex {
+    fn market_id(instrument: &Instrument) -> String {
+        format!(
+            "t{}{}",
+            instrument.base.to_string().to_uppercase(),
+            instrument.quote.to_string().to_uppercase()
+        )
+    }
+
+    fn parse_market_id(market: &str) -> Option<(Symbol, Symbol)> {
+        // Trading pairs are prefixed with "t", and symbols longer than three characters are
+        // separated with a colon (eg/ "tTESTBTC:TESTUSD")
+        let pair = market.strip_prefix('t')?;
+        split_separated(pair, ':')
+            .or_else(|| split_known_quote(pair))
+            .map(|(base, quote)| (Symbol::new(base), Symbol::new(quote)))
+    }
+}
+
 impl<Kind> Identifier<BitfinexMarket> for Subscription<Bitfinex, Kind> {
     fn id(&self) -> BitfinexMarket {
-        BitfinexMarket(format!(
-            "t{}{}",
-            self.instrument.base.to_string().to_uppercase(),
-            self.instrument.quote.to_string().to_uppercase()
-        ))
+        BitfinexMarket(Bitfinex::market_id(&self.instrument))
     }
 }
 
@@ -7,6 +7,7 @@ use barter_integration::{
     model::{instrument::Instrument, SubscriptionId},
 };
 use serde::Serialize;
+use std::sync::Arc;
 
 /// [`Bitfinex`](super::Bitfinex) message received over
 /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) relating to an active
@@ -60,8 +61,10 @@ impl Identifier<Option<SubscriptionId>> for BitfinexMessage {
     }
 }
 
-impl From<(ExchangeId, Instrument, BitfinexMessage)> for MarketIter<PublicTrade> {
-    fn from((exchange_id, instrument, message): (ExchangeId, Instrument, BitfinexMessage)) -> Self {
+impl From<(ExchangeId, Arc<Instrument>, BitfinexMessage)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Arc<Instrument>, BitfinexMessage),
+    ) -> Self {
         match message.payload {
             BitfinexPayload::Heartbeat => Self(vec![]),
             BitfinexPayload::Trade(trade) => Self::from((exchange_id, instrument, trade)),
@@ -1,6 +1,13 @@
+use crate::{error::SubscriptionError, exchange::ExchangeId};
 use barter_integration::{error::SocketError, Validator};
 use serde::{Deserialize, Serialize};
 
+/// [`Bitfinex`](super::Bitfinex) error code communicating the maximum number of open channels for
+/// a connection has been reached.
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#error-codes>
+pub const BITFINEX_ERROR_CODE_CHANNEL_LIMIT: u32 = 10305;
+
 /// [`Bitfinex`](super::Bitfinex) platform event detailing the variants expected to be received
 /// while connecting and subscribing.
 ///
@@ -161,6 +168,7 @@ pub struct BitfinexChannelId(pub u32);
 /// 10300: Generic failure
 /// 10301: Already subscribed
 /// 10302: Unknown channel
+/// 10305: Reached limit of open channels
 ///
 /// See [`BitfinexPlatformStatus`] for full raw payload examples.
 ///
@@ -171,6 +179,22 @@ pub struct BitfinexError {
     code: u32,
 }
 
+impl BitfinexError {
+    /// Determine if this [`BitfinexError`] communicates the open channel limit has been reached.
+    pub fn is_limit_exceeded(&self) -> bool {
+        self.code == BITFINEX_ERROR_CODE_CHANNEL_LIMIT
+    }
+
+    /// Convert this [`BitfinexError`] into a [`SubscriptionError::LimitExceeded`].
+    pub fn into_limit_exceeded(self, exchange: ExchangeId) -> SubscriptionError {
+        SubscriptionError::LimitExceeded {
+            exchange,
+            code: self.code.to_string(),
+            message: self.msg,
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for Status {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -355,4 +379,41 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_bitfinex_error_limit_exceeded() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<SubscriptionError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: open channel limit reached
+                input: r#"{"event": "error", "msg": "subscribe: limit", "code": 10305}"#,
+                expected: Some(SubscriptionError::LimitExceeded {
+                    exchange: ExchangeId::Bitfinex,
+                    code: "10305".to_string(),
+                    message: "subscribe: limit".to_string(),
+                }),
+            },
+            TestCase {
+                // TC1: generic subscription failure is not a limit error
+                input: r#"{"event": "error", "msg": "Already subscribed", "code": 10301}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let error = match serde_json::from_str::<BitfinexPlatformEvent>(test.input).unwrap() {
+                BitfinexPlatformEvent::Error(error) => error,
+                event => panic!("TC{index} failed to deserialise BitfinexError: {event:?}"),
+            };
+
+            let actual = error
+                .is_limit_exceeded()
+                .then(|| error.into_limit_exceeded(ExchangeId::Bitfinex));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
 }
@@ -1,7 +1,8 @@
 use crate::{
+    clock,
     event::{MarketEvent, MarketIter},
     exchange::ExchangeId,
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, TradeFields},
 };
 use barter_integration::{
     de::{datetime_utc_from_epoch_duration, extract_next},
@@ -9,6 +10,7 @@ use barter_integration::{
 };
 use chrono::{DateTime, Utc};
 use serde::Serialize;
+use std::sync::Arc;
 
 /// [`Bitfinex`](super::Bitfinex) real-time trade message.
 ///
@@ -31,7 +33,7 @@ use serde::Serialize;
 ///
 /// ## Notes:
 /// - [`Bitfinex`](super::Bitfinex) trades subscriptions results in receiving tag="te" & tag="tu"
-/// trades, both of which are identical.
+///   trades, both of which are identical.
 /// - "te" trades arrive marginally faster.
 /// - Therefore, tag="tu" trades are filtered out and considered only as additional Heartbeats.
 ///
@@ -45,18 +47,22 @@ pub struct BitfinexTrade {
     pub amount: f64,
 }
 
-impl From<(ExchangeId, Instrument, BitfinexTrade)> for MarketIter<PublicTrade> {
-    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, BitfinexTrade)) -> Self {
+impl From<(ExchangeId, Arc<Instrument>, BitfinexTrade)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument, trade): (ExchangeId, Arc<Instrument>, BitfinexTrade),
+    ) -> Self {
+        let fields = TradeFields::current();
         Self(vec![Ok(MarketEvent {
             exchange_time: trade.time,
-            received_time: Utc::now(),
+            received_time: clock::received_time(),
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: PublicTrade {
-                id: trade.id.to_string(),
+                id: fields.id(|| trade.id.to_string()),
                 price: trade.price,
                 amount: trade.amount,
                 side: trade.side,
+                conditions: vec![],
             },
         })])
     }
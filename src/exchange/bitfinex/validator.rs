@@ -1,5 +1,6 @@
 use super::subscription::{BitfinexPlatformEvent, BitfinexSubResponse};
 use crate::{
+    error::DataError,
     exchange::{Connector, ExchangeSub},
     subscriber::validator::SubscriptionValidator,
     subscription::{Map, SubKind},
@@ -17,6 +18,7 @@ use barter_integration::{
 };
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use tracing::debug;
 
 /// [`Bitfinex`](super::Bitfinex) specific [`SubscriptionValidator`].
@@ -38,9 +40,9 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
     type Parser = WebSocketParser;
 
     async fn validate<Exchange, Kind>(
-        mut map: Map<Instrument>,
+        mut map: Map<Arc<Instrument>>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument>, SocketError>
+    ) -> Result<Map<Arc<Instrument>>, DataError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send,
@@ -66,18 +68,22 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
             tokio::select! {
                 // If timeout reached, return SubscribeError
                 _ = tokio::time::sleep(timeout) => {
-                    break Err(SocketError::Subscribe(
+                    break Err(DataError::from(SocketError::Subscribe(
                         format!("subscription validation timeout reached: {:?}", timeout)
-                    ))
+                    )))
                 },
                 // Parse incoming messages and determine subscription outcomes
                 message = websocket.next() => {
                     let response = match message {
                         Some(response) => response,
-                        None => break Err(SocketError::Subscribe("WebSocket stream terminated unexpectedly".to_string()))
+                        None => break Err(DataError::from(SocketError::Subscribe("WebSocket stream terminated unexpectedly".to_string())))
                     };
 
                     match Self::Parser::parse::<BitfinexPlatformEvent>(response) {
+                        // Subscription failure due to the Bitfinex channel limit being hit
+                        Some(Ok(BitfinexPlatformEvent::Error(error))) if error.is_limit_exceeded() => {
+                            break Err(DataError::from(error.into_limit_exceeded(Exchange::ID)))
+                        }
                         Some(Ok(response)) => match response.validate() {
                             // Bitfinex server is online
                             Ok(BitfinexPlatformEvent::PlatformStatus(status)) => {
@@ -112,7 +118,7 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                             }
 
                             // Subscription failure
-                            Err(err) => break Err(err),
+                            Err(err) => break Err(DataError::from(err)),
 
                             // Not reachable after BitfinexPlatformEvent validate()
                             Ok(BitfinexPlatformEvent::Error(error)) => panic!("{error:?}"),
@@ -131,9 +137,9 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                             continue
                         }
                         Some(Err(SocketError::Terminated(close_frame))) => {
-                            break Err(SocketError::Subscribe(
+                            break Err(DataError::from(SocketError::Subscribe(
                                 format!("received WebSocket CloseFrame: {close_frame}")
-                            ))
+                            )))
                         }
                         _ => {
                             // Pings, Pongs, Frames, etc.
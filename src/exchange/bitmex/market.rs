@@ -1,4 +1,12 @@
-use crate::{exchange::bitmex::Bitmex, subscription::Subscription, Identifier};
+use crate::{
+    exchange::{
+        bitmex::Bitmex,
+        market::{from_xbt, split_known_quote, to_xbt, MarketId},
+    },
+    subscription::Subscription,
+    Identifier,
+};
+use barter_integration::model::instrument::{symbol::Symbol, Instrument};
 use serde::{Deserialize, Serialize};
 
 /// Type that defines how to translate a Barter [`Subscription`] into a [`Bitmex`]
@@ -8,11 +16,22 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BitmexMarket(pub String);
 
-impl<Kind> Identifier<BitmexMarket> for Subscription<Bitmex, Kind> {
-    fn id(&self) -> BitmexMarket {
+impl MarketId for Bitmex {
+    fn market_id(instrument: &Instrument) -> String {
         // Notes:
         // - Must be uppercase since Bitmex sends message with uppercase MARKET (eg/ XBTUSD).
-        BitmexMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase())
+        // - Bitmex uses the XBT alias for BTC.
+        format!("{}{}", to_xbt(&instrument.base), to_xbt(&instrument.quote)).to_uppercase()
+    }
+
+    fn parse_market_id(market: &str) -> Option<(Symbol, Symbol)> {
+        split_known_quote(market).map(|(base, quote)| (from_xbt(base), from_xbt(quote)))
+    }
+}
+
+impl<Kind> Identifier<BitmexMarket> for Subscription<Bitmex, Kind> {
+    fn id(&self) -> BitmexMarket {
+        BitmexMarket(Bitmex::market_id(&self.instrument))
     }
 }
 
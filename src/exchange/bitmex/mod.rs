@@ -16,7 +16,7 @@ use barter_integration::{
     error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
 };
 use serde::de::{Error, Unexpected};
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 use url::Url;
 
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
@@ -72,7 +72,7 @@ impl Connector for Bitmex {
         )]
     }
 
-    fn expected_responses(_: &Map<Instrument>) -> usize {
+    fn expected_responses(_: &Map<Arc<Instrument>>) -> usize {
         1
     }
 }
@@ -90,7 +90,7 @@ impl<'de> serde::Deserialize<'de> for Bitmex {
         let expected = Self::ID.as_str();
 
         if input == Self::ID.as_str() {
-            Ok(Self::default())
+            Ok(Self)
         } else {
             Err(Error::invalid_value(Unexpected::Str(input), &expected))
         }
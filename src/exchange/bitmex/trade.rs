@@ -1,11 +1,13 @@
 use crate::{
+    clock,
     event::{MarketEvent, MarketIter},
     exchange::{bitmex::message::BitmexMessage, ExchangeId},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, TradeFields},
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Terse type alias for an [`BitmexTrade`](BitmexTradeInner) real-time trades WebSocket message.
 pub type BitmexTrade = BitmexMessage<BitmexTradeInner>;
@@ -41,6 +43,9 @@ pub struct BitmexTradeInner {
     pub symbol: String,
 
     pub side: Side,
+    /// Quote denominated notional for inverse contracts (eg/ USD for XBTUSD), otherwise number
+    /// of contracts - see
+    /// [`ExchangeId::trade_quantity_unit`](crate::exchange::ExchangeId::trade_quantity_unit).
     #[serde(rename = "size")]
     pub amount: f64,
     pub price: f64,
@@ -49,8 +54,9 @@ pub struct BitmexTradeInner {
     pub id: String,
 }
 
-impl From<(ExchangeId, Instrument, BitmexTrade)> for MarketIter<PublicTrade> {
-    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, BitmexTrade)) -> Self {
+impl From<(ExchangeId, Arc<Instrument>, BitmexTrade)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trades): (ExchangeId, Arc<Instrument>, BitmexTrade)) -> Self {
+        let fields = TradeFields::current();
         Self(
             trades
                 .data
@@ -58,14 +64,15 @@ impl From<(ExchangeId, Instrument, BitmexTrade)> for MarketIter<PublicTrade> {
                 .map(|trade| {
                     Ok(MarketEvent {
                         exchange_time: trade.timestamp,
-                        received_time: Utc::now(),
+                        received_time: clock::received_time(),
                         exchange: Exchange::from(exchange_id),
                         instrument: instrument.clone(),
                         kind: PublicTrade {
-                            id: trade.id,
+                            id: fields.id(|| trade.id),
                             price: trade.price,
                             amount: trade.amount,
                             side: trade.side,
+                            conditions: vec![],
                         },
                     })
                 })
@@ -0,0 +1,396 @@
+use super::super::{message::de_message_subscription_id, subscription::BybitResponse};
+use crate::{
+    error::DataError,
+    exchange::Connector,
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{BookMessageKind, InstrumentOrderBook, OrderBookUpdater, TaggedBookSync},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// [`Bybit`](super::super::Bybit) OrderBook Level2 WebSocket message, which is either an
+/// OrderBook snapshot or delta, or a [`BybitResponse`] (eg/ a pong) received on the same
+/// connection.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BybitBookMessage {
+    Response(BybitResponse),
+    OrderBook(BybitOrderBookL2),
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitBookMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            BybitBookMessage::Response(_) => None,
+            BybitBookMessage::OrderBook(book) => Some(book.subscription_id.clone()),
+        }
+    }
+}
+
+/// [`Bybit`](super::super::Bybit) real-time OrderBook Level2 snapshot or delta.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// #### Snapshot
+/// ```json
+/// {
+///     "topic": "orderbook.50.BTCUSDT",
+///     "type": "snapshot",
+///     "ts": 1672304484978,
+///     "data": {
+///         "s": "BTCUSDT",
+///         "b": [["16493.50", "0.006"], ["16493.00", "0.100"]],
+///         "a": [["16611.00", "0.029"], ["16612.00", "0.213"]],
+///         "u": 18521288,
+///         "seq": 7961638724
+///     },
+///     "cts": 1672304484976
+/// }
+/// ```
+///
+/// #### Delta
+/// ```json
+/// {
+///     "topic": "orderbook.50.BTCUSDT",
+///     "type": "delta",
+///     "ts": 1687940967466,
+///     "data": {
+///         "s": "BTCUSDT",
+///         "b": [["30247.20", "30.028"]],
+///         "a": [["30248.70", "0"], ["30249.30", "0.892"]],
+///         "u": 177400507,
+///         "seq": 66544703342
+///     },
+///     "cts": 1687940967464
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitOrderBookL2 {
+    #[serde(alias = "topic", deserialize_with = "de_message_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(rename = "type")]
+    pub kind: BookMessageKind,
+    #[serde(
+        alias = "ts",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub data: BybitOrderBookL2Data,
+}
+
+/// [`Bybit`](super::super::Bybit) OrderBook Level2 snapshot or delta data.
+///
+/// See [`BybitOrderBookL2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitOrderBookL2Data {
+    #[serde(rename = "b")]
+    pub bids: Vec<BybitLevel>,
+    #[serde(rename = "a")]
+    pub asks: Vec<BybitLevel>,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+}
+
+/// [`Bybit`](super::super::Bybit) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// ```json
+/// ["16493.50", "0.006"]
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitLevel {
+    #[serde(deserialize_with = "crate::de::de_f64")]
+    pub price: f64,
+    #[serde(deserialize_with = "crate::de::de_f64")]
+    pub amount: f64,
+}
+
+impl From<BybitLevel> for Level {
+    fn from(level: BybitLevel) -> Self {
+        Self::new(level.price, level.amount)
+    }
+}
+
+/// [`Bybit`](super::super::Bybit) [`OrderBookUpdater`].
+///
+/// Bybit: How To Maintain A Local OrderBook
+///
+/// 1. The first message of a subscription is a snapshot ("type": "snapshot") of the book.
+/// 2. Subsequent messages are deltas ("type": "delta") containing the absolute size for a
+///    price level, where a size of 0 removes the price level.
+/// 3. A new snapshot may be sent at any time (eg/ after a service restart), which resets the
+///    local book.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct BybitBookUpdater {
+    pub sync: TaggedBookSync,
+}
+
+#[async_trait]
+impl OrderBookUpdater for BybitBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = BybitBookMessage;
+    const WS_SNAPSHOT: bool = true;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Arc<Instrument>,
+        depth: Option<u16>,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Connector + Send,
+        Kind: Send,
+    {
+        // Bybit sends the initial OrderBook snapshot over the WebSocket, so start empty
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+            depth,
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        let BybitBookMessage::OrderBook(update) = update else {
+            return Ok(None);
+        };
+
+        self.sync.apply(
+            book,
+            update.kind,
+            update.time,
+            update.data.bids,
+            update.data.asks,
+        )?;
+        book.bids.sort();
+        book.asks.sort();
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_bybit_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BybitBookMessage, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    {
+                        "topic": "orderbook.50.BTCUSDT",
+                        "type": "snapshot",
+                        "ts": 1672304484978,
+                        "data": {
+                            "s": "BTCUSDT",
+                            "b": [["16493.50", "0.006"], ["16493.00", "0.100"]],
+                            "a": [["16611.00", "0.029"]],
+                            "u": 18521288,
+                            "seq": 7961638724
+                        },
+                        "cts": 1672304484976
+                    }
+                    "#,
+                    expected: Ok(BybitBookMessage::OrderBook(BybitOrderBookL2 {
+                        subscription_id: SubscriptionId::from("orderbook.50|BTCUSDT"),
+                        kind: BookMessageKind::Snapshot,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672304484978,
+                        )),
+                        data: BybitOrderBookL2Data {
+                            bids: vec![
+                                BybitLevel {
+                                    price: 16493.50,
+                                    amount: 0.006,
+                                },
+                                BybitLevel {
+                                    price: 16493.00,
+                                    amount: 0.1,
+                                },
+                            ],
+                            asks: vec![BybitLevel {
+                                price: 16611.00,
+                                amount: 0.029,
+                            }],
+                            update_id: 18521288,
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: valid delta w/ a removed level
+                    input: r#"
+                    {
+                        "topic": "orderbook.50.BTCUSDT",
+                        "type": "delta",
+                        "ts": 1687940967466,
+                        "data": {
+                            "s": "BTCUSDT",
+                            "b": [],
+                            "a": [["30248.70", "0"], ["30249.30", "0.892"]],
+                            "u": 177400507,
+                            "seq": 66544703342
+                        },
+                        "cts": 1687940967464
+                    }
+                    "#,
+                    expected: Ok(BybitBookMessage::OrderBook(BybitOrderBookL2 {
+                        subscription_id: SubscriptionId::from("orderbook.50|BTCUSDT"),
+                        kind: BookMessageKind::Update,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1687940967466,
+                        )),
+                        data: BybitOrderBookL2Data {
+                            bids: vec![],
+                            asks: vec![
+                                BybitLevel {
+                                    price: 30248.70,
+                                    amount: 0.0,
+                                },
+                                BybitLevel {
+                                    price: 30249.30,
+                                    amount: 0.892,
+                                },
+                            ],
+                            update_id: 177400507,
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC2: invalid message type
+                    input: r#"
+                    {
+                        "topic": "orderbook.50.BTCUSDT",
+                        "type": "unknown",
+                        "ts": 1687940967466,
+                        "data": {"s": "BTCUSDT", "b": [], "a": [], "u": 1, "seq": 1}
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitBookMessage>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_bybit_order_book_l2() {
+        let message = |kind: &str, bids: &str, asks: &str| {
+            serde_json::from_str::<BybitBookMessage>(&format!(
+                r#"{{"topic": "orderbook.50.BTCUSDT", "type": "{kind}", "ts": 1672304484978, "data": {{"s": "BTCUSDT", "b": [{bids}], "a": [{asks}], "u": 1, "seq": 1}}}}"#
+            ))
+            .unwrap()
+        };
+
+        let mut updater = BybitBookUpdater::default();
+        let mut book = OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+            asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+        };
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1672304484978));
+
+        // TC0: delta received before any snapshot is a BookDesync
+        let delta = message("delta", r#"["100", "1"]"#, "");
+        assert!(
+            matches!(updater.update(&mut book, delta), Err(DataError::BookDesync)),
+            "TC0 failed"
+        );
+
+        // TC1: snapshot resets the OrderBook
+        let snapshot = message(
+            "snapshot",
+            r#"["99.5", "2"], ["100", "1"]"#,
+            r#"["101", "3"]"#,
+        );
+        let actual = updater.update(&mut book, snapshot).unwrap().unwrap();
+        let expected = OrderBook {
+            last_update_time: time,
+            bids: OrderBookSide::new(
+                Side::Buy,
+                vec![Level::new(100.0, 1.0), Level::new(99.5, 2.0)],
+            ),
+            asks: OrderBookSide::new(Side::Sell, vec![Level::new(101.0, 3.0)]),
+        };
+        assert_eq!(actual, expected, "TC1 failed");
+
+        // TC2: delta removes & upserts levels
+        let delta = message("delta", r#"["100", "0"]"#, r#"["102", "0.25"]"#);
+        let actual = updater.update(&mut book, delta).unwrap().unwrap();
+        let expected = OrderBook {
+            last_update_time: time,
+            bids: OrderBookSide::new(Side::Buy, vec![Level::new(99.5, 2.0)]),
+            asks: OrderBookSide::new(
+                Side::Sell,
+                vec![Level::new(101.0, 3.0), Level::new(102.0, 0.25)],
+            ),
+        };
+        assert_eq!(actual, expected, "TC2 failed");
+
+        // TC3: a new snapshot resets the OrderBook rather than being applied as a delta
+        let snapshot = message("snapshot", r#"["98", "1"]"#, r#"["103", "1"]"#);
+        let actual = updater.update(&mut book, snapshot).unwrap().unwrap();
+        let expected = OrderBook {
+            last_update_time: time,
+            bids: OrderBookSide::new(Side::Buy, vec![Level::new(98.0, 1.0)]),
+            asks: OrderBookSide::new(Side::Sell, vec![Level::new(103.0, 1.0)]),
+        };
+        assert_eq!(actual, expected, "TC3 failed");
+
+        // TC4: pong response received on the OrderBook connection is ignored
+        let pong = serde_json::from_str::<BybitBookMessage>(
+            r#"{"success": true, "ret_msg": "pong", "conn_id": "1", "op": "ping"}"#,
+        )
+        .unwrap();
+        assert_eq!(updater.update(&mut book, pong).unwrap(), None, "TC4 failed");
+    }
+}
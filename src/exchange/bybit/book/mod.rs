@@ -0,0 +1,2 @@
+/// Level 2 OrderBook types (type-tagged snapshots & deltas).
+pub mod l2;
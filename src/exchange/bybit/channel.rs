@@ -1,6 +1,6 @@
 use crate::{
     exchange::bybit::Bybit,
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Subscription},
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +17,11 @@ impl BybitChannel {
     ///
     /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
     pub const TRADES: Self = Self("publicTrade");
+
+    /// [`Bybit`](super::Bybit) real-time OrderBook Level2 (top 50 levels) channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+    pub const ORDER_BOOK_L2: Self = Self("orderbook.50");
 }
 
 impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, PublicTrades> {
@@ -25,6 +30,12 @@ impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, PublicTrad
     }
 }
 
+impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, OrderBooksL2> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::ORDER_BOOK_L2
+    }
+}
+
 impl AsRef<str> for BybitChannel {
     fn as_ref(&self) -> &str {
         self.0
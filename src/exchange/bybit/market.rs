@@ -1,4 +1,12 @@
-use crate::{exchange::bybit::Bybit, subscription::Subscription, Identifier};
+use crate::{
+    exchange::{
+        bybit::Bybit,
+        market::{split_known_quote, MarketId},
+    },
+    subscription::Subscription,
+    Identifier,
+};
+use barter_integration::model::instrument::{symbol::Symbol, Instrument};
 use serde::{Deserialize, Serialize};
 
 /// Type that defines how to translate a Barter [`Subscription`] into a [`Bybit`](super::Bybit)
@@ -8,11 +16,21 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BybitMarket(pub String);
 
-impl<Server, Kind> Identifier<BybitMarket> for Subscription<Bybit<Server>, Kind> {
-    fn id(&self) -> BybitMarket {
+impl<Server> MarketId for Bybit<Server> {
+    fn market_id(instrument: &Instrument) -> String {
         // Notes:
         // - Must be uppercase since Bybit sends message with uppercase MARKET (eg/ BTCUSDT).
-        BybitMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase())
+        format!("{}{}", instrument.base, instrument.quote).to_uppercase()
+    }
+
+    fn parse_market_id(market: &str) -> Option<(Symbol, Symbol)> {
+        split_known_quote(market).map(|(base, quote)| (Symbol::new(base), Symbol::new(quote)))
+    }
+}
+
+impl<Server, Kind> Identifier<BybitMarket> for Subscription<Bybit<Server>, Kind> {
+    fn id(&self) -> BybitMarket {
+        BybitMarket(Bybit::<Server>::market_id(&self.instrument))
     }
 }
 
@@ -13,6 +13,7 @@ use serde::{
     de::{Error, Unexpected},
     Deserialize, Serialize,
 };
+use std::sync::Arc;
 
 /// [`Bybit`](super::Bybit) websocket message supports both [`BybitTrade`](BybitTrade) and [`BybitResponse`](BybitResponse) .
 #[derive(Debug, Serialize, Deserialize)]
@@ -60,10 +61,10 @@ pub struct BybitPayload<T> {
     pub data: T,
 }
 
-/// Deserialize a [`BybitPayload`] "s" (eg/ "publicTrade.BTCUSDT") as the associated
-/// [`SubscriptionId`].
+/// Deserialize a [`BybitPayload`] "topic" (eg/ "publicTrade.BTCUSDT" or
+/// "orderbook.50.BTCUSDT") as the associated [`SubscriptionId`].
 ///
-/// eg/ "publicTrade|BTCUSDT"
+/// eg/ "publicTrade|BTCUSDT" or "orderbook.50|BTCUSDT"
 pub fn de_message_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
 where
     D: serde::de::Deserializer<'de>,
@@ -71,11 +72,14 @@ where
     let input = <&str as serde::Deserialize>::deserialize(deserializer)?;
     let mut tokens = input.split('.');
 
-    match (tokens.next(), tokens.next(), tokens.next()) {
-        (Some("publicTrade"), Some(market), None) => Ok(SubscriptionId::from(format!(
+    match (tokens.next(), tokens.next(), tokens.next(), tokens.next()) {
+        (Some("publicTrade"), Some(market), None, None) => Ok(SubscriptionId::from(format!(
             "{}|{market}",
             BybitChannel::TRADES.0
         ))),
+        (Some("orderbook"), Some(depth), Some(market), None) => {
+            Ok(SubscriptionId::from(format!("orderbook.{depth}|{market}")))
+        }
         _ => Err(Error::invalid_value(
             Unexpected::Str(input),
             &"invalid message type expected pattern: <type>.<symbol>",
@@ -92,8 +96,10 @@ impl Identifier<Option<SubscriptionId>> for BybitMessage {
     }
 }
 
-impl From<(ExchangeId, Instrument, BybitMessage)> for MarketIter<PublicTrade> {
-    fn from((exchange_id, instrument, message): (ExchangeId, Instrument, BybitMessage)) -> Self {
+impl From<(ExchangeId, Arc<Instrument>, BybitMessage)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Arc<Instrument>, BybitMessage),
+    ) -> Self {
         match message {
             BybitMessage::Response(_) => Self(vec![]),
             BybitMessage::Trade(trade) => Self::from((exchange_id, instrument, trade)),
@@ -1,25 +1,30 @@
 use crate::{
     exchange::{
         bybit::{
-            channel::BybitChannel, market::BybitMarket, message::BybitMessage,
-            subscription::BybitResponse,
+            book::l2::BybitBookUpdater, channel::BybitChannel, market::BybitMarket,
+            message::BybitMessage, subscription::BybitResponse,
         },
+        rate_limit::OutboundRateLimit,
         subscription::ExchangeSub,
         Connector, ExchangeId, ExchangeServer, PingInterval, StreamSelector,
     },
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{trade::PublicTrades, Map},
-    transformer::stateless::StatelessTransformer,
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Map},
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
 use barter_integration::{
     error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
 };
 use serde::de::{Error, Unexpected};
-use std::{fmt::Debug, marker::PhantomData, time::Duration};
+use std::{fmt::Debug, marker::PhantomData, sync::Arc, time::Duration};
 use tokio::time;
 use url::Url;
 
+/// OrderBook types common to both [`BybitSpot`](spot::BybitSpot) and
+/// [`BybitPerpetualsUsd`](futures::BybitPerpetualsUsd).
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -74,6 +79,10 @@ where
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
 
+    fn outbound_rate_limit() -> Option<OutboundRateLimit> {
+        Server::outbound_rate_limit()
+    }
+
     fn ping_interval() -> Option<PingInterval> {
         Some(PingInterval {
             interval: time::interval(Duration::from_millis(5_000)),
@@ -89,25 +98,42 @@ where
     }
 
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        let stream_names = exchange_subs
-            .into_iter()
-            .map(|sub| format!("{}.{}", sub.channel.as_ref(), sub.market.as_ref(),))
-            .collect::<Vec<String>>();
-
-        vec![WsMessage::Text(
-            serde_json::json!({
-                "op": "subscribe",
-                "args": stream_names
-            })
-            .to_string(),
-        )]
+        vec![topic_request("subscribe", exchange_subs)]
     }
 
-    fn expected_responses(_: &Map<Instrument>) -> usize {
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        vec![topic_request("unsubscribe", exchange_subs)]
+    }
+
+    fn expected_responses(_: &Map<Arc<Instrument>>) -> usize {
         1
     }
 }
 
+/// Construct the [`Bybit`] `op` (eg/ "subscribe") [`WsMessage`] for the topics of the provided
+/// [`ExchangeSub`]s.
+///
+/// eg/ {"op": "unsubscribe", "args": ["orderbook.50.BTCUSDT"]}
+fn topic_request(
+    op: &str,
+    exchange_subs: Vec<ExchangeSub<BybitChannel, BybitMarket>>,
+) -> WsMessage {
+    let topics = exchange_subs
+        .into_iter()
+        .map(|sub| format!("{}.{}", sub.channel.as_ref(), sub.market.as_ref()))
+        .collect::<Vec<String>>();
+
+    WsMessage::Text(
+        serde_json::json!({
+            "op": op,
+            "args": topics
+        })
+        .to_string(),
+    )
+}
+
 impl<Server> StreamSelector<PublicTrades> for Bybit<Server>
 where
     Server: ExchangeServer + Debug + Send + Sync,
@@ -115,6 +141,13 @@ where
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BybitMessage>>;
 }
 
+impl<Server> StreamSelector<OrderBooksL2> for Bybit<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync + 'static,
+{
+    type Stream = ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BybitBookUpdater>>;
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Bybit<Server>
 where
     Server: ExchangeServer,
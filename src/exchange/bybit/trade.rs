@@ -1,11 +1,13 @@
 use crate::{
+    clock,
     event::{MarketEvent, MarketIter},
     exchange::{bybit::message::BybitPayload, ExchangeId},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, TradeCondition, TradeFields},
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Terse type alias for an [`BybitTrade`](BybitTradeInner) real-time trades WebSocket message.
 pub type BybitTrade = BybitPayload<Vec<BybitTradeInner>>;
@@ -39,18 +41,22 @@ pub struct BybitTradeInner {
     #[serde(rename = "S")]
     pub side: Side,
 
-    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(alias = "v", deserialize_with = "crate::de::de_f64")]
     pub amount: f64,
 
-    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(alias = "p", deserialize_with = "crate::de::de_f64")]
     pub price: f64,
 
     #[serde(rename = "i")]
     pub id: String,
+
+    #[serde(rename = "BT", default)]
+    pub block_trade: bool,
 }
 
-impl From<(ExchangeId, Instrument, BybitTrade)> for MarketIter<PublicTrade> {
-    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, BybitTrade)) -> Self {
+impl From<(ExchangeId, Arc<Instrument>, BybitTrade)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trades): (ExchangeId, Arc<Instrument>, BybitTrade)) -> Self {
+        let fields = TradeFields::current();
         Self(
             trades
                 .data
@@ -58,14 +64,19 @@ impl From<(ExchangeId, Instrument, BybitTrade)> for MarketIter<PublicTrade> {
                 .map(|trade| {
                     Ok(MarketEvent {
                         exchange_time: trade.time,
-                        received_time: Utc::now(),
+                        received_time: clock::received_time(),
                         exchange: Exchange::from(exchange_id),
                         instrument: instrument.clone(),
                         kind: PublicTrade {
-                            id: trade.id,
+                            id: fields.id(|| trade.id),
                             price: trade.price,
                             amount: trade.amount,
                             side: trade.side,
+                            conditions: if trade.block_trade {
+                                vec![TradeCondition::Block]
+                            } else {
+                                vec![]
+                            },
                         },
                     })
                 })
@@ -116,9 +127,10 @@ mod tests {
                         amount: 0.001,
                         price: 16578.50,
                         id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_string(),
+                        block_trade: false,
                     }),
                 },
-                // TC1: input BybitTradeInner is deserialised
+                // TC1: input BybitTradeInner block trade is deserialised
                 TestCase {
                     input: r#"
                         {
@@ -129,7 +141,7 @@ mod tests {
                             "p": "16578.50",
                             "L": "PlusTick",
                             "i": "20f43950-d8dd-5b31-9112-a178eb6023af",
-                            "BT": false
+                            "BT": true
                         }
                     "#,
                     expected: Ok(BybitTradeInner {
@@ -141,6 +153,7 @@ mod tests {
                         amount: 0.001,
                         price: 16578.50,
                         id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_string(),
+                        block_trade: true,
                     }),
                 },
                 // TC2: input BybitTradeInner is unable to be deserialised
@@ -236,6 +249,7 @@ mod tests {
                                 amount: 0.001,
                                 price: 16578.50,
                                 id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_string(),
+                                block_trade: false,
                             },
                             BybitTradeInner {
                                 time: datetime_utc_from_epoch_duration(Duration::from_millis(
@@ -246,6 +260,7 @@ mod tests {
                                 amount: 0.001,
                                 price: 16578.50,
                                 id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_string(),
+                                block_trade: false,
                             },
                         ],
                     }),
@@ -1,5 +1,10 @@
 use super::Coinbase;
-use crate::{subscription::Subscription, Identifier};
+use crate::{
+    exchange::market::{split_separated, MarketId},
+    subscription::Subscription,
+    Identifier,
+};
+use barter_integration::model::instrument::{symbol::Symbol, Instrument};
 use serde::{Deserialize, Serialize};
 
 /// Type that defines how to translate a Barter [`Subscription`] into a
@@ -9,9 +14,19 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct CoinbaseMarket(pub String);
 
+impl MarketId for Coinbase {
+    fn market_id(instrument: &Instrument) -> String {
+        format!("{}-{}", instrument.base, instrument.quote).to_uppercase()
+    }
+
+    fn parse_market_id(market: &str) -> Option<(Symbol, Symbol)> {
+        split_separated(market, '-').map(|(base, quote)| (Symbol::new(base), Symbol::new(quote)))
+    }
+}
+
 impl<Kind> Identifier<CoinbaseMarket> for Subscription<Coinbase, Kind> {
     fn id(&self) -> CoinbaseMarket {
-        CoinbaseMarket(format!("{}-{}", self.instrument.base, self.instrument.quote).to_uppercase())
+        CoinbaseMarket(Coinbase::market_id(&self.instrument))
     }
 }
 
@@ -1,13 +1,15 @@
 use super::CoinbaseChannel;
 use crate::{
+    clock,
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, TradeFields},
     Identifier,
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Coinbase real-time trade WebSocket message.
 ///
@@ -35,9 +37,9 @@ pub struct CoinbaseTrade {
     #[serde(alias = "trade_id")]
     pub id: u64,
     pub time: DateTime<Utc>,
-    #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(alias = "size", deserialize_with = "crate::de::de_f64")]
     pub amount: f64,
-    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    #[serde(deserialize_with = "crate::de::de_f64")]
     pub price: f64,
     pub side: Side,
 }
@@ -48,18 +50,22 @@ impl Identifier<Option<SubscriptionId>> for CoinbaseTrade {
     }
 }
 
-impl From<(ExchangeId, Instrument, CoinbaseTrade)> for MarketIter<PublicTrade> {
-    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, CoinbaseTrade)) -> Self {
+impl From<(ExchangeId, Arc<Instrument>, CoinbaseTrade)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument, trade): (ExchangeId, Arc<Instrument>, CoinbaseTrade),
+    ) -> Self {
+        let fields = TradeFields::current();
         Self(vec![Ok(MarketEvent {
             exchange_time: trade.time,
-            received_time: Utc::now(),
+            received_time: clock::received_time(),
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: PublicTrade {
-                id: trade.id.to_string(),
+                id: fields.id(|| trade.id.to_string()),
                 price: trade.price,
                 amount: trade.amount,
                 side: trade.side,
+                conditions: vec![],
             },
         })])
     }
@@ -115,12 +121,34 @@ mod tests {
                     price: 400.23,
                     amount: 5.23512,
                     side: Side::Sell,
-                    time: DateTime::from_utc(
+                    time: DateTime::from_naive_utc_and_offset(
                         NaiveDateTime::from_str("2014-11-07T08:19:27.028459").unwrap(),
                         Utc,
                     ),
                 }),
             },
+            TestCase {
+                // TC2: valid buy side CoinbaseTrade w/ product-id SubscriptionId
+                input: r#"
+                {
+                    "type": "match","trade_id": 512877846,"sequence": 41475894597,
+                    "maker_order_id": "0a2c2d5d-2bc7-41a2-a4a4-0b2e0e3ed3d0",
+                    "taker_order_id": "b8d1d3e5-9b5c-4b0c-8e68-5f4d3bd7d8f3",
+                    "time": "2023-06-01T12:30:45.123Z",
+                    "product_id": "ETH-USD", "size": "0.5", "price": "1870.12", "side": "buy"
+                }"#,
+                expected: Ok(CoinbaseTrade {
+                    subscription_id: SubscriptionId::from("matches|ETH-USD"),
+                    id: 512877846,
+                    price: 1870.12,
+                    amount: 0.5,
+                    side: Side::Buy,
+                    time: DateTime::from_naive_utc_and_offset(
+                        NaiveDateTime::from_str("2023-06-01T12:30:45.123").unwrap(),
+                        Utc,
+                    ),
+                }),
+            },
         ];
 
         for (index, test) in cases.into_iter().enumerate() {
@@ -1,5 +1,6 @@
+use super::spot::GateioSpot;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Subscription},
     Identifier,
 };
 use barter_integration::model::instrument::kind::InstrumentKind;
@@ -18,6 +19,11 @@ impl GateioChannel {
     /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#public-trades-channel>
     pub const SPOT_TRADES: Self = Self("spot.trades");
 
+    /// Gateio [`InstrumentKind::Spot`] OrderBook Level2 updates channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+    pub const SPOT_ORDER_BOOK_L2: Self = Self("spot.order_book_update");
+
     /// Gateio [`InstrumentKind::Future`] & [`InstrumentKind::Perpetual`] real-time trades channel.
     ///
     /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#trades-subscription>
@@ -40,6 +46,12 @@ impl<GateioExchange> Identifier<GateioChannel> for Subscription<GateioExchange,
     }
 }
 
+impl Identifier<GateioChannel> for Subscription<GateioSpot, OrderBooksL2> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::SPOT_ORDER_BOOK_L2
+    }
+}
+
 impl AsRef<str> for GateioChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,7 +1,12 @@
 use super::Gateio;
-use crate::{subscription::Subscription, Identifier};
+use crate::{
+    exchange::market::{split_separated, MarketId},
+    subscription::Subscription,
+    Identifier,
+};
 use barter_integration::model::instrument::{
     kind::{InstrumentKind, OptionKind},
+    symbol::Symbol,
     Instrument,
 };
 use chrono::{
@@ -17,29 +22,40 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct GateioMarket(pub String);
 
-impl<Server, Kind> Identifier<GateioMarket> for Subscription<Gateio<Server>, Kind> {
-    fn id(&self) -> GateioMarket {
+impl<Server> MarketId for Gateio<Server> {
+    fn market_id(instrument: &Instrument) -> String {
         use InstrumentKind::*;
-        let Instrument { base, quote, kind } = &self.instrument;
+        let Instrument { base, quote, kind } = instrument;
 
-        GateioMarket(
-            match kind {
-                Spot | Perpetual => format!("{base}_{quote}"),
-                Future(future) => {
-                    format!("{base}_{quote}_QUARTERLY_{}", format_expiry(future.expiry))
-                }
-                Option(option) => format!(
-                    "{base}_{quote}-{}-{}-{}",
-                    format_expiry(option.expiry),
-                    option.strike,
-                    match option.kind {
-                        OptionKind::Call => "C",
-                        OptionKind::Put => "P",
-                    },
-                ),
+        match kind {
+            Spot | Perpetual => format!("{base}_{quote}"),
+            Future(future) => {
+                format!("{base}_{quote}_QUARTERLY_{}", format_expiry(future.expiry))
             }
-            .to_uppercase(),
-        )
+            Option(option) => format!(
+                "{base}_{quote}-{}-{}-{}",
+                format_expiry(option.expiry),
+                option.strike,
+                match option.kind {
+                    OptionKind::Call => "C",
+                    OptionKind::Put => "P",
+                },
+            ),
+        }
+        .to_uppercase()
+    }
+
+    fn parse_market_id(market: &str) -> Option<(Symbol, Symbol)> {
+        // Option markets separate the expiry from the quote with a dash (eg/ "BTC_USDT-20241231")
+        let (base, quote) = split_separated(market, '_')?;
+        let quote = quote.split('-').next().filter(|quote| !quote.is_empty())?;
+        Some((Symbol::new(base), Symbol::new(quote)))
+    }
+}
+
+impl<Server, Kind> Identifier<GateioMarket> for Subscription<Gateio<Server>, Kind> {
+    fn id(&self) -> GateioMarket {
+        GateioMarket(Gateio::<Server>::market_id(&self.instrument))
     }
 }
 
@@ -1,6 +1,12 @@
-use self::{channel::GateioChannel, market::GateioMarket, subscription::GateioSubResponse};
+use self::{
+    channel::GateioChannel, market::GateioMarket, spot::l2::BOOK_L2_UPDATE_INTERVAL_GATEIO_SPOT,
+    subscription::GateioSubResponse,
+};
 use crate::{
-    exchange::{subscription::ExchangeSub, Connector, ExchangeId, ExchangeServer},
+    exchange::{
+        rate_limit::OutboundRateLimit, subscription::ExchangeSub, Connector, ExchangeId,
+        ExchangeServer,
+    },
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
@@ -71,16 +77,28 @@ where
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
 
+    fn outbound_rate_limit() -> Option<OutboundRateLimit> {
+        Server::outbound_rate_limit()
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
         exchange_subs
             .into_iter()
             .map(|ExchangeSub { channel, market }| {
+                // OrderBook Level2 updates are subscribed to at a specific update interval
+                let payload = match channel {
+                    GateioChannel::SPOT_ORDER_BOOK_L2 => {
+                        json!([market.as_ref(), BOOK_L2_UPDATE_INTERVAL_GATEIO_SPOT])
+                    }
+                    _ => json!([market.as_ref()]),
+                };
+
                 WsMessage::Text(
                     json!({
                         "time": chrono::Utc::now().timestamp_millis(),
                         "channel": channel.as_ref(),
                         "event": "subscribe",
-                        "payload": [market.as_ref()]
+                        "payload": payload
                     })
                     .to_string(),
                 )
@@ -123,3 +141,47 @@ where
         serializer.serialize_str(exchange_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{channel::GateioChannel, market::GateioMarket, spot::GateioSpot, *};
+
+    #[test]
+    fn test_gateio_requests() {
+        struct TestCase {
+            input: ExchangeSub<GateioChannel, GateioMarket>,
+            expected_payload: serde_json::Value,
+        }
+
+        let market = || GateioMarket("BTC_USDT".to_string());
+        let tests = vec![
+            TestCase {
+                // TC0: trades are subscribed to by market
+                input: ExchangeSub::from((GateioChannel::SPOT_TRADES, market())),
+                expected_payload: json!(["BTC_USDT"]),
+            },
+            TestCase {
+                // TC1: OrderBook Level2 updates are subscribed to by market & update interval
+                input: ExchangeSub::from((GateioChannel::SPOT_ORDER_BOOK_L2, market())),
+                expected_payload: json!(["BTC_USDT", "100ms"]),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let channel = test.input.channel;
+            let requests = GateioSpot::requests(vec![test.input]);
+            let [WsMessage::Text(request)] = requests.as_slice() else {
+                panic!("TC{index} failed: expected a single text request, got: {requests:?}");
+            };
+
+            let request = serde_json::from_str::<serde_json::Value>(request).unwrap();
+            assert_eq!(request["channel"], channel.as_ref(), "TC{index} failed");
+            assert_eq!(request["event"], "subscribe", "TC{index} failed");
+            assert_eq!(
+                request["payload"], test.expected_payload,
+                "TC{index} failed"
+            );
+            assert!(request["time"].is_i64(), "TC{index} failed");
+        }
+    }
+}
@@ -1,13 +1,15 @@
 use super::super::message::GateioMessage;
 use crate::{
+    clock,
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, TradeFields},
     Identifier,
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Terse type alias for a
 /// [`GateioFuturesUsdt`](super::super::futures::GateioFuturesUsdt),
@@ -57,7 +59,7 @@ pub struct GateioFuturesTradeInner {
     )]
     pub time: DateTime<Utc>,
     pub id: u64,
-    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    #[serde(deserialize_with = "crate::de::de_f64")]
     pub price: f64,
     #[serde(rename = "size")]
     pub amount: f64,
@@ -71,21 +73,22 @@ impl Identifier<Option<SubscriptionId>> for GateioFuturesTrades {
     }
 }
 
-impl From<(ExchangeId, Instrument, GateioFuturesTrades)> for MarketIter<PublicTrade> {
+impl From<(ExchangeId, Arc<Instrument>, GateioFuturesTrades)> for MarketIter<PublicTrade> {
     fn from(
-        (exchange_id, instrument, trades): (ExchangeId, Instrument, GateioFuturesTrades),
+        (exchange_id, instrument, trades): (ExchangeId, Arc<Instrument>, GateioFuturesTrades),
     ) -> Self {
+        let fields = TradeFields::current();
         trades
             .data
             .into_iter()
             .map(|trade| {
                 Ok(MarketEvent {
                     exchange_time: trade.time,
-                    received_time: Utc::now(),
+                    received_time: clock::received_time(),
                     exchange: Exchange::from(exchange_id),
                     instrument: instrument.clone(),
                     kind: PublicTrade {
-                        id: trade.id.to_string(),
+                        id: fields.id(|| trade.id.to_string()),
                         price: trade.price,
                         amount: trade.amount,
                         side: if trade.amount.is_sign_positive() {
@@ -93,6 +96,7 @@ impl From<(ExchangeId, Instrument, GateioFuturesTrades)> for MarketIter<PublicTr
                         } else {
                             Side::Sell
                         },
+                        conditions: vec![],
                     },
                 })
             })
@@ -0,0 +1,447 @@
+use super::{super::message::GateioMessage, GateioSpot};
+use crate::{
+    error::DataError,
+    exchange::{market::MarketId, subscription::ExchangeSub, Connector},
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// [`GateioSpot`](super::GateioSpot) HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#retrieve-order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_SPOT: &str =
+    "https://api.gateio.ws/api/v4/spot/order_book";
+
+/// [`GateioSpot`](super::GateioSpot) OrderBook Level2 update interval subscribed to.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+pub const BOOK_L2_UPDATE_INTERVAL_GATEIO_SPOT: &str = "100ms";
+
+/// Default number of [`Level`]s per side of the [`GateioSpot`](super::GateioSpot) OrderBook
+/// Level2 snapshot.
+pub const DEFAULT_BOOK_L2_SNAPSHOT_DEPTH_GATEIO_SPOT: u16 = 100;
+
+/// [`GateioSpot`](super::GateioSpot) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// ```json
+/// ["19137.74", "0.0001"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioLevel {
+    #[serde(deserialize_with = "crate::de::de_f64")]
+    pub price: f64,
+    #[serde(deserialize_with = "crate::de::de_f64")]
+    pub amount: f64,
+}
+
+impl From<GateioLevel> for Level {
+    fn from(level: GateioLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// [`GateioSpot`](super::GateioSpot) OrderBook Level2 snapshot HTTP message (requested
+/// `with_id=true`).
+///
+/// Used as the starting [`OrderBook`] before OrderBook Level2 update WebSocket messages are
+/// applied.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#retrieve-order-book>
+/// ```json
+/// {
+///     "id": 123456,
+///     "current": 1623898993123,
+///     "update": 1623898993121,
+///     "asks": [["1.52", "1.151"]],
+///     "bids": [["1.17", "201.863"]]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioOrderBookL2Snapshot {
+    #[serde(rename = "id")]
+    pub last_update_id: u64,
+    pub bids: Vec<GateioLevel>,
+    pub asks: Vec<GateioLevel>,
+}
+
+impl From<GateioOrderBookL2Snapshot> for OrderBook {
+    fn from(snapshot: GateioOrderBookL2Snapshot) -> Self {
+        Self {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, snapshot.bids),
+            asks: OrderBookSide::new(Side::Sell, snapshot.asks),
+        }
+    }
+}
+
+/// Terse type alias for a [`GateioSpot`](super::GateioSpot) OrderBook Level2 update WebSocket
+/// message.
+pub type GateioSpotOrderBookL2 = GateioMessage<GateioSpotOrderBookL2Inner>;
+
+/// [`GateioSpot`](super::GateioSpot) OrderBook Level2 update WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+/// ```json
+/// {
+///     "t": 1606294781123,
+///     "e": "depthUpdate",
+///     "E": 1606294781,
+///     "s": "BTC_USDT",
+///     "U": 48776301,
+///     "u": 48776306,
+///     "b": [["19137.74", "0.0001"], ["19088.37", "0"]],
+///     "a": [["19137.75", "0.6135"]]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioSpotOrderBookL2Inner {
+    #[serde(rename = "s")]
+    pub market: String,
+    #[serde(
+        rename = "t",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    #[serde(rename = "u")]
+    pub last_update_id: u64,
+    #[serde(rename = "b")]
+    pub bids: Vec<GateioLevel>,
+    #[serde(rename = "a")]
+    pub asks: Vec<GateioLevel>,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioSpotOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((&self.channel, &self.data.market)).id())
+    }
+}
+
+/// [`GateioSpot`](super::GateioSpot) [`OrderBookUpdater`].
+///
+/// GateioSpot: How To Maintain A Local OrderBook
+///
+/// 1. Subscribe to the `spot.order_book_update` channel & buffer the updates received.
+/// 2. Get a snapshot from <https://api.gateio.ws/api/v4/spot/order_book?currency_pair=BTC_USDT&limit=100&with_id=true>,
+///    which has a baseline `id`.
+/// 3. Drop any update where u < id + 1.
+/// 4. The first processed update should have U <= id + 1 AND u >= id + 1.
+/// 5. Each new update's U should be equal to the previous update's u + 1.
+/// 6. The data in each update is the absolute quantity for a price level.
+/// 7. If the quantity is 0, remove the price level.
+///
+/// ### Notes
+/// - Uppercase U => first_update_id
+/// - Lowercase u => last_update_id,
+/// - A stale snapshot, or a gap between subsequent updates, yields a terminal
+///   [`DataError::InvalidSequence`], so the consumer loop re-connects and re-initialises the
+///   [`OrderBook`] from a fresh snapshot rather than emitting a corrupt book.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#how-to-maintain-local-order-book>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct GateioSpotBookUpdater {
+    pub updates_processed: u64,
+    pub last_update_id: u64,
+}
+
+impl GateioSpotBookUpdater {
+    /// Construct a new GateioSpot [`OrderBookUpdater`] using the provided last_update_id from
+    /// a HTTP snapshot.
+    pub fn new(last_update_id: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            last_update_id,
+        }
+    }
+
+    /// GateioSpot: How To Maintain A Local OrderBook: Step 4 & 5:
+    /// "The first processed update should have U <= id + 1 AND u >= id + 1" &
+    /// "Each new update's U should be equal to the previous update's u + 1".
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#how-to-maintain-local-order-book>
+    pub fn validate_update(&self, update: &GateioSpotOrderBookL2Inner) -> Result<(), DataError> {
+        let expected_next_id = self.last_update_id + 1;
+        let valid = match self.updates_processed {
+            0 => {
+                update.first_update_id <= expected_next_id
+                    && update.last_update_id >= expected_next_id
+            }
+            _ => update.first_update_id == expected_next_id,
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_update_id,
+                first_update_id: update.first_update_id,
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for GateioSpotBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = GateioSpotOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Arc<Instrument>,
+        depth: Option<u16>,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Connector + Send,
+        Kind: Send,
+    {
+        // Construct initial OrderBook snapshot GET url
+        let snapshot_url = format!(
+            "{}?currency_pair={}&limit={}&with_id=true",
+            HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_SPOT,
+            GateioSpot::market_id(&instrument),
+            depth.unwrap_or(DEFAULT_BOOK_L2_SNAPSHOT_DEPTH_GATEIO_SPOT),
+        );
+
+        // Fetch initial OrderBook snapshot via HTTP
+        let snapshot = reqwest::get(snapshot_url)
+            .await
+            .map_err(SocketError::Http)?
+            .json::<GateioOrderBookL2Snapshot>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(snapshot.last_update_id),
+            book: OrderBook::from(snapshot),
+            depth,
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        // GateioSpot: How To Maintain A Local OrderBook
+        // See Self's Rust Docs for more information on each numbered step
+        // See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#how-to-maintain-local-order-book>
+        let update = update.data;
+
+        // 3. Drop any update where u < id + 1:
+        if update.last_update_id <= self.last_update_id {
+            return Ok(None);
+        }
+
+        // 4. & 5. Validate the update follows on from the snapshot or previous update:
+        self.validate_update(&update)?;
+
+        // Update OrderBook metadata & Levels:
+        // 6. The data in each update is the absolute quantity for a price level.
+        // 7. If the quantity is 0, remove the price level.
+        book.last_update_time = update.time;
+        book.bids.upsert(update.bids);
+        book.asks.upsert(update.asks);
+
+        // Update OrderBookUpdater metadata
+        self.updates_processed += 1;
+        self.last_update_id = update.last_update_id;
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn update(first_update_id: u64, last_update_id: u64) -> GateioSpotOrderBookL2 {
+        GateioSpotOrderBookL2 {
+            channel: "spot.order_book_update".to_string(),
+            error: None,
+            data: GateioSpotOrderBookL2Inner {
+                market: "BTC_USDT".to_string(),
+                time: Utc.timestamp_millis_opt(1606294781123).unwrap(),
+                first_update_id,
+                last_update_id,
+                bids: vec![GateioLevel {
+                    price: 100.0,
+                    amount: last_update_id as f64,
+                }],
+                asks: vec![],
+            },
+        }
+    }
+
+    #[test]
+    fn test_de_gateio_spot_order_book_l2() {
+        let input = r#"
+        {
+            "time": 1606294781,
+            "time_ms": 1606294781236,
+            "channel": "spot.order_book_update",
+            "event": "update",
+            "result": {
+                "t": 1606294781123,
+                "e": "depthUpdate",
+                "E": 1606294781,
+                "s": "BTC_USDT",
+                "U": 48776301,
+                "u": 48776306,
+                "b": [["19137.74", "0.0001"], ["19088.37", "0"]],
+                "a": [["19137.75", "0.6135"]]
+            }
+        }
+        "#;
+
+        let actual = serde_json::from_str::<GateioSpotOrderBookL2>(input).unwrap();
+        assert_eq!(
+            actual.id(),
+            Some(SubscriptionId::from("spot.order_book_update|BTC_USDT"))
+        );
+        assert_eq!(
+            actual.data,
+            GateioSpotOrderBookL2Inner {
+                market: "BTC_USDT".to_string(),
+                time: Utc.timestamp_millis_opt(1606294781123).unwrap(),
+                first_update_id: 48776301,
+                last_update_id: 48776306,
+                bids: vec![
+                    GateioLevel {
+                        price: 19137.74,
+                        amount: 0.0001
+                    },
+                    GateioLevel {
+                        price: 19088.37,
+                        amount: 0.0
+                    },
+                ],
+                asks: vec![GateioLevel {
+                    price: 19137.75,
+                    amount: 0.6135
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_de_gateio_order_book_l2_snapshot() {
+        let input = r#"
+        {
+            "id": 123456,
+            "current": 1623898993123,
+            "update": 1623898993121,
+            "asks": [["1.52", "1.151"]],
+            "bids": [["1.17", "201.863"]]
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<GateioOrderBookL2Snapshot>(input).unwrap(),
+            GateioOrderBookL2Snapshot {
+                last_update_id: 123456,
+                bids: vec![GateioLevel {
+                    price: 1.17,
+                    amount: 201.863
+                }],
+                asks: vec![GateioLevel {
+                    price: 1.52,
+                    amount: 1.151
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_gateio_spot_book_updater_update() {
+        struct TestCase {
+            input: GateioSpotOrderBookL2,
+            expected: Result<Option<f64>, DataError>,
+        }
+
+        let mut updater = GateioSpotBookUpdater::new(100);
+        let mut book = OrderBook::from(GateioOrderBookL2Snapshot {
+            last_update_id: 100,
+            bids: vec![],
+            asks: vec![],
+        });
+
+        let tests = vec![
+            TestCase {
+                // TC0: update already included in the snapshot is dropped
+                input: update(90, 100),
+                expected: Ok(None),
+            },
+            TestCase {
+                // TC1: first update straddling the snapshot id is applied
+                input: update(95, 105),
+                expected: Ok(Some(105.0)),
+            },
+            TestCase {
+                // TC2: next sequential update is applied
+                input: update(106, 110),
+                expected: Ok(Some(110.0)),
+            },
+            TestCase {
+                // TC3: gap between updates is an InvalidSequence
+                input: update(112, 115),
+                expected: Err(DataError::InvalidSequence {
+                    prev_last_update_id: 110,
+                    first_update_id: 112,
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = updater
+                .update(&mut book, test.input)
+                .map(|book| book.map(|book| book.bids.levels[0].amount));
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => assert_eq!(actual, expected, "TC{index} failed"),
+                (Err(actual), Err(expected)) => {
+                    assert_eq!(actual.to_string(), expected.to_string(), "TC{index} failed")
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gateio_spot_book_updater_rejects_stale_snapshot() {
+        let mut book = OrderBook::from(GateioOrderBookL2Snapshot {
+            last_update_id: 100,
+            bids: vec![],
+            asks: vec![],
+        });
+
+        let actual = GateioSpotBookUpdater::new(100).update(&mut book, update(102, 105));
+        assert!(matches!(
+            actual,
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: 100,
+                first_update_id: 102,
+            })
+        ));
+    }
+}
@@ -1,13 +1,16 @@
-use self::trade::GateioSpotTrade;
+use self::{l2::GateioSpotBookUpdater, trade::GateioSpotTrade};
 use super::Gateio;
 use crate::{
     exchange::{ExchangeId, ExchangeServer, StreamSelector},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{book::OrderBooksL2, trade::PublicTrades},
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
 use barter_macro::{DeExchange, SerExchange};
 
+/// Level 2 OrderBook types.
+pub mod l2;
+
 /// Public trades types.
 pub mod trade;
 
@@ -36,3 +39,7 @@ impl ExchangeServer for GateioServerSpot {
 impl StreamSelector<PublicTrades> for GateioSpot {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, GateioSpotTrade>>;
 }
+
+impl StreamSelector<OrderBooksL2> for GateioSpot {
+    type Stream = ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, GateioSpotBookUpdater>>;
+}
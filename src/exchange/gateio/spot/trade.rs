@@ -1,13 +1,15 @@
 use super::super::message::GateioMessage;
 use crate::{
+    clock,
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, TradeFields},
     Identifier,
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Terse type alias for an [`GateioSpot`](super::GateioSpot) real-time trades WebSocket message.
 pub type GateioSpotTrade = GateioMessage<GateioSpotTradeInner>;
@@ -37,10 +39,10 @@ pub struct GateioSpotTradeInner {
     )]
     pub time: DateTime<Utc>,
     pub id: u64,
-    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    #[serde(deserialize_with = "crate::de::de_f64")]
     pub price: f64,
 
-    #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(alias = "size", deserialize_with = "crate::de::de_f64")]
     pub amount: f64,
 
     /// Taker [`Side`] of the trade.
@@ -53,18 +55,22 @@ impl Identifier<Option<SubscriptionId>> for GateioSpotTrade {
     }
 }
 
-impl From<(ExchangeId, Instrument, GateioSpotTrade)> for MarketIter<PublicTrade> {
-    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, GateioSpotTrade)) -> Self {
+impl From<(ExchangeId, Arc<Instrument>, GateioSpotTrade)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument, trade): (ExchangeId, Arc<Instrument>, GateioSpotTrade),
+    ) -> Self {
+        let fields = TradeFields::current();
         Self(vec![Ok(MarketEvent {
             exchange_time: trade.data.time,
-            received_time: Utc::now(),
+            received_time: clock::received_time(),
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: PublicTrade {
-                id: trade.data.id.to_string(),
+                id: fields.id(|| trade.data.id.to_string()),
                 price: trade.data.price,
                 amount: trade.data.amount,
                 side: trade.data.side,
+                conditions: vec![],
             },
         })])
     }
@@ -96,7 +102,22 @@ mod tests {
                 }
             }
             "#;
-            serde_json::from_str::<GateioSpotTrade>(input).unwrap();
+            let actual = serde_json::from_str::<GateioSpotTrade>(input).unwrap();
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("spot.trades|GT_USDT"))
+            );
+            assert_eq!(
+                actual.data,
+                GateioSpotTradeInner {
+                    market: "GT_USDT".to_string(),
+                    time: DateTime::from_timestamp_millis(1606292218213).unwrap(),
+                    id: 309143071,
+                    price: 0.4705,
+                    amount: 16.47,
+                    side: Side::Sell,
+                }
+            );
         }
     }
 }
@@ -1,5 +1,6 @@
 use super::super::KrakenMessage;
 use crate::{
+    clock,
     event::{MarketEvent, MarketIter},
     exchange::{kraken::channel::KrakenChannel, subscription::ExchangeSub, ExchangeId},
     subscription::book::{Level, OrderBookL1},
@@ -11,6 +12,7 @@ use barter_integration::{
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Terse type alias for an [`Kraken`](super::super::Kraken) real-time OrderBook Level1
 /// (top of book) WebSocket message.
@@ -35,15 +37,15 @@ pub struct KrakenOrderBookL1Inner {
 /// See docs: <https://docs.kraken.com/websockets/#message-spread>
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct KrakenSpread {
-    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    #[serde(deserialize_with = "crate::de::de_f64")]
     pub best_bid_price: f64,
-    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    #[serde(deserialize_with = "crate::de::de_f64")]
     pub best_ask_price: f64,
     #[serde(deserialize_with = "barter_integration::de::de_str_f64_epoch_s_as_datetime_utc")]
     pub time: DateTime<Utc>,
-    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    #[serde(deserialize_with = "crate::de::de_f64")]
     pub best_bid_amount: f64,
-    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    #[serde(deserialize_with = "crate::de::de_f64")]
     pub best_ask_amount: f64,
 }
 
@@ -53,12 +55,14 @@ impl Identifier<Option<SubscriptionId>> for KrakenOrderBookL1Inner {
     }
 }
 
-impl From<(ExchangeId, Instrument, KrakenOrderBookL1)> for MarketIter<OrderBookL1> {
-    fn from((exchange_id, instrument, book): (ExchangeId, Instrument, KrakenOrderBookL1)) -> Self {
+impl From<(ExchangeId, Arc<Instrument>, KrakenOrderBookL1)> for MarketIter<OrderBookL1> {
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, Arc<Instrument>, KrakenOrderBookL1),
+    ) -> Self {
         match book {
             KrakenOrderBookL1::Data(book) => Self(vec![Ok(MarketEvent {
                 exchange_time: book.spread.time,
-                received_time: Utc::now(),
+                received_time: clock::received_time(),
                 exchange: Exchange::from(exchange_id),
                 instrument,
                 kind: OrderBookL1 {
@@ -0,0 +1,645 @@
+use super::super::KrakenMessage;
+use crate::{
+    error::DataError,
+    exchange::{kraken::channel::KrakenChannel, subscription::ExchangeSub, Connector},
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{
+        crc32, BookMessageKind, InstrumentOrderBook, OrderBookUpdater, TaggedBookSync,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::{datetime_utc_from_epoch_duration, extract_next},
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// [`Kraken`](super::super::Kraken) OrderBook Level2 depth subscribed to, which is also the
+/// number of [`Level`]s per side included in each book checksum.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+pub const BOOK_L2_DEPTH_KRAKEN: u16 = 10;
+
+/// Terse type alias for an [`Kraken`](super::super::Kraken) real-time OrderBook Level2
+/// WebSocket message.
+pub type KrakenOrderBookL2 = KrakenMessage<KrakenOrderBookL2Inner>;
+
+/// [`Kraken`](super::super::Kraken) real-time OrderBook Level2 snapshot or update, and the
+/// associated [`SubscriptionId`] (eg/ "book|XBT/USD").
+///
+/// See [`KrakenMessage`](super::super::message::KrakenMessage) for full raw payload examples.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-book>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenOrderBookL2Inner {
+    pub subscription_id: SubscriptionId,
+    pub kind: BookMessageKind,
+    pub bids: Vec<KrakenLevel>,
+    pub asks: Vec<KrakenLevel>,
+    pub checksum: Option<u32>,
+}
+
+impl KrakenOrderBookL2Inner {
+    /// Most recent [`KrakenLevel`] time of the message, if it contains any [`KrakenLevel`]s.
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        self.bids
+            .iter()
+            .chain(self.asks.iter())
+            .map(|level| level.time)
+            .max()
+    }
+
+    /// [`KrakenPrecision`] of the first [`KrakenLevel`] of the message, if any.
+    pub fn precision(&self) -> Option<KrakenPrecision> {
+        self.asks
+            .first()
+            .or_else(|| self.bids.first())
+            .map(|level| level.precision)
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenOrderBookL2Inner {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// [`Kraken`](super::super::Kraken) OrderBook Level2 level.
+///
+/// The [`KrakenPrecision`] of the raw price & volume strings is retained, since it is required
+/// to generate the book checksum string.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-book>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenLevel {
+    pub price: f64,
+    pub amount: f64,
+    pub time: DateTime<Utc>,
+    pub precision: KrakenPrecision,
+}
+
+impl From<KrakenLevel> for Level {
+    fn from(level: KrakenLevel) -> Self {
+        Self::new(level.price, level.amount)
+    }
+}
+
+/// Number of decimal places of the raw [`Kraken`](super::super::Kraken) price & volume strings
+/// of an instrument (eg/ XBT/USD "5541.30000" & "2.50700000" => 5 & 8).
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct KrakenPrecision {
+    pub price: usize,
+    pub amount: usize,
+}
+
+/// [`Kraken`](super::super::Kraken) [`OrderBookUpdater`].
+///
+/// Kraken: How To Maintain A Local OrderBook
+///
+/// 1. The first message of a subscription is a snapshot ("as" & "bs") of the subscribed depth.
+/// 2. Subsequent messages are updates ("a" and/or "b") containing the absolute volume for a price
+///    level, where a volume of 0 removes the price level.
+/// 3. After applying an update, truncate each side to the subscribed depth, since price levels
+///    that fall out of scope are not explicitly removed.
+/// 4. Validate the local book against the CRC32 checksum ("c") of each update, re-initialising
+///    the book if it does not match.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct KrakenBookUpdater {
+    pub sync: TaggedBookSync,
+    pub precision: KrakenPrecision,
+}
+
+impl KrakenBookUpdater {
+    /// Generate the [`Kraken`](super::super::Kraken) CRC32 checksum of the top
+    /// [`BOOK_L2_DEPTH_KRAKEN`] asks & bids of the provided sorted [`OrderBook`].
+    pub fn checksum(&self, book: &OrderBook) -> u32 {
+        crc32(self.checksum_input(book).as_bytes())
+    }
+
+    /// Generate the string the [`Kraken`](super::super::Kraken) checksum is calculated from,
+    /// concatenating the price & volume of the top asks (ascending), then the top bids
+    /// (descending), with the decimal point and any leading zeros removed.
+    fn checksum_input(&self, book: &OrderBook) -> String {
+        let depth = usize::from(BOOK_L2_DEPTH_KRAKEN);
+        book.asks
+            .levels
+            .iter()
+            .take(depth)
+            .chain(book.bids.levels.iter().take(depth))
+            .fold(String::new(), |mut input, level| {
+                push_checksum_value(&mut input, level.price, self.precision.price);
+                push_checksum_value(&mut input, level.amount, self.precision.amount);
+                input
+            })
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for KrakenBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = KrakenOrderBookL2;
+    const WS_SNAPSHOT: bool = true;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Arc<Instrument>,
+        depth: Option<u16>,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Connector + Send,
+        Kind: Send,
+    {
+        // Kraken sends the initial OrderBook snapshot over the WebSocket, so start empty
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+            depth,
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        let update = match update {
+            KrakenOrderBookL2::Data(update) => update,
+            KrakenOrderBookL2::Event(_) => return Ok(None),
+        };
+
+        let time = update.time().unwrap_or(book.last_update_time);
+        if update.kind == BookMessageKind::Snapshot {
+            self.precision = update.precision().unwrap_or(self.precision);
+        }
+
+        self.sync
+            .apply(book, update.kind, time, update.bids, update.asks)?;
+
+        // Price levels that fall out of the subscribed depth are not explicitly removed
+        book.bids.sort();
+        book.asks.sort();
+        book.truncate(usize::from(BOOK_L2_DEPTH_KRAKEN));
+
+        if let Some(expected) = update.checksum {
+            let actual = self.checksum(book);
+            if actual != expected {
+                return Err(DataError::InvalidChecksum { expected, actual });
+            }
+        }
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+/// Append the value formatted at the provided precision to the checksum input, with the decimal
+/// point and any leading zeros removed (eg/ "0.05005" => "5005").
+fn push_checksum_value(input: &mut String, value: f64, precision: usize) {
+    let formatted = format!("{value:.precision$}").replace('.', "");
+    input.push_str(formatted.trim_start_matches('0'));
+}
+
+/// Number of decimal places of a raw [`Kraken`](super::super::Kraken) decimal string.
+fn decimal_places(value: &str) -> usize {
+    value
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+impl<'de> serde::de::Deserialize<'de> for KrakenLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenLevel struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenLevel Sequence Format:
+                // [price, volume, timestamp, (updateType)]
+                // <https://docs.kraken.com/websockets/#message-book>
+                let price = extract_next::<SeqAccessor, String>(&mut seq, "price")?;
+                let amount = extract_next::<SeqAccessor, String>(&mut seq, "volume")?;
+                let time = extract_next::<SeqAccessor, String>(&mut seq, "timestamp")?;
+
+                // Ignore any additional elements (eg/ "r" republish updateType) or SerDe will fail
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                let parse = |value: &str| {
+                    value.parse::<f64>().map_err(|_| {
+                        serde::de::Error::invalid_value(
+                            serde::de::Unexpected::Str(value),
+                            &"decimal string",
+                        )
+                    })
+                };
+
+                Ok(KrakenLevel {
+                    price: parse(&price)?,
+                    amount: parse(&amount)?,
+                    time: datetime_utc_from_epoch_duration(
+                        std::time::Duration::try_from_secs_f64(parse(&time)?)
+                            .map_err(serde::de::Error::custom)?,
+                    ),
+                    precision: KrakenPrecision {
+                        price: decimal_places(&price),
+                        amount: decimal_places(&amount),
+                    },
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialize the KrakenLevel
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// Element of a [`KrakenOrderBookL2Inner`] sequence following the deprecated channelID.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KrakenBookElement {
+    Levels(KrakenBookLevels),
+    Name(String),
+}
+
+/// Snapshot or update [`KrakenLevel`]s of a [`KrakenOrderBookL2Inner`] sequence. Updates may
+/// split the asks & bids across two of these objects.
+#[derive(Default, Deserialize)]
+struct KrakenBookLevels {
+    #[serde(rename = "bs")]
+    snapshot_bids: Option<Vec<KrakenLevel>>,
+    #[serde(rename = "as")]
+    snapshot_asks: Option<Vec<KrakenLevel>>,
+    #[serde(rename = "b")]
+    bids: Option<Vec<KrakenLevel>>,
+    #[serde(rename = "a")]
+    asks: Option<Vec<KrakenLevel>>,
+    #[serde(rename = "c")]
+    checksum: Option<String>,
+}
+
+impl<'de> serde::de::Deserialize<'de> for KrakenOrderBookL2Inner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenOrderBookL2Inner;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenOrderBookL2Inner struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenOrderBookL2Inner Sequence Formats:
+                // Snapshot: [channelID, {"as": [..], "bs": [..]}, channelName, pair]
+                // Update:   [channelID, {"a": [..]}, {"b": [..], "c": checksum}, channelName, pair]
+                // <https://docs.kraken.com/websockets/#message-book>
+
+                // Extract deprecated channelID & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "channelID")?;
+
+                // Extract level objects, followed by channelName (eg/ "book-10") & pair
+                let mut kind = BookMessageKind::Update;
+                let (mut bids, mut asks, mut checksum) = (Vec::new(), Vec::new(), None);
+                let mut names = Vec::with_capacity(2);
+                while let Some(element) = seq.next_element::<KrakenBookElement>()? {
+                    match element {
+                        KrakenBookElement::Levels(levels) => {
+                            if levels.snapshot_bids.is_some() || levels.snapshot_asks.is_some() {
+                                kind = BookMessageKind::Snapshot;
+                            }
+                            bids.extend(levels.snapshot_bids.into_iter().flatten());
+                            bids.extend(levels.bids.into_iter().flatten());
+                            asks.extend(levels.snapshot_asks.into_iter().flatten());
+                            asks.extend(levels.asks.into_iter().flatten());
+                            checksum = levels.checksum.or(checksum);
+                        }
+                        KrakenBookElement::Name(name) => names.push(name),
+                    }
+                }
+
+                // Map pair (eg/ "XBT/USD") to SubscriptionId (ie/ "book|{pair}"), ignoring the
+                // depth specific channelName
+                let subscription_id = names
+                    .into_iter()
+                    .nth(1)
+                    .map(|market| ExchangeSub::from((KrakenChannel::ORDER_BOOK_L2, market)).id())
+                    .ok_or_else(|| serde::de::Error::missing_field("pair"))?;
+
+                let checksum = checksum
+                    .map(|checksum| {
+                        checksum.parse::<u32>().map_err(|_| {
+                            serde::de::Error::invalid_value(
+                                serde::de::Unexpected::Str(&checksum),
+                                &"u32 checksum string",
+                            )
+                        })
+                    })
+                    .transpose()?;
+
+                Ok(KrakenOrderBookL2Inner {
+                    subscription_id,
+                    kind,
+                    bids,
+                    asks,
+                    checksum,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialize the KrakenOrderBookL2Inner
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn level(price: f64, amount: f64, time: f64) -> KrakenLevel {
+        KrakenLevel {
+            price,
+            amount,
+            time: datetime_utc_from_epoch_duration(Duration::from_secs_f64(time)),
+            precision: KrakenPrecision {
+                price: 5,
+                amount: 8,
+            },
+        }
+    }
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_kraken_message_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenOrderBookL2, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    [
+                        0,
+                        {
+                            "as": [
+                                ["5541.30000", "2.50700000", "1534614248.123678"],
+                                ["5541.80000", "0.33000000", "1534614098.345543"]
+                            ],
+                            "bs": [
+                                ["5541.20000", "1.52900000", "1534614248.765567"]
+                            ]
+                        },
+                        "book-10",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Ok(KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                        subscription_id: SubscriptionId::from("book|XBT/USD"),
+                        kind: BookMessageKind::Snapshot,
+                        bids: vec![level(5541.2, 1.529, 1534614248.765567)],
+                        asks: vec![
+                            level(5541.3, 2.507, 1534614248.123678),
+                            level(5541.8, 0.33, 1534614098.345543),
+                        ],
+                        checksum: None,
+                    })),
+                },
+                TestCase {
+                    // TC1: valid update w/ asks & bids split across objects, & republish flag
+                    input: r#"
+                    [
+                        1234,
+                        {
+                            "a": [
+                                ["5541.30000", "2.50700000", "1534614248.456738", "r"]
+                            ]
+                        },
+                        {
+                            "b": [
+                                ["5541.20000", "0.00000000", "1534614248.456781"]
+                            ],
+                            "c": "974942666"
+                        },
+                        "book-10",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Ok(KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                        subscription_id: SubscriptionId::from("book|XBT/USD"),
+                        kind: BookMessageKind::Update,
+                        bids: vec![level(5541.2, 0.0, 1534614248.456781)],
+                        asks: vec![level(5541.3, 2.507, 1534614248.456738)],
+                        checksum: Some(974942666),
+                    })),
+                },
+                TestCase {
+                    // TC2: invalid update w/ missing pair
+                    input: r#"[1234, {"a": [], "c": "974942666"}, "book-10"]"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC3: invalid update w/ negative level timestamp
+                    input: r#"
+                    [
+                        1234,
+                        {
+                            "a": [
+                                ["5541.30000", "2.50700000", "-1"]
+                            ],
+                            "c": "974942666"
+                        },
+                        "book-10",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenOrderBookL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_checksum_input() {
+        let updater = KrakenBookUpdater {
+            sync: TaggedBookSync::default(),
+            precision: KrakenPrecision {
+                price: 5,
+                amount: 8,
+            },
+        };
+
+        let book = OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, vec![Level::new(0.05005, 0.000005)]),
+            asks: OrderBookSide::new(Side::Sell, vec![Level::new(5541.3, 2.507)]),
+        };
+
+        assert_eq!(updater.checksum_input(&book), "5541300002507000005005500");
+    }
+
+    #[test]
+    fn test_update_kraken_order_book_l2() {
+        let message = |input: &str| serde_json::from_str::<KrakenOrderBookL2>(input).unwrap();
+        let snapshot = r#"[0, {"as": [["5541.30000", "2.50700000", "1534614248.1"]], "bs": [["5541.20000", "1.52900000", "1534614248.2"]]}, "book-10", "XBT/USD"]"#;
+
+        let mut updater = KrakenBookUpdater::default();
+        let mut book = OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+            asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+        };
+
+        // TC0: update received before any snapshot is a BookDesync
+        let update =
+            r#"[0, {"a": [["5541.40000", "1.00000000", "1534614248.3"]]}, "book-10", "XBT/USD"]"#;
+        assert!(
+            matches!(
+                updater.update(&mut book, message(update)),
+                Err(DataError::BookDesync)
+            ),
+            "TC0 failed"
+        );
+
+        // TC1: snapshot resets the OrderBook & records the precision
+        let actual = updater
+            .update(&mut book, message(snapshot))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            actual.asks.levels,
+            vec![Level::new(5541.3, 2.507)],
+            "TC1 failed"
+        );
+        assert_eq!(
+            actual.bids.levels,
+            vec![Level::new(5541.2, 1.529)],
+            "TC1 failed"
+        );
+        assert_eq!(
+            updater.precision,
+            KrakenPrecision {
+                price: 5,
+                amount: 8
+            },
+            "TC1 failed"
+        );
+
+        // TC2: update w/ a valid checksum is applied
+        let mut expected = OrderBook {
+            last_update_time: book.last_update_time,
+            bids: OrderBookSide::new(Side::Buy, vec![Level::new(5541.2, 1.529)]),
+            asks: OrderBookSide::new(
+                Side::Sell,
+                vec![Level::new(5541.3, 2.507), Level::new(5541.4, 1.0)],
+            ),
+        };
+        let checksum = updater.checksum(&expected);
+        let update = format!(
+            r#"[0, {{"a": [["5541.40000", "1.00000000", "1534614248.3"]], "c": "{checksum}"}}, "book-10", "XBT/USD"]"#
+        );
+        let actual = updater
+            .update(&mut book, message(&update))
+            .unwrap()
+            .unwrap();
+        expected.last_update_time = actual.last_update_time;
+        assert_eq!(actual, expected, "TC2 failed");
+
+        // TC3: update w/ an invalid checksum is terminal
+        let update = format!(
+            r#"[0, {{"b": [["5541.10000", "1.00000000", "1534614248.4"]], "c": "{checksum}"}}, "book-10", "XBT/USD"]"#
+        );
+        let actual = updater.update(&mut book, message(&update));
+        assert!(
+            matches!(actual, Err(DataError::InvalidChecksum { expected, .. }) if expected == checksum),
+            "TC3 failed"
+        );
+
+        // TC4: levels that fall out of the subscribed depth are removed
+        let mut updater = KrakenBookUpdater::default();
+        updater.update(&mut book, message(snapshot)).unwrap();
+        let asks = (1..=11)
+            .map(|index| {
+                format!(
+                    r#"["{}.00000", "1.00000000", "1534614248.5"]"#,
+                    5541 + index
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let update = format!(r#"[0, {{"a": [{asks}]}}, "book-10", "XBT/USD"]"#);
+        let actual = updater
+            .update(&mut book, message(&update))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            actual.asks.levels.len(),
+            usize::from(BOOK_L2_DEPTH_KRAKEN),
+            "TC4 failed"
+        );
+        assert_eq!(actual.asks.levels[9], Level::new(5550.0, 1.0), "TC4 failed");
+    }
+}
@@ -1,2 +1,5 @@
 /// Level 1 OrderBook types (top of book).
 pub mod l1;
+
+/// Level 2 OrderBook types (top 10 levels, validated by checksum).
+pub mod l2;
@@ -1,5 +1,10 @@
 use super::Kraken;
-use crate::{subscription::Subscription, Identifier};
+use crate::{
+    exchange::market::{from_xbt, split_separated, to_xbt, MarketId},
+    subscription::Subscription,
+    Identifier,
+};
+use barter_integration::model::instrument::{symbol::Symbol, Instrument};
 use serde::{Deserialize, Serialize};
 
 /// Type that defines how to translate a Barter [`Subscription`] into a
@@ -9,9 +14,21 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct KrakenMarket(pub String);
 
+impl MarketId for Kraken {
+    fn market_id(instrument: &Instrument) -> String {
+        // Notes:
+        // - Kraken uses the XBT alias for BTC, both when subscribing & in every message sent.
+        format!("{}/{}", to_xbt(&instrument.base), to_xbt(&instrument.quote)).to_uppercase()
+    }
+
+    fn parse_market_id(market: &str) -> Option<(Symbol, Symbol)> {
+        split_separated(market, '/').map(|(base, quote)| (from_xbt(base), from_xbt(quote)))
+    }
+}
+
 impl<Kind> Identifier<KrakenMarket> for Subscription<Kraken, Kind> {
     fn id(&self) -> KrakenMarket {
-        KrakenMarket(format!("{}/{}", self.instrument.base, self.instrument.quote).to_uppercase())
+        KrakenMarket(Kraken::market_id(&self.instrument))
     }
 }
 
@@ -25,6 +25,18 @@ use serde::{Deserialize, Serialize};
 /// ]
 /// ```
 ///
+/// #### OrderBookL2 Update
+/// See docs: <https://docs.kraken.com/websockets/#message-book>
+/// ```json
+/// [
+///     1234,
+///     {"a": [["5541.30000", "2.50700000", "1534614248.456738"]]},
+///     {"b": [["5541.20000", "0.00000000", "1534614248.456781"]], "c": "974942666"},
+///     "book-10",
+///     "XBT/USD"
+/// ]
+/// ```
+///
 /// #### Trades
 /// See docs: <https://docs.kraken.com/websockets/#message-trade>
 /// ```json
@@ -61,6 +73,19 @@ use serde::{Deserialize, Serialize};
 /// }
 /// ```
 ///
+/// #### Subscription Status
+/// See docs: <https://docs.kraken.com/websockets/#message-subscriptionStatus>
+/// ```json
+/// {
+///   "channelID": 10001,
+///   "channelName": "book-10",
+///   "event": "subscriptionStatus",
+///   "pair": "XBT/USD",
+///   "status": "unsubscribed",
+///   "subscription": {"depth": 10, "name": "book"}
+/// }
+/// ```
+///
 /// #### KrakenError Generic
 /// See docs: <https://docs.kraken.com/websockets/#errortypes>
 /// ```json
@@ -91,7 +116,8 @@ where
 /// [`Kraken`](super::Kraken) messages received over the WebSocket which are not subscription data.
 ///
 /// eg/ [`Kraken`](super::Kraken) sends a [`KrakenEvent::Heartbeat`] if no subscription traffic
-/// has been sent within the last second.
+/// has been sent within the last second, and a [`KrakenEvent::SubscriptionStatus`] once a
+/// subscription is (un)subscribed over an open connection.
 ///
 /// See [`KrakenMessage`] for full raw payload examples.
 ///
@@ -100,6 +126,7 @@ where
 #[serde(tag = "event", rename_all = "camelCase")]
 pub enum KrakenEvent {
     Heartbeat,
+    SubscriptionStatus,
     Error(KrakenError),
 }
 
@@ -147,6 +174,11 @@ mod tests {
                         message: "Malformed request".to_string(),
                     }))),
                 },
+                TestCase {
+                    // TC2: valid KrakenTrades::Event(KrakenEvent::SubscriptionStatus)
+                    input: r#"{"channelID": 10001, "channelName": "book-10", "event": "subscriptionStatus", "pair": "XBT/USD", "status": "unsubscribed", "subscription": {"depth": 10, "name": "book"}}"#,
+                    expected: Ok(KrakenMessage::Event(KrakenEvent::SubscriptionStatus)),
+                },
             ];
 
             for (index, test) in tests.into_iter().enumerate() {
@@ -1,17 +1,28 @@
 use self::{
-    book::l1::KrakenOrderBookL1, channel::KrakenChannel, market::KrakenMarket,
-    message::KrakenMessage, subscription::KrakenSubResponse, trade::KrakenTrades,
+    book::{
+        l1::KrakenOrderBookL1,
+        l2::{KrakenBookUpdater, BOOK_L2_DEPTH_KRAKEN},
+    },
+    channel::KrakenChannel,
+    market::KrakenMarket,
+    message::KrakenMessage,
+    subscription::KrakenSubResponse,
+    trade::KrakenTrades,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades},
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+    },
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
+use std::time::Duration;
 use url::Url;
 
 /// Order book types for [`Kraken`]
@@ -40,6 +51,14 @@ pub mod trade;
 /// See docs: <https://docs.kraken.com/websockets/#overview>
 pub const BASE_URL_KRAKEN: &str = "wss://ws.kraken.com/";
 
+/// [`Duration`] after which an idle [`Kraken`] connection is considered dead.
+///
+/// Kraken sends a heartbeat every second when no other messages are sent, so a connection that
+/// is silent for this long has been lost.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-heartbeat>
+pub const IDLE_TIMEOUT_KRAKEN: Duration = Duration::from_secs(30);
+
 /// [`Kraken`] exchange.
 ///
 /// See docs: <https://docs.kraken.com/websockets/#overview>
@@ -60,25 +79,53 @@ impl Connector for Kraken {
         Url::parse(BASE_URL_KRAKEN).map_err(SocketError::UrlParse)
     }
 
+    fn idle_timeout() -> Option<Duration> {
+        Some(IDLE_TIMEOUT_KRAKEN)
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        exchange_subs
-            .into_iter()
-            .map(|ExchangeSub { channel, market }| {
-                WsMessage::Text(
-                    json!({
-                        "event": "subscribe",
-                        "pair": [market.as_ref()],
-                        "subscription": {
-                            "name": channel.as_ref()
-                        }
-                    })
-                    .to_string(),
-                )
-            })
-            .collect()
+        subscription_requests("subscribe", exchange_subs)
+    }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        subscription_requests("unsubscribe", exchange_subs)
     }
 }
 
+/// Generate a [`Kraken`] subscription `event` (eg/ "subscribe" or "unsubscribe") request for each
+/// [`ExchangeSub`].
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-subscribe>
+fn subscription_requests(
+    event: &str,
+    exchange_subs: Vec<ExchangeSub<KrakenChannel, KrakenMarket>>,
+) -> Vec<WsMessage> {
+    exchange_subs
+        .into_iter()
+        .map(|ExchangeSub { channel, market }| {
+            // OrderBook Level2 subscriptions must specify the depth to subscribe to
+            let subscription = match channel {
+                KrakenChannel::ORDER_BOOK_L2 => json!({
+                    "name": channel.as_ref(),
+                    "depth": BOOK_L2_DEPTH_KRAKEN
+                }),
+                _ => json!({ "name": channel.as_ref() }),
+            };
+
+            WsMessage::Text(
+                json!({
+                    "event": event,
+                    "pair": [market.as_ref()],
+                    "subscription": subscription
+                })
+                .to_string(),
+            )
+        })
+        .collect()
+}
+
 impl StreamSelector<PublicTrades> for Kraken {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, KrakenTrades>>;
 }
@@ -86,3 +133,7 @@ impl StreamSelector<PublicTrades> for Kraken {
 impl StreamSelector<OrderBooksL1> for Kraken {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, KrakenOrderBookL1>>;
 }
+
+impl StreamSelector<OrderBooksL2> for Kraken {
+    type Stream = ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, KrakenBookUpdater>>;
+}
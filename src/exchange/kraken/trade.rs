@@ -1,8 +1,9 @@
 use super::KrakenMessage;
 use crate::{
+    clock,
     event::{MarketEvent, MarketIter},
     exchange::ExchangeId,
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, TradeFields},
     Identifier,
 };
 use barter_integration::{
@@ -11,6 +12,7 @@ use barter_integration::{
 };
 use chrono::{DateTime, Utc};
 use serde::Serialize;
+use std::sync::Arc;
 
 /// Terse type alias for an [`Kraken`](super::Kraken) real-time trades WebSocket message.
 pub type KrakenTrades = KrakenMessage<KrakenTradesInner>;
@@ -51,15 +53,18 @@ impl Identifier<Option<SubscriptionId>> for KrakenTradesInner {
 fn custom_kraken_trade_id(trade: &KrakenTrade) -> String {
     format!(
         "{}_{}_{}_{}",
-        trade.time.timestamp_nanos(),
+        trade.time.timestamp_nanos_opt().unwrap_or_default(),
         trade.side,
         trade.price,
         trade.amount
     )
 }
 
-impl From<(ExchangeId, Instrument, KrakenTrades)> for MarketIter<PublicTrade> {
-    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, KrakenTrades)) -> Self {
+impl From<(ExchangeId, Arc<Instrument>, KrakenTrades)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument, trades): (ExchangeId, Arc<Instrument>, KrakenTrades),
+    ) -> Self {
+        let fields = TradeFields::current();
         match trades {
             KrakenTrades::Data(trades) => trades
                 .trades
@@ -67,14 +72,15 @@ impl From<(ExchangeId, Instrument, KrakenTrades)> for MarketIter<PublicTrade> {
                 .map(|trade| {
                     Ok(MarketEvent {
                         exchange_time: trade.time,
-                        received_time: Utc::now(),
+                        received_time: clock::received_time(),
                         exchange: Exchange::from(exchange_id),
                         instrument: instrument.clone(),
                         kind: PublicTrade {
-                            id: custom_kraken_trade_id(&trade),
+                            id: fields.id(|| custom_kraken_trade_id(&trade)),
                             price: trade.price,
                             amount: trade.amount,
                             side: trade.side,
+                            conditions: vec![],
                         },
                     })
                 })
@@ -288,4 +294,59 @@ mod tests {
             }
         }
     }
+
+    mod transform {
+        use super::*;
+        use barter_integration::model::instrument::kind::InstrumentKind;
+
+        #[test]
+        fn test_kraken_trades_array_yields_market_event_per_trade() {
+            let input = r#"
+                [
+                    0,
+                    [
+                        ["5541.20000", "0.15850568", "1534614057.321597", "s", "l", ""],
+                        ["6060.00000", "0.02455000", "1534614057.324998", "b", "l", ""],
+                        ["6060.00000", "0.01000000", "1534614057.324998", "b", "m", ""]
+                    ],
+                    "trade",
+                    "XBT/USD"
+                ]
+            "#;
+
+            let trades = serde_json::from_str::<KrakenTrades>(input).unwrap();
+            let instrument = Arc::new(Instrument::from(("btc", "usd", InstrumentKind::Spot)));
+
+            let actual = MarketIter::<PublicTrade>::from((ExchangeId::Kraken, instrument, trades))
+                .0
+                .into_iter()
+                .map(|event| event.unwrap())
+                .collect::<Vec<_>>();
+
+            let time = |secs: f64| {
+                datetime_utc_from_epoch_duration(std::time::Duration::from_secs_f64(secs))
+            };
+            let expected = vec![
+                (time(1534614057.321597), 5541.2, 0.15850568, Side::Sell),
+                (time(1534614057.324998), 6060.0, 0.02455, Side::Buy),
+                (time(1534614057.324998), 6060.0, 0.01, Side::Buy),
+            ];
+
+            assert_eq!(actual.len(), expected.len());
+            for (index, (actual, expected)) in actual.iter().zip(expected).enumerate() {
+                let (exchange_time, price, amount, side) = expected;
+                assert_eq!(actual.exchange_time, exchange_time, "TC{index} failed");
+                assert_eq!(actual.kind.price, price, "TC{index} failed");
+                assert_eq!(actual.kind.amount, amount, "TC{index} failed");
+                assert_eq!(actual.kind.side, side, "TC{index} failed");
+            }
+
+            // Every trade keeps an individual identifier, even those sharing a time & side
+            let ids = actual
+                .iter()
+                .map(|event| event.kind.id.as_str())
+                .collect::<std::collections::HashSet<_>>();
+            assert_eq!(ids.len(), actual.len());
+        }
+    }
 }
@@ -0,0 +1,196 @@
+use barter_integration::model::instrument::{symbol::Symbol, Instrument};
+
+/// Quote assets recognised when splitting the market of an exchange that does not separate the
+/// base & quote (eg/ "BTCUSDT"). See [`split_known_quote`].
+pub const KNOWN_QUOTES: &[&str] = &[
+    "USDT", "USDC", "BUSD", "FDUSD", "TUSD", "DAI", "USD", "EUR", "GBP", "TRY", "BTC", "XBT",
+    "ETH", "BNB",
+];
+
+/// Defines how an exchange formats an [`Instrument`] into the market string it subscribes with
+/// and sends in inbound frames, and how to parse such a market string back again.
+///
+/// Each exchange has its own quirks (eg/ Binance "BTCUSDT", Coinbase "BTC-USD", Kraken
+/// "XBT/USD"), so the exchange [`Connector::Market`](super::Connector::Market) of every
+/// [`Subscription`](crate::subscription::Subscription) is derived from [`Self::market_id`].
+pub trait MarketId {
+    /// Format the provided [`Instrument`] as the exchange market string.
+    fn market_id(instrument: &Instrument) -> String;
+
+    /// Parse an exchange market string back into the base & quote [`Symbol`]s of the
+    /// [`Instrument`], reversing any symbol aliasing applied by [`Self::market_id`].
+    ///
+    /// The [`InstrumentKind`](barter_integration::model::instrument::kind::InstrumentKind) is
+    /// not consistently encoded by exchanges, so it is not recovered.
+    fn parse_market_id(market: &str) -> Option<(Symbol, Symbol)>;
+}
+
+/// Split a market string without a base & quote separator (eg/ "BTCUSDT") using the longest
+/// matching [`KNOWN_QUOTES`] suffix that leaves a base of at least three characters.
+///
+/// eg/ "XBTUSD" is split into "XBT" & "USD" rather than "XB" & "TUSD".
+pub fn split_known_quote(market: &str) -> Option<(&str, &str)> {
+    KNOWN_QUOTES
+        .iter()
+        .filter(|quote| market.len() > quote.len() && market.ends_with(*quote))
+        .max_by_key(|quote| (market.len() - quote.len() >= 3, quote.len()))
+        .map(|quote| market.split_at(market.len() - quote.len()))
+}
+
+/// Split a market string on the provided separator, returning the first two parts (eg/
+/// "BTC-USDT-SWAP" => ("BTC", "USDT")).
+pub fn split_separated(market: &str, separator: char) -> Option<(&str, &str)> {
+    let mut parts = market.split(separator);
+    match (parts.next(), parts.next()) {
+        (Some(base), Some(quote)) if !base.is_empty() && !quote.is_empty() => Some((base, quote)),
+        _ => None,
+    }
+}
+
+/// Map a Barter [`Symbol`] to the "XBT" alias used for Bitcoin by some exchanges (eg/ Kraken &
+/// Bitmex), leaving every other symbol untouched.
+pub fn to_xbt(symbol: &Symbol) -> String {
+    match symbol.as_ref() {
+        "btc" => "xbt".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Map an exchange "XBT" alias back to the Barter "btc" [`Symbol`].
+pub fn from_xbt(symbol: &str) -> Symbol {
+    match Symbol::new(symbol) {
+        symbol if symbol.as_ref() == "xbt" => Symbol::new("btc"),
+        symbol => symbol,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::{
+        binance::Binance, bitfinex::Bitfinex, bitmex::Bitmex, bybit::Bybit, coinbase::Coinbase,
+        gateio::Gateio, kraken::Kraken, okx::Okx,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    /// Format the [`Instrument`] with the exchange [`MarketId`], and parse it back again.
+    fn round_trip<Exchange>(instrument: &Instrument) -> (String, Option<(Symbol, Symbol)>)
+    where
+        Exchange: MarketId,
+    {
+        let market = Exchange::market_id(instrument);
+        let parsed = Exchange::parse_market_id(&market);
+        (market, parsed)
+    }
+
+    #[test]
+    fn test_market_id() {
+        struct TestCase {
+            actual: (String, Option<(Symbol, Symbol)>),
+            expected_market: &'static str,
+            expected_parsed: Option<(&'static str, &'static str)>,
+        }
+
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let btc_usd = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+        let eth_btc = Instrument::from(("eth", "btc", InstrumentKind::Spot));
+        let btc_usdt_perp = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+
+        let tests = vec![
+            TestCase {
+                // TC0: Binance is uppercase w/o a separator
+                actual: round_trip::<Binance<()>>(&btc_usdt),
+                expected_market: "BTCUSDT",
+                expected_parsed: Some(("btc", "usdt")),
+            },
+            TestCase {
+                // TC1: Binance market w/ a crypto quote is split on the longest known quote
+                actual: round_trip::<Binance<()>>(&eth_btc),
+                expected_market: "ETHBTC",
+                expected_parsed: Some(("eth", "btc")),
+            },
+            TestCase {
+                // TC2: Bybit is uppercase w/o a separator
+                actual: round_trip::<Bybit<()>>(&btc_usdt),
+                expected_market: "BTCUSDT",
+                expected_parsed: Some(("btc", "usdt")),
+            },
+            TestCase {
+                // TC3: Bitfinex is uppercase w/ a "t" trading pair prefix
+                actual: round_trip::<Bitfinex>(&btc_usd),
+                expected_market: "tBTCUSD",
+                expected_parsed: Some(("btc", "usd")),
+            },
+            TestCase {
+                // TC4: Coinbase is uppercase w/ a dash separator
+                actual: round_trip::<Coinbase>(&btc_usd),
+                expected_market: "BTC-USD",
+                expected_parsed: Some(("btc", "usd")),
+            },
+            TestCase {
+                // TC5: Gateio is uppercase w/ an underscore separator
+                actual: round_trip::<Gateio<()>>(&btc_usdt),
+                expected_market: "BTC_USDT",
+                expected_parsed: Some(("btc", "usdt")),
+            },
+            TestCase {
+                // TC6: Okx perpetual is suffixed w/ "SWAP"
+                actual: round_trip::<Okx>(&btc_usdt_perp),
+                expected_market: "BTC-USDT-SWAP",
+                expected_parsed: Some(("btc", "usdt")),
+            },
+            TestCase {
+                // TC7: Kraken uses the XBT alias for BTC w/ a slash separator
+                actual: round_trip::<Kraken>(&btc_usd),
+                expected_market: "XBT/USD",
+                expected_parsed: Some(("btc", "usd")),
+            },
+            TestCase {
+                // TC8: Kraken leaves every other symbol untouched
+                actual: round_trip::<Kraken>(&Instrument::from((
+                    "eth",
+                    "usd",
+                    InstrumentKind::Spot,
+                ))),
+                expected_market: "ETH/USD",
+                expected_parsed: Some(("eth", "usd")),
+            },
+            TestCase {
+                // TC9: Bitmex uses the XBT alias for BTC w/o a separator
+                actual: round_trip::<Bitmex>(&Instrument::from((
+                    "btc",
+                    "usd",
+                    InstrumentKind::Perpetual,
+                ))),
+                expected_market: "XBTUSD",
+                expected_parsed: Some(("btc", "usd")),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (market, parsed) = test.actual;
+            assert_eq!(market, test.expected_market, "TC{index} failed");
+            assert_eq!(
+                parsed,
+                test.expected_parsed
+                    .map(|(base, quote)| (Symbol::new(base), Symbol::new(quote))),
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_market_id_edge_cases() {
+        // Unknown quote assets & missing separators cannot be split
+        assert_eq!(Binance::<()>::parse_market_id("BTCXYZ"), None);
+        assert_eq!(Binance::<()>::parse_market_id("USDT"), None);
+        assert_eq!(Coinbase::parse_market_id("BTCUSD"), None);
+        assert_eq!(Kraken::parse_market_id("XBT/"), None);
+
+        // Short bases are still split when no other known quote matches
+        assert_eq!(
+            Binance::<()>::parse_market_id("OPUSDT"),
+            Some((Symbol::new("op"), Symbol::new("usdt")))
+        );
+    }
+}
@@ -1,22 +1,39 @@
-use self::subscription::ExchangeSub;
+use self::{
+    rate_limit::OutboundRateLimit,
+    subscription::{ExchangeSub, ResumableSub},
+};
 use crate::{
-    subscriber::{validator::SubscriptionValidator, Subscriber},
-    subscription::{Map, SubKind},
+    error::SubscriptionError,
+    subscriber::{handshake::Handshake, validator::SubscriptionValidator, Subscriber},
+    subscription::{
+        candle::Interval, funding::FundingSchedule, trade::QuantityUnit, Map, SubKind, SubKindId,
+    },
     MarketStream,
 };
 use barter_integration::{
     error::SocketError,
-    model::instrument::{kind::InstrumentKind, Instrument},
+    model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        SubscriptionId,
+    },
     protocol::websocket::WsMessage,
     Validator,
 };
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{
     fmt::{Debug, Display},
+    future::Future,
+    str::FromStr,
+    sync::Arc,
     time::Duration,
 };
 use url::Url;
 
+tokio::task_local! {
+    /// Per-connection base [`Url`] override of the current task, see [`with_base_url`].
+    static BASE_URL: Option<Url>;
+}
+
 /// `BinanceSpot` & `BinanceFuturesUsd` [`Connector`] and [`StreamSelector`] implementations.
 pub mod binance;
 
@@ -39,9 +56,18 @@ pub mod gateio;
 /// `Kraken` [`Connector`] and [`StreamSelector`] implementations.
 pub mod kraken;
 
+/// [`MarketId`](market::MarketId) trait defining how each exchange formats an
+/// [`Instrument`] into its market string (and back), handling exchange quirks such as the
+/// Kraken "XBT" alias.
+pub mod market;
+
 /// `Okx` [`Connector`] and [`StreamSelector`] implementations.
 pub mod okx;
 
+/// [`OutboundRateLimit`] & token bucket [`RateLimiter`](rate_limit::RateLimiter) used to pace
+/// client→server messages sent over an exchange connection.
+pub mod rate_limit;
+
 /// Defines the generic [`ExchangeSub`] containing a market and channel combination used by an
 /// exchange [`Connector`] to build [`WsMessage`] subscription payloads.
 pub mod subscription;
@@ -54,16 +80,36 @@ pub const DEFAULT_SUBSCRIPTION_TIMEOUT: Duration = Duration::from_secs(10);
 /// [`Subscription`](crate::subscription::Subscription) [`SubKind`](crate::subscription::SubKind).
 ///
 /// ### Notes
-/// Must be implemented by an exchange [`Connector`] if it supports a specific
-/// [`SubKind`](crate::subscription::SubKind).
+/// - Must be implemented by an exchange [`Connector`] if it supports a specific
+///   [`SubKind`](crate::subscription::SubKind).
+/// - [`Self::Stream`] should be a concrete type (eg/ an
+///   [`ExchangeWsStream`](crate::ExchangeWsStream)) rather than a boxed
+///   [`Stream`](futures::Stream). See [`SelectedStream`].
 pub trait StreamSelector<Kind>
 where
     Self: Connector,
     Kind: SubKind,
 {
     type Stream: MarketStream<Self, Kind>;
+
+    /// Exchange sequence of the provided [`SubKind::Event`](crate::subscription::SubKind::Event),
+    /// used to resume the exchange stream from the last-seen event after a brief disconnect (see
+    /// [`ResumeFrom`](crate::subscription::resume::ResumeFrom)).
+    ///
+    /// Defaults to `None`, meaning the exchange does not sequence the events of this
+    /// [`SubKind`](crate::subscription::SubKind).
+    fn sequence(_: &Kind::Event) -> Option<u64> {
+        None
+    }
 }
 
+/// Concrete [`MarketStream`] type selected by an exchange [`StreamSelector`] for a
+/// [`SubKind`] (eg/ `SelectedStream<BinanceSpot, PublicTrades>`).
+///
+/// Monomorphised consumers can name this type directly, avoiding the allocation & dynamic
+/// dispatch of a boxed [`Stream`](futures::Stream).
+pub type SelectedStream<Exchange, Kind> = <Exchange as StreamSelector<Kind>>::Stream;
+
 /// Primary exchange abstraction. Defines how to translate Barter types into exchange specific
 /// types, as well as connecting, subscribing, and interacting with the exchange server.
 ///
@@ -91,7 +137,7 @@ where
     ///
     /// ### Examples
     /// - [`BinanceMarket("btcusdt")`](binance::market::BinanceMarket)
-    /// - [`KrakenMarket("BTC/USDT")`](kraken::market::KrakenMarket)
+    /// - [`KrakenMarket("XBT/USDT")`](kraken::market::KrakenMarket)
     type Market: AsRef<str>;
 
     /// [`Subscriber`] type that establishes a connection with the exchange server, and actions
@@ -110,7 +156,10 @@ where
     /// communicates a successful [`Subscription`](crate::subscription::Subscription) outcome.
     type SubResponse: Validator + Debug + DeserializeOwned;
 
-    /// Base [`Url`] of the exchange server being connected with.
+    /// Base [`Url`] of the exchange server being connected with, unless overridden via
+    /// [`with_base_url`].
+    // SocketError is defined upstream in barter-integration, so cannot be boxed here
+    #[allow(clippy::result_large_err)]
     fn url() -> Result<Url, SocketError>;
 
     /// Defines [`PingInterval`] of custom application-level
@@ -122,22 +171,159 @@ where
         None
     }
 
+    /// Defines the [`Duration`] after which a connection that has not received any message
+    /// (data, ping or pong) is considered dead, and is re-initialised (see
+    /// [`IdleTimeout`](crate::protocol::IdleTimeout)).
+    ///
+    /// Defaults to `None`, meaning that idle connections are never timed out.
+    fn idle_timeout() -> Option<Duration> {
+        None
+    }
+
+    /// Defines the [`OutboundRateLimit`] of client→server messages (eg/ subscriptions, pings &
+    /// pongs) documented by the exchange server being connected with.
+    ///
+    /// Defaults to `None`, meaning that outbound messages are not paced.
+    fn outbound_rate_limit() -> Option<OutboundRateLimit> {
+        None
+    }
+
+    /// Defines the [`Handshake`] state machine performed after connecting to the exchange
+    /// server, but before sending the subscription [`Self::requests`] (eg/ a login, or
+    /// configuration flags that must be acknowledged).
+    ///
+    /// Defaults to `None`, meaning the subscription [`Self::requests`] are sent immediately.
+    fn handshake() -> Option<Box<dyn Handshake>> {
+        None
+    }
+
     /// Defines how to translate a collection of [`ExchangeSub`]s into the [`WsMessage`]
     /// subscription payloads sent to the exchange server.
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage>;
 
+    /// Defines how to translate a collection of [`ExchangeSub`]s, and the last-seen sequence of
+    /// each, into the [`WsMessage`] payloads sent to the exchange server to resume the
+    /// subscriptions after a brief disconnect.
+    ///
+    /// Defaults to the standard [`Self::requests`], meaning the exchange does not support
+    /// resuming and any snapshots are re-fetched. Exchanges that support resuming from a
+    /// sequence opt in by overriding this method.
+    fn resume_requests(
+        exchange_subs: Vec<ResumableSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        Self::requests(
+            exchange_subs
+                .into_iter()
+                .map(|(exchange_sub, _)| exchange_sub)
+                .collect(),
+        )
+    }
+
+    /// Defines how to translate a collection of [`ExchangeSub`]s into the [`WsMessage`]
+    /// unsubscribe payloads sent to the exchange server to stop streaming them over an open
+    /// connection (see [`LiveSubscriptions`](crate::streams::live::LiveSubscriptions)).
+    ///
+    /// Defaults to no payloads, meaning the exchange does not support unsubscribing. Exchanges
+    /// that do opt in by overriding this method.
+    fn unsubscribe_requests(_: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        vec![]
+    }
+
+    /// Maximum number of [`ExchangeSub`]s the exchange server accepts in a single subscription
+    /// [`WsMessage`]. Larger batches are chunked into multiple [`Self::requests`] by the
+    /// [`SubscriptionMapper`](crate::subscriber::mapper::SubscriptionMapper), each paced by any
+    /// [`Self::outbound_rate_limit`].
+    ///
+    /// Defaults to `None`, meaning every [`ExchangeSub`] is sent in a single batch.
+    fn max_subscriptions_per_message() -> Option<usize> {
+        None
+    }
+
     /// Number of [`Subscription`](crate::subscription::Subscription) responses expected from the
     /// exchange server in responses to the requests send. Used to validate all
     /// [`Subscription`](crate::subscription::Subscription)s were accepted.
-    fn expected_responses(map: &Map<Instrument>) -> usize {
+    fn expected_responses(map: &Map<Arc<Instrument>>) -> usize {
         map.0.len()
     }
 
+    /// Determine the [`SubscriptionId`] of the [`Subscription`](crate::subscription::Subscription)
+    /// acknowledged by a [`Self::SubResponse`], if the exchange identifies it.
+    ///
+    /// Used by the [`SubscriptionValidator`] to match each response to its
+    /// [`Subscription`](crate::subscription::Subscription), so duplicated & re-ordered responses
+    /// are counted exactly once towards the [`Self::expected_responses`].
+    ///
+    /// Defaults to `None`, meaning every success response is counted.
+    fn subscription_id(_: &Self::SubResponse) -> Option<SubscriptionId> {
+        None
+    }
+
+    /// Number of [`Level`](crate::subscription::book::Level)s per side that the exchange server
+    /// supports for [`OrderBook`](crate::subscription::book::OrderBook) snapshots (see
+    /// [`OrderBooksL2Depth`](crate::subscription::book::OrderBooksL2Depth)).
+    ///
+    /// Defaults to none, meaning custom depths are not supported.
+    fn book_depths() -> &'static [u16] {
+        &[]
+    }
+
+    /// Standard candle [`Interval`](crate::subscription::candle::Interval)s that the exchange
+    /// server supports (see
+    /// [`ContinuousCandles`](crate::subscription::candle::ContinuousCandles)).
+    ///
+    /// Defaults to none, meaning candle streams are not supported.
+    fn supported_intervals() -> &'static [Interval] {
+        &[]
+    }
+
+    /// HTTP request weight of the [`OrderBook`](crate::subscription::book::OrderBook) snapshot
+    /// fetched when initialising a book of the provided depth (`None` for the default depth).
+    ///
+    /// Defaults to zero, meaning books are not initialised from a rate limited snapshot.
+    fn snapshot_weight(_depth: Option<u16>) -> u32 {
+        0
+    }
+
     /// Expected [`Duration`] the [`SubscriptionValidator`] will wait to receive all success
     /// responses to actioned [`Subscription`](crate::subscription::Subscription) requests.
     fn subscription_timeout() -> Duration {
         DEFAULT_SUBSCRIPTION_TIMEOUT
     }
+
+    /// Determine if a [`Self::SubResponse`] communicates that an exchange subscription limit has
+    /// been hit, returning the associated [`SubscriptionError::LimitExceeded`].
+    ///
+    /// Defaults to `None`, meaning that all failure responses are treated as generic
+    /// [`SocketError::Subscribe`] rejections.
+    fn subscription_limit_exceeded(_: &Self::SubResponse) -> Option<SubscriptionError> {
+        None
+    }
+}
+
+/// Drive the provided [`Future`] (eg/ [`MarketStream::init`]) with a per-connection base [`Url`]
+/// that takes precedence over the [`Connector::url`] of any connection it establishes (eg/ to
+/// connect to an exchange testnet, via a regional proxy, or to a local mock server).
+///
+/// The base [`Url`] is not validated, see
+/// [`validate_base_url`](crate::streams::builder::validate_base_url).
+pub async fn with_base_url<Fut>(url: Option<Url>, future: Fut) -> Fut::Output
+where
+    Fut: Future,
+{
+    BASE_URL.scope(url, future).await
+}
+
+/// Base [`Url`] new connections with the [`Connector`] are established with, which is the
+/// per-connection [`with_base_url`] override if one is set, otherwise the [`Connector::url`].
+#[allow(clippy::result_large_err)]
+pub fn connector_url<Exchange>() -> Result<Url, SocketError>
+where
+    Exchange: Connector,
+{
+    match BASE_URL.try_with(Option::clone).ok().flatten() {
+        Some(url) => Ok(url),
+        None => Exchange::url(),
+    }
 }
 
 /// Used when an exchange has servers different
@@ -150,6 +336,13 @@ where
 pub trait ExchangeServer: Default + Debug + Clone + Send {
     const ID: ExchangeId;
     fn websocket_url() -> &'static str;
+
+    /// [`OutboundRateLimit`] of client→server messages documented by the exchange server.
+    ///
+    /// Defaults to `None`, meaning that outbound messages are not paced.
+    fn outbound_rate_limit() -> Option<OutboundRateLimit> {
+        None
+    }
 }
 
 /// Defines the frequency and construction function for custom
@@ -200,7 +393,41 @@ impl Display for ExchangeId {
     }
 }
 
+impl FromStr for ExchangeId {
+    type Err = SocketError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|exchange| exchange.as_str() == input)
+            .ok_or_else(|| SocketError::Unsupported {
+                entity: "ExchangeId",
+                item: input.to_owned(),
+            })
+    }
+}
+
 impl ExchangeId {
+    /// Every [`ExchangeId`].
+    pub const ALL: [ExchangeId; 16] = [
+        ExchangeId::BinanceFuturesUsd,
+        ExchangeId::BinanceSpot,
+        ExchangeId::BinanceUSSpot,
+        ExchangeId::Bitfinex,
+        ExchangeId::Bitmex,
+        ExchangeId::BybitSpot,
+        ExchangeId::BybitPerpetualsUsd,
+        ExchangeId::Coinbase,
+        ExchangeId::GateioSpot,
+        ExchangeId::GateioFuturesUsd,
+        ExchangeId::GateioFuturesBtc,
+        ExchangeId::GateioPerpetualsBtc,
+        ExchangeId::GateioPerpetualsUsd,
+        ExchangeId::GateioOptions,
+        ExchangeId::Kraken,
+        ExchangeId::Okx,
+    ];
+
     /// Return the &str representation of this [`ExchangeId`]
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -223,6 +450,124 @@ impl ExchangeId {
         }
     }
 
+    /// Base WebSocket url of the exchange server associated with this [`ExchangeId`].
+    pub fn websocket_base_url(&self) -> &'static str {
+        use ExchangeId::*;
+
+        match self {
+            BinanceFuturesUsd => binance::futures::WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD,
+            BinanceSpot => binance::spot::WEBSOCKET_BASE_URL_BINANCE_SPOT,
+            BinanceUSSpot => binance::spot::WEBSOCKET_BASE_URL_BINANCEUS_SPOT,
+            Bitfinex => bitfinex::BASE_URL_BITFINEX,
+            Bitmex => bitmex::BASE_URL_BITMEX,
+            BybitSpot => bybit::spot::WEBSOCKET_BASE_URL_BYBIT_SPOT,
+            BybitPerpetualsUsd => bybit::futures::WEBSOCKET_BASE_URL_BYBIT_PERPETUALS_USD,
+            Coinbase => coinbase::BASE_URL_COINBASE,
+            GateioSpot => gateio::spot::WEBSOCKET_BASE_URL_GATEIO_SPOT,
+            GateioFuturesUsd => gateio::future::WEBSOCKET_BASE_URL_GATEIO_FUTURES_USD,
+            GateioFuturesBtc => gateio::future::WEBSOCKET_BASE_URL_GATEIO_FUTURES_BTC,
+            GateioPerpetualsBtc => gateio::perpetual::WEBSOCKET_BASE_URL_GATEIO_PERPETUALS_BTC,
+            GateioPerpetualsUsd => gateio::perpetual::WEBSOCKET_BASE_URL_GATEIO_PERPETUALS_USD,
+            GateioOptions => gateio::option::WEBSOCKET_BASE_URL_GATEIO_OPTIONS_USD,
+            Kraken => kraken::BASE_URL_KRAKEN,
+            Okx => okx::BASE_URL_OKX,
+        }
+    }
+
+    /// Normalised stream [`SubKind`]s the [`Connector`] associated with this [`ExchangeId`]
+    /// implements a [`StreamSelector`] for, allowing applications to enumerate capabilities at
+    /// runtime (eg/ to validate a config).
+    pub fn supported_stream_kinds(&self) -> &'static [SubKindId] {
+        use ExchangeId::*;
+        use SubKindId::*;
+
+        match self {
+            BinanceFuturesUsd => &[
+                PublicTrades,
+                TaggedTrades,
+                OrderBooksL1,
+                OrderBooksL2,
+                OrderBooksL2Depth,
+                OrderBooksTop,
+                Liquidations,
+                FundingRates,
+                IndexPrices,
+                Candles,
+                ContinuousCandles,
+            ],
+            BinanceSpot => &[
+                PublicTrades,
+                TaggedTrades,
+                OrderBooksL1,
+                OrderBooksL2,
+                OrderBooksL2Depth,
+                OrderBooksTop,
+                Candles,
+                Tickers,
+            ],
+            BinanceUSSpot => &[
+                PublicTrades,
+                TaggedTrades,
+                OrderBooksL1,
+                OrderBooksL2,
+                OrderBooksL2Depth,
+                OrderBooksTop,
+                Candles,
+            ],
+            Kraken => &[PublicTrades, OrderBooksL1, OrderBooksL2],
+            BybitSpot | BybitPerpetualsUsd | GateioSpot | Okx => &[PublicTrades, OrderBooksL2],
+            Bitfinex | Bitmex | Coinbase | GateioFuturesUsd | GateioFuturesBtc
+            | GateioPerpetualsBtc | GateioPerpetualsUsd | GateioOptions => &[PublicTrades],
+        }
+    }
+
+    /// Determines the [`FundingSchedule`] of [`InstrumentKind::Perpetual`] markets on the exchange
+    /// associated with this [`ExchangeId`].
+    ///
+    /// Returns `None` if the exchange does not support [`InstrumentKind::Perpetual`] markets.
+    pub fn funding_schedule(&self) -> Option<FundingSchedule> {
+        use ExchangeId::*;
+
+        match self {
+            BinanceFuturesUsd | BybitPerpetualsUsd | GateioPerpetualsUsd | GateioPerpetualsBtc
+            | Okx => Some(FundingSchedule::EIGHT_HOURLY),
+
+            // Bitmex funding settles at 04:00, 12:00 & 20:00 UTC
+            Bitmex => Some(FundingSchedule::new(
+                Duration::from_secs(8 * 60 * 60),
+                Duration::from_secs(4 * 60 * 60),
+            )),
+
+            _ => None,
+        }
+    }
+
+    /// Determines the [`QuantityUnit`] the exchange associated with this [`ExchangeId`] uses to
+    /// report the raw amount of [`PublicTrade`](crate::subscription::trade::PublicTrade)s for the
+    /// provided [`Instrument`].
+    ///
+    /// ### Conventions
+    /// - Binance, Bitfinex, Bybit, Coinbase, Kraken & spot markets: base asset amount.
+    /// - Bitmex inverse contracts (ie/ USD quoted, eg/ XBTUSD): quote notional, since they have 1
+    ///   USD contracts.
+    /// - Bitmex linear contracts (eg/ XBTUSDT), Gateio futures, perpetuals & options, and Okx
+    ///   derivatives: number of contracts.
+    pub fn trade_quantity_unit(&self, instrument: &Instrument) -> QuantityUnit {
+        use ExchangeId::*;
+
+        match (self, instrument.kind) {
+            (_, InstrumentKind::Spot) => QuantityUnit::Base,
+            (Bitmex, _) if instrument.quote.as_ref() == "usd" => QuantityUnit::Quote,
+            (Bitmex, _) => QuantityUnit::Contract,
+            (
+                GateioFuturesUsd | GateioFuturesBtc | GateioPerpetualsUsd | GateioPerpetualsBtc
+                | GateioOptions | Okx,
+                _,
+            ) => QuantityUnit::Contract,
+            _ => QuantityUnit::Base,
+        }
+    }
+
     /// Determines whether the [`Connector`] associated with this [`ExchangeId`] supports the
     /// ingestion of market data for the provided [`InstrumentKind`].
     #[allow(clippy::match_like_matches_macro)]
@@ -257,3 +602,42 @@ impl ExchangeId {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exchange_id_str_round_trip() {
+        for exchange in ExchangeId::ALL {
+            assert_eq!(exchange.as_str().parse::<ExchangeId>().unwrap(), exchange);
+            assert!(
+                Url::parse(exchange.websocket_base_url()).is_ok(),
+                "{exchange}"
+            );
+            assert!(!exchange.supported_stream_kinds().is_empty(), "{exchange}");
+        }
+
+        for kind in SubKindId::ALL {
+            assert_eq!(kind.as_str().parse::<SubKindId>().unwrap(), kind);
+        }
+
+        assert!(matches!(
+            "unknown".parse::<ExchangeId>(),
+            Err(SocketError::Unsupported { item, .. }) if item == "unknown"
+        ));
+    }
+
+    #[test]
+    fn test_exchange_id_metadata() {
+        let kinds = ExchangeId::BinanceSpot.supported_stream_kinds();
+        assert!(kinds.contains(&SubKindId::PublicTrades));
+        assert!(kinds.contains(&SubKindId::OrderBooksL2));
+        assert!(!kinds.contains(&SubKindId::Liquidations));
+
+        assert_eq!(
+            ExchangeId::BinanceSpot.websocket_base_url(),
+            binance::spot::WEBSOCKET_BASE_URL_BINANCE_SPOT
+        );
+    }
+}
@@ -0,0 +1,565 @@
+use super::super::{subscription::OkxSubResponse, trade::de_okx_message_arg_as_subscription_id};
+use crate::{
+    error::DataError,
+    exchange::Connector,
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{
+        crc32, BookMessageKind, InstrumentOrderBook, OrderBookUpdater, TaggedBookSync,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Write, sync::Arc};
+use tokio::sync::mpsc;
+
+/// Number of [`Level`]s per side of the local [`OrderBook`] included in each
+/// [`Okx`](super::super::Okx) book checksum.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+pub const BOOK_L2_CHECKSUM_DEPTH_OKX: usize = 25;
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 WebSocket message, which is either an OrderBook
+/// snapshot or update, or an [`OkxSubResponse`] (eg/ an unsubscription) received on the same
+/// connection.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum OkxBookMessage {
+    Response(OkxSubResponse),
+    OrderBook(OkxOrderBookL2),
+}
+
+impl Identifier<Option<SubscriptionId>> for OkxBookMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            OkxBookMessage::Response(_) => None,
+            OkxBookMessage::OrderBook(book) => Some(book.subscription_id.clone()),
+        }
+    }
+}
+
+/// [`Okx`](super::super::Okx) real-time OrderBook Level2 snapshot or update.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+/// #### Snapshot
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "books",
+///     "instId": "BTC-USDT"
+///   },
+///   "action": "snapshot",
+///   "data": [
+///     {
+///       "asks": [["8476.98", "415", "0", "13"], ["8477", "7", "0", "2"]],
+///       "bids": [["8476.97", "256", "0", "12"], ["8475.55", "101", "0", "1"]],
+///       "ts": "1597026383085",
+///       "checksum": -855196043,
+///       "prevSeqId": -1,
+///       "seqId": 123456
+///     }
+///   ]
+/// }
+/// ```
+///
+/// #### Update
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "books",
+///     "instId": "BTC-USDT"
+///   },
+///   "action": "update",
+///   "data": [
+///     {
+///       "asks": [["8476.98", "0", "0", "0"]],
+///       "bids": [["8476.96", "12", "0", "1"]],
+///       "ts": "1597026383086",
+///       "checksum": 1735630749,
+///       "prevSeqId": 123456,
+///       "seqId": 123457
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookL2 {
+    #[serde(
+        rename = "arg",
+        deserialize_with = "de_okx_message_arg_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    #[serde(rename = "action")]
+    pub kind: BookMessageKind,
+    pub data: Vec<OkxOrderBookL2Data>,
+}
+
+impl Identifier<Option<SubscriptionId>> for OkxOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 snapshot or update data.
+///
+/// See [`OkxOrderBookL2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookL2Data {
+    pub bids: Vec<OkxLevel>,
+    pub asks: Vec<OkxLevel>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub checksum: Option<i32>,
+}
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 level.
+///
+/// #### Raw Payload Examples
+/// ```json
+/// ["8476.98", "415", "0", "13"]
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct OkxLevel {
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl From<OkxLevel> for Level {
+    fn from(level: OkxLevel) -> Self {
+        Self::new(level.price, level.amount)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for OkxLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = OkxLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("OkxLevel struct from the Okx WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // OkxLevel Sequence Format:
+                // [price, size, deprecated liquidated orders, number of orders]
+                // <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+                let price = extract_next::<SeqAccessor, String>(&mut seq, "price")?;
+                let amount = extract_next::<SeqAccessor, String>(&mut seq, "size")?;
+
+                // Ignore any additional elements or SerDe will fail
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                let parse = |value: &str| {
+                    value.parse::<f64>().map_err(|_| {
+                        serde::de::Error::invalid_value(
+                            serde::de::Unexpected::Str(value),
+                            &"decimal string",
+                        )
+                    })
+                };
+
+                Ok(OkxLevel {
+                    price: parse(&price)?,
+                    amount: parse(&amount)?,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialize the OkxLevel
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// [`Okx`](super::super::Okx) [`OrderBookUpdater`].
+///
+/// Okx: How To Maintain A Local OrderBook
+///
+/// 1. The first message of a subscription is a snapshot ("action": "snapshot") of the book.
+/// 2. Subsequent messages are updates ("action": "update") containing the absolute size for a
+///    price level, where a size of 0 removes the price level.
+/// 3. Validate the top [`BOOK_L2_CHECKSUM_DEPTH_OKX`] levels of the local book against the signed
+///    CRC32 "checksum" of each message, re-initialising the book if it does not match.
+///
+/// ### Notes
+/// The checksum string is generated from the shortest decimal representation of each [`Level`]
+/// price & amount, which matches the raw Okx decimal strings since they omit trailing zeros.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct OkxBookUpdater {
+    pub sync: TaggedBookSync,
+}
+
+impl OkxBookUpdater {
+    /// Generate the [`Okx`](super::super::Okx) signed CRC32 checksum of the top
+    /// [`BOOK_L2_CHECKSUM_DEPTH_OKX`] bids & asks of the provided sorted [`OrderBook`].
+    pub fn checksum(book: &OrderBook) -> i32 {
+        crc32(Self::checksum_input(book).as_bytes()) as i32
+    }
+
+    /// Generate the string the [`Okx`](super::super::Okx) checksum is calculated from,
+    /// alternating the "price:size" of each bid & ask from the best price, and continuing with the
+    /// remaining levels of the deeper side (eg/ "bid1:size:ask1:size:bid2:size:ask2:size").
+    fn checksum_input(book: &OrderBook) -> String {
+        let mut input = String::new();
+        for index in 0..BOOK_L2_CHECKSUM_DEPTH_OKX {
+            let levels = [book.bids.levels.get(index), book.asks.levels.get(index)];
+            for level in levels.into_iter().flatten() {
+                let separator = if input.is_empty() { "" } else { ":" };
+                let _ = write!(input, "{separator}{}:{}", level.price, level.amount);
+            }
+        }
+        input
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for OkxBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = OkxBookMessage;
+    const WS_SNAPSHOT: bool = true;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Arc<Instrument>,
+        depth: Option<u16>,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Connector + Send,
+        Kind: Send,
+    {
+        // Okx sends the initial OrderBook snapshot over the WebSocket, so start empty
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+            depth,
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        let OkxBookMessage::OrderBook(update) = update else {
+            return Ok(None);
+        };
+
+        let mut updated = false;
+        for data in update.data {
+            self.sync
+                .apply(book, update.kind, data.time, data.bids, data.asks)?;
+            book.bids.sort();
+            book.asks.sort();
+
+            if let Some(expected) = data.checksum {
+                let actual = Self::checksum(book);
+                if actual != expected {
+                    return Err(DataError::InvalidChecksum {
+                        expected: expected as u32,
+                        actual: actual as u32,
+                    });
+                }
+            }
+            updated = true;
+        }
+
+        Ok(updated.then(|| book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_okx_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxOrderBookL2, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    {
+                        "arg": {"channel": "books", "instId": "BTC-USDT"},
+                        "action": "snapshot",
+                        "data": [
+                            {
+                                "asks": [["8476.98", "415", "0", "13"], ["8477", "7", "0", "2"]],
+                                "bids": [["8476.97", "256", "0", "12"]],
+                                "ts": "1597026383085",
+                                "checksum": -855196043,
+                                "prevSeqId": -1,
+                                "seqId": 123456
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxOrderBookL2 {
+                        subscription_id: SubscriptionId::from("books|BTC-USDT"),
+                        kind: BookMessageKind::Snapshot,
+                        data: vec![OkxOrderBookL2Data {
+                            bids: vec![OkxLevel {
+                                price: 8476.97,
+                                amount: 256.0,
+                            }],
+                            asks: vec![
+                                OkxLevel {
+                                    price: 8476.98,
+                                    amount: 415.0,
+                                },
+                                OkxLevel {
+                                    price: 8477.0,
+                                    amount: 7.0,
+                                },
+                            ],
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1597026383085,
+                            )),
+                            checksum: Some(-855196043),
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: valid update w/ a removed level
+                    input: r#"
+                    {
+                        "arg": {"channel": "books", "instId": "BTC-USDT"},
+                        "action": "update",
+                        "data": [
+                            {
+                                "asks": [["8476.98", "0", "0", "0"]],
+                                "bids": [],
+                                "ts": "1597026383086",
+                                "checksum": 1735630749,
+                                "prevSeqId": 123456,
+                                "seqId": 123457
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxOrderBookL2 {
+                        subscription_id: SubscriptionId::from("books|BTC-USDT"),
+                        kind: BookMessageKind::Update,
+                        data: vec![OkxOrderBookL2Data {
+                            bids: vec![],
+                            asks: vec![OkxLevel {
+                                price: 8476.98,
+                                amount: 0.0,
+                            }],
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1597026383086,
+                            )),
+                            checksum: Some(1735630749),
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid level w/ non-numeric price
+                    input: r#"
+                    {
+                        "arg": {"channel": "books", "instId": "BTC-USDT"},
+                        "action": "update",
+                        "data": [{"asks": [["invalid", "1", "0", "1"]], "bids": [], "ts": "1597026383086"}]
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxOrderBookL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_checksum_input() {
+        struct TestCase {
+            bids: Vec<Level>,
+            asks: Vec<Level>,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: equal depth sides alternate bids & asks
+                bids: vec![Level::new(3366.1, 7.0), Level::new(3366.0, 6.0)],
+                asks: vec![Level::new(3366.8, 9.0), Level::new(3368.0, 8.0)],
+                expected: "3366.1:7:3366.8:9:3366:6:3368:8",
+            },
+            TestCase {
+                // TC1: remaining levels of the deeper side are appended
+                bids: vec![Level::new(3366.1, 7.0)],
+                asks: vec![
+                    Level::new(3366.8, 9.0),
+                    Level::new(3368.0, 8.0),
+                    Level::new(3372.0, 8.0),
+                ],
+                expected: "3366.1:7:3366.8:9:3368:8:3372:8",
+            },
+            TestCase {
+                // TC2: only the top checksum depth levels are included
+                bids: vec![],
+                asks: (1..=30)
+                    .map(|price| Level::new(f64::from(price), 0.5))
+                    .collect(),
+                expected: "1:0.5:2:0.5:3:0.5:4:0.5:5:0.5:6:0.5:7:0.5:8:0.5:9:0.5:10:0.5:11:0.5:\
+                    12:0.5:13:0.5:14:0.5:15:0.5:16:0.5:17:0.5:18:0.5:19:0.5:20:0.5:21:0.5:22:0.5:\
+                    23:0.5:24:0.5:25:0.5",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, test.bids),
+                asks: OrderBookSide::new(Side::Sell, test.asks),
+            };
+            assert_eq!(
+                OkxBookUpdater::checksum_input(&book),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_update_okx_order_book_l2() {
+        let message = |kind: &str, bids: &str, asks: &str, checksum: Option<i32>| {
+            let checksum = checksum.map_or("null".to_string(), |checksum| checksum.to_string());
+            serde_json::from_str::<OkxBookMessage>(&format!(
+                r#"{{"arg": {{"channel": "books", "instId": "BTC-USDT"}}, "action": "{kind}", "data": [{{"bids": [{bids}], "asks": [{asks}], "ts": "1597026383085", "checksum": {checksum}}}]}}"#
+            ))
+            .unwrap()
+        };
+
+        let mut updater = OkxBookUpdater::default();
+        let mut book = OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+            asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+        };
+
+        // TC0: update received before any snapshot is a BookDesync
+        let update = message("update", r#"["100", "1", "0", "1"]"#, "", None);
+        assert!(
+            matches!(
+                updater.update(&mut book, update),
+                Err(DataError::BookDesync)
+            ),
+            "TC0 failed"
+        );
+
+        // TC1: snapshot w/ a valid checksum resets the OrderBook
+        let expected = OrderBook {
+            last_update_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                1597026383085,
+            )),
+            bids: OrderBookSide::new(
+                Side::Buy,
+                vec![Level::new(100.0, 1.0), Level::new(99.5, 2.0)],
+            ),
+            asks: OrderBookSide::new(Side::Sell, vec![Level::new(101.0, 3.0)]),
+        };
+        let snapshot = message(
+            "snapshot",
+            r#"["99.5", "2", "0", "1"], ["100", "1", "0", "1"]"#,
+            r#"["101", "3", "0", "1"]"#,
+            Some(OkxBookUpdater::checksum(&expected)),
+        );
+        let actual = updater.update(&mut book, snapshot).unwrap().unwrap();
+        assert_eq!(actual, expected, "TC1 failed");
+
+        // TC2: update w/ a valid checksum removes & upserts levels
+        let expected = OrderBook {
+            last_update_time: expected.last_update_time,
+            bids: OrderBookSide::new(Side::Buy, vec![Level::new(99.5, 2.0)]),
+            asks: OrderBookSide::new(
+                Side::Sell,
+                vec![Level::new(101.0, 3.0), Level::new(102.0, 0.25)],
+            ),
+        };
+        let update = message(
+            "update",
+            r#"["100", "0", "0", "0"]"#,
+            r#"["102", "0.25", "0", "1"]"#,
+            Some(OkxBookUpdater::checksum(&expected)),
+        );
+        let actual = updater.update(&mut book, update).unwrap().unwrap();
+        assert_eq!(actual, expected, "TC2 failed");
+
+        // TC3: update w/ an invalid checksum is terminal
+        let checksum = OkxBookUpdater::checksum(&expected);
+        let update = message("update", r#"["99", "1", "0", "1"]"#, "", Some(checksum));
+        assert!(
+            matches!(
+                updater.update(&mut book, update),
+                Err(DataError::InvalidChecksum { expected, .. }) if expected == checksum as u32
+            ),
+            "TC3 failed"
+        );
+
+        // TC4: unsubscription response received on the same connection is ignored
+        let response = serde_json::from_str::<OkxBookMessage>(
+            r#"{"event": "unsubscribe", "arg": {"channel": "books", "instId": "BTC-USDT"}}"#,
+        )
+        .unwrap();
+        assert!(
+            matches!(updater.update(&mut book, response), Ok(None)),
+            "TC4 failed"
+        );
+    }
+}
@@ -0,0 +1,2 @@
+/// Level 2 OrderBook types (validated by checksum over the top 25 levels).
+pub mod l2;
@@ -1,6 +1,6 @@
 use super::Okx;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Subscription},
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +17,11 @@ impl OkxChannel {
     ///
     /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-trades-channel>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Okx`] real-time OrderBook Level2 (400 levels) channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+    pub const ORDER_BOOK_L2: Self = Self("books");
 }
 
 impl Identifier<OkxChannel> for Subscription<Okx, PublicTrades> {
@@ -25,6 +30,12 @@ impl Identifier<OkxChannel> for Subscription<Okx, PublicTrades> {
     }
 }
 
+impl Identifier<OkxChannel> for Subscription<Okx, OrderBooksL2> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDER_BOOK_L2
+    }
+}
+
 impl AsRef<str> for OkxChannel {
     fn as_ref(&self) -> &str {
         self.0
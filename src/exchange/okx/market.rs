@@ -1,7 +1,12 @@
 use super::Okx;
-use crate::{subscription::Subscription, Identifier};
+use crate::{
+    exchange::market::{split_separated, MarketId},
+    subscription::Subscription,
+    Identifier,
+};
 use barter_integration::model::instrument::{
     kind::{InstrumentKind, OptionKind},
+    symbol::Symbol,
     Instrument,
 };
 use chrono::{
@@ -17,12 +22,12 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct OkxMarket(pub String);
 
-impl<Kind> Identifier<OkxMarket> for Subscription<Okx, Kind> {
-    fn id(&self) -> OkxMarket {
+impl MarketId for Okx {
+    fn market_id(instrument: &Instrument) -> String {
         use InstrumentKind::*;
-        let Instrument { base, quote, kind } = &self.instrument;
+        let Instrument { base, quote, kind } = instrument;
 
-        OkxMarket(match kind {
+        match kind {
             Spot => format!("{base}-{quote}").to_uppercase(),
             Future(future) => {
                 format!("{base}-{quote}-{}", format_expiry(future.expiry)).to_uppercase()
@@ -38,7 +43,17 @@ impl<Kind> Identifier<OkxMarket> for Subscription<Okx, Kind> {
                 },
             )
             .to_uppercase(),
-        })
+        }
+    }
+
+    fn parse_market_id(market: &str) -> Option<(Symbol, Symbol)> {
+        split_separated(market, '-').map(|(base, quote)| (Symbol::new(base), Symbol::new(quote)))
+    }
+}
+
+impl<Kind> Identifier<OkxMarket> for Subscription<Okx, Kind> {
+    fn id(&self) -> OkxMarket {
+        OkxMarket(Okx::market_id(&self.instrument))
     }
 }
 
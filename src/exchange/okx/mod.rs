@@ -1,19 +1,32 @@
 use self::{
-    channel::OkxChannel, market::OkxMarket, subscription::OkxSubResponse, trade::OkxTrades,
+    book::l2::OkxBookUpdater, channel::OkxChannel, market::OkxMarket, subscription::OkxSubResponse,
+    trade::OkxTrades,
 };
 use crate::{
-    exchange::{Connector, ExchangeId, ExchangeSub, PingInterval, StreamSelector},
+    error::SubscriptionError,
+    exchange::{
+        subscription::ResumableSub, Connector, ExchangeId, ExchangeSub, PingInterval,
+        StreamSelector,
+    },
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::OrderBooksL2,
+        trade::{PublicTrade, PublicTrades},
+    },
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    error::SocketError, model::SubscriptionId, protocol::websocket::WsMessage,
+};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use std::time::Duration;
 use url::Url;
 
+/// OrderBook types for [`Okx`].
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -75,8 +88,115 @@ impl Connector for Okx {
             .to_string(),
         )]
     }
+
+    /// Subscriptions with a last-seen sequence are resumed from the `seqId` after it, rather than
+    /// only receiving events from the time of re-subscription. Subscriptions without a last-seen
+    /// sequence (eg/ [`OrderBooksL2`], which are unsequenced) are subscribed to as standard,
+    /// re-fetching any snapshot.
+    fn resume_requests(
+        exchange_subs: Vec<ResumableSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        let args = exchange_subs
+            .iter()
+            .map(|(exchange_sub, sequence)| match sequence {
+                Some(sequence) => json!({
+                    "channel": exchange_sub.channel.as_ref(),
+                    "instId": exchange_sub.market.as_ref(),
+                    "seqId": sequence.to_string(),
+                }),
+                None => json!(exchange_sub),
+            })
+            .collect::<Vec<_>>();
+
+        vec![WsMessage::Text(
+            json!({
+                "op": "subscribe",
+                "args": args,
+            })
+            .to_string(),
+        )]
+    }
+
+    /// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-unsubscribe>
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        vec![WsMessage::Text(
+            json!({
+                "op": "unsubscribe",
+                "args": &exchange_subs,
+            })
+            .to_string(),
+        )]
+    }
+
+    fn subscription_id(response: &Self::SubResponse) -> Option<SubscriptionId> {
+        response.subscription_id()
+    }
+
+    fn subscription_limit_exceeded(response: &Self::SubResponse) -> Option<SubscriptionError> {
+        response.limit_exceeded()
+    }
 }
 
 impl StreamSelector<PublicTrades> for Okx {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, OkxTrades>>;
+
+    /// [`Okx`] trade ids are sequential per instrument.
+    fn sequence(trade: &PublicTrade) -> Option<u64> {
+        trade.id.parse().ok()
+    }
+}
+
+impl StreamSelector<OrderBooksL2> for Okx {
+    type Stream = ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, OkxBookUpdater>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{channel::OkxChannel, market::OkxMarket, *};
+
+    #[test]
+    fn test_okx_resume_requests() {
+        struct TestCase {
+            input: ResumableSub<OkxChannel, OkxMarket>,
+            expected: serde_json::Value,
+        }
+
+        let market = || OkxMarket("BTC-USDT".to_string());
+        let tests = vec![
+            TestCase {
+                // TC0: sequenced trades are resumed from the last-seen sequence
+                input: (
+                    ExchangeSub::from((OkxChannel::TRADES, market())),
+                    Some(130639474),
+                ),
+                expected: json!({"channel": "trades", "instId": "BTC-USDT", "seqId": "130639474"}),
+            },
+            TestCase {
+                // TC1: trades without a last-seen sequence are subscribed to as standard
+                input: (ExchangeSub::from((OkxChannel::TRADES, market())), None),
+                expected: json!({"channel": "trades", "instId": "BTC-USDT"}),
+            },
+            TestCase {
+                // TC2: unsequenced OrderBooks are subscribed to as standard, re-fetching snapshots
+                input: (
+                    ExchangeSub::from((OkxChannel::ORDER_BOOK_L2, market())),
+                    None,
+                ),
+                expected: json!({"channel": "books", "instId": "BTC-USDT"}),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let requests = Okx::resume_requests(vec![test.input]);
+            let [WsMessage::Text(request)] = requests.as_slice() else {
+                panic!("TC{index} failed: expected a single text request, got: {requests:?}");
+            };
+
+            let request = serde_json::from_str::<serde_json::Value>(request).unwrap();
+            assert_eq!(request["op"], "subscribe", "TC{index} failed");
+            assert_eq!(request["args"], json!([test.expected]), "TC{index} failed");
+        }
+    }
 }
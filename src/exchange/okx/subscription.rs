@@ -1,6 +1,10 @@
 use super::{channel::OkxChannel, market::OkxMarket};
-use crate::exchange::subscription::ExchangeSub;
-use barter_integration::{error::SocketError, Validator};
+use crate::{
+    error::SubscriptionError,
+    exchange::{subscription::ExchangeSub, ExchangeId},
+    Identifier,
+};
+use barter_integration::{error::SocketError, model::SubscriptionId, Validator};
 use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
 
 // Implement custom Serialize to assist aesthetics of <Okx as Connector>::requests() function.
@@ -23,7 +27,18 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
 /// ```json
 /// {
 ///   "event": "subscribe",
-///   "args": {
+///   "arg": {
+///     "channel": "trades",
+///     "instId": "BTC-USD-191227"
+///   }
+/// }
+/// ```
+///
+/// #### Unsubscription Trades Ok Response
+/// ```json
+/// {
+///   "event": "unsubscribe",
+///   "arg": {
 ///     "channel": "trades",
 ///     "instId": "BTC-USD-191227"
 ///   }
@@ -44,7 +59,15 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
 #[serde(tag = "event", rename_all = "lowercase")]
 pub enum OkxSubResponse {
     #[serde(rename = "subscribe")]
-    Subscribed,
+    Subscribed {
+        #[serde(default)]
+        arg: Option<OkxSubArg>,
+    },
+    #[serde(rename = "unsubscribe")]
+    Unsubscribed {
+        #[serde(default)]
+        arg: Option<OkxSubArg>,
+    },
     Error {
         code: String,
         #[serde(rename = "msg")]
@@ -52,13 +75,63 @@ pub enum OkxSubResponse {
     },
 }
 
+/// Channel & market of the subscription acknowledged by an [`OkxSubResponse`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxSubArg {
+    pub channel: String,
+    #[serde(rename = "instId")]
+    pub market: String,
+}
+
+/// [`Okx`](super::Okx) error codes communicating that a subscription limit has been hit.
+///
+/// ### Error Codes:
+/// 60014: Requests too frequent
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#error-code-websocket-public>
+pub const OKX_ERROR_CODES_LIMIT_EXCEEDED: &[&str] = &["60014"];
+
+impl OkxSubResponse {
+    /// Determine the [`SubscriptionId`] of the subscription acknowledged by this
+    /// [`OkxSubResponse`], matching the [`ExchangeSub`] it was requested with.
+    pub fn subscription_id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Subscribed { arg: Some(arg) } => Some(
+                ExchangeSub {
+                    channel: arg.channel.as_str(),
+                    market: arg.market.as_str(),
+                }
+                .id(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Determine if this [`OkxSubResponse`] communicates that a subscription limit has been hit,
+    /// returning the associated [`SubscriptionError::LimitExceeded`].
+    pub fn limit_exceeded(&self) -> Option<SubscriptionError> {
+        match self {
+            Self::Error { code, message }
+                if OKX_ERROR_CODES_LIMIT_EXCEEDED.contains(&code.as_str()) =>
+            {
+                Some(SubscriptionError::LimitExceeded {
+                    exchange: ExchangeId::Okx,
+                    code: code.clone(),
+                    message: message.clone(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
 impl Validator for OkxSubResponse {
     fn validate(self) -> Result<Self, SocketError>
     where
         Self: Sized,
     {
         match self {
-            Self::Subscribed => Ok(self),
+            Self::Subscribed { .. } | Self::Unsubscribed { .. } => Ok(self),
             Self::Error { code, message } => Err(SocketError::Subscribe(format!(
                 "received failure subscription response code: {code} with message: {message}",
             ))),
@@ -69,6 +142,7 @@ impl Validator for OkxSubResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::exchange::Connector;
 
     mod de {
         use super::*;
@@ -86,10 +160,15 @@ mod tests {
                     input: r#"
                 {
                     "event": "subscribe",
-                    "args": {"channel": "trades", "instId": "BTC-USD-191227"}
+                    "arg": {"channel": "trades", "instId": "BTC-USD-191227"}
                 }
                 "#,
-                    expected: Ok(OkxSubResponse::Subscribed),
+                    expected: Ok(OkxSubResponse::Subscribed {
+                        arg: Some(OkxSubArg {
+                            channel: "trades".to_string(),
+                            market: "BTC-USD-191227".to_string(),
+                        }),
+                    }),
                 },
                 TestCase {
                     // TC1: input response is failed subscription
@@ -105,6 +184,21 @@ mod tests {
                         message: "Invalid request: {\"op\": \"subscribe\", \"args\":[{ \"channel\" : \"trades\", \"instId\" : \"BTC-USD-191227\"}]}".to_string()
                     }),
                 },
+                TestCase {
+                    // TC2: input response is unsubscription success
+                    input: r#"
+                {
+                    "event": "unsubscribe",
+                    "arg": {"channel": "books", "instId": "BTC-USDT"}
+                }
+                "#,
+                    expected: Ok(OkxSubResponse::Unsubscribed {
+                        arg: Some(OkxSubArg {
+                            channel: "books".to_string(),
+                            market: "BTC-USDT".to_string(),
+                        }),
+                    }),
+                },
             ];
 
             for (index, test) in cases.into_iter().enumerate() {
@@ -135,7 +229,7 @@ mod tests {
         let cases = vec![
             TestCase {
                 // TC0: input response is subscription success
-                input_response: OkxSubResponse::Subscribed,
+                input_response: OkxSubResponse::Subscribed { arg: None },
                 is_valid: true,
             },
             TestCase {
@@ -153,4 +247,40 @@ mod tests {
             assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
         }
     }
+
+    #[test]
+    fn test_okx_sub_response_limit_exceeded() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<SubscriptionError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is subscription success
+                input: r#"{"event": "subscribe", "arg": {"channel": "trades", "instId": "BTC-USDT"}}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC1: input response is malformed subscription failure
+                input: r#"{"event": "error", "code": "60012", "msg": "Invalid request"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC2: input response is limit exceeded subscription failure
+                input: r#"{"event": "error", "code": "60014", "msg": "Requests too frequent"}"#,
+                expected: Some(SubscriptionError::LimitExceeded {
+                    exchange: ExchangeId::Okx,
+                    code: "60014".to_string(),
+                    message: "Requests too frequent".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let response = serde_json::from_str::<OkxSubResponse>(test.input).unwrap();
+            let actual = <super::super::Okx as Connector>::subscription_limit_exceeded(&response);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
 }
@@ -1,12 +1,14 @@
 use crate::{
+    clock,
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, TradeFields},
     Identifier,
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Terse type alias for an [`Okx`](super::Okx) real-time trades WebSocket message.
 pub type OkxTrades = OkxMessage<OkxTrade>;
@@ -79,9 +81,9 @@ impl<T> Identifier<Option<SubscriptionId>> for OkxMessage<T> {
 pub struct OkxTrade {
     #[serde(rename = "tradeId")]
     pub id: String,
-    #[serde(rename = "px", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(rename = "px", deserialize_with = "crate::de::de_f64")]
     pub price: f64,
-    #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
+    #[serde(rename = "sz", deserialize_with = "crate::de::de_f64")]
     pub amount: f64,
     pub side: Side,
     #[serde(
@@ -91,22 +93,24 @@ pub struct OkxTrade {
     pub time: DateTime<Utc>,
 }
 
-impl From<(ExchangeId, Instrument, OkxTrades)> for MarketIter<PublicTrade> {
-    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, OkxTrades)) -> Self {
+impl From<(ExchangeId, Arc<Instrument>, OkxTrades)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trades): (ExchangeId, Arc<Instrument>, OkxTrades)) -> Self {
+        let fields = TradeFields::current();
         trades
             .data
             .into_iter()
             .map(|trade| {
                 Ok(MarketEvent {
                     exchange_time: trade.time,
-                    received_time: Utc::now(),
+                    received_time: clock::received_time(),
                     exchange: Exchange::from(exchange_id),
                     instrument: instrument.clone(),
                     kind: PublicTrade {
-                        id: trade.id,
+                        id: fields.id(|| trade.id),
                         price: trade.price,
                         amount: trade.amount,
                         side: trade.side,
+                        conditions: vec![],
                     },
                 })
             })
@@ -115,7 +119,7 @@ impl From<(ExchangeId, Instrument, OkxTrades)> for MarketIter<PublicTrade> {
 }
 
 /// Deserialize an [`OkxMessage`] "arg" field as a Barter [`SubscriptionId`].
-fn de_okx_message_arg_as_subscription_id<'de, D>(
+pub fn de_okx_message_arg_as_subscription_id<'de, D>(
     deserializer: D,
 ) -> Result<SubscriptionId, D::Error>
 where
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Maximum rate at which client→server messages may be sent over a single exchange connection
+/// (eg/ 5 messages per second), as documented by the exchange server.
+///
+/// Bursting past the limit typically results in the exchange closing the connection, so every
+/// outbound [`WsMessage`](barter_integration::protocol::websocket::WsMessage) is paced by a
+/// [`RateLimiter`] rather than dropped.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OutboundRateLimit {
+    pub messages: u32,
+    pub interval: Duration,
+}
+
+impl OutboundRateLimit {
+    /// Construct a new [`Self`] allowing `messages` to be sent every `interval`.
+    pub const fn new(messages: u32, interval: Duration) -> Self {
+        Self { messages, interval }
+    }
+
+    /// Construct a new [`Self`] allowing `messages` to be sent every second.
+    pub const fn per_second(messages: u32) -> Self {
+        Self::new(messages, Duration::from_secs(1))
+    }
+}
+
+/// Token bucket [`RateLimiter`] that paces outbound messages according to an
+/// [`OutboundRateLimit`].
+///
+/// The bucket holds up to [`OutboundRateLimit::messages`] tokens, allowing a burst of that many
+/// messages, and is refilled continuously over the [`OutboundRateLimit::interval`].
+#[derive(Copy, Clone, Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Construct a new full [`Self`] for the provided [`OutboundRateLimit`].
+    pub fn new(limit: OutboundRateLimit) -> Self {
+        let capacity = f64::from(limit.messages.max(1));
+        Self {
+            capacity,
+            tokens: capacity,
+            refill_per_sec: capacity / limit.interval.as_secs_f64().max(f64::EPSILON),
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Wait until a token is available, and consume it.
+    pub async fn acquire(&mut self) {
+        self.refill();
+
+        if self.tokens < 1.0 {
+            let wait = (1.0 - self.tokens) / self.refill_per_sec;
+            tokio::time::sleep(Duration::from_secs_f64(wait)).await;
+            self.refill();
+        }
+
+        self.tokens = (self.tokens - 1.0).max(0.0);
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+    }
+}
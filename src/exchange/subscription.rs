@@ -37,10 +37,15 @@ pub struct ExchangeSub<Channel, Market> {
     ///
     /// ### Examples
     /// - [`BinanceMarket("btcusdt")`](super::binance::market::BinanceMarket)
-    /// - [`KrakenMarket("BTC/USDT")`](super::kraken::market::KrakenMarket)
+    /// - [`KrakenMarket("XBT/USDT")`](super::kraken::market::KrakenMarket)
     pub market: Market,
 }
 
+/// [`ExchangeSub`] paired with its last-seen sequence, used by an exchange
+/// [`Connector`](super::Connector) to build the subscription payloads that resume an exchange
+/// stream after a brief disconnect.
+pub type ResumableSub<Channel, Market> = (ExchangeSub<Channel, Market>, Option<u64>);
+
 impl<Channel, Market> Identifier<SubscriptionId> for ExchangeSub<Channel, Market>
 where
     Channel: AsRef<str>,
@@ -0,0 +1,79 @@
+use crate::{
+    event::MarketEvent,
+    subscription::{
+        book::{Level, OrderBook, OrderBookSide},
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Exchange, Side,
+};
+use chrono::{DateTime, Utc};
+
+/// [`DateTime<Utc>`] the provided number of seconds after the Unix epoch.
+pub fn secs(secs: i64) -> DateTime<Utc> {
+    DateTime::<Utc>::from_timestamp(secs, 0).unwrap()
+}
+
+/// [`DateTime<Utc>`] the provided number of milliseconds after the Unix epoch.
+pub fn millis(millis: i64) -> DateTime<Utc> {
+    DateTime::<Utc>::from_timestamp_millis(millis).unwrap()
+}
+
+/// [`MarketEvent<PublicTrade>`] of the "btc_usdt" spot [`Instrument`] on "exchange", exchanged &
+/// received at the provided time.
+///
+/// Events of other exchanges or [`Instrument`]s override the `exchange` & `instrument` fields
+/// via struct update syntax.
+pub fn trade(
+    time: DateTime<Utc>,
+    id: &str,
+    price: f64,
+    amount: f64,
+    side: Side,
+) -> MarketEvent<PublicTrade> {
+    event(
+        time,
+        PublicTrade {
+            id: id.to_string(),
+            price,
+            amount,
+            side,
+            conditions: vec![],
+        },
+    )
+}
+
+/// [`MarketEvent<OrderBook>`] of the "btc_usdt" spot [`Instrument`] on "exchange", last updated
+/// at the provided time with the provided `(price, amount)` bid & ask levels.
+///
+/// Events of other exchanges or [`Instrument`]s override the `exchange` & `instrument` fields
+/// via struct update syntax.
+pub fn book_event(
+    time: DateTime<Utc>,
+    bids: Vec<(f64, f64)>,
+    asks: Vec<(f64, f64)>,
+) -> MarketEvent<OrderBook> {
+    event(time, book(time, bids, asks))
+}
+
+/// [`OrderBook`] last updated at the provided time with the provided `(price, amount)` bid & ask
+/// levels, in the provided order.
+pub fn book(time: DateTime<Utc>, bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>) -> OrderBook {
+    OrderBook {
+        last_update_time: time,
+        bids: OrderBookSide::new(Side::Buy, bids.into_iter().map(Level::from)),
+        asks: OrderBookSide::new(Side::Sell, asks.into_iter().map(Level::from)),
+    }
+}
+
+fn event<T>(time: DateTime<Utc>, kind: T) -> MarketEvent<T> {
+    MarketEvent {
+        exchange_time: time,
+        received_time: time,
+        exchange: Exchange::from("exchange"),
+        instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)).into(),
+        kind,
+    }
+}
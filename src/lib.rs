@@ -84,19 +84,59 @@
 use crate::{
     error::DataError,
     event::MarketEvent,
-    exchange::{Connector, ExchangeId, PingInterval},
+    exchange::{
+        rate_limit::{OutboundRateLimit, RateLimiter},
+        subscription::ExchangeSub,
+        Connector, ExchangeId, PingInterval, StreamSelector,
+    },
+    protocol::{FrameContext, IdleTimeout, RawWebSocketParser, WebSocketParser, WsFrame},
     subscriber::Subscriber,
-    subscription::{SubKind, Subscription},
+    subscription::{intern::intern, resume::ResumeFrom, Map, SubKind, Subscription},
     transformer::ExchangeTransformer,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    protocol::websocket::{WebSocketParser, WsMessage, WsSink, WsStream},
+    model::{instrument::Instrument, SubscriptionId},
+    protocol::{
+        websocket::{WsError, WsMessage, WsStream},
+        StreamParser,
+    },
     ExchangeStream,
 };
 use futures::{SinkExt, Stream, StreamExt};
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tokio::sync::mpsc;
-use tracing::{debug, error};
+use tracing::{debug, error, warn};
+
+/// [`Adapter`](adapter::Adapter) implementations that derive analytics (eg/ rolling spread) from
+/// normalised [`MarketEvent<T>`](event::MarketEvent) streams.
+pub mod adapter;
+
+/// Tolerance based [`ApproxEq`](approx::ApproxEq) comparison of normalised data containing `f64`
+/// prices & quantities.
+pub mod approx;
+
+/// [`received_time`](clock::received_time) clock used to timestamp normalised
+/// [`MarketEvent<T>`](event::MarketEvent)s, and the opt-in coarse
+/// [`CachedClock`](clock::CachedClock).
+pub mod clock;
+
+/// [`Codec`](codec::Codec) serialisation formats (eg/ JSON, MessagePack, Bincode) used to
+/// encode & decode [`MarketEvent<T>`](event::MarketEvent)s for recording and forwarding.
+pub mod codec;
+
+/// Lossless [`Decimal`](rust_decimal::Decimal) representations of normalised prices &
+/// quantities, enabled by the `decimal` feature.
+#[cfg(feature = "decimal")]
+pub mod decimal;
+
+/// Deserialisation helpers shared by exchange specific data structures (eg/ numeric fields
+/// encoded as either strings or numbers).
+pub mod de;
 
 /// All [`Error`](std::error::Error)s generated in Barter-Data.
 pub mod error;
@@ -107,6 +147,21 @@ pub mod event;
 /// [`Connector`] implementations for each exchange.
 pub mod exchange;
 
+/// Normalised [`MarketEvent<T>`](event::MarketEvent) fixtures (eg/ trades & OrderBooks) shared
+/// by unit tests.
+#[cfg(test)]
+pub(crate) mod fixtures;
+
+/// Deterministic [`MockExchangeServer`](mock::MockExchangeServer) playing scripted exchange
+/// frames for integration testing, enabled by the `test-util` feature.
+#[cfg(any(test, feature = "test-util"))]
+pub mod mock;
+
+/// Barter-Data [`WebSocketParser`](protocol::WebSocketParser) that surfaces exchange WebSocket
+/// CloseFrame codes & reasons as a [`DataError::ConnectionClosed`](error::DataError), and the
+/// [`RawWebSocketParser`](protocol::RawWebSocketParser) used to forward raw text frames.
+pub mod protocol;
+
 /// High-level API types used for building [`MarketStream`]s from collections
 /// of Barter [`Subscription`]s.
 pub mod streams;
@@ -135,8 +190,16 @@ pub mod subscription;
 pub mod transformer;
 
 /// Convenient type alias for an [`ExchangeStream`] utilising a tungstenite
-/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
-pub type ExchangeWsStream<Transformer> = ExchangeStream<WebSocketParser, WsStream, Transformer>;
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket), which is recycled if idle
+/// for longer than the [`Connector::idle_timeout`] (see [`IdleTimeout`]).
+pub type ExchangeWsStream<Transformer> =
+    ExchangeStream<WebSocketParser, IdleTimeout<WsStream>, Transformer>;
+
+/// Convenient type alias for an [`ExchangeStream`] utilising a tungstenite
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) that passes each text frame
+/// to the [`Transformer`](barter_integration::Transformer) untouched (see [`RawWebSocketParser`]).
+pub type ExchangeRawWsStream<Transformer> =
+    ExchangeStream<RawWebSocketParser, IdleTimeout<WsStream>, Transformer>;
 
 /// Defines a generic identification type for the implementor.
 pub trait Identifier<T> {
@@ -155,11 +218,57 @@ where
     async fn init(subscriptions: &[Subscription<Exchange, Kind>]) -> Result<Self, DataError>
     where
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>;
+
+    /// Initialise [`Self`], resuming from the last-seen sequences in the provided [`ResumeFrom`]
+    /// where the exchange supports it (see
+    /// [`Connector::resume_requests`](exchange::Connector::resume_requests)).
+    ///
+    /// Defaults to [`Self::init`], meaning the exchange streams are not resumed.
+    async fn init_from(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        _: &ResumeFrom,
+    ) -> Result<Self, DataError>
+    where
+        Exchange: Sync,
+        Kind: Sync,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        Self::init(subscriptions).await
+    }
+
+    /// Begin gracefully closing the connection of [`Self`], sending the provided `requests` (eg/
+    /// exchange unsubscribe requests) followed by a WebSocket CloseFrame to the exchange. [`Self`]
+    /// then ends once the exchange acknowledges the CloseFrame.
+    ///
+    /// Returns `false` if [`Self`] cannot close its connection gracefully, in which case it
+    /// should simply be dropped. Defaults to `false`.
+    fn close(&mut self, _requests: Vec<WsMessage>) -> bool {
+        false
+    }
+
+    /// Subscribe to the `added` & unsubscribe from the `removed` [`Subscription`]s over the open
+    /// connection of [`Self`], without re-initialising it.
+    ///
+    /// Returns `false` if [`Self`] cannot update its [`Subscription`]s live (eg/ the exchange does
+    /// not support unsubscribing, or an added OrderBook requires a snapshot), in which case
+    /// [`Self`] should be re-initialised with the target [`Subscription`]s. Defaults to `false`.
+    fn update_subscriptions(
+        &mut self,
+        _added: &[Subscription<Exchange, Kind>],
+        _removed: &[Subscription<Exchange, Kind>],
+    ) -> bool
+    where
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        false
+    }
 }
 
 #[async_trait]
-impl<Exchange, Kind, Transformer> MarketStream<Exchange, Kind> for ExchangeWsStream<Transformer>
+impl<Exchange, Kind, Parser, Transformer> MarketStream<Exchange, Kind>
+    for ExchangeStream<Parser, IdleTimeout<WsStream>, Transformer>
 where
+    Parser: StreamParser<Message = WsFrame, Error = WsError> + Send,
     Exchange: Connector + Send + Sync,
     Kind: SubKind + Send + Sync,
     Transformer: ExchangeTransformer<Exchange, Kind> + Send,
@@ -169,48 +278,244 @@ where
     where
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
-        // Connect & subscribe
-        let (websocket, map) = Exchange::Subscriber::subscribe(subscriptions).await?;
+        Self::init_from(subscriptions, &ResumeFrom::default()).await
+    }
 
-        // Split WebSocket into WsStream & WsSink components
-        let (ws_sink, ws_stream) = websocket.split();
+    async fn init_from(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        resume: &ResumeFrom,
+    ) -> Result<Self, DataError>
+    where
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        connect(subscriptions, resume)
+            .await
+            .map(|(stream, _map)| stream)
+    }
 
-        // Spawn task to distribute Transformer messages (eg/ custom pongs) to the exchange
-        let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
-        tokio::spawn(distribute_messages_to_exchange(
-            Exchange::ID,
-            ws_sink,
-            ws_sink_rx,
-        ));
+    fn close(&mut self, requests: Vec<WsMessage>) -> bool {
+        self.stream.close(requests)
+    }
+
+    fn update_subscriptions(
+        &mut self,
+        added: &[Subscription<Exchange, Kind>],
+        removed: &[Subscription<Exchange, Kind>],
+    ) -> bool
+    where
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let exchange_subs = |subscriptions: &[Subscription<Exchange, Kind>]| {
+            subscriptions
+                .iter()
+                .map(ExchangeSub::<Exchange::Channel, Exchange::Market>::new)
+                .collect::<Vec<_>>()
+        };
 
-        // Spawn optional task to distribute custom application-level pings to the exchange
-        if let Some(ping_interval) = Exchange::ping_interval() {
-            tokio::spawn(schedule_pings_to_exchange(
-                Exchange::ID,
-                ws_sink_tx.clone(),
-                ping_interval,
-            ));
+        // Removed Subscriptions can only be updated live if the exchange supports unsubscribing
+        let removed_ids = exchange_subs(removed)
+            .iter()
+            .map(Identifier::<SubscriptionId>::id)
+            .collect::<Vec<_>>();
+        let unsubscribe = match removed.is_empty() {
+            true => vec![],
+            false => match Exchange::unsubscribe_requests(exchange_subs(removed)) {
+                requests if requests.is_empty() => return false,
+                requests => requests,
+            },
+        };
+
+        // Route messages of added Subscriptions before the exchange starts sending them
+        let added_routes = added
+            .iter()
+            .map(|subscription| {
+                (
+                    ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription).id(),
+                    intern(&subscription.instrument),
+                )
+            })
+            .collect();
+        if !self.transformer.reroute(added_routes, &removed_ids) {
+            return false;
         }
 
-        // Construct Transformer associated with this Exchange and SubKind
-        let transformer = Transformer::new(ws_sink_tx, map).await?;
+        let subscribe = match added.is_empty() {
+            true => vec![],
+            false => Exchange::requests(exchange_subs(added)),
+        };
+        self.stream
+            .send(unsubscribe.into_iter().chain(subscribe).collect())
+    }
+}
+
+/// Connect & subscribe to the provided [`Subscription`]s, returning the [`ExchangeWsStream`]
+/// selected by the exchange [`StreamSelector`], alongside the [`Map`] of every [`SubscriptionId`]
+/// the exchange uses to identify the messages of the [`Subscription`]s to the associated
+/// [`Instrument`].
+///
+/// Useful for custom routing & debugging (eg/ pre-allocating per-instrument buffers keyed by the
+/// exact [`SubscriptionId`]s), since the [`Map`] is otherwise owned by the
+/// [`ExchangeTransformer`].
+///
+/// [`SubscriptionId`]: barter_integration::model::SubscriptionId
+pub async fn init<Exchange, Kind, Transformer>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+) -> Result<(ExchangeWsStream<Transformer>, Map<Arc<Instrument>>), DataError>
+where
+    Exchange: StreamSelector<Kind, Stream = ExchangeWsStream<Transformer>> + Send + Sync,
+    Kind: SubKind + Send + Sync,
+    Transformer: ExchangeTransformer<Exchange, Kind> + Send,
+    Kind::Event: Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    connect(subscriptions, &ResumeFrom::default()).await
+}
 
-        Ok(ExchangeWsStream::new(ws_stream, transformer))
+/// Connect & subscribe to the provided [`Subscription`]s, resuming from any last-seen sequences,
+/// returning the [`ExchangeStream`] and the [`Map`] of each [`Subscription`] the
+/// [`ExchangeTransformer`] was constructed with.
+///
+/// Further [`WsMessage`]s (eg/ live subscription requests) are sent to the exchange over the same
+/// connection via the [`IdleTimeout`] of the [`ExchangeStream`] (see [`IdleTimeout::send`]).
+pub(crate) async fn connect<Exchange, Kind, Parser, Transformer>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+    resume: &ResumeFrom,
+) -> Result<
+    (
+        ExchangeStream<Parser, IdleTimeout<WsStream>, Transformer>,
+        Map<Arc<Instrument>>,
+    ),
+    DataError,
+>
+where
+    Parser: StreamParser<Message = WsFrame, Error = WsError> + Send,
+    Exchange: Connector + Send + Sync,
+    Kind: SubKind + Send + Sync,
+    Transformer: ExchangeTransformer<Exchange, Kind> + Send,
+    Kind::Event: Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    // Connect & subscribe, resuming from any last-seen sequences
+    let (websocket, map) = Exchange::Subscriber::subscribe(subscriptions, resume).await?;
+
+    // Split WebSocket into WsStream & WsSink components
+    let (ws_sink, ws_stream) = websocket.split();
+
+    // Spawn task to distribute data messages (eg/ live subscribe requests) & prioritised control
+    // messages (eg/ custom pings & Transformer custom pongs) to the exchange
+    let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
+    let (control_tx, control_rx) = mpsc::unbounded_channel();
+    tokio::spawn(distribute_messages_to_exchange(
+        Exchange::ID,
+        ws_sink,
+        control_rx,
+        ws_sink_rx,
+        Exchange::outbound_rate_limit(),
+    ));
+
+    // Spawn optional task to distribute custom application-level pings to the exchange
+    if let Some(ping_interval) = Exchange::ping_interval() {
+        tokio::spawn(schedule_pings_to_exchange(
+            Exchange::ID,
+            control_tx.clone(),
+            ping_interval,
+        ));
     }
+
+    // Construct Transformer associated with this Exchange and SubKind, sending any messages
+    // (eg/ custom pongs) via the prioritised control channel
+    let transformer =
+        Transformer::from_subscriptions(control_tx, map.clone(), subscriptions).await?;
+
+    Ok((
+        ExchangeStream::new(
+            IdleTimeout::new(ws_stream, Exchange::idle_timeout())
+                .with_outbound(ws_sink_tx)
+                .with_context(FrameContext::new(Exchange::ID, &map)),
+            transformer,
+        ),
+        map,
+    ))
 }
 
-/// Transmit [`WsMessage`]s sent from the [`ExchangeTransformer`] to the exchange via
-/// the [`WsSink`].
+/// Number of queued outbound [`WsMessage`]s at which the outbound queue is considered backed up,
+/// and a warning is logged.
+pub const OUTBOUND_BACKLOG_WARN_THRESHOLD: usize = 100;
+
+/// Duration after which sending a single outbound [`WsMessage`] is considered slow, and a warning
+/// is logged.
+pub const OUTBOUND_SLOW_SEND_WARN: Duration = Duration::from_secs(1);
+
+/// Transmit [`WsMessage`]s sent from the [`ExchangeTransformer`], the custom ping scheduler and
+/// the connection owner to the exchange via the
+/// [`WsSink`](barter_integration::protocol::websocket::WsSink).
 ///
-/// **Note:**
-/// ExchangeTransformer is operating in a synchronous trait context so we use this separate task
-/// to avoid adding `#[\async_trait\]` to the transformer - this avoids allocations.
-pub async fn distribute_messages_to_exchange(
+/// **Notes:**
+/// - ExchangeTransformer is operating in a synchronous trait context so we use this separate task
+///   to avoid adding `#[\async_trait\]` to the transformer - this avoids allocations.
+/// - Outbound messages are queued rather than dropped, so reading is never blocked by a slow
+///   sink. If the queue backs up, control frames (pings & pongs) and the messages received via
+///   `control_rx` (custom application-level pings, and [`ExchangeTransformer`] messages such as
+///   custom pongs) are sent before any other queued message, preventing the exchange from timing
+///   out the connection.
+/// - If an [`OutboundRateLimit`] is provided, every outbound message (including control frames)
+///   is paced by a token bucket [`RateLimiter`], with bursts queued rather than dropped.
+pub async fn distribute_messages_to_exchange<Sink>(
     exchange: ExchangeId,
-    mut ws_sink: WsSink,
+    mut ws_sink: Sink,
+    mut control_rx: mpsc::UnboundedReceiver<WsMessage>,
     mut ws_sink_rx: mpsc::UnboundedReceiver<WsMessage>,
-) {
-    while let Some(message) = ws_sink_rx.recv().await {
+    rate_limit: Option<OutboundRateLimit>,
+) where
+    Sink: futures::Sink<WsMessage, Error = WsError> + Unpin,
+{
+    let mut queue = OutboundQueue::default();
+    let mut backed_up = false;
+    let mut limiter = rate_limit.map(RateLimiter::new);
+
+    loop {
+        // Wait for the next outbound message if none are queued
+        if queue.is_empty() {
+            tokio::select! {
+                biased;
+                Some(message) = control_rx.recv() => queue.control.push_back(message),
+                Some(message) = ws_sink_rx.recv() => queue.push(message),
+                else => break,
+            }
+        }
+
+        // Wait until the OutboundRateLimit allows the next message to be sent
+        if let Some(limiter) = &mut limiter {
+            limiter.acquire().await;
+        }
+
+        // Queue any further messages that arrived whilst the previous message was being sent
+        while let Ok(message) = control_rx.try_recv() {
+            queue.control.push_back(message);
+        }
+        while let Ok(message) = ws_sink_rx.try_recv() {
+            queue.push(message);
+        }
+
+        // Surface a backed up outbound queue, rather than silently falling behind
+        match (backed_up, queue.len() >= OUTBOUND_BACKLOG_WARN_THRESHOLD) {
+            (false, true) => {
+                backed_up = true;
+                warn!(
+                    %exchange,
+                    queued = queue.len(),
+                    "outbound WsMessage queue is backed up, prioritising control frames"
+                );
+            }
+            (true, false) => backed_up = false,
+            _ => {}
+        }
+
+        let Some(message) = queue.pop() else {
+            continue;
+        };
+
+        let started = Instant::now();
         if let Err(error) = ws_sink.send(message).await {
             if barter_integration::protocol::websocket::is_websocket_disconnected(&error) {
                 break;
@@ -223,6 +528,47 @@ pub async fn distribute_messages_to_exchange(
                 "failed to send  output message to the exchange via WsSink"
             );
         }
+
+        let elapsed = started.elapsed();
+        if elapsed >= OUTBOUND_SLOW_SEND_WARN {
+            warn!(
+                %exchange,
+                ?elapsed,
+                queued = queue.len(),
+                "slow WsSink: sending outbound WsMessage to the exchange stalled"
+            );
+        }
+    }
+}
+
+/// Outbound [`WsMessage`] queue that yields control frames before any other message.
+///
+/// A CloseFrame is queued behind the data messages sent before it (eg/ unsubscribe requests), so
+/// they are not discarded by closing the connection first.
+#[derive(Debug, Default)]
+struct OutboundQueue {
+    control: VecDeque<WsMessage>,
+    data: VecDeque<WsMessage>,
+}
+
+impl OutboundQueue {
+    fn push(&mut self, message: WsMessage) {
+        match message {
+            WsMessage::Ping(_) | WsMessage::Pong(_) => self.control.push_back(message),
+            message => self.data.push_back(message),
+        }
+    }
+
+    fn pop(&mut self) -> Option<WsMessage> {
+        self.control.pop_front().or_else(|| self.data.pop_front())
+    }
+
+    fn len(&self) -> usize {
+        self.control.len() + self.data.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.control.is_empty() && self.data.is_empty()
     }
 }
 
@@ -232,9 +578,10 @@ pub async fn distribute_messages_to_exchange(
 /// **Notes:**
 ///  - This is only used for those exchanges that require custom application-level pings.
 ///  - This is additional to the protocol-level pings already handled by `tokio_tungstenite`.
+///  - Pings are sent via the prioritised `control_tx` of [`distribute_messages_to_exchange`].
 pub async fn schedule_pings_to_exchange(
     exchange: ExchangeId,
-    ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+    control_tx: mpsc::UnboundedSender<WsMessage>,
     PingInterval { mut interval, ping }: PingInterval,
 ) {
     loop {
@@ -245,8 +592,254 @@ pub async fn schedule_pings_to_exchange(
         let payload = ping();
         debug!(%exchange, %payload, "sending custom application-level ping to exchange");
 
-        if ws_sink_tx.send(payload).is_err() {
+        if control_tx.send(payload).is_err() {
             break;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{
+            binance::{
+                channel::BinanceChannel, spot::BinanceSpot, subscription::BinanceSubResponse,
+                trade::BinanceTrade,
+            },
+            SelectedStream,
+        },
+        subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+        subscription::trade::{PublicTrade, PublicTrades},
+        transformer::stateless::StatelessTransformer,
+    };
+    use std::{any::TypeId, sync::Mutex};
+
+    #[test]
+    fn test_selected_stream_is_concrete() {
+        fn assert_unboxed_market_stream<St>()
+        where
+            St: MarketStream<BinanceSpot, PublicTrades>
+                + Stream<Item = Result<MarketEvent<PublicTrade>, DataError>>
+                + Unpin
+                + Send
+                + 'static,
+        {
+        }
+
+        // Statically selected MarketStream is usable as a Stream without a Box
+        assert_unboxed_market_stream::<SelectedStream<BinanceSpot, PublicTrades>>();
+
+        // Statically selected MarketStream is the named ExchangeWsStream, not a boxed Stream
+        assert_eq!(
+            TypeId::of::<SelectedStream<BinanceSpot, PublicTrades>>(),
+            TypeId::of::<
+                ExchangeWsStream<StatelessTransformer<BinanceSpot, PublicTrades, BinanceTrade>>,
+            >()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_distribute_messages_to_exchange_prioritises_control_frames() {
+        // Slow WsSink that records every WsMessage it sends
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let ws_sink = Box::pin(futures::sink::unfold(
+            Arc::clone(&sent),
+            |sent, message: WsMessage| async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                sent.lock().unwrap().push(message);
+                Ok::<_, WsError>(sent)
+            },
+        ));
+
+        let (control_tx, control_rx) = mpsc::unbounded_channel();
+        let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
+        let distributor = tokio::spawn(distribute_messages_to_exchange(
+            ExchangeId::Okx,
+            ws_sink,
+            control_rx,
+            ws_sink_rx,
+            None,
+        ));
+
+        // Back up the outbound queue, then send a pong & a custom application-level ping
+        for index in 0..20 {
+            ws_sink_tx.send(WsMessage::text(index.to_string())).unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        ws_sink_tx.send(WsMessage::Pong(vec![1])).unwrap();
+        control_tx.send(WsMessage::text("ping")).unwrap();
+
+        drop(ws_sink_tx);
+        drop(control_tx);
+        distributor.await.unwrap();
+
+        let sent = sent.lock().unwrap();
+
+        // No WsMessage is dropped
+        assert_eq!(sent.len(), 22);
+
+        // Control frames jump the backed up queue, sent right after the in-flight WsMessage
+        let position = |target: &WsMessage| sent.iter().position(|message| message == target);
+        assert!(position(&WsMessage::text("ping")).unwrap() <= 2);
+        assert!(position(&WsMessage::Pong(vec![1])).unwrap() <= 2);
+    }
+
+    #[tokio::test]
+    async fn test_distribute_messages_to_exchange_paces_outbound_rate_limit() {
+        // Instant WsSink that records every WsMessage it sends
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let ws_sink = Box::pin(futures::sink::unfold(
+            Arc::clone(&sent),
+            |sent, message: WsMessage| async move {
+                sent.lock().unwrap().push(message);
+                Ok::<_, WsError>(sent)
+            },
+        ));
+
+        // Allow a burst of 5 WsMessages, refilled at 50 WsMessages per second
+        let (_control_tx, control_rx) = mpsc::unbounded_channel();
+        let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
+        let started = Instant::now();
+        let distributor = tokio::spawn(distribute_messages_to_exchange(
+            ExchangeId::BinanceSpot,
+            ws_sink,
+            control_rx,
+            ws_sink_rx,
+            Some(OutboundRateLimit::new(5, Duration::from_millis(100))),
+        ));
+
+        // Send 3x the burst limit in one go
+        let messages = (0..15)
+            .map(|index| WsMessage::text(index.to_string()))
+            .collect::<Vec<_>>();
+        for message in &messages {
+            ws_sink_tx.send(message.clone()).unwrap();
+        }
+        drop(ws_sink_tx);
+
+        // Wait until every WsMessage has been sent
+        while sent.lock().unwrap().len() < messages.len() {
+            assert!(
+                started.elapsed() < Duration::from_secs(5),
+                "outbound WsMessages were dropped"
+            );
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        let elapsed = started.elapsed();
+        distributor.abort();
+
+        // The 10 WsMessages exceeding the burst are paced at 50 WsMessages per second
+        assert!(
+            elapsed >= Duration::from_millis(180),
+            "WsMessages were not paced: {elapsed:?}"
+        );
+
+        // No WsMessage is dropped or re-ordered
+        assert_eq!(*sent.lock().unwrap(), messages);
+    }
+
+    /// Address of the mock WebSocket server of the [`MockExchange`].
+    static MOCK_URL: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+
+    /// [`Connector`] of a mock WebSocket server that accepts every subscription.
+    #[derive(
+        Copy,
+        Clone,
+        Eq,
+        PartialEq,
+        Ord,
+        PartialOrd,
+        Debug,
+        Default,
+        serde::Deserialize,
+        serde::Serialize,
+    )]
+    struct MockExchange;
+
+    impl Connector for MockExchange {
+        const ID: ExchangeId = ExchangeId::BinanceSpot;
+        type Channel = BinanceChannel;
+        type Market = String;
+        type Subscriber = WebSocketSubscriber;
+        type SubValidator = WebSocketSubValidator;
+        type SubResponse = BinanceSubResponse;
+
+        fn url() -> Result<url::Url, barter_integration::error::SocketError> {
+            url::Url::parse(MOCK_URL.get().unwrap())
+                .map_err(barter_integration::error::SocketError::UrlParse)
+        }
+
+        fn requests(
+            _: Vec<exchange::subscription::ExchangeSub<Self::Channel, Self::Market>>,
+        ) -> Vec<WsMessage> {
+            vec![WsMessage::Text("subscribe".to_string())]
+        }
+
+        fn expected_responses(_: &Map<Arc<Instrument>>) -> usize {
+            0
+        }
+    }
+
+    impl Identifier<BinanceChannel> for Subscription<MockExchange, PublicTrades> {
+        fn id(&self) -> BinanceChannel {
+            BinanceChannel::TRADES
+        }
+    }
+
+    impl Identifier<String> for Subscription<MockExchange, PublicTrades> {
+        fn id(&self) -> String {
+            format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase()
+        }
+    }
+
+    impl StreamSelector<PublicTrades> for MockExchange {
+        type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BinanceTrade>>;
+    }
+
+    #[tokio::test]
+    async fn test_init_returns_subscription_id_map() {
+        use barter_integration::model::{instrument::kind::InstrumentKind, SubscriptionId};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        MOCK_URL
+            .set(format!("ws://{}", listener.local_addr().unwrap()))
+            .unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            while let Some(Ok(_)) = websocket.next().await {}
+        });
+
+        let subscriptions = vec![
+            Subscription::from((
+                MockExchange,
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            )),
+            Subscription::from((
+                MockExchange,
+                "eth",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            )),
+        ];
+
+        let (_stream, map) = init(&subscriptions).await.unwrap();
+
+        // One entry per Subscription, keyed by the exact SubscriptionId the exchange uses
+        assert_eq!(map.0.len(), subscriptions.len());
+        for (id, subscription) in [
+            ("@trade|BTCUSDT", &subscriptions[0]),
+            ("@trade|ETHUSDT", &subscriptions[1]),
+        ] {
+            assert_eq!(
+                map.find(&SubscriptionId::from(id)).unwrap().as_ref(),
+                &subscription.instrument
+            );
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use barter_integration::{error::SocketError, protocol::websocket::WsError};
+use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    time::Duration,
+};
+use tokio::{net::TcpListener, task::JoinHandle};
+use tokio_tungstenite::{tungstenite::Message, WebSocketStream};
+use url::Url;
+
+/// Text frame sent by a [`MockStep::Malformed`], which fails to deserialise as any exchange
+/// message.
+pub const MALFORMED_FRAME: &str = r#"{"e":"trade","s":"#;
+
+/// Scripted step of a [`MockExchangeServer`] fixture.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum MockStep {
+    /// Send the provided exchange message (eg/ a Binance trade or book update) as a text frame.
+    Frame(String),
+    /// Send the [`MALFORMED_FRAME`].
+    Malformed,
+    /// Drop the connection without a CloseFrame. The next connection accepted by the
+    /// [`MockExchangeServer`] resumes the fixture from the following [`MockStep`].
+    Disconnect,
+    /// Wait for the provided [`Duration`] before the following [`MockStep`].
+    Delay(Duration),
+}
+
+impl MockStep {
+    /// [`MockStep::Frame`] of a Binance trade with the provided symbol (eg/ "BTCUSDT"), trade id
+    /// & price.
+    pub fn trade(symbol: &str, id: u64, price: f64) -> Self {
+        Self::Frame(format!(
+            r#"{{"e":"trade","E":1649324825173,"s":"{symbol}","t":{id},"p":"{price}","q":"1.0","b":1,"a":2,"T":1649324825173,"m":false,"M":true}}"#
+        ))
+    }
+}
+
+/// Local WebSocket server that accepts the Binance subscribe protocol, and then plays a scripted
+/// fixture of [`MockStep`]s, so a real [`ExchangeWsStream`](crate::ExchangeWsStream) can be tested
+/// deterministically.
+///
+/// ### Notes
+/// - Each connection must send a `{"method": "SUBSCRIBE", ..}` request before the fixture is
+///   played, which is acknowledged with `{"result": null, "id": ..}`.
+/// - Connect to [`Self`] by initialising connections within
+///   [`with_base_url`](crate::exchange::with_base_url) (or a
+///   [`StreamBuilder::with_base_url`](crate::streams::builder::StreamBuilder::with_base_url)) of
+///   [`Self::url`], so parallel tests never redirect each other's connections.
+/// - Once the fixture is exhausted the connection stays open until the client closes it.
+#[derive(Debug)]
+pub struct MockExchangeServer {
+    address: SocketAddr,
+    connections: Arc<AtomicUsize>,
+    subscriptions: Arc<Mutex<Vec<String>>>,
+    task: JoinHandle<()>,
+}
+
+/// Binance `method` request (eg/ "SUBSCRIBE") received by a [`MockExchangeServer`].
+#[derive(Deserialize)]
+struct MockRequest {
+    method: String,
+    #[serde(default)]
+    params: Vec<String>,
+    id: serde_json::Value,
+}
+
+impl MockExchangeServer {
+    /// Start a [`MockExchangeServer`] on a random local port that plays the provided fixture.
+    pub async fn start<Fixture>(fixture: Fixture) -> Result<Self, SocketError>
+    where
+        Fixture: IntoIterator<Item = MockStep>,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|error| SocketError::WebSocket(WsError::Io(error)))?;
+        let address = listener
+            .local_addr()
+            .map_err(|error| SocketError::WebSocket(WsError::Io(error)))?;
+
+        let connections = Arc::new(AtomicUsize::new(0));
+        let subscriptions = Arc::new(Mutex::new(Vec::new()));
+        let task = tokio::spawn(serve(
+            listener,
+            fixture.into_iter().collect(),
+            Arc::clone(&connections),
+            Arc::clone(&subscriptions),
+        ));
+
+        Ok(Self {
+            address,
+            connections,
+            subscriptions,
+            task,
+        })
+    }
+
+    /// [`SocketAddr`] [`Self`] is listening on.
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// WebSocket [`Url`] of [`Self`].
+    pub fn url(&self) -> Url {
+        Url::parse(&format!("ws://{}", self.address)).expect("mock server url is valid")
+    }
+
+    /// Number of connections accepted so far.
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::Relaxed)
+    }
+
+    /// Stream names (eg/ "btcusdt@trade") of every "SUBSCRIBE" request received so far.
+    pub fn subscriptions(&self) -> Vec<String> {
+        self.subscriptions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl Drop for MockExchangeServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Accept connections sequentially, playing the remaining fixture over each one.
+async fn serve(
+    listener: TcpListener,
+    fixture: Vec<MockStep>,
+    connections: Arc<AtomicUsize>,
+    subscriptions: Arc<Mutex<Vec<String>>>,
+) {
+    let mut steps = fixture.into_iter();
+
+    'accept: while let Ok((stream, _)) = listener.accept().await {
+        let Ok(mut websocket) = tokio_tungstenite::accept_async(stream).await else {
+            continue;
+        };
+        connections.fetch_add(1, Ordering::Relaxed);
+
+        // Await the subscription request before playing the fixture
+        loop {
+            match websocket.next().await {
+                Some(Ok(Message::Text(text))) => {
+                    if respond(&mut websocket, &text, &subscriptions).await {
+                        break;
+                    }
+                }
+                Some(Ok(_)) => continue,
+                _ => continue 'accept,
+            }
+        }
+
+        for step in steps.by_ref() {
+            let sent = match step {
+                MockStep::Frame(frame) => websocket.send(Message::Text(frame)).await,
+                MockStep::Malformed => {
+                    websocket
+                        .send(Message::Text(MALFORMED_FRAME.to_string()))
+                        .await
+                }
+                MockStep::Delay(duration) => {
+                    tokio::time::sleep(duration).await;
+                    Ok(())
+                }
+                MockStep::Disconnect => continue 'accept,
+            };
+            if sent.is_err() {
+                continue 'accept;
+            }
+        }
+
+        // Fixture is exhausted, so acknowledge any further requests until the client closes
+        while let Some(Ok(message)) = websocket.next().await {
+            if let Message::Text(text) = message {
+                respond(&mut websocket, &text, &subscriptions).await;
+            }
+        }
+    }
+}
+
+/// Acknowledge a Binance `method` request, returning `true` if it was a "SUBSCRIBE" request.
+async fn respond<S>(
+    websocket: &mut WebSocketStream<S>,
+    text: &str,
+    subscriptions: &Mutex<Vec<String>>,
+) -> bool
+where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    let Ok(request) = serde_json::from_str::<MockRequest>(text) else {
+        return false;
+    };
+
+    let subscribe = request.method == "SUBSCRIBE";
+    if subscribe {
+        subscriptions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .extend(request.params);
+    }
+
+    let response = serde_json::json!({ "result": null, "id": request.id }).to_string();
+    let _ = websocket.send(Message::Text(response)).await;
+    subscribe
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::DataError,
+        exchange::{binance::spot::BinanceSpot, with_base_url, ExchangeId, StreamSelector},
+        subscription::{trade::PublicTrades, Subscription},
+        MarketStream,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[tokio::test]
+    async fn test_mock_exchange_server_plays_scripted_trades() {
+        let server = MockExchangeServer::start([
+            MockStep::trade("BTCUSDT", 1, 100.0),
+            MockStep::Delay(Duration::from_millis(10)),
+            MockStep::trade("BTCUSDT", 2, 101.0),
+            MockStep::Malformed,
+            MockStep::trade("BTCUSDT", 3, 102.0),
+            MockStep::Disconnect,
+            MockStep::trade("BTCUSDT", 4, 103.0),
+        ])
+        .await
+        .unwrap();
+
+        let subscriptions = [Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))];
+
+        // First connection plays the fixture up to the Disconnect, in order
+        let mut stream = with_base_url(
+            Some(server.url()),
+            <BinanceSpot as StreamSelector<PublicTrades>>::Stream::init(&subscriptions),
+        )
+        .await
+        .unwrap();
+        assert_eq!(server.subscriptions(), vec!["btcusdt@trade".to_string()]);
+
+        let mut actual = Vec::new();
+        while let Some(result) = stream.next().await {
+            match result {
+                Ok(event) => actual.push((event.kind.id, event.kind.price)),
+                Err(DataError::RawFrame(error)) => {
+                    assert_eq!(error.exchange, ExchangeId::BinanceSpot);
+                    assert_eq!(error.raw, MALFORMED_FRAME);
+                    actual.push(("malformed".to_string(), 0.0));
+                }
+                Err(error) if error.is_terminal() => break,
+                Err(_) => {}
+            }
+        }
+        assert_eq!(
+            actual,
+            vec![
+                ("1".to_string(), 100.0),
+                ("2".to_string(), 101.0),
+                ("malformed".to_string(), 0.0),
+                ("3".to_string(), 102.0),
+            ]
+        );
+
+        // Re-connection resumes the fixture after the Disconnect
+        let mut stream = with_base_url(
+            Some(server.url()),
+            <BinanceSpot as StreamSelector<PublicTrades>>::Stream::init(&subscriptions),
+        )
+        .await
+        .unwrap();
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!((event.kind.id.as_str(), event.kind.price), ("4", 103.0));
+        assert_eq!(server.connections(), 2);
+    }
+}
@@ -0,0 +1,870 @@
+use crate::{exchange::ExchangeId, subscription::Map};
+use barter_integration::{
+    error::SocketError,
+    model::SubscriptionId,
+    protocol::{
+        websocket::{self, WebSocket, WsError, WsMessage},
+        StreamParser,
+    },
+};
+use futures::{Stream, StreamExt};
+use serde::{
+    de::{DeserializeOwned, Expected, Unexpected, Visitor},
+    forward_to_deserialize_any, Deserialize, Deserializer, Serialize,
+};
+use std::{
+    fmt::{Display, Formatter},
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{
+    sync::mpsc,
+    time::{Instant, Sleep},
+};
+use tracing::{debug, trace, warn};
+
+/// Prefix of the [`SocketError::Terminated`] message used to communicate the code & reason of a
+/// received WebSocket CloseFrame.
+///
+/// eg/ "CloseFrame|1008|rate limit exceeded"
+pub const CLOSE_FRAME_PREFIX: &str = "CloseFrame";
+
+/// WebSocket close code used when a CloseFrame is received without a status code.
+///
+/// See RFC 6455: <https://www.rfc-editor.org/rfc/rfc6455#section-7.4.1>
+pub const CLOSE_CODE_NO_STATUS: u16 = 1005;
+
+/// Maximum number of bytes of a raw frame included in the `warn` level event logged when the
+/// frame fails to deserialise. The full frame is logged at `trace` level.
+pub const MAX_LOGGED_FRAME_LEN: usize = 256;
+
+/// Prefix of the encoded [`SocketError::Deserialise`] payload used to communicate the
+/// [`FrameContext`] & raw text of a frame that failed to deserialise.
+///
+/// The barter-integration [`ExchangeStream`](barter_integration::ExchangeStream) converts every
+/// parser error into a [`DataError`](crate::error::DataError) via a [`SocketError`], so the
+/// [`FrameContext`] is encoded into its payload, but only once a frame fails to deserialise.
+pub const RAW_FRAME_PREFIX: &str = "RawFrame";
+
+/// Default maximum number of bytes of a raw frame attached to a
+/// [`RawFrameError`](crate::error::RawFrameError), see [`with_max_raw_frame_len`].
+pub const DEFAULT_MAX_RAW_FRAME_LEN: usize = 4096;
+
+tokio::task_local! {
+    /// Per-connection maximum raw frame length of the current task, see
+    /// [`with_max_raw_frame_len`].
+    static MAX_RAW_FRAME_LEN: Option<usize>;
+}
+
+/// Drive the provided [`Future`] (eg/ [`MarketStream::init`](crate::MarketStream::init)) with a
+/// per-connection maximum number of bytes of a raw frame attached to a
+/// [`RawFrameError`](crate::error::RawFrameError) by any connection it establishes (defaults to
+/// [`DEFAULT_MAX_RAW_FRAME_LEN`]).
+pub async fn with_max_raw_frame_len<Fut>(len: Option<usize>, future: Fut) -> Fut::Output
+where
+    Fut: Future,
+{
+    MAX_RAW_FRAME_LEN.scope(len, future).await
+}
+
+/// Maximum number of bytes of a raw frame attached to a
+/// [`RawFrameError`](crate::error::RawFrameError) by new connections, which is the
+/// per-connection [`with_max_raw_frame_len`] override if one is set, otherwise the
+/// [`DEFAULT_MAX_RAW_FRAME_LEN`].
+pub fn max_raw_frame_len() -> usize {
+    MAX_RAW_FRAME_LEN
+        .try_with(|len| *len)
+        .ok()
+        .flatten()
+        .unwrap_or(DEFAULT_MAX_RAW_FRAME_LEN)
+}
+
+/// Exchange connection context attached to a [`RawFrameError`](crate::error::RawFrameError)
+/// when one of its frames fails to deserialise.
+///
+/// The `max_raw_frame_len` is only used to truncate the raw frame attached to the
+/// [`RawFrameError`](crate::error::RawFrameError), so it is not (de)serialised.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct FrameContext {
+    pub exchange: ExchangeId,
+    pub subscription_id: Option<SubscriptionId>,
+    #[serde(skip, default = "max_raw_frame_len")]
+    pub max_raw_frame_len: usize,
+}
+
+impl FrameContext {
+    /// Construct the [`FrameContext`] of a connection with the provided [`Map`] of
+    /// [`SubscriptionId`]s, truncating raw frames to the current [`max_raw_frame_len`].
+    ///
+    /// The [`SubscriptionId`] is only known if the connection carries exactly one subscription,
+    /// since a frame that fails to deserialise cannot be routed.
+    pub fn new<T>(exchange: ExchangeId, map: &Map<T>) -> Self {
+        let mut subscription_ids = map.0.keys();
+        let subscription_id = match (subscription_ids.next(), subscription_ids.next()) {
+            (Some(subscription_id), None) => Some(subscription_id.clone()),
+            _ => None,
+        };
+
+        Self {
+            exchange,
+            subscription_id,
+            max_raw_frame_len: max_raw_frame_len(),
+        }
+    }
+}
+
+/// [`WsMessage`] yielded by an [`IdleTimeout`], alongside the shared [`FrameContext`] of the
+/// connection it was received on (if any).
+#[derive(Clone, PartialEq, Debug)]
+pub struct WsFrame {
+    pub message: WsMessage,
+    pub context: Option<Arc<FrameContext>>,
+}
+
+impl From<WsMessage> for WsFrame {
+    fn from(message: WsMessage) -> Self {
+        Self {
+            message,
+            context: None,
+        }
+    }
+}
+
+/// [`StreamParser`] implementation for a [`WebSocket`] that retains the code & reason of any
+/// received CloseFrame.
+///
+/// Behaves identically to the barter-integration
+/// [`WebSocketParser`](barter_integration::protocol::websocket::WebSocketParser), except that a
+/// CloseFrame is surfaced as an encoded [`SocketError::Terminated`], which converts into a
+/// [`DataError::ConnectionClosed`](crate::error::DataError::ConnectionClosed).
+///
+/// Every text frame is logged at `trace` level, and a frame that fails to deserialise is logged
+/// at `warn` level with its payload truncated to [`MAX_LOGGED_FRAME_LEN`]. When parsed within the
+/// [`consume`](crate::streams::consumer::consume) loop, these events are recorded within the
+/// "connection" span carrying the [`ExchangeId`](crate::exchange::ExchangeId) & the
+/// [`SubscriptionId`](barter_integration::model::SubscriptionId)s of the connection.
+///
+/// If the [`WsFrame`] carries a [`FrameContext`] (as does every [`WsFrame`] yielded by the
+/// [`IdleTimeout`] of an [`ExchangeWsStream`](crate::ExchangeWsStream)), a frame that fails to
+/// deserialise converts into a [`DataError::RawFrame`](crate::error::DataError::RawFrame)
+/// carrying the [`FrameContext`] & the raw frame truncated to its `max_raw_frame_len`.
+///
+/// ### Notes
+/// Fragmented text & binary messages (eg/ large depth snapshots) are reassembled from their
+/// continuation frames by the underlying [`WebSocket`] before being parsed, so each parsed
+/// message is always complete.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct WebSocketParser;
+
+impl StreamParser for WebSocketParser {
+    type Stream = WebSocket;
+    type Message = WsFrame;
+    type Error = WsError;
+
+    fn parse<Output>(
+        input: Result<Self::Message, Self::Error>,
+    ) -> Option<Result<Output, SocketError>>
+    where
+        Output: DeserializeOwned,
+    {
+        let (input, context) = match input {
+            Ok(WsFrame { message, context }) => (Ok(message), context),
+            Err(error) => (Err(error), None),
+        };
+
+        match input {
+            Ok(WsMessage::Close(close_frame)) => {
+                let (code, reason) = close_frame
+                    .map(|frame| (u16::from(frame.code), frame.reason.into_owned()))
+                    .unwrap_or((CLOSE_CODE_NO_STATUS, String::new()));
+
+                debug!(code, %reason, "received CloseFrame WebSocket message");
+                Some(Err(SocketError::Terminated(encode_close_frame(
+                    code, &reason,
+                ))))
+            }
+            input => {
+                if let Ok(WsMessage::Text(text)) = &input {
+                    trace!(payload = %text, "received WebSocket text frame");
+                }
+
+                attach_context(websocket::WebSocketParser::parse(input), context)
+            }
+        }
+    }
+}
+
+/// Log a frame that failed to deserialise, and attach the [`FrameContext`] of the connection it
+/// was received on to the [`SocketError::Deserialise`] payload.
+fn attach_context<Output>(
+    output: Option<Result<Output, SocketError>>,
+    context: Option<Arc<FrameContext>>,
+) -> Option<Result<Output, SocketError>> {
+    let Some(Err(SocketError::Deserialise { error, payload })) = output else {
+        return output;
+    };
+
+    warn!(
+        %error,
+        payload = truncate(&payload, MAX_LOGGED_FRAME_LEN),
+        payload_len = payload.len(),
+        "failed to deserialise WebSocket frame"
+    );
+
+    let payload = match context {
+        Some(context) => encode_raw_frame(&context, truncate(&payload, context.max_raw_frame_len)),
+        None => payload,
+    };
+
+    Some(Err(SocketError::Deserialise { error, payload }))
+}
+
+/// Truncate the provided raw frame to at most `max_len` bytes, on a `char` boundary.
+fn truncate(payload: &str, max_len: usize) -> &str {
+    if payload.len() <= max_len {
+        return payload;
+    }
+
+    let end = (0..=max_len)
+        .rev()
+        .find(|&index| payload.is_char_boundary(index))
+        .unwrap_or_default();
+    &payload[..end]
+}
+
+/// [`StreamParser`] implementation for a [`WebSocket`] that passes each text frame to the
+/// [`Transformer`](barter_integration::Transformer) untouched, rather than deserialising it as
+/// JSON.
+///
+/// Used by [`ForwardRaw`](crate::subscription::forward::ForwardRaw) streams, whose
+/// [`RoutedFrame`](crate::subscription::forward::RoutedFrame) `Input` only parses the minimum
+/// needed to route each frame. Every other message is handled identically to the
+/// [`WebSocketParser`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct RawWebSocketParser;
+
+impl StreamParser for RawWebSocketParser {
+    type Stream = WebSocket;
+    type Message = WsFrame;
+    type Error = WsError;
+
+    fn parse<Output>(
+        input: Result<Self::Message, Self::Error>,
+    ) -> Option<Result<Output, SocketError>>
+    where
+        Output: DeserializeOwned,
+    {
+        match input {
+            Ok(WsFrame {
+                message: WsMessage::Text(text),
+                context,
+            }) => {
+                // Text is moved into the Output, and only recovered if the Output rejects it
+                let output =
+                    Output::deserialize(RawText(text)).map_err(|error| SocketError::Deserialise {
+                        error: serde::de::Error::custom(error.message),
+                        payload: error.frame.unwrap_or_default(),
+                    });
+                attach_context(Some(output), context)
+            }
+            input => WebSocketParser::parse(input),
+        }
+    }
+}
+
+/// [`Deserializer`] that moves a raw text frame into the `Output` of a [`RawWebSocketParser`].
+struct RawText(String);
+
+impl<'de> Deserializer<'de> for RawText {
+    type Error = RawTextError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_string(self.0)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option
+        unit unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier
+        ignored_any
+    }
+}
+
+/// Error of a [`RawText`] rejected by the `Output`, which recovers the raw frame if the `Output`
+/// rejected it as an [`Unexpected::Str`] (eg/ an unroutable
+/// [`RoutedFrame`](crate::subscription::forward::RoutedFrame)).
+#[derive(Debug)]
+struct RawTextError {
+    message: String,
+    frame: Option<String>,
+}
+
+impl Display for RawTextError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for RawTextError {}
+
+impl serde::de::Error for RawTextError {
+    fn custom<T>(message: T) -> Self
+    where
+        T: Display,
+    {
+        Self {
+            message: message.to_string(),
+            frame: None,
+        }
+    }
+
+    fn invalid_value(unexpected: Unexpected<'_>, expected: &dyn Expected) -> Self {
+        match unexpected {
+            Unexpected::Str(frame) => Self {
+                message: format!("invalid frame, expected {expected}"),
+                frame: Some(frame.to_owned()),
+            },
+            unexpected => Self::custom(format!("invalid value: {unexpected}, expected {expected}")),
+        }
+    }
+}
+
+/// [`Stream`] wrapper that detects a dead connection by yielding a [`WsError`] if no message
+/// (data, ping or pong) is received from the inner [`WsStream`](websocket::WsStream) within the
+/// idle `timeout` (see [`Connector::idle_timeout`](crate::exchange::Connector::idle_timeout)).
+///
+/// ### Notes
+/// - Once timed out, the [`Stream`] ends after yielding the [`WsError`], so the consumer
+///   re-initialises the connection.
+/// - Protocol level pings are answered with pongs by the underlying [`WebSocket`], so they keep
+///   the connection alive without any application level handling.
+/// - A `timeout` of `None` never times out, behaving identically to the inner [`Stream`].
+/// - If constructed with the outbound [`WsMessage`] sender of the connection (see
+///   [`Self::with_outbound`]), live requests can be sent via [`Self::send`], and the connection
+///   can be gracefully closed via [`Self::close`].
+/// - Each message is yielded as a [`WsFrame`], carrying the [`FrameContext`] of the connection
+///   if [`Self`] was constructed with one (see [`Self::with_context`]).
+#[derive(Debug)]
+pub struct IdleTimeout<S> {
+    stream: S,
+    timeout: Option<Duration>,
+    deadline: Option<Pin<Box<Sleep>>>,
+    timed_out: bool,
+    outbound: Option<mpsc::UnboundedSender<WsMessage>>,
+    context: Option<Arc<FrameContext>>,
+}
+
+impl<S> IdleTimeout<S> {
+    /// Construct a new [`Self`] that times out if the inner [`Stream`] is idle for the provided
+    /// `timeout`.
+    pub fn new(stream: S, timeout: Option<Duration>) -> Self {
+        Self {
+            stream,
+            timeout,
+            deadline: timeout.map(|timeout| Box::pin(tokio::time::sleep(timeout))),
+            timed_out: false,
+            outbound: None,
+            context: None,
+        }
+    }
+
+    /// Attach the [`FrameContext`] of the connection to every frame that fails to deserialise.
+    pub fn with_context(mut self, context: FrameContext) -> Self {
+        self.context = Some(Arc::new(context));
+        self
+    }
+
+    /// Retain the [`mpsc::UnboundedSender`] used to send [`WsMessage`]s to the exchange over the
+    /// same connection, so it can be gracefully closed via [`Self::close`].
+    pub fn with_outbound(mut self, outbound: mpsc::UnboundedSender<WsMessage>) -> Self {
+        self.outbound = Some(outbound);
+        self
+    }
+
+    /// Send the provided `requests` (eg/ live subscribe & unsubscribe requests) to the exchange
+    /// over the same connection.
+    ///
+    /// Returns `false` if [`Self`] has no outbound sender, or the connection is already closed.
+    pub fn send(&self, requests: Vec<WsMessage>) -> bool {
+        let Some(outbound) = &self.outbound else {
+            return false;
+        };
+
+        requests
+            .into_iter()
+            .all(|message| outbound.send(message).is_ok())
+    }
+
+    /// Send the provided `requests` followed by a WebSocket CloseFrame to the exchange. The inner
+    /// [`Stream`] then ends once the exchange acknowledges the CloseFrame.
+    ///
+    /// Returns `false` if [`Self`] has no outbound sender, or the connection is already closed.
+    pub fn close(&self, requests: Vec<WsMessage>) -> bool {
+        let Some(outbound) = &self.outbound else {
+            return false;
+        };
+
+        requests
+            .into_iter()
+            .chain([WsMessage::Close(None)])
+            .all(|message| outbound.send(message).is_ok())
+    }
+}
+
+impl<S> Stream for IdleTimeout<S>
+where
+    S: Stream<Item = Result<WsMessage, WsError>> + Unpin,
+{
+    type Item = Result<WsFrame, WsError>;
+
+    // WsError is defined upstream in tungstenite, so cannot be boxed here
+    #[allow(clippy::result_large_err)]
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.timed_out {
+            return Poll::Ready(None);
+        }
+
+        if let Poll::Ready(next) = self.stream.poll_next_unpin(cx) {
+            // Any received message proves the connection is alive, so reset the deadline
+            if let (Some(timeout), Some(deadline)) = (self.timeout, self.deadline.as_mut()) {
+                deadline.as_mut().reset(Instant::now() + timeout);
+            }
+
+            return Poll::Ready(next.map(|result| {
+                result.map(|message| WsFrame {
+                    message,
+                    context: self.context.clone(),
+                })
+            }));
+        }
+
+        let Some(timeout) = self.timeout else {
+            return Poll::Pending;
+        };
+
+        match self
+            .deadline
+            .as_mut()
+            .map(|deadline| deadline.as_mut().poll(cx))
+        {
+            Some(Poll::Ready(())) => {
+                warn!(?timeout, "WebSocket connection idle timeout elapsed");
+                self.timed_out = true;
+                Poll::Ready(Some(Err(WsError::Io(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    format!("no WebSocket message received within idle timeout {timeout:?}"),
+                )))))
+            }
+            _ => Poll::Pending,
+        }
+    }
+}
+
+/// Encode a WebSocket CloseFrame code & reason into a [`SocketError::Terminated`] message.
+pub fn encode_close_frame(code: u16, reason: &str) -> String {
+    format!("{CLOSE_FRAME_PREFIX}|{code}|{reason}")
+}
+
+/// Decode a WebSocket CloseFrame code & reason from a [`SocketError::Terminated`] message
+/// generated by [`encode_close_frame`].
+pub fn decode_close_frame(message: &str) -> Option<(u16, String)> {
+    let mut parts = message
+        .strip_prefix(CLOSE_FRAME_PREFIX)?
+        .strip_prefix('|')?
+        .splitn(2, '|');
+
+    let code = parts.next()?.parse().ok()?;
+    let reason = parts.next().unwrap_or_default().to_owned();
+
+    Some((code, reason))
+}
+
+/// Encode the [`FrameContext`] & raw text of a frame that failed to deserialise into a
+/// [`SocketError::Deserialise`] payload.
+pub fn encode_raw_frame(context: &FrameContext, raw: &str) -> String {
+    serde_json::json!([RAW_FRAME_PREFIX, context, raw]).to_string()
+}
+
+/// Decode the [`FrameContext`] & raw text of a frame from a [`SocketError::Deserialise`] payload
+/// generated by [`encode_raw_frame`].
+pub fn decode_raw_frame(payload: &str) -> Option<(FrameContext, String)> {
+    if !payload.starts_with(&format!("[\"{RAW_FRAME_PREFIX}\"")) {
+        return None;
+    }
+
+    serde_json::from_str::<(String, FrameContext, String)>(payload)
+        .ok()
+        .map(|(_, context, raw)| (context, raw))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::DataError,
+        exchange::binance::{
+            spot::BinanceSpot,
+            trade::{BinanceTrade, BinanceTradeRoute},
+        },
+        subscription::{forward::RoutedFrame, intern::intern, trade::PublicTrades},
+        transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+        ExchangeWsStream,
+    };
+    use barter_integration::{
+        model::instrument::{kind::InstrumentKind, Instrument},
+        ExchangeStream,
+    };
+    use futures::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::protocol::{
+        frame::{
+            coding::{CloseCode, Data, OpCode},
+            Frame,
+        },
+        CloseFrame,
+    };
+
+    #[tokio::test]
+    async fn test_websocket_parser_close_frame() {
+        // Mock server that closes every connection with a policy violation CloseFrame
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            websocket
+                .send(WsMessage::Close(Some(CloseFrame {
+                    code: CloseCode::Policy,
+                    reason: "too many requests".into(),
+                })))
+                .await
+                .unwrap();
+        });
+
+        let mut websocket = websocket::connect(format!("ws://{address}")).await.unwrap();
+
+        let error = loop {
+            let input = websocket.next().await.expect("CloseFrame not received");
+            let input = input.map(WsFrame::from);
+            if let Some(Err(error)) = WebSocketParser::parse::<serde_json::Value>(input) {
+                break DataError::from(error);
+            }
+        };
+
+        match error {
+            DataError::ConnectionClosed { code, reason } => {
+                assert_eq!(code, 1008);
+                assert_eq!(reason, "too many requests");
+            }
+            error => panic!("expected DataError::ConnectionClosed, received: {error:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_websocket_parser_reassembles_fragmented_messages() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Snapshot {
+            bids: Vec<(f64, f64)>,
+            asks: Vec<(f64, f64)>,
+        }
+
+        let snapshot = Snapshot {
+            bids: (0..5000).map(|level| (f64::from(level), 1.5)).collect(),
+            asks: (5000..10000).map(|level| (f64::from(level), 2.5)).collect(),
+        };
+        let payload = serde_json::to_vec(&snapshot).unwrap();
+
+        // Mock server that sends the snapshot as a fragmented text message, followed by a
+        // fragmented binary message, each split across many continuation frames
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn({
+            let payload = payload.clone();
+            async move {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+                for data in [Data::Text, Data::Binary] {
+                    let chunks = payload.chunks(4096).collect::<Vec<_>>();
+                    for (index, chunk) in chunks.iter().enumerate() {
+                        let opcode = if index == 0 {
+                            OpCode::Data(data)
+                        } else {
+                            OpCode::Data(Data::Continue)
+                        };
+                        let frame =
+                            Frame::message(chunk.to_vec(), opcode, index == chunks.len() - 1);
+                        websocket.send(WsMessage::Frame(frame)).await.unwrap();
+                    }
+                }
+                // Keep the connection open until the client has read both messages
+                let _ = websocket.next().await;
+            }
+        });
+
+        let mut websocket = websocket::connect(format!("ws://{address}")).await.unwrap();
+
+        for index in 0..2 {
+            let input = websocket
+                .next()
+                .await
+                .expect("message not received")
+                .map(WsFrame::from);
+            let Some(actual) = WebSocketParser::parse::<Snapshot>(input) else {
+                panic!("TC{index} failed")
+            };
+            let actual = actual.unwrap_or_else(|error| panic!("TC{index} failed: {error:?}"));
+            assert_eq!(actual, snapshot, "TC{index} failed");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_idle_timeout() {
+        // Mock server that sends a message & a ping, then goes silent without closing
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            websocket.send(WsMessage::Text("{}".into())).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(60)).await;
+            websocket.send(WsMessage::Ping(vec![1])).await.unwrap();
+            std::future::pending::<()>().await;
+        });
+
+        let websocket = websocket::connect(format!("ws://{address}")).await.unwrap();
+        let (_ws_sink, ws_stream) = websocket.split();
+        let mut stream = IdleTimeout::new(ws_stream, Some(Duration::from_millis(100)));
+
+        // Messages received within the timeout keep the connection alive
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(WsFrame {
+                message: WsMessage::Text(_),
+                context: None
+            }))
+        ));
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(WsFrame {
+                message: WsMessage::Ping(_),
+                ..
+            }))
+        ));
+
+        // Silent connection times out, and the stream ends
+        let start = Instant::now();
+        match stream.next().await {
+            Some(Err(WsError::Io(error))) => {
+                assert_eq!(error.kind(), std::io::ErrorKind::TimedOut)
+            }
+            next => panic!("expected WsError::Io TimedOut, received: {next:?}"),
+        }
+        assert!(start.elapsed() >= Duration::from_millis(90));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_exchange_ws_stream_attaches_raw_frame() {
+        struct TestCase {
+            frame: String,
+            expected_raw: String,
+        }
+
+        let long = format!(
+            r#"{{"e":"trade","p":"{}"#,
+            "9".repeat(DEFAULT_MAX_RAW_FRAME_LEN)
+        );
+        let tests = vec![
+            TestCase {
+                // TC0: malformed frame is attached in full
+                frame: r#"{"e":"trade","s":"BTCUSDT""#.to_string(),
+                expected_raw: r#"{"e":"trade","s":"BTCUSDT""#.to_string(),
+            },
+            TestCase {
+                // TC1: malformed frame longer than the limit is truncated
+                frame: long.clone(),
+                expected_raw: long[..DEFAULT_MAX_RAW_FRAME_LEN].to_string(),
+            },
+        ];
+
+        // Mock server that sends every malformed frame, then goes silent without closing
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let frames = tests
+            .iter()
+            .map(|test| test.frame.clone())
+            .collect::<Vec<_>>();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            for frame in frames {
+                websocket.send(WsMessage::Text(frame)).await.unwrap();
+            }
+            std::future::pending::<()>().await;
+        });
+
+        let map = Map::from_iter([(
+            SubscriptionId::from("@trade|BTCUSDT"),
+            intern(&Instrument::from(("btc", "usdt", InstrumentKind::Spot))),
+        )]);
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let transformer = StatelessTransformer::<BinanceSpot, PublicTrades, BinanceTrade>::new(
+            ws_sink_tx,
+            map.clone(),
+        )
+        .await
+        .unwrap();
+
+        let websocket = websocket::connect(format!("ws://{address}")).await.unwrap();
+        let (_ws_sink, ws_stream) = websocket.split();
+        let mut stream: ExchangeWsStream<_> = ExchangeStream::new(
+            IdleTimeout::new(ws_stream, None)
+                .with_context(FrameContext::new(ExchangeId::BinanceSpot, &map)),
+            transformer,
+        );
+
+        for (index, test) in tests.into_iter().enumerate() {
+            match stream.next().await {
+                Some(Err(DataError::RawFrame(error))) => {
+                    assert_eq!(error.exchange, ExchangeId::BinanceSpot, "TC{index} failed");
+                    assert_eq!(
+                        error.subscription_id,
+                        Some(SubscriptionId::from("@trade|BTCUSDT")),
+                        "TC{index} failed"
+                    );
+                    assert_eq!(error.raw, test.expected_raw, "TC{index} failed");
+                }
+                next => panic!("TC{index} failed: expected DataError::RawFrame, got: {next:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_raw_websocket_parser_attaches_raw_frame() {
+        struct TestCase {
+            frame: &'static str,
+            context: Option<Arc<FrameContext>>,
+            expected: Option<FrameContext>,
+        }
+
+        let map = Map::from_iter([(
+            SubscriptionId::from("@trade|BTCUSDT"),
+            intern(&Instrument::from(("btc", "usdt", InstrumentKind::Spot))),
+        )]);
+        let context = FrameContext::new(ExchangeId::BinanceSpot, &map);
+
+        let tests = vec![
+            TestCase {
+                // TC0: unroutable frame from a connection with a FrameContext
+                frame: r#"{"e":"trade","s":"#,
+                context: Some(Arc::new(context.clone())),
+                expected: Some(context),
+            },
+            TestCase {
+                // TC1: unroutable frame without a FrameContext
+                frame: r#"{"e":"trade","s":"#,
+                context: None,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = WsFrame {
+                message: WsMessage::Text(test.frame.to_owned()),
+                context: test.context,
+            };
+
+            match (
+                RawWebSocketParser::parse::<RoutedFrame<BinanceTradeRoute>>(Ok(input)),
+                test.expected,
+            ) {
+                (Some(Err(SocketError::Deserialise { payload, .. })), Some(expected)) => {
+                    let (context, raw) = decode_raw_frame(&payload)
+                        .unwrap_or_else(|| panic!("TC{index} failed: {payload}"));
+                    assert_eq!(context, expected, "TC{index} failed");
+                    assert_eq!(raw, test.frame, "TC{index} failed");
+                }
+                (Some(Err(SocketError::Deserialise { payload, .. })), None) => {
+                    assert_eq!(payload, test.frame, "TC{index} failed");
+                }
+                (actual, _) => panic!("TC{index} failed: {actual:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_max_raw_frame_len_is_per_connection() {
+        let map = Map::from_iter([(
+            SubscriptionId::from("@trade|BTCUSDT"),
+            intern(&Instrument::from(("btc", "usdt", InstrumentKind::Spot))),
+        )]);
+
+        // Connections established concurrently with & without an override keep their own limit
+        let (short, default) = tokio::join!(
+            with_max_raw_frame_len(Some(8), async {
+                tokio::task::yield_now().await;
+                FrameContext::new(ExchangeId::BinanceSpot, &map)
+            }),
+            async { FrameContext::new(ExchangeId::BinanceSpot, &map) },
+        );
+        assert_eq!(short.max_raw_frame_len, 8);
+        assert_eq!(default.max_raw_frame_len, DEFAULT_MAX_RAW_FRAME_LEN);
+
+        let frame = r#"{"e":"trade","s":"BTCUSDT""#;
+        for (context, expected_raw) in [(short, &frame[..8]), (default, frame)] {
+            let input = WsFrame {
+                message: WsMessage::Text(frame.to_owned()),
+                context: Some(Arc::new(context)),
+            };
+
+            match WebSocketParser::parse::<BinanceTrade>(Ok(input)) {
+                Some(Err(SocketError::Deserialise { payload, .. })) => {
+                    let (_, raw) = decode_raw_frame(&payload).unwrap();
+                    assert_eq!(raw, expected_raw);
+                }
+                actual => panic!("expected SocketError::Deserialise, got: {actual:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_close_frame() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<(u16, String)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: code & reason
+                input: "CloseFrame|1013|try again later",
+                expected: Some((1013, "try again later".to_string())),
+            },
+            TestCase {
+                // TC1: reason containing the delimiter
+                input: "CloseFrame|4000|maintenance|eta 10m",
+                expected: Some((4000, "maintenance|eta 10m".to_string())),
+            },
+            TestCase {
+                // TC2: code without reason
+                input: "CloseFrame|1005|",
+                expected: Some((1005, String::new())),
+            },
+            TestCase {
+                // TC3: unrelated Terminated message
+                input: "Some(CloseFrame { code: Normal, reason: \"\" })",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = decode_close_frame(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
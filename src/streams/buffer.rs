@@ -0,0 +1,267 @@
+use crate::event::MarketEvent;
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, PoisonError},
+};
+use tokio::sync::{mpsc, Notify};
+
+/// Policy applied by a [`BufferedReceiver`] once its bounded capacity is full because the
+/// consumer is lagging.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum BufferPolicy {
+    /// Stop receiving from the upstream receiver until the consumer catches up, so no
+    /// [`MarketEvent<T>`] is dropped.
+    Block,
+    /// Drop the oldest buffered [`MarketEvent<T>`] to make room for the newest.
+    DropOldest,
+    /// Drop the newest [`MarketEvent<T>`] until the consumer catches up.
+    DropNewest,
+}
+
+/// Bounded [`MarketEvent<T>`] receiver produced by [`buffer`], applying a [`BufferPolicy`]
+/// whilst the consumer is lagging.
+///
+/// ### Notes
+/// The consumer loops of the [`Streams`](super::Streams) distribute events via unbounded
+/// channels, so a [`BufferPolicy::Block`] holds any backlog in the upstream receiver rather
+/// than stalling the exchange connection. The drop policies bound the backlog to the capacity.
+#[derive(Debug)]
+pub struct BufferedReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> BufferedReceiver<T> {
+    /// Receive the next buffered [`MarketEvent<T>`], or `None` once the upstream receiver has
+    /// ended & every buffered [`MarketEvent<T>`] has been received.
+    pub async fn recv(&mut self) -> Option<MarketEvent<T>> {
+        loop {
+            {
+                let mut state = self.shared.lock();
+                if let Some(event) = state.queue.pop_front() {
+                    self.shared.space.notify_one();
+                    return Some(event);
+                }
+                if state.closed {
+                    return None;
+                }
+            }
+            self.shared.events.notified().await;
+        }
+    }
+
+    /// [`BufferDrops`] handle used to observe the [`MarketEvent<T>`]s dropped by the
+    /// [`BufferPolicy`].
+    pub fn drops(&self) -> BufferDrops {
+        BufferDrops {
+            counts: Arc::clone(&self.shared.drops),
+        }
+    }
+}
+
+/// Handle used to observe the number of [`MarketEvent<T>`]s dropped by a [`BufferedReceiver`]
+/// for each [`Instrument`] (ie/ each [`Subscription`](crate::subscription::Subscription) of the
+/// exchange receiver).
+#[derive(Clone, Debug, Default)]
+pub struct BufferDrops {
+    counts: Arc<Mutex<HashMap<Instrument, u64>>>,
+}
+
+impl BufferDrops {
+    /// Number of [`MarketEvent<T>`]s of the provided [`Instrument`] dropped so far.
+    pub fn dropped(&self, instrument: &Instrument) -> u64 {
+        self.counts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(instrument)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Total number of [`MarketEvent<T>`]s dropped so far.
+    pub fn total(&self) -> u64 {
+        self.counts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .sum()
+    }
+
+    /// Count a dropped [`MarketEvent<T>`] of the provided [`Instrument`].
+    fn record(&self, instrument: &Instrument) {
+        *self
+            .counts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(instrument.clone())
+            .or_default() += 1;
+    }
+}
+
+/// State shared by a [`BufferedReceiver`] and the task that fills it.
+#[derive(Debug)]
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    events: Notify,
+    space: Notify,
+    drops: Arc<Mutex<HashMap<Instrument, u64>>>,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> std::sync::MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Buffered [`MarketEvent<T>`]s of a [`BufferedReceiver`].
+#[derive(Debug)]
+struct State<T> {
+    queue: VecDeque<MarketEvent<T>>,
+    closed: bool,
+}
+
+/// Bound the provided [`MarketEvent<T>`] receiver (eg/ an exchange receiver returned by
+/// [`Streams::select`](super::Streams::select)) to `capacity` buffered events, applying the
+/// [`BufferPolicy`] whilst the consumer of the returned [`BufferedReceiver`] is lagging.
+///
+/// Events are moved into the buffer by a spawned task until the upstream receiver ends. A
+/// `capacity` of zero is treated as one.
+pub fn buffer<T>(
+    mut upstream_rx: mpsc::UnboundedReceiver<MarketEvent<T>>,
+    capacity: usize,
+    policy: BufferPolicy,
+) -> BufferedReceiver<T>
+where
+    T: Send + 'static,
+{
+    let capacity = capacity.max(1);
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::with_capacity(capacity),
+            closed: false,
+        }),
+        events: Notify::new(),
+        space: Notify::new(),
+        drops: Arc::default(),
+    });
+    let receiver = BufferedReceiver {
+        shared: Arc::clone(&shared),
+    };
+    let drops = receiver.drops();
+
+    tokio::spawn(async move {
+        loop {
+            // Stop receiving from upstream whilst a Blocking buffer is full
+            while policy == BufferPolicy::Block && shared.lock().queue.len() >= capacity {
+                shared.space.notified().await;
+            }
+
+            let Some(event) = upstream_rx.recv().await else {
+                shared.lock().closed = true;
+                shared.events.notify_one();
+                break;
+            };
+
+            {
+                let mut state = shared.lock();
+                match (state.queue.len() < capacity, policy) {
+                    // Blocking buffers only receive from upstream once there is space
+                    (true, _) | (false, BufferPolicy::Block) => state.queue.push_back(event),
+                    (false, BufferPolicy::DropNewest) => drops.record(&event.instrument),
+                    (false, BufferPolicy::DropOldest) => {
+                        if let Some(oldest) = state.queue.pop_front() {
+                            drops.record(&oldest.instrument);
+                        }
+                        state.queue.push_back(event);
+                    }
+                }
+            }
+            shared.events.notify_one();
+        }
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, subscription::trade::PublicTrade};
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use chrono::Utc;
+    use std::time::Duration;
+
+    fn trade(instrument: &Arc<Instrument>, id: usize) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            instrument: instrument.clone(),
+            ..fixtures::trade(Utc::now(), &id.to_string(), 1.0, 1.0, Side::Buy)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_buffer_with_stalled_consumer() {
+        struct TestCase {
+            policy: BufferPolicy,
+            expected_ids: Vec<&'static str>,
+            expected_drops: u64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Block retains every event once the consumer catches up
+                policy: BufferPolicy::Block,
+                expected_ids: vec!["1", "2", "3", "4", "5"],
+                expected_drops: 0,
+            },
+            TestCase {
+                // TC1: DropOldest retains the newest events
+                policy: BufferPolicy::DropOldest,
+                expected_ids: vec!["4", "5"],
+                expected_drops: 3,
+            },
+            TestCase {
+                // TC2: DropNewest retains the oldest events
+                policy: BufferPolicy::DropNewest,
+                expected_ids: vec!["1", "2"],
+                expected_drops: 3,
+            },
+        ];
+
+        let instrument = Arc::new(Instrument::from(("btc", "usdt", InstrumentKind::Spot)));
+        let other = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (upstream_tx, upstream_rx) = mpsc::unbounded_channel();
+            let mut buffered = buffer(upstream_rx, 2, test.policy);
+            let drops = buffered.drops();
+
+            // Fill the buffer whilst the consumer is stalled
+            (1..=5).for_each(|id| upstream_tx.send(trade(&instrument, id)).unwrap());
+            drop(upstream_tx);
+
+            // Allow the buffering task to process every upstream event it will accept
+            tokio::time::timeout(Duration::from_secs(5), async {
+                while drops.total() < test.expected_drops {
+                    tokio::task::yield_now().await;
+                }
+            })
+            .await
+            .unwrap_or_else(|_| panic!("TC{index} failed: events were not dropped"));
+            tokio::time::sleep(Duration::from_millis(10)).await;
+
+            assert_eq!(
+                drops.dropped(&instrument),
+                test.expected_drops,
+                "TC{index} failed"
+            );
+            assert_eq!(drops.dropped(&other), 0, "TC{index} failed");
+
+            let mut actual = Vec::new();
+            while let Some(event) = buffered.recv().await {
+                actual.push(event.kind.id);
+            }
+            assert_eq!(actual, test.expected_ids, "TC{index} failed");
+        }
+    }
+}
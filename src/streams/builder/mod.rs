@@ -1,14 +1,34 @@
-use super::{consumer::consume, Streams};
+use super::{
+    connection::{ConnectionCounter, Connections},
+    consumer::{consume, ConsumerConfig, ReconnectBackoff},
+    liveness::{Liveness, LivenessTracker},
+    maintenance::MaintenanceSchedule,
+    manager::ConnectionManager,
+    metrics::Metrics,
+    mute::{MuteSwitch, Mutes},
+    reconcile::Reconciler,
+    shutdown::Shutdown,
+    status::ConnectionStatuses,
+    Streams,
+};
 use crate::{
-    error::DataError,
-    event::MarketEvent,
-    exchange::{ExchangeId, StreamSelector},
-    subscription::{SubKind, Subscription},
+    error::{DataError, SubscriptionError, UnsupportedSubscription},
+    event::{MarketEvent, StreamItem},
+    exchange::{
+        binance::{auto::BinanceAuto, futures::BinanceFuturesUsd, spot::BinanceSpot},
+        Connector, ExchangeId, StreamSelector,
+    },
+    subscription::{trade::TradeFields, SubKind, Subscription},
+    transformer::book::BookWatchdog,
     Identifier,
 };
 use barter_integration::{error::SocketError, Validator};
-use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
+use std::{
+    collections::HashMap, fmt::Debug, future::Future, marker::PhantomData, pin::Pin, sync::Arc,
+    time::Duration,
+};
 use tokio::sync::mpsc;
+use url::Url;
 
 /// Defines the [`MultiStreamBuilder`](multi::MultiStreamBuilder) API for ergonomically
 /// initialising a common [`Streams<Output>`](Streams) from multiple
@@ -21,28 +41,50 @@ pub type SubscribeFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>>;
 
 /// Builder to configure and initialise a [`Streams<MarketEvent<SubKind::Event>`](Streams) instance
 /// for a specific [`SubKind`].
-#[derive(Default)]
-pub struct StreamBuilder<Kind>
+///
+/// Use a [`StreamEvent<SubKind::Event>`](crate::event::StreamEvent) `Output` to also receive
+/// connection lifecycle markers.
+pub struct StreamBuilder<Kind, Output = MarketEvent<<Kind as SubKind>::Event>>
 where
     Kind: SubKind,
 {
-    pub channels: HashMap<ExchangeId, ExchangeChannel<MarketEvent<Kind::Event>>>,
+    pub channels: HashMap<ExchangeId, ExchangeChannel<Output>>,
     pub futures: Vec<SubscribeFuture>,
+    pub liveness: LivenessTracker,
+    pub mutes: MuteSwitch,
+    pub connections: ConnectionCounter,
+    pub statuses: ConnectionStatuses,
+    pub max_connections: Option<usize>,
+    pub consumer: ConsumerConfig,
+    shared_subscriptions: usize,
+    phantom: PhantomData<Kind>,
 }
 
-impl<Kind> Debug for StreamBuilder<Kind>
+impl<Kind, Output> Debug for StreamBuilder<Kind, Output>
 where
     Kind: SubKind,
+    Output: Debug,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("StreamBuilder<SubKind>")
             .field("channels", &self.channels)
             .field("num_futures", &self.futures.len())
+            .field("max_connections", &self.max_connections)
+            .field("consumer", &self.consumer)
             .finish()
     }
 }
 
-impl<Kind> StreamBuilder<Kind>
+impl<Kind, Output> Default for StreamBuilder<Kind, Output>
+where
+    Kind: SubKind,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Kind, Output> StreamBuilder<Kind, Output>
 where
     Kind: SubKind,
 {
@@ -51,41 +93,317 @@ where
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            liveness: LivenessTracker::new(),
+            mutes: MuteSwitch::new(),
+            connections: ConnectionCounter::new(),
+            statuses: ConnectionStatuses::default(),
+            max_connections: None,
+            consumer: ConsumerConfig::default(),
+            shared_subscriptions: 0,
+            phantom: PhantomData,
         }
     }
 
+    /// Limit the number of concurrent connections the [`StreamBuilder`] may open, guarding
+    /// against a misconfiguration opening enough connections to exhaust file descriptors or trip
+    /// an exchange connection-per-IP limit.
+    ///
+    /// Each [`subscribe()`](StreamBuilder::subscribe()) call opens a distinct connection, so
+    /// [`init()`](StreamBuilder::init()) returns a [`DataError::ConnectionLimitExceeded`] if
+    /// more than `limit` collections of [`Subscription`]s were added.
+    pub fn max_connections(mut self, limit: usize) -> Self {
+        self.max_connections = Some(limit);
+        self
+    }
+
+    /// Emit a [`StreamEvent::Heartbeat`](crate::event::StreamEvent::Heartbeat) every `interval`
+    /// that a connection is healthy but has not yielded any data, allowing a quiet but live
+    /// connection to be distinguished from a dead one.
+    ///
+    /// Applies to [`Subscription`]s subsequently added via
+    /// [`subscribe()`](StreamBuilder::subscribe()) or
+    /// [`subscribe_reconcilable()`](StreamBuilder::subscribe_reconcilable()), and only has an
+    /// effect with a [`StreamEvent<SubKind::Event>`](crate::event::StreamEvent) `Output`.
+    pub fn heartbeat(mut self, interval: Duration) -> Self {
+        self.consumer.heartbeat = Some(interval);
+        self
+    }
+
+    /// Use the provided [`ReconnectBackoff`] policy between re-connection attempts, rather than
+    /// the default exponential backoff.
+    ///
+    /// Applies to [`Subscription`]s subsequently added via
+    /// [`subscribe()`](StreamBuilder::subscribe()) or
+    /// [`subscribe_reconcilable()`](StreamBuilder::subscribe_reconcilable()).
+    pub fn reconnect_backoff(mut self, backoff: ReconnectBackoff) -> Self {
+        self.consumer.backoff = backoff;
+        self
+    }
+
+    /// Use the provided [`MaintenanceSchedule`] of known exchange maintenance windows, during
+    /// which re-connection attempts are made at the extended
+    /// [`MaintenanceSchedule::reconnect_interval`] and a
+    /// [`StreamEvent::Maintenance`](crate::event::StreamEvent::Maintenance) marker is emitted.
+    ///
+    /// Applies to [`Subscription`]s subsequently added via
+    /// [`subscribe()`](StreamBuilder::subscribe()) or
+    /// [`subscribe_reconcilable()`](StreamBuilder::subscribe_reconcilable()). The schedule is
+    /// shared, so windows can still be added to it after [`init()`](StreamBuilder::init()).
+    pub fn maintenance(mut self, schedule: MaintenanceSchedule) -> Self {
+        self.consumer.maintenance = schedule;
+        self
+    }
+
+    /// Gracefully close the connections of the consumer loops once the provided [`Shutdown`]
+    /// handle is triggered, ending the [`Streams`] receivers without re-connecting.
+    ///
+    /// Applies to [`Subscription`]s subsequently added via
+    /// [`subscribe()`](StreamBuilder::subscribe()) or
+    /// [`subscribe_reconcilable()`](StreamBuilder::subscribe_reconcilable()).
+    pub fn shutdown(mut self, shutdown: Shutdown) -> Self {
+        self.consumer.shutdown = shutdown;
+        self
+    }
+
+    /// Record the events, parse errors & re-connections of the consumer loops with the provided
+    /// [`Metrics`].
+    ///
+    /// Applies to [`Subscription`]s subsequently added via
+    /// [`subscribe()`](StreamBuilder::subscribe()) or
+    /// [`subscribe_reconcilable()`](StreamBuilder::subscribe_reconcilable()).
+    pub fn metrics<M>(mut self, metrics: M) -> Self
+    where
+        M: Metrics + 'static,
+    {
+        self.consumer.metrics = Arc::new(metrics);
+        self
+    }
+
+    /// Connect to the provided base [`Url`] (eg/ an exchange testnet or a regional proxy)
+    /// rather than the default [`Connector::url`].
+    ///
+    /// Applies to [`Subscription`]s subsequently added via
+    /// [`subscribe()`](StreamBuilder::subscribe()) or
+    /// [`subscribe_reconcilable()`](StreamBuilder::subscribe_reconcilable()), so it should be
+    /// configured alongside the matching exchange. [`init()`](StreamBuilder::init()) returns a
+    /// [`DataError::InvalidBaseUrl`] if it is not a `ws://` or `wss://` url.
+    pub fn with_base_url(mut self, url: Url) -> Self {
+        self.consumer.base_url = Some(url);
+        self
+    }
+
+    /// Truncate the raw frame attached to each [`RawFrameError`](crate::error::RawFrameError) to
+    /// at most the provided number of bytes, rather than the default
+    /// [`DEFAULT_MAX_RAW_FRAME_LEN`](crate::protocol::DEFAULT_MAX_RAW_FRAME_LEN).
+    ///
+    /// Applies to [`Subscription`]s subsequently added via
+    /// [`subscribe()`](StreamBuilder::subscribe()) or
+    /// [`subscribe_reconcilable()`](StreamBuilder::subscribe_reconcilable()).
+    pub fn with_max_raw_frame_len(mut self, len: usize) -> Self {
+        self.consumer.max_raw_frame_len = Some(len);
+        self
+    }
+
+    /// Populate the provided [`TradeFields`] of every trade normalised by the connections of
+    /// [`Subscription`]s subsequently added via [`subscribe()`](StreamBuilder::subscribe()) or
+    /// [`subscribe_reconcilable()`](StreamBuilder::subscribe_reconcilable()).
+    pub fn with_trade_fields(mut self, fields: TradeFields) -> Self {
+        self.consumer.trade_fields = fields;
+        self
+    }
+
+    /// Detect (and optionally re-snapshot) the stale OrderBooks of the connections of
+    /// [`Subscription`]s subsequently added via [`subscribe()`](StreamBuilder::subscribe()) or
+    /// [`subscribe_reconcilable()`](StreamBuilder::subscribe_reconcilable()), using the
+    /// provided [`BookWatchdog`].
+    pub fn with_book_watchdog(mut self, watchdog: BookWatchdog) -> Self {
+        self.consumer.book_watchdog = Some(watchdog);
+        self
+    }
+
     /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
     /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
     ///
     /// Note that [`Subscription`]s are not actioned until the
     /// [`init()`](StreamBuilder::init()) method is invoked.
-    pub fn subscribe<SubIter, Sub, Exchange>(mut self, subscriptions: SubIter) -> Self
+    pub fn subscribe<SubIter, Sub, Exchange>(self, subscriptions: SubIter) -> Self
     where
         SubIter: IntoIterator<Item = Sub>,
         Sub: Into<Subscription<Exchange, Kind>>,
         Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
         Kind: Ord + Send + Sync + 'static,
         Kind::Event: Send,
+        Output: StreamItem<Kind::Event> + Debug + Send + 'static,
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
         // Construct Vec<Subscriptions> from input SubIter
-        let mut subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
+        let subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
+
+        self.action(subscriptions, None)
+    }
+
+    /// Add a collection of mixed kind [`BinanceAuto`] [`Subscription`]s to the [`StreamBuilder`],
+    /// grouped by [`InstrumentKind`](barter_integration::model::instrument::kind::InstrumentKind)
+    /// into a [`BinanceSpot`] connection & a [`BinanceFuturesUsd`] connection.
+    ///
+    /// A connection is only requested for a server that at least one [`Subscription`] routes to.
+    /// See [`BinanceAuto::split`] for the routing rules.
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`init()`](StreamBuilder::init()) method is invoked.
+    pub fn subscribe_binance<SubIter, Sub>(self, subscriptions: SubIter) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<BinanceAuto, Kind>>,
+        BinanceSpot: StreamSelector<Kind>,
+        BinanceFuturesUsd: StreamSelector<Kind>,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Output: StreamItem<Kind::Event> + Debug + Send + 'static,
+        Subscription<BinanceSpot, Kind>: Identifier<<BinanceSpot as Connector>::Channel>
+            + Identifier<<BinanceSpot as Connector>::Market>,
+        Subscription<BinanceFuturesUsd, Kind>: Identifier<<BinanceFuturesUsd as Connector>::Channel>
+            + Identifier<<BinanceFuturesUsd as Connector>::Market>,
+    {
+        let (spot, futures) = BinanceAuto::split(subscriptions.into_iter().map(Sub::into));
 
-        // Acquire channel Sender to send Market<Kind::Event> from consumer loop to user
+        let mut builder = self;
+        if !spot.is_empty() {
+            builder = builder.subscribe(spot);
+        }
+        if !futures.is_empty() {
+            builder = builder.subscribe(futures);
+        }
+        builder
+    }
+
+    /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
+    /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection,
+    /// returning a [`Reconciler`] that can hot-reload the [`Subscription`] universe of that
+    /// connection once initialised.
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`init()`](StreamBuilder::init()) method is invoked.
+    pub fn subscribe_reconcilable<SubIter, Sub, Exchange>(
+        self,
+        subscriptions: SubIter,
+    ) -> (Self, Reconciler<Exchange, Kind>)
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Output: StreamItem<Kind::Event> + Debug + Send + 'static,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Construct Vec<Subscriptions> from input SubIter
+        let subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
+
+        let (reconciler, universe_rx) = Reconciler::new(subscriptions.clone());
+
+        (self.action(subscriptions, Some(universe_rx)), reconciler)
+    }
+
+    /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on a
+    /// connection shared via the provided [`ConnectionManager`], reusing an existing open
+    /// connection of the same exchange where capacity allows.
+    ///
+    /// Shared connections are not counted towards the
+    /// [`max_connections()`](StreamBuilder::max_connections()) limit, since the
+    /// [`ConnectionManager`] determines whether a new connection is required.
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`init()`](StreamBuilder::init()) method is invoked.
+    pub fn subscribe_shared<SubIter, Sub, Exchange>(
+        mut self,
+        manager: &ConnectionManager<Exchange, Kind, Output>,
+        subscriptions: SubIter,
+    ) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Clone + Send,
+        Output: StreamItem<Kind::Event> + Debug + Send + 'static,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Construct Vec<Subscriptions> from input SubIter
+        let subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
+
+        // Acquire channel Sender to send Output from the shared connection to user
+        let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
+
+        // Register Subscriptions with the LivenessTracker so the first event of each is awaited
+        subscriptions
+            .iter()
+            .for_each(|subscription| self.liveness.register(subscription));
+        let liveness = self.liveness.clone();
+        let mutes = self.mutes.clone();
+        let manager = manager.clone();
+
+        // Add Future that once awaited will add the Subscriptions to a shared connection
+        self.shared_subscriptions += 1;
+        self.futures.push(Box::pin(async move {
+            manager.subscribe(subscriptions, exchange_tx, liveness, mutes)
+        }));
+
+        self
+    }
+
+    /// Add a [`Future`] that validates the provided [`Subscription`]s and spawns a consumer loop
+    /// to action them.
+    fn action<Exchange>(
+        mut self,
+        mut subscriptions: Vec<Subscription<Exchange, Kind>>,
+        universe_rx: Option<mpsc::UnboundedReceiver<Vec<Subscription<Exchange, Kind>>>>,
+    ) -> Self
+    where
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Output: StreamItem<Kind::Event> + Debug + Send + 'static,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Acquire channel Sender to send Output from consumer loop to user
         // '--> Add ExchangeChannel Entry if this Exchange <--> SubKind combination is new
         let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
 
+        // Register Subscriptions with the LivenessTracker so the first event of each is awaited
+        subscriptions
+            .iter()
+            .for_each(|subscription| self.liveness.register(subscription));
+        let liveness = self.liveness.clone();
+        let mutes = self.mutes.clone();
+        let connections = self.connections.clone();
+        let config = ConsumerConfig {
+            status: self.statuses.register(Exchange::ID),
+            ..self.consumer.clone()
+        };
+
         // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
         self.futures.push(Box::pin(async move {
-            // Validate Subscriptions
+            // Validate Subscriptions & any base Url override
             validate(&subscriptions)?;
+            if let Some(url) = &config.base_url {
+                validate_base_url(url)?;
+            }
 
             // Remove duplicate Subscriptions
             subscriptions.sort();
             subscriptions.dedup();
 
             // Spawn a MarketStream consumer loop with these Subscriptions<Exchange, Kind>
-            tokio::spawn(consume(subscriptions, exchange_tx));
+            tokio::spawn(consume(
+                subscriptions,
+                exchange_tx,
+                liveness,
+                mutes,
+                connections,
+                universe_rx,
+                config,
+            ));
 
             Ok(())
         }));
@@ -99,7 +417,10 @@ where
     ///
     /// Each consumer loop distributes consumed [`MarketEvent<SubKind::Event>s`](MarketEvent) to
     /// the [`Streams`] `HashMap` returned by this method.
-    pub async fn init(self) -> Result<Streams<MarketEvent<Kind::Event>>, DataError> {
+    pub async fn init(self) -> Result<Streams<Output>, DataError> {
+        // Ensure the configured connection limit is respected before opening any connections
+        validate_connections(self.requested_connections(), self.max_connections)?;
+
         // Await Stream initialisation perpetual and ensure success
         futures::future::try_join_all(self.futures).await?;
 
@@ -110,8 +431,18 @@ where
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, channel.rx))
                 .collect(),
+            liveness: Liveness::from(self.liveness),
+            mutes: Mutes::from(self.mutes),
+            connections: Connections::from(self.connections),
+            statuses: self.statuses,
         })
     }
+
+    /// Number of distinct connections requested via [`subscribe()`](StreamBuilder::subscribe()),
+    /// excluding [`Subscription`]s actioned on shared connections.
+    fn requested_connections(&self) -> usize {
+        self.futures.len() - self.shared_subscriptions
+    }
 }
 
 /// Convenient type that holds the [`mpsc::UnboundedSender`] and [`mpsc::UnboundedReceiver`] for a
@@ -137,7 +468,12 @@ impl<T> Default for ExchangeChannel<T> {
 }
 
 /// Validate the provided collection of [`Subscription`]s, ensuring that the associated exchange
-/// supports every [`Subscription`] [`InstrumentKind`](barter_integration::model::InstrumentKind).
+/// supports every [`Subscription`] [`InstrumentKind`](barter_integration::model::InstrumentKind)
+/// (and any requested OrderBook depth or candle interval).
+///
+/// Every [`Subscription`] is validated before any connection is made, so a
+/// [`SubscriptionError::Unsupported`] lists each unsupported [`Subscription`] rather than only
+/// the first.
 pub fn validate<Exchange, Kind>(
     subscriptions: &[Subscription<Exchange, Kind>],
 ) -> Result<(), DataError>
@@ -147,26 +483,160 @@ where
 {
     // Ensure at least one Subscription has been provided
     if subscriptions.is_empty() {
-        return Err(DataError::Socket(SocketError::Subscribe(
+        return Err(DataError::Socket(Box::new(SocketError::Subscribe(
             "StreamBuilder contains no Subscription to action".to_owned(),
-        )));
+        ))));
     }
 
-    // Validate the Exchange supports each Subscription InstrumentKind
-    subscriptions
+    // Validate the Exchange supports each Subscription, collecting every unsupported Subscription
+    let unsupported = subscriptions
         .iter()
-        .map(|subscription| subscription.validate())
-        .collect::<Result<Vec<_>, SocketError>>()?;
+        .filter_map(|subscription| match subscription.validate() {
+            Ok(_) => None,
+            Err(SocketError::Unsupported { item, .. }) => Some((subscription, item)),
+            Err(error) => Some((subscription, error.to_string())),
+        })
+        .map(|(subscription, reason)| UnsupportedSubscription {
+            instrument: subscription.instrument.clone(),
+            reason,
+        })
+        .collect::<Vec<_>>();
+
+    if !unsupported.is_empty() {
+        return Err(DataError::Subscription(SubscriptionError::Unsupported {
+            exchange: Exchange::ID,
+            kind: kind_name::<Kind>(),
+            unsupported,
+        }));
+    }
 
     Ok(())
 }
 
+/// Unqualified type name of the provided [`SubKind`] (eg/ "OrderBooksL2").
+fn kind_name<Kind>() -> &'static str {
+    let name = std::any::type_name::<Kind>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Validate the provided base [`Url`] override is a well-formed `ws://` or `wss://` WebSocket url.
+pub fn validate_base_url(url: &Url) -> Result<(), DataError> {
+    match (url.scheme(), url.host_str()) {
+        ("ws" | "wss", Some(host)) if !host.is_empty() => Ok(()),
+        _ => Err(DataError::InvalidBaseUrl {
+            url: url.to_string(),
+        }),
+    }
+}
+
+/// Validate the number of requested concurrent connections does not exceed the optional limit.
+pub fn validate_connections(requested: usize, limit: Option<usize>) -> Result<(), DataError> {
+    match limit {
+        Some(limit) if requested > limit => {
+            Err(DataError::ConnectionLimitExceeded { requested, limit })
+        }
+        _ => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{exchange::coinbase::Coinbase, subscription::trade::PublicTrades};
+    use crate::{
+        exchange::coinbase::Coinbase,
+        subscription::trade::{PublicTrade, PublicTrades},
+    };
     use barter_integration::model::instrument::kind::InstrumentKind;
 
+    #[tokio::test]
+    async fn test_init_exceeding_max_connections() {
+        let subscription =
+            || Subscription::from((Coinbase, "btc", "usd", InstrumentKind::Spot, PublicTrades));
+
+        // Each subscribe() call requires a distinct connection
+        let builder = || {
+            Streams::<MarketEvent<PublicTrade>>::builder()
+                .subscribe([subscription()])
+                .subscribe([subscription()])
+                .subscribe([subscription()])
+        };
+
+        // StreamBuilder: connection limit exceeded before any connection is opened
+        let actual = builder().max_connections(2).init().await;
+        assert!(matches!(
+            actual,
+            Err(DataError::ConnectionLimitExceeded {
+                requested: 3,
+                limit: 2
+            })
+        ));
+
+        // MultiStreamBuilder: connection limit applies across all added StreamBuilders
+        let actual = Streams::<MarketEvent<PublicTrade>>::builder_multi()
+            .add(builder())
+            .add(StreamBuilder::new().subscribe([subscription()]))
+            .max_connections(3)
+            .init()
+            .await;
+        assert!(matches!(
+            actual,
+            Err(DataError::ConnectionLimitExceeded {
+                requested: 4,
+                limit: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_subscribe_binance_with_mixed_instrument_kinds() {
+        let builder = Streams::<MarketEvent<PublicTrade>>::builder().subscribe_binance([
+            (
+                BinanceAuto,
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ),
+            (
+                BinanceAuto,
+                "eth",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ),
+            (
+                BinanceAuto,
+                "btc",
+                "usdt",
+                InstrumentKind::Perpetual,
+                PublicTrades,
+            ),
+        ]);
+
+        // One spot connection & one futures connection
+        assert_eq!(builder.requested_connections(), 2);
+        let mut exchanges = builder.channels.keys().copied().collect::<Vec<_>>();
+        exchanges.sort();
+        assert_eq!(
+            exchanges,
+            vec![ExchangeId::BinanceFuturesUsd, ExchangeId::BinanceSpot]
+        );
+
+        // Single kind Subscriptions only require one connection
+        let builder = Streams::<MarketEvent<PublicTrade>>::builder().subscribe_binance([(
+            BinanceAuto,
+            "btc",
+            "usdt",
+            InstrumentKind::Perpetual,
+            PublicTrades,
+        )]);
+        assert_eq!(builder.requested_connections(), 1);
+        assert_eq!(
+            builder.channels.keys().copied().collect::<Vec<_>>(),
+            vec![ExchangeId::BinanceFuturesUsd]
+        );
+    }
+
     #[test]
     fn test_validate() {
         struct TestCase {
@@ -227,4 +697,128 @@ mod tests {
             }
         }
     }
+    #[test]
+    fn test_validate_lists_every_unsupported_subscription() {
+        let subscription = |base: &str, kind: InstrumentKind| {
+            Subscription::from((Coinbase, base, "usd", kind, PublicTrades))
+        };
+
+        let actual = validate(&[
+            subscription("btc", InstrumentKind::Spot),
+            subscription("eth", InstrumentKind::Perpetual),
+            subscription("sol", InstrumentKind::Spot),
+            subscription("xrp", InstrumentKind::Perpetual),
+        ]);
+
+        match actual {
+            Err(DataError::Subscription(SubscriptionError::Unsupported {
+                exchange,
+                kind,
+                unsupported,
+            })) => {
+                assert_eq!(exchange, ExchangeId::Coinbase);
+                assert_eq!(kind, "PublicTrades");
+                assert_eq!(
+                    unsupported,
+                    vec![
+                        UnsupportedSubscription {
+                            instrument: subscription("eth", InstrumentKind::Perpetual).instrument,
+                            reason: InstrumentKind::Perpetual.to_string(),
+                        },
+                        UnsupportedSubscription {
+                            instrument: subscription("xrp", InstrumentKind::Perpetual).instrument,
+                            reason: InstrumentKind::Perpetual.to_string(),
+                        },
+                    ]
+                );
+            }
+            actual => panic!("expected SubscriptionError::Unsupported, got {actual:?}"),
+        }
+    }
+    #[test]
+    fn test_validate_base_url() {
+        struct TestCase {
+            input: &'static str,
+            expected_valid: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: wss:// testnet url is valid
+                input: "wss://testnet.binance.vision/ws",
+                expected_valid: true,
+            },
+            TestCase {
+                // TC1: ws:// proxy url is valid
+                input: "ws://127.0.0.1:9443",
+                expected_valid: true,
+            },
+            TestCase {
+                // TC2: https:// url is invalid
+                input: "https://testnet.binance.vision/ws",
+                expected_valid: false,
+            },
+            TestCase {
+                // TC3: url without a host is invalid
+                input: "unix:/var/run/proxy.sock",
+                expected_valid: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = validate_base_url(&Url::parse(test.input).unwrap());
+            match (actual, test.expected_valid) {
+                (Ok(()), true) => {}
+                (Err(DataError::InvalidBaseUrl { url }), false) => {
+                    assert_eq!(url, test.input, "TC{index} failed")
+                }
+                (actual, _) => panic!("TC{index} failed: {actual:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_init_with_base_url_connects_to_override() {
+        use crate::mock::{MockExchangeServer, MockStep};
+
+        let subscription = || {
+            Subscription::from((
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ))
+        };
+
+        // Invalid base url is rejected before connecting
+        let actual = Streams::<MarketEvent<PublicTrade>>::builder()
+            .with_base_url(Url::parse("https://127.0.0.1").unwrap())
+            .subscribe([subscription()])
+            .init()
+            .await;
+        assert!(matches!(actual, Err(DataError::InvalidBaseUrl { .. })));
+
+        // Valid base url is connected to instead of the default BinanceSpot url
+        let server = MockExchangeServer::start([MockStep::trade("BTCUSDT", 1, 100.0)])
+            .await
+            .unwrap();
+
+        let mut streams = Streams::<MarketEvent<PublicTrade>>::builder()
+            .with_base_url(server.url())
+            .subscribe([subscription()])
+            .init()
+            .await
+            .unwrap();
+
+        let event = streams
+            .select(ExchangeId::BinanceSpot)
+            .unwrap()
+            .recv()
+            .await
+            .unwrap();
+        assert_eq!(event.kind.id, "1");
+        assert_eq!(server.connections(), 1);
+        assert_eq!(server.subscriptions(), vec!["btcusdt@trade".to_string()]);
+    }
 }
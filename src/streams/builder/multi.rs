@@ -1,4 +1,7 @@
-use super::{ExchangeChannel, StreamBuilder, Streams};
+use super::{
+    validate_connections, ConnectionStatuses, Connections, ExchangeChannel, Liveness, Mutes,
+    StreamBuilder, Streams,
+};
 use crate::{error::DataError, event::MarketEvent, exchange::ExchangeId, subscription::SubKind};
 use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
 
@@ -12,6 +15,12 @@ pub type BuilderInitFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>
 pub struct MultiStreamBuilder<Output> {
     pub channels: HashMap<ExchangeId, ExchangeChannel<Output>>,
     pub futures: Vec<BuilderInitFuture>,
+    pub liveness: Liveness,
+    pub mutes: Mutes,
+    pub connections: Connections,
+    pub statuses: ConnectionStatuses,
+    pub max_connections: Option<usize>,
+    requested_connections: usize,
 }
 
 impl<Output> Debug for MultiStreamBuilder<Output>
@@ -22,6 +31,7 @@ where
         f.debug_struct("MultiStreamBuilder<Output>")
             .field("channels", &self.channels)
             .field("num_futures", &self.futures.len())
+            .field("max_connections", &self.max_connections)
             .finish()
     }
 }
@@ -32,9 +42,26 @@ impl<Output> MultiStreamBuilder<Output> {
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            liveness: Liveness::default(),
+            mutes: Mutes::default(),
+            connections: Connections::default(),
+            statuses: ConnectionStatuses::default(),
+            max_connections: None,
+            requested_connections: 0,
         }
     }
 
+    /// Limit the total number of concurrent connections opened by every
+    /// [`StreamBuilder<SubKind>`](StreamBuilder) added to the [`MultiStreamBuilder`].
+    ///
+    /// [`init()`](MultiStreamBuilder::init()) returns a
+    /// [`DataError::ConnectionLimitExceeded`] if the added [`StreamBuilder`]s request more than
+    /// `limit` connections in total. See [`StreamBuilder::max_connections`].
+    pub fn max_connections(mut self, limit: usize) -> Self {
+        self.max_connections = Some(limit);
+        self
+    }
+
     /// Add a [`StreamBuilder<SubKind>`](StreamBuilder) to the [`MultiStreamBuilder`]. Creates a
     /// [`Future`] that calls [`StreamBuilder::init`] and maps the [`SubKind::Event`](SubKind)
     /// into a common `Output`.
@@ -60,6 +87,19 @@ impl<Output> MultiStreamBuilder<Output> {
             exchange_txs.insert(exchange, exchange_tx);
         }
 
+        // Track the liveness of the StreamBuilder Subscriptions alongside the others
+        self.liveness
+            .merge(Liveness::from(builder.liveness.clone()));
+
+        // Allow the StreamBuilder Subscriptions to be muted alongside the others
+        self.mutes.merge(Mutes::from(builder.mutes.clone()));
+
+        // Count the StreamBuilder connections alongside the others
+        self.connections
+            .merge(Connections::from(builder.connections.clone()));
+        self.requested_connections += builder.requested_connections();
+        self.statuses.merge(builder.statuses.clone());
+
         // Init Streams<Kind::Event> & send mapped Outputs to the associated exchange_tx
         self.futures.push(Box::pin(async move {
             builder
@@ -92,6 +132,9 @@ impl<Output> MultiStreamBuilder<Output> {
     /// [`MultiStreamBuilder`] and map all [`Streams<SubKind::Event>`](Streams) into a common
     /// [`Streams<Output>`](Streams).
     pub async fn init(self) -> Result<Streams<Output>, DataError> {
+        // Ensure the configured connection limit is respected before opening any connections
+        validate_connections(self.requested_connections, self.max_connections)?;
+
         // Await Stream initialisation perpetual and ensure success
         futures::future::try_join_all(self.futures).await?;
 
@@ -102,6 +145,10 @@ impl<Output> MultiStreamBuilder<Output> {
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, channel.rx))
                 .collect(),
+            liveness: self.liveness,
+            mutes: self.mutes,
+            connections: self.connections,
+            statuses: self.statuses,
         })
     }
 }
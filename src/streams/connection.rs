@@ -0,0 +1,108 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// Handle used to observe the number of concurrently open exchange
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connections of the
+/// [`Streams`](super::Streams), eg/ to export as a metric.
+///
+/// ### Notes
+/// - A connection is counted as open from when its [`MarketStream`](crate::MarketStream) is
+///   successfully initialised, until it ends (eg/ disconnect before re-connecting).
+/// - Each [`StreamBuilder`](super::builder::StreamBuilder) owns a [`ConnectionCounter`], which is
+///   merged into the [`Connections`] of any
+///   [`MultiStreamBuilder`](super::builder::multi::MultiStreamBuilder) it is added to.
+#[derive(Clone, Debug, Default)]
+pub struct Connections {
+    counters: Vec<ConnectionCounter>,
+}
+
+impl Connections {
+    /// Merge the [`ConnectionCounter`]s of another [`Connections`] into [`Self`].
+    pub fn merge(&mut self, other: Connections) {
+        self.counters.extend(other.counters);
+    }
+
+    /// Number of currently open connections.
+    pub fn open(&self) -> usize {
+        self.counters.iter().map(ConnectionCounter::open).sum()
+    }
+}
+
+impl From<ConnectionCounter> for Connections {
+    fn from(counter: ConnectionCounter) -> Self {
+        Self {
+            counters: vec![counter],
+        }
+    }
+}
+
+/// Shared count of open connections, incremented by a [`consume`](super::consumer::consume) loop
+/// for as long as it holds the [`ConnectionGuard`] of an initialised
+/// [`MarketStream`](crate::MarketStream).
+#[derive(Clone, Debug, Default)]
+pub struct ConnectionCounter {
+    open: Arc<AtomicUsize>,
+}
+
+impl ConnectionCounter {
+    /// Construct a new [`Self`] with no open connections.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count a newly opened connection until the returned [`ConnectionGuard`] is dropped.
+    pub fn connect(&self) -> ConnectionGuard {
+        self.open.fetch_add(1, Ordering::Relaxed);
+        ConnectionGuard {
+            open: Arc::clone(&self.open),
+        }
+    }
+
+    /// Number of currently open connections.
+    pub fn open(&self) -> usize {
+        self.open.load(Ordering::Relaxed)
+    }
+}
+
+/// Guard representing an open connection counted by a [`ConnectionCounter`]. The connection is
+/// no longer counted once dropped.
+#[derive(Debug)]
+pub struct ConnectionGuard {
+    open: Arc<AtomicUsize>,
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.open.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connections_count_open_guards() {
+        let counter_a = ConnectionCounter::new();
+        let counter_b = ConnectionCounter::new();
+
+        let mut connections = Connections::from(counter_a.clone());
+        connections.merge(Connections::from(counter_b.clone()));
+        assert_eq!(connections.open(), 0);
+
+        let guard_a = counter_a.connect();
+        let guard_b = counter_b.connect();
+        let reconnected_b = counter_b.connect();
+        assert_eq!(connections.open(), 3);
+
+        drop(guard_b);
+        assert_eq!(counter_b.open(), 1);
+        assert_eq!(connections.open(), 2);
+
+        drop(guard_a);
+        drop(reconnected_b);
+        assert_eq!(connections.open(), 0);
+    }
+}
@@ -1,36 +1,232 @@
+use super::{
+    connection::ConnectionCounter,
+    liveness::LivenessTracker,
+    maintenance::MaintenanceSchedule,
+    metrics::{Metrics, NoopMetrics},
+    mute::MuteSwitch,
+    reconcile::SubscriptionDiff,
+    shutdown::{Shutdown, SHUTDOWN_DRAIN_TIMEOUT},
+    status::ConnectionStatus,
+};
 use crate::{
     error::DataError,
-    event::MarketEvent,
-    exchange::StreamSelector,
-    subscription::{SubKind, Subscription},
+    event::StreamItem,
+    exchange::{subscription::ExchangeSub, with_base_url, ExchangeId, StreamSelector},
+    protocol::with_max_raw_frame_len,
+    subscription::{
+        resume::ResumeFrom,
+        trade::{with_trade_fields, TradeFields},
+        SubKind, Subscription,
+    },
+    transformer::book::{with_book_watchdog, BookWatchdog},
     Identifier, MarketStream,
 };
+use barter_integration::model::SubscriptionId;
+use chrono::Utc;
 use futures::StreamExt;
-use std::time::Duration;
-use tokio::sync::mpsc;
-use tracing::{error, info, warn};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, fmt::Debug, sync::Arc, time::Duration};
+use tokio::{
+    sync::{mpsc, watch},
+    time::Instant,
+};
+use tracing::{error, info, info_span, warn, Instrument, Span};
+use url::Url;
 
 /// Initial duration that the [`consume`] function should wait after disconnecting before attempting
 /// to re-initialise a [`MarketStream`]. This duration will increase exponentially as a result
 /// of repeated disconnections with re-initialisation failures.
 pub const STARTING_RECONNECT_BACKOFF_MS: u64 = 125;
 
-/// Central [`MarketEvent<T>`](MarketEvent) consumer loop.
+/// Minimum duration that the [`consume`] function should wait before attempting to re-initialise
+/// a [`MarketStream`] after the exchange closed the connection due to a policy violation (eg/ rate
+/// limit) or a "try again later" condition.
+pub const THROTTLED_RECONNECT_BACKOFF_MS: u64 = 30_000;
+
+/// Default maximum duration that the [`consume`] function waits between re-connection attempts.
+pub const DEFAULT_MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Exponential backoff policy used by the [`consume`] function between re-connection attempts.
+///
+/// The `initial` delay is used after a [`MarketStream`] ends, and doubles for each consecutive
+/// failed re-initialisation, up to the `max` delay. A random fraction of up to `jitter` (between
+/// 0 and 1) of each delay is then subtracted, de-synchronising the re-connections of many
+/// consumers dropped at the same time.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct ReconnectBackoff {
+    pub initial: Duration,
+    pub max: Duration,
+    pub jitter: f64,
+}
+
+impl Default for ReconnectBackoff {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_millis(STARTING_RECONNECT_BACKOFF_MS),
+            max: DEFAULT_MAX_RECONNECT_BACKOFF,
+            jitter: 0.0,
+        }
+    }
+}
+
+impl ReconnectBackoff {
+    /// Construct a new [`Self`] with the provided `initial` delay, `max` delay, and `jitter`.
+    pub fn new(initial: Duration, max: Duration, jitter: f64) -> Self {
+        Self {
+            initial,
+            max,
+            jitter,
+        }
+    }
+
+    /// Delay to wait after the provided number of consecutive failed re-initialisations.
+    pub fn delay(&self, failures: u32) -> Duration {
+        let delay = self
+            .initial
+            .checked_mul(2_u32.saturating_pow(failures))
+            .unwrap_or(Duration::MAX)
+            .min(self.max);
+
+        // Uniformly distributed random fraction in [0, 1) of the jitter to subtract
+        let random = rand::thread_rng().gen::<f64>();
+        delay.mul_f64(1.0 - self.jitter.clamp(0.0, 1.0) * random)
+    }
+}
+
+/// Optional behaviours of a [`consume`] loop.
+#[derive(Clone, Debug)]
+pub struct ConsumerConfig {
+    /// Exponential backoff policy used between re-connection attempts.
+    pub backoff: ReconnectBackoff,
+    /// Interval at which a connected but quiet [`MarketStream`] sends a heartbeat, if the
+    /// `Output` [`StreamItem`] represents heartbeats.
+    pub heartbeat: Option<Duration>,
+    /// Known exchange maintenance windows, during which re-connection attempts are made at the
+    /// extended [`MaintenanceSchedule::reconnect_interval`].
+    pub maintenance: MaintenanceSchedule,
+    /// Handle used to gracefully close the connection & stop the consumer loop without
+    /// re-connecting.
+    pub shutdown: Shutdown,
+    /// [`Metrics`] used to record consumed events, parse errors & re-connections.
+    pub metrics: Arc<dyn Metrics>,
+    /// [`watch::Sender`] used to publish the [`ConnectionStatus`] of the connection at each
+    /// lifecycle transition. Defaults to a [`watch::Sender`] without any receivers.
+    pub status: watch::Sender<ConnectionStatus>,
+    /// Base [`Url`] every (re)connection is established with, taking precedence over the
+    /// [`Connector::url`](crate::exchange::Connector::url) (see [`with_base_url`]).
+    pub base_url: Option<Url>,
+    /// Maximum number of bytes of a raw frame attached to the
+    /// [`RawFrameError`](crate::error::RawFrameError)s of every (re)connection, defaulting to the
+    /// [`DEFAULT_MAX_RAW_FRAME_LEN`](crate::protocol::DEFAULT_MAX_RAW_FRAME_LEN) (see
+    /// [`with_max_raw_frame_len`]).
+    pub max_raw_frame_len: Option<usize>,
+    /// [`TradeFields`] populated by the trade transformer of every (re)connection (see
+    /// [`with_trade_fields`]).
+    pub trade_fields: TradeFields,
+    /// [`BookWatchdog`] of the OrderBook transformer of every (re)connection (see
+    /// [`with_book_watchdog`]).
+    pub book_watchdog: Option<BookWatchdog>,
+}
+
+impl Default for ConsumerConfig {
+    fn default() -> Self {
+        Self {
+            backoff: ReconnectBackoff::default(),
+            heartbeat: None,
+            maintenance: MaintenanceSchedule::default(),
+            shutdown: Shutdown::default(),
+            metrics: Arc::new(NoopMetrics),
+            status: watch::channel(ConnectionStatus::Connecting).0,
+            base_url: None,
+            max_raw_frame_len: None,
+            trade_fields: TradeFields::default(),
+            book_watchdog: None,
+        }
+    }
+}
+
+/// Central [`MarketEvent<T>`](crate::event::MarketEvent) consumer loop.
 ///
 /// Initialises an exchange [`MarketStream`] using a collection of [`Subscription`]s. Consumed
 /// events are distributed downstream via the `exchange_tx mpsc::UnboundedSender`. A re-connection
-/// mechanism with an exponential backoff policy is utilised to ensure maximum up-time.
-pub async fn consume<Exchange, Kind>(
+/// mechanism with an exponential [`ReconnectBackoff`] policy is utilised to ensure maximum
+/// up-time, re-issuing the [`Subscription`]s on every re-connection.
+///
+/// The first event consumed for each [`Subscription`] is recorded with the provided
+/// [`LivenessTracker`]. Events of [`Subscription`]s muted via the [`MuteSwitch`] are consumed
+/// but not distributed. Each open connection is counted by the provided [`ConnectionCounter`]
+/// until its [`MarketStream`] ends.
+///
+/// Target [`Subscription`] universes received via the optional `universe_rx` (see
+/// [`Reconciler`](super::reconcile::Reconciler)) are applied over the open connection by sending
+/// incremental subscribe & unsubscribe requests (see [`MarketStream::update_subscriptions`]),
+/// dropping any in-flight messages of the removed [`Subscription`]s. If the [`MarketStream`]
+/// cannot be updated live, it is instead re-initialised with the target [`Subscription`]s.
+///
+/// The last-seen sequence of each [`Subscription`] the exchange sequences (see
+/// [`StreamSelector::sequence`]) is tracked, and used to resume the [`MarketStream`] on
+/// re-connection where the exchange supports it (see [`ResumeFrom`]). Events replayed by the
+/// exchange after resuming are discarded. If the exchange resets a sequence (see
+/// [`ResumeFrom::is_reset`]), the new sequence is accepted as the baseline and the
+/// [`MarketStream`] is re-initialised once without resuming that [`Subscription`] (eg/
+/// re-fetching its OrderBook snapshot).
+///
+/// If the `Output` [`StreamItem`] represents connection lifecycle markers (eg/
+/// [`StreamEvent<T>`](crate::event::StreamEvent)), a marker is sent after every successful
+/// (re)connection, before any [`MarketEvent<T>`](crate::event::MarketEvent) consumed from that connection.
+///
+/// If a [`ConsumerConfig::heartbeat`] interval is provided and the `Output` [`StreamItem`]
+/// represents heartbeats, a heartbeat is sent whenever a connected [`MarketStream`] has not
+/// distributed a [`MarketEvent<T>`](crate::event::MarketEvent) for that interval. No heartbeats
+/// are sent whilst disconnected.
+///
+/// Whilst the exchange is within a [`ConsumerConfig::maintenance`] window, failed
+/// initialisations are always retried, re-connection attempts are made at the extended
+/// maintenance interval rather than with the exponential backoff, and a maintenance marker is
+/// sent if the `Output` [`StreamItem`] represents them.
+///
+/// Every [`MarketEvent<T>`](crate::event::MarketEvent) consumed (including those subsequently
+/// discarded), every frame that failed to deserialise, and every successful re-connection is
+/// recorded with the [`ConsumerConfig::metrics`].
+///
+/// Every connection lifecycle transition (connecting, connected, re-connecting & disconnected)
+/// is published via the [`ConsumerConfig::status`] [`watch::Sender`].
+///
+/// Once the [`ConsumerConfig::shutdown`] handle is triggered, the connection is gracefully closed
+/// (see [`MarketStream::close`]), any in-flight [`MarketEvent<T>`](crate::event::MarketEvent)s
+/// are distributed, and [`DataError::ConsumerTerminated`] is returned without re-connecting.
+pub async fn consume<Exchange, Kind, Output>(
     subscriptions: Vec<Subscription<Exchange, Kind>>,
-    exchange_tx: mpsc::UnboundedSender<MarketEvent<Kind::Event>>,
+    exchange_tx: mpsc::UnboundedSender<Output>,
+    liveness: LivenessTracker,
+    mutes: MuteSwitch,
+    connections: ConnectionCounter,
+    mut universe_rx: Option<mpsc::UnboundedReceiver<Vec<Subscription<Exchange, Kind>>>>,
+    config: ConsumerConfig,
 ) -> DataError
 where
-    Exchange: StreamSelector<Kind>,
-    Kind: SubKind,
-    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    Exchange: StreamSelector<Kind> + Sync,
+    Kind: SubKind + Sync,
+    Output: StreamItem<Kind::Event> + Debug,
+    Subscription<Exchange, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market> + PartialEq,
 {
     // Determine ExchangeId associated with these Subscriptions
     let exchange = Exchange::ID;
+    let mut subscriptions = subscriptions;
+    let ConsumerConfig {
+        backoff: policy,
+        heartbeat,
+        maintenance,
+        shutdown,
+        metrics,
+        status,
+        base_url,
+        max_raw_frame_len,
+        trade_fields,
+        book_watchdog,
+    } = config;
 
     info!(
         %exchange,
@@ -39,42 +235,197 @@ where
         "MarketStream consumer loop running",
     );
 
+    // Instruments that have not yet produced a MarketEvent
+    let mut pending = subscriptions
+        .iter()
+        .map(|subscription| subscription.instrument.clone())
+        .collect::<HashSet<_>>();
+
+    // Last-seen sequence of each Instrument, used to resume the MarketStream on re-connection
+    let mut resume = ResumeFrom::default();
+
     // Consumer loop retry parameters
     let mut attempt: u32 = 0;
-    let mut backoff_ms: u64 = STARTING_RECONNECT_BACKOFF_MS;
+    let mut backoff: Duration;
+    let mut connected_before = false;
 
     loop {
+        // Stop without re-connecting once shut down
+        if shutdown.is_triggered() {
+            info!(%exchange, "MarketStream consumer loop shut down");
+            status.send_replace(ConnectionStatus::Disconnected {
+                reason: "shut down".to_string(),
+            });
+            return DataError::ConsumerTerminated { exchange };
+        }
+
         // Increment retry parameters at start of every iteration
         attempt += 1;
-        backoff_ms *= 2;
-        info!(%exchange, attempt, "attempting to initialise MarketStream");
-
-        // Attempt to initialise MarketStream: if it fails on first attempt return DataError
-        let mut stream = match Exchange::Stream::init(&subscriptions).await {
-            Ok(stream) => {
-                info!(%exchange, attempt, "successfully initialised MarketStream");
-                attempt = 0;
-                backoff_ms = STARTING_RECONNECT_BACKOFF_MS;
-                stream
-            }
-            Err(error) => {
-                error!(%exchange, attempt, ?error, "failed to initialise MarketStream");
-
-                // Exit function function if Stream::init failed the first attempt, else retry
-                if attempt == 1 {
-                    return error;
-                } else {
+        backoff = policy.delay(attempt);
+        status.send_replace(match connected_before {
+            true => ConnectionStatus::Reconnecting { attempt },
+            false => ConnectionStatus::Connecting,
+        });
+        let span = connection_span(exchange, attempt, &subscriptions);
+        info!(parent: &span, %exchange, attempt, "attempting to initialise MarketStream");
+
+        // Attempt to initialise MarketStream: if it fails on the first connection return DataError
+        let init = with_max_raw_frame_len(
+            max_raw_frame_len,
+            with_base_url(
+                base_url.clone(),
+                Exchange::Stream::init_from(&subscriptions, &resume),
+            ),
+        );
+        let mut stream =
+            match with_book_watchdog(book_watchdog, with_trade_fields(trade_fields, init))
+                .instrument(span.clone())
+                .await
+            {
+                Ok(stream) => {
+                    info!(%exchange, attempt, "successfully initialised MarketStream");
+                    attempt = 0;
+                    backoff = policy.delay(attempt);
+
+                    if connected_before {
+                        metrics.record_reconnect(exchange);
+                    }
+                    status.send_replace(ConnectionStatus::Connected);
+
+                    // Mark the (re)connection before any MarketEvent it yields is sent downstream
+                    if let Some(marker) = Output::connected(exchange, connected_before) {
+                        let _ = exchange_tx.send(marker);
+                    }
+                    connected_before = true;
+
+                    stream
+                }
+                Err(error) => {
+                    error!(%exchange, attempt, ?error, "failed to initialise MarketStream");
+
+                    // Exit function function if Stream::init failed the first attempt of the first
+                    // connection outside of a scheduled maintenance window, or if an exchange
+                    // subscription limit was hit (retrying would be futile), else retry
+                    let in_maintenance = maintenance.active(exchange, Utc::now()).is_some();
+                    let first_connection = attempt == 1 && !connected_before;
+                    if (first_connection && !in_maintenance) || error.is_limit_exceeded() {
+                        if let Some(marker) = Output::failed(exchange, error.to_string()) {
+                            let _ = exchange_tx.send(marker);
+                        }
+                        status.send_replace(ConnectionStatus::Disconnected {
+                            reason: error.to_string(),
+                        });
+                        return error;
+                    } else {
+                        wait_to_reconnect(exchange, &maintenance, &shutdown, &exchange_tx, backoff)
+                            .await;
+                        continue;
+                    }
+                }
+            };
+
+        // Count the open connection until the MarketStream ends
+        let _connection = connections.connect();
+
+        // Deadline of the next heartbeat, postponed by every MarketEvent sent downstream
+        let mut heartbeat_at = heartbeat.map(|interval| Instant::now() + interval);
+
+        // SubscriptionIds unsubscribed over this connection, whose in-flight messages are dropped
+        let mut unsubscribed = HashSet::new();
+
+        // Consume Result<MarketEvent<T>, DataError> from MarketStream until it ends, a new
+        // target Subscription universe is received, or the exchange resets a sequence
+        let interruption = loop {
+            let event_result = tokio::select! {
+                biased;
+                _ = shutdown.triggered() => break Interruption::Shutdown,
+                target = next_universe(&mut universe_rx) => {
+                    // Apply the target universe over the open connection where supported
+                    let diff = SubscriptionDiff::between(&subscriptions, &target);
+                    if !stream.update_subscriptions(&diff.added, &diff.removed) {
+                        break Interruption::Universe(target);
+                    }
+
+                    info!(
+                        %exchange,
+                        added = diff.added.len(),
+                        removed = diff.removed.len(),
+                        action = "sent incremental subscription requests over the open connection",
+                        "reconciling MarketStream Subscription universe",
+                    );
+                    unsubscribed.extend(diff.removed.iter().map(subscription_id));
+                    diff.added.iter().for_each(|subscription| {
+                        unsubscribed.remove(&subscription_id(subscription));
+                    });
+                    reconcile_universe(target, &mut subscriptions, &liveness, &mut pending, &mut resume);
                     continue;
                 }
+                event_result = stream.next().instrument(span.clone()) => match event_result {
+                    Some(event_result) => event_result,
+                    None => {
+                        status.send_replace(ConnectionStatus::Disconnected {
+                            reason: "MarketStream ended".to_string(),
+                        });
+                        break Interruption::Ended;
+                    }
+                },
+                _ = next_heartbeat(heartbeat_at) => {
+                    if let Some(heartbeat) = Output::heartbeat(exchange) {
+                        let _ = exchange_tx.send(heartbeat);
+                    }
+                    heartbeat_at = heartbeat.map(|interval| Instant::now() + interval);
+                    continue;
+                }
+            };
+
+            match &event_result {
+                Ok(_) => metrics.record_event(exchange, Kind::ID),
+                Err(DataError::Deserialise { .. } | DataError::RawFrame(_)) => {
+                    metrics.record_parse_error(exchange)
+                }
+                Err(_) => {}
             }
-        };
 
-        // Consume Result<MarketEvent<T>, DataError> from MarketStream
-        while let Some(event_result) = stream.next().await {
             match event_result {
                 // If Ok: send MarketEvent<T> to exchange receiver
                 Ok(market_event) => {
-                    let _ = exchange_tx.send(market_event).map_err(|err| {
+                    // Discard events replayed by the exchange after resuming, else record them
+                    if let Some(sequence) = Exchange::sequence(&market_event.kind) {
+                        // Accept the new baseline of a reset sequence & resync once, rather than
+                        // discarding every subsequent event as stale
+                        if resume.is_reset(&market_event.instrument, sequence) {
+                            warn!(
+                                %exchange,
+                                instrument = %market_event.instrument,
+                                last_sequence = ?resume.sequence(&market_event.instrument),
+                                sequence,
+                                action = "accepting new sequence baseline & re-initialising Stream",
+                                "exchange reset the MarketStream sequence",
+                            );
+                            resume.forget(&market_event.instrument);
+                            break Interruption::SequenceReset;
+                        }
+                        if resume.is_stale(&market_event.instrument, sequence) {
+                            continue;
+                        }
+                        resume.record(&market_event.instrument, sequence);
+                    }
+
+                    // Record the first MarketEvent of each Subscription with the LivenessTracker
+                    if pending.remove(&market_event.instrument) {
+                        subscriptions
+                            .iter()
+                            .filter(|sub| sub.instrument == *market_event.instrument)
+                            .for_each(|sub| liveness.record(exchange, &sub.kind, &sub.instrument));
+                    }
+
+                    // Suppress MarketEvents of muted Subscriptions
+                    if mutes.is_muted(exchange, &market_event.instrument) {
+                        continue;
+                    }
+
+                    heartbeat_at = heartbeat.map(|interval| Instant::now() + interval);
+                    let _ = exchange_tx.send(Output::from(market_event)).map_err(|err| {
                         error!(
                             payload = ?err.0,
                             why = "receiver dropped",
@@ -84,13 +435,29 @@ where
                 }
                 // If terminal DataError: break
                 Err(error) if error.is_terminal() => {
+                    // Avoid hammering an exchange that explicitly asked us to back off
+                    if error.is_connection_throttled() {
+                        backoff =
+                            backoff.max(Duration::from_millis(THROTTLED_RECONNECT_BACKOFF_MS));
+                    }
+
                     error!(
                         %exchange,
                         %error,
                         action = "re-initialising Stream",
                         "consumed DataError from MarketStream",
                     );
-                    break;
+                    status.send_replace(ConnectionStatus::Disconnected {
+                        reason: error.to_string(),
+                    });
+                    break Interruption::Ended;
+                }
+
+                // Drop in-flight messages of Subscriptions unsubscribed over this connection
+                Err(DataError::Unidentifiable(subscription_id))
+                    if unsubscribed.contains(&subscription_id) =>
+                {
+                    continue;
                 }
 
                 // If non-terminal DataError: log & continue
@@ -104,15 +471,1672 @@ where
                     continue;
                 }
             }
+        };
+
+        match interruption {
+            // If a target Subscription universe cannot be applied live, re-initialise immediately
+            Interruption::Universe(target) => {
+                info!(
+                    %exchange,
+                    ?target,
+                    action = "re-initialising Stream with target Subscriptions",
+                    "reconciling MarketStream Subscription universe",
+                );
+
+                reconcile_universe(
+                    target,
+                    &mut subscriptions,
+                    &liveness,
+                    &mut pending,
+                    &mut resume,
+                );
+            }
+
+            // If the exchange reset a sequence, resync immediately without resuming that Instrument
+            Interruption::SequenceReset => {}
+
+            // If MarketStream ends unexpectedly, attempt re-connection after backoff
+            Interruption::Ended => {
+                warn!(
+                    %exchange,
+                    ?backoff,
+                    action = "attempt re-connection after backoff",
+                    "exchange MarketStream unexpectedly ended"
+                );
+                wait_to_reconnect(exchange, &maintenance, &shutdown, &exchange_tx, backoff).await;
+            }
+
+            // If shut down, gracefully close the connection & distribute in-flight MarketEvents
+            Interruption::Shutdown => {
+                let requests = match shutdown.unsubscribes() {
+                    true => Exchange::unsubscribe_requests(
+                        subscriptions
+                            .iter()
+                            .map(ExchangeSub::<Exchange::Channel, Exchange::Market>::new)
+                            .collect(),
+                    ),
+                    false => vec![],
+                };
+
+                info!(
+                    %exchange,
+                    unsubscribe_requests = requests.len(),
+                    action = "closing connection & draining in-flight messages",
+                    "shutting down MarketStream consumer loop",
+                );
+
+                if stream.close(requests) {
+                    let drain = async {
+                        while let Some(event_result) = stream.next().instrument(span.clone()).await
+                        {
+                            if let Ok(market_event) = event_result {
+                                metrics.record_event(exchange, Kind::ID);
+                                if !mutes.is_muted(exchange, &market_event.instrument) {
+                                    let _ = exchange_tx.send(Output::from(market_event));
+                                }
+                            }
+                        }
+                    };
+                    if tokio::time::timeout(SHUTDOWN_DRAIN_TIMEOUT, drain)
+                        .await
+                        .is_err()
+                    {
+                        warn!(
+                            %exchange,
+                            timeout = ?SHUTDOWN_DRAIN_TIMEOUT,
+                            "exchange did not acknowledge the CloseFrame, dropping connection"
+                        );
+                    }
+                }
+
+                info!(%exchange, "MarketStream consumer loop shut down");
+                status.send_replace(ConnectionStatus::Disconnected {
+                    reason: "shut down".to_string(),
+                });
+                return DataError::ConsumerTerminated { exchange };
+            }
+        }
+    }
+}
+
+/// Construct the "connection" [`Span`] within which a [`consume`] loop initialises & consumes a
+/// single connection, carrying the [`ExchangeId`] and the [`SubscriptionId`]s of every
+/// [`Subscription`] actioned on it.
+///
+/// Events of the subscribe & validation handshake, and of every parsed frame (eg/ a frame that
+/// failed to deserialise), are therefore attributable to the [`SubscriptionId`]s that produced
+/// them.
+fn connection_span<Exchange, Kind>(
+    exchange: ExchangeId,
+    attempt: u32,
+    subscriptions: &[Subscription<Exchange, Kind>],
+) -> Span
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let subscription_ids = subscriptions
+        .iter()
+        .map(|subscription| subscription_id(subscription).0)
+        .collect::<Vec<_>>()
+        .join(",");
+
+    info_span!("connection", %exchange, attempt, subscriptions = %subscription_ids)
+}
+
+/// [`SubscriptionId`] the exchange uses to identify the messages of the [`Subscription`].
+fn subscription_id<Exchange, Kind>(subscription: &Subscription<Exchange, Kind>) -> SubscriptionId
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    Identifier::<SubscriptionId>::id(&ExchangeSub::<Exchange::Channel, Exchange::Market>::new(
+        subscription,
+    ))
+}
+
+/// Converge the [`consume`] loop state on the target [`Subscription`] universe, registering
+/// added [`Subscription`]s with the [`LivenessTracker`], and forgetting the pending liveness &
+/// last-seen sequences of removed [`Subscription`]s.
+fn reconcile_universe<Exchange, Kind>(
+    target: Vec<Subscription<Exchange, Kind>>,
+    subscriptions: &mut Vec<Subscription<Exchange, Kind>>,
+    liveness: &LivenessTracker,
+    pending: &mut HashSet<barter_integration::model::instrument::Instrument>,
+    resume: &mut ResumeFrom,
+) where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: PartialEq,
+{
+    target
+        .iter()
+        .filter(|subscription| !subscriptions.contains(subscription))
+        .for_each(|subscription| {
+            liveness.register(subscription);
+            pending.insert(subscription.instrument.clone());
+        });
+    pending.retain(|instrument| {
+        target
+            .iter()
+            .any(|subscription| &subscription.instrument == instrument)
+    });
+    resume.retain(|instrument| {
+        target
+            .iter()
+            .any(|subscription| &subscription.instrument == instrument)
+    });
+
+    *subscriptions = target;
+}
+
+/// Reason a [`consume`] loop stopped consuming a connected [`MarketStream`].
+enum Interruption<Exchange, Kind> {
+    /// New target [`Subscription`] universe received via the `universe_rx`.
+    Universe(Vec<Subscription<Exchange, Kind>>),
+    /// Exchange reset the sequence of an [`Instrument`](barter_integration::model::instrument::Instrument),
+    /// so the [`MarketStream`] must resync.
+    SequenceReset,
+    /// [`MarketStream`] ended, or yielded a terminal [`DataError`].
+    Ended,
+    /// [`ConsumerConfig::shutdown`] handle was triggered.
+    Shutdown,
+}
+
+/// Wait before attempting to re-initialise a [`MarketStream`], using the extended
+/// [`MaintenanceSchedule::reconnect_interval`] (bounded by the end of the window) rather than the
+/// `backoff` if the exchange is within a scheduled maintenance window.
+///
+/// Returns early if the [`Shutdown`] is triggered whilst waiting.
+async fn wait_to_reconnect<T, Output>(
+    exchange: ExchangeId,
+    maintenance: &MaintenanceSchedule,
+    shutdown: &Shutdown,
+    exchange_tx: &mpsc::UnboundedSender<Output>,
+    backoff: Duration,
+) where
+    Output: StreamItem<T>,
+{
+    tokio::select! {
+        _ = shutdown.triggered() => {}
+        _ = wait_for_backoff(exchange, maintenance, exchange_tx, backoff) => {}
+    }
+}
+
+/// See [`wait_to_reconnect`].
+async fn wait_for_backoff<T, Output>(
+    exchange: ExchangeId,
+    maintenance: &MaintenanceSchedule,
+    exchange_tx: &mpsc::UnboundedSender<Output>,
+    backoff: Duration,
+) where
+    Output: StreamItem<T>,
+{
+    let now = Utc::now();
+    let Some(window) = maintenance.active(exchange, now) else {
+        tokio::time::sleep(backoff).await;
+        return;
+    };
+
+    let wait = maintenance
+        .reconnect_interval()
+        .min((window.end - now).to_std().unwrap_or_default());
+    info!(
+        %exchange,
+        ?window,
+        ?wait,
+        action = "extending re-connection backoff",
+        "exchange is within a scheduled maintenance window"
+    );
+
+    if let Some(marker) = Output::maintenance(exchange, window.end) {
+        let _ = exchange_tx.send(marker);
+    }
+
+    tokio::time::sleep(wait).await;
+}
+
+/// Wait until the next heartbeat deadline, pending forever if heartbeats are disabled.
+async fn next_heartbeat(heartbeat_at: Option<Instant>) {
+    match heartbeat_at {
+        Some(heartbeat_at) => tokio::time::sleep_until(heartbeat_at).await,
+        None => futures::future::pending().await,
+    }
+}
+
+/// Wait for the next target [`Subscription`] universe, pending forever if there is no
+/// `universe_rx`, or once every associated [`Reconciler`](super::reconcile::Reconciler) is dropped.
+async fn next_universe<Subscriptions>(
+    universe_rx: &mut Option<mpsc::UnboundedReceiver<Subscriptions>>,
+) -> Subscriptions {
+    if let Some(rx) = universe_rx {
+        match rx.recv().await {
+            Some(target) => return target,
+            None => *universe_rx = None,
+        }
+    }
+
+    futures::future::pending().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::{MarketEvent, StreamEvent},
+        exchange::{
+            binance::{
+                channel::BinanceChannel, spot::BinanceSpot, subscription::BinanceSubResponse,
+                trade::BinanceTrade,
+            },
+            coinbase::subscription::CoinbaseSubResponse,
+            subscription::ResumableSub,
+            Connector, ExchangeId,
+        },
+        fixtures,
+        mock::{MockExchangeServer, MockStep},
+        streams::{
+            maintenance::MaintenanceWindow,
+            reconcile::{Reconciler, SubscriptionDiff},
+        },
+        subscriber::{
+            mapper::{SubscriptionMapper, WebSocketSubMapper},
+            validator::WebSocketSubValidator,
+            WebSocketSubscriber,
+        },
+        subscription::{
+            book::{Level, OrderBookL1},
+            trade::{PublicTrade, PublicTrades},
+            Map, SubKindId,
+        },
+        transformer::stateless::StatelessTransformer,
+        ExchangeWsStream, MarketStream,
+    };
+    use async_trait::async_trait;
+    use barter_integration::{
+        error::SocketError,
+        model::{
+            instrument::{kind::InstrumentKind, Instrument},
+            Exchange, Side,
+        },
+        protocol::websocket::WsMessage,
+    };
+    use chrono::Utc;
+    use futures::stream::{self, BoxStream};
+    use serde::{Deserialize, Serialize};
+    use std::{
+        collections::HashMap,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+    };
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+    use url::Url;
+
+    #[derive(
+        Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Deserialize, Serialize,
+    )]
+    struct MockExchange;
+
+    impl Connector for MockExchange {
+        const ID: ExchangeId = ExchangeId::Coinbase;
+        type Channel = String;
+        type Market = String;
+        type Subscriber = WebSocketSubscriber;
+        type SubValidator = WebSocketSubValidator;
+        type SubResponse = CoinbaseSubResponse;
+
+        fn url() -> Result<Url, SocketError> {
+            Url::parse("ws://localhost").map_err(SocketError::UrlParse)
+        }
+
+        fn requests(_: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+            vec![]
+        }
+
+        fn resume_requests(
+            exchange_subs: Vec<ResumableSub<Self::Channel, Self::Market>>,
+        ) -> Vec<WsMessage> {
+            exchange_subs
+                .into_iter()
+                .filter_map(|(exchange_sub, sequence)| {
+                    let sequence = sequence?;
+                    Some(WsMessage::Text(format!(
+                        "resume {} from {sequence}",
+                        exchange_sub.market
+                    )))
+                })
+                .collect()
+        }
+    }
+
+    /// Normalised [`SubKindId`] & exchange sequence of the [`MockKind`] yielding each event type.
+    trait MockEvent {
+        const ID: SubKindId;
+
+        fn sequence(&self) -> Option<u64> {
+            None
+        }
+    }
+
+    impl MockEvent for PublicTrade {
+        const ID: SubKindId = SubKindId::PublicTrades;
+    }
+
+    impl MockEvent for OrderBookL1 {
+        const ID: SubKindId = SubKindId::OrderBooksL1;
+    }
+
+    /// [`PublicTrade`] sequenced by the [`MockExchange`] with its numeric id.
+    #[derive(Clone, PartialEq, Debug)]
+    struct SequencedTrade(PublicTrade);
+
+    impl MockEvent for SequencedTrade {
+        const ID: SubKindId = SubKindId::PublicTrades;
+
+        fn sequence(&self) -> Option<u64> {
+            self.0.id.parse().ok()
+        }
+    }
+
+    /// Scripted behaviour of a single [`MockKind`] [`MarketStream`] initialisation.
+    enum MockInit<T> {
+        /// Initialisation fails.
+        Fail,
+        /// Connection yields the provided events, and then ends, forcing a re-connection.
+        End(Vec<Result<MarketEvent<T>, DataError>>),
+        /// Connection yields the provided events, and then stays open.
+        Open(Vec<Result<MarketEvent<T>, DataError>>),
+        /// Connection yields the provided [`BoxStream`].
+        Stream(BoxStream<'static, Result<MarketEvent<T>, DataError>>),
+    }
+
+    /// Maps each initialisation number (starting at 1) & the [`Instrument`] of the first
+    /// [`Subscription`] to the [`MockInit`] behaviour of that initialisation.
+    type MockScript<T> = dyn Fn(usize, Arc<Instrument>) -> MockInit<T> + Send + Sync;
+
+    /// Time, [`Instrument`]s & subscription requests of a single [`MockKind`] [`MarketStream`]
+    /// initialisation.
+    type MockInitRecord = (std::time::Instant, Vec<Instrument>, Vec<WsMessage>);
+
+    /// [`SubKind`] driving a [`MockExchange`] [`MarketStream`] that plays a [`MockScript`], and
+    /// records the time, [`Instrument`]s & subscription requests of every initialisation.
+    ///
+    /// Clones share the same [`MockScript`] & records, and only compare equal to each other.
+    struct MockKind<T> {
+        script: Arc<MockScript<T>>,
+        inits: Arc<Mutex<Vec<MockInitRecord>>>,
+    }
+
+    impl<T> MockKind<T> {
+        fn new<Script>(script: Script) -> Self
+        where
+            Script: Fn(usize, Arc<Instrument>) -> MockInit<T> + Send + Sync + 'static,
+        {
+            Self {
+                script: Arc::new(script),
+                inits: Arc::default(),
+            }
+        }
+
+        /// Times of every initialisation.
+        fn init_times(&self) -> Vec<std::time::Instant> {
+            self.inits
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(time, _, _)| *time)
+                .collect()
+        }
+
+        /// [`Instrument`]s of every initialisation.
+        fn init_instruments(&self) -> Vec<Vec<Instrument>> {
+            self.inits
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(_, instruments, _)| instruments.clone())
+                .collect()
+        }
+
+        /// Subscription requests of every initialisation.
+        fn init_requests(&self) -> Vec<Vec<WsMessage>> {
+            self.inits
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(_, _, requests)| requests.clone())
+                .collect()
+        }
+    }
+
+    impl<T> Clone for MockKind<T> {
+        fn clone(&self) -> Self {
+            Self {
+                script: Arc::clone(&self.script),
+                inits: Arc::clone(&self.inits),
+            }
+        }
+    }
+
+    impl<T> Debug for MockKind<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("MockKind").finish_non_exhaustive()
+        }
+    }
+
+    impl<T> PartialEq for MockKind<T> {
+        fn eq(&self, other: &Self) -> bool {
+            Arc::ptr_eq(&self.inits, &other.inits)
+        }
+    }
+
+    impl<T> Eq for MockKind<T> {}
+
+    impl<T> PartialOrd for MockKind<T> {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<T> Ord for MockKind<T> {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            Arc::as_ptr(&self.inits).cmp(&Arc::as_ptr(&other.inits))
+        }
+    }
+
+    impl<T> SubKind for MockKind<T>
+    where
+        T: MockEvent + Debug,
+    {
+        type Event = T;
+        const ID: Option<SubKindId> = Some(T::ID);
+    }
+
+    impl<T> Identifier<String> for Subscription<MockExchange, MockKind<T>> {
+        fn id(&self) -> String {
+            self.instrument.to_string()
+        }
+    }
+
+    impl<T> StreamSelector<MockKind<T>> for MockExchange
+    where
+        T: MockEvent + Debug + Send + 'static,
+    {
+        type Stream = BoxStream<'static, Result<MarketEvent<T>, DataError>>;
+
+        fn sequence(event: &T) -> Option<u64> {
+            event.sequence()
+        }
+    }
+
+    #[async_trait]
+    impl<T> MarketStream<MockExchange, MockKind<T>>
+        for BoxStream<'static, Result<MarketEvent<T>, DataError>>
+    where
+        T: MockEvent + Debug + Send + 'static,
+    {
+        async fn init(
+            subscriptions: &[Subscription<MockExchange, MockKind<T>>],
+        ) -> Result<Self, DataError> {
+            Self::init_from(subscriptions, &ResumeFrom::default()).await
+        }
+
+        async fn init_from(
+            subscriptions: &[Subscription<MockExchange, MockKind<T>>],
+            resume: &ResumeFrom,
+        ) -> Result<Self, DataError> {
+            let kind = &subscriptions[0].kind;
+            let init = {
+                let mut inits = kind.inits.lock().unwrap();
+                inits.push((
+                    std::time::Instant::now(),
+                    subscriptions
+                        .iter()
+                        .map(|subscription| subscription.instrument.clone())
+                        .collect(),
+                    WebSocketSubMapper::map(subscriptions, resume).subscriptions,
+                ));
+                inits.len()
+            };
+
+            match (kind.script)(init, Arc::new(subscriptions[0].instrument.clone())) {
+                MockInit::Fail => Err(DataError::Socket(Box::new(SocketError::Sink))),
+                MockInit::End(events) => Ok(Box::pin(stream::iter(events))),
+                MockInit::Open(events) => {
+                    Ok(Box::pin(stream::iter(events).chain(stream::pending())))
+                }
+                MockInit::Stream(stream) => Ok(stream),
+            }
+        }
+    }
+
+    /// [`MockExchange`] [`PublicTrade`] of the provided [`Instrument`] with the provided id.
+    fn trade<Id>(
+        instrument: &Arc<Instrument>,
+        id: Id,
+    ) -> Result<MarketEvent<PublicTrade>, DataError>
+    where
+        Id: ToString,
+    {
+        Ok(MarketEvent {
+            exchange: Exchange::from(MockExchange::ID),
+            instrument: Arc::clone(instrument),
+            ..fixtures::trade(Utc::now(), &id.to_string(), 1.0, 1.0, Side::Buy)
+        })
+    }
+
+    /// [`MockExchange`] [`SequencedTrade`] of the provided [`Instrument`] with the provided
+    /// sequence.
+    fn sequenced(
+        instrument: &Arc<Instrument>,
+        sequence: u64,
+    ) -> Result<MarketEvent<SequencedTrade>, DataError> {
+        trade(instrument, sequence).map(|event| MarketEvent {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: SequencedTrade(event.kind),
+        })
+    }
+
+    /// Frame that fails to deserialise.
+    fn bad_frame<T>() -> Result<MarketEvent<T>, DataError> {
+        let error = serde_json::from_str::<PublicTrade>("not json").unwrap_err();
+        Err(DataError::from(SocketError::Deserialise {
+            error,
+            payload: "not json".to_string(),
+        }))
+    }
+
+    /// [`Metrics`] that counts every recorded event, parse error & re-connection.
+    #[derive(Debug, Default)]
+    struct MockMetrics {
+        events: Mutex<HashMap<(ExchangeId, Option<SubKindId>), usize>>,
+        parse_errors: AtomicUsize,
+        reconnects: AtomicUsize,
+    }
+
+    impl Metrics for MockMetrics {
+        fn record_event(&self, exchange: ExchangeId, kind: Option<SubKindId>) {
+            *self
+                .events
+                .lock()
+                .unwrap()
+                .entry((exchange, kind))
+                .or_default() += 1;
+        }
+
+        fn record_parse_error(&self, _: ExchangeId) {
+            self.parse_errors.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn record_reconnect(&self, _: ExchangeId) {
+            self.reconnects.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_reconnect_backoff_delay() {
+        struct TestCase {
+            backoff: ReconnectBackoff,
+            failures: u32,
+            expected_min: Duration,
+            expected_max: Duration,
+        }
+
+        let ms = Duration::from_millis;
+        let tests = vec![
+            TestCase {
+                // TC0: initial delay after a MarketStream ends
+                backoff: ReconnectBackoff::new(ms(100), ms(1000), 0.0),
+                failures: 0,
+                expected_min: ms(100),
+                expected_max: ms(100),
+            },
+            TestCase {
+                // TC1: delay doubles with each consecutive failure
+                backoff: ReconnectBackoff::new(ms(100), ms(1000), 0.0),
+                failures: 3,
+                expected_min: ms(800),
+                expected_max: ms(800),
+            },
+            TestCase {
+                // TC2: delay is capped at the max delay
+                backoff: ReconnectBackoff::new(ms(100), ms(1000), 0.0),
+                failures: 64,
+                expected_min: ms(1000),
+                expected_max: ms(1000),
+            },
+            TestCase {
+                // TC3: jitter subtracts up to the jitter fraction of the delay
+                backoff: ReconnectBackoff::new(ms(100), ms(1000), 0.5),
+                failures: 1,
+                expected_min: ms(100),
+                expected_max: ms(200),
+            },
+            TestCase {
+                // TC4: full jitter subtracts up to the whole delay
+                backoff: ReconnectBackoff::new(ms(100), ms(1000), 1.0),
+                failures: 2,
+                expected_min: ms(0),
+                expected_max: ms(400),
+            },
+            TestCase {
+                // TC5: jitter outside [0, 1] is clamped to full jitter
+                backoff: ReconnectBackoff::new(ms(100), ms(1000), 2.0),
+                failures: 2,
+                expected_min: ms(0),
+                expected_max: ms(400),
+            },
+            TestCase {
+                // TC6: jitter applies to the capped max delay
+                backoff: ReconnectBackoff::new(ms(100), ms(1000), 0.25),
+                failures: 64,
+                expected_min: ms(750),
+                expected_max: ms(1000),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            for _ in 0..100 {
+                let actual = test.backoff.delay(test.failures);
+                assert!(
+                    test.expected_min <= actual && actual <= test.expected_max,
+                    "TC{index} failed: {actual:?}"
+                );
+            }
         }
+    }
+
+    #[tokio::test]
+    async fn test_consume_replays_subscriptions_after_failed_reconnections() {
+        // First connection ends, the next two initialisations fail, & later connections stay open
+        let kind = MockKind::new(|init, instrument| match init {
+            1 => MockInit::End(vec![trade(&instrument, init)]),
+            2 | 3 => MockInit::Fail,
+            _ => MockInit::Open(vec![trade(&instrument, init)]),
+        });
+        let subscriptions = vec![
+            Subscription::from((
+                MockExchange,
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                kind.clone(),
+            )),
+            Subscription::from((
+                MockExchange,
+                "eth",
+                "usdt",
+                InstrumentKind::Spot,
+                kind.clone(),
+            )),
+        ];
+        let instruments = subscriptions
+            .iter()
+            .map(|subscription| subscription.instrument.clone())
+            .collect::<Vec<_>>();
+
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel::<StreamEvent<PublicTrade>>();
+        tokio::spawn(consume(
+            subscriptions,
+            exchange_tx,
+            LivenessTracker::new(),
+            MuteSwitch::new(),
+            ConnectionCounter::new(),
+            None,
+            ConsumerConfig {
+                backoff: ReconnectBackoff::new(
+                    Duration::from_millis(10),
+                    Duration::from_millis(40),
+                    0.5,
+                ),
+                ..ConsumerConfig::default()
+            },
+        ));
+
+        let mut actual = Vec::with_capacity(4);
+        while actual.len() < 4 {
+            let event = tokio::time::timeout(Duration::from_secs(5), exchange_rx.recv())
+                .await
+                .expect("consume loop did not send the expected events")
+                .unwrap();
+
+            actual.push(match event {
+                StreamEvent::Connected { .. } => "connected".to_string(),
+                StreamEvent::Reconnected { .. } => "reconnected".to_string(),
+                StreamEvent::Market(event) => format!("trade {}", event.kind.id),
+                other => format!("{other:?}"),
+            });
+        }
+
+        // Failed re-initialisations after a dropped connection are retried with backoff
+        assert_eq!(
+            actual,
+            vec!["connected", "trade 1", "reconnected", "trade 4"]
+        );
+
+        // Every initialisation re-issued the original Subscriptions
+        assert_eq!(kind.init_instruments(), vec![instruments; 4]);
+    }
+
+    #[tokio::test]
+    async fn test_consume_records_metrics() {
+        let metrics = Arc::new(MockMetrics::default());
+
+        // First connection yields a frame that fails to deserialise amongst trades, and then ends
+        let kind = MockKind::new(|init, instrument| match init {
+            1 => MockInit::End(vec![
+                trade(&instrument, 1),
+                trade(&instrument, 2),
+                bad_frame(),
+                trade(&instrument, 3),
+            ]),
+            _ => MockInit::Open(vec![trade(&instrument, 4)]),
+        });
+
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel::<MarketEvent<PublicTrade>>();
+        tokio::spawn(consume(
+            vec![Subscription::from((
+                MockExchange,
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                kind,
+            ))],
+            exchange_tx,
+            LivenessTracker::new(),
+            MuteSwitch::new(),
+            ConnectionCounter::new(),
+            None,
+            ConsumerConfig {
+                backoff: ReconnectBackoff::new(
+                    Duration::from_millis(10),
+                    Duration::from_millis(10),
+                    0.0,
+                ),
+                metrics: metrics.clone(),
+                ..ConsumerConfig::default()
+            },
+        ));
+
+        let mut actual = Vec::with_capacity(4);
+        while actual.len() < 4 {
+            let event = tokio::time::timeout(Duration::from_secs(5), exchange_rx.recv())
+                .await
+                .expect("consume loop did not send the expected events")
+                .unwrap();
+            actual.push(event.kind.id);
+        }
+        assert_eq!(actual, vec!["1", "2", "3", "4"]);
+
+        assert_eq!(
+            *metrics.events.lock().unwrap(),
+            HashMap::from([((MockExchange::ID, Some(SubKindId::PublicTrades)), 4)])
+        );
+        assert_eq!(metrics.parse_errors.load(Ordering::SeqCst), 1);
+        assert_eq!(metrics.reconnects.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_consume_publishes_connection_status() {
+        let (status_tx, mut status_rx) = watch::channel(ConnectionStatus::Connecting);
+
+        // First connection ends once dropped, and the next initialisation fails
+        let dropped = Arc::new(tokio::sync::Notify::new());
+        let kind = MockKind::new({
+            let dropped = Arc::clone(&dropped);
+            move |init, _| match init {
+                1 => {
+                    let dropped = Arc::clone(&dropped);
+                    MockInit::Stream(Box::pin(
+                        stream::once(async move { dropped.notified().await })
+                            .filter_map(|_| futures::future::ready(None)),
+                    ))
+                }
+                2 => MockInit::Fail,
+                _ => MockInit::Open(vec![]),
+            }
+        });
+
+        let (exchange_tx, _exchange_rx) = mpsc::unbounded_channel::<MarketEvent<PublicTrade>>();
+        tokio::spawn(consume(
+            vec![Subscription::from((
+                MockExchange,
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                kind.clone(),
+            ))],
+            exchange_tx,
+            LivenessTracker::new(),
+            MuteSwitch::new(),
+            ConnectionCounter::new(),
+            None,
+            ConsumerConfig {
+                backoff: ReconnectBackoff::new(
+                    Duration::from_millis(200),
+                    Duration::from_millis(200),
+                    0.0,
+                ),
+                status: status_tx,
+                ..ConsumerConfig::default()
+            },
+        ));
+
+        // Connect -> dropped connection -> failed re-connection attempt -> re-connected
+        let expected = [
+            ConnectionStatus::Connected,
+            ConnectionStatus::Disconnected {
+                reason: "MarketStream ended".to_string(),
+            },
+            ConnectionStatus::Reconnecting { attempt: 1 },
+            ConnectionStatus::Connected,
+        ];
+
+        for (index, expected) in expected.into_iter().enumerate() {
+            if index == 1 {
+                dropped.notify_one();
+            }
+
+            tokio::time::timeout(
+                Duration::from_secs(5),
+                status_rx.wait_for(|status| *status == expected),
+            )
+            .await
+            .unwrap_or_else(|_| panic!("TC{index} failed: {expected:?} was not published"))
+            .unwrap();
+        }
+        assert_eq!(kind.init_times().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_consume_resumes_from_last_seen_sequence() {
+        // The first connection yields trades 1..=3 and then ends, forcing a re-connection.
+        // Subsequent connections replay trade 2 onwards, and then stay open.
+        let kind = MockKind::new(|init, instrument| {
+            let trades = match init {
+                1 => 1..=3,
+                _ => 2..=5,
+            };
+            let trades = trades.map(|id| sequenced(&instrument, id)).collect();
+            match init {
+                1 => MockInit::End(trades),
+                _ => MockInit::Open(trades),
+            }
+        });
+        let subscription = Subscription::from((
+            MockExchange,
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            kind.clone(),
+        ));
+
+        let (exchange_tx, mut exchange_rx) =
+            mpsc::unbounded_channel::<MarketEvent<SequencedTrade>>();
+        tokio::spawn(consume(
+            vec![subscription.clone()],
+            exchange_tx,
+            LivenessTracker::new(),
+            MuteSwitch::new(),
+            ConnectionCounter::new(),
+            None,
+            ConsumerConfig::default(),
+        ));
+
+        // Allow time for the re-connection backoff to elapse
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        // Initial connection sends the standard requests, and the re-connection resumes from the
+        // last-seen sequence
+        assert_eq!(
+            kind.init_requests(),
+            vec![
+                vec![],
+                vec![WsMessage::Text(format!(
+                    "resume {} from 3",
+                    subscription.instrument
+                ))],
+            ]
+        );
+
+        // Events replayed after resuming are discarded, so events continue from the right point
+        let mut actual = Vec::new();
+        while let Ok(event) = exchange_rx.try_recv() {
+            actual.push(event.kind.0.id);
+        }
+        assert_eq!(actual, vec!["1", "2", "3", "4", "5"]);
+    }
+
+    #[tokio::test]
+    async fn test_consume_resyncs_once_after_sequence_reset() {
+        // The first connection yields trades 5000..=5002, and then the reset sequence 1..=3 whilst
+        // staying open. Subsequent connections yield trades 1..=4 from the reset sequence
+        // baseline, and then stay open.
+        let kind = MockKind::new(|init, instrument| {
+            let trades = match init {
+                1 => (5000..=5002).chain(1..=3).collect::<Vec<_>>(),
+                _ => (1..=4).collect(),
+            };
+            MockInit::Open(
+                trades
+                    .into_iter()
+                    .map(|id| sequenced(&instrument, id))
+                    .collect(),
+            )
+        });
+        let subscription = Subscription::from((
+            MockExchange,
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            kind.clone(),
+        ));
+
+        let (exchange_tx, mut exchange_rx) =
+            mpsc::unbounded_channel::<MarketEvent<SequencedTrade>>();
+        tokio::spawn(consume(
+            vec![subscription],
+            exchange_tx,
+            LivenessTracker::new(),
+            MuteSwitch::new(),
+            ConnectionCounter::new(),
+            None,
+            ConsumerConfig::default(),
+        ));
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        // Sequence reset triggers a single resync, which does not resume from the stale sequence
+        assert_eq!(kind.init_requests(), vec![vec![]; 2]);
+
+        // Events continue from the new sequence baseline rather than being discarded as stale
+        let mut actual = Vec::new();
+        while let Ok(event) = exchange_rx.try_recv() {
+            actual.push(event.kind.0.id);
+        }
+        assert_eq!(actual, vec!["5000", "5001", "5002", "1", "2", "3", "4"]);
+    }
+
+    #[tokio::test]
+    async fn test_consume_reconciles_subscription_universe() {
+        let kind = MockKind::new(|_, _| MockInit::<PublicTrade>::Open(vec![]));
+        let subscription = |base: &str| {
+            Subscription::from((
+                MockExchange,
+                base,
+                "usdt",
+                InstrumentKind::Spot,
+                kind.clone(),
+            ))
+        };
+
+        let universe_a = vec![subscription("btc"), subscription("eth")];
+        let universe_b = vec![subscription("eth"), subscription("sol")];
+
+        let (reconciler, universe_rx) = Reconciler::new(universe_a.clone());
+        let (exchange_tx, _exchange_rx) = mpsc::unbounded_channel::<MarketEvent<PublicTrade>>();
+        tokio::spawn(consume(
+            universe_a,
+            exchange_tx,
+            LivenessTracker::new(),
+            MuteSwitch::new(),
+            ConnectionCounter::new(),
+            Some(universe_rx),
+            ConsumerConfig::default(),
+        ));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // TC0: reconciling universe A -> B adds sol & removes btc
+        let actual = reconciler.reconcile(universe_b.clone()).unwrap();
+        assert_eq!(
+            actual,
+            SubscriptionDiff {
+                added: vec![subscription("sol")],
+                removed: vec![subscription("btc")],
+            },
+            "TC0 failed"
+        );
+        assert_eq!(reconciler.current(), universe_b, "TC0 failed");
+
+        // TC1: reconciling to the current universe is a no-op
+        let actual = reconciler.reconcile(universe_b.clone()).unwrap();
+        assert!(actual.is_empty(), "TC1 failed");
+
+        // MarketStream cannot be updated live, so is re-initialised exactly once, with universe B
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let instruments = |universe: &[Subscription<MockExchange, MockKind<PublicTrade>>]| {
+            universe
+                .iter()
+                .map(|subscription| subscription.instrument.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            kind.init_instruments(),
+            vec![
+                instruments(&[subscription("btc"), subscription("eth")]),
+                instruments(&universe_b)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_consume_reconciles_subscription_universe_over_open_connection() {
+        let server = MockExchangeServer::start([
+            MockStep::trade("BTCUSDT", 1, 100.0),
+            MockStep::Delay(Duration::from_millis(200)),
+            MockStep::trade("BTCUSDT", 2, 100.0),
+            MockStep::trade("ETHUSDT", 3, 100.0),
+        ])
+        .await
+        .unwrap();
+
+        let subscription = |base: &str| {
+            Subscription::from((
+                BinanceSpot::default(),
+                base,
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ))
+        };
+
+        let (reconciler, universe_rx) = Reconciler::new(vec![subscription("btc")]);
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel::<MarketEvent<PublicTrade>>();
+        tokio::spawn(consume(
+            vec![subscription("btc")],
+            exchange_tx,
+            LivenessTracker::new(),
+            MuteSwitch::new(),
+            ConnectionCounter::new(),
+            Some(universe_rx),
+            ConsumerConfig {
+                base_url: Some(server.url()),
+                ..ConsumerConfig::default()
+            },
+        ));
+        let event = exchange_rx.recv().await.unwrap();
+        assert_eq!(event.kind.id, "1");
+
+        // Swap btc for eth whilst the connection is open
+        reconciler.reconcile(vec![subscription("eth")]).unwrap();
+
+        // In-flight btc trade is dropped, and the eth trade is routed over the same connection
+        let event = tokio::time::timeout(Duration::from_secs(1), exchange_rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            (event.instrument.base.as_ref(), event.kind.id.as_str()),
+            ("eth", "3")
+        );
+        assert_eq!(server.connections(), 1);
+        assert_eq!(
+            server.subscriptions(),
+            vec!["btcusdt@trade".to_string(), "ethusdt@trade".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_consume_suppresses_muted_subscriptions() {
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        // Maintains an internal best bid price for each Instrument, incremented by every update
+        let (feed_tx, feed_rx) = mpsc::unbounded_channel::<(Instrument, f64)>();
+        let feed = Mutex::new(Some(feed_rx));
+        let kind = MockKind::new(move |_, _| {
+            let feed = feed.lock().unwrap().take().unwrap();
+            let books = UnboundedReceiverStream::new(feed).scan(
+                HashMap::<Instrument, f64>::new(),
+                |books, (instrument, increment)| {
+                    let best_bid = books.entry(instrument.clone()).or_default();
+                    *best_bid += increment;
+
+                    futures::future::ready(Some(Ok(MarketEvent {
+                        exchange_time: Utc::now(),
+                        received_time: Utc::now(),
+                        exchange: Exchange::from(MockExchange::ID),
+                        instrument: Arc::new(instrument),
+                        kind: OrderBookL1 {
+                            last_update_time: Utc::now(),
+                            best_bid: Level::new(*best_bid, 1.0),
+                            best_ask: Level::new(*best_bid + 1.0, 1.0),
+                        },
+                    })))
+                },
+            );
+
+            MockInit::Stream(Box::pin(books.chain(stream::pending())))
+        });
+        let subscriptions = vec![
+            Subscription::from((MockExchange, btc.clone(), kind.clone())),
+            Subscription::from((MockExchange, eth.clone(), kind)),
+        ];
 
-        // If MarketStream ends unexpectedly, attempt re-connection after backoff_ms
-        warn!(
-            %exchange,
-            backoff_ms,
-            action = "attempt re-connection after backoff",
-            "exchange MarketStream unexpectedly ended"
+        let mutes = MuteSwitch::new();
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel::<MarketEvent<OrderBookL1>>();
+        tokio::spawn(consume(
+            subscriptions,
+            exchange_tx,
+            LivenessTracker::new(),
+            mutes.clone(),
+            ConnectionCounter::new(),
+            None,
+            ConsumerConfig::default(),
+        ));
+
+        let mut next = || {
+            let event = exchange_rx.try_recv().ok()?;
+            Some((
+                Instrument::clone(&event.instrument),
+                event.kind.best_bid.price,
+            ))
+        };
+
+        // Send updates and yield until the consumer loop has processed them
+        let send = |updates: &[&Instrument]| {
+            updates
+                .iter()
+                .for_each(|instrument| feed_tx.send(((*instrument).clone(), 1.0)).unwrap());
+        };
+        let drain = || tokio::time::sleep(Duration::from_millis(50));
+
+        // TC0: events for every Subscription are distributed
+        send(&[&btc, &eth]);
+        drain().await;
+        assert_eq!(next(), Some((btc.clone(), 1.0)), "TC0 failed");
+        assert_eq!(next(), Some((eth.clone(), 1.0)), "TC0 failed");
+
+        // TC1: muted btc events are suppressed, whilst eth events continue
+        mutes.mute(MockExchange::ID, &btc);
+        send(&[&btc, &eth, &btc]);
+        drain().await;
+        assert_eq!(next(), Some((eth.clone(), 2.0)), "TC1 failed");
+        assert_eq!(next(), None, "TC1 failed");
+
+        // TC2: unmuted btc events resume with the internal book state kept current
+        mutes.unmute(MockExchange::ID, &btc);
+        send(&[&btc]);
+        drain().await;
+        assert_eq!(next(), Some((btc.clone(), 4.0)), "TC2 failed");
+        assert_eq!(next(), None, "TC2 failed");
+    }
+
+    #[tokio::test]
+    async fn test_consume_sends_connection_markers_before_data() {
+        // First connection yields a single trade & ends, whereas later connections stay open
+        let kind = MockKind::new(|init, instrument| match init {
+            1 => MockInit::End(vec![trade(&instrument, init)]),
+            _ => MockInit::Open(vec![trade(&instrument, init)]),
+        });
+        let subscriptions = vec![Subscription::from((
+            MockExchange,
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            kind,
+        ))];
+
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel::<StreamEvent<PublicTrade>>();
+        tokio::spawn(consume(
+            subscriptions,
+            exchange_tx,
+            LivenessTracker::new(),
+            MuteSwitch::new(),
+            ConnectionCounter::new(),
+            None,
+            ConsumerConfig::default(),
+        ));
+
+        let mut actual = Vec::with_capacity(4);
+        while actual.len() < 4 {
+            let event = tokio::time::timeout(Duration::from_secs(5), exchange_rx.recv())
+                .await
+                .expect("consume loop did not send the expected events")
+                .unwrap();
+
+            actual.push(match event {
+                StreamEvent::Connected { .. } => "connected".to_string(),
+                StreamEvent::Reconnected { .. } => "reconnected".to_string(),
+                StreamEvent::Market(event) => format!("trade {}", event.kind.id),
+                StreamEvent::Heartbeat { .. } => "heartbeat".to_string(),
+                StreamEvent::Maintenance { .. } => "maintenance".to_string(),
+                StreamEvent::Failed { .. } => "failed".to_string(),
+            });
+        }
+
+        assert_eq!(
+            actual,
+            vec!["connected", "trade 1", "reconnected", "trade 2"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_consume_sends_heartbeats_whilst_connected_and_quiet() {
+        // First connection stays quiet for 350ms & ends, whereas later connections stay open
+        let kind = MockKind::new(|init, _| match init {
+            1 => MockInit::<PublicTrade>::Stream(Box::pin(
+                stream::once(tokio::time::sleep(Duration::from_millis(350)))
+                    .filter_map(|_| futures::future::ready(None)),
+            )),
+            _ => MockInit::Open(vec![]),
+        });
+        let subscriptions = vec![Subscription::from((
+            MockExchange,
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            kind,
+        ))];
+
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel::<StreamEvent<PublicTrade>>();
+        tokio::spawn(consume(
+            subscriptions,
+            exchange_tx,
+            LivenessTracker::new(),
+            MuteSwitch::new(),
+            ConnectionCounter::new(),
+            None,
+            ConsumerConfig {
+                heartbeat: Some(Duration::from_millis(100)),
+                ..ConsumerConfig::default()
+            },
+        ));
+
+        let mut actual = Vec::with_capacity(6);
+        while actual.len() < 6 {
+            let event = tokio::time::timeout(Duration::from_secs(5), exchange_rx.recv())
+                .await
+                .expect("consume loop did not send the expected events")
+                .unwrap();
+            actual.push(event);
+        }
+
+        // Heartbeats are sent every interval whilst connected, stop whilst disconnected, and
+        // resume once re-connected
+        let kinds = actual
+            .iter()
+            .map(|event| match event {
+                StreamEvent::Connected { .. } => "connected",
+                StreamEvent::Reconnected { .. } => "reconnected",
+                StreamEvent::Heartbeat { .. } => "heartbeat",
+                StreamEvent::Maintenance { .. } => "maintenance",
+                StreamEvent::Failed { .. } => "failed",
+                StreamEvent::Market(_) => "market",
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                "connected",
+                "heartbeat",
+                "heartbeat",
+                "heartbeat",
+                "reconnected",
+                "heartbeat"
+            ]
+        );
+
+        // No heartbeat was sent during the re-connection backoff
+        let time = |event: &StreamEvent<PublicTrade>| match event {
+            StreamEvent::Heartbeat { time, .. } | StreamEvent::Reconnected { time, .. } => *time,
+            _ => unreachable!(),
+        };
+        let disconnected = time(&actual[4]) - time(&actual[3]);
+        assert!(
+            disconnected >= chrono::Duration::milliseconds(STARTING_RECONNECT_BACKOFF_MS as i64),
+            "heartbeats were sent whilst disconnected: {disconnected}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_consume_extends_reconnect_backoff_during_maintenance() {
+        // First connection ends immediately, and every re-connection attempt fails
+        let kind = MockKind::new(|init, _| match init {
+            1 => MockInit::<PublicTrade>::End(vec![]),
+            _ => MockInit::Fail,
+        });
+        let subscriptions = vec![Subscription::from((
+            MockExchange,
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            kind.clone(),
+        ))];
+
+        let window = MaintenanceWindow::new(
+            Utc::now() - chrono::Duration::seconds(1),
+            Utc::now() + chrono::Duration::seconds(10),
+        );
+        let maintenance =
+            MaintenanceSchedule::new().with_reconnect_interval(Duration::from_millis(600));
+        maintenance.add(MockExchange::ID, window);
+
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel::<StreamEvent<PublicTrade>>();
+        tokio::spawn(consume(
+            subscriptions,
+            exchange_tx,
+            LivenessTracker::new(),
+            MuteSwitch::new(),
+            ConnectionCounter::new(),
+            None,
+            ConsumerConfig {
+                maintenance,
+                ..ConsumerConfig::default()
+            },
+        ));
+
+        let mut actual = Vec::with_capacity(4);
+        while actual.len() < 4 {
+            let event = tokio::time::timeout(Duration::from_secs(5), exchange_rx.recv())
+                .await
+                .expect("consume loop did not send the expected events")
+                .unwrap();
+            actual.push(match event {
+                StreamEvent::Connected { .. } => "connected".to_string(),
+                StreamEvent::Maintenance { until, .. } if until == window.end => {
+                    "maintenance".to_string()
+                }
+                other => format!("{other:?}"),
+            });
+        }
+
+        // Maintenance marker is sent before every re-connection attempt within the window
+        assert_eq!(
+            actual,
+            vec!["connected", "maintenance", "maintenance", "maintenance"]
+        );
+
+        // Re-connection attempts are made at the maintenance interval, rather than the much
+        // shorter exponential backoff
+        let inits = kind.init_times();
+        assert_eq!(inits.len(), 3);
+        for attempts in inits.windows(2) {
+            let gap = attempts[1] - attempts[0];
+            assert!(
+                gap >= Duration::from_millis(550),
+                "re-connection attempted {gap:?} after the previous attempt"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_consume_sends_failed_marker_if_first_connection_fails() {
+        let subscriptions = vec![Subscription::from((
+            MockExchange,
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            MockKind::new(|_, _| MockInit::<PublicTrade>::Fail),
+        ))];
+
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel::<StreamEvent<PublicTrade>>();
+        let consumer = tokio::spawn(consume(
+            subscriptions,
+            exchange_tx,
+            LivenessTracker::new(),
+            MuteSwitch::new(),
+            ConnectionCounter::new(),
+            None,
+            ConsumerConfig::default(),
+        ));
+
+        let error = tokio::time::timeout(Duration::from_secs(5), consumer)
+            .await
+            .expect("consume loop did not give up on the unreachable exchange")
+            .unwrap();
+        assert!(
+            matches!(&error, DataError::Socket(socket) if matches!(**socket, SocketError::Sink))
+        );
+
+        // Failure is surfaced to the receiver before the channel closes
+        match exchange_rx.recv().await {
+            Some(StreamEvent::Failed {
+                exchange,
+                error: actual,
+                ..
+            }) => {
+                assert_eq!(exchange, MockExchange::ID);
+                assert_eq!(actual, error.to_string());
+            }
+            other => panic!("expected StreamEvent::Failed, got: {other:?}"),
+        }
+        assert!(exchange_rx.recv().await.is_none());
+    }
+
+    /// [`Layer`](tracing_subscriber::Layer) capturing the level, message & fields of every event,
+    /// alongside the fields of every span it was recorded within.
+    #[derive(Clone, Default)]
+    struct CaptureLayer(Arc<Mutex<Vec<(tracing::Level, String)>>>);
+
+    /// Fields of a span, stored in its extensions by the [`CaptureLayer`].
+    struct SpanFields(String);
+
+    /// [`Visit`](tracing::field::Visit) formatting every field as "name=value".
+    struct FieldVisitor<'a>(&'a mut String);
+
+    impl tracing::field::Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn Debug) {
+            self.0.push_str(&format!("{}={value:?} ", field.name()));
+        }
+    }
+
+    impl<S> tracing_subscriber::Layer<S> for CaptureLayer
+    where
+        S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            id: &tracing::span::Id,
+            ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut fields = String::new();
+            attrs.record(&mut FieldVisitor(&mut fields));
+            if let Some(span) = ctx.span(id) {
+                span.extensions_mut().insert(SpanFields(fields));
+            }
+        }
+
+        fn on_event(
+            &self,
+            event: &tracing::Event<'_>,
+            ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut fields = String::new();
+            event.record(&mut FieldVisitor(&mut fields));
+            for span in ctx.event_scope(event).into_iter().flatten() {
+                if let Some(SpanFields(span_fields)) = span.extensions().get::<SpanFields>() {
+                    fields.push_str(&format!("{}{{{span_fields}}} ", span.name()));
+                }
+            }
+            self.0
+                .lock()
+                .unwrap()
+                .push((*event.metadata().level(), fields));
+        }
+    }
+
+    #[test]
+    fn test_connection_span_attributes_deserialise_failure_to_subscription() {
+        use crate::{
+            exchange::binance::{spot::BinanceSpot, trade::BinanceTrade},
+            protocol::{WebSocketParser, WsFrame, MAX_LOGGED_FRAME_LEN},
+        };
+        use barter_integration::protocol::StreamParser;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let capture = CaptureLayer::default();
+        let subscriber = tracing_subscriber::registry().with(capture.clone());
+
+        let subscriptions = vec![Subscription::<BinanceSpot, PublicTrades>::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))];
+
+        // Malformed trade frame longer than the logged limit
+        let frame = format!(
+            r#"{{"e":"trade","s":"BTCUSDT","p":"{}"}}"#,
+            "9".repeat(1024)
+        );
+        let frame_len = frame.len();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = connection_span(BinanceSpot::ID, 1, &subscriptions);
+            let _entered = span.enter();
+            let output =
+                WebSocketParser::parse::<BinanceTrade>(Ok(WsFrame::from(WsMessage::Text(frame))));
+            assert!(matches!(output, Some(Err(SocketError::Deserialise { .. }))));
+        });
+
+        let events = capture.0.lock().unwrap();
+        let (_, warning) = events
+            .iter()
+            .find(|(level, _)| *level == tracing::Level::WARN)
+            .expect("deserialise failure did not produce a warn event");
+
+        assert!(warning.contains("failed to deserialise WebSocket frame"));
+        assert!(warning.contains("exchange=binance_spot"));
+        assert!(warning.contains("subscriptions=@trade|BTCUSDT"));
+        assert!(warning.contains(&format!("payload_len={frame_len}")));
+        assert!(!warning.contains(&"9".repeat(MAX_LOGGED_FRAME_LEN)));
+    }
+
+    /// [`Connector`] of a mock WebSocket server streaming Binance formatted trades, connected to
+    /// via the [`ConsumerConfig::base_url`].
+    #[derive(
+        Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Deserialize, Serialize,
+    )]
+    struct MockWsExchange;
+
+    impl Connector for MockWsExchange {
+        const ID: ExchangeId = ExchangeId::BinanceSpot;
+        type Channel = BinanceChannel;
+        type Market = String;
+        type Subscriber = WebSocketSubscriber;
+        type SubValidator = WebSocketSubValidator;
+        type SubResponse = BinanceSubResponse;
+
+        fn url() -> Result<Url, SocketError> {
+            Url::parse("ws://localhost").map_err(SocketError::UrlParse)
+        }
+
+        fn requests(_: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+            vec![WsMessage::Text("subscribe".to_string())]
+        }
+
+        fn unsubscribe_requests(
+            _: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+        ) -> Vec<WsMessage> {
+            vec![WsMessage::Text("unsubscribe".to_string())]
+        }
+
+        fn expected_responses(_: &Map<Arc<Instrument>>) -> usize {
+            0
+        }
+    }
+
+    impl Identifier<BinanceChannel> for Subscription<MockWsExchange, PublicTrades> {
+        fn id(&self) -> BinanceChannel {
+            BinanceChannel::TRADES
+        }
+    }
+
+    impl Identifier<String> for Subscription<MockWsExchange, PublicTrades> {
+        fn id(&self) -> String {
+            format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase()
+        }
+    }
+
+    impl StreamSelector<PublicTrades> for MockWsExchange {
+        type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BinanceTrade>>;
+    }
+
+    #[tokio::test]
+    async fn test_consume_shutdown_closes_connection_gracefully() {
+        use futures::SinkExt;
+
+        // Mock server that streams a trade once subscribed, recording every received message
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("ws://{}", listener.local_addr().unwrap())).unwrap();
+        let (received_tx, mut received_rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            while let Some(Ok(message)) = websocket.next().await {
+                let subscribed = message == WsMessage::Text("subscribe".to_string());
+                let _ = received_tx.send(message);
+                if subscribed {
+                    websocket
+                        .send(WsMessage::Text(
+                            r#"{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1,"p":"10000.19","q":"0.239000","b":1,"a":2,"T":1649324825173,"m":false,"M":true}"#
+                                .to_string(),
+                        ))
+                        .await
+                        .unwrap();
+                }
+            }
+        });
+
+        let shutdown = Shutdown::new().unsubscribe_on_close(true);
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel::<MarketEvent<PublicTrade>>();
+        let consumer = tokio::spawn(consume(
+            vec![Subscription::from((
+                MockWsExchange,
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ))],
+            exchange_tx,
+            LivenessTracker::new(),
+            MuteSwitch::new(),
+            ConnectionCounter::new(),
+            None,
+            ConsumerConfig {
+                shutdown: shutdown.clone(),
+                base_url: Some(url),
+                ..ConsumerConfig::default()
+            },
+        ));
+
+        let event = tokio::time::timeout(Duration::from_secs(5), exchange_rx.recv())
+            .await
+            .expect("no MarketEvent received from the mock server")
+            .unwrap();
+        assert_eq!(event.kind.price, 10000.19);
+
+        // Shutting down closes the connection gracefully & stops without re-connecting
+        shutdown.trigger();
+        let error = tokio::time::timeout(Duration::from_secs(5), consumer)
+            .await
+            .expect("consume loop did not shut down")
+            .unwrap();
+        assert!(matches!(error, DataError::ConsumerTerminated { .. }));
+        assert!(exchange_rx.recv().await.is_none());
+
+        // Unsubscribe request is sent before the CloseFrame
+        let mut received = vec![];
+        while let Some(message) = received_rx.recv().await {
+            received.push(message);
+        }
+        assert_eq!(
+            received,
+            vec![
+                WsMessage::Text("subscribe".to_string()),
+                WsMessage::Text("unsubscribe".to_string()),
+                WsMessage::Close(None),
+            ]
         );
-        tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
     }
 }
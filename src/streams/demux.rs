@@ -0,0 +1,139 @@
+use crate::event::MarketEvent;
+use barter_integration::model::instrument::Instrument;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// Per-[`Instrument`] partition of a merged [`MarketEvent<T>`] stream (eg/ the single exchange
+/// receiver returned by [`Streams::select`](super::Streams::select)), produced by [`demux`].
+///
+/// ### Notes
+/// [`MarketEvent<T>`]s of an [`Instrument`] that was not registered with [`demux`] are routed to
+/// the `fallback` receiver rather than dropped.
+#[derive(Debug)]
+pub struct InstrumentDemux<T> {
+    pub instruments: HashMap<Instrument, mpsc::UnboundedReceiver<MarketEvent<T>>>,
+    pub fallback: mpsc::UnboundedReceiver<MarketEvent<T>>,
+}
+
+impl<T> InstrumentDemux<T> {
+    /// Remove the [`mpsc::UnboundedReceiver`] of the provided [`Instrument`] from the
+    /// [`InstrumentDemux`].
+    pub fn select(
+        &mut self,
+        instrument: &Instrument,
+    ) -> Option<mpsc::UnboundedReceiver<MarketEvent<T>>> {
+        self.instruments.remove(instrument)
+    }
+}
+
+/// Partition the provided merged [`MarketEvent<T>`] receiver into one receiver per provided
+/// [`Instrument`], plus a fallback receiver for every other [`Instrument`].
+///
+/// Events are routed by a spawned task until the merged receiver ends. Events of an
+/// [`Instrument`] whose receiver has been dropped are discarded.
+pub fn demux<T, Instruments>(
+    mut merged_rx: mpsc::UnboundedReceiver<MarketEvent<T>>,
+    instruments: Instruments,
+) -> InstrumentDemux<T>
+where
+    T: Send + 'static,
+    Instruments: IntoIterator<Item = Instrument>,
+{
+    let (senders, receivers): (HashMap<_, _>, HashMap<_, _>) = instruments
+        .into_iter()
+        .map(|instrument| {
+            let (tx, rx) = mpsc::unbounded_channel();
+            ((instrument.clone(), tx), (instrument, rx))
+        })
+        .unzip();
+
+    let (fallback_tx, fallback_rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        while let Some(event) = merged_rx.recv().await {
+            let _ = match senders.get(&*event.instrument) {
+                Some(instrument_tx) => instrument_tx.send(event),
+                None => fallback_tx.send(event),
+            };
+        }
+    });
+
+    InstrumentDemux {
+        instruments: receivers,
+        fallback: fallback_rx,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::ExchangeId, fixtures, subscription::trade::PublicTrade};
+    use barter_integration::model::{instrument::kind::InstrumentKind, Exchange, Side};
+    use chrono::Utc;
+
+    fn instrument(base: &str) -> Instrument {
+        Instrument::from((base, "usdt", InstrumentKind::Spot))
+    }
+
+    fn trade(base: &str, id: &str) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: instrument(base).into(),
+            ..fixtures::trade(Utc::now(), id, 100.0, 1.0, Side::Buy)
+        }
+    }
+
+    async fn ids(mut rx: mpsc::UnboundedReceiver<MarketEvent<PublicTrade>>) -> Vec<String> {
+        let mut ids = Vec::new();
+        while let Some(event) = rx.recv().await {
+            ids.push(event.kind.id);
+        }
+        ids
+    }
+
+    #[tokio::test]
+    async fn test_demux_partitions_by_instrument() {
+        let (merged_tx, merged_rx) = mpsc::unbounded_channel();
+        let mut demux = demux(merged_rx, [instrument("btc"), instrument("eth")]);
+
+        // Interleaved events of two registered instruments & one unregistered instrument
+        [
+            ("btc", "1"),
+            ("eth", "2"),
+            ("sol", "3"),
+            ("btc", "4"),
+            ("eth", "5"),
+        ]
+        .into_iter()
+        .for_each(|(base, id)| merged_tx.send(trade(base, id)).unwrap());
+        drop(merged_tx);
+
+        struct TestCase {
+            input: Instrument,
+            expected: Vec<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: btc events are routed to the btc receiver in order
+                input: instrument("btc"),
+                expected: vec!["1", "4"],
+            },
+            TestCase {
+                // TC1: eth events are routed to the eth receiver in order
+                input: instrument("eth"),
+                expected: vec!["2", "5"],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let rx = demux
+                .select(&test.input)
+                .unwrap_or_else(|| panic!("TC{index} failed"));
+            assert_eq!(ids(rx).await, test.expected, "TC{index} failed");
+        }
+
+        // Events of unregistered instruments are routed to the fallback receiver
+        assert_eq!(ids(demux.fallback).await, vec!["3"]);
+    }
+}
@@ -0,0 +1,399 @@
+use super::{
+    builder::validate,
+    connection::ConnectionCounter,
+    consumer::{consume, ConsumerConfig},
+    liveness::LivenessTracker,
+    mute::MuteSwitch,
+    reconcile::Reconciler,
+    shutdown::Shutdown,
+};
+use crate::{
+    error::DataError,
+    event::StreamEvent,
+    exchange::{subscription::ExchangeSub, StreamSelector},
+    subscription::{SubKind, Subscription},
+    Identifier,
+};
+use barter_integration::{error::SocketError, model::SubscriptionId};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tracing::debug;
+
+/// [`StreamEvent<T>`] stream of the [`consume`] loop of a live connection, whose individual
+/// [`Subscription`]s can be added & removed via the associated [`LiveSubscriptions`] handle.
+///
+/// See [`init_live`].
+pub type LiveStream<T> = UnboundedReceiverStream<StreamEvent<T>>;
+
+/// Connect & subscribe to the provided [`Subscription`]s, returning the [`LiveStream`] alongside
+/// a [`LiveSubscriptions`] handle used to subscribe & unsubscribe individual [`Subscription`]s
+/// over the same open connection.
+///
+/// Equivalent to [`init_live_with`] using the default [`ConsumerConfig`].
+///
+/// ```rust,no_run
+/// use barter_data::{
+///     exchange::binance::spot::BinanceSpot, streams::live::init_live,
+///     subscription::trade::PublicTrades,
+/// };
+/// use barter_integration::model::instrument::kind::InstrumentKind;
+///
+/// # async fn example() -> Result<(), barter_data::error::DataError> {
+/// let (stream, subscriptions) = init_live(&[
+///     (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, PublicTrades).into(),
+/// ])
+/// .await?;
+///
+/// // Swap the BTC stream for ETH over the same connection
+/// subscriptions.subscribe(
+///     &(BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, PublicTrades).into(),
+/// )?;
+/// subscriptions.unsubscribe(
+///     &(BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, PublicTrades).into(),
+/// )?;
+/// # drop(stream);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn init_live<Exchange, Kind>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+) -> Result<(LiveStream<Kind::Event>, LiveSubscriptions<Exchange, Kind>), DataError>
+where
+    Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+    Kind: SubKind + Ord + Send + Sync + 'static,
+    Kind::Event: Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    init_live_with(subscriptions, ConsumerConfig::default()).await
+}
+
+/// Connect & subscribe to the provided [`Subscription`]s via a [`consume`] loop configured with
+/// the provided [`ConsumerConfig`], returning the [`LiveStream`] alongside a
+/// [`LiveSubscriptions`] handle used to subscribe & unsubscribe individual [`Subscription`]s
+/// over the same open connection.
+///
+/// ### Notes
+/// - The connection is re-connected, and publishes its status & metrics, like any other
+///   [`consume`] loop. Re-connections re-subscribe the current [`Subscription`]s of the
+///   [`LiveSubscriptions`].
+/// - Returns the [`DataError`] of the first connection if it fails, rather than retrying.
+pub async fn init_live_with<Exchange, Kind>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+    config: ConsumerConfig,
+) -> Result<(LiveStream<Kind::Event>, LiveSubscriptions<Exchange, Kind>), DataError>
+where
+    Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+    Kind: SubKind + Ord + Send + Sync + 'static,
+    Kind::Event: Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    validate(subscriptions)?;
+
+    let (reconciler, universe_rx) = Reconciler::new(subscriptions.to_vec());
+    let shutdown = config.shutdown.clone();
+    let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+    let consumer = tokio::spawn(consume(
+        reconciler.current(),
+        exchange_tx,
+        LivenessTracker::new(),
+        MuteSwitch::new(),
+        ConnectionCounter::new(),
+        Some(universe_rx),
+        config,
+    ));
+
+    // Await the first connection, returning the DataError of the consumer loop if it fails
+    match exchange_rx.recv().await {
+        Some(StreamEvent::Connected { .. }) => {}
+        _ => {
+            return Err(consumer.await.unwrap_or(DataError::ConsumerTerminated {
+                exchange: Exchange::ID,
+            }))
+        }
+    }
+
+    Ok((
+        LiveStream::new(exchange_rx),
+        LiveSubscriptions::new(reconciler, shutdown),
+    ))
+}
+
+/// Handle used to subscribe & unsubscribe individual [`Subscription`]s over the open connection
+/// of a [`LiveStream`], without tearing down the connection. See [`init_live`].
+///
+/// ### Notes
+/// - Exchanges that do not define
+///   [`Connector::unsubscribe_requests`](crate::exchange::Connector::unsubscribe_requests)
+///   reject unsubscribing with a [`SocketError::Unsupported`].
+/// - Added [`Subscription`]s that cannot be routed live (eg/ OrderBooks requiring a snapshot)
+///   are applied by re-initialising the connection (see [`Reconciler`]).
+/// - Exchange responses to live requests are not validated, and are skipped by the [`consume`]
+///   loop like any other message that cannot be deserialised.
+#[derive(Debug)]
+pub struct LiveSubscriptions<Exchange, Kind> {
+    reconciler: Reconciler<Exchange, Kind>,
+    shutdown: Shutdown,
+    close_when_empty: bool,
+}
+
+impl<Exchange, Kind> Clone for LiveSubscriptions<Exchange, Kind> {
+    fn clone(&self) -> Self {
+        Self {
+            reconciler: self.reconciler.clone(),
+            shutdown: self.shutdown.clone(),
+            close_when_empty: self.close_when_empty,
+        }
+    }
+}
+
+impl<Exchange, Kind> LiveSubscriptions<Exchange, Kind>
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market> + Ord,
+{
+    fn new(reconciler: Reconciler<Exchange, Kind>, shutdown: Shutdown) -> Self {
+        Self {
+            reconciler,
+            shutdown,
+            close_when_empty: false,
+        }
+    }
+
+    /// Close the connection once the last [`Subscription`] is unsubscribed via this handle,
+    /// ending the [`LiveStream`].
+    pub fn close_when_empty(mut self, close: bool) -> Self {
+        self.close_when_empty = close;
+        self
+    }
+
+    /// [`SubscriptionId`]s of every [`Subscription`] currently streamed over the connection.
+    pub fn subscription_ids(&self) -> Vec<SubscriptionId> {
+        self.reconciler
+            .current()
+            .iter()
+            .map(|subscription| {
+                ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription).id()
+            })
+            .collect()
+    }
+
+    /// Subscribe to the provided [`Subscription`] over the open connection. Subscribing to an
+    /// already active [`Subscription`] is a no-op.
+    pub fn subscribe(&self, subscription: &Subscription<Exchange, Kind>) -> Result<(), DataError> {
+        let mut target = self.reconciler.current();
+        if target.contains(subscription) {
+            debug!(exchange = %Exchange::ID, ?subscription, "already subscribed, ignoring");
+            return Ok(());
+        }
+
+        target.push(subscription.clone());
+        self.reconciler.reconcile(target).map(|_| ())
+    }
+
+    /// Unsubscribe from the provided [`Subscription`] over the open connection, dropping any of
+    /// its messages received after this call. Unsubscribing from an inactive [`Subscription`]
+    /// is a no-op.
+    ///
+    /// The connection cannot stream an empty [`Subscription`] universe, so unsubscribing from
+    /// the last [`Subscription`] is rejected unless [`Self::close_when_empty`] is enabled.
+    pub fn unsubscribe(
+        &self,
+        subscription: &Subscription<Exchange, Kind>,
+    ) -> Result<(), DataError> {
+        let exchange_sub = ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription);
+        if Exchange::unsubscribe_requests(vec![exchange_sub]).is_empty() {
+            return Err(DataError::Socket(Box::new(SocketError::Unsupported {
+                entity: Exchange::ID.as_str(),
+                item: "unsubscribe".to_owned(),
+            })));
+        }
+
+        let mut target = self.reconciler.current();
+        let Some(index) = target.iter().position(|active| active == subscription) else {
+            debug!(exchange = %Exchange::ID, ?subscription, "not subscribed, ignoring");
+            return Ok(());
+        };
+
+        // Optionally close the connection once the last Subscription is removed
+        target.remove(index);
+        if target.is_empty() && self.close_when_empty {
+            self.shutdown.trigger();
+            return Ok(());
+        }
+
+        self.reconciler.reconcile(target).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{binance::spot::BinanceSpot, coinbase::Coinbase},
+        mock::{MockExchangeServer, MockStep},
+        subscription::trade::PublicTrades,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    fn subscription(base: &str) -> Subscription<BinanceSpot, PublicTrades> {
+        Subscription::from((
+            BinanceSpot::default(),
+            base,
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))
+    }
+
+    /// Receiver of the target [`Subscription`] universes sent by a [`LiveSubscriptions`].
+    type UniverseRx<Exchange, Kind> = mpsc::UnboundedReceiver<Vec<Subscription<Exchange, Kind>>>;
+
+    fn handle<Exchange, Kind>(
+        subscriptions: Vec<Subscription<Exchange, Kind>>,
+    ) -> (
+        LiveSubscriptions<Exchange, Kind>,
+        UniverseRx<Exchange, Kind>,
+    )
+    where
+        Exchange: StreamSelector<Kind>,
+        Kind: SubKind,
+        Subscription<Exchange, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market> + Ord,
+    {
+        let (reconciler, universe_rx) = Reconciler::new(subscriptions);
+        (
+            LiveSubscriptions::new(reconciler, Shutdown::new()),
+            universe_rx,
+        )
+    }
+
+    #[test]
+    fn test_live_subscriptions_unsubscribe_binance() {
+        let (live, mut universe_rx) = handle(vec![subscription("btc"), subscription("eth")]);
+        let live = live.close_when_empty(true);
+
+        // Unsubscribing sends the target universe without the Subscription
+        live.unsubscribe(&subscription("btc")).unwrap();
+        assert_eq!(universe_rx.try_recv().unwrap(), vec![subscription("eth")]);
+        assert_eq!(
+            live.subscription_ids(),
+            vec![SubscriptionId::from("@trade|ETHUSDT")]
+        );
+
+        // Unsubscribing from an inactive Subscription is a no-op
+        live.unsubscribe(&subscription("btc")).unwrap();
+        assert!(universe_rx.try_recv().is_err());
+
+        // Unsubscribing the last Subscription closes the connection
+        live.unsubscribe(&subscription("eth")).unwrap();
+        assert!(universe_rx.try_recv().is_err());
+        assert!(live.shutdown.is_triggered());
+
+        // Unsubscribing the last Subscription is rejected if the connection is kept open
+        let (live, _universe_rx) = handle(vec![subscription("btc")]);
+        assert!(live.unsubscribe(&subscription("btc")).is_err());
+        assert!(!live.shutdown.is_triggered());
+    }
+
+    #[test]
+    fn test_live_subscriptions_subscribe_binance() {
+        let (live, mut universe_rx) = handle(vec![subscription("btc")]);
+
+        live.subscribe(&subscription("eth")).unwrap();
+        assert_eq!(
+            universe_rx.try_recv().unwrap(),
+            vec![subscription("btc"), subscription("eth")]
+        );
+
+        // Subscribing to an active Subscription is a no-op
+        live.subscribe(&subscription("btc")).unwrap();
+        assert!(universe_rx.try_recv().is_err());
+
+        let mut actual = live.subscription_ids();
+        actual.sort();
+        assert_eq!(
+            actual,
+            vec![
+                SubscriptionId::from("@trade|BTCUSDT"),
+                SubscriptionId::from("@trade|ETHUSDT")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_live_subscriptions_unsubscribe_unsupported() {
+        let subscription =
+            Subscription::from((Coinbase, "btc", "usd", InstrumentKind::Spot, PublicTrades));
+        let (live, mut universe_rx) = handle(vec![subscription.clone()]);
+
+        assert!(matches!(
+            live.unsubscribe(&subscription),
+            Err(DataError::Socket(error)) if matches!(*error, SocketError::Unsupported { .. })
+        ));
+        assert!(universe_rx.try_recv().is_err());
+        assert_eq!(live.subscription_ids().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_init_live_updates_subscriptions_over_open_connection() {
+        let server = MockExchangeServer::start([
+            MockStep::trade("BTCUSDT", 1, 100.0),
+            MockStep::Delay(Duration::from_millis(200)),
+            MockStep::trade("BTCUSDT", 2, 100.0),
+            MockStep::trade("ETHUSDT", 3, 100.0),
+        ])
+        .await
+        .unwrap();
+
+        let (mut stream, live) = init_live_with(
+            &[subscription("btc")],
+            ConsumerConfig {
+                base_url: Some(server.url()),
+                ..ConsumerConfig::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            stream.next().await,
+            Some(StreamEvent::Market(event)) if event.kind.id == "1"
+        ));
+
+        // Swap btc for eth over the same connection
+        live.subscribe(&subscription("eth")).unwrap();
+        live.unsubscribe(&subscription("btc")).unwrap();
+
+        // In-flight btc trade is dropped, and the eth trade is routed
+        let event = tokio::time::timeout(Duration::from_secs(1), stream.next())
+            .await
+            .unwrap();
+        assert!(matches!(
+            event,
+            Some(StreamEvent::Market(event)) if event.kind.id == "3"
+        ));
+        assert_eq!(server.connections(), 1);
+        assert_eq!(
+            server.subscriptions(),
+            vec!["btcusdt@trade".to_string(), "ethusdt@trade".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_init_live_returns_first_connection_error() {
+        let url = url::Url::parse("ws://127.0.0.1:1").unwrap();
+
+        let actual = init_live_with(
+            &[subscription("btc")],
+            ConsumerConfig {
+                base_url: Some(url),
+                ..ConsumerConfig::default()
+            },
+        )
+        .await;
+        assert!(actual.is_err());
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{
+    error::DataError,
+    exchange::{Connector, ExchangeId},
+    subscription::Subscription,
+};
+use barter_integration::model::instrument::Instrument;
+use std::{collections::BTreeSet, fmt::Debug, sync::Arc, time::Duration};
+use tokio::sync::watch;
+
+/// Readiness gate that tracks which [`Subscription`]s have not yet produced their first
+/// [`MarketEvent`](crate::event::MarketEvent).
+///
+/// ### Notes
+/// - Each [`StreamBuilder`](super::builder::StreamBuilder) owns a [`LivenessTracker`], which is
+///   merged into the [`Liveness`] of any
+///   [`MultiStreamBuilder`](super::builder::multi::MultiStreamBuilder) it is added to.
+/// - Used via [`Streams::wait_until_live`](super::Streams::wait_until_live) to determine when
+///   every [`Subscription`] is actually receiving data (eg/ for a readiness probe).
+#[derive(Clone, Debug, Default)]
+pub struct Liveness {
+    trackers: Vec<LivenessTracker>,
+}
+
+impl Liveness {
+    /// Merge the [`LivenessTracker`]s of another [`Liveness`] into [`Self`].
+    pub fn merge(&mut self, other: Liveness) {
+        self.trackers.extend(other.trackers);
+    }
+
+    /// Collection of [`Subscription`] identifiers that have not yet produced an event.
+    pub fn pending(&self) -> Vec<String> {
+        self.trackers
+            .iter()
+            .flat_map(|tracker| tracker.pending.borrow().iter().cloned().collect::<Vec<_>>())
+            .collect()
+    }
+
+    /// Wait until every tracked [`Subscription`] has produced at least one event.
+    ///
+    /// If the timeout is reached first, a [`DataError::SubscriptionsNotLive`] is returned listing
+    /// the [`Subscription`]s that never produced data.
+    pub async fn wait_until_live(&self, timeout: Duration) -> Result<(), DataError> {
+        let all_live = futures::future::join_all(self.trackers.iter().map(|tracker| {
+            let mut pending_rx = tracker.pending.subscribe();
+            async move {
+                let _ = pending_rx.wait_for(BTreeSet::is_empty).await;
+            }
+        }));
+
+        match tokio::time::timeout(timeout, all_live).await {
+            Ok(_) => Ok(()),
+            Err(_) => Err(DataError::SubscriptionsNotLive {
+                timeout,
+                subscriptions: self.pending(),
+            }),
+        }
+    }
+}
+
+impl From<LivenessTracker> for Liveness {
+    fn from(tracker: LivenessTracker) -> Self {
+        Self {
+            trackers: vec![tracker],
+        }
+    }
+}
+
+/// Shared set of [`Subscription`]s that have not yet produced an event. Used by a
+/// [`consume`](super::consumer::consume) loop to record the first
+/// [`MarketEvent`](crate::event::MarketEvent) of each [`Subscription`].
+#[derive(Clone, Debug)]
+pub struct LivenessTracker {
+    pending: Arc<watch::Sender<BTreeSet<String>>>,
+}
+
+impl Default for LivenessTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LivenessTracker {
+    /// Construct a new [`Self`] with no registered [`Subscription`]s.
+    pub fn new() -> Self {
+        let (pending, _) = watch::channel(BTreeSet::new());
+        Self {
+            pending: Arc::new(pending),
+        }
+    }
+
+    /// Register a [`Subscription`] that is expected to produce events.
+    pub fn register<Exchange, Kind>(&self, subscription: &Subscription<Exchange, Kind>)
+    where
+        Exchange: Connector,
+        Kind: Debug,
+    {
+        let key = liveness_key(Exchange::ID, &subscription.kind, &subscription.instrument);
+        self.pending.send_modify(|pending| {
+            pending.insert(key);
+        });
+    }
+
+    /// Record that the [`Subscription`] associated with the provided [`ExchangeId`], `Kind` &
+    /// [`Instrument`] has produced an event.
+    pub fn record<Kind>(&self, exchange: ExchangeId, kind: &Kind, instrument: &Instrument)
+    where
+        Kind: Debug,
+    {
+        let key = liveness_key(exchange, kind, instrument);
+        self.pending
+            .send_if_modified(|pending| pending.remove(&key));
+    }
+}
+
+/// Generate the identifier of a [`Subscription`] used by [`Liveness`].
+///
+/// eg/ "binance_spot|PublicTrades|btc_usdt_spot"
+fn liveness_key<Kind>(exchange: ExchangeId, kind: &Kind, instrument: &Instrument) -> String
+where
+    Kind: Debug,
+{
+    format!("{exchange}|{kind:?}|{instrument}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::binance::spot::BinanceSpot, subscription::trade::PublicTrades};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[tokio::test]
+    async fn test_wait_until_live() {
+        let tracker = LivenessTracker::new();
+        let liveness = Liveness::from(tracker.clone());
+
+        let btc = Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ));
+        let eth = Subscription::from((
+            BinanceSpot::default(),
+            "eth",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ));
+        tracker.register(&btc);
+        tracker.register(&eth);
+
+        // Only the btc Subscription produces data
+        tracker.record(ExchangeId::BinanceSpot, &PublicTrades, &btc.instrument);
+
+        match liveness.wait_until_live(Duration::from_millis(10)).await {
+            Err(DataError::SubscriptionsNotLive { subscriptions, .. }) => {
+                assert_eq!(
+                    subscriptions,
+                    vec![liveness_key(
+                        ExchangeId::BinanceSpot,
+                        &PublicTrades,
+                        &eth.instrument
+                    )]
+                );
+            }
+            other => panic!("expected DataError::SubscriptionsNotLive, received: {other:?}"),
+        }
+
+        // Once the eth Subscription produces data, the Streams are live
+        tracker.record(ExchangeId::BinanceSpot, &PublicTrades, &eth.instrument);
+        assert!(liveness
+            .wait_until_live(Duration::from_millis(10))
+            .await
+            .is_ok());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+    time::Duration,
+};
+
+/// Default interval between re-connection attempts whilst an exchange is within a scheduled
+/// [`MaintenanceWindow`].
+pub const DEFAULT_MAINTENANCE_RECONNECT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Announced period of planned exchange downtime, during which connections are expected to drop
+/// and re-connection attempts are futile.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct MaintenanceWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl MaintenanceWindow {
+    /// Construct a new [`Self`] spanning `start` to `end`.
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self { start, end }
+    }
+
+    /// Determine if the provided time falls within [`Self`].
+    pub fn contains(&self, time: DateTime<Utc>) -> bool {
+        self.start <= time && time < self.end
+    }
+}
+
+/// Shared schedule of known exchange [`MaintenanceWindow`]s. Checked by a
+/// [`consume`](super::consumer::consume) loop before each re-connection attempt.
+///
+/// Whilst an exchange is within a [`MaintenanceWindow`], re-connection attempts are made at the
+/// extended `reconnect_interval` (bounded by the end of the window) rather than with the
+/// standard exponential backoff, and a
+/// [`StreamEvent::Maintenance`](crate::event::StreamEvent::Maintenance) marker is sent.
+///
+/// ### Notes
+/// Windows can be added at any time (eg/ after polling an exchange status endpoint), since every
+/// clone of [`Self`] shares the same schedule.
+#[derive(Clone, Debug)]
+pub struct MaintenanceSchedule {
+    windows: Arc<RwLock<HashMap<ExchangeId, Vec<MaintenanceWindow>>>>,
+    reconnect_interval: Duration,
+}
+
+impl Default for MaintenanceSchedule {
+    fn default() -> Self {
+        Self {
+            windows: Arc::default(),
+            reconnect_interval: DEFAULT_MAINTENANCE_RECONNECT_INTERVAL,
+        }
+    }
+}
+
+impl MaintenanceSchedule {
+    /// Construct a new [`Self`] with no scheduled [`MaintenanceWindow`]s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Configure the interval between re-connection attempts whilst within a
+    /// [`MaintenanceWindow`].
+    pub fn with_reconnect_interval(self, reconnect_interval: Duration) -> Self {
+        Self {
+            reconnect_interval,
+            ..self
+        }
+    }
+
+    /// Interval between re-connection attempts whilst within a [`MaintenanceWindow`].
+    pub fn reconnect_interval(&self) -> Duration {
+        self.reconnect_interval
+    }
+
+    /// Schedule a [`MaintenanceWindow`] for the provided exchange, discarding any windows that
+    /// have already ended.
+    pub fn add(&self, exchange: ExchangeId, window: MaintenanceWindow) {
+        let now = Utc::now();
+        let mut windows = self.windows.write().unwrap_or_else(PoisonError::into_inner);
+        let exchange_windows = windows.entry(exchange).or_default();
+        exchange_windows.retain(|scheduled| scheduled.end > now);
+        exchange_windows.push(window);
+    }
+
+    /// Remove every scheduled [`MaintenanceWindow`] of the provided exchange.
+    pub fn clear(&self, exchange: ExchangeId) {
+        self.windows
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&exchange);
+    }
+
+    /// Scheduled [`MaintenanceWindow`] of the provided exchange that contains the provided time,
+    /// if any.
+    pub fn active(&self, exchange: ExchangeId, time: DateTime<Utc>) -> Option<MaintenanceWindow> {
+        self.windows
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&exchange)?
+            .iter()
+            .find(|window| window.contains(time))
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_maintenance_schedule_active() {
+        let now = Utc::now();
+        let window = |start_secs: i64, end_secs: i64| {
+            MaintenanceWindow::new(
+                now + chrono::Duration::seconds(start_secs),
+                now + chrono::Duration::seconds(end_secs),
+            )
+        };
+
+        let schedule = MaintenanceSchedule::new();
+        schedule.add(ExchangeId::Coinbase, window(-10, 10));
+        schedule.add(ExchangeId::Coinbase, window(60, 120));
+
+        struct TestCase {
+            exchange: ExchangeId,
+            time: DateTime<Utc>,
+            expected: Option<MaintenanceWindow>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: time within the current window
+                exchange: ExchangeId::Coinbase,
+                time: now,
+                expected: Some(window(-10, 10)),
+            },
+            TestCase {
+                // TC1: time between windows
+                exchange: ExchangeId::Coinbase,
+                time: now + chrono::Duration::seconds(30),
+                expected: None,
+            },
+            TestCase {
+                // TC2: window end is exclusive
+                exchange: ExchangeId::Coinbase,
+                time: now + chrono::Duration::seconds(120),
+                expected: None,
+            },
+            TestCase {
+                // TC3: time within a window of another exchange
+                exchange: ExchangeId::Kraken,
+                time: now,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = schedule.active(test.exchange, test.time);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+
+        // Every clone shares the same schedule
+        schedule.clone().clear(ExchangeId::Coinbase);
+        assert_eq!(schedule.active(ExchangeId::Coinbase, now), None);
+    }
+}
@@ -0,0 +1,539 @@
+use super::{
+    builder::validate,
+    connection::ConnectionCounter,
+    consumer::{consume, ConsumerConfig},
+    liveness::LivenessTracker,
+    mute::MuteSwitch,
+    reconcile::Reconciler,
+};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, StreamEvent, StreamItem},
+    exchange::StreamSelector,
+    subscription::{SubKind, Subscription},
+    Identifier,
+};
+use barter_integration::model::instrument::Instrument;
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    sync::{Arc, Mutex, PoisonError},
+};
+use tokio::sync::mpsc;
+use tracing::info;
+
+/// Manager that multiplexes the [`Subscription`]s of [`Streams`](super::Streams) built at
+/// different times onto the existing open connections of the same exchange, rather than always
+/// opening fresh connections.
+///
+/// Used via
+/// [`StreamBuilder::subscribe_shared`](super::builder::StreamBuilder::subscribe_shared), sharing
+/// the same [`ConnectionManager`] between every [`StreamBuilder`](super::builder::StreamBuilder)
+/// that should reuse connections.
+///
+/// ### Notes
+/// - A collection of [`Subscription`]s is added to the first shared connection with enough
+///   capacity for the combined [`Subscription`] universe, else a new shared connection is opened.
+/// - [`Subscription`]s added to a shared connection are multiplexed onto the open connection
+///   via incremental subscription requests (see
+///   [`MarketStream::update_subscriptions`](crate::MarketStream::update_subscriptions)). Only
+///   [`MarketStream`](crate::MarketStream)s that cannot be updated live (eg/ OrderBooks
+///   requiring a snapshot) are re-initialised (see [`Reconciler`]).
+/// - Events are routed to each attached [`Streams`](super::Streams) by [`Instrument`], honouring
+///   the liveness & mutes of the [`StreamBuilder`](super::builder::StreamBuilder) that added them.
+/// - Shared connections are counted by [`Self::open_connections`], rather than by the
+///   [`Streams::open_connections`](super::Streams::open_connections) of each attached
+///   [`Streams`](super::Streams).
+pub struct ConnectionManager<Exchange, Kind, Output = MarketEvent<<Kind as SubKind>::Event>>
+where
+    Kind: SubKind,
+{
+    capacity: usize,
+    config: ConsumerConfig,
+    shared: Arc<Mutex<Vec<SharedConnection<Exchange, Kind, Output>>>>,
+    connections: ConnectionCounter,
+}
+
+impl<Exchange, Kind, Output> Debug for ConnectionManager<Exchange, Kind, Output>
+where
+    Kind: SubKind,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectionManager")
+            .field("capacity", &self.capacity)
+            .field("open_connections", &self.connections.open())
+            .finish()
+    }
+}
+
+impl<Exchange, Kind, Output> Clone for ConnectionManager<Exchange, Kind, Output>
+where
+    Kind: SubKind,
+{
+    fn clone(&self) -> Self {
+        Self {
+            capacity: self.capacity,
+            config: self.config.clone(),
+            shared: Arc::clone(&self.shared),
+            connections: self.connections.clone(),
+        }
+    }
+}
+
+/// Shared connection actioning the [`Subscription`]s of every attached [`Route`].
+struct SharedConnection<Exchange, Kind, Output> {
+    reconciler: Reconciler<Exchange, Kind>,
+    routes: Arc<Mutex<Vec<Route<Exchange, Kind, Output>>>>,
+}
+
+/// [`Subscription`]s added to a [`SharedConnection`] by a single
+/// [`StreamBuilder`](super::builder::StreamBuilder), and where to distribute their events.
+struct Route<Exchange, Kind, Output> {
+    subscriptions: Vec<Subscription<Exchange, Kind>>,
+    exchange_tx: mpsc::UnboundedSender<Output>,
+    liveness: LivenessTracker,
+    mutes: MuteSwitch,
+    pending: HashSet<Instrument>,
+    connected: bool,
+}
+
+impl<Exchange, Kind, Output> Route<Exchange, Kind, Output>
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Kind::Event: Clone,
+    Output: StreamItem<Kind::Event>,
+{
+    /// Send a (re)connection marker, if `Output` represents connection lifecycle markers.
+    fn connected(&mut self) {
+        if let Some(marker) = Output::connected(Exchange::ID, self.connected) {
+            let _ = self.exchange_tx.send(marker);
+        }
+        self.connected = true;
+    }
+
+    /// Send a failure marker, if `Output` represents connection failure markers.
+    fn failed(&mut self, error: String) {
+        if let Some(marker) = Output::failed(Exchange::ID, error) {
+            let _ = self.exchange_tx.send(marker);
+        }
+    }
+
+    /// Send the [`MarketEvent`] if it is associated with one of the route [`Subscription`]s.
+    fn distribute(&mut self, event: &MarketEvent<Kind::Event>) {
+        let instrument = &*event.instrument;
+        if !self
+            .subscriptions
+            .iter()
+            .any(|subscription| subscription.instrument == *instrument)
+        {
+            return;
+        }
+
+        // Record the first MarketEvent of each Subscription with the LivenessTracker
+        if self.pending.remove(instrument) {
+            self.subscriptions
+                .iter()
+                .filter(|subscription| subscription.instrument == *instrument)
+                .for_each(|subscription| {
+                    self.liveness
+                        .record(Exchange::ID, &subscription.kind, instrument)
+                });
+        }
+
+        // Suppress MarketEvents of muted Subscriptions
+        if self.mutes.is_muted(Exchange::ID, instrument) {
+            return;
+        }
+
+        let _ = self.exchange_tx.send(Output::from(event.clone()));
+    }
+}
+
+impl<Exchange, Kind, Output> ConnectionManager<Exchange, Kind, Output>
+where
+    Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+    Kind: SubKind + Ord + Send + Sync + 'static,
+    Kind::Event: Clone + Send,
+    Output: StreamItem<Kind::Event> + Debug + Send + 'static,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    /// Construct a new [`Self`] that shares a connection between at most `capacity`
+    /// [`Subscription`]s (eg/ the exchange per-connection subscription limit).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            config: ConsumerConfig::default(),
+            shared: Arc::new(Mutex::new(Vec::new())),
+            connections: ConnectionCounter::new(),
+        }
+    }
+
+    /// Use the provided [`ConsumerConfig`] (eg/ a [`ConsumerConfig::base_url`]) for the consumer
+    /// loop of every shared connection subsequently opened by [`Self`].
+    pub fn with_config(mut self, config: ConsumerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Number of currently open shared connections.
+    pub fn open_connections(&self) -> usize {
+        self.connections.open()
+    }
+
+    /// Action the provided [`Subscription`]s on an existing shared connection with enough
+    /// capacity, else on a new shared connection, distributing their events via the
+    /// `exchange_tx`.
+    ///
+    /// A collection of [`Subscription`]s larger than the capacity is actioned on a dedicated
+    /// shared connection.
+    pub fn subscribe(
+        &self,
+        mut subscriptions: Vec<Subscription<Exchange, Kind>>,
+        exchange_tx: mpsc::UnboundedSender<Output>,
+        liveness: LivenessTracker,
+        mutes: MuteSwitch,
+    ) -> Result<(), DataError> {
+        validate(&subscriptions)?;
+        subscriptions.sort();
+        subscriptions.dedup();
+
+        let mut route = Some(Route {
+            pending: subscriptions
+                .iter()
+                .map(|subscription| subscription.instrument.clone())
+                .collect(),
+            subscriptions: subscriptions.clone(),
+            exchange_tx,
+            liveness,
+            mutes,
+            connected: false,
+        });
+
+        let mut shared = self.shared.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // Attach to the first live shared connection with enough capacity
+        let mut terminated = Vec::new();
+        for (index, connection) in shared.iter().enumerate() {
+            let mut target = connection.reconciler.current();
+            target.extend(subscriptions.iter().cloned());
+            target.sort();
+            target.dedup();
+            if target.len() > self.capacity {
+                continue;
+            }
+
+            // Route events before the re-initialised MarketStream yields them
+            let mut routes = connection
+                .routes
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            routes.extend(route.take());
+
+            match connection.reconciler.reconcile(target) {
+                Ok(_) => break,
+                Err(_) => {
+                    // Consumer loop of the shared connection has terminated
+                    route = routes.pop();
+                    terminated.push(index);
+                }
+            }
+        }
+        terminated.into_iter().rev().for_each(|index| {
+            shared.remove(index);
+        });
+
+        let Some(route) = route else {
+            info!(
+                exchange = %Exchange::ID,
+                shared_connections = shared.len(),
+                "added Subscriptions to an existing shared connection"
+            );
+            return Ok(());
+        };
+
+        // Open a new shared connection
+        let (reconciler, universe_rx) = Reconciler::new(subscriptions.clone());
+        let routes = Arc::new(Mutex::new(vec![route]));
+        let (stream_tx, stream_rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(consume(
+            subscriptions,
+            stream_tx,
+            LivenessTracker::new(),
+            MuteSwitch::new(),
+            self.connections.clone(),
+            Some(universe_rx),
+            self.config.clone(),
+        ));
+        tokio::spawn(distribute_to_routes(stream_rx, Arc::clone(&routes)));
+
+        shared.push(SharedConnection { reconciler, routes });
+        info!(
+            exchange = %Exchange::ID,
+            shared_connections = shared.len(),
+            "opened new shared connection"
+        );
+
+        Ok(())
+    }
+}
+
+/// Distribute the [`StreamEvent`]s consumed from a shared connection to every attached
+/// [`Route`], dropping routes whose receiver has been dropped.
+async fn distribute_to_routes<Exchange, Kind, Output>(
+    mut stream_rx: mpsc::UnboundedReceiver<StreamEvent<Kind::Event>>,
+    routes: Arc<Mutex<Vec<Route<Exchange, Kind, Output>>>>,
+) where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Kind::Event: Clone,
+    Output: StreamItem<Kind::Event>,
+{
+    while let Some(event) = stream_rx.recv().await {
+        let mut routes = routes.lock().unwrap_or_else(PoisonError::into_inner);
+
+        match event {
+            StreamEvent::Connected { .. } | StreamEvent::Reconnected { .. } => {
+                routes.iter_mut().for_each(Route::connected)
+            }
+            // Heartbeats & maintenance windows are not enabled on shared connections
+            StreamEvent::Heartbeat { .. } | StreamEvent::Maintenance { .. } => {}
+            StreamEvent::Failed { error, .. } => routes
+                .iter_mut()
+                .for_each(|route| route.failed(error.clone())),
+            StreamEvent::Market(event) => {
+                routes.iter_mut().for_each(|route| route.distribute(&event))
+            }
+        }
+
+        routes.retain(|route| !route.exchange_tx.is_closed());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::spot::BinanceSpot,
+        exchange::{
+            coinbase::subscription::CoinbaseSubResponse, subscription::ExchangeSub, Connector,
+            ExchangeId,
+        },
+        mock::{MockExchangeServer, MockStep},
+        streams::Streams,
+        subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+        subscription::trade::{PublicTrade, PublicTrades},
+        MarketStream,
+    };
+    use async_trait::async_trait;
+    use barter_integration::{
+        error::SocketError,
+        model::{instrument::kind::InstrumentKind, Exchange, Side},
+        protocol::websocket::WsMessage,
+    };
+    use chrono::Utc;
+    use futures::stream::{self, BoxStream, StreamExt};
+    use serde::{Deserialize, Serialize};
+    use std::time::Duration;
+    use url::Url;
+
+    /// Instruments of every [`MockExchange`] [`MarketStream`] initialisation.
+    static INITS: Mutex<Vec<Vec<Instrument>>> = Mutex::new(Vec::new());
+
+    #[derive(
+        Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Deserialize, Serialize,
+    )]
+    struct MockExchange;
+
+    impl Connector for MockExchange {
+        const ID: ExchangeId = ExchangeId::Coinbase;
+        type Channel = String;
+        type Market = String;
+        type Subscriber = WebSocketSubscriber;
+        type SubValidator = WebSocketSubValidator;
+        type SubResponse = CoinbaseSubResponse;
+
+        fn url() -> Result<Url, SocketError> {
+            Url::parse("ws://localhost").map_err(SocketError::UrlParse)
+        }
+
+        fn requests(_: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+            vec![]
+        }
+    }
+
+    impl Identifier<String> for Subscription<MockExchange, PublicTrades> {
+        fn id(&self) -> String {
+            self.instrument.to_string()
+        }
+    }
+
+    impl StreamSelector<PublicTrades> for MockExchange {
+        type Stream = BoxStream<'static, Result<MarketEvent<PublicTrade>, DataError>>;
+    }
+
+    /// Records the Instruments of each initialisation, yields a single trade for each
+    /// Subscription, and then stays open.
+    #[async_trait]
+    impl MarketStream<MockExchange, PublicTrades>
+        for BoxStream<'static, Result<MarketEvent<PublicTrade>, DataError>>
+    {
+        async fn init(
+            subscriptions: &[Subscription<MockExchange, PublicTrades>],
+        ) -> Result<Self, DataError> {
+            let instruments = subscriptions
+                .iter()
+                .map(|subscription| subscription.instrument.clone())
+                .collect::<Vec<_>>();
+            INITS.lock().unwrap().push(instruments.clone());
+
+            let trades = instruments.into_iter().map(|instrument| {
+                Ok(MarketEvent {
+                    exchange_time: Utc::now(),
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(MockExchange::ID),
+                    kind: PublicTrade {
+                        id: instrument.base.to_string(),
+                        price: 1.0,
+                        amount: 1.0,
+                        side: Side::Buy,
+                        conditions: vec![],
+                    },
+                    instrument: Arc::new(instrument),
+                })
+            });
+
+            Ok(Box::pin(stream::iter(trades).chain(stream::pending())))
+        }
+    }
+
+    fn subscription(base: &str) -> Subscription<MockExchange, PublicTrades> {
+        Subscription::from((
+            MockExchange,
+            base,
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))
+    }
+
+    async fn trade_ids(streams: &mut Streams<MarketEvent<PublicTrade>>) -> Vec<String> {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let exchange_rx = streams.streams.get_mut(&MockExchange::ID).unwrap();
+
+        let mut ids = Vec::new();
+        while let Ok(event) = exchange_rx.try_recv() {
+            ids.push(event.kind.id);
+        }
+        ids
+    }
+
+    #[tokio::test]
+    async fn test_connection_manager_reuses_connection_with_capacity() {
+        let manager = ConnectionManager::<MockExchange, PublicTrades>::new(2);
+
+        // TC0: first Streams opens a new shared connection
+        let mut streams_btc = Streams::<MarketEvent<PublicTrade>>::builder()
+            .subscribe_shared(&manager, [subscription("btc")])
+            .init()
+            .await
+            .unwrap();
+        assert_eq!(trade_ids(&mut streams_btc).await, vec!["btc"], "TC0 failed");
+        assert_eq!(manager.open_connections(), 1, "TC0 failed");
+
+        // TC1: second Streams built later reuses the existing connection, since capacity allows
+        let mut streams_eth = Streams::<MarketEvent<PublicTrade>>::builder()
+            .subscribe_shared(&manager, [subscription("eth")])
+            .init()
+            .await
+            .unwrap();
+        assert_eq!(trade_ids(&mut streams_eth).await, vec!["eth"], "TC1 failed");
+        assert_eq!(trade_ids(&mut streams_btc).await, vec!["btc"], "TC1 failed");
+        assert_eq!(manager.open_connections(), 1, "TC1 failed");
+
+        // TC2: third Streams exceeds the capacity of the existing connection, so opens another
+        let mut streams_sol = Streams::<MarketEvent<PublicTrade>>::builder()
+            .subscribe_shared(&manager, [subscription("sol")])
+            .init()
+            .await
+            .unwrap();
+        assert_eq!(trade_ids(&mut streams_sol).await, vec!["sol"], "TC2 failed");
+        assert_eq!(manager.open_connections(), 2, "TC2 failed");
+
+        let instruments = |bases: &[&str]| {
+            bases
+                .iter()
+                .map(|base| subscription(base).instrument)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            *INITS.lock().unwrap(),
+            vec![
+                instruments(&["btc"]),
+                instruments(&["btc", "eth"]),
+                instruments(&["sol"]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_connection_manager_multiplexes_onto_open_connection() {
+        let server = MockExchangeServer::start([
+            MockStep::Delay(Duration::from_millis(100)),
+            MockStep::trade("ETHUSDT", 1, 100.0),
+        ])
+        .await
+        .unwrap();
+
+        let manager =
+            ConnectionManager::<BinanceSpot, PublicTrades>::new(2).with_config(ConsumerConfig {
+                base_url: Some(server.url()),
+                ..ConsumerConfig::default()
+            });
+        let subscription = |base: &str| {
+            Subscription::from((
+                BinanceSpot::default(),
+                base,
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ))
+        };
+
+        let (btc_tx, _btc_rx) = mpsc::unbounded_channel();
+        manager
+            .subscribe(
+                vec![subscription("btc")],
+                btc_tx,
+                LivenessTracker::new(),
+                MuteSwitch::new(),
+            )
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // Subscriptions added later are subscribed to over the same open connection
+        let (eth_tx, mut eth_rx) = mpsc::unbounded_channel();
+        manager
+            .subscribe(
+                vec![subscription("eth")],
+                eth_tx,
+                LivenessTracker::new(),
+                MuteSwitch::new(),
+            )
+            .unwrap();
+
+        let event: MarketEvent<PublicTrade> =
+            tokio::time::timeout(Duration::from_secs(1), eth_rx.recv())
+                .await
+                .unwrap()
+                .unwrap();
+        assert_eq!(event.instrument.base.as_ref(), "eth");
+        assert_eq!(server.connections(), 1);
+        assert_eq!(
+            server.subscriptions(),
+            vec!["btcusdt@trade".to_string(), "ethusdt@trade".to_string()]
+        );
+        assert_eq!(manager.open_connections(), 1);
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{exchange::ExchangeId, subscription::SubKindId};
+use std::fmt::Debug;
+
+/// Counters recorded by the [`consume`](super::consumer::consume) loops of the
+/// [`Streams`](super::Streams), used to export production metrics (eg/ events per second) to a
+/// user provided metrics backend.
+///
+/// Every method defaults to a no-op, so implementations only need to handle the counters they
+/// export.
+///
+/// ### Notes
+/// - Methods are called inline on the consumer loop's hot path, so implementations should be
+///   cheap (eg/ an atomic increment).
+/// - The [`SubKindId`] is `None` for [`SubKind`](crate::subscription::SubKind)s without a
+///   normalised identifier (see [`SubKind::ID`](crate::subscription::SubKind::ID)).
+pub trait Metrics
+where
+    Self: Debug + Send + Sync,
+{
+    /// Record a [`MarketEvent<T>`](crate::event::MarketEvent) consumed from an exchange
+    /// connection, including events subsequently dropped (eg/ muted or replayed).
+    fn record_event(&self, _exchange: ExchangeId, _kind: Option<SubKindId>) {}
+
+    /// Record an exchange frame that failed to deserialise (see
+    /// [`DataError::Deserialise`](crate::error::DataError::Deserialise) &
+    /// [`DataError::RawFrame`](crate::error::DataError::RawFrame)).
+    fn record_parse_error(&self, _exchange: ExchangeId) {}
+
+    /// Record a successful re-connection after an exchange connection ended.
+    fn record_reconnect(&self, _exchange: ExchangeId) {}
+}
+
+/// [`Metrics`] implementation that records nothing, used by default.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {}
@@ -1,22 +1,103 @@
-use self::builder::{multi::MultiStreamBuilder, StreamBuilder};
-use crate::{exchange::ExchangeId, subscription::SubKind};
-use std::collections::HashMap;
+use self::{
+    builder::{multi::MultiStreamBuilder, StreamBuilder},
+    connection::Connections,
+    liveness::Liveness,
+    mute::Mutes,
+    sink::{EventSink, SinkErrorPolicy},
+    status::{ConnectionStatus, ConnectionStatuses},
+};
+use crate::{error::DataError, exchange::ExchangeId, subscription::SubKind};
+use barter_integration::model::instrument::Instrument;
+use futures::{Stream, StreamExt};
+use std::{collections::HashMap, fmt::Debug, time::Duration};
 use tokio::sync::mpsc;
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamMap};
 
+/// [`buffer`](buffer::buffer) utility that bounds a [`MarketEvent<T>`](crate::event::MarketEvent)
+/// receiver, applying a [`BufferPolicy`](buffer::BufferPolicy) whilst its consumer is lagging.
+pub mod buffer;
+
 /// Defines the [`StreamBuilder`](builder::StreamBuilder) and
 /// [`MultiStreamBuilder`](builder::multi::MultiStreamBuilder) APIs for ergonomically initialising
 /// [`MarketStream`](super::MarketStream) [`Streams`].
 pub mod builder;
 
+/// [`Connections`] handle used to observe the number of concurrently open exchange connections.
+pub mod connection;
+
 /// Central consumer loop functionality used by the [`StreamBuilder`](builder::StreamBuilder) to
 /// to drive a re-connecting [`MarketStream`](super::MarketStream).
 pub mod consumer;
 
+/// [`demux`](demux::demux) utility that partitions a merged
+/// [`MarketEvent<T>`](crate::event::MarketEvent) stream into one receiver per
+/// [`Instrument`].
+pub mod demux;
+
+/// [`LiveSubscriptions`](live::LiveSubscriptions) handle used to subscribe & unsubscribe
+/// individual [`Subscription`](crate::subscription::Subscription)s over an open connection.
+pub mod live;
+
+/// [`Liveness`] readiness gate used to determine when every
+/// [`Subscription`](crate::subscription::Subscription) of the [`Streams`] has produced data.
+pub mod liveness;
+
+/// [`MaintenanceSchedule`](maintenance::MaintenanceSchedule) of known exchange maintenance
+/// windows, during which re-connection attempts are made at an extended interval.
+pub mod maintenance;
+
+/// [`ConnectionManager`](manager::ConnectionManager) used to share exchange connections between
+/// [`Streams`] built at different times.
+pub mod manager;
+
+/// [`Metrics`](metrics::Metrics) abstraction used to export counters (eg/ events, parse errors
+/// & re-connections) recorded by the consumer loops.
+pub mod metrics;
+
+/// [`Mutes`] handle used to temporarily suppress the events of individual
+/// [`Subscription`](crate::subscription::Subscription)s without tearing down their state.
+pub mod mute;
+
+/// [`BookPublisher`](publish::BookPublisher) used to re-publish reconstructed
+/// [`OrderBook`](crate::subscription::book::OrderBook)s to many subscribers as incremental
+/// patches.
+pub mod publish;
+
+/// [`Reconciler`](reconcile::Reconciler) handle used to hot-reload the
+/// [`Subscription`](crate::subscription::Subscription) universe of a running consumer loop.
+pub mod reconcile;
+
+/// [`ReplayStream`](replay::ReplayStream) used to re-emit recorded
+/// [`MarketEvent<T>`](crate::event::MarketEvent)s through the same interface as a live stream.
+pub mod replay;
+
+/// [`Shutdown`](shutdown::Shutdown) handle used to gracefully close the connections of running
+/// consumer loops.
+pub mod shutdown;
+
+/// [`EventSink`](sink::EventSink) trait used to drive [`Streams`] into user provided
+/// destinations (eg/ a message bus), the associated [`SinkErrorPolicy`], and the
+/// [`CsvSink`](sink::csv::CsvSink) & [`FrameSink`](sink::frame::FrameSink) used to record
+/// [`Streams`] to disk.
+pub mod sink;
+
+/// [`ConnectionStatuses`] handle used to observe the [`ConnectionStatus`] of each exchange
+/// connection of the [`Streams`].
+pub mod status;
+
+/// [`DynamicUniverse`](universe::DynamicUniverse) driver used to periodically reconcile the
+/// [`Subscription`](crate::subscription::Subscription) universe of a running consumer loop on
+/// the universe yielded by a user provided closure.
+pub mod universe;
+
 /// Ergonomic collection of exchange [`MarketEvent<T>`](crate::event::MarketEvent) receivers.
 #[derive(Debug)]
 pub struct Streams<T> {
     pub streams: HashMap<ExchangeId, mpsc::UnboundedReceiver<T>>,
+    pub liveness: Liveness,
+    pub mutes: Mutes,
+    pub connections: Connections,
+    pub statuses: ConnectionStatuses,
 }
 
 impl<T> Streams<T> {
@@ -35,6 +116,38 @@ impl<T> Streams<T> {
         MultiStreamBuilder::<T>::new()
     }
 
+    /// Wait until at least one [`MarketEvent`](crate::event::MarketEvent) has been received for
+    /// every [`Subscription`](crate::subscription::Subscription) of the [`Streams`].
+    ///
+    /// Returns a [`DataError::SubscriptionsNotLive`] listing the
+    /// [`Subscription`](crate::subscription::Subscription)s that never produced data if the
+    /// timeout is reached first.
+    pub async fn wait_until_live(&self, timeout: Duration) -> Result<(), DataError> {
+        self.liveness.wait_until_live(timeout).await
+    }
+
+    /// Suppress the events of the provided exchange [`Instrument`] whilst keeping its
+    /// subscription & internal state (eg/ order book) warm. See [`Mutes`] for more information.
+    pub fn mute(&self, exchange: ExchangeId, instrument: &Instrument) {
+        self.mutes.mute(exchange, instrument)
+    }
+
+    /// Resume distributing the events of a previously muted exchange [`Instrument`].
+    pub fn unmute(&self, exchange: ExchangeId, instrument: &Instrument) {
+        self.mutes.unmute(exchange, instrument)
+    }
+
+    /// Number of currently open exchange connections. See [`Connections`] for more information.
+    pub fn open_connections(&self) -> usize {
+        self.connections.open()
+    }
+
+    /// Latest [`ConnectionStatus`] of every connection with the provided exchange. See
+    /// [`ConnectionStatuses`] for more information.
+    pub fn status(&self, exchange: ExchangeId) -> Vec<ConnectionStatus> {
+        self.statuses.latest(exchange)
+    }
+
     /// Remove an exchange [`mpsc::UnboundedReceiver`] from the [`Streams`] `HashMap`.
     pub fn select(&mut self, exchange: ExchangeId) -> Option<mpsc::UnboundedReceiver<T>> {
         self.streams.remove(&exchange)
@@ -60,6 +173,40 @@ impl<T> Streams<T> {
         joined_rx
     }
 
+    /// Join all exchange [`mpsc::UnboundedReceiver`] streams and drive every event into the
+    /// provided [`EventSink`], handling delivery failures according to the [`SinkErrorPolicy`].
+    ///
+    /// Returns once every exchange stream has ended, or with a [`DataError::Sink`] if the
+    /// [`SinkErrorPolicy::Stop`] policy was triggered.
+    pub async fn drive_into<Sink>(
+        self,
+        sink: Sink,
+        policy: SinkErrorPolicy,
+    ) -> Result<(), DataError>
+    where
+        T: Clone + Debug + Send + 'static,
+        Sink: EventSink<T>,
+    {
+        sink::drive(self.join().await, sink, policy).await
+    }
+
+    /// Merge all exchange [`mpsc::UnboundedReceiver`] streams into a single [`Stream`] of events
+    /// tagged with the [`ExchangeId`] that produced them, polled fairly via
+    /// [`select_all`](futures::stream::select_all).
+    ///
+    /// The merged [`Stream`] continues whilst any exchange stream is still open, so an exchange
+    /// that fails to connect does not end it. With a
+    /// [`StreamEvent<T>`](crate::event::StreamEvent) `Output` the failure is surfaced as a
+    /// [`StreamEvent::Failed`](crate::event::StreamEvent::Failed) marker.
+    pub fn merge(self) -> impl Stream<Item = (ExchangeId, T)> + Send + Unpin
+    where
+        T: Send + 'static,
+    {
+        futures::stream::select_all(self.streams.into_iter().map(|(exchange, rx)| {
+            UnboundedReceiverStream::new(rx).map(move |event| (exchange, event))
+        }))
+    }
+
     /// Join all exchange [`mpsc::UnboundedReceiver`] streams into a unified [`StreamMap`].
     pub async fn join_map(self) -> StreamMap<ExchangeId, UnboundedReceiverStream<T>> {
         self.streams
@@ -70,3 +217,78 @@ impl<T> Streams<T> {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::StreamEvent;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_streams_merge() {
+        let (binance_tx, binance_rx) = mpsc::unbounded_channel::<StreamEvent<u64>>();
+        let (okx_tx, okx_rx) = mpsc::unbounded_channel::<StreamEvent<u64>>();
+
+        let streams = Streams {
+            streams: HashMap::from([
+                (ExchangeId::BinanceSpot, binance_rx),
+                (ExchangeId::Okx, okx_rx),
+            ]),
+            liveness: Liveness::default(),
+            mutes: Mutes::default(),
+            connections: Connections::default(),
+            statuses: ConnectionStatuses::default(),
+        };
+        let mut merged = streams.merge();
+
+        // Okx fails to connect, and its consumer loop ends
+        okx_tx
+            .send(StreamEvent::Failed {
+                exchange: ExchangeId::Okx,
+                error: "connection refused".to_string(),
+                time: Utc::now(),
+            })
+            .unwrap();
+        drop(okx_tx);
+
+        // BinanceSpot keeps producing events after the Okx failure
+        let connected = StreamEvent::Connected {
+            exchange: ExchangeId::BinanceSpot,
+            time: Utc::now(),
+        };
+        binance_tx.send(connected.clone()).unwrap();
+        binance_tx.send(connected).unwrap();
+
+        let mut actual = Vec::with_capacity(3);
+        while actual.len() < 3 {
+            let (exchange, event) = tokio::time::timeout(Duration::from_secs(5), merged.next())
+                .await
+                .unwrap()
+                .unwrap();
+            actual.push(match event {
+                StreamEvent::Failed {
+                    exchange: failed, ..
+                } => {
+                    assert_eq!(failed, exchange);
+                    format!("{exchange} failed")
+                }
+                StreamEvent::Connected { .. } => format!("{exchange} connected"),
+                other => panic!("unexpected event: {other:?}"),
+            });
+        }
+        actual.sort();
+
+        assert_eq!(
+            actual,
+            vec![
+                "binance_spot connected",
+                "binance_spot connected",
+                "okx failed"
+            ]
+        );
+
+        // Merged stream ends once every exchange stream has ended
+        drop(binance_tx);
+        assert!(merged.next().await.is_none());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::exchange::ExchangeId;
+use barter_integration::model::instrument::Instrument;
+use std::{
+    collections::HashSet,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+/// Handle used to temporarily suppress the consumer-visible
+/// [`MarketEvent`](crate::event::MarketEvent)s of individual
+/// [`Subscription`](crate::subscription::Subscription)s (eg/ during a suspected data issue).
+///
+/// ### Notes
+/// - Muting only suppresses the distribution of events. The underlying connection and any
+///   internal state (eg/ [`OrderBook`](crate::subscription::book::OrderBook)s maintained by the
+///   [`ExchangeTransformer`](crate::transformer::ExchangeTransformer)) keep updating, so events
+///   resume instantly & up to date once unmuted.
+/// - Each [`StreamBuilder`](super::builder::StreamBuilder) owns a [`MuteSwitch`], which is merged
+///   into the [`Mutes`] of any [`MultiStreamBuilder`](super::builder::multi::MultiStreamBuilder)
+///   it is added to.
+#[derive(Clone, Debug, Default)]
+pub struct Mutes {
+    switches: Vec<MuteSwitch>,
+}
+
+impl Mutes {
+    /// Merge the [`MuteSwitch`]es of another [`Mutes`] into [`Self`].
+    pub fn merge(&mut self, other: Mutes) {
+        self.switches.extend(other.switches);
+    }
+
+    /// Suppress the events of the provided exchange [`Instrument`].
+    pub fn mute(&self, exchange: ExchangeId, instrument: &Instrument) {
+        self.switches
+            .iter()
+            .for_each(|switch| switch.mute(exchange, instrument));
+    }
+
+    /// Resume distributing the events of the provided exchange [`Instrument`].
+    pub fn unmute(&self, exchange: ExchangeId, instrument: &Instrument) {
+        self.switches
+            .iter()
+            .for_each(|switch| switch.unmute(exchange, instrument));
+    }
+
+    /// Determine if the events of the provided exchange [`Instrument`] are currently muted.
+    pub fn is_muted(&self, exchange: ExchangeId, instrument: &Instrument) -> bool {
+        self.switches
+            .iter()
+            .any(|switch| switch.is_muted(exchange, instrument))
+    }
+}
+
+impl From<MuteSwitch> for Mutes {
+    fn from(switch: MuteSwitch) -> Self {
+        Self {
+            switches: vec![switch],
+        }
+    }
+}
+
+/// Shared set of muted exchange [`Instrument`]s. Checked by a
+/// [`consume`](super::consumer::consume) loop before distributing each
+/// [`MarketEvent`](crate::event::MarketEvent).
+#[derive(Clone, Debug, Default)]
+pub struct MuteSwitch {
+    muted: Arc<RwLock<HashSet<(ExchangeId, Instrument)>>>,
+}
+
+impl MuteSwitch {
+    /// Construct a new [`Self`] with no muted [`Instrument`]s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Suppress the events of the provided exchange [`Instrument`].
+    pub fn mute(&self, exchange: ExchangeId, instrument: &Instrument) {
+        self.muted
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert((exchange, instrument.clone()));
+    }
+
+    /// Resume distributing the events of the provided exchange [`Instrument`].
+    pub fn unmute(&self, exchange: ExchangeId, instrument: &Instrument) {
+        self.muted
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&(exchange, instrument.clone()));
+    }
+
+    /// Determine if the events of the provided exchange [`Instrument`] are currently muted.
+    pub fn is_muted(&self, exchange: ExchangeId, instrument: &Instrument) -> bool {
+        let muted = self.muted.read().unwrap_or_else(PoisonError::into_inner);
+
+        // Avoid cloning the Instrument on the hot path when nothing is muted
+        !muted.is_empty() && muted.contains(&(exchange, instrument.clone()))
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{
+    event::MarketEvent,
+    subscription::book::{BookPatch, OrderBook},
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::mpsc;
+
+/// Message distributed to each [`BookPublisher`] subscriber.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum BookMessage {
+    /// Full [`OrderBook`] snapshot, sent for every known [`OrderBook`] when a subscriber joins.
+    Snapshot(MarketEvent<OrderBook>),
+    /// Minimal level-diff to apply to the previously received [`OrderBook`] via
+    /// [`OrderBook::apply`].
+    Patch(MarketEvent<BookPatch>),
+}
+
+/// Re-publishes the reconstructed [`OrderBook`]s of a [`Streams`](super::Streams) to many
+/// subscribers as incremental [`BookPatch`]es, rather than sending each subscriber the full
+/// [`OrderBook`] on every update.
+///
+/// The first [`BookMessage`]s a new subscriber receives are a [`BookMessage::Snapshot`] of every
+/// known [`OrderBook`], followed by a [`BookMessage::Patch`] for each published update.
+///
+/// ### Notes
+/// - Updates that leave the [`OrderBook`] [`Level`](crate::subscription::book::Level)s unchanged
+///   are not distributed.
+/// - Subscribers that have dropped their receiver are removed on the next publish.
+#[derive(Debug, Default)]
+pub struct BookPublisher {
+    books: HashMap<(Exchange, Arc<Instrument>), MarketEvent<OrderBook>>,
+    subscribers: Vec<mpsc::UnboundedSender<BookMessage>>,
+}
+
+impl BookPublisher {
+    /// Construct a new [`Self`] with no known [`OrderBook`]s or subscribers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a new subscriber, immediately sending it a [`BookMessage::Snapshot`] of every
+    /// known [`OrderBook`].
+    pub fn subscribe(&mut self) -> mpsc::UnboundedReceiver<BookMessage> {
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        self.books.values().for_each(|book| {
+            let _ = tx.send(BookMessage::Snapshot(book.clone()));
+        });
+
+        self.subscribers.push(tx);
+        rx
+    }
+
+    /// Number of currently registered subscribers.
+    pub fn subscribers(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    /// Publish the latest reconstructed [`OrderBook`], distributing the [`BookMessage`] that
+    /// brings every subscriber up to date with it.
+    pub fn publish(&mut self, event: MarketEvent<OrderBook>) {
+        let key = (event.exchange.clone(), event.instrument.clone());
+
+        let message = match self.books.get(&key) {
+            Some(previous) => {
+                let patch = previous.kind.diff(&event.kind);
+                if patch.is_empty() {
+                    return;
+                }
+
+                BookMessage::Patch(MarketEvent {
+                    exchange_time: event.exchange_time,
+                    received_time: event.received_time,
+                    exchange: event.exchange.clone(),
+                    instrument: event.instrument.clone(),
+                    kind: patch,
+                })
+            }
+            None => BookMessage::Snapshot(event.clone()),
+        };
+
+        self.books.insert(key, event);
+        self.subscribers
+            .retain(|subscriber| subscriber.send(message.clone()).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{book_event, millis};
+
+    #[test]
+    fn test_late_subscriber_reconstructs_book_from_snapshot_and_patches() {
+        let mut publisher = BookPublisher::new();
+        let mut early_rx = publisher.subscribe();
+
+        // Book updates published before the late subscriber joins
+        publisher.publish(book_event(
+            millis(1),
+            vec![(99.0, 1.0), (98.0, 2.0)],
+            vec![(101.0, 1.0)],
+        ));
+        publisher.publish(book_event(
+            millis(2),
+            vec![(99.0, 3.0), (98.0, 2.0)],
+            vec![(101.0, 1.0)],
+        ));
+
+        let mut late_rx = publisher.subscribe();
+        assert_eq!(publisher.subscribers(), 2);
+
+        // Book updates published after the late subscriber joins
+        let updates = vec![
+            // Unchanged levels are not distributed
+            book_event(
+                millis(3),
+                vec![(99.0, 3.0), (98.0, 2.0)],
+                vec![(101.0, 1.0)],
+            ),
+            // New best bid, removed ask level & new ask levels
+            book_event(
+                millis(4),
+                vec![(99.5, 1.0), (99.0, 3.0), (98.0, 2.0)],
+                vec![(100.5, 2.0), (102.0, 4.0)],
+            ),
+            // Removed bid level & amended ask
+            book_event(
+                millis(5),
+                vec![(99.5, 1.0), (98.0, 2.0)],
+                vec![(100.5, 1.5), (102.0, 4.0)],
+            ),
+        ];
+        let expected = updates.last().unwrap().kind.clone();
+        updates
+            .into_iter()
+            .for_each(|update| publisher.publish(update));
+
+        // Late subscriber first receives a snapshot of the latest book
+        let mut actual = match late_rx.try_recv().unwrap() {
+            BookMessage::Snapshot(snapshot) => {
+                assert_eq!(snapshot.exchange_time.timestamp_millis(), 2);
+                snapshot.kind
+            }
+            message => panic!("expected BookMessage::Snapshot, got: {message:?}"),
+        };
+
+        // Followed by minimal patches of only the changed levels, one per changed update
+        let mut patch_sizes = vec![];
+        while let Ok(message) = late_rx.try_recv() {
+            match message {
+                BookMessage::Patch(patch) => {
+                    patch_sizes.push(patch.kind.bids.len() + patch.kind.asks.len());
+                    actual.apply(patch.kind);
+                }
+                message => panic!("expected BookMessage::Patch, got: {message:?}"),
+            }
+        }
+        assert_eq!(patch_sizes, vec![4, 2]);
+        assert_eq!(actual, expected);
+
+        // Early subscriber reconstructs the same book from the start
+        let mut early = match early_rx.try_recv().unwrap() {
+            BookMessage::Snapshot(snapshot) => snapshot.kind,
+            message => panic!("expected BookMessage::Snapshot, got: {message:?}"),
+        };
+        while let Ok(BookMessage::Patch(patch)) = early_rx.try_recv() {
+            early.apply(patch.kind);
+        }
+        assert_eq!(early, expected);
+
+        // Dropped subscribers are removed on the next publish
+        drop(early_rx);
+        publisher.publish(book_event(millis(6), vec![(99.5, 2.0)], vec![(100.5, 1.5)]));
+        assert_eq!(publisher.subscribers(), 1);
+    }
+}
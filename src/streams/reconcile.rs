@@ -0,0 +1,141 @@
+use super::builder::validate;
+use crate::{
+    error::DataError,
+    exchange::StreamSelector,
+    subscription::{SubKind, Subscription},
+};
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::sync::mpsc;
+
+/// Difference between the current and target [`Subscription`] universe of a
+/// [`consume`](super::consumer::consume) loop.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SubscriptionDiff<Exchange, Kind> {
+    /// [`Subscription`]s present in the target universe, but not the current universe.
+    pub added: Vec<Subscription<Exchange, Kind>>,
+    /// [`Subscription`]s present in the current universe, but not the target universe.
+    pub removed: Vec<Subscription<Exchange, Kind>>,
+}
+
+impl<Exchange, Kind> SubscriptionDiff<Exchange, Kind>
+where
+    Subscription<Exchange, Kind>: Clone + PartialEq,
+{
+    /// Determine the [`Subscription`]s that must be added & removed to converge the `current`
+    /// universe on the `target` universe.
+    pub fn between(
+        current: &[Subscription<Exchange, Kind>],
+        target: &[Subscription<Exchange, Kind>],
+    ) -> Self {
+        let added = target
+            .iter()
+            .filter(|subscription| !current.contains(subscription))
+            .cloned()
+            .collect();
+
+        let removed = current
+            .iter()
+            .filter(|subscription| !target.contains(subscription))
+            .cloned()
+            .collect();
+
+        Self { added, removed }
+    }
+
+    /// Determine if the current universe already equals the target universe.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Handle used to hot-reload the [`Subscription`] universe of a running
+/// [`consume`](super::consumer::consume) loop without restarting the application.
+///
+/// Constructed via
+/// [`StreamBuilder::subscribe_reconcilable`](super::builder::StreamBuilder::subscribe_reconcilable).
+///
+/// ### Notes
+/// A non-empty [`SubscriptionDiff`] is applied over the open connection by sending the
+/// incremental subscribe & unsubscribe requests (see
+/// [`MarketStream::update_subscriptions`](crate::MarketStream::update_subscriptions)). If the
+/// exchange does not support unsubscribing, or an added
+/// [`OrderBook`](crate::subscription::book::OrderBook) requires a snapshot, the
+/// [`MarketStream`](crate::MarketStream) is instead re-initialised with the target universe.
+#[derive(Debug)]
+pub struct Reconciler<Exchange, Kind> {
+    current: Arc<Mutex<Vec<Subscription<Exchange, Kind>>>>,
+    universe_tx: mpsc::UnboundedSender<Vec<Subscription<Exchange, Kind>>>,
+}
+
+impl<Exchange, Kind> Clone for Reconciler<Exchange, Kind> {
+    fn clone(&self) -> Self {
+        Self {
+            current: Arc::clone(&self.current),
+            universe_tx: self.universe_tx.clone(),
+        }
+    }
+}
+
+impl<Exchange, Kind> Reconciler<Exchange, Kind>
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: Ord,
+{
+    /// Construct a new [`Self`] for a [`consume`](super::consumer::consume) loop actioning the
+    /// provided `current` [`Subscription`] universe, returning the receiver the loop uses to
+    /// consume target universes.
+    pub fn new(
+        mut current: Vec<Subscription<Exchange, Kind>>,
+    ) -> (
+        Self,
+        mpsc::UnboundedReceiver<Vec<Subscription<Exchange, Kind>>>,
+    ) {
+        current.sort();
+        current.dedup();
+
+        let (universe_tx, universe_rx) = mpsc::unbounded_channel();
+        let reconciler = Self {
+            current: Arc::new(Mutex::new(current)),
+            universe_tx,
+        };
+
+        (reconciler, universe_rx)
+    }
+
+    /// Current [`Subscription`] universe.
+    pub fn current(&self) -> Vec<Subscription<Exchange, Kind>> {
+        self.current
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Converge the [`Subscription`] universe of the [`consume`](super::consumer::consume) loop
+    /// on the provided target universe, returning the applied [`SubscriptionDiff`].
+    ///
+    /// An empty [`SubscriptionDiff`] is a no-op, so the connection is left untouched.
+    pub fn reconcile(
+        &self,
+        mut target: Vec<Subscription<Exchange, Kind>>,
+    ) -> Result<SubscriptionDiff<Exchange, Kind>, DataError> {
+        validate(&target)?;
+        target.sort();
+        target.dedup();
+
+        let mut current = self.current.lock().unwrap_or_else(PoisonError::into_inner);
+        let diff = SubscriptionDiff::between(&current, &target);
+        if diff.is_empty() {
+            return Ok(diff);
+        }
+
+        self.universe_tx
+            .send(target.clone())
+            .map_err(|_| DataError::ConsumerTerminated {
+                exchange: Exchange::ID,
+            })?;
+
+        *current = target;
+        Ok(diff)
+    }
+}
@@ -0,0 +1,368 @@
+use crate::{codec::Codec, error::DataError, event::MarketEvent, streams::sink::csv};
+use chrono::{DateTime, Utc};
+use futures::{stream::BoxStream, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use std::{
+    ffi::OsStr,
+    fs::File,
+    io::{BufRead, BufReader, Read},
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::sync::mpsc;
+
+/// [`Stream`] that re-emits recorded [`MarketEvent<T>`]s (eg/ for backtesting without touching
+/// network code), through the same interface as a live stream.
+///
+/// Events are read from JSON lines, one [`MarketEvent<T>`] per line, from the length-delimited
+/// [`Codec`] frames recorded by a [`FrameSink`](crate::streams::sink::frame::FrameSink), or from
+/// the CSV files recorded by a [`CsvSink`](csv::CsvSink), and yielded in the order they were
+/// recorded.
+///
+/// ### Notes
+/// - Recordings are read & decoded on a blocking thread once the [`Self`] is first polled, so the
+///   async runtime is never blocked by file I/O.
+/// - Emission is paced to match the original `exchange_time` deltas, divided by the `speed`
+///   multiplier (eg/ `2.0` replays twice as fast). A `speed` of `0.0` emits as fast as possible.
+/// - Events whose `exchange_time` is earlier than the previous event are emitted immediately,
+///   rather than re-ordered.
+/// - Malformed lines are yielded as a [`DataError::Replay`] with their 1-indexed line number,
+///   and replay continues with the next line. Empty lines are skipped.
+/// - Malformed CSV rows are yielded as a [`DataError::Replay`] with the 1-indexed line number
+///   they start on, and replay continues with the next row.
+/// - Malformed frames are yielded as a [`DataError::Replay`] with their 1-indexed frame number,
+///   and end the replay, since the boundary of the following frame is unknown.
+pub struct ReplayStream<T> {
+    inner: BoxStream<'static, Result<MarketEvent<T>, DataError>>,
+}
+
+impl<T> ReplayStream<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    /// Number of decoded events buffered ahead of the paced emission.
+    const BUFFER: usize = 1_024;
+
+    /// Construct a new [`Self`] that replays the JSON lines of the provided reader.
+    pub fn new<R>(reader: R, speed: f64) -> Self
+    where
+        R: BufRead + Send + 'static,
+    {
+        let records = reader.lines().enumerate().filter_map(|(index, line)| {
+            let line_number = index + 1;
+            match line {
+                Ok(text) if text.trim().is_empty() => None,
+                Ok(text) => Some(
+                    serde_json::from_str(&text).map_err(|error| DataError::Replay {
+                        line: line_number,
+                        error: error.to_string(),
+                    }),
+                ),
+                Err(error) => Some(Err(DataError::Replay {
+                    line: line_number,
+                    error: error.to_string(),
+                })),
+            }
+        });
+
+        Self::from_records(records, speed)
+    }
+
+    /// Construct a new [`Self`] that replays the length-delimited frames of the provided reader,
+    /// decoding each frame with the provided [`Codec`].
+    pub fn from_frames<R>(mut reader: R, codec: Codec, speed: f64) -> Self
+    where
+        R: Read + Send + 'static,
+    {
+        let mut frame = 0;
+        let mut corrupt = false;
+        let records = std::iter::from_fn(move || {
+            if corrupt {
+                return None;
+            }
+
+            frame += 1;
+            match codec.read_frame(&mut reader) {
+                Ok(event) => event.map(Ok),
+                Err(error) => {
+                    corrupt = true;
+                    Some(Err(DataError::Replay {
+                        line: frame,
+                        error: error.to_string(),
+                    }))
+                }
+            }
+        });
+
+        Self::from_records(records, speed)
+    }
+
+    /// Construct a new [`Self`] that replays the CSV rows of the provided reader, as recorded by
+    /// a [`CsvSink`](csv::CsvSink).
+    pub fn from_csv<R>(reader: R, speed: f64) -> Self
+    where
+        R: BufRead + Send + 'static,
+    {
+        Self::from_records(csv::read(reader), speed)
+    }
+
+    /// Construct a new [`Self`] that replays the recording at the provided path.
+    ///
+    /// Files with a "csv" extension are replayed as CSV, files with a [`Codec::MessagePack`] or
+    /// [`Codec::Bincode`] extension (see [`Codec::from_path`]) are replayed as frames, and any
+    /// other file as JSON lines.
+    pub fn from_path<P>(path: P, speed: f64) -> std::io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let reader = BufReader::new(File::open(path)?);
+
+        Ok(match Codec::from_path(path) {
+            None if path.extension() == Some(OsStr::new("csv")) => Self::from_csv(reader, speed),
+            None | Some(Codec::Json) => Self::new(reader, speed),
+            Some(codec) => Self::from_frames(reader, codec, speed),
+        })
+    }
+
+    /// Decode the records on a blocking thread, pacing them to match the original
+    /// `exchange_time` deltas divided by the `speed` multiplier.
+    fn from_records<I>(records: I, speed: f64) -> Self
+    where
+        I: Iterator<Item = Result<MarketEvent<T>, DataError>> + Send + 'static,
+    {
+        let pace = (speed.is_finite() && speed > 0.0).then_some(speed);
+        let (tx, rx) = mpsc::channel(Self::BUFFER);
+
+        let inner = futures::stream::unfold(
+            (Some((records, tx)), rx, None::<DateTime<Utc>>),
+            move |(mut reader, mut rx, mut previous)| async move {
+                // Lazily spawn the blocking reader, which stops once the ReplayStream is dropped
+                if let Some((records, tx)) = reader.take() {
+                    tokio::task::spawn_blocking(move || {
+                        for record in records {
+                            if tx.blocking_send(record).is_err() {
+                                break;
+                            }
+                        }
+                    });
+                }
+
+                let event = match rx.recv().await? {
+                    Ok(event) => event,
+                    Err(error) => return Some((Err(error), (reader, rx, previous))),
+                };
+
+                if let (Some(speed), Some(previous)) = (pace, previous) {
+                    if let Ok(delta) = (event.exchange_time - previous).to_std() {
+                        // A delta too large to represent (eg/ with a tiny speed) waits forever
+                        let delay = Duration::try_from_secs_f64(delta.as_secs_f64() / speed)
+                            .unwrap_or(Duration::MAX);
+                        tokio::time::sleep(delay).await;
+                    }
+                }
+                previous = Some(event.exchange_time);
+
+                Some((Ok(event), (reader, rx, previous)))
+            },
+        )
+        .boxed();
+
+        Self { inner }
+    }
+}
+
+impl<T> std::fmt::Debug for ReplayStream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReplayStream").finish_non_exhaustive()
+    }
+}
+
+impl<T> Stream for ReplayStream<T> {
+    type Item = Result<MarketEvent<T>, DataError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        streams::sink::csv::CsvSink,
+        subscription::{candle::Candle, trade::PublicTrade},
+    };
+    use barter_integration::model::{
+        instrument::{
+            kind::{FutureContract, InstrumentKind},
+            Instrument,
+        },
+        Exchange, Side,
+    };
+    use futures::SinkExt;
+    use std::{fs, io::Cursor, time::Instant};
+
+    fn trade(secs: i64, id: &str) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange: Exchange::from("binance_spot"),
+            ..fixtures::trade(fixtures::secs(secs), id, 100.0, 1.0, Side::Buy)
+        }
+    }
+
+    /// JSON lines recording of the provided trades, with a malformed line after the first.
+    fn recording(trades: &[MarketEvent<PublicTrade>]) -> Cursor<Vec<u8>> {
+        let mut lines = trades
+            .iter()
+            .map(|trade| serde_json::to_string(trade).unwrap())
+            .collect::<Vec<_>>();
+        lines.insert(1, r#"{"exchange_time": "malformed"}"#.to_string());
+        lines.insert(2, String::new());
+        Cursor::new(lines.join("\n").into_bytes())
+    }
+
+    #[tokio::test]
+    async fn test_replay_stream() {
+        // Trades recorded 1s apart
+        let trades = vec![trade(0, "1"), trade(1, "2"), trade(2, "3")];
+
+        let start = Instant::now();
+        let actual = ReplayStream::<PublicTrade>::new(recording(&trades), 0.0)
+            .collect::<Vec<_>>()
+            .await;
+
+        // Replayed as fast as possible with a speed of 0.0
+        assert!(start.elapsed() < Duration::from_millis(500));
+
+        // Trades are replayed in recorded order, with the malformed line surfaced in between
+        assert_eq!(actual.len(), 4);
+        assert_eq!(actual[0].as_ref().unwrap(), &trades[0]);
+        assert!(matches!(actual[1], Err(DataError::Replay { line: 2, .. })));
+        assert_eq!(actual[2].as_ref().unwrap(), &trades[1]);
+        assert_eq!(actual[3].as_ref().unwrap(), &trades[2]);
+
+        // Replayed with the original 1s deltas divided by a speed of 20.0
+        let start = Instant::now();
+        let actual = ReplayStream::<PublicTrade>::new(recording(&trades), 20.0)
+            .filter_map(|result| async move { result.ok() })
+            .collect::<Vec<_>>()
+            .await;
+
+        assert!(start.elapsed() >= Duration::from_millis(100));
+        assert_eq!(actual, trades);
+    }
+
+    #[tokio::test]
+    async fn test_replay_stream_from_frames() {
+        let trades = vec![trade(0, "1"), trade(1, "2")];
+
+        for (index, codec) in [Codec::Json, Codec::MessagePack, Codec::Bincode]
+            .into_iter()
+            .enumerate()
+        {
+            let mut buffer = Vec::new();
+            for trade in &trades {
+                codec.write_frame(&mut buffer, trade).unwrap();
+            }
+
+            // Truncated trailing frame is surfaced, and ends the replay
+            buffer.extend_from_slice(&8u32.to_le_bytes());
+            buffer.push(0);
+
+            let actual = ReplayStream::<PublicTrade>::from_frames(Cursor::new(buffer), codec, 0.0)
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(actual.len(), 3, "TC{index} failed for {codec}");
+            assert_eq!(actual[0].as_ref().unwrap(), &trades[0], "TC{index} failed");
+            assert_eq!(actual[1].as_ref().unwrap(), &trades[1], "TC{index} failed");
+            assert!(
+                matches!(actual[2], Err(DataError::Replay { line: 3, .. })),
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_stream_from_csv() {
+        let dir =
+            std::env::temp_dir().join(format!("barter-data-replay-csv-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let future = InstrumentKind::Future(FutureContract {
+            expiry: DateTime::<Utc>::from_timestamp_millis(1_703_836_800_000).unwrap(),
+        });
+        let trades = [trade(0, "1"), trade(1, "2, \"quoted\"")]
+            .map(|trade| MarketEvent {
+                instrument: Instrument::from(("btc", "usd", future)).into(),
+                ..trade
+            })
+            .to_vec();
+
+        let time = DateTime::<Utc>::from_timestamp(2, 0).unwrap();
+        let candle = MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from("binance_spot"),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)).into(),
+            kind: Candle {
+                close_time: time,
+                open: 100.0,
+                high: 110.0,
+                low: 90.0,
+                close: 105.0,
+                volume: 10.0,
+                quote_volume: None,
+                trade_count: 3,
+                is_final: true,
+            },
+        };
+
+        let mut sink = CsvSink::new(&dir);
+        for trade in trades.clone() {
+            sink.feed(trade).await.unwrap();
+        }
+        sink.feed(candle.clone()).await.unwrap();
+        SinkExt::<MarketEvent<Candle>>::close(&mut sink)
+            .await
+            .unwrap();
+
+        // TC0: recorded trades are replayed with their nested instrument & quoted cells intact
+        let actual =
+            ReplayStream::<PublicTrade>::from_path(dir.join("binance_spot_trades_0.csv"), 0.0)
+                .unwrap()
+                .collect::<Vec<_>>()
+                .await;
+        let actual = actual.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(actual, trades, "TC0 failed");
+
+        // TC1: empty cells are replayed as None
+        let actual = ReplayStream::<Candle>::from_path(dir.join("binance_spot_candles_0.csv"), 0.0)
+            .unwrap()
+            .collect::<Vec<_>>()
+            .await;
+        let actual = actual.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(actual, vec![candle], "TC1 failed");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_replay_stream_with_tiny_speed_does_not_panic() {
+        let trades = vec![trade(0, "1"), trade(1_000_000, "2")];
+        let mut stream = ReplayStream::<PublicTrade>::new(recording(&trades), f64::MIN_POSITIVE);
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), trades[0]);
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(DataError::Replay { .. }))
+        ));
+
+        // Delay of the next trade overflows a Duration, so waits indefinitely
+        let next = tokio::time::timeout(Duration::from_millis(50), stream.next()).await;
+        assert!(next.is_err());
+    }
+}
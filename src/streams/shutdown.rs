@@ -0,0 +1,99 @@
+use std::{sync::Arc, time::Duration};
+use tokio::sync::watch;
+
+/// Maximum duration a [`consume`](super::consumer::consume) loop waits for the exchange to
+/// acknowledge the CloseFrame of a gracefully shut down connection, whilst draining any in-flight
+/// messages.
+pub const SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Handle used to gracefully shut down the [`consume`](super::consumer::consume) loops of the
+/// [`Streams`](super::Streams), rather than dropping their connections mid-stream.
+///
+/// Once triggered, each consumer loop:
+/// 1. Optionally sends the exchange unsubscribe requests of its
+///    [`Subscription`](crate::subscription::Subscription)s (see [`Self::unsubscribe_on_close`]).
+/// 2. Sends a WebSocket CloseFrame.
+/// 3. Drains & distributes in-flight messages until the exchange acknowledges the CloseFrame,
+///    or the [`SHUTDOWN_DRAIN_TIMEOUT`] elapses.
+/// 4. Stops without re-connecting, so the [`Streams`](super::Streams) receivers end cleanly.
+///
+/// ### Notes
+/// A [`Shutdown`] is shared by all of its clones, so triggering any clone shuts down every
+/// consumer loop configured with it (see
+/// [`StreamBuilder::shutdown`](super::builder::StreamBuilder::shutdown)).
+#[derive(Clone, Debug)]
+pub struct Shutdown {
+    triggered: Arc<watch::Sender<bool>>,
+    unsubscribe: bool,
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shutdown {
+    /// Construct a new [`Self`] that has not been triggered.
+    pub fn new() -> Self {
+        Self {
+            triggered: Arc::new(watch::channel(false).0),
+            unsubscribe: false,
+        }
+    }
+
+    /// Send the exchange unsubscribe requests of every
+    /// [`Subscription`](crate::subscription::Subscription) before the CloseFrame, for
+    /// exchanges that define
+    /// [`Connector::unsubscribe_requests`](crate::exchange::Connector::unsubscribe_requests).
+    pub fn unsubscribe_on_close(mut self, unsubscribe: bool) -> Self {
+        self.unsubscribe = unsubscribe;
+        self
+    }
+
+    /// Determine if the exchange unsubscribe requests should be sent before the CloseFrame.
+    pub fn unsubscribes(&self) -> bool {
+        self.unsubscribe
+    }
+
+    /// Gracefully shut down every consumer loop configured with [`Self`]. Triggering an already
+    /// triggered [`Self`] is a no-op.
+    pub fn trigger(&self) {
+        self.triggered.send_replace(true);
+    }
+
+    /// Determine if [`Self`] has been triggered.
+    pub fn is_triggered(&self) -> bool {
+        *self.triggered.borrow()
+    }
+
+    /// Wait until [`Self`] is triggered.
+    pub async fn triggered(&self) {
+        let mut triggered = self.triggered.subscribe();
+        // Sender is owned by Self, so this only returns once triggered
+        let _ = triggered.wait_for(|triggered| *triggered).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_shutdown_is_shared_by_clones() {
+        let shutdown = Shutdown::new();
+        let clone = shutdown.clone().unsubscribe_on_close(true);
+        assert!(!shutdown.unsubscribes());
+        assert!(clone.unsubscribes());
+
+        let waiter = tokio::spawn(async move { shutdown.triggered().await });
+        assert!(!clone.is_triggered());
+
+        clone.trigger();
+        assert!(clone.is_triggered());
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("triggering a clone did not trigger the Shutdown")
+            .unwrap();
+    }
+}
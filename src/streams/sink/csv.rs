@@ -0,0 +1,1042 @@
+use crate::{
+    error::DataError,
+    event::{DataKind, MarketEvent},
+    subscription::{
+        book::{OrderBook, OrderBookL1},
+        candle::{Candle, ContinuousCandle},
+        funding::FundingRate,
+        index::IndexPrice,
+        liquidation::Liquidation,
+        status::InstrumentStatus,
+        ticker::Ticker,
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::Exchange;
+use futures::Sink;
+use serde::{
+    de::{
+        value::{Error as CellError, MapDeserializer},
+        DeserializeOwned, EnumAccess, Error as _, IntoDeserializer, VariantAccess, Visitor,
+    },
+    Deserialize, Deserializer, Serialize,
+};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufWriter, ErrorKind, Write},
+    path::{Path, PathBuf},
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+/// Normalised data that can be recorded to disk by a [`CsvSink`].
+pub trait Recordable: Serialize {
+    /// Name of the normalised data kind (eg/ "trades"), used to partition the recorded files.
+    fn record_kind(&self) -> &'static str;
+
+    /// Serialise the normalised data into the value recorded as the data columns of each row.
+    fn record_value(&self) -> Result<Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+}
+
+impl Recordable for PublicTrade {
+    fn record_kind(&self) -> &'static str {
+        "trades"
+    }
+}
+
+impl Recordable for OrderBookL1 {
+    fn record_kind(&self) -> &'static str {
+        "order_books_l1"
+    }
+}
+
+impl Recordable for OrderBook {
+    fn record_kind(&self) -> &'static str {
+        "order_books_l2"
+    }
+}
+
+impl Recordable for Candle {
+    fn record_kind(&self) -> &'static str {
+        "candles"
+    }
+}
+
+impl Recordable for ContinuousCandle {
+    fn record_kind(&self) -> &'static str {
+        "continuous_candles"
+    }
+}
+
+impl Recordable for Liquidation {
+    fn record_kind(&self) -> &'static str {
+        "liquidations"
+    }
+}
+
+impl Recordable for InstrumentStatus {
+    fn record_kind(&self) -> &'static str {
+        "instrument_statuses"
+    }
+}
+
+impl Recordable for IndexPrice {
+    fn record_kind(&self) -> &'static str {
+        "index_prices"
+    }
+}
+
+impl Recordable for FundingRate {
+    fn record_kind(&self) -> &'static str {
+        "funding_rates"
+    }
+}
+
+impl Recordable for Ticker {
+    fn record_kind(&self) -> &'static str {
+        "tickers"
+    }
+}
+
+impl Recordable for DataKind {
+    fn record_kind(&self) -> &'static str {
+        match self {
+            DataKind::Trade(trade) => trade.record_kind(),
+            DataKind::OrderBookL1(book) => book.record_kind(),
+            DataKind::OrderBook(book) => book.record_kind(),
+            DataKind::Candle(candle) => candle.record_kind(),
+            DataKind::ContinuousCandle(candle) => candle.record_kind(),
+            DataKind::Liquidation(liquidation) => liquidation.record_kind(),
+            DataKind::InstrumentStatus(status) => status.record_kind(),
+            DataKind::IndexPrice(index) => index.record_kind(),
+            DataKind::FundingRate(funding) => funding.record_kind(),
+            DataKind::Ticker(ticker) => ticker.record_kind(),
+        }
+    }
+
+    fn record_value(&self) -> Result<Value, serde_json::Error> {
+        match self {
+            DataKind::Trade(trade) => trade.record_value(),
+            DataKind::OrderBookL1(book) => book.record_value(),
+            DataKind::OrderBook(book) => book.record_value(),
+            DataKind::Candle(candle) => candle.record_value(),
+            DataKind::ContinuousCandle(candle) => candle.record_value(),
+            DataKind::Liquidation(liquidation) => liquidation.record_value(),
+            DataKind::InstrumentStatus(status) => status.record_value(),
+            DataKind::IndexPrice(index) => index.record_value(),
+            DataKind::FundingRate(funding) => funding.record_value(),
+            DataKind::Ticker(ticker) => ticker.record_value(),
+        }
+    }
+}
+
+/// Recorded file of a [`CsvSink`] partition.
+type FileKey = (Exchange, &'static str);
+
+/// Flattened `(column, cell)` pairs of a recorded [`MarketEvent`].
+type Row = Vec<(String, String)>;
+
+/// [`Sink`] that records [`MarketEvent`]s to disk as CSV, in a format that can be replayed for
+/// backtesting by a [`ReplayStream::from_csv`](crate::streams::replay::ReplayStream::from_csv).
+///
+/// One file is written per exchange & [`Recordable::record_kind`] (eg/
+/// "binance_spot_trades_0.csv"), so each kind of a [`MarketEvent<DataKind>`] stream is recorded
+/// to a different file.
+///
+/// Each file starts with a header row derived from the normalised schema: `exchange_time`,
+/// `received_time`, `exchange`, the `instrument` fields, followed by the fields of the
+/// normalised data. Nested fields are flattened into dot separated columns (eg/
+/// `best_bid.price`), and sequences (eg/ [`OrderBook`] levels) are recorded as a JSON cell.
+///
+/// ### Notes
+/// - Rows are buffered in memory, and written to disk once `batch_size` rows are buffered, or
+///   the [`Sink`] is flushed or closed.
+/// - Files are rotated once they reach the configured maximum rows or age, incrementing the
+///   file index. Age is checked as rows are written, so quiet files are rotated lazily.
+/// - Existing files are never truncated, so a restarted [`CsvSink`] continues each partition
+///   from the next free file index.
+/// - Columns of each file are fixed by its first row. Writes are blocking, so large batches
+///   should be preferred on busy runtimes.
+/// - The variant of a [`MarketEvent<DataKind>`] is recorded in the file name rather than a
+///   column, so each file must be replayed as its concrete kind (eg/ [`PublicTrade`]).
+#[derive(Debug)]
+pub struct CsvSink {
+    dir: PathBuf,
+    batch_size: usize,
+    max_rows: Option<u64>,
+    max_age: Option<Duration>,
+    pending: Vec<(FileKey, Row)>,
+    files: HashMap<FileKey, CsvFile>,
+}
+
+impl CsvSink {
+    /// Default number of buffered rows that triggers a write to disk.
+    pub const DEFAULT_BATCH_SIZE: usize = 1_000;
+
+    /// Construct a new [`Self`] that records to files in the provided directory, which is created
+    /// if it does not exist. Files are not rotated unless configured otherwise.
+    pub fn new<P>(dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            dir: dir.into(),
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            max_rows: None,
+            max_age: None,
+            pending: Vec::new(),
+            files: HashMap::new(),
+        }
+    }
+
+    /// Write buffered rows to disk once the provided number of rows are buffered.
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self {
+            batch_size: batch_size.max(1),
+            ..self
+        }
+    }
+
+    /// Rotate each file once it contains the provided number of rows.
+    pub fn with_max_rows(self, max_rows: u64) -> Self {
+        Self {
+            max_rows: Some(max_rows.max(1)),
+            ..self
+        }
+    }
+
+    /// Rotate each file once it has been open for the provided [`Duration`].
+    pub fn with_max_age(self, max_age: Duration) -> Self {
+        Self {
+            max_age: Some(max_age),
+            ..self
+        }
+    }
+
+    fn buffer<T>(&mut self, event: &MarketEvent<T>) -> Result<(), DataError>
+    where
+        T: Recordable,
+    {
+        let row = row(event).map_err(|error| DataError::Record {
+            error: error.to_string(),
+        })?;
+
+        self.pending
+            .push(((event.exchange.clone(), event.kind.record_kind()), row));
+
+        Ok(())
+    }
+
+    /// Write every buffered row to its file, and flush every file to disk.
+    fn write_pending(&mut self) -> Result<(), DataError> {
+        for (key, row) in std::mem::take(&mut self.pending) {
+            let file = match self.files.remove(&key) {
+                Some(file) if !file.expired(self.max_rows, self.max_age) => file,
+                Some(file) => file.rotate(&self.dir, &key, &row)?,
+                None => CsvFile::create(&self.dir, &key, 0, &row)?,
+            };
+
+            self.files.entry(key).or_insert(file).write(&row)?;
+        }
+
+        self.files.values_mut().try_for_each(CsvFile::flush)
+    }
+}
+
+impl<T> Sink<MarketEvent<T>> for CsvSink
+where
+    T: Recordable,
+{
+    type Error = DataError;
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let sink = self.get_mut();
+        if sink.pending.len() >= sink.batch_size {
+            return Poll::Ready(sink.write_pending());
+        }
+
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, event: MarketEvent<T>) -> Result<(), Self::Error> {
+        self.get_mut().buffer(&event)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(self.get_mut().write_pending())
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        <Self as Sink<MarketEvent<T>>>::poll_flush(self, cx)
+    }
+}
+
+/// Open CSV file of a [`CsvSink`] partition.
+#[derive(Debug)]
+struct CsvFile {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    index: u64,
+    columns: Vec<String>,
+    rows: u64,
+    opened: Instant,
+}
+
+impl CsvFile {
+    /// Create the file with the first free rotation index from the provided index, writing a
+    /// header row derived from the columns of its first row.
+    fn create(dir: &Path, key: &FileKey, index: u64, first: &Row) -> Result<Self, DataError> {
+        let (exchange, kind) = key;
+        fs::create_dir_all(dir).map_err(|error| record_error(dir, error))?;
+
+        let (path, index, file) = (index..)
+            .find_map(|index| {
+                let path = dir.join(format!("{exchange}_{kind}_{index}.csv"));
+                match OpenOptions::new().write(true).create_new(true).open(&path) {
+                    Err(error) if error.kind() == ErrorKind::AlreadyExists => None,
+                    result => Some(
+                        result
+                            .map(|file| (path.clone(), index, file))
+                            .map_err(|error| record_error(&path, error)),
+                    ),
+                }
+            })
+            .expect("unbounded file index range")?;
+
+        let mut file = Self {
+            path,
+            writer: BufWriter::new(file),
+            index,
+            columns: first.iter().map(|(column, _)| column.clone()).collect(),
+            rows: 0,
+            opened: Instant::now(),
+        };
+
+        let header = file.columns.clone();
+        file.write_line(&header.iter().map(String::as_str).collect::<Vec<_>>())?;
+
+        Ok(file)
+    }
+
+    /// Determines if the file has reached the maximum rows or age, and must be rotated.
+    fn expired(&self, max_rows: Option<u64>, max_age: Option<Duration>) -> bool {
+        max_rows.is_some_and(|max_rows| self.rows >= max_rows)
+            || max_age.is_some_and(|max_age| self.opened.elapsed() >= max_age)
+    }
+
+    /// Flush the file, and create the next file of the partition.
+    fn rotate(mut self, dir: &Path, key: &FileKey, first: &Row) -> Result<Self, DataError> {
+        self.flush()?;
+        Self::create(dir, key, self.index + 1, first)
+    }
+
+    fn write(&mut self, row: &Row) -> Result<(), DataError> {
+        let cells = self
+            .columns
+            .iter()
+            .map(|column| {
+                row.iter()
+                    .find_map(|(key, cell)| (key == column).then_some(cell.as_str()))
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+
+        self.write_line(&cells)?;
+        self.rows += 1;
+        Ok(())
+    }
+
+    fn write_line(&mut self, cells: &[&str]) -> Result<(), DataError> {
+        let line = cells.iter().map(|cell| escape(cell)).collect::<Vec<_>>();
+
+        writeln!(self.writer, "{}", line.join(",")).map_err(|error| record_error(&self.path, error))
+    }
+
+    fn flush(&mut self) -> Result<(), DataError> {
+        self.writer
+            .flush()
+            .map_err(|error| record_error(&self.path, error))
+    }
+}
+
+/// Flatten the [`MarketEvent`] into the `(column, cell)` pairs of a CSV row.
+fn row<T>(event: &MarketEvent<T>) -> Result<Row, serde_json::Error>
+where
+    T: Recordable,
+{
+    let mut row = Row::new();
+    flatten(
+        "exchange_time",
+        serde_json::to_value(event.exchange_time)?,
+        &mut row,
+    );
+    flatten(
+        "received_time",
+        serde_json::to_value(event.received_time)?,
+        &mut row,
+    );
+    flatten("exchange", serde_json::to_value(&event.exchange)?, &mut row);
+    flatten(
+        "instrument",
+        serde_json::to_value(&*event.instrument)?,
+        &mut row,
+    );
+
+    match event.kind.record_value()? {
+        Value::Object(fields) => fields
+            .into_iter()
+            .for_each(|(column, value)| flatten(&column, value, &mut row)),
+        value => flatten("kind", value, &mut row),
+    }
+
+    Ok(row)
+}
+
+/// Flatten the value into `(column, cell)` pairs, using dot separated columns for the fields of
+/// nested objects.
+fn flatten(column: &str, value: Value, row: &mut Row) {
+    match value {
+        Value::Object(fields) => fields.into_iter().for_each(|(field, value)| {
+            flatten(&format!("{column}.{field}"), value, row);
+        }),
+        Value::Null => row.push((column.to_string(), String::new())),
+        Value::String(cell) => row.push((column.to_string(), cell)),
+        value => row.push((column.to_string(), value.to_string())),
+    }
+}
+
+/// Read back the [`MarketEvent`]s of a file recorded by a [`CsvSink`], in recorded order.
+///
+/// Malformed rows are yielded as a [`DataError::Replay`] with the 1-indexed line number they
+/// start on, and reading continues with the next row.
+pub(crate) fn read<R, T>(reader: R) -> impl Iterator<Item = Result<MarketEvent<T>, DataError>>
+where
+    R: BufRead,
+    T: DeserializeOwned,
+{
+    let mut records = records(reader);
+    let mut columns = None::<Vec<String>>;
+
+    std::iter::from_fn(move || loop {
+        let (line, cells) = match records.next()? {
+            Ok(record) => record,
+            Err(error) => return Some(Err(error)),
+        };
+
+        let Some(columns) = &columns else {
+            columns = Some(cells);
+            continue;
+        };
+
+        let node = event_node(columns, cells);
+        return Some(
+            MarketEvent::<T>::deserialize(&node).map_err(|error| DataError::Replay {
+                line,
+                error: error.to_string(),
+            }),
+        );
+    })
+}
+
+/// Parse the CSV records of the reader, yielding each with the 1-indexed line number it
+/// starts on. Quoted cells may span multiple lines.
+fn records<R>(mut reader: R) -> impl Iterator<Item = Result<(usize, Vec<String>), DataError>>
+where
+    R: BufRead,
+{
+    let mut line = 0;
+
+    std::iter::from_fn(move || {
+        let mut record = String::new();
+        let start = line + 1;
+
+        loop {
+            let mut text = String::new();
+            match reader.read_line(&mut text) {
+                Ok(0) if record.is_empty() => return None,
+                Ok(0) => {
+                    return Some(Err(DataError::Replay {
+                        line: start,
+                        error: "unterminated quoted cell".to_string(),
+                    }))
+                }
+                Ok(_) => line += 1,
+                Err(error) => {
+                    return Some(Err(DataError::Replay {
+                        line: start,
+                        error: error.to_string(),
+                    }))
+                }
+            }
+
+            record.push_str(&text);
+            if let Some(cells) = unescape(record.trim_end_matches(['\n', '\r'])) {
+                return Some(Ok((start, cells)));
+            }
+        }
+    })
+    .filter(|record| !matches!(record, Ok((_, cells)) if cells == &[String::new()]))
+}
+
+/// Split the CSV record into unquoted cells, returning `None` if a quoted cell is unterminated.
+fn unescape(record: &str) -> Option<Vec<String>> {
+    let mut cells = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = record.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        let cell = cells.last_mut().expect("cells is non-empty");
+        match (char, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                cell.push('"');
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => cells.push(String::new()),
+            (char, _) => cell.push(char),
+        }
+    }
+
+    (!quoted).then_some(cells)
+}
+
+/// Columns of a recorded row that belong to the [`MarketEvent`] rather than its `kind`.
+const EVENT_COLUMNS: [&str; 4] = ["exchange_time", "received_time", "exchange", "instrument"];
+
+/// Recorded row, un-flattened back into the nested fields of a [`MarketEvent`].
+#[derive(Debug)]
+enum Node {
+    Cell(String),
+    Fields(Vec<(String, Node)>),
+}
+
+impl Node {
+    fn insert(&mut self, path: &[&str], cell: String) {
+        let (Node::Fields(fields), [field, rest @ ..]) = (self, path) else {
+            return;
+        };
+
+        let position = fields.iter().position(|(key, _)| key == field);
+        match (position, rest.is_empty()) {
+            (_, true) => fields.push((field.to_string(), Node::Cell(cell))),
+            (Some(position), false) => fields[position].1.insert(rest, cell),
+            (None, false) => {
+                let mut node = Node::Fields(Vec::new());
+                node.insert(rest, cell);
+                fields.push((field.to_string(), node));
+            }
+        }
+    }
+
+    fn cell(&self) -> Result<&str, CellError> {
+        match self {
+            Node::Cell(cell) => Ok(cell),
+            Node::Fields(_) => Err(CellError::custom("expected a cell, found nested fields")),
+        }
+    }
+
+    fn parse<T>(&self) -> Result<T, CellError>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        let cell = self.cell()?;
+        cell.parse()
+            .map_err(|error| CellError::custom(format!("invalid cell {cell:?}: {error}")))
+    }
+
+    /// Parse the cell as a JSON value (eg/ a recorded sequence).
+    fn json(&self) -> Result<Value, CellError> {
+        serde_json::from_str(self.cell()?).map_err(CellError::custom)
+    }
+}
+
+/// Un-flatten the cells of a recorded row into a [`Node`] of the [`MarketEvent`] fields, nesting
+/// the normalised data columns under its `kind`.
+fn event_node(columns: &[String], cells: Vec<String>) -> Node {
+    let mut event = Node::Fields(Vec::new());
+    let mut kind = Node::Fields(Vec::new());
+
+    for (column, cell) in columns.iter().zip(cells) {
+        let path = column.split('.').collect::<Vec<_>>();
+        match path.as_slice() {
+            ["kind"] => kind = Node::Cell(cell),
+            [field, ..] if EVENT_COLUMNS.contains(field) => event.insert(&path, cell),
+            _ => kind.insert(&path, cell),
+        }
+    }
+
+    if let Node::Fields(fields) = &mut event {
+        fields.push(("kind".to_string(), kind));
+    }
+    event
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident),* $(,)?) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                visitor.$visit(self.parse()?)
+            }
+        )*
+    };
+}
+
+/// Lenient [`Deserializer`] of a recorded [`Node`], which parses each cell into the type
+/// requested by the target (eg/ an `id` of "1" is a `String`, but a `price` of "1" is an
+/// `f64`).
+impl<'de> Deserializer<'de> for &'de Node {
+    type Error = CellError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Node::Fields(fields) => visitor.visit_map(MapDeserializer::new(
+                fields.iter().map(|(key, node)| (key.as_str(), node)),
+            )),
+            Node::Cell(cell) if cell.is_empty() => visitor.visit_unit(),
+            Node::Cell(cell) => match serde_json::from_str::<Value>(cell) {
+                Ok(value) => value.deserialize_any(visitor).map_err(CellError::custom),
+                Err(_) => visitor.visit_str(cell),
+            },
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_borrowed_str(self.cell()?)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_borrowed_bytes(self.cell()?.as_bytes())
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Node::Cell(cell) if cell.is_empty() => visitor.visit_none(),
+            node => visitor.visit_some(node),
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.json()?
+            .deserialize_seq(visitor)
+            .map_err(CellError::custom)
+    }
+
+    fn deserialize_tuple<V>(self, _: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Node::Fields(_) => self.deserialize_any(visitor),
+            Node::Cell(_) => self
+                .json()?
+                .deserialize_map(visitor)
+                .map_err(CellError::custom),
+        }
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Node::Cell(cell) => visitor.visit_enum(cell.as_str().into_deserializer()),
+            Node::Fields(fields) => match fields.as_slice() {
+                [(variant, node)] => visitor.visit_enum(NodeVariant { variant, node }),
+                _ => Err(CellError::custom("expected a single enum variant field")),
+            },
+        }
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+}
+
+impl<'de> IntoDeserializer<'de, CellError> for &'de Node {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+/// Recorded enum variant with data (eg/ `instrument_kind.future.expiry`).
+struct NodeVariant<'de> {
+    variant: &'de str,
+    node: &'de Node,
+}
+
+impl<'de> EnumAccess<'de> for NodeVariant<'de> {
+    type Error = CellError;
+    type Variant = &'de Node;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: serde::de::DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+        Ok((variant, self.node))
+    }
+}
+
+impl<'de> VariantAccess<'de> for &'de Node {
+    type Error = CellError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: serde::de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, _: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+}
+
+/// Quote the CSV cell if it contains a delimiter, quote or line break.
+fn escape(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+fn record_error(path: &Path, error: std::io::Error) -> DataError {
+    DataError::Record {
+        error: format!("{path:?}: {error}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Side,
+    };
+    use chrono::{DateTime, Utc};
+    use futures::SinkExt;
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        DateTime::<Utc>::from_timestamp(secs, 0).unwrap()
+    }
+
+    fn event(secs: i64, kind: DataKind) -> MarketEvent<DataKind> {
+        MarketEvent {
+            exchange_time: time(secs),
+            received_time: time(secs),
+            exchange: Exchange::from("binance_spot"),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)).into(),
+            kind,
+        }
+    }
+
+    fn trade(secs: i64, id: &str, price: f64) -> MarketEvent<DataKind> {
+        event(
+            secs,
+            DataKind::Trade(PublicTrade {
+                id: id.to_string(),
+                price,
+                amount: 0.5,
+                side: Side::Buy,
+                conditions: vec![],
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_csv_sink_records_trades() {
+        let dir = std::env::temp_dir().join(format!("barter-data-csv-sink-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let mut sink = CsvSink::new(&dir).with_batch_size(2).with_max_rows(2);
+
+        let candle = event(
+            2,
+            DataKind::Candle(Candle {
+                close_time: time(60),
+                open: 100.0,
+                high: 110.0,
+                low: 90.0,
+                close: 105.0,
+                volume: 10.0,
+                quote_volume: None,
+                trade_count: 3,
+                is_final: true,
+            }),
+        );
+
+        let mut events = futures::stream::iter(vec![
+            Ok(trade(0, "1", 100.0)),
+            Ok(trade(1, "2", 101.0)),
+            Ok(candle),
+            Ok(trade(3, "3", 102.0)),
+        ]);
+        sink.send_all(&mut events).await.unwrap();
+        SinkExt::<MarketEvent<DataKind>>::close(&mut sink)
+            .await
+            .unwrap();
+
+        let header = "exchange_time,received_time,exchange,instrument.base,\
+            instrument.instrument_kind,instrument.quote,amount,conditions,id,price,side";
+
+        struct TestCase {
+            file: &'static str,
+            expected: Vec<String>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trades are recorded with a header row until the file reaches max rows
+                file: "binance_spot_trades_0.csv",
+                expected: vec![
+                    header.to_string(),
+                    "1970-01-01T00:00:00Z,1970-01-01T00:00:00Z,binance_spot,btc,spot,usdt,0.5,[],\
+                        1,100.0,Buy"
+                        .to_string(),
+                    "1970-01-01T00:00:01Z,1970-01-01T00:00:01Z,binance_spot,btc,spot,usdt,0.5,[],\
+                        2,101.0,Buy"
+                        .to_string(),
+                ],
+            },
+            TestCase {
+                // TC1: trades are rotated into the next file once max rows is reached
+                file: "binance_spot_trades_1.csv",
+                expected: vec![
+                    header.to_string(),
+                    "1970-01-01T00:00:03Z,1970-01-01T00:00:03Z,binance_spot,btc,spot,usdt,0.5,[],\
+                        3,102.0,Buy"
+                        .to_string(),
+                ],
+            },
+            TestCase {
+                // TC2: candles of the same stream are recorded to a different file
+                file: "binance_spot_candles_0.csv",
+                expected: vec![
+                    "exchange_time,received_time,exchange,instrument.base,\
+                        instrument.instrument_kind,instrument.quote,close,close_time,high,\
+                        is_final,low,open,quote_volume,trade_count,volume"
+                        .to_string(),
+                    "1970-01-01T00:00:02Z,1970-01-01T00:00:02Z,binance_spot,btc,spot,usdt,105.0,\
+                        1970-01-01T00:01:00Z,110.0,true,90.0,100.0,,3,10.0"
+                        .to_string(),
+                ],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = fs::read_to_string(dir.join(test.file))
+                .unwrap_or_else(|error| panic!("TC{index} failed: {error}"));
+            assert_eq!(
+                actual.lines().collect::<Vec<_>>(),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_csv_sink_restart_never_truncates_recordings() {
+        let dir = std::env::temp_dir().join(format!(
+            "barter-data-csv-sink-restart-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+
+        // Each CsvSink simulates a recorder run on the same directory
+        for (id, price) in [("1", 100.0), ("2", 101.0)] {
+            let mut sink = CsvSink::new(&dir);
+            sink.send(trade(0, id, price)).await.unwrap();
+            SinkExt::<MarketEvent<DataKind>>::close(&mut sink)
+                .await
+                .unwrap();
+        }
+
+        // Restarted sink continues from the next free index rather than truncating the first file
+        for (index, (file, cells)) in [
+            ("binance_spot_trades_0.csv", ",1,100.0,"),
+            ("binance_spot_trades_1.csv", ",2,101.0,"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let actual = fs::read_to_string(dir.join(file))
+                .unwrap_or_else(|error| panic!("TC{index} failed: {error}"));
+            assert_eq!(actual.lines().count(), 2, "TC{index} failed");
+            assert!(actual.contains(cells), "TC{index} failed");
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("Buy"), "Buy");
+        assert_eq!(escape("[1.0,2.0]"), "\"[1.0,2.0]\"");
+        assert_eq!(escape("a\"b"), "\"a\"\"b\"");
+    }
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(
+            unescape("Buy,\"[1.0,2.0]\",\"a\"\"b\","),
+            Some(vec![
+                "Buy".to_string(),
+                "[1.0,2.0]".to_string(),
+                "a\"b".to_string(),
+                String::new()
+            ])
+        );
+        assert_eq!(unescape("Buy,\"unterminated"), None);
+    }
+}
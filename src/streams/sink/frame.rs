@@ -0,0 +1,140 @@
+use crate::{codec::Codec, error::DataError, event::MarketEvent};
+use futures::Sink;
+use serde::Serialize;
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// [`Sink`] that records [`MarketEvent`]s to a writer (eg/ a file) as length-delimited
+/// [`Codec`] frames, which can be replayed by a
+/// [`ReplayStream::from_frames`](crate::streams::replay::ReplayStream::from_frames).
+///
+/// ### Notes
+/// - Frames are buffered in memory, and written to the underlying writer once the buffer is
+///   full, or the [`Sink`] is flushed or closed.
+/// - Writes are blocking, so a buffered writer to a local file should be preferred on busy
+///   runtimes.
+#[derive(Debug)]
+pub struct FrameSink<W>
+where
+    W: Write,
+{
+    codec: Codec,
+    writer: BufWriter<W>,
+}
+
+impl<W> FrameSink<W>
+where
+    W: Write,
+{
+    /// Construct a new [`Self`] that records frames encoded with the provided [`Codec`] to the
+    /// writer.
+    pub fn new(writer: W, codec: Codec) -> Self {
+        Self {
+            codec,
+            writer: BufWriter::new(writer),
+        }
+    }
+
+    /// [`Codec`] used to encode each recorded frame.
+    pub fn codec(&self) -> Codec {
+        self.codec
+    }
+}
+
+impl FrameSink<File> {
+    /// Construct a new [`Self`] that records to the file at the provided path, using the
+    /// [`Codec`] associated with its extension (see [`Codec::from_path`]), or [`Codec::Json`].
+    ///
+    /// Frames are appended, so an existing recording is never truncated.
+    pub fn create<P>(path: P) -> std::io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let codec = Codec::from_path(&path).unwrap_or_default();
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map(|file| Self::new(file, codec))
+    }
+}
+
+impl<T, W> Sink<MarketEvent<T>> for FrameSink<W>
+where
+    T: Serialize,
+    W: Write + Unpin,
+{
+    type Error = DataError;
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, event: MarketEvent<T>) -> Result<(), Self::Error> {
+        let sink = self.get_mut();
+        sink.codec.write_frame(&mut sink.writer, &event)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(
+            self.get_mut()
+                .writer
+                .flush()
+                .map_err(|error| DataError::Record {
+                    error: error.to_string(),
+                }),
+        )
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        <Self as Sink<MarketEvent<T>>>::poll_flush(self, cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, subscription::trade::PublicTrade};
+    use barter_integration::model::{Exchange, Side};
+    use futures::SinkExt;
+
+    fn trade(secs: i64, id: &str) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange: Exchange::from("binance_spot"),
+            ..fixtures::trade(fixtures::secs(secs), id, 100.0, 1.0, Side::Buy)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_frame_sink_records_frames() {
+        let trades = vec![trade(0, "1"), trade(1, "2")];
+
+        for (index, codec) in [Codec::Json, Codec::MessagePack, Codec::Bincode]
+            .into_iter()
+            .enumerate()
+        {
+            let mut sink = FrameSink::new(Vec::new(), codec);
+            for trade in trades.clone() {
+                sink.send(trade).await.unwrap();
+            }
+
+            let buffer = sink.writer.into_inner().unwrap();
+            let mut reader = buffer.as_slice();
+            let mut actual = Vec::new();
+            while let Some(event) = codec
+                .read_frame::<_, MarketEvent<PublicTrade>>(&mut reader)
+                .unwrap()
+            {
+                actual.push(event);
+            }
+
+            assert_eq!(actual, trades, "TC{index} failed for {codec}");
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use crate::error::DataError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, sync::Arc, time::Duration};
+use tokio::sync::mpsc;
+use tracing::{error, warn};
+
+/// [`CsvSink`](csv::CsvSink) [`futures::Sink`] used to record
+/// [`MarketEvent<T>`](crate::event::MarketEvent)s to disk in batches.
+pub mod csv;
+
+/// [`FrameSink`](frame::FrameSink) [`futures::Sink`] used to record
+/// [`MarketEvent<T>`](crate::event::MarketEvent)s as length-delimited
+/// [`Codec`](crate::codec::Codec) frames.
+pub mod frame;
+
+/// Error returned by an [`EventSink`] that failed to deliver an event.
+pub type SinkError = Box<dyn std::error::Error + Send + Sync>;
+
+/// User provided destination (eg/ a message bus, gRPC stream, or actor) that consumed events are
+/// delivered to by [`drive`].
+#[async_trait]
+pub trait EventSink<T>: Send + Sync {
+    /// Deliver the event, returning a [`SinkError`] if it could not be delivered.
+    async fn send(&self, event: T) -> Result<(), SinkError>;
+}
+
+#[async_trait]
+impl<T, Sink> EventSink<T> for Box<Sink>
+where
+    T: Send + 'static,
+    Sink: EventSink<T> + ?Sized,
+{
+    async fn send(&self, event: T) -> Result<(), SinkError> {
+        (**self).send(event).await
+    }
+}
+
+#[async_trait]
+impl<T, Sink> EventSink<T> for Arc<Sink>
+where
+    T: Send + 'static,
+    Sink: EventSink<T> + ?Sized,
+{
+    async fn send(&self, event: T) -> Result<(), SinkError> {
+        (**self).send(event).await
+    }
+}
+
+/// Action taken by [`drive`] when an [`EventSink`] fails to deliver an event.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SinkErrorPolicy {
+    /// Re-send the event up to `max_retries` times, waiting `backoff` between each attempt,
+    /// before dropping it.
+    Retry { max_retries: u32, backoff: Duration },
+    /// Drop the event and continue with the next event.
+    Drop,
+    /// Stop driving the stream, returning a [`DataError::Sink`].
+    Stop,
+}
+
+/// Drive every event received via the provided [`mpsc::UnboundedReceiver`] into the
+/// [`EventSink`] until the receiver is exhausted, handling delivery failures according to the
+/// [`SinkErrorPolicy`].
+///
+/// Returns a [`DataError::Sink`] if the [`SinkErrorPolicy::Stop`] policy was triggered.
+pub async fn drive<T, Sink>(
+    mut rx: mpsc::UnboundedReceiver<T>,
+    sink: Sink,
+    policy: SinkErrorPolicy,
+) -> Result<(), DataError>
+where
+    T: Clone + Debug + Send + 'static,
+    Sink: EventSink<T>,
+{
+    while let Some(event) = rx.recv().await {
+        let Err(error) = send_with_retries(&sink, &event, policy).await else {
+            continue;
+        };
+
+        if policy == SinkErrorPolicy::Stop {
+            error!(?event, %error, action = "stopping", "EventSink failed to deliver event");
+            return Err(DataError::Sink {
+                error: error.to_string(),
+            });
+        }
+
+        warn!(?event, %error, action = "dropping event", "EventSink failed to deliver event");
+    }
+
+    Ok(())
+}
+
+/// Send the event to the [`EventSink`], re-sending it after a failure if the
+/// [`SinkErrorPolicy`] is [`SinkErrorPolicy::Retry`].
+async fn send_with_retries<T, Sink>(
+    sink: &Sink,
+    event: &T,
+    policy: SinkErrorPolicy,
+) -> Result<(), SinkError>
+where
+    T: Clone + Send + 'static,
+    Sink: EventSink<T>,
+{
+    let mut result = sink.send(event.clone()).await;
+
+    if let SinkErrorPolicy::Retry {
+        max_retries,
+        backoff,
+    } = policy
+    {
+        for attempt in 1..=max_retries {
+            let Err(error) = &result else {
+                break;
+            };
+
+            warn!(attempt, %error, "retrying failed EventSink delivery");
+            tokio::time::sleep(backoff).await;
+            result = sink.send(event.clone()).await;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{collections::HashMap, sync::Mutex};
+
+    /// [`EventSink`] that records every delivered event, failing each event a configured number
+    /// of times before accepting it.
+    #[derive(Debug, Default)]
+    struct MockSink {
+        delivered: Mutex<Vec<u64>>,
+        failures: Mutex<HashMap<u64, u32>>,
+    }
+
+    impl MockSink {
+        fn failing(failures: impl IntoIterator<Item = (u64, u32)>) -> Self {
+            Self {
+                delivered: Mutex::new(Vec::new()),
+                failures: Mutex::new(failures.into_iter().collect()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl EventSink<u64> for MockSink {
+        async fn send(&self, event: u64) -> Result<(), SinkError> {
+            if let Some(remaining) = self.failures.lock().unwrap().get_mut(&event) {
+                if *remaining > 0 {
+                    *remaining -= 1;
+                    return Err(format!("failed to deliver {event}").into());
+                }
+            }
+
+            self.delivered.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_drive() {
+        struct TestCase {
+            sink: MockSink,
+            policy: SinkErrorPolicy,
+            expected_delivered: Vec<u64>,
+            expected_stopped: bool,
+        }
+
+        let retry = SinkErrorPolicy::Retry {
+            max_retries: 2,
+            backoff: Duration::from_millis(1),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: every event is delivered in order by a healthy sink
+                sink: MockSink::default(),
+                policy: SinkErrorPolicy::Stop,
+                expected_delivered: vec![0, 1, 2, 3],
+                expected_stopped: false,
+            },
+            TestCase {
+                // TC1: Retry policy re-sends a failed event until it is delivered
+                sink: MockSink::failing([(1, 2)]),
+                policy: retry,
+                expected_delivered: vec![0, 1, 2, 3],
+                expected_stopped: false,
+            },
+            TestCase {
+                // TC2: Retry policy drops an event that fails every retry
+                sink: MockSink::failing([(1, 3)]),
+                policy: retry,
+                expected_delivered: vec![0, 2, 3],
+                expected_stopped: false,
+            },
+            TestCase {
+                // TC3: Drop policy drops a failed event and continues
+                sink: MockSink::failing([(2, 1)]),
+                policy: SinkErrorPolicy::Drop,
+                expected_delivered: vec![0, 1, 3],
+                expected_stopped: false,
+            },
+            TestCase {
+                // TC4: Stop policy stops driving at the first failed event
+                sink: MockSink::failing([(2, 1)]),
+                policy: SinkErrorPolicy::Stop,
+                expected_delivered: vec![0, 1],
+                expected_stopped: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (tx, rx) = mpsc::unbounded_channel();
+            (0..4).for_each(|event| tx.send(event).unwrap());
+            drop(tx);
+
+            let sink = Arc::new(test.sink);
+            let actual = drive(rx, Arc::clone(&sink), test.policy).await;
+
+            assert_eq!(
+                matches!(actual, Err(DataError::Sink { .. })),
+                test.expected_stopped,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                *sink.delivered.lock().unwrap(),
+                test.expected_delivered,
+                "TC{index} failed"
+            );
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::exchange::ExchangeId;
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+
+/// Lifecycle status of a single exchange connection driven by a
+/// [`consume`](super::consumer::consume) loop, published via a [`watch`] channel so consumers
+/// (eg/ ops dashboards) can [`borrow`](watch::Receiver::borrow) the latest status without
+/// inferring it from data flow.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum ConnectionStatus {
+    /// Initialising the first connection.
+    Connecting,
+    /// [`MarketStream`](crate::MarketStream) is initialised & consuming.
+    Connected,
+    /// Re-initialising the [`MarketStream`](crate::MarketStream) after a previous connection,
+    /// where `attempt` is the number of the consecutive re-connection attempt.
+    Reconnecting { attempt: u32 },
+    /// Connection is down, either awaiting re-connection or permanently (eg/ shut down).
+    Disconnected { reason: String },
+}
+
+/// Handle used to observe the [`ConnectionStatus`] of every exchange connection of the
+/// [`Streams`](super::Streams).
+///
+/// ### Notes
+/// - Each [`StreamBuilder::subscribe`](super::builder::StreamBuilder::subscribe) call registers
+///   a connection, which is merged into the [`ConnectionStatuses`] of any
+///   [`MultiStreamBuilder`](super::builder::multi::MultiStreamBuilder) it is added to.
+/// - Connections shared via a [`ConnectionManager`](super::manager::ConnectionManager) are not
+///   registered.
+#[derive(Clone, Debug, Default)]
+pub struct ConnectionStatuses {
+    receivers: Vec<(ExchangeId, watch::Receiver<ConnectionStatus>)>,
+}
+
+impl ConnectionStatuses {
+    /// Register a new exchange connection, returning the [`watch::Sender`] its
+    /// [`consume`](super::consumer::consume) loop publishes [`ConnectionStatus`] updates with.
+    pub fn register(&mut self, exchange: ExchangeId) -> watch::Sender<ConnectionStatus> {
+        let (status_tx, status_rx) = watch::channel(ConnectionStatus::Connecting);
+        self.receivers.push((exchange, status_rx));
+        status_tx
+    }
+
+    /// Merge the registered connections of another [`ConnectionStatuses`] into [`Self`].
+    pub fn merge(&mut self, other: ConnectionStatuses) {
+        self.receivers.extend(other.receivers);
+    }
+
+    /// [`watch::Receiver`]s of every registered connection with the provided exchange.
+    pub fn receivers(
+        &self,
+        exchange: ExchangeId,
+    ) -> impl Iterator<Item = &watch::Receiver<ConnectionStatus>> {
+        self.receivers
+            .iter()
+            .filter(move |(id, _)| *id == exchange)
+            .map(|(_, status_rx)| status_rx)
+    }
+
+    /// Latest [`ConnectionStatus`] of every registered connection with the provided exchange.
+    pub fn latest(&self, exchange: ExchangeId) -> Vec<ConnectionStatus> {
+        self.receivers(exchange)
+            .map(|status_rx| status_rx.borrow().clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_statuses_latest() {
+        let mut statuses = ConnectionStatuses::default();
+        let binance_tx = statuses.register(ExchangeId::BinanceSpot);
+
+        let mut other = ConnectionStatuses::default();
+        let okx_tx = other.register(ExchangeId::Okx);
+        statuses.merge(other);
+
+        assert_eq!(
+            statuses.latest(ExchangeId::BinanceSpot),
+            vec![ConnectionStatus::Connecting]
+        );
+
+        binance_tx.send_replace(ConnectionStatus::Connected);
+        okx_tx.send_replace(ConnectionStatus::Reconnecting { attempt: 2 });
+
+        assert_eq!(
+            statuses.latest(ExchangeId::BinanceSpot),
+            vec![ConnectionStatus::Connected]
+        );
+        assert_eq!(
+            statuses.latest(ExchangeId::Okx),
+            vec![ConnectionStatus::Reconnecting { attempt: 2 }]
+        );
+        assert!(statuses.latest(ExchangeId::Kraken).is_empty());
+    }
+}
@@ -0,0 +1,178 @@
+use super::reconcile::Reconciler;
+use crate::{
+    error::DataError,
+    exchange::StreamSelector,
+    subscription::{SubKind, Subscription},
+};
+use std::{fmt::Debug, future::Future, time::Duration};
+use tokio::time::MissedTickBehavior;
+use tracing::{info, warn};
+
+/// Driver that periodically calls a user provided `Provider` for the current
+/// [`Subscription`] universe (eg/ "top 20 instruments by volume"), and converges the
+/// [`Subscription`]s of a running [`consume`](super::consumer::consume) loop on it via the
+/// associated [`Reconciler`].
+///
+/// ### Notes
+/// - The `Provider` is called immediately, and then once every `refresh` interval.
+/// - A `Provider` error, or a target universe that fails validation (eg/ empty), is logged and
+///   the current universe is retained until the next refresh.
+#[derive(Debug)]
+pub struct DynamicUniverse<Exchange, Kind, Provider> {
+    reconciler: Reconciler<Exchange, Kind>,
+    refresh: Duration,
+    provider: Provider,
+}
+
+impl<Exchange, Kind, Provider, Fut, Error> DynamicUniverse<Exchange, Kind, Provider>
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: Ord + Debug,
+    Provider: FnMut() -> Fut,
+    Fut: Future<Output = Result<Vec<Subscription<Exchange, Kind>>, Error>>,
+    Error: Debug,
+{
+    /// Construct a new [`Self`] that calls the `provider` every `refresh` interval, reconciling
+    /// the [`Subscription`] universe of the [`Reconciler`] consumer loop on each call.
+    pub fn new(
+        reconciler: Reconciler<Exchange, Kind>,
+        refresh: Duration,
+        provider: Provider,
+    ) -> Self {
+        Self {
+            reconciler,
+            refresh,
+            provider,
+        }
+    }
+
+    /// Drive the [`DynamicUniverse`] until the associated [`consume`](super::consumer::consume)
+    /// loop is no longer running, returning the [`DataError::ConsumerTerminated`].
+    pub async fn run(mut self) -> DataError {
+        let exchange = Exchange::ID;
+        let mut refresh = tokio::time::interval(self.refresh);
+        refresh.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            refresh.tick().await;
+
+            let target = match (self.provider)().await {
+                Ok(target) => target,
+                Err(error) => {
+                    warn!(
+                        %exchange,
+                        ?error,
+                        action = "retaining current universe",
+                        "DynamicUniverse provider failed to yield the Subscription universe"
+                    );
+                    continue;
+                }
+            };
+
+            match self.reconciler.reconcile(target) {
+                Ok(diff) if diff.is_empty() => {}
+                Ok(diff) => {
+                    info!(
+                        %exchange,
+                        added = ?diff.added,
+                        removed = ?diff.removed,
+                        "DynamicUniverse reconciled the Subscription universe"
+                    );
+                }
+                Err(error @ DataError::ConsumerTerminated { .. }) => return error,
+                Err(error) => {
+                    warn!(
+                        %exchange,
+                        ?error,
+                        action = "retaining current universe",
+                        "DynamicUniverse provider yielded an invalid Subscription universe"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::coinbase::Coinbase, subscription::trade::PublicTrades};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test]
+    async fn test_dynamic_universe_converges_on_provider_universe() {
+        let subscription = |base: &str| {
+            Subscription::from((Coinbase, base, "usd", InstrumentKind::Spot, PublicTrades))
+        };
+
+        let (reconciler, mut universe_rx) = Reconciler::new(vec![subscription("btc")]);
+
+        // Provider yields whatever universe (or error) the test currently sets as the source
+        let source = Arc::new(Mutex::new(Ok(vec![subscription("btc")])));
+        let provider = {
+            let source = Arc::clone(&source);
+            move || {
+                let universe = source.lock().unwrap().clone();
+                async move { universe }
+            }
+        };
+        let set = |universe: Result<Vec<_>, &'static str>| *source.lock().unwrap() = universe;
+
+        let driver = tokio::spawn(
+            DynamicUniverse::new(reconciler.clone(), Duration::from_millis(20), provider).run(),
+        );
+
+        // TC0: unchanged universe is a no-op
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(universe_rx.try_recv().is_err(), "TC0 failed");
+
+        // TC1: provider error retains the current universe
+        set(Err("provider unavailable"));
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(universe_rx.try_recv().is_err(), "TC1 failed");
+        assert_eq!(
+            reconciler.current(),
+            vec![subscription("btc")],
+            "TC1 failed"
+        );
+
+        // TC2: added Subscription is reconciled
+        set(Ok(vec![subscription("eth"), subscription("btc")]));
+        let actual = tokio::time::timeout(Duration::from_secs(5), universe_rx.recv())
+            .await
+            .expect("TC2 failed")
+            .unwrap();
+        assert_eq!(
+            actual,
+            vec![subscription("btc"), subscription("eth")],
+            "TC2 failed"
+        );
+
+        // TC3: entirely different universe is reconciled
+        set(Ok(vec![subscription("sol")]));
+        let actual = tokio::time::timeout(Duration::from_secs(5), universe_rx.recv())
+            .await
+            .expect("TC3 failed")
+            .unwrap();
+        assert_eq!(actual, vec![subscription("sol")], "TC3 failed");
+        assert_eq!(
+            reconciler.current(),
+            vec![subscription("sol")],
+            "TC3 failed"
+        );
+
+        // TC4: driver stops once the consumer loop is no longer running
+        drop(universe_rx);
+        set(Ok(vec![subscription("btc")]));
+        let actual = tokio::time::timeout(Duration::from_secs(5), driver)
+            .await
+            .expect("TC4 failed")
+            .unwrap();
+        assert!(
+            matches!(actual, DataError::ConsumerTerminated { .. }),
+            "TC4 failed"
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use crate::error::DataError;
+use barter_integration::{
+    error::SocketError,
+    protocol::websocket::{WebSocket, WsMessage},
+};
+use futures::{SinkExt, StreamExt};
+use std::{fmt::Debug, time::Duration};
+use tracing::debug;
+
+/// Next step of a [`Handshake`] state machine, determined from each message received from the
+/// exchange server.
+#[derive(Clone, PartialEq, Debug)]
+pub enum HandshakeStep {
+    /// Continue awaiting messages (eg/ ignore an unrelated frame).
+    Await,
+    /// Send the follow-up frames, then continue awaiting messages.
+    Send(Vec<WsMessage>),
+    /// Send any final frames, then complete the [`Handshake`].
+    Complete(Vec<WsMessage>),
+}
+
+/// Exchange specific multi-step handshake performed by the
+/// [`WebSocketSubscriber`](super::WebSocketSubscriber) after connecting, but before sending the
+/// [`Subscription`](crate::subscription::Subscription) requests (eg/ Bitfinex conf flags, OKX
+/// login).
+///
+/// Modelled as a state machine that sends the [`Handshake::start`] frames, and then transitions
+/// on every message received until the [`HandshakeStep::Complete`] step is reached.
+pub trait Handshake: Debug + Send {
+    /// Frames sent to the exchange server to start the [`Handshake`].
+    fn start(&mut self) -> Vec<WsMessage>;
+
+    /// Determine the next [`HandshakeStep`] from a message received from the exchange server.
+    ///
+    /// An error aborts the [`Handshake`] (eg/ the exchange rejected a login).
+    // SocketError is defined upstream in barter-integration, so cannot be boxed here
+    #[allow(clippy::result_large_err)]
+    fn next(&mut self, message: &WsMessage) -> Result<HandshakeStep, SocketError>;
+}
+
+/// Drive the provided [`Handshake`] state machine to completion over the [`WebSocket`].
+///
+/// If the [`Handshake`] does not complete within the `timeout`, a [`SocketError::Subscribe`] is
+/// returned.
+pub async fn handshake(
+    websocket: &mut WebSocket,
+    mut handshake: Box<dyn Handshake>,
+    timeout: Duration,
+) -> Result<(), DataError> {
+    send(websocket, handshake.start()).await?;
+
+    let steps = async {
+        loop {
+            let message = match websocket.next().await {
+                Some(Ok(message)) => message,
+                Some(Err(error)) => return Err(DataError::from(SocketError::WebSocket(error))),
+                None => {
+                    return Err(DataError::from(SocketError::Subscribe(
+                        "WebSocket stream terminated during handshake".to_string(),
+                    )))
+                }
+            };
+
+            match handshake.next(&message)? {
+                HandshakeStep::Await => continue,
+                HandshakeStep::Send(frames) => {
+                    debug!(?handshake, payload = ?message, "sending handshake follow-up frames");
+                    send(websocket, frames).await?;
+                }
+                HandshakeStep::Complete(frames) => {
+                    send(websocket, frames).await?;
+                    debug!(?handshake, "completed exchange handshake");
+                    return Ok(());
+                }
+            }
+        }
+    };
+
+    match tokio::time::timeout(timeout, steps).await {
+        Ok(outcome) => outcome,
+        Err(_) => Err(DataError::from(SocketError::Subscribe(format!(
+            "handshake timeout reached: {timeout:?}"
+        )))),
+    }
+}
+
+/// Send each of the provided frames over the [`WebSocket`].
+async fn send(websocket: &mut WebSocket, frames: Vec<WsMessage>) -> Result<(), DataError> {
+    for frame in frames {
+        websocket
+            .send(frame)
+            .await
+            .map_err(SocketError::WebSocket)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::protocol::websocket;
+    use tokio::net::TcpListener;
+
+    /// Two-step [`Handshake`] that logs in, and then configures the connection once the login
+    /// is acknowledged.
+    #[derive(Debug)]
+    struct MockHandshake;
+
+    impl Handshake for MockHandshake {
+        fn start(&mut self) -> Vec<WsMessage> {
+            vec![WsMessage::Text("login".to_string())]
+        }
+
+        fn next(&mut self, message: &WsMessage) -> Result<HandshakeStep, SocketError> {
+            match message.to_text().unwrap_or_default() {
+                "login_ack" => Ok(HandshakeStep::Send(vec![WsMessage::Text(
+                    "conf".to_string(),
+                )])),
+                "conf_ack" => Ok(HandshakeStep::Complete(vec![])),
+                "login_rejected" => Err(SocketError::Subscribe("login rejected".to_string())),
+                _ => Ok(HandshakeStep::Await),
+            }
+        }
+    }
+
+    /// Mock server that responds to each handshake frame with the provided responses, and then
+    /// echoes the first frame received after the handshake.
+    async fn mock_server(responses: Vec<(&'static str, &'static str)>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+            for (expected, response) in responses {
+                let frame = websocket.next().await.unwrap().unwrap();
+                assert_eq!(frame.to_text().unwrap(), expected);
+                websocket
+                    .send(WsMessage::Text("heartbeat".to_string()))
+                    .await
+                    .unwrap();
+                websocket
+                    .send(WsMessage::Text(response.to_string()))
+                    .await
+                    .unwrap();
+            }
+
+            if let Some(Ok(frame)) = websocket.next().await {
+                let _ = websocket.send(frame).await;
+            }
+        });
+
+        format!("ws://{address}")
+    }
+
+    #[tokio::test]
+    async fn test_two_step_handshake() {
+        struct TestCase {
+            responses: Vec<(&'static str, &'static str)>,
+            expected: Result<(), ()>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: login & conf acknowledged, so the handshake completes
+                responses: vec![("login", "login_ack"), ("conf", "conf_ack")],
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: login rejected, so the handshake is aborted
+                responses: vec![("login", "login_rejected")],
+                expected: Err(()),
+            },
+            TestCase {
+                // TC2: conf never acknowledged, so the handshake times out
+                responses: vec![("login", "login_ack"), ("conf", "unrelated")],
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let url = mock_server(test.responses).await;
+            let mut websocket = websocket::connect(url).await.unwrap();
+
+            let actual = handshake(
+                &mut websocket,
+                Box::new(MockHandshake),
+                Duration::from_millis(200),
+            )
+            .await;
+
+            match (actual, test.expected) {
+                (Ok(()), Ok(())) => {
+                    // Subsequent subscription frames flow over the same connection
+                    websocket
+                        .send(WsMessage::Text("subscribe".to_string()))
+                        .await
+                        .unwrap();
+                    let echo = websocket.next().await.unwrap().unwrap();
+                    assert_eq!(echo.to_text().unwrap(), "subscribe", "TC{index} failed");
+                }
+                (Err(DataError::Socket(error)), Err(()))
+                    if matches!(*error, SocketError::Subscribe(_)) =>
+                {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -1,16 +1,26 @@
 use crate::{
-    exchange::{subscription::ExchangeSub, Connector},
-    subscription::{Map, SubKind, Subscription, SubscriptionMeta},
+    exchange::{
+        subscription::{ExchangeSub, ResumableSub},
+        Connector,
+    },
+    subscription::{
+        intern::intern, resume::ResumeFrom, Map, SubKind, Subscription, SubscriptionMeta,
+    },
     Identifier,
 };
 use barter_integration::model::SubscriptionId;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 /// Defines how to map a collection of Barter [`Subscription`]s into exchange specific
 /// [`SubscriptionMeta`], containing subscription payloads that are sent to the exchange.
+///
+/// If the provided [`ResumeFrom`] contains last-seen sequences, the payloads should resume the
+/// exchange streams from those sequences where the exchange supports it.
 pub trait SubscriptionMapper {
-    fn map<Exchange, Kind>(subscriptions: &[Subscription<Exchange, Kind>]) -> SubscriptionMeta
+    fn map<Exchange, Kind>(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        resume: &ResumeFrom,
+    ) -> SubscriptionMeta
     where
         Exchange: Connector,
         Kind: SubKind,
@@ -23,7 +33,10 @@ pub trait SubscriptionMapper {
 pub struct WebSocketSubMapper;
 
 impl SubscriptionMapper for WebSocketSubMapper {
-    fn map<Exchange, Kind>(subscriptions: &[Subscription<Exchange, Kind>]) -> SubscriptionMeta
+    fn map<Exchange, Kind>(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        resume: &ResumeFrom,
+    ) -> SubscriptionMeta
     where
         Exchange: Connector,
         Kind: SubKind,
@@ -31,9 +44,10 @@ impl SubscriptionMapper for WebSocketSubMapper {
         ExchangeSub<Exchange::Channel, Exchange::Market>: Identifier<SubscriptionId>,
     {
         // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
-        let mut instrument_map = Map(HashMap::with_capacity(subscriptions.len()));
+        let mut instrument_map = Map::with_capacity(subscriptions.len());
 
-        // Map Barter Subscriptions to exchange specific subscriptions
+        // Map Barter Subscriptions to exchange specific subscriptions, interning each Instrument
+        // so every MarketEvent shares a single allocation of it
         let exchange_subs = subscriptions
             .iter()
             .map(|subscription| {
@@ -46,14 +60,41 @@ impl SubscriptionMapper for WebSocketSubMapper {
                 // Use ExchangeSub SubscriptionId as the link to this Barter Subscription
                 instrument_map
                     .0
-                    .insert(subscription_id, subscription.instrument.clone());
+                    .insert(subscription_id, intern(&subscription.instrument));
 
-                exchange_sub
+                (exchange_sub, resume.sequence(&subscription.instrument))
             })
-            .collect::<Vec<ExchangeSub<Exchange::Channel, Exchange::Market>>>();
+            .collect::<Vec<ResumableSub<Exchange::Channel, Exchange::Market>>>();
 
-        // Construct WebSocket message subscriptions requests
-        let subscriptions = Exchange::requests(exchange_subs);
+        // Chunk the exchange subscriptions into batches the exchange accepts in a single message
+        let batches = match Exchange::max_subscriptions_per_message() {
+            Some(max) => {
+                let mut exchange_subs = exchange_subs.into_iter().peekable();
+                std::iter::from_fn(|| {
+                    exchange_subs.peek()?;
+                    Some(exchange_subs.by_ref().take(max.max(1)).collect::<Vec<_>>())
+                })
+                .collect::<Vec<_>>()
+            }
+            None => vec![exchange_subs],
+        };
+
+        // Construct WebSocket message subscriptions requests, resuming from last-seen sequences
+        let subscriptions = batches
+            .into_iter()
+            .flat_map(|batch| {
+                if resume.is_empty() {
+                    Exchange::requests(
+                        batch
+                            .into_iter()
+                            .map(|(exchange_sub, _)| exchange_sub)
+                            .collect(),
+                    )
+                } else {
+                    Exchange::resume_requests(batch)
+                }
+            })
+            .collect();
 
         SubscriptionMeta {
             instrument_map,
@@ -61,3 +102,56 @@ impl SubscriptionMapper for WebSocketSubMapper {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::{spot::BinanceSpot, MAX_SUBSCRIPTIONS_PER_MESSAGE_BINANCE},
+        subscription::trade::PublicTrades,
+    };
+    use barter_integration::{
+        model::instrument::kind::InstrumentKind, protocol::websocket::WsMessage,
+    };
+
+    #[test]
+    fn test_web_socket_sub_mapper_chunks_subscriptions() {
+        let subscriptions = (0..300)
+            .map(|index| {
+                Subscription::from((
+                    BinanceSpot::default(),
+                    format!("base{index}"),
+                    "usdt".to_string(),
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let SubscriptionMeta {
+            instrument_map,
+            subscriptions: requests,
+        } = WebSocketSubMapper::map(&subscriptions, &ResumeFrom::default());
+
+        // 300 subscriptions are split into one full batch & one batch of the remainder
+        let params = requests
+            .iter()
+            .map(|request| match request {
+                WsMessage::Text(payload) => serde_json::from_str::<serde_json::Value>(payload)
+                    .unwrap()["params"]
+                    .as_array()
+                    .unwrap()
+                    .len(),
+                other => panic!("unexpected request: {other:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(params, vec![MAX_SUBSCRIPTIONS_PER_MESSAGE_BINANCE, 100]);
+
+        // Every subscription is still routed, and a response is expected per request
+        assert_eq!(instrument_map.0.len(), 300);
+        assert_eq!(
+            BinanceSpot::expected_responses(&instrument_map),
+            requests.len()
+        );
+    }
+}
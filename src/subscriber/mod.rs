@@ -1,10 +1,12 @@
 use self::{
+    handshake::handshake,
     mapper::{SubscriptionMapper, WebSocketSubMapper},
     validator::SubscriptionValidator,
 };
 use crate::{
-    exchange::Connector,
-    subscription::{Map, SubKind, Subscription, SubscriptionMeta},
+    error::DataError,
+    exchange::{connector_url, rate_limit::RateLimiter, Connector},
+    subscription::{resume::ResumeFrom, Map, SubKind, Subscription, SubscriptionMeta},
     Identifier,
 };
 use async_trait::async_trait;
@@ -15,8 +17,13 @@ use barter_integration::{
 };
 use futures::SinkExt;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use tracing::{debug, info};
 
+/// [`Handshake`](handshake::Handshake) state machine used to express multi-step exchange
+/// handshakes that must complete before subscribing.
+pub mod handshake;
+
 /// [`SubscriptionMapper`](mapper::SubscriptionMapper) implementations defining how to map a
 /// collection of Barter [`Subscription`]s into exchange specific [`SubscriptionMeta`].
 pub mod mapper;
@@ -25,14 +32,16 @@ pub mod mapper;
 /// validate actioned [`Subscription`]s were successful.
 pub mod validator;
 
-/// Defines how to connect to a socket and subscribe to market data streams.
+/// Defines how to connect to a socket and subscribe to market data streams, resuming from the
+/// last-seen sequences in the provided [`ResumeFrom`] where the exchange supports it.
 #[async_trait]
 pub trait Subscriber {
     type SubMapper: SubscriptionMapper;
 
     async fn subscribe<Exchange, Kind>(
         subscriptions: &[Subscription<Exchange, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument>), SocketError>
+        resume: &ResumeFrom,
+    ) -> Result<(WebSocket, Map<Arc<Instrument>>), DataError>
     where
         Exchange: Connector + Send + Sync,
         Kind: SubKind + Send + Sync,
@@ -49,7 +58,8 @@ impl Subscriber for WebSocketSubscriber {
 
     async fn subscribe<Exchange, Kind>(
         subscriptions: &[Subscription<Exchange, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument>), SocketError>
+        resume: &ResumeFrom,
+    ) -> Result<(WebSocket, Map<Arc<Instrument>>), DataError>
     where
         Exchange: Connector + Send + Sync,
         Kind: SubKind + Send + Sync,
@@ -57,23 +67,40 @@ impl Subscriber for WebSocketSubscriber {
     {
         // Define variables for logging ergonomics
         let exchange = Exchange::ID;
-        let url = Exchange::url()?;
+        let url = connector_url::<Exchange>()?;
         debug!(%exchange, %url, ?subscriptions, "subscribing to WebSocket");
 
         // Connect to exchange
         let mut websocket = connect(url).await?;
         debug!(%exchange, ?subscriptions, "connected to WebSocket");
 
+        // Perform any exchange specific multi-step handshake
+        if let Some(exchange_handshake) = Exchange::handshake() {
+            handshake(
+                &mut websocket,
+                exchange_handshake,
+                Exchange::subscription_timeout(),
+            )
+            .await?;
+        }
+
         // Map &[Subscription<Exchange, Kind>] to SubscriptionMeta
         let SubscriptionMeta {
             instrument_map,
             subscriptions,
-        } = Self::SubMapper::map::<Exchange, Kind>(subscriptions);
+        } = Self::SubMapper::map::<Exchange, Kind>(subscriptions, resume);
 
-        // Send Subscriptions over WebSocket
+        // Send Subscriptions over WebSocket, paced by any exchange OutboundRateLimit
+        let mut limiter = Exchange::outbound_rate_limit().map(RateLimiter::new);
         for subscription in subscriptions {
+            if let Some(limiter) = &mut limiter {
+                limiter.acquire().await;
+            }
             debug!(%exchange, payload = ?subscription, "sending exchange subscription");
-            websocket.send(subscription).await?;
+            websocket
+                .send(subscription)
+                .await
+                .map_err(SocketError::WebSocket)?;
         }
 
         // Validate Subscription responses
@@ -1,11 +1,12 @@
 use crate::{
+    error::{DataError, SubscriptionError},
     exchange::Connector,
     subscription::{Map, SubKind},
 };
 use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
-    model::instrument::Instrument,
+    model::{instrument::Instrument, SubscriptionId},
     protocol::{
         websocket::{WebSocket, WebSocketParser},
         StreamParser,
@@ -14,6 +15,7 @@ use barter_integration::{
 };
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, sync::Arc};
 use tracing::debug;
 
 /// Defines how to validate that actioned market data
@@ -23,9 +25,9 @@ pub trait SubscriptionValidator {
     type Parser: StreamParser;
 
     async fn validate<Exchange, Kind>(
-        instrument_map: Map<Instrument>,
+        instrument_map: Map<Arc<Instrument>>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument>, SocketError>
+    ) -> Result<Map<Arc<Instrument>>, DataError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send;
@@ -40,9 +42,9 @@ impl SubscriptionValidator for WebSocketSubValidator {
     type Parser = WebSocketParser;
 
     async fn validate<Exchange, Kind>(
-        instrument_map: Map<Instrument>,
+        instrument_map: Map<Arc<Instrument>>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument>, SocketError>
+    ) -> Result<Map<Arc<Instrument>>, DataError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send,
@@ -51,8 +53,10 @@ impl SubscriptionValidator for WebSocketSubValidator {
         let timeout = Exchange::subscription_timeout();
         let expected_responses = Exchange::expected_responses(&instrument_map);
 
-        // Parameter to keep track of successful Subscription outcomes
+        // Parameters to keep track of successful Subscription outcomes, where responses that
+        // identify their Subscription are counted once, regardless of duplication or ordering
         let mut success_responses = 0usize;
+        let mut acknowledged = HashSet::new();
 
         loop {
             // Break if all Subscriptions were a success
@@ -64,33 +68,62 @@ impl SubscriptionValidator for WebSocketSubValidator {
             tokio::select! {
                 // If timeout reached, return SubscribeError
                 _ = tokio::time::sleep(timeout) => {
-                    break Err(SocketError::Subscribe(
-                        format!("subscription validation timeout reached: {:?}", timeout)
+                    break Err(unconfirmed::<Exchange>(
+                        &instrument_map,
+                        &acknowledged,
+                        success_responses,
+                        format!("subscription validation timeout reached: {:?}", timeout),
                     ))
                 },
                 // Parse incoming messages and determine subscription outcomes
                 message = websocket.next() => {
                     let response = match message {
                         Some(response) => response,
-                        None => break Err(SocketError::Subscribe("WebSocket stream terminated unexpectedly".to_string()))
+                        None => break Err(unconfirmed::<Exchange>(
+                            &instrument_map,
+                            &acknowledged,
+                            success_responses,
+                            "WebSocket stream terminated unexpectedly".to_string(),
+                        ))
                     };
 
                     match Self::Parser::parse::<Exchange::SubResponse>(response) {
-                        Some(Ok(response)) => match response.validate() {
-                            // Subscription success
-                            Ok(response) => {
-                                success_responses += 1;
-                                debug!(
-                                    exchange = %Exchange::ID,
-                                    %success_responses,
-                                    %expected_responses,
-                                    payload = ?response,
-                                    "received valid Ok subscription response",
-                                );
+                        Some(Ok(response)) => {
+                            // Subscription failure due to an exchange subscription limit being hit
+                            if let Some(error) = Exchange::subscription_limit_exceeded(&response) {
+                                break Err(DataError::from(error))
                             }
 
-                            // Subscription failure
-                            Err(err) => break Err(err)
+                            let subscription_id = Exchange::subscription_id(&response);
+
+                            match response.validate() {
+                                // Subscription success of an unknown or already acknowledged Subscription
+                                Ok(response) if subscription_id.as_ref().is_some_and(|id| {
+                                    !instrument_map.0.contains_key(id) || !acknowledged.insert(id.clone())
+                                }) => {
+                                    debug!(
+                                        exchange = %Exchange::ID,
+                                        ?subscription_id,
+                                        payload = ?response,
+                                        "ignoring duplicate or unknown Ok subscription response",
+                                    );
+                                }
+
+                                // Subscription success
+                                Ok(response) => {
+                                    success_responses += 1;
+                                    debug!(
+                                        exchange = %Exchange::ID,
+                                        %success_responses,
+                                        %expected_responses,
+                                        payload = ?response,
+                                        "received valid Ok subscription response",
+                                    );
+                                }
+
+                                // Subscription failure
+                                Err(err) => break Err(DataError::from(err))
+                            }
                         }
                         Some(Err(SocketError::Deserialise { error, payload })) if success_responses >= 1 => {
                             // Already active subscription payloads, so skip to next SubResponse
@@ -105,8 +138,11 @@ impl SubscriptionValidator for WebSocketSubValidator {
                             continue
                         }
                         Some(Err(SocketError::Terminated(close_frame))) => {
-                            break Err(SocketError::Subscribe(
-                                format!("received WebSocket CloseFrame: {close_frame}")
+                            break Err(unconfirmed::<Exchange>(
+                                &instrument_map,
+                                &acknowledged,
+                                success_responses,
+                                format!("received WebSocket CloseFrame: {close_frame}"),
                             ))
                         }
                         _ => {
@@ -119,3 +155,291 @@ impl SubscriptionValidator for WebSocketSubValidator {
         }
     }
 }
+
+/// Construct the [`DataError`] returned when validation ends before every
+/// [`Subscription`](crate::subscription::Subscription) was confirmed.
+///
+/// If every success response so far identified its
+/// [`Subscription`](crate::subscription::Subscription) (see [`Connector::subscription_id`]), the
+/// [`SubscriptionError::Unconfirmed`] lists each one the exchange did not confirm. Otherwise the
+/// unconfirmed [`Subscription`](crate::subscription::Subscription)s cannot be determined, and a
+/// generic [`SocketError::Subscribe`] is returned.
+fn unconfirmed<Exchange>(
+    instrument_map: &Map<Arc<Instrument>>,
+    acknowledged: &HashSet<SubscriptionId>,
+    success_responses: usize,
+    reason: String,
+) -> DataError
+where
+    Exchange: Connector,
+{
+    if success_responses != acknowledged.len() {
+        return DataError::from(SocketError::Subscribe(reason));
+    }
+
+    let mut unconfirmed = instrument_map
+        .0
+        .keys()
+        .filter(|id| !acknowledged.contains(*id))
+        .cloned()
+        .collect::<Vec<_>>();
+    unconfirmed.sort();
+
+    DataError::from(SubscriptionError::Unconfirmed {
+        exchange: Exchange::ID,
+        unconfirmed,
+        reason,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{
+            okx::{channel::OkxChannel, market::OkxMarket, Okx},
+            subscription::ExchangeSub,
+            ExchangeId,
+        },
+        subscription::trade::PublicTrades,
+        Identifier,
+    };
+    use barter_integration::{
+        model::instrument::kind::InstrumentKind,
+        protocol::websocket::{self, WsMessage},
+    };
+    use futures::SinkExt;
+    use serde_json::json;
+    use tokio::net::TcpListener;
+
+    /// Scripted exchange acknowledgement of a single actioned subscription.
+    #[derive(Copy, Clone, Debug)]
+    enum Ack {
+        Accept,
+        Reject(&'static str),
+        Close,
+    }
+
+    /// Final frame sent by the [`ack_server`] once every scripted ack has been sent.
+    const SENTINEL: &str = "sentinel";
+
+    /// Mock [`Okx`] server that waits for the subscription request, and then acknowledges each
+    /// scripted market in order, interleaved with an unrelated heartbeat frame, followed by the
+    /// [`SENTINEL`] frame. An [`Ack::Close`] closes the connection instead.
+    async fn ack_server(script: Vec<(&'static str, Ack)>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+            let request = websocket.next().await.unwrap().unwrap();
+            assert!(request.to_text().unwrap().contains(r#""op":"subscribe""#));
+
+            for (market, ack) in script {
+                let response = match ack {
+                    Ack::Accept => json!({
+                        "event": "subscribe",
+                        "arg": { "channel": "trades", "instId": market },
+                    }),
+                    Ack::Reject(message) => json!({
+                        "event": "error",
+                        "code": "60012",
+                        "msg": message,
+                    }),
+                    Ack::Close => {
+                        websocket.send(WsMessage::Close(None)).await.unwrap();
+                        break;
+                    }
+                };
+
+                websocket
+                    .send(WsMessage::Text("pong".to_string()))
+                    .await
+                    .unwrap();
+                websocket
+                    .send(WsMessage::Text(response.to_string()))
+                    .await
+                    .unwrap();
+            }
+
+            websocket
+                .send(WsMessage::Text(SENTINEL.to_string()))
+                .await
+                .unwrap();
+
+            while let Some(Ok(_)) = websocket.next().await {}
+        });
+
+        format!("ws://{address}")
+    }
+
+    /// Validate subscriptions to the provided trades markets (eg/ `BTC-USDT`) of the mock [`Okx`]
+    /// server running the provided ack script, returning the outcome & the validated [`WebSocket`].
+    async fn validate_script(
+        markets: &[&'static str],
+        script: Vec<(&'static str, Ack)>,
+    ) -> (Result<Map<Arc<Instrument>>, DataError>, WebSocket) {
+        let exchange_subs = markets
+            .iter()
+            .map(|market| ExchangeSub {
+                channel: OkxChannel::TRADES,
+                market: OkxMarket(market.to_string()),
+            })
+            .collect::<Vec<_>>();
+
+        let instrument_map = exchange_subs
+            .iter()
+            .map(|exchange_sub| {
+                let (base, quote) = exchange_sub.market.0.split_once('-').unwrap();
+                let instrument = Instrument::from((base, quote, InstrumentKind::Spot));
+                (exchange_sub.id(), Arc::new(instrument))
+            })
+            .collect::<Map<_>>();
+
+        let url = ack_server(script).await;
+        let mut websocket = websocket::connect(url).await.unwrap();
+        for request in Okx::requests(exchange_subs) {
+            websocket.send(request).await.unwrap();
+        }
+
+        let outcome =
+            WebSocketSubValidator::validate::<Okx, PublicTrades>(instrument_map, &mut websocket)
+                .await;
+
+        (outcome, websocket)
+    }
+
+    #[tokio::test]
+    async fn test_validate_scripted_acks() {
+        struct TestCase {
+            script: Vec<(&'static str, Ack)>,
+            expected: Result<usize, &'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: every subscription accepted, so every subscription is validated
+                script: vec![("BTC-USDT", Ack::Accept), ("ETH-USDT", Ack::Accept)],
+                expected: Ok(2),
+            },
+            TestCase {
+                // TC1: one acceptance & one rejection, so the rejection message is reported
+                script: vec![
+                    ("BTC-USDT", Ack::Accept),
+                    ("ETH-USDT", Ack::Reject("Invalid request: ETH-USDT")),
+                ],
+                expected: Err("Invalid request: ETH-USDT"),
+            },
+            TestCase {
+                // TC2: rejection received before the remaining acceptance is reported
+                script: vec![
+                    ("ETH-USDT", Ack::Reject("Invalid request: ETH-USDT")),
+                    ("BTC-USDT", Ack::Accept),
+                ],
+                expected: Err("Invalid request: ETH-USDT"),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (actual, _) = validate_script(&["BTC-USDT", "ETH-USDT"], test.script).await;
+
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual.0.len(), expected, "TC{index} failed")
+                }
+                (Err(DataError::Socket(error)), Err(expected)) => match *error {
+                    SocketError::Subscribe(actual) => {
+                        assert!(actual.contains(expected), "TC{index} failed: {actual}")
+                    }
+                    error => panic!("TC{index} failed: {error:?}"),
+                },
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validate_duplicate_and_reordered_acks() {
+        struct TestCase {
+            script: Vec<(&'static str, Ack)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: duplicated ack is counted once, so validation waits for the ETH-USDT ack
+                script: vec![
+                    ("BTC-USDT", Ack::Accept),
+                    ("BTC-USDT", Ack::Accept),
+                    ("ETH-USDT", Ack::Accept),
+                ],
+            },
+            TestCase {
+                // TC1: re-ordered & duplicated acks are matched to their subscriptions
+                script: vec![
+                    ("ETH-USDT", Ack::Accept),
+                    ("ETH-USDT", Ack::Accept),
+                    ("BTC-USDT", Ack::Accept),
+                ],
+            },
+            TestCase {
+                // TC2: ack of an unknown subscription is not counted
+                script: vec![
+                    ("ETH-USDT", Ack::Accept),
+                    ("SOL-USDT", Ack::Accept),
+                    ("BTC-USDT", Ack::Accept),
+                ],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (actual, mut websocket) =
+                validate_script(&["BTC-USDT", "ETH-USDT"], test.script).await;
+
+            let actual = actual.unwrap_or_else(|error| panic!("TC{index} failed: {error:?}"));
+            assert_eq!(actual.0.len(), 2, "TC{index} failed");
+
+            // Validation completed on the final ack, so the next frame is the sentinel
+            let next = loop {
+                match websocket.next().await {
+                    Some(Ok(WsMessage::Text(text))) if text == "pong" => continue,
+                    next => break next,
+                }
+            };
+            assert!(
+                matches!(&next, Some(Ok(WsMessage::Text(text))) if text == SENTINEL),
+                "TC{index} failed: {next:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validate_reports_unconfirmed_subscriptions() {
+        // Exchange acks 2 of 3 subscriptions before closing the connection
+        let (actual, _) = validate_script(
+            &["BTC-USDT", "ETH-USDT", "SOL-USDT"],
+            vec![
+                ("BTC-USDT", Ack::Accept),
+                ("SOL-USDT", Ack::Accept),
+                ("", Ack::Close),
+            ],
+        )
+        .await;
+
+        match actual {
+            Err(DataError::Subscription(SubscriptionError::Unconfirmed {
+                exchange,
+                unconfirmed,
+                ..
+            })) => {
+                assert_eq!(exchange, ExchangeId::Okx);
+                assert_eq!(unconfirmed, vec![SubscriptionId::from("trades|ETH-USDT")]);
+            }
+            actual => panic!("expected SubscriptionError::Unconfirmed, got {actual:?}"),
+        }
+    }
+}
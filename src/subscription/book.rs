@@ -1,5 +1,6 @@
-use super::SubKind;
+use super::{load::StreamLoad, SubKind, SubKindId};
 use crate::{
+    clock,
     event::{MarketEvent, MarketIter},
     exchange::ExchangeId,
 };
@@ -7,7 +8,7 @@ use barter_integration::model::{instrument::Instrument, Exchange, Side};
 use barter_macro::{DeSubKind, SerSubKind};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::cmp::Ordering;
+use std::{cmp::Ordering, sync::Arc};
 use tracing::debug;
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 1 [`OrderBook`]
@@ -20,6 +21,11 @@ pub struct OrderBooksL1;
 
 impl SubKind for OrderBooksL1 {
     type Event = OrderBookL1;
+    const ID: Option<SubKindId> = Some(SubKindId::OrderBooksL1);
+
+    fn typical_load(&self) -> StreamLoad {
+        StreamLoad::new(10.0, 150.0)
+    }
 }
 
 /// Normalised Barter [`OrderBookL1`] snapshot containing the latest best bid and ask.
@@ -45,6 +51,11 @@ impl OrderBookL1 {
     pub fn volume_weighed_mid_price(&self) -> f64 {
         volume_weighted_mid_price(self.best_bid, self.best_ask)
     }
+
+    /// Calculate the [`spread_bps`] of the best bid & ask of this snapshot.
+    pub fn spread_bps(&self) -> Option<f64> {
+        spread_bps(self.best_bid.price, self.best_ask.price)
+    }
 }
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 2 [`OrderBook`]
@@ -56,6 +67,37 @@ pub struct OrderBooksL2;
 
 impl SubKind for OrderBooksL2 {
     type Event = OrderBook;
+    const ID: Option<SubKindId> = Some(SubKindId::OrderBooksL2);
+
+    fn typical_load(&self) -> StreamLoad {
+        StreamLoad::new(10.0, 1_000.0).with_snapshot()
+    }
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 2 [`OrderBook`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events, each containing at most `depth`
+/// [`Level`]s per [`OrderBookSide`].
+///
+/// Unlike [`OrderBooksL2`], each [`Subscription`](super::Subscription) in a batch can request a
+/// different depth (eg/ deep books for majors, shallow books for alts). The requested depth must
+/// be supported by the exchange (see
+/// [`Connector::book_depths`](crate::exchange::Connector::book_depths)).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OrderBooksL2Depth {
+    pub depth: u16,
+}
+
+impl SubKind for OrderBooksL2Depth {
+    type Event = OrderBook;
+    const ID: Option<SubKindId> = Some(SubKindId::OrderBooksL2Depth);
+
+    fn depth(&self) -> Option<u16> {
+        Some(self.depth)
+    }
+
+    fn typical_load(&self) -> StreamLoad {
+        StreamLoad::new(10.0, 1_000.0).with_snapshot()
+    }
 }
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 3 [`OrderBook`]
@@ -70,6 +112,46 @@ impl SubKind for OrderBooksL3 {
     type Event = OrderBook;
 }
 
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields lightweight
+/// [`TopOfBook`] [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// The full level 2 [`OrderBook`] is reconstructed internally, but only its top is yielded, and
+/// only when the top changes. Provides book-ticker like data without a separate exchange channel,
+/// and without the overhead of carrying each [`OrderBookSide`] ladder.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct OrderBooksTop;
+
+impl SubKind for OrderBooksTop {
+    type Event = TopOfBook;
+    const ID: Option<SubKindId> = Some(SubKindId::OrderBooksTop);
+
+    fn typical_load(&self) -> StreamLoad {
+        StreamLoad::new(10.0, 1_000.0).with_snapshot()
+    }
+}
+
+/// Normalised Barter [`TopOfBook`] containing the best bid and ask of a reconstructed
+/// [`OrderBook`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct TopOfBook {
+    pub time: DateTime<Utc>,
+    pub best_bid: f64,
+    pub best_bid_qty: f64,
+    pub best_ask: f64,
+    pub best_ask_qty: f64,
+}
+
+impl TopOfBook {
+    /// Determine if the best bid & ask of [`Self`] are equal to those of the `other`
+    /// [`TopOfBook`], ignoring the time.
+    pub fn eq_top(&self, other: &Self) -> bool {
+        self.best_bid == other.best_bid
+            && self.best_bid_qty == other.best_bid_qty
+            && self.best_ask == other.best_ask
+            && self.best_ask_qty == other.best_ask_qty
+    }
+}
+
 /// Normalised Barter [`OrderBook`] snapshot.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
 pub struct OrderBook {
@@ -87,6 +169,22 @@ impl OrderBook {
         self.clone()
     }
 
+    /// Truncate each sorted [`OrderBookSide`] to at most `depth` [`Level`]s.
+    pub fn truncate(&mut self, depth: usize) {
+        self.bids.levels.truncate(depth);
+        self.asks.levels.truncate(depth);
+    }
+
+    /// Best bid [`Level`] of the sorted bids [`OrderBookSide`], or `None` if there are no bids.
+    pub fn best_bid(&self) -> Option<Level> {
+        self.bids.levels.first().copied()
+    }
+
+    /// Best ask [`Level`] of the sorted asks [`OrderBookSide`], or `None` if there are no asks.
+    pub fn best_ask(&self) -> Option<Level> {
+        self.asks.levels.first().copied()
+    }
+
     /// Calculate the mid price by taking the average of the best bid and ask prices.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
@@ -113,6 +211,127 @@ impl OrderBook {
             (None, None) => None,
         }
     }
+
+    /// Calculate the volume weighted mid price (micro-price) over the top `depth` [`Level`]s of
+    /// each side, weighing the volume weighted average bid and ask prices with the total amount
+    /// of the opposite side.
+    ///
+    /// Equivalent to [`volume_weighed_mid_price`](Self::volume_weighed_mid_price) for a `depth`
+    /// of one. Returns `None` if either [`OrderBookSide`] has fewer than `depth` [`Level`]s.
+    pub fn volume_weighted_mid(&self, depth: usize) -> Option<f64> {
+        Some(volume_weighted_mid_price(
+            self.bids.aggregate(depth)?,
+            self.asks.aggregate(depth)?,
+        ))
+    }
+
+    /// Calculate the order book imbalance over the top `depth` [`Level`]s of each side, ie/ the
+    /// total bid amount as a fraction of the total bid & ask amount.
+    ///
+    /// Ranges from 0.0 (all asks) to 1.0 (all bids). Returns `None` if either
+    /// [`OrderBookSide`] has fewer than `depth` [`Level`]s.
+    pub fn imbalance(&self, depth: usize) -> Option<f64> {
+        let bid_amount = self.bids.aggregate(depth)?.amount;
+        let ask_amount = self.asks.aggregate(depth)?.amount;
+        Some(bid_amount / (bid_amount + ask_amount))
+    }
+
+    /// Calculate the [`spread_bps`] of the first [`Level`] of each sorted [`OrderBookSide`].
+    ///
+    /// Returns `None` if either [`OrderBookSide`] is empty.
+    pub fn spread_bps(&self) -> Option<f64> {
+        match (self.bids.levels.first(), self.asks.levels.first()) {
+            (Some(best_bid), Some(best_ask)) => spread_bps(best_bid.price, best_ask.price),
+            _ => None,
+        }
+    }
+
+    /// Calculate the total bid & ask amounts resting within `bps` basis points of the mid price,
+    /// returned as `(bid_size, ask_size)`.
+    ///
+    /// A one-sided book measures the band around its only best price, and an empty book has
+    /// zero depth on both sides.
+    pub fn depth_within_bps(&self, bps: f64) -> (f64, f64) {
+        let Some(mid_price) = self.mid_price() else {
+            return (0.0, 0.0);
+        };
+
+        let band = mid_price * bps / 10_000.0;
+        let bid_floor = mid_price - band;
+        let ask_ceiling = mid_price + band;
+
+        let bid_size = self
+            .bids
+            .levels
+            .iter()
+            .filter(|level| level.price >= bid_floor)
+            .map(|level| level.amount)
+            .sum();
+        let ask_size = self
+            .asks
+            .levels
+            .iter()
+            .filter(|level| level.price <= ask_ceiling)
+            .map(|level| level.amount)
+            .sum();
+
+        (bid_size, ask_size)
+    }
+
+    /// Calculate the average price paid (or received) to immediately execute a market order of
+    /// `size` on the provided [`Side`], walking the sorted asks for a [`Side::Buy`] and the
+    /// sorted bids for a [`Side::Sell`].
+    ///
+    /// Returns `None` if `size` is not positive, or the [`OrderBookSide`] is too thin to fill it.
+    pub fn impact_price(&self, side: Side, size: f64) -> Option<f64> {
+        match side {
+            Side::Buy => self.asks.impact_price(size),
+            Side::Sell => self.bids.impact_price(size),
+        }
+    }
+
+    /// Calculate the effective spread to execute a round trip of `size`, ie/ the buy
+    /// [`impact_price`](Self::impact_price) minus the sell [`impact_price`](Self::impact_price).
+    ///
+    /// Returns `None` if either [`OrderBookSide`] is too thin to fill `size`.
+    pub fn effective_spread(&self, size: f64) -> Option<f64> {
+        Some(self.impact_price(Side::Buy, size)? - self.impact_price(Side::Sell, size)?)
+    }
+
+    /// Generate the minimal [`BookPatch`] that transforms [`Self`] into the `next` [`OrderBook`].
+    pub fn diff(&self, next: &OrderBook) -> BookPatch {
+        BookPatch {
+            last_update_time: next.last_update_time,
+            bids: self.bids.diff(&next.bids),
+            asks: self.asks.diff(&next.asks),
+        }
+    }
+
+    /// Apply a [`BookPatch`] generated by [`OrderBook::diff`] to [`Self`], leaving each
+    /// [`OrderBookSide`] sorted.
+    pub fn apply(&mut self, patch: BookPatch) {
+        self.last_update_time = patch.last_update_time;
+        self.bids.upsert(patch.bids);
+        self.asks.upsert(patch.asks);
+        self.bids.sort();
+        self.asks.sort();
+    }
+
+    /// Generate the [`TopOfBook`] of this sorted [`OrderBook`].
+    ///
+    /// Returns `None` if either [`OrderBookSide`] is empty.
+    pub fn top(&self) -> Option<TopOfBook> {
+        match (self.bids.levels.first(), self.asks.levels.first()) {
+            (Some(best_bid), Some(best_ask)) => Some(TopOfBook {
+                time: self.last_update_time,
+                best_bid: best_bid.price,
+                best_bid_qty: best_bid.amount,
+                best_ask: best_ask.price,
+                best_ask_qty: best_ask.amount,
+            }),
+            _ => None,
+        }
+    }
 }
 
 /// Normalised Barter [`Level`]s for one [`Side`] of the [`OrderBook`].
@@ -192,6 +411,30 @@ impl OrderBookSide {
         };
     }
 
+    /// Generate the [`Level`]s that must be upserted into [`Self`] to transform it into the `next`
+    /// [`OrderBookSide`]. Removed [`Level`]s are represented with a zero amount.
+    pub fn diff(&self, next: &OrderBookSide) -> Vec<Level> {
+        let changed = next.levels.iter().copied().filter(|level| {
+            !self
+                .levels
+                .iter()
+                .any(|current| current.eq_price(level.price) && current.amount == level.amount)
+        });
+
+        let removed = self
+            .levels
+            .iter()
+            .filter(|current| {
+                !next
+                    .levels
+                    .iter()
+                    .any(|level| level.eq_price(current.price))
+            })
+            .map(|current| Level::new(current.price, 0.0));
+
+        changed.chain(removed).collect()
+    }
+
     /// Sort this [`OrderBookSide`] (bids are reversed).
     pub fn sort(&mut self) {
         // Sort Levels
@@ -202,6 +445,68 @@ impl OrderBookSide {
             self.levels.reverse();
         }
     }
+
+    /// Aggregate the top `depth` sorted [`Level`]s into a single [`Level`] of their volume
+    /// weighted average price & total amount.
+    ///
+    /// Returns `None` if `depth` is zero, exceeds the number of [`Level`]s, or the aggregated
+    /// amount is not positive.
+    fn aggregate(&self, depth: usize) -> Option<Level> {
+        if depth == 0 || depth > self.levels.len() {
+            return None;
+        }
+
+        let (notional, amount) =
+            self.levels[..depth]
+                .iter()
+                .fold((0.0, 0.0), |(notional, amount), level| {
+                    (notional + level.price * level.amount, amount + level.amount)
+                });
+
+        (amount > 0.0).then(|| Level::new(notional / amount, amount))
+    }
+
+    /// Calculate the average price to fill `size` by walking the sorted [`Level`]s from the
+    /// best price.
+    ///
+    /// Returns `None` if `size` is not positive, or exceeds the total amount of the [`Level`]s.
+    pub fn impact_price(&self, size: f64) -> Option<f64> {
+        if size <= 0.0 {
+            return None;
+        }
+
+        let mut remaining = size;
+        let mut notional = 0.0;
+        for level in &self.levels {
+            let fill = remaining.min(level.amount);
+            notional += fill * level.price;
+            remaining -= fill;
+
+            if remaining <= 0.0 {
+                return Some(notional / size);
+            }
+        }
+
+        None
+    }
+}
+
+/// Minimal level-diff between two [`OrderBook`]s, generated by [`OrderBook::diff`].
+///
+/// Each [`Level`] is upserted into the corresponding [`OrderBookSide`] when applied via
+/// [`OrderBook::apply`], so removed [`Level`]s have a zero amount.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct BookPatch {
+    pub last_update_time: DateTime<Utc>,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+impl BookPatch {
+    /// Determine if applying [`Self`] leaves the [`OrderBook`] [`Level`]s unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
 }
 
 /// Normalised Barter OrderBook [`Level`].
@@ -227,6 +532,7 @@ impl Ord for Level {
     }
 }
 
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for Level {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match self.price.partial_cmp(&other.price)? {
@@ -273,11 +579,19 @@ pub fn volume_weighted_mid_price(best_bid: Level, best_ask: Level) -> f64 {
         / (best_bid.amount + best_ask.amount)
 }
 
-impl From<(ExchangeId, Instrument, OrderBook)> for MarketIter<OrderBook> {
-    fn from((exchange_id, instrument, book): (ExchangeId, Instrument, OrderBook)) -> Self {
+/// Calculate the bid-ask spread in basis points of the mid price.
+///
+/// Returns `None` if the mid price is not positive, since the spread is then meaningless.
+pub fn spread_bps(best_bid_price: f64, best_ask_price: f64) -> Option<f64> {
+    let mid_price = mid_price(best_bid_price, best_ask_price);
+    (mid_price > 0.0).then(|| (best_ask_price - best_bid_price) / mid_price * 10_000.0)
+}
+
+impl From<(ExchangeId, Arc<Instrument>, OrderBook)> for MarketIter<OrderBook> {
+    fn from((exchange_id, instrument, book): (ExchangeId, Arc<Instrument>, OrderBook)) -> Self {
         Self(vec![Ok(MarketEvent {
             exchange_time: book.last_update_time,
-            received_time: Utc::now(),
+            received_time: clock::received_time(),
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: book,
@@ -383,6 +697,10 @@ mod tests {
 
     mod order_book {
         use super::*;
+        use crate::{
+            approx::{approx_eq, DEFAULT_EPSILON},
+            fixtures::{book, secs},
+        };
 
         #[test]
         fn test_mid_price() {
@@ -459,6 +777,154 @@ mod tests {
             }
         }
 
+        #[test]
+        fn test_best_bid_best_ask() {
+            struct TestCase {
+                input: OrderBook,
+                expected: (Option<Level>, Option<Level>),
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: empty book has no best bid or ask
+                    input: book(secs(0), vec![], vec![]),
+                    expected: (None, None),
+                },
+                TestCase {
+                    // TC1: one-sided book only has a best bid
+                    input: book(secs(0), vec![(100.0, 1.0), (50.0, 2.0)], vec![]),
+                    expected: (Some(Level::new(100.0, 1.0)), None),
+                },
+                TestCase {
+                    // TC2: two-sided book has a best bid & ask
+                    input: book(
+                        secs(0),
+                        vec![(100.0, 1.0), (50.0, 2.0)],
+                        vec![(200.0, 3.0), (300.0, 4.0)],
+                    ),
+                    expected: (Some(Level::new(100.0, 1.0)), Some(Level::new(200.0, 3.0))),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = (test.input.best_bid(), test.input.best_ask());
+                assert_eq!(actual, test.expected, "TC{index} failed")
+            }
+        }
+
+        #[test]
+        fn test_depth_within_bps() {
+            struct TestCase {
+                input: OrderBook,
+                bps: f64,
+                expected: (f64, f64),
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: empty book has no depth
+                    input: book(secs(0), vec![], vec![]),
+                    bps: 100.0,
+                    expected: (0.0, 0.0),
+                },
+                TestCase {
+                    // TC1: depth within 1% of the 100.0 mid price, inclusive of the band edges
+                    input: book(
+                        secs(0),
+                        vec![(99.5, 1.0), (99.0, 2.0), (98.5, 4.0)],
+                        vec![(100.5, 1.5), (101.0, 2.5), (101.5, 8.0)],
+                    ),
+                    bps: 100.0,
+                    expected: (3.0, 4.0),
+                },
+                TestCase {
+                    // TC2: narrower band of 0.5% only includes the best levels
+                    input: book(
+                        secs(0),
+                        vec![(99.5, 1.0), (99.0, 2.0), (98.5, 4.0)],
+                        vec![(100.5, 1.5), (101.0, 2.5), (101.5, 8.0)],
+                    ),
+                    bps: 50.0,
+                    expected: (1.0, 1.5),
+                },
+                TestCase {
+                    // TC3: one-sided book measures the band around the best bid
+                    input: book(
+                        secs(0),
+                        vec![(100.0, 1.0), (99.5, 2.0), (98.0, 4.0)],
+                        vec![],
+                    ),
+                    bps: 100.0,
+                    expected: (3.0, 0.0),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let (bid_size, ask_size) = test.input.depth_within_bps(test.bps);
+                assert!(
+                    approx_eq(bid_size, test.expected.0, DEFAULT_EPSILON)
+                        && approx_eq(ask_size, test.expected.1, DEFAULT_EPSILON),
+                    "TC{index} failed because actual != expected. \nActual: {:?}\nExpected: {:?}\n",
+                    (bid_size, ask_size),
+                    test.expected
+                );
+            }
+        }
+
+        #[test]
+        fn test_effective_spread() {
+            struct TestCase {
+                input: OrderBook,
+                size: f64,
+                expected: Option<f64>,
+            }
+
+            let bids = vec![(99.0, 1.0), (98.0, 2.0), (96.0, 5.0)];
+            let asks = vec![(101.0, 2.0), (102.0, 1.0), (105.0, 5.0)];
+
+            let tests = vec![
+                TestCase {
+                    // TC0: size filled by the best levels equals the top of book spread
+                    input: book(secs(0), bids.clone(), asks.clone()),
+                    size: 1.0,
+                    expected: Some(2.0),
+                },
+                TestCase {
+                    // TC1: size spanning multiple levels on both sides
+                    // buy 4.0 => (2*101 + 1*102 + 1*105) / 4 = 102.25
+                    // sell 4.0 => (1*99 + 2*98 + 1*96) / 4 = 97.75
+                    input: book(secs(0), bids.clone(), asks.clone()),
+                    size: 4.0,
+                    expected: Some(4.5),
+                },
+                TestCase {
+                    // TC2: size exceeding the total bid amount is too thin to fill
+                    input: book(secs(0), bids, asks.clone()),
+                    size: 8.5,
+                    expected: None,
+                },
+                TestCase {
+                    // TC3: one-sided book has no effective spread
+                    input: book(secs(0), vec![], asks),
+                    size: 1.0,
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = test.input.effective_spread(test.size);
+                match (actual, test.expected) {
+                    (Some(actual), Some(expected)) => {
+                        assert!(
+                            approx_eq(actual, expected, DEFAULT_EPSILON),
+                            "TC{index} failed"
+                        )
+                    }
+                    (actual, expected) => assert_eq!(actual, expected, "TC{index} failed"),
+                }
+            }
+        }
+
         #[test]
         fn test_volume_weighted_mid_price() {
             struct TestCase {
@@ -552,6 +1018,123 @@ mod tests {
                 )
             }
         }
+
+        #[test]
+        fn test_volume_weighted_mid_and_imbalance() {
+            struct TestCase {
+                depth: usize,
+                expected_mid: Option<f64>,
+                expected_imbalance: Option<f64>,
+            }
+
+            let book = OrderBook {
+                last_update_time: Default::default(),
+                bids: OrderBookSide::new(Side::Buy, vec![(100.0, 3.0), (99.0, 1.0), (98.0, 4.0)]),
+                asks: OrderBookSide::new(Side::Sell, vec![(101.0, 1.0), (103.0, 1.0)]),
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: zero depth is meaningless
+                    depth: 0,
+                    expected_mid: None,
+                    expected_imbalance: None,
+                },
+                TestCase {
+                    // TC1: top level equals the micro-price
+                    // mid => (100*1 + 101*3) / 4 = 100.75
+                    depth: 1,
+                    expected_mid: Some(100.75),
+                    expected_imbalance: Some(0.75),
+                },
+                TestCase {
+                    // TC2: top two levels
+                    // bid vwap => (100*3 + 99*1) / 4 = 99.75, ask vwap => (101*1 + 103*1) / 2 = 102
+                    // mid => (99.75*2 + 102*4) / 6 = 101.25
+                    depth: 2,
+                    expected_mid: Some(101.25),
+                    expected_imbalance: Some(4.0 / 6.0),
+                },
+                TestCase {
+                    // TC3: depth exceeds the available ask levels
+                    depth: 3,
+                    expected_mid: None,
+                    expected_imbalance: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let assert_approx =
+                    |actual: Option<f64>, expected: Option<f64>| match (actual, expected) {
+                        (Some(actual), Some(expected)) => {
+                            assert!(
+                                approx_eq(actual, expected, DEFAULT_EPSILON),
+                                "TC{index} failed because {actual} != {expected}"
+                            )
+                        }
+                        (actual, expected) => assert_eq!(actual, expected, "TC{index} failed"),
+                    };
+
+                assert_approx(book.volume_weighted_mid(test.depth), test.expected_mid);
+                assert_approx(book.imbalance(test.depth), test.expected_imbalance);
+            }
+
+            // One-sided book
+            let one_sided = OrderBook {
+                last_update_time: Default::default(),
+                bids: OrderBookSide::new(Side::Buy, vec![(100.0, 1.0)]),
+                asks: OrderBookSide::new(Side::Sell, Vec::<(f64, f64)>::new()),
+            };
+            assert_eq!(one_sided.volume_weighted_mid(1), None);
+            assert_eq!(one_sided.imbalance(1), None);
+        }
+
+        #[test]
+        fn test_diff_apply_round_trip() {
+            struct TestCase {
+                previous: OrderBook,
+                current: OrderBook,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: identical books
+                    previous: book(secs(0), vec![(100.0, 1.0)], vec![(101.0, 1.0)]),
+                    current: book(secs(1), vec![(100.0, 1.0)], vec![(101.0, 1.0)]),
+                },
+                TestCase {
+                    // TC1: changed, added & removed levels on each side
+                    previous: book(
+                        secs(0),
+                        vec![(100.0, 1.0), (99.0, 2.0), (98.0, 3.0)],
+                        vec![(101.0, 1.0), (102.0, 2.0)],
+                    ),
+                    current: book(
+                        secs(1),
+                        vec![(100.5, 4.0), (100.0, 2.0), (98.0, 3.0)],
+                        vec![(102.0, 2.0), (103.0, 5.0)],
+                    ),
+                },
+                TestCase {
+                    // TC2: every level removed
+                    previous: book(secs(0), vec![(100.0, 1.0)], vec![(101.0, 1.0)]),
+                    current: book(secs(1), vec![], vec![]),
+                },
+                TestCase {
+                    // TC3: every level added to an empty book
+                    previous: book(secs(0), vec![], vec![]),
+                    current: book(secs(1), vec![(100.0, 1.0), (99.0, 1.0)], vec![(101.0, 1.0)]),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let patch = test.previous.diff(&test.current);
+
+                let mut actual = test.previous;
+                actual.apply(patch);
+                assert_eq!(actual, test.current, "TC{index} failed");
+            }
+        }
     }
 
     mod order_book_side {
@@ -864,6 +1447,12 @@ mod tests {
                     input_two: Level::new(100, 100),
                     expected: Some(Ordering::Less),
                 },
+                TestCase {
+                    // TC9: Input One has NaN price -> None
+                    input_one: Level::new(f64::NAN, 100.0),
+                    input_two: Level::new(100.0, 100.0),
+                    expected: None,
+                },
             ];
 
             for (index, test) in tests.into_iter().enumerate() {
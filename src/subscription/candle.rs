@@ -1,17 +1,41 @@
-use super::SubKind;
-use chrono::{DateTime, Utc};
+use super::{load::StreamLoad, SubKind, SubKindId};
+use barter_integration::error::SocketError;
+use chrono::{DateTime, Datelike, Duration, Months, TimeZone, Utc, Weekday};
 use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Candle`]
 /// [`MarketEvent<T>`](crate::event::MarketEvent) events.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
-pub struct Candles;
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Candles {
+    pub interval: Interval,
+}
 
 impl SubKind for Candles {
     type Event = Candle;
+    const ID: Option<SubKindId> = Some(SubKindId::Candles);
+
+    fn interval(&self) -> Option<&Interval> {
+        Some(&self.interval)
+    }
+
+    fn typical_load(&self) -> StreamLoad {
+        StreamLoad::new(1.0, 400.0)
+    }
 }
 
 /// Normalised Barter OHLCV [`Candle`] model.
+///
+/// Exchanges stream intra-candle updates of an open [`Candle`] before it closes. The final update
+/// of a closed [`Candle`] has `is_final` set (see
+/// [`ClosedCandles`](crate::adapter::closed::ClosedCandles) to suppress intra-candle updates).
+///
+/// The `quote_volume` is only populated for exchanges that report it directly (eg/ Binance
+/// `quote_asset_volume`), since it is more accurate than `volume` × `close` given per-trade
+/// prices.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Candle {
     pub close_time: DateTime<Utc>,
@@ -20,5 +44,533 @@ pub struct Candle {
     pub low: f64,
     pub close: f64,
     pub volume: f64,
+    pub quote_volume: Option<f64>,
     pub trade_count: u64,
+    pub is_final: bool,
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`ContinuousCandle`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Continuous candles are generated for a futures [`ContractType`] rather than a specific
+/// contract symbol, and are stitched across contract rollovers by the exchange.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct ContinuousCandles {
+    pub contract_type: ContractType,
+    pub interval: Interval,
+}
+
+impl SubKind for ContinuousCandles {
+    type Event = ContinuousCandle;
+    const ID: Option<SubKindId> = Some(SubKindId::ContinuousCandles);
+
+    fn interval(&self) -> Option<&Interval> {
+        Some(&self.interval)
+    }
+
+    fn typical_load(&self) -> StreamLoad {
+        StreamLoad::new(4.0, 500.0)
+    }
+}
+
+/// Normalised Barter [`Candle`] generated for a futures [`ContractType`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct ContinuousCandle {
+    pub contract_type: ContractType,
+    pub candle: Candle,
+}
+
+/// Futures contract type that a [`ContinuousCandles`] [`Subscription`](super::Subscription)
+/// tracks across contract rollovers.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContractType {
+    Perpetual,
+    CurrentQuarter,
+    NextQuarter,
+}
+
+impl Display for ContractType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ContractType::Perpetual => "perpetual",
+                ContractType::CurrentQuarter => "current_quarter",
+                ContractType::NextQuarter => "next_quarter",
+            }
+        )
+    }
+}
+
+/// Duration of time covered by a [`Candle`].
+///
+/// Note that [`Interval::Month1`] ("1M") and [`Interval::Minute1`] ("1m") differ only by case.
+///
+/// ### Notes
+/// - [`Interval::from_str`] only accepts the canonical string of a standard [`Interval`], so a
+///   typo (eg/ "1hr") is rejected rather than silently failing at the exchange.
+/// - [`Interval::Custom`] is an escape hatch for exchange specific intervals, and must be
+///   constructed explicitly. It is (de)serialised as the raw interval string, so the wire format
+///   of every [`Interval`] is its exchange string.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum Interval {
+    #[serde(rename = "1m")]
+    Minute1,
+    #[serde(rename = "3m")]
+    Minute3,
+    #[serde(rename = "5m")]
+    Minute5,
+    #[serde(rename = "15m")]
+    Minute15,
+    #[serde(rename = "30m")]
+    Minute30,
+    #[serde(rename = "1h")]
+    Hour1,
+    #[serde(rename = "2h")]
+    Hour2,
+    #[serde(rename = "4h")]
+    Hour4,
+    #[serde(rename = "6h")]
+    Hour6,
+    #[serde(rename = "8h")]
+    Hour8,
+    #[serde(rename = "12h")]
+    Hour12,
+    #[serde(rename = "1d")]
+    Day1,
+    #[serde(rename = "3d")]
+    Day3,
+    #[serde(rename = "1w")]
+    Week1,
+    #[serde(rename = "1M")]
+    Month1,
+    /// Exchange specific interval not covered by the standard variants (eg/ "2w"), passed to the
+    /// exchange verbatim.
+    #[serde(untagged)]
+    Custom(String),
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Interval {
+    type Err = SocketError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Interval::STANDARD
+            .into_iter()
+            .find(|interval| interval.as_str() == input)
+            .ok_or_else(|| SocketError::Unsupported {
+                entity: "Interval",
+                item: input.to_owned(),
+            })
+    }
+}
+
+impl TryFrom<&str> for Interval {
+    type Error = SocketError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Interval::from_str(input)
+    }
+}
+
+impl Interval {
+    /// Every standard [`Interval`] (ie/ every variant except [`Interval::Custom`]).
+    pub const STANDARD: [Interval; 15] = [
+        Interval::Minute1,
+        Interval::Minute3,
+        Interval::Minute5,
+        Interval::Minute15,
+        Interval::Minute30,
+        Interval::Hour1,
+        Interval::Hour2,
+        Interval::Hour4,
+        Interval::Hour6,
+        Interval::Hour8,
+        Interval::Hour12,
+        Interval::Day1,
+        Interval::Day3,
+        Interval::Week1,
+        Interval::Month1,
+    ];
+
+    /// Canonical exchange string of the [`Interval`] (eg/ "1m", "4h", "1M").
+    pub fn as_str(&self) -> &str {
+        match self {
+            Interval::Minute1 => "1m",
+            Interval::Minute3 => "3m",
+            Interval::Minute5 => "5m",
+            Interval::Minute15 => "15m",
+            Interval::Minute30 => "30m",
+            Interval::Hour1 => "1h",
+            Interval::Hour2 => "2h",
+            Interval::Hour4 => "4h",
+            Interval::Hour6 => "6h",
+            Interval::Hour8 => "8h",
+            Interval::Hour12 => "12h",
+            Interval::Day1 => "1d",
+            Interval::Day3 => "3d",
+            Interval::Week1 => "1w",
+            Interval::Month1 => "1M",
+            Interval::Custom(interval) => interval,
+        }
+    }
+
+    /// Fixed [`Duration`] of the [`Interval`].
+    ///
+    /// Returns `None` for [`Interval::Month1`], since calendar months are not fixed-duration, and
+    /// for [`Interval::Custom`], since its duration is exchange specific.
+    pub fn duration(&self) -> Option<Duration> {
+        match self {
+            Interval::Minute1 => Some(Duration::minutes(1)),
+            Interval::Minute3 => Some(Duration::minutes(3)),
+            Interval::Minute5 => Some(Duration::minutes(5)),
+            Interval::Minute15 => Some(Duration::minutes(15)),
+            Interval::Minute30 => Some(Duration::minutes(30)),
+            Interval::Hour1 => Some(Duration::hours(1)),
+            Interval::Hour2 => Some(Duration::hours(2)),
+            Interval::Hour4 => Some(Duration::hours(4)),
+            Interval::Hour6 => Some(Duration::hours(6)),
+            Interval::Hour8 => Some(Duration::hours(8)),
+            Interval::Hour12 => Some(Duration::hours(12)),
+            Interval::Day1 => Some(Duration::days(1)),
+            Interval::Day3 => Some(Duration::days(3)),
+            Interval::Week1 => Some(Duration::weeks(1)),
+            Interval::Month1 | Interval::Custom(_) => None,
+        }
+    }
+
+    /// Start of the [`Interval`] bucket containing the provided time.
+    ///
+    /// ### Notes
+    /// - Sub-weekly intervals are aligned to the UTC epoch (eg/ [`Interval::Hour4`] buckets start
+    ///   at 00:00, 04:00, 08:00 UTC etc.).
+    /// - [`Interval::Week1`] buckets start on Monday 00:00 UTC (see
+    ///   [`Self::floor_with_week_start`] for a different start-of-week).
+    /// - [`Interval::Month1`] buckets start on the 1st of the month 00:00 UTC.
+    /// - [`Interval::Custom`] buckets cannot be determined, so the time is returned unchanged.
+    pub fn floor(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        self.floor_with_week_start(time, Weekday::Mon)
+    }
+
+    /// Start of the [`Interval`] bucket containing the provided time, with [`Interval::Week1`]
+    /// buckets starting on the provided `week_start` day 00:00 UTC.
+    ///
+    /// See [`Self::floor`] for the alignment of every other [`Interval`].
+    pub fn floor_with_week_start(&self, time: DateTime<Utc>, week_start: Weekday) -> DateTime<Utc> {
+        match self {
+            Interval::Week1 => {
+                let days_since_week_start = i64::from(
+                    (time.weekday().num_days_from_monday() + 7 - week_start.num_days_from_monday())
+                        % 7,
+                );
+                start_of_day(time) - Duration::days(days_since_week_start)
+            }
+            Interval::Month1 => Utc
+                .with_ymd_and_hms(time.year(), time.month(), 1, 0, 0, 0)
+                .single()
+                .unwrap_or(time),
+            Interval::Custom(_) => time,
+            interval => {
+                let duration = interval
+                    .duration()
+                    .map(|duration| duration.num_milliseconds())
+                    .unwrap_or(1);
+                let millis = time.timestamp_millis();
+                let floored = millis - millis.rem_euclid(duration);
+                Utc.timestamp_millis_opt(floored).single().unwrap_or(time)
+            }
+        }
+    }
+
+    /// Start of the next [`Interval`] bucket after the bucket containing the provided time (ie/
+    /// the exclusive end of the bucket containing it).
+    ///
+    /// Note that a time exactly on a bucket boundary belongs to the bucket it starts, so the next
+    /// boundary is one full [`Interval`] later.
+    pub fn ceil(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        self.ceil_with_week_start(time, Weekday::Mon)
+    }
+
+    /// Start of the next [`Interval`] bucket after the bucket containing the provided time, with
+    /// [`Interval::Week1`] buckets starting on the provided `week_start` day 00:00 UTC.
+    ///
+    /// See [`Self::ceil`].
+    pub fn ceil_with_week_start(&self, time: DateTime<Utc>, week_start: Weekday) -> DateTime<Utc> {
+        let floor = self.floor_with_week_start(time, week_start);
+        match (self, self.duration()) {
+            (_, Some(duration)) => floor + duration,
+            (Interval::Month1, None) => floor.checked_add_months(Months::new(1)).unwrap_or(floor),
+            (_, None) => floor,
+        }
+    }
+}
+
+/// Start of the UTC day containing the provided time.
+fn start_of_day(time: DateTime<Utc>) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(time.year(), time.month(), time.day(), 0, 0, 0)
+        .single()
+        .unwrap_or(time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, second)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_interval_floor_and_ceil() {
+        struct TestCase {
+            interval: Interval,
+            input: DateTime<Utc>,
+            expected_floor: DateTime<Utc>,
+            expected_ceil: DateTime<Utc>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Minute1 mid-minute
+                interval: Interval::Minute1,
+                input: time(2023, 5, 26, 12, 30, 45),
+                expected_floor: time(2023, 5, 26, 12, 30, 0),
+                expected_ceil: time(2023, 5, 26, 12, 31, 0),
+            },
+            TestCase {
+                // TC1: Minute15 exactly on a boundary belongs to the bucket it starts
+                interval: Interval::Minute15,
+                input: time(2023, 5, 26, 12, 45, 0),
+                expected_floor: time(2023, 5, 26, 12, 45, 0),
+                expected_ceil: time(2023, 5, 26, 13, 0, 0),
+            },
+            TestCase {
+                // TC2: Hour4 rolls over to the next day
+                interval: Interval::Hour4,
+                input: time(2023, 5, 26, 23, 59, 59),
+                expected_floor: time(2023, 5, 26, 20, 0, 0),
+                expected_ceil: time(2023, 5, 27, 0, 0, 0),
+            },
+            TestCase {
+                // TC3: Day1 rolls over to the next month
+                interval: Interval::Day1,
+                input: time(2023, 4, 30, 18, 0, 0),
+                expected_floor: time(2023, 4, 30, 0, 0, 0),
+                expected_ceil: time(2023, 5, 1, 0, 0, 0),
+            },
+            TestCase {
+                // TC4: Week1 on a Sunday floors to the previous Monday
+                interval: Interval::Week1,
+                input: time(2023, 5, 28, 22, 0, 0),
+                expected_floor: time(2023, 5, 22, 0, 0, 0),
+                expected_ceil: time(2023, 5, 29, 0, 0, 0),
+            },
+            TestCase {
+                // TC5: Week1 spanning the year boundary
+                interval: Interval::Week1,
+                input: time(2023, 1, 1, 8, 0, 0),
+                expected_floor: time(2022, 12, 26, 0, 0, 0),
+                expected_ceil: time(2023, 1, 2, 0, 0, 0),
+            },
+            TestCase {
+                // TC6: Month1 on a 31 day month
+                interval: Interval::Month1,
+                input: time(2023, 1, 31, 23, 59, 59),
+                expected_floor: time(2023, 1, 1, 0, 0, 0),
+                expected_ceil: time(2023, 2, 1, 0, 0, 0),
+            },
+            TestCase {
+                // TC7: Month1 on a leap year February
+                interval: Interval::Month1,
+                input: time(2024, 2, 29, 12, 0, 0),
+                expected_floor: time(2024, 2, 1, 0, 0, 0),
+                expected_ceil: time(2024, 3, 1, 0, 0, 0),
+            },
+            TestCase {
+                // TC8: Month1 December rolls over to the next year
+                interval: Interval::Month1,
+                input: time(2023, 12, 15, 0, 0, 0),
+                expected_floor: time(2023, 12, 1, 0, 0, 0),
+                expected_ceil: time(2024, 1, 1, 0, 0, 0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                test.interval.floor(test.input),
+                test.expected_floor,
+                "TC{} floor failed",
+                index
+            );
+            assert_eq!(
+                test.interval.ceil(test.input),
+                test.expected_ceil,
+                "TC{} ceil failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_interval_floor_and_ceil_with_week_start() {
+        struct TestCase {
+            interval: Interval,
+            week_start: Weekday,
+            input: DateTime<Utc>,
+            expected_floor: DateTime<Utc>,
+            expected_ceil: DateTime<Utc>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Week1 starting Sunday, on a Sunday floors to the same day
+                interval: Interval::Week1,
+                week_start: Weekday::Sun,
+                input: time(2023, 5, 28, 22, 0, 0),
+                expected_floor: time(2023, 5, 28, 0, 0, 0),
+                expected_ceil: time(2023, 6, 4, 0, 0, 0),
+            },
+            TestCase {
+                // TC1: Week1 starting Sunday, on a Saturday floors to the previous Sunday
+                interval: Interval::Week1,
+                week_start: Weekday::Sun,
+                input: time(2023, 5, 27, 23, 59, 59),
+                expected_floor: time(2023, 5, 21, 0, 0, 0),
+                expected_ceil: time(2023, 5, 28, 0, 0, 0),
+            },
+            TestCase {
+                // TC2: Week1 starting Monday matches Interval::floor
+                interval: Interval::Week1,
+                week_start: Weekday::Mon,
+                input: time(2023, 5, 28, 22, 0, 0),
+                expected_floor: time(2023, 5, 22, 0, 0, 0),
+                expected_ceil: time(2023, 5, 29, 0, 0, 0),
+            },
+            TestCase {
+                // TC3: Month1 ignores the start-of-week, aligning to the 1st of the month
+                interval: Interval::Month1,
+                week_start: Weekday::Sun,
+                input: time(2023, 5, 28, 22, 0, 0),
+                expected_floor: time(2023, 5, 1, 0, 0, 0),
+                expected_ceil: time(2023, 6, 1, 0, 0, 0),
+            },
+            TestCase {
+                // TC4: Minute1 differs from Month1 on the same time
+                interval: Interval::Minute1,
+                week_start: Weekday::Sun,
+                input: time(2023, 5, 28, 22, 0, 30),
+                expected_floor: time(2023, 5, 28, 22, 0, 0),
+                expected_ceil: time(2023, 5, 28, 22, 1, 0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                test.interval
+                    .floor_with_week_start(test.input, test.week_start),
+                test.expected_floor,
+                "TC{} floor failed",
+                index
+            );
+            assert_eq!(
+                test.interval
+                    .ceil_with_week_start(test.input, test.week_start),
+                test.expected_ceil,
+                "TC{} ceil failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_interval_month_and_minute_are_distinct() {
+        assert_eq!(Interval::Minute1.to_string(), "1m");
+        assert_eq!(Interval::Month1.to_string(), "1M");
+
+        assert_eq!(
+            serde_json::from_str::<Interval>(r#""1m""#).unwrap(),
+            Interval::Minute1
+        );
+        assert_eq!(
+            serde_json::from_str::<Interval>(r#""1M""#).unwrap(),
+            Interval::Month1
+        );
+        assert_eq!(serde_json::to_string(&Interval::Month1).unwrap(), r#""1M""#);
+    }
+
+    #[test]
+    fn test_interval_round_trip() {
+        for (index, interval) in Interval::STANDARD
+            .into_iter()
+            .chain([Interval::Custom("2w".to_string())])
+            .enumerate()
+        {
+            let json = serde_json::to_string(&interval).unwrap();
+            assert_eq!(json, format!(r#""{interval}""#), "TC{index} failed");
+            assert_eq!(
+                serde_json::from_str::<Interval>(&json).unwrap(),
+                interval,
+                "TC{index} failed"
+            );
+
+            // Only standard Intervals can be parsed from their exchange string
+            let parsed = Interval::from_str(interval.as_str());
+            match interval {
+                Interval::Custom(_) => assert!(parsed.is_err(), "TC{index} failed"),
+                _ => assert_eq!(parsed.unwrap(), interval, "TC{index} failed"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_interval_from_str() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Interval>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: canonical hour string
+                input: "1h",
+                expected: Some(Interval::Hour1),
+            },
+            TestCase {
+                // TC1: canonical month string is case sensitive
+                input: "1M",
+                expected: Some(Interval::Month1),
+            },
+            TestCase {
+                // TC2: typo is rejected
+                input: "1hr",
+                expected: None,
+            },
+            TestCase {
+                // TC3: wrong case is rejected
+                input: "1H",
+                expected: None,
+            },
+            TestCase {
+                // TC4: empty string is rejected
+                input: "",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                Interval::try_from(test.input).ok(),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
 }
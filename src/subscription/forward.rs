@@ -0,0 +1,301 @@
+use super::{load::StreamLoad, SubKind, SubKindId};
+use crate::{
+    clock,
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use serde::{
+    de::{Error, Unexpected},
+    Deserialize, Deserializer, Serialize,
+};
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that wraps a normalised [`SubKind`],
+/// forwarding each raw exchange frame as a [`RawFrame`] rather than normalising it.
+///
+/// Only the exchange specific `Route` (eg/
+/// [`BinanceTradeRoute`](crate::exchange::binance::trade::BinanceTradeRoute)) is parsed from
+/// each frame to determine the [`SubscriptionId`] it is routed by, bypassing the cost of full
+/// `Dto` deserialisation & normalisation. Useful for consumers that relay the frames, or
+/// re-parse them downstream.
+///
+/// ### Notes
+/// - [`ForwardRaw`] streams use the [`RawWebSocketParser`](crate::protocol::RawWebSocketParser),
+///   so each text frame is only parsed once, to determine its `Route`.
+/// - The exchange timestamp is not parsed, so the `exchange_time` of each yielded
+///   [`MarketEvent<RawFrame>`](MarketEvent) is equal to its `received_time`.
+#[derive(Deserialize, Serialize)]
+#[serde(transparent, bound = "Kind: Serialize + for<'d> Deserialize<'d>")]
+pub struct ForwardRaw<Kind, Route> {
+    pub kind: Kind,
+    #[serde(skip)]
+    phantom: PhantomData<fn() -> Route>,
+}
+
+impl<Kind, Route> ForwardRaw<Kind, Route> {
+    /// Construct a new [`Self`] that forwards the raw frames of the provided normalised
+    /// [`SubKind`].
+    pub fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<Kind, Route> SubKind for ForwardRaw<Kind, Route>
+where
+    Kind: SubKind,
+    Route: Debug,
+{
+    type Event = RawFrame;
+    const ID: Option<SubKindId> = Kind::ID;
+
+    fn typical_load(&self) -> StreamLoad {
+        self.kind.typical_load()
+    }
+}
+
+impl<Kind, Route> From<Kind> for ForwardRaw<Kind, Route> {
+    fn from(kind: Kind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl<Kind, Route> Clone for ForwardRaw<Kind, Route>
+where
+    Kind: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.kind.clone())
+    }
+}
+
+impl<Kind, Route> Copy for ForwardRaw<Kind, Route> where Kind: Copy {}
+
+impl<Kind, Route> Debug for ForwardRaw<Kind, Route>
+where
+    Kind: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ForwardRaw").field(&self.kind).finish()
+    }
+}
+
+impl<Kind, Route> PartialEq for ForwardRaw<Kind, Route>
+where
+    Kind: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+impl<Kind, Route> Eq for ForwardRaw<Kind, Route> where Kind: Eq {}
+
+impl<Kind, Route> PartialOrd for ForwardRaw<Kind, Route>
+where
+    Kind: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.kind.partial_cmp(&other.kind)
+    }
+}
+
+impl<Kind, Route> Ord for ForwardRaw<Kind, Route>
+where
+    Kind: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.kind.cmp(&other.kind)
+    }
+}
+
+impl<Kind, Route> Hash for ForwardRaw<Kind, Route>
+where
+    Kind: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.kind.hash(state)
+    }
+}
+
+/// Raw exchange frame forwarded by a [`ForwardRaw`] [`Subscription`](super::Subscription).
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct RawFrame {
+    pub bytes: Vec<u8>,
+}
+
+/// Raw exchange frame alongside the minimal exchange specific `Route` parsed from it to
+/// determine its [`SubscriptionId`].
+///
+/// Only deserialisable from the untouched text frames passed on by the
+/// [`RawWebSocketParser`](crate::protocol::RawWebSocketParser).
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct RoutedFrame<Route> {
+    pub route: Route,
+    pub frame: String,
+}
+
+impl<'de, Route> Deserialize<'de> for RoutedFrame<Route>
+where
+    Route: for<'r> Deserialize<'r>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Take ownership of the raw frame, and then only parse the Route from it
+        let frame = String::deserialize(deserializer)?;
+        match serde_json::from_str(&frame) {
+            Ok(route) => Ok(Self { route, frame }),
+            // Reject the frame as an Unexpected::Str so the RawWebSocketParser can recover it
+            Err(error) => Err(D::Error::invalid_value(
+                Unexpected::Str(&frame),
+                &format!("a routable frame ({error})").as_str(),
+            )),
+        }
+    }
+}
+
+impl<Route> Identifier<Option<SubscriptionId>> for RoutedFrame<Route>
+where
+    Route: Identifier<Option<SubscriptionId>>,
+{
+    fn id(&self) -> Option<SubscriptionId> {
+        self.route.id()
+    }
+}
+
+impl<Route> From<(ExchangeId, Arc<Instrument>, RoutedFrame<Route>)> for MarketIter<RawFrame> {
+    fn from(
+        (exchange_id, instrument, routed): (ExchangeId, Arc<Instrument>, RoutedFrame<Route>),
+    ) -> Self {
+        let received_time = clock::received_time();
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: received_time,
+            received_time,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: RawFrame {
+                bytes: routed.frame.into_bytes(),
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::{channel::BinanceChannel, spot::BinanceSpot, trade::BinanceTradeRoute},
+        protocol::{RawWebSocketParser, WsFrame},
+        subscription::{intern::intern, trade::PublicTrades, Map, Subscription},
+        transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+    };
+    use barter_integration::{
+        model::instrument::kind::InstrumentKind,
+        protocol::{websocket::WsMessage, StreamParser},
+        Transformer,
+    };
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_forward_raw_binance_trades_routing() {
+        type Kind = ForwardRaw<PublicTrades, BinanceTradeRoute>;
+
+        let btc = Subscription::<BinanceSpot, Kind>::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            ForwardRaw::new(PublicTrades),
+        ));
+        let eth = Subscription::<BinanceSpot, Kind>::from((
+            BinanceSpot::default(),
+            "eth",
+            "usdt",
+            InstrumentKind::Spot,
+            ForwardRaw::new(PublicTrades),
+        ));
+
+        // ForwardRaw Subscription re-uses the normalised exchange channel
+        let channel: BinanceChannel = btc.id();
+        assert_eq!(channel, BinanceChannel::TRADES);
+
+        let (ws_sink_tx, _) = mpsc::unbounded_channel();
+        let mut transformer =
+            StatelessTransformer::<BinanceSpot, Kind, RoutedFrame<BinanceTradeRoute>>::new(
+                ws_sink_tx,
+                Map::from_iter([
+                    (
+                        SubscriptionId::from("@trade|BTCUSDT"),
+                        intern(&btc.instrument),
+                    ),
+                    (
+                        SubscriptionId::from("@trade|ETHUSDT"),
+                        intern(&eth.instrument),
+                    ),
+                ]),
+            )
+            .await
+            .unwrap();
+
+        struct TestCase {
+            frame: &'static str,
+            expected: Option<Instrument>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BTCUSDT frame is routed to the btc Subscription
+                frame: r#"{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1,"p":"10000.19","q":"0.239000","T":1749354825200,"m":false,"M":true}"#,
+                expected: Some(btc.instrument.clone()),
+            },
+            TestCase {
+                // TC1: ETHUSDT frame is routed to the eth Subscription
+                frame: r#"{"e":"trade","E":1649324825173,"s":"ETHUSDT","t":2,"p":"1000.00","q":"1.000000","T":1749354825201,"m":true,"M":true}"#,
+                expected: Some(eth.instrument.clone()),
+            },
+            TestCase {
+                // TC2: frame of an unknown market is unidentifiable
+                frame: r#"{"e":"trade","E":1649324825173,"s":"XRPUSDT","t":3,"p":"0.33","q":"500","T":1749354825202,"m":true,"M":true}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = RawWebSocketParser::parse::<RoutedFrame<BinanceTradeRoute>>(Ok(
+                WsFrame::from(WsMessage::Text(test.frame.to_owned())),
+            ))
+            .unwrap()
+            .unwrap();
+
+            let actual = transformer.transform(input).remove(0);
+
+            match (actual, test.expected) {
+                (Ok(actual), Some(expected)) => {
+                    // Routed to the expected Instrument, with the frame forwarded untouched
+                    assert_eq!(*actual.instrument, expected, "TC{index} failed");
+                    assert_eq!(actual.kind.bytes, test.frame.as_bytes(), "TC{index} failed");
+                }
+                (Err(_), None) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
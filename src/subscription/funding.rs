@@ -0,0 +1,248 @@
+use super::{load::StreamLoad, SubKind, SubKindId};
+use crate::{error::DataError, exchange::ExchangeId};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter},
+    time::Duration,
+};
+
+/// Maximum difference between an exchange reported next funding time and the next funding time
+/// computed from the [`FundingSchedule`] before a [`DataError::FundingTimeMismatch`] is flagged.
+pub const FUNDING_TIME_TOLERANCE: Duration = Duration::from_secs(1);
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`FundingRate`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Funding only exists for [`InstrumentKind::Perpetual`] instruments, so
+/// [`Subscription`](super::Subscription)s to any other [`InstrumentKind`] are rejected during
+/// validation.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct FundingRates;
+
+impl SubKind for FundingRates {
+    type Event = FundingRate;
+    const ID: Option<SubKindId> = Some(SubKindId::FundingRates);
+
+    fn supports(&self, instrument_kind: InstrumentKind) -> bool {
+        instrument_kind == InstrumentKind::Perpetual
+    }
+
+    fn typical_load(&self) -> StreamLoad {
+        StreamLoad::new(0.33, 200.0)
+    }
+}
+
+impl Display for FundingRates {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "funding_rates")
+    }
+}
+
+/// Normalised Barter [`FundingRate`] model of a perpetual instrument, alongside the mark price
+/// the funding payment is computed from.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FundingRate {
+    pub rate: f64,
+    pub mark_price: f64,
+    pub next_funding_time: DateTime<Utc>,
+    pub time: DateTime<Utc>,
+}
+
+impl FundingRate {
+    /// Validate the exchange reported `next_funding_time` against the [`FundingSchedule`] of the
+    /// provided [`ExchangeId`], normalising it to the scheduled settlement time.
+    ///
+    /// [`FundingRate`]s of exchanges without a known [`FundingSchedule`] are returned unchanged.
+    pub fn validate(self, exchange: ExchangeId) -> Result<Self, DataError> {
+        let Some(schedule) = exchange.funding_schedule() else {
+            return Ok(self);
+        };
+
+        schedule
+            .validate_next_funding_time(self.next_funding_time, self.time)
+            .map(|next_funding_time| Self {
+                next_funding_time,
+                ..self
+            })
+    }
+}
+
+/// Funding settlement schedule of an [`InstrumentKind::Perpetual`] market.
+///
+/// Funding settles every `interval`, aligned to wall-clock UTC time starting `offset` past
+/// midnight.
+///
+/// eg/ [`FundingSchedule::EIGHT_HOURLY`] settles at 00:00, 08:00 & 16:00 UTC.
+///
+/// [`InstrumentKind::Perpetual`]: barter_integration::model::instrument::kind::InstrumentKind
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct FundingSchedule {
+    pub interval: Duration,
+    pub offset: Duration,
+}
+
+impl FundingSchedule {
+    /// [`FundingSchedule`] that settles at 00:00, 08:00 & 16:00 UTC.
+    pub const EIGHT_HOURLY: Self = Self::new(Duration::from_secs(8 * 60 * 60), Duration::ZERO);
+
+    /// Construct a new [`Self`] settling every `interval`, starting `offset` past midnight UTC.
+    pub const fn new(interval: Duration, offset: Duration) -> Self {
+        Self { interval, offset }
+    }
+
+    /// Compute the next funding settlement time strictly after the provided `now`.
+    pub fn next_funding_time(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let interval = self.interval.as_millis() as i64;
+        let offset = self.offset.as_millis() as i64;
+
+        // Interval must be non-zero, else every instant is a settlement
+        if interval == 0 {
+            return now;
+        }
+
+        let elapsed_since_anchor = now.timestamp_millis() - offset;
+        let next_period = elapsed_since_anchor.div_euclid(interval) + 1;
+
+        Utc.timestamp_millis_opt(next_period * interval + offset)
+            .single()
+            .unwrap_or(now)
+    }
+
+    /// Validate an exchange reported next funding time against the next funding time computed
+    /// from this [`FundingSchedule`].
+    ///
+    /// Returns the computed next funding time if the reported value is within
+    /// [`FUNDING_TIME_TOLERANCE`], else a [`DataError::FundingTimeMismatch`].
+    pub fn validate_next_funding_time(
+        &self,
+        reported: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<DateTime<Utc>, DataError> {
+        let expected = self.next_funding_time(now);
+        let difference = (reported - expected).num_milliseconds().unsigned_abs();
+
+        if difference <= FUNDING_TIME_TOLERANCE.as_millis() as u64 {
+            Ok(expected)
+        } else {
+            Err(DataError::FundingTimeMismatch { expected, reported })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u32, minute: u32, second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2023, 5, 26, hour, minute, second)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_next_funding_time_eight_hourly() {
+        struct TestCase {
+            now: DateTime<Utc>,
+            expected: DateTime<Utc>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: midnight settlement is not the next settlement
+                now: time(0, 0, 0),
+                expected: time(8, 0, 0),
+            },
+            TestCase {
+                // TC1: just after midnight
+                now: time(0, 0, 1),
+                expected: time(8, 0, 0),
+            },
+            TestCase {
+                // TC2: just before 08:00
+                now: time(7, 59, 59),
+                expected: time(8, 0, 0),
+            },
+            TestCase {
+                // TC3: mid afternoon
+                now: time(12, 30, 0),
+                expected: time(16, 0, 0),
+            },
+            TestCase {
+                // TC4: late evening rolls over to midnight of the next day
+                now: time(23, 15, 0),
+                expected: Utc.with_ymd_and_hms(2023, 5, 27, 0, 0, 0).unwrap(),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = FundingSchedule::EIGHT_HOURLY.next_funding_time(test.now);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_next_funding_time_with_offset() {
+        let schedule = FundingSchedule::new(
+            Duration::from_secs(8 * 60 * 60),
+            Duration::from_secs(4 * 60 * 60),
+        );
+
+        assert_eq!(schedule.next_funding_time(time(1, 0, 0)), time(4, 0, 0));
+        assert_eq!(schedule.next_funding_time(time(4, 0, 0)), time(12, 0, 0));
+        assert_eq!(
+            schedule.next_funding_time(time(21, 0, 0)),
+            Utc.with_ymd_and_hms(2023, 5, 27, 4, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_validate_next_funding_time() {
+        struct TestCase {
+            reported: DateTime<Utc>,
+            now: DateTime<Utc>,
+            expected: Result<DateTime<Utc>, DataError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: reported next funding time matches the schedule
+                reported: time(16, 0, 0),
+                now: time(9, 0, 0),
+                expected: Ok(time(16, 0, 0)),
+            },
+            TestCase {
+                // TC1: reported next funding time within tolerance
+                reported: time(15, 59, 59),
+                now: time(9, 0, 0),
+                expected: Ok(time(16, 0, 0)),
+            },
+            TestCase {
+                // TC2: reported next funding time is a stale settlement
+                reported: time(8, 0, 0),
+                now: time(9, 0, 0),
+                expected: Err(DataError::FundingTimeMismatch {
+                    expected: time(16, 0, 0),
+                    reported: time(8, 0, 0),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual =
+                FundingSchedule::EIGHT_HOURLY.validate_next_funding_time(test.reported, test.now);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::hash::{BuildHasherDefault, Hasher};
+
+/// [`BuildHasher`](std::hash::BuildHasher) used by [`Map`](super::Map) to hash
+/// [`SubscriptionId`](barter_integration::model::SubscriptionId)s.
+pub type SubscriptionIdBuildHasher = BuildHasherDefault<SubscriptionIdHasher>;
+
+/// Multiplier used by the [`SubscriptionIdHasher`] (the FxHash multiplier used by rustc).
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+/// Fast non-cryptographic [`Hasher`] for short [`SubscriptionId`] strings (eg/
+/// "@trade|BTCUSDT"), based on the FxHash algorithm used by rustc.
+///
+/// Every incoming exchange message is matched against the [`Map`](super::Map) of actioned
+/// [`SubscriptionId`]s, so the default SipHash [`Hasher`] is a measurable cost on high-rate feeds.
+///
+/// ### Notes
+/// FxHash is not resistant to HashDoS. This is acceptable since the [`Map`](super::Map) keys are
+/// generated locally when subscribing, and incoming messages are only ever used for lookups.
+///
+/// [`SubscriptionId`]: barter_integration::model::SubscriptionId
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct SubscriptionIdHasher {
+    hash: u64,
+}
+
+impl SubscriptionIdHasher {
+    #[inline]
+    fn add(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for SubscriptionIdHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(chunk);
+            self.add(u64::from_le_bytes(word));
+        }
+
+        let remainder = chunks.remainder();
+        if !remainder.is_empty() {
+            let mut word = [0u8; 8];
+            word[..remainder.len()].copy_from_slice(remainder);
+            self.add(u64::from_le_bytes(word));
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, value: u8) {
+        self.add(u64::from(value));
+    }
+
+    #[inline]
+    fn write_u32(&mut self, value: u32) {
+        self.add(u64::from(value));
+    }
+
+    #[inline]
+    fn write_u64(&mut self, value: u64) {
+        self.add(value);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, value: usize) {
+        self.add(value as u64);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hash::BuildHasher;
+
+    #[test]
+    fn test_subscription_id_hasher() {
+        let hasher = SubscriptionIdBuildHasher::default();
+
+        // Equal inputs produce equal hashes
+        assert_eq!(
+            hasher.hash_one("@trade|BTCUSDT"),
+            hasher.hash_one("@trade|BTCUSDT")
+        );
+
+        // Inputs differing only in the trailing partial word produce different hashes
+        assert_ne!(
+            hasher.hash_one("@trade|BTCUSDT"),
+            hasher.hash_one("@trade|ETHUSDT")
+        );
+        assert_ne!(hasher.hash_one("12345678a"), hasher.hash_one("12345678b"));
+    }
+}
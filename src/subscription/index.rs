@@ -0,0 +1,33 @@
+use super::{load::StreamLoad, SubKind, SubKindId};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`IndexPrice`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Index prices only exist for derivative instruments, so [`InstrumentKind::Spot`]
+/// [`Subscription`](super::Subscription)s are rejected during validation.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct IndexPrices;
+
+impl SubKind for IndexPrices {
+    type Event = IndexPrice;
+    const ID: Option<SubKindId> = Some(SubKindId::IndexPrices);
+
+    fn supports(&self, instrument_kind: InstrumentKind) -> bool {
+        instrument_kind != InstrumentKind::Spot
+    }
+
+    fn typical_load(&self) -> StreamLoad {
+        StreamLoad::new(1.0, 200.0)
+    }
+}
+
+/// Normalised Barter [`IndexPrice`] model, the composite underlying price of a derivative
+/// instrument (eg/ used to derive the mark price & funding rate).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct IndexPrice {
+    pub price: f64,
+    pub time: DateTime<Utc>,
+}
@@ -0,0 +1,96 @@
+use barter_integration::model::instrument::Instrument;
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+};
+
+/// Pool of interned [`Instrument`]s, allowing every [`MarketEvent`](crate::event::MarketEvent)
+/// & routing [`Map`](super::Map) entry of an [`Instrument`] to share a single allocation via a
+/// cheap [`Arc`] clone, rather than duplicating its symbol strings.
+///
+/// ### Notes
+/// Interned [`Instrument`]s are retained for the lifetime of the [`InstrumentInterner`], which
+/// is bounded by the size of the subscribed universe.
+#[derive(Debug, Default)]
+pub struct InstrumentInterner {
+    instruments: Mutex<HashSet<Arc<Instrument>>>,
+}
+
+impl InstrumentInterner {
+    /// Construct a new empty [`Self`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shared [`InstrumentInterner`] used when mapping [`Subscription`](super::Subscription)s.
+    pub fn global() -> &'static Self {
+        static GLOBAL: OnceLock<InstrumentInterner> = OnceLock::new();
+        GLOBAL.get_or_init(Self::new)
+    }
+
+    /// Intern the provided [`Instrument`], returning the shared [`Arc<Instrument>`] of any equal
+    /// [`Instrument`] that has already been interned.
+    pub fn intern(&self, instrument: &Instrument) -> Arc<Instrument> {
+        let mut instruments = self
+            .instruments
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        match instruments.get(instrument) {
+            Some(interned) => Arc::clone(interned),
+            None => {
+                let interned = Arc::new(instrument.clone());
+                instruments.insert(Arc::clone(&interned));
+                interned
+            }
+        }
+    }
+
+    /// Number of distinct interned [`Instrument`]s.
+    pub fn len(&self) -> usize {
+        self.instruments
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Determine if no [`Instrument`]s have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Intern the provided [`Instrument`] using the [`InstrumentInterner::global`] pool.
+pub fn intern(instrument: &Instrument) -> Arc<Instrument> {
+    InstrumentInterner::global().intern(instrument)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_interned_instruments_share_storage() {
+        let interner = InstrumentInterner::new();
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        let btc_a = interner.intern(&btc);
+        let btc_b = interner.intern(&btc.clone());
+        let eth_a = interner.intern(&eth);
+
+        // Equal Instruments compare equal & share storage
+        assert_eq!(btc_a, btc_b);
+        assert!(Arc::ptr_eq(&btc_a, &btc_b));
+
+        // Distinct Instruments do not
+        assert_ne!(btc_a, eth_a);
+        assert!(!Arc::ptr_eq(&btc_a, &eth_a));
+        assert_eq!(interner.len(), 2);
+
+        // Interned Instruments convert back into equal owned Instruments
+        assert_eq!(Instrument::clone(&btc_a), btc);
+        assert_eq!(*eth_a, eth);
+    }
+}
@@ -1,15 +1,27 @@
-use super::SubKind;
-use barter_integration::model::Side;
+use super::{load::StreamLoad, SubKind, SubKindId};
+use barter_integration::model::{instrument::kind::InstrumentKind, Side};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Liquidation`]
 /// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Forced liquidations only exist for derivative instruments, so [`InstrumentKind::Spot`]
+/// [`Subscription`](super::Subscription)s are rejected during validation.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct Liquidations;
 
 impl SubKind for Liquidations {
     type Event = Liquidation;
+    const ID: Option<SubKindId> = Some(SubKindId::Liquidations);
+
+    fn supports(&self, instrument_kind: InstrumentKind) -> bool {
+        instrument_kind != InstrumentKind::Spot
+    }
+
+    fn typical_load(&self) -> StreamLoad {
+        StreamLoad::new(0.1, 300.0)
+    }
 }
 
 /// Normalised Barter [`Liquidation`] model.
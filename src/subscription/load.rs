@@ -0,0 +1,214 @@
+use super::{SubKind, Subscription};
+use crate::exchange::Connector;
+use serde::{Deserialize, Serialize};
+use std::ops::Add;
+
+/// Typical load generated by a single [`Subscription`] to a [`SubKind`] (see
+/// [`SubKind::typical_load`]).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct StreamLoad {
+    pub messages_per_sec: f64,
+    pub bytes_per_message: f64,
+    /// Determines if the stream is initialised from an HTTP snapshot (eg/ an
+    /// [`OrderBook`](super::book::OrderBook)), weighted by
+    /// [`Connector::snapshot_weight`].
+    pub snapshot: bool,
+}
+
+impl Default for StreamLoad {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl StreamLoad {
+    /// Conservative [`StreamLoad`] of a [`SubKind`] without a more specific typical load.
+    pub const DEFAULT: Self = Self::new(1.0, 250.0);
+
+    /// Construct a new [`Self`] that is not initialised from an HTTP snapshot.
+    pub const fn new(messages_per_sec: f64, bytes_per_message: f64) -> Self {
+        Self {
+            messages_per_sec,
+            bytes_per_message,
+            snapshot: false,
+        }
+    }
+
+    /// Mark [`Self`] as initialised from an HTTP snapshot.
+    pub const fn with_snapshot(self) -> Self {
+        Self {
+            snapshot: true,
+            ..self
+        }
+    }
+}
+
+/// Projected load of a collection of [`Subscription`]s, used to size infrastructure & verify
+/// exchange rate limits before deploying a large universe.
+///
+/// ### Notes
+/// - Figures are estimates derived from the [`SubKind::typical_load`] of a liquid instrument, so
+///   quiet instruments will generate less load, and volatile markets more.
+/// - Estimates of different exchanges or [`SubKind`]s can be combined with `+`.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct LoadEstimate {
+    pub subscriptions: usize,
+    pub connections: usize,
+    pub messages_per_sec: f64,
+    pub bytes_per_sec: f64,
+    /// Total HTTP request weight of the snapshots fetched when initialising every
+    /// [`Subscription`] (eg/ on start-up, or after each re-connection).
+    pub snapshot_weight: u32,
+}
+
+impl LoadEstimate {
+    /// Estimate the load of the provided [`Subscription`]s, actioned over connections of at most
+    /// `capacity` [`Subscription`]s each (eg/ the capacity of a
+    /// [`ConnectionManager`](crate::streams::manager::ConnectionManager)).
+    pub fn new<Exchange, Kind>(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        capacity: usize,
+    ) -> Self
+    where
+        Exchange: Connector,
+        Kind: SubKind,
+    {
+        subscriptions.iter().fold(
+            Self {
+                subscriptions: subscriptions.len(),
+                connections: subscriptions.len().div_ceil(capacity.max(1)),
+                ..Self::default()
+            },
+            |mut estimate, subscription| {
+                let load = subscription.kind.typical_load();
+                estimate.messages_per_sec += load.messages_per_sec;
+                estimate.bytes_per_sec += load.messages_per_sec * load.bytes_per_message;
+                if load.snapshot {
+                    estimate.snapshot_weight +=
+                        Exchange::snapshot_weight(subscription.kind.depth());
+                }
+                estimate
+            },
+        )
+    }
+}
+
+impl Add for LoadEstimate {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            subscriptions: self.subscriptions + rhs.subscriptions,
+            connections: self.connections + rhs.connections,
+            messages_per_sec: self.messages_per_sec + rhs.messages_per_sec,
+            bytes_per_sec: self.bytes_per_sec + rhs.bytes_per_sec,
+            snapshot_weight: self.snapshot_weight + rhs.snapshot_weight,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{
+            binance::{futures::BinanceFuturesUsd, spot::BinanceSpot},
+            coinbase::Coinbase,
+        },
+        subscription::{book::OrderBooksL2Depth, trade::PublicTrades},
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_load_estimate() {
+        let spot_books = ["btc", "eth", "sol"]
+            .into_iter()
+            .zip([100, 500, 1000])
+            .map(|(base, depth)| {
+                Subscription::from((
+                    BinanceSpot::default(),
+                    base,
+                    "usdt",
+                    InstrumentKind::Spot,
+                    OrderBooksL2Depth { depth },
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let futures_books = [50, 1000]
+            .into_iter()
+            .map(|depth| {
+                Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Perpetual,
+                    OrderBooksL2Depth { depth },
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let trades = ["btc", "eth", "sol", "ada"]
+            .into_iter()
+            .map(|base| {
+                Subscription::from((Coinbase, base, "usd", InstrumentKind::Spot, PublicTrades))
+            })
+            .collect::<Vec<_>>();
+
+        struct TestCase {
+            input: LoadEstimate,
+            expected: LoadEstimate,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BinanceSpot books weighted by depth, split across connections of two
+                input: LoadEstimate::new(&spot_books, 2),
+                expected: LoadEstimate {
+                    subscriptions: 3,
+                    connections: 2,
+                    messages_per_sec: 30.0,
+                    bytes_per_sec: 30_000.0,
+                    snapshot_weight: 5 + 25 + 50,
+                },
+            },
+            TestCase {
+                // TC1: BinanceFuturesUsd books use the futures snapshot weights
+                input: LoadEstimate::new(&futures_books, 10),
+                expected: LoadEstimate {
+                    subscriptions: 2,
+                    connections: 1,
+                    messages_per_sec: 20.0,
+                    bytes_per_sec: 20_000.0,
+                    snapshot_weight: 2 + 20,
+                },
+            },
+            TestCase {
+                // TC2: trades are not initialised from a snapshot
+                input: LoadEstimate::new(&trades, 1),
+                expected: LoadEstimate {
+                    subscriptions: 4,
+                    connections: 4,
+                    messages_per_sec: 20.0,
+                    bytes_per_sec: 4_000.0,
+                    snapshot_weight: 0,
+                },
+            },
+            TestCase {
+                // TC3: estimates of different exchanges & SubKinds are combined
+                input: LoadEstimate::new(&spot_books, 2) + LoadEstimate::new(&trades, 1),
+                expected: LoadEstimate {
+                    subscriptions: 7,
+                    connections: 6,
+                    messages_per_sec: 50.0,
+                    bytes_per_sec: 34_000.0,
+                    snapshot_weight: 80,
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(test.input, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use self::id::SubscriptionIdBuildHasher;
 use crate::exchange::StreamSelector;
 use barter_integration::{
     error::SocketError,
@@ -8,21 +9,70 @@ use barter_integration::{
     protocol::websocket::WsMessage,
     Validator,
 };
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{value::MapAccessDeserializer, DeserializeOwned, MapAccess, Visitor},
+    Deserialize, Serialize,
+};
 use std::{
     collections::HashMap,
     fmt::{Debug, Display, Formatter},
+    marker::PhantomData,
+    str::FromStr,
+    sync::Arc,
 };
 
 /// OrderBook [`SubKind`]s and the associated Barter output data models.
 pub mod book;
 
-/// Candle [`SubKind`] and the associated Barter output data model.
+/// Candle [`SubKind`]s and the associated Barter output data models.
 pub mod candle;
 
+/// [`ForwardRaw`](forward::ForwardRaw) [`SubKind`] wrapper that forwards raw exchange frames,
+/// only parsing what is needed to route them.
+pub mod forward;
+
+/// Funding rate [`SubKind`] and the associated Barter output data model, plus the perpetual
+/// funding settlement [`FundingSchedule`](funding::FundingSchedule) used to compute and validate
+/// the next funding time of an exchange.
+pub mod funding;
+
+/// Fast [`Hasher`](std::hash::Hasher) used by [`Map`] to lookup the [`SubscriptionId`] of every
+/// incoming exchange message.
+pub mod id;
+
+/// Index price [`SubKind`] and the associated Barter output data model.
+pub mod index;
+
+/// [`InstrumentInterner`](intern::InstrumentInterner) used to share a single allocation of each
+/// [`Instrument`] between every event & routing entry associated with it.
+pub mod intern;
+
 /// Liquidation [`SubKind`] and the associated Barter output data model.
 pub mod liquidation;
 
+/// [`LoadEstimate`](load::LoadEstimate) of the expected message rate, bandwidth & snapshot
+/// request weight of a collection of [`Subscription`]s, used for capacity planning.
+pub mod load;
+
+/// [`Raw`](raw::Raw) [`SubKind`] wrapper that yields the exchange specific data transfer object
+/// alongside each normalised event.
+pub mod raw;
+
+/// [`ResumeFrom`](resume::ResumeFrom) last-seen sequences used to resume exchange streams after
+/// a brief disconnect.
+pub mod resume;
+
+/// Normalised [`InstrumentStatus`](status::InstrumentStatus) model used to communicate changes in
+/// the trading status of an instrument (eg/ halts & delistings).
+pub mod status;
+
+/// Shorthand `"{exchange}:{base}/{quote}:{kind}"` string form of a [`Subscription`] used in
+/// config files.
+pub mod shorthand;
+
+/// 24h rolling statistics ticker [`SubKind`] and the associated Barter output data model.
+pub mod ticker;
+
 /// Public trade [`SubKind`] and the associated Barter output data model.
 pub mod trade;
 
@@ -32,11 +82,127 @@ where
     Self: Debug + Clone,
 {
     type Event: Debug;
+
+    /// Normalised [`SubKindId`] of [`Self`], used to label the events of [`Self`] at runtime
+    /// (eg/ by [`Metrics`](crate::streams::metrics::Metrics)).
+    ///
+    /// Defaults to `None`, meaning [`Self`] has no normalised [`SubKindId`].
+    const ID: Option<SubKindId> = None;
+
+    /// Number of [`Level`](book::Level)s per side requested for the
+    /// [`OrderBook`](book::OrderBook)s yielded by [`Self`].
+    ///
+    /// Defaults to `None`, meaning the exchange default depth is used.
+    fn depth(&self) -> Option<u16> {
+        None
+    }
+
+    /// [`Interval`](candle::Interval) of the [`Candle`](candle::Candle)s yielded by [`Self`].
+    ///
+    /// Defaults to `None`, meaning [`Self`] does not yield interval specific events.
+    fn interval(&self) -> Option<&candle::Interval> {
+        None
+    }
+
+    /// Determine if [`Self`] is available for instruments of the provided [`InstrumentKind`]
+    /// (eg/ index prices only exist for derivatives).
+    ///
+    /// Defaults to `true`, meaning every [`InstrumentKind`] is supported.
+    fn supports(&self, _instrument_kind: InstrumentKind) -> bool {
+        true
+    }
+
+    /// Typical [`StreamLoad`](load::StreamLoad) generated by a single [`Subscription`] to
+    /// [`Self`] for a liquid instrument, used by a [`LoadEstimate`](load::LoadEstimate).
+    ///
+    /// Defaults to [`StreamLoad::DEFAULT`](load::StreamLoad::DEFAULT).
+    fn typical_load(&self) -> load::StreamLoad {
+        load::StreamLoad::DEFAULT
+    }
+}
+
+/// Unique identifier of a normalised [`SubKind`], used to enumerate the
+/// [`SubKind`]s an exchange supports at runtime (see
+/// [`ExchangeId::supported_stream_kinds`](crate::exchange::ExchangeId::supported_stream_kinds)).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubKindId {
+    PublicTrades,
+    TaggedTrades,
+    OrderBooksL1,
+    OrderBooksL2,
+    OrderBooksL2Depth,
+    OrderBooksTop,
+    Liquidations,
+    FundingRates,
+    IndexPrices,
+    Candles,
+    ContinuousCandles,
+    Tickers,
+}
+
+impl SubKindId {
+    /// Every [`SubKindId`].
+    pub const ALL: [SubKindId; 12] = [
+        SubKindId::PublicTrades,
+        SubKindId::TaggedTrades,
+        SubKindId::OrderBooksL1,
+        SubKindId::OrderBooksL2,
+        SubKindId::OrderBooksL2Depth,
+        SubKindId::OrderBooksTop,
+        SubKindId::Liquidations,
+        SubKindId::FundingRates,
+        SubKindId::IndexPrices,
+        SubKindId::Candles,
+        SubKindId::ContinuousCandles,
+        SubKindId::Tickers,
+    ];
+
+    /// Return the &str representation of this [`SubKindId`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubKindId::PublicTrades => "public_trades",
+            SubKindId::TaggedTrades => "tagged_trades",
+            SubKindId::OrderBooksL1 => "order_books_l1",
+            SubKindId::OrderBooksL2 => "order_books_l2",
+            SubKindId::OrderBooksL2Depth => "order_books_l2_depth",
+            SubKindId::OrderBooksTop => "order_books_top",
+            SubKindId::Liquidations => "liquidations",
+            SubKindId::FundingRates => "funding_rates",
+            SubKindId::IndexPrices => "index_prices",
+            SubKindId::Candles => "candles",
+            SubKindId::ContinuousCandles => "continuous_candles",
+            SubKindId::Tickers => "tickers",
+        }
+    }
+}
+
+impl Display for SubKindId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for SubKindId {
+    type Err = SocketError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == input)
+            .ok_or_else(|| SocketError::Unsupported {
+                entity: "SubKindId",
+                item: input.to_owned(),
+            })
+    }
 }
 
 /// Barter [`Subscription`] used to subscribe to a [`SubKind`] for a particular exchange
 /// [`Instrument`].
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+///
+/// Deserialises from either the struct form, or the shorthand string form (eg/
+/// `"binance_spot:eth/usdt:candles:1m"`, see [`shorthand::parse`]).
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
 pub struct Subscription<Exchange, Kind> {
     pub exchange: Exchange,
     #[serde(flatten)]
@@ -45,6 +211,76 @@ pub struct Subscription<Exchange, Kind> {
     pub kind: Kind,
 }
 
+/// Struct form of a [`Subscription`], used to deserialise a [`Subscription`] that is not in the
+/// shorthand string form.
+#[derive(Deserialize)]
+struct SubscriptionStruct<Exchange, Kind> {
+    exchange: Exchange,
+    #[serde(flatten)]
+    instrument: Instrument,
+    #[serde(alias = "type")]
+    kind: Kind,
+}
+
+impl<'de, Exchange, Kind> Deserialize<'de> for Subscription<Exchange, Kind>
+where
+    Exchange: DeserializeOwned,
+    Kind: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SubscriptionVisitor<Exchange, Kind>(PhantomData<(Exchange, Kind)>);
+
+        impl<'de, Exchange, Kind> Visitor<'de> for SubscriptionVisitor<Exchange, Kind>
+        where
+            Exchange: DeserializeOwned,
+            Kind: DeserializeOwned,
+        {
+            type Value = Subscription<Exchange, Kind>;
+
+            fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("Subscription struct or shorthand string")
+            }
+
+            fn visit_str<E>(self, input: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                shorthand::parse(input).map_err(E::custom)
+            }
+
+            fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let SubscriptionStruct {
+                    exchange,
+                    instrument,
+                    kind,
+                } = SubscriptionStruct::deserialize(MapAccessDeserializer::new(map))?;
+
+                Ok(Subscription::new(exchange, instrument, kind))
+            }
+        }
+
+        deserializer.deserialize_any(SubscriptionVisitor(PhantomData))
+    }
+}
+
+impl<Exchange, Kind> FromStr for Subscription<Exchange, Kind>
+where
+    Exchange: DeserializeOwned,
+    Kind: DeserializeOwned,
+{
+    type Err = SocketError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        shorthand::parse(input)
+    }
+}
+
 impl<Exchange, Kind> Display for Subscription<Exchange, Kind>
 where
     Exchange: Display,
@@ -78,18 +314,49 @@ where
 
 impl<Exchange, Kind> Subscription<Exchange, Kind> {
     /// Constructs a new [`Subscription`] using the provided configuration.
+    ///
+    /// The base & quote [`Symbol`]s of the [`Instrument`] are normalised to their canonical form
+    /// (see [`canonical_symbol`]), so equivalent instruments constructed with a different casing
+    /// or stray separators (eg/ `"BTC/"` & `"btc"`) are equal & hash identically. Each exchange
+    /// applies its required casing when generating its market identifier.
     pub fn new<I>(exchange: Exchange, instrument: I, kind: Kind) -> Self
     where
         I: Into<Instrument>,
     {
+        let Instrument {
+            base,
+            quote,
+            kind: instrument_kind,
+        } = instrument.into();
+
         Self {
             exchange,
-            instrument: instrument.into(),
+            instrument: Instrument::new(
+                canonical_symbol(base),
+                canonical_symbol(quote),
+                instrument_kind,
+            ),
             kind,
         }
     }
 }
 
+/// Canonical form of a [`Subscription`] base or quote [`Symbol`]: lowercase, without
+/// surrounding whitespace or pair separators (ie/ `-`, `_`, `/` or `:`).
+///
+/// eg/ `" BTC-"` -> `"btc"`
+pub fn canonical_symbol(symbol: Symbol) -> Symbol {
+    let trimmed = symbol
+        .as_ref()
+        .trim_matches(|c: char| c.is_whitespace() || matches!(c, '-' | '_' | '/' | ':'));
+
+    if trimmed.len() == symbol.as_ref().len() {
+        symbol
+    } else {
+        Symbol::new(trimmed)
+    }
+}
+
 impl<Exchange, Kind> Validator for &Subscription<Exchange, Kind>
 where
     Exchange: StreamSelector<Kind>,
@@ -103,13 +370,42 @@ where
         let exchange = Exchange::ID;
 
         // Validate the Exchange supports the Subscription InstrumentKind
-        if exchange.supports(self.instrument.kind) {
-            Ok(self)
-        } else {
-            Err(SocketError::Unsupported {
+        if !exchange.supports(self.instrument.kind) {
+            return Err(SocketError::Unsupported {
                 entity: exchange.as_str(),
                 item: self.instrument.kind.to_string(),
-            })
+            });
+        }
+
+        // Validate the SubKind is available for the Subscription InstrumentKind
+        if !self.kind.supports(self.instrument.kind) {
+            return Err(SocketError::Unsupported {
+                entity: exchange.as_str(),
+                item: format!("{:?} for {} instruments", self.kind, self.instrument.kind),
+            });
+        }
+
+        // Validate the Exchange supports any requested OrderBook depth
+        if let Some(depth) = self.kind.depth() {
+            if !Exchange::book_depths().contains(&depth) {
+                return Err(SocketError::Unsupported {
+                    entity: exchange.as_str(),
+                    item: format!("OrderBook depth {depth}"),
+                });
+            }
+        }
+
+        // Validate the Exchange supports any requested standard candle Interval
+        //  '--> Custom Intervals are exchange specific, so are passed through unvalidated
+        match self.kind.interval() {
+            Some(candle::Interval::Custom(_)) | None => Ok(self),
+            Some(interval) if !Exchange::supported_intervals().contains(interval) => {
+                Err(SocketError::Unsupported {
+                    entity: exchange.as_str(),
+                    item: format!("candle Interval {interval}"),
+                })
+            }
+            Some(_) => Ok(self),
         }
     }
 }
@@ -120,7 +416,7 @@ where
 pub struct SubscriptionMeta {
     /// `HashMap` containing the mapping between a [`SubscriptionId`] and
     /// it's associated Barter [`Instrument`].
-    pub instrument_map: Map<Instrument>,
+    pub instrument_map: Map<Arc<Instrument>>,
     /// Collection of [`WsMessage`]s containing exchange specific subscription payloads to be sent.
     pub subscriptions: Vec<WsMessage>,
 }
@@ -129,20 +425,32 @@ pub struct SubscriptionMeta {
 ///
 /// Used by [`ExchangeTransformer`](crate::transformer::ExchangeTransformer)s to identify the
 /// Barter [`Instrument`] associated with incoming exchange messages.
+///
+/// Uses the [`SubscriptionIdBuildHasher`] since a lookup is performed for every incoming message.
 #[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
-pub struct Map<T>(pub HashMap<SubscriptionId, T>);
+pub struct Map<T>(pub HashMap<SubscriptionId, T, SubscriptionIdBuildHasher>);
 
 impl<T> FromIterator<(SubscriptionId, T)> for Map<T> {
     fn from_iter<Iter>(iter: Iter) -> Self
     where
         Iter: IntoIterator<Item = (SubscriptionId, T)>,
     {
-        Self(iter.into_iter().collect::<HashMap<SubscriptionId, T>>())
+        Self(iter.into_iter().collect())
     }
 }
 
 impl<T> Map<T> {
+    /// Construct a new empty [`Self`] with at least the specified capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity_and_hasher(
+            capacity,
+            SubscriptionIdBuildHasher::default(),
+        ))
+    }
+
     /// Find the `T` associated with the provided [`SubscriptionId`].
+    // SocketError is defined upstream in barter-integration, so cannot be boxed here
+    #[allow(clippy::result_large_err)]
     pub fn find(&self, id: &SubscriptionId) -> Result<T, SocketError>
     where
         T: Clone,
@@ -154,6 +462,7 @@ impl<T> Map<T> {
     }
 
     /// Find the mutable reference to `T` associated with the provided [`SubscriptionId`].
+    #[allow(clippy::result_large_err)]
     pub fn find_mut(&mut self, id: &SubscriptionId) -> Result<&mut T, SocketError> {
         self.0
             .get_mut(id)
@@ -168,10 +477,18 @@ mod tests {
     mod subscription {
         use super::*;
         use crate::{
-            exchange::{coinbase::Coinbase, okx::Okx},
-            subscription::trade::PublicTrades,
+            exchange::{
+                binance::{futures::BinanceFuturesUsd, spot::BinanceSpot},
+                coinbase::Coinbase,
+                okx::Okx,
+            },
+            subscription::{
+                funding::FundingRates, index::IndexPrices, liquidation::Liquidations,
+                ticker::Tickers, trade::PublicTrades,
+            },
         };
-        use barter_integration::model::instrument::kind::InstrumentKind;
+        use barter_integration::model::instrument::kind::{FutureContract, InstrumentKind};
+        use chrono::{TimeZone, Utc};
 
         mod de {
             use super::*;
@@ -181,7 +498,11 @@ mod tests {
                     gateio::perpetual::GateioPerpetualsUsd,
                     okx::Okx,
                 },
-                subscription::{book::OrderBooksL2, trade::PublicTrades},
+                subscription::{
+                    book::{OrderBooksL1, OrderBooksL2},
+                    candle::{Candles, ContinuousCandles, ContractType, Interval},
+                    trade::PublicTrades,
+                },
             };
 
             #[test]
@@ -245,6 +566,193 @@ mod tests {
                 serde_json::from_str::<Subscription<GateioPerpetualsUsd, PublicTrades>>(input)
                     .unwrap();
             }
+
+            #[test]
+            fn test_subscription_shorthand() {
+                // Shorthand strings are accepted directly, and as a serde string
+                let actual = "binance_spot:btc/usdt:trades"
+                    .parse::<Subscription<BinanceSpot, PublicTrades>>()
+                    .unwrap();
+                assert_eq!(
+                    actual,
+                    Subscription::from((
+                        BinanceSpot::default(),
+                        "btc",
+                        "usdt",
+                        InstrumentKind::Spot,
+                        PublicTrades
+                    ))
+                );
+
+                let actual = serde_json::from_str::<Vec<Subscription<BinanceSpot, Candles>>>(
+                    r#"["binance_spot:eth/usdt:candles:1m", "binance_spot:btc/usdt-spot:candles:4h"]"#,
+                )
+                .unwrap();
+                assert_eq!(
+                    actual
+                        .iter()
+                        .map(|sub| sub.kind.interval.clone())
+                        .collect::<Vec<_>>(),
+                    vec![Interval::Minute1, Interval::Hour4]
+                );
+                assert_eq!(actual[0].instrument.base, Symbol::new("eth"));
+
+                let actual = "binance_spot:btc/usdt:quotes"
+                    .parse::<Subscription<BinanceSpot, OrderBooksL1>>()
+                    .unwrap();
+                assert_eq!(actual.kind, OrderBooksL1);
+
+                let actual = "binance_futures_usd:btc/usdt-perpetual:order_books_l2"
+                    .parse::<Subscription<BinanceFuturesUsd, OrderBooksL2>>()
+                    .unwrap();
+                assert_eq!(actual.instrument.kind, InstrumentKind::Perpetual);
+
+                let actual = "binance_futures_usd:btc/usdt-perpetual:continuous_candles:1h"
+                    .parse::<Subscription<BinanceFuturesUsd, ContinuousCandles>>()
+                    .unwrap();
+                assert_eq!(
+                    actual.kind,
+                    ContinuousCandles {
+                        contract_type: ContractType::Perpetual,
+                        interval: Interval::Hour1,
+                    }
+                );
+            }
+
+            #[test]
+            fn test_subscription_shorthand_invalid() {
+                struct TestCase {
+                    input: &'static str,
+                    expected: (&'static str, &'static str),
+                }
+
+                let tests = vec![
+                    TestCase {
+                        // TC0: too few segments
+                        input: "binance_spot:btc/usdt",
+                        expected: ("Subscription shorthand", "binance_spot:btc/usdt"),
+                    },
+                    TestCase {
+                        // TC1: unknown exchange
+                        input: "binanse_spot:btc/usdt:trades",
+                        expected: ("Subscription exchange", "binanse_spot"),
+                    },
+                    TestCase {
+                        // TC2: instrument without a quote
+                        input: "binance_spot:btcusdt:trades",
+                        expected: ("Subscription instrument", "btcusdt"),
+                    },
+                    TestCase {
+                        // TC3: unsupported instrument kind
+                        input: "binance_spot:btc/usdt-swap:trades",
+                        expected: ("Subscription instrument kind", "swap"),
+                    },
+                    TestCase {
+                        // TC4: kind that does not match the Subscription Kind
+                        input: "binance_spot:btc/usdt:order_books_l2",
+                        expected: ("Subscription kind", "order_books_l2"),
+                    },
+                    TestCase {
+                        // TC5: interval of a kind without an interval
+                        input: "binance_spot:btc/usdt:trades:1m",
+                        expected: ("Subscription interval", "1m"),
+                    },
+                    TestCase {
+                        // TC6: too many segments
+                        input: "binance_spot:btc/usdt:trades:1m:extra",
+                        expected: ("Subscription shorthand segment", "extra"),
+                    },
+                ];
+
+                for (index, test) in tests.into_iter().enumerate() {
+                    match test
+                        .input
+                        .parse::<Subscription<BinanceSpot, PublicTrades>>()
+                    {
+                        Err(SocketError::Unsupported { entity, item }) => {
+                            assert_eq!((entity, item.as_str()), test.expected, "TC{index} failed")
+                        }
+                        actual => panic!("TC{index} failed: {actual:?}"),
+                    }
+                }
+
+                // Invalid interval of a candle kind is named
+                let actual = "binance_spot:btc/usdt:candles:1x"
+                    .parse::<Subscription<BinanceSpot, Candles>>()
+                    .unwrap_err();
+                assert_eq!(
+                    actual.to_string(),
+                    "Subscription interval does not support: 1x"
+                );
+
+                // Serde errors of the shorthand form are surfaced
+                let actual = serde_json::from_str::<Subscription<BinanceSpot, PublicTrades>>(
+                    r#""binance_spot:btc/usdt:candles""#,
+                )
+                .unwrap_err();
+                assert!(actual.to_string().contains("Subscription kind"), "{actual}");
+            }
+        }
+
+        #[test]
+        fn test_subscription_normalises_symbols() {
+            use std::hash::{BuildHasher, RandomState};
+
+            struct TestCase {
+                input: Subscription<Okx, PublicTrades>,
+                expected: Subscription<Okx, PublicTrades>,
+            }
+
+            let canonical =
+                || Subscription::from((Okx, "btc", "usdt", InstrumentKind::Spot, PublicTrades));
+
+            let tests = vec![
+                TestCase {
+                    // TC0: uppercase symbols
+                    input: Subscription::from((
+                        Okx,
+                        "BTC",
+                        "USDT",
+                        InstrumentKind::Spot,
+                        PublicTrades,
+                    )),
+                    expected: canonical(),
+                },
+                TestCase {
+                    // TC1: mixed case symbols with surrounding whitespace & separators
+                    input: Subscription::from((
+                        Okx,
+                        " Btc/",
+                        "-usdT ",
+                        InstrumentKind::Spot,
+                        PublicTrades,
+                    )),
+                    expected: canonical(),
+                },
+                TestCase {
+                    // TC2: Instrument constructed directly
+                    input: Subscription::new(
+                        Okx,
+                        Instrument {
+                            base: Symbol::new("BTC_"),
+                            quote: Symbol::new("USDT"),
+                            kind: InstrumentKind::Spot,
+                        },
+                        PublicTrades,
+                    ),
+                    expected: canonical(),
+                },
+            ];
+
+            let hasher = RandomState::new();
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(test.input, test.expected, "TC{index} failed");
+                assert_eq!(
+                    hasher.hash_one(&test.input),
+                    hasher.hash_one(&test.expected),
+                    "TC{index} failed"
+                );
+            }
         }
 
         #[test]
@@ -365,6 +873,153 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_validate_binance_futures_index_prices() {
+            struct TestCase {
+                input: Subscription<BinanceFuturesUsd, IndexPrices>,
+                expected: bool,
+            }
+
+            let subscription = |kind| {
+                Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    kind,
+                    IndexPrices,
+                ))
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Valid BinanceFuturesUsd Perpetual IndexPrices subscription
+                    input: subscription(InstrumentKind::Perpetual),
+                    expected: true,
+                },
+                TestCase {
+                    // TC1: Invalid BinanceFuturesUsd Spot IndexPrices subscription
+                    input: subscription(InstrumentKind::Spot),
+                    expected: false,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.validate().is_ok(),
+                    test.expected,
+                    "TC{index} failed"
+                );
+            }
+
+            // IndexPrices are only available for derivative instruments on any exchange
+            assert!(!IndexPrices.supports(InstrumentKind::Spot));
+            assert!(IndexPrices.supports(InstrumentKind::Perpetual));
+        }
+
+        #[test]
+        fn test_validate_binance_futures_funding_rates() {
+            struct TestCase {
+                input: Subscription<BinanceFuturesUsd, FundingRates>,
+                expected: bool,
+            }
+
+            let subscription = |kind| {
+                Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    kind,
+                    FundingRates,
+                ))
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Valid BinanceFuturesUsd Perpetual FundingRates subscription
+                    input: subscription(InstrumentKind::Perpetual),
+                    expected: true,
+                },
+                TestCase {
+                    // TC1: Invalid BinanceFuturesUsd Spot FundingRates subscription
+                    input: subscription(InstrumentKind::Spot),
+                    expected: false,
+                },
+                TestCase {
+                    // TC2: Invalid BinanceFuturesUsd Future FundingRates subscription
+                    input: subscription(InstrumentKind::Future(FutureContract {
+                        expiry: Utc.with_ymd_and_hms(2023, 6, 30, 8, 0, 0).unwrap(),
+                    })),
+                    expected: false,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.validate().is_ok(),
+                    test.expected,
+                    "TC{index} failed"
+                );
+            }
+
+            assert_eq!(FundingRates.to_string(), "funding_rates");
+        }
+
+        #[test]
+        fn test_validate_binance_spot_tickers() {
+            let subscription = Subscription::from((
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                Tickers,
+            ));
+
+            assert!(subscription.validate().is_ok());
+            assert!(crate::exchange::ExchangeId::BinanceSpot
+                .supported_stream_kinds()
+                .contains(&SubKindId::Tickers));
+            assert_eq!(Tickers.to_string(), "tickers");
+        }
+
+        #[test]
+        fn test_validate_binance_futures_liquidations() {
+            struct TestCase {
+                input: Subscription<BinanceFuturesUsd, Liquidations>,
+                expected: bool,
+            }
+
+            let subscription = |kind| {
+                Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    kind,
+                    Liquidations,
+                ))
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Valid BinanceFuturesUsd Perpetual Liquidations subscription
+                    input: subscription(InstrumentKind::Perpetual),
+                    expected: true,
+                },
+                TestCase {
+                    // TC1: Invalid BinanceFuturesUsd Spot Liquidations subscription
+                    input: subscription(InstrumentKind::Spot),
+                    expected: false,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.validate().is_ok(),
+                    test.expected,
+                    "TC{index} failed"
+                );
+            }
+        }
     }
 
     mod instrument_map {
@@ -374,10 +1029,10 @@ mod tests {
         #[test]
         fn test_find_instrument() {
             // Initialise SubscriptionId-InstrumentId HashMap
-            let ids = Map(HashMap::from_iter([(
+            let ids = Map::from_iter([(
                 SubscriptionId::from("present"),
                 Instrument::from(("base", "quote", InstrumentKind::Spot)),
-            )]));
+            )]);
 
             struct TestCase {
                 input: SubscriptionId,
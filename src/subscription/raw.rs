@@ -0,0 +1,225 @@
+use super::{load::StreamLoad, SubKind, SubKindId};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+};
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that wraps a normalised [`SubKind`], yielding
+/// [`RawEvent`]s that contain the exchange specific `Dto` (eg/
+/// [`BinanceTrade`](crate::exchange::binance::trade::BinanceTrade)) alongside the normalised
+/// [`SubKind::Event`].
+///
+/// Useful for accessing exchange specific fields that the normalised data models omit.
+///
+/// ### Notes
+/// If a single `Dto` yields several normalised events (eg/ a batch of trades), the `Dto` is
+/// cloned into each [`RawEvent`].
+#[derive(Deserialize, Serialize)]
+#[serde(transparent, bound = "Kind: Serialize + for<'d> Deserialize<'d>")]
+pub struct Raw<Kind, Dto> {
+    pub kind: Kind,
+    #[serde(skip)]
+    phantom: PhantomData<fn() -> Dto>,
+}
+
+impl<Kind, Dto> Raw<Kind, Dto> {
+    /// Construct a new [`Self`] that wraps the provided normalised [`SubKind`].
+    pub fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<Kind, Dto> SubKind for Raw<Kind, Dto>
+where
+    Kind: SubKind,
+    Dto: Debug,
+{
+    type Event = RawEvent<Kind::Event, Dto>;
+    const ID: Option<SubKindId> = Kind::ID;
+
+    fn typical_load(&self) -> StreamLoad {
+        self.kind.typical_load()
+    }
+}
+
+impl<Kind, Dto> From<Kind> for Raw<Kind, Dto> {
+    fn from(kind: Kind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl<Kind, Dto> Clone for Raw<Kind, Dto>
+where
+    Kind: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.kind.clone())
+    }
+}
+
+impl<Kind, Dto> Copy for Raw<Kind, Dto> where Kind: Copy {}
+
+impl<Kind, Dto> Debug for Raw<Kind, Dto>
+where
+    Kind: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Raw").field(&self.kind).finish()
+    }
+}
+
+impl<Kind, Dto> PartialEq for Raw<Kind, Dto>
+where
+    Kind: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+impl<Kind, Dto> Eq for Raw<Kind, Dto> where Kind: Eq {}
+
+impl<Kind, Dto> PartialOrd for Raw<Kind, Dto>
+where
+    Kind: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.kind.partial_cmp(&other.kind)
+    }
+}
+
+impl<Kind, Dto> Ord for Raw<Kind, Dto>
+where
+    Kind: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.kind.cmp(&other.kind)
+    }
+}
+
+impl<Kind, Dto> Hash for Raw<Kind, Dto>
+where
+    Kind: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.kind.hash(state)
+    }
+}
+
+/// Exchange specific `Dto` yielded alongside the normalised `T` event it was transformed into.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct RawEvent<T, Dto> {
+    pub normalised: T,
+    pub raw: Dto,
+}
+
+impl<T, Dto> From<(ExchangeId, Arc<Instrument>, Dto)> for MarketIter<RawEvent<T, Dto>>
+where
+    MarketIter<T>: From<(ExchangeId, Arc<Instrument>, Dto)>,
+    Dto: Clone,
+{
+    fn from((exchange_id, instrument, raw): (ExchangeId, Arc<Instrument>, Dto)) -> Self {
+        MarketIter::<T>::from((exchange_id, instrument, raw.clone()))
+            .0
+            .into_iter()
+            .map(|result| {
+                result.map(|event| MarketEvent {
+                    exchange_time: event.exchange_time,
+                    received_time: event.received_time,
+                    exchange: event.exchange,
+                    instrument: event.instrument,
+                    kind: RawEvent {
+                        normalised: event.kind,
+                        raw: raw.clone(),
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::{
+            channel::BinanceChannel, market::BinanceMarket, spot::BinanceSpot, trade::BinanceTrade,
+        },
+        streams::builder::StreamBuilder,
+        subscription::{intern::intern, trade::PublicTrades, Map, Subscription},
+        transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+        Identifier,
+    };
+    use barter_integration::{
+        model::{instrument::kind::InstrumentKind, Side, SubscriptionId},
+        Transformer,
+    };
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_raw_binance_trades() {
+        let subscription = Subscription::<BinanceSpot, Raw<PublicTrades, BinanceTrade>>::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            Raw::new(PublicTrades),
+        ));
+
+        // Raw Subscription can be actioned via the StreamBuilder
+        let builder = StreamBuilder::<Raw<PublicTrades, BinanceTrade>>::new()
+            .subscribe([subscription.clone()]);
+        assert_eq!(builder.futures.len(), 1);
+
+        // Raw Subscription re-uses the normalised exchange channel & market
+        let channel: BinanceChannel = subscription.id();
+        let market: BinanceMarket = subscription.id();
+        assert_eq!(channel, BinanceChannel::TRADES);
+        assert_eq!(market.as_ref(), "BTCUSDT");
+
+        let (ws_sink_tx, _) = mpsc::unbounded_channel();
+        let mut transformer = StatelessTransformer::<
+            BinanceSpot,
+            Raw<PublicTrades, BinanceTrade>,
+            BinanceTrade,
+        >::new(
+            ws_sink_tx,
+            Map::from_iter([(
+                SubscriptionId::from("@trade|BTCUSDT"),
+                intern(&subscription.instrument),
+            )]),
+        )
+        .await
+        .unwrap();
+
+        let input = serde_json::from_str::<BinanceTrade>(
+            r#"{
+                "e":"trade","E":1649324825173,"s":"BTCUSDT","t":1000000000,
+                "p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,
+                "T":1749354825200,"m":false,"M":true
+            }"#,
+        )
+        .unwrap();
+
+        let actual = transformer.transform(input).remove(0).unwrap();
+
+        // Exchange specific DTO fields are accessible alongside the normalised PublicTrade
+        assert_eq!(*actual.instrument, subscription.instrument);
+        assert_eq!(actual.kind.raw.id, 1000000000);
+        assert_eq!(actual.kind.raw.subscription_id.as_ref(), "@trade|BTCUSDT");
+        assert_eq!(actual.kind.normalised.id, "1000000000");
+        assert_eq!(actual.kind.normalised.side, Side::Buy);
+    }
+}
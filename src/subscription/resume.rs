@@ -0,0 +1,188 @@
+use barter_integration::model::instrument::Instrument;
+use std::collections::HashMap;
+
+/// Minimum backwards jump from the last-seen sequence for a sequence to be considered an
+/// exchange sequence reset (see [`ResumeFrom::is_reset`]).
+pub const SEQUENCE_RESET_MIN_JUMP: u64 = 1_000;
+
+/// Last-seen sequence of each [`Instrument`] consumed from a
+/// [`MarketStream`](crate::MarketStream), used to resume the exchange streams after a brief
+/// disconnect rather than re-fetching snapshots.
+///
+/// ### Notes
+/// - Sequences are only recorded for the exchange [`SubKind`](super::SubKind)s that define a
+///   [`StreamSelector::sequence`](crate::exchange::StreamSelector::sequence), and are assumed to
+///   be monotonic across connections, unless the exchange resets them (see
+///   [`ResumeFrom::is_reset`]).
+/// - Exchanges opt in to resuming by overriding
+///   [`Connector::resume_requests`](crate::exchange::Connector::resume_requests). Otherwise the
+///   standard subscription requests are sent (eg/ re-fetching
+///   [`OrderBook`](super::book::OrderBook) snapshots).
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct ResumeFrom {
+    sequences: HashMap<Instrument, u64>,
+}
+
+impl ResumeFrom {
+    /// Record the provided sequence as the last-seen sequence of the [`Instrument`].
+    pub fn record(&mut self, instrument: &Instrument, sequence: u64) {
+        match self.sequences.get_mut(instrument) {
+            Some(last) => *last = sequence.max(*last),
+            None => {
+                self.sequences.insert(instrument.clone(), sequence);
+            }
+        }
+    }
+
+    /// Last-seen sequence of the provided [`Instrument`], if any.
+    pub fn sequence(&self, instrument: &Instrument) -> Option<u64> {
+        self.sequences.get(instrument).copied()
+    }
+
+    /// Determine if the provided sequence of the [`Instrument`] has already been seen (eg/ an
+    /// event replayed by the exchange after resuming).
+    pub fn is_stale(&self, instrument: &Instrument, sequence: u64) -> bool {
+        self.sequence(instrument)
+            .is_some_and(|last| sequence <= last)
+    }
+
+    /// Determine if the provided sequence of the [`Instrument`] indicates that the exchange has
+    /// reset its sequence counter (eg/ after maintenance or a failover), rather than replayed an
+    /// already seen event.
+    ///
+    /// ### Heuristic
+    /// A sequence is considered a reset if it jumps backwards to a small value, ie/ it is both:
+    /// - Less than half of the last-seen sequence.
+    /// - At least [`SEQUENCE_RESET_MIN_JUMP`] behind the last-seen sequence.
+    ///
+    /// Replays after resuming are only ever slightly behind the last-seen sequence, so they are
+    /// never considered a reset.
+    pub fn is_reset(&self, instrument: &Instrument, sequence: u64) -> bool {
+        self.sequence(instrument)
+            .is_some_and(|last| sequence < last / 2 && last - sequence >= SEQUENCE_RESET_MIN_JUMP)
+    }
+
+    /// Forget the last-seen sequence of the provided [`Instrument`], such that the next recorded
+    /// sequence becomes its new baseline.
+    pub fn forget(&mut self, instrument: &Instrument) {
+        self.sequences.remove(instrument);
+    }
+
+    /// Only keep the sequences of [`Instrument`]s that satisfy the provided predicate.
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&Instrument) -> bool,
+    {
+        self.sequences.retain(|instrument, _| predicate(instrument))
+    }
+
+    /// Determine if no sequences have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.sequences.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_resume_from_is_stale() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let mut resume = ResumeFrom::default();
+
+        // Sequences of an Instrument without a last-seen sequence are never stale
+        assert!(!resume.is_stale(&instrument, 1));
+
+        resume.record(&instrument, 10);
+        resume.record(&instrument, 5);
+
+        struct TestCase {
+            input: u64,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: forward sequence is not stale
+                input: 11,
+                expected: false,
+            },
+            TestCase {
+                // TC1: last-seen sequence is stale
+                input: 10,
+                expected: true,
+            },
+            TestCase {
+                // TC2: replayed sequence recorded out of order is stale
+                input: 5,
+                expected: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = resume.is_stale(&instrument, test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+
+        // Only the sequences of retained Instruments are kept
+        resume.retain(|_| false);
+        assert!(resume.is_empty());
+    }
+
+    #[test]
+    fn test_resume_from_is_reset() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let mut resume = ResumeFrom::default();
+        resume.record(&instrument, 1_000_000);
+
+        struct TestCase {
+            input: u64,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: forward sequence is not a reset
+                input: 1_000_001,
+                expected: false,
+            },
+            TestCase {
+                // TC1: slightly backwards replayed sequence is not a reset
+                input: 999_990,
+                expected: false,
+            },
+            TestCase {
+                // TC2: large backwards jump to a small value is a reset
+                input: 1,
+                expected: true,
+            },
+            TestCase {
+                // TC3: backwards jump not below half the last-seen sequence is not a reset
+                input: 600_000,
+                expected: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = resume.is_reset(&instrument, test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+
+        // TC4: small last-seen sequences are never reset, since the jump is too small
+        resume.record(
+            &Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+            500,
+        );
+        assert!(
+            !resume.is_reset(&Instrument::from(("eth", "usdt", InstrumentKind::Spot)), 1),
+            "TC4 failed"
+        );
+
+        // Forgotten sequences have no baseline to reset from
+        resume.forget(&instrument);
+        assert!(!resume.is_reset(&instrument, 1));
+        assert!(!resume.is_stale(&instrument, 1));
+    }
+}
@@ -0,0 +1,101 @@
+use super::{candle::Interval, Subscription};
+use barter_integration::{
+    error::SocketError,
+    model::instrument::{kind::InstrumentKind, Instrument},
+};
+use serde::de::{value::BorrowedStrDeserializer, DeserializeOwned};
+use std::str::FromStr;
+
+/// Separator between the segments of a shorthand [`Subscription`].
+pub const SEGMENT_SEPARATOR: char = ':';
+
+/// Parse a [`Subscription`] from the shorthand
+/// `"{exchange}:{base}/{quote}[-{instrument_kind}]:{kind}[:{interval}]"` form used in config
+/// files.
+///
+/// eg/ `"binance_spot:btc/usdt:trades"`, `"binance_spot:eth/usdt:candles:1m"` or
+/// `"binance_futures_usd:btc/usdt-perpetual:public_trades"`.
+///
+/// ### Notes
+/// - The `exchange` & `kind` segments use the same strings as the struct form (eg/
+///   "public_trades"), with "trades" & "quotes" accepted as aliases for "public_trades" &
+///   "order_books_l1" (best bid & ask) respectively.
+/// - The `instrument_kind` defaults to spot, and only "spot" & "perpetual" are supported. Dated
+///   futures & options must use the struct form.
+/// - The `interval` is only accepted for candle kinds (eg/ "candles" or "continuous_candles").
+///   Continuous candles are only available for perpetual instruments in shorthand form.
+/// - Errors name the malformed segment (eg/ "Subscription interval does not support: 1x").
+// SocketError is defined upstream in barter-integration, so cannot be boxed here
+#[allow(clippy::result_large_err)]
+pub fn parse<Exchange, Kind>(input: &str) -> Result<Subscription<Exchange, Kind>, SocketError>
+where
+    Exchange: DeserializeOwned,
+    Kind: DeserializeOwned,
+{
+    let mut segments = input.split(SEGMENT_SEPARATOR);
+    let (Some(exchange), Some(instrument), Some(kind)) =
+        (segments.next(), segments.next(), segments.next())
+    else {
+        return Err(unsupported("Subscription shorthand", input));
+    };
+    let interval = segments.next();
+    if let Some(extra) = segments.next() {
+        return Err(unsupported("Subscription shorthand segment", extra));
+    }
+
+    let exchange = Exchange::deserialize(BorrowedStrDeserializer::<serde::de::value::Error>::new(
+        exchange,
+    ))
+    .map_err(|_| unsupported("Subscription exchange", exchange))?;
+
+    let instrument = parse_instrument(instrument)?;
+
+    if let Some(interval) = interval {
+        Interval::from_str(interval).map_err(|_| unsupported("Subscription interval", interval))?;
+    }
+
+    let kind_value = match (kind, interval) {
+        ("trades", None) => serde_json::Value::from("public_trades"),
+        ("quotes", None) => serde_json::Value::from("order_books_l1"),
+        (kind, None) => serde_json::Value::from(kind),
+        ("candles", Some(interval)) => serde_json::json!({ "interval": interval }),
+        ("continuous_candles", Some(interval)) if instrument.kind == InstrumentKind::Perpetual => {
+            serde_json::json!({ "contract_type": "PERPETUAL", "interval": interval })
+        }
+        (_, Some(interval)) => return Err(unsupported("Subscription interval", interval)),
+    };
+    let kind = Kind::deserialize(kind_value).map_err(|_| {
+        let segment = interval.map_or(kind.to_owned(), |interval| format!("{kind}:{interval}"));
+        unsupported("Subscription kind", &segment)
+    })?;
+
+    Ok(Subscription::new(exchange, instrument, kind))
+}
+
+/// Parse the `"{base}/{quote}[-{instrument_kind}]"` [`Instrument`] segment of a shorthand
+/// [`Subscription`].
+#[allow(clippy::result_large_err)]
+fn parse_instrument(segment: &str) -> Result<Instrument, SocketError> {
+    let (market, instrument_kind) = match segment.split_once('-') {
+        Some((market, "spot")) => (market, InstrumentKind::Spot),
+        Some((market, "perpetual")) => (market, InstrumentKind::Perpetual),
+        Some((_, instrument_kind)) => {
+            return Err(unsupported("Subscription instrument kind", instrument_kind))
+        }
+        None => (segment, InstrumentKind::Spot),
+    };
+
+    match market.split_once('/') {
+        Some((base, quote)) if !base.is_empty() && !quote.is_empty() && !quote.contains('/') => {
+            Ok(Instrument::from((base, quote, instrument_kind)))
+        }
+        _ => Err(unsupported("Subscription instrument", segment)),
+    }
+}
+
+fn unsupported(entity: &'static str, item: &str) -> SocketError {
+    SocketError::Unsupported {
+        entity,
+        item: item.to_owned(),
+    }
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Normalised Barter [`InstrumentStatus`] model, generated when the trading status of an
+/// instrument changes (eg/ it is halted or delisted mid-session).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct InstrumentStatus {
+    pub status: TradingStatus,
+    pub previous: TradingStatus,
+    pub time: DateTime<Utc>,
+}
+
+/// Normalised trading status of an instrument.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TradingStatus {
+    /// Instrument is not yet open for trading (eg/ pre-listing or pre-open auction).
+    PreTrading,
+    /// Instrument is open for continuous trading.
+    Trading,
+    /// Trading is temporarily suspended (eg/ halt or circuit breaker).
+    Halted,
+    /// Trading session has ended, and will re-open later.
+    PostTrading,
+    /// Instrument has been delisted, settled, or otherwise permanently closed.
+    Delisted,
+}
+
+impl TradingStatus {
+    /// Determine if the instrument can currently be traded.
+    pub fn is_trading(&self) -> bool {
+        matches!(self, TradingStatus::Trading)
+    }
+}
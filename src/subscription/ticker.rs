@@ -0,0 +1,38 @@
+use super::{load::StreamLoad, SubKind, SubKindId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields 24h rolling statistics
+/// [`Ticker`] [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Tickers;
+
+impl SubKind for Tickers {
+    type Event = Ticker;
+    const ID: Option<SubKindId> = Some(SubKindId::Tickers);
+
+    fn typical_load(&self) -> StreamLoad {
+        StreamLoad::new(1.0, 500.0)
+    }
+}
+
+impl Display for Tickers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "tickers")
+    }
+}
+
+/// Normalised Barter [`Ticker`] model containing the rolling 24h statistics of an instrument.
+///
+/// The `volume_24h` is denominated in the base asset, and the `price_change_pct` is a percentage
+/// (eg/ 2.5 for +2.5%).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Ticker {
+    pub last_price: f64,
+    pub high_24h: f64,
+    pub low_24h: f64,
+    pub volume_24h: f64,
+    pub price_change_pct: f64,
+    pub time: DateTime<Utc>,
+}
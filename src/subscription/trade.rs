@@ -1,7 +1,15 @@
-use super::SubKind;
-use barter_integration::model::Side;
+use super::{load::StreamLoad, SubKind, SubKindId};
+use crate::{error::DataError, event::MarketEvent, exchange::ExchangeId};
+use barter_integration::model::{instrument::Instrument, Side};
 use barter_macro::{DeSubKind, SerSubKind};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::{future::Future, sync::Arc};
+
+tokio::task_local! {
+    /// [`TradeFields`] of the current scope, see [`with_trade_fields`] & [`TradeFields::scope`].
+    static TRADE_FIELDS: TradeFields;
+}
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`PublicTrade`]
 /// [`MarketEvent<T>`](crate::event::MarketEvent) events.
@@ -10,6 +18,11 @@ pub struct PublicTrades;
 
 impl SubKind for PublicTrades {
     type Event = PublicTrade;
+    const ID: Option<SubKindId> = Some(SubKindId::PublicTrades);
+
+    fn typical_load(&self) -> StreamLoad {
+        StreamLoad::new(5.0, 200.0)
+    }
 }
 
 /// Normalised Barter [`PublicTrade`] model.
@@ -19,4 +32,416 @@ pub struct PublicTrade {
     pub price: f64,
     pub amount: f64,
     pub side: Side,
+    /// Exchange specific [`TradeCondition`] flags of the [`PublicTrade`] (eg/ a block trade).
+    /// Empty for regular trades, and for exchanges that do not flag trades.
+    #[serde(default)]
+    pub conditions: Vec<TradeCondition>,
+}
+
+impl PublicTrade {
+    /// Quote denominated volume of the [`PublicTrade`] (ie/ `price` × `amount`).
+    pub fn quote_volume(&self) -> f64 {
+        self.price * self.amount
+    }
+
+    /// Amount of the [`PublicTrade`] denominated in the base asset, given the [`QuantityUnit`]
+    /// used by the exchange to report the raw amount.
+    ///
+    /// Returns `None` for [`QuantityUnit::Contract`] amounts, since converting them requires the
+    /// contract size of the instrument.
+    pub fn base_amount(&self, unit: QuantityUnit) -> Option<f64> {
+        match unit {
+            QuantityUnit::Base => Some(self.amount),
+            QuantityUnit::Quote if self.price != 0.0 => Some(self.amount / self.price),
+            QuantityUnit::Quote | QuantityUnit::Contract => None,
+        }
+    }
+
+    /// Determines if the [`PublicTrade`] is flagged with any [`TradeCondition`].
+    pub fn is_flagged(&self) -> bool {
+        !self.conditions.is_empty()
+    }
+}
+
+/// Exchange specific condition flagged on a [`PublicTrade`] that was not matched on the regular
+/// continuous order book, which aggregators may choose to exclude (eg/ from a VWAP).
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TradeCondition {
+    /// Privately negotiated block trade, reported to the public tape after execution.
+    Block,
+    /// Any other exchange specific condition, identified by the raw exchange flag.
+    Other(String),
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`TaggedTrade`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events of the provided [`TradeKind`].
+///
+/// Subscribing to both [`TradeKind`]s of the same instrument multiplexes the raw & aggregated
+/// trade channels over a single connection, with each event tagged by the channel it arrived on.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct TaggedTrades(pub TradeKind);
+
+impl SubKind for TaggedTrades {
+    type Event = TaggedTrade;
+    const ID: Option<SubKindId> = Some(SubKindId::TaggedTrades);
+
+    fn typical_load(&self) -> StreamLoad {
+        match self.0 {
+            TradeKind::Raw => StreamLoad::new(5.0, 200.0),
+            TradeKind::Aggregated => StreamLoad::new(3.0, 200.0),
+        }
+    }
+}
+
+/// Kind of exchange trade channel a [`TaggedTrade`] was received on.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TradeKind {
+    /// Every individual fill between a taker & a maker order.
+    Raw,
+    /// Fills of a single taker order at the same price, aggregated into one trade.
+    Aggregated,
+}
+
+/// Normalised Barter [`PublicTrade`] tagged with the [`TradeKind`] of the channel it was
+/// received on.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct TaggedTrade {
+    pub kind: TradeKind,
+    pub trade: PublicTrade,
+}
+
+/// Selection of the optional fields populated by a trade transformer when normalising each
+/// exchange trade into a [`PublicTrade`] [`MarketEvent`](crate::event::MarketEvent).
+///
+/// High-rate consumers that never read a field can skip populating it to shave work per
+/// message. Skipped fields are left at their default:
+/// - `id`: an empty [`String`] (the exchange trade id is not formatted or cloned).
+///
+/// Defaults to populating every field.
+///
+/// ### Notes
+/// - Each [`StatelessTransformer`](crate::transformer::stateless::StatelessTransformer) uses the
+///   [`TradeFields`] of the scope it is constructed in (see [`with_trade_fields`], or
+///   [`StreamBuilder::with_trade_fields`](crate::streams::builder::StreamBuilder::with_trade_fields)),
+///   so streams with different [`TradeFields`] never affect each other.
+/// - The `received_time` is always populated, use a [`CachedClock`](crate::clock::CachedClock)
+///   to avoid the clock syscall per message instead.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct TradeFields {
+    pub id: bool,
+}
+
+impl Default for TradeFields {
+    fn default() -> Self {
+        Self { id: true }
+    }
+}
+
+impl TradeFields {
+    /// [`TradeFields`] of the current scope, or the default [`TradeFields`] outside of any scope.
+    pub fn current() -> Self {
+        TRADE_FIELDS.try_with(|fields| *fields).unwrap_or_default()
+    }
+
+    /// Run the provided closure (eg/ a trade normalisation) with [`Self`] as the
+    /// [`TradeFields::current`].
+    pub fn scope<F, R>(self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        TRADE_FIELDS.sync_scope(self, f)
+    }
+
+    /// [`PublicTrade`] id generated by the provided closure, or an empty [`String`] if the `id`
+    /// is skipped.
+    pub fn id<F>(&self, id: F) -> String
+    where
+        F: FnOnce() -> String,
+    {
+        if self.id {
+            id()
+        } else {
+            String::new()
+        }
+    }
+}
+
+/// Run the provided future with the provided [`TradeFields`] as the [`TradeFields::current`],
+/// such that the trade transformers of connections initialised within it use them.
+pub async fn with_trade_fields<Fut>(fields: TradeFields, future: Fut) -> Fut::Output
+where
+    Fut: Future,
+{
+    TRADE_FIELDS.scope(fields, future).await
+}
+
+/// Unit an exchange uses to report the raw amount of a [`PublicTrade`].
+///
+/// See [`ExchangeId::trade_quantity_unit`](crate::exchange::ExchangeId::trade_quantity_unit) for
+/// the convention of each exchange.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuantityUnit {
+    /// Amount of the base asset (eg/ 0.5 BTC of BTC-USD).
+    Base,
+    /// Notional amount of the quote asset (eg/ 200 USD of BTC-USD).
+    Quote,
+    /// Number of exchange specific contracts, each with an instrument specific contract size.
+    Contract,
+}
+
+/// Compact, heap allocation free record of a [`PublicTrade`] [`MarketEvent`], used for dense
+/// storage & IPC of trade ticks with a fixed-size binary layout (see [`TradeTick::to_bytes`]).
+///
+/// ### Notes
+/// The conversion from a [`PublicTrade`] [`MarketEvent`] is lossy, dropping the `id`,
+/// `conditions` & `received_time`, since the exchange & [`Instrument`] are expected to be keyed
+/// by the storage (eg/ a file per instrument).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct TradeTick {
+    /// Exchange timestamp in microseconds since the Unix epoch.
+    pub ts: i64,
+    pub price: f64,
+    pub qty: f64,
+    pub side: Side,
+}
+
+impl TradeTick {
+    /// Number of bytes in the binary layout of a [`TradeTick`].
+    pub const SIZE: usize = 25;
+
+    /// Encode the [`TradeTick`] into its fixed-size little-endian binary layout:
+    /// `ts` (8 bytes) | `price` (8 bytes) | `qty` (8 bytes) | `side` (1 byte, 0 = Buy, 1 = Sell).
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        bytes[0..8].copy_from_slice(&self.ts.to_le_bytes());
+        bytes[8..16].copy_from_slice(&self.price.to_le_bytes());
+        bytes[16..24].copy_from_slice(&self.qty.to_le_bytes());
+        bytes[24] = match self.side {
+            Side::Buy => 0,
+            Side::Sell => 1,
+        };
+        bytes
+    }
+
+    /// Decode a [`TradeTick`] from its fixed-size binary layout (see [`TradeTick::to_bytes`]).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DataError> {
+        let bytes: &[u8; Self::SIZE] =
+            bytes.try_into().map_err(|_| DataError::InvalidTradeTick {
+                error: format!("expected {} bytes, found {}", Self::SIZE, bytes.len()),
+            })?;
+
+        let side = match bytes[24] {
+            0 => Side::Buy,
+            1 => Side::Sell,
+            other => {
+                return Err(DataError::InvalidTradeTick {
+                    error: format!("invalid side byte {other}"),
+                })
+            }
+        };
+
+        let word = |start: usize| {
+            let mut word = [0; 8];
+            word.copy_from_slice(&bytes[start..start + 8]);
+            word
+        };
+
+        Ok(Self {
+            ts: i64::from_le_bytes(word(0)),
+            price: f64::from_le_bytes(word(8)),
+            qty: f64::from_le_bytes(word(16)),
+            side,
+        })
+    }
+}
+
+impl From<&MarketEvent<PublicTrade>> for TradeTick {
+    fn from(event: &MarketEvent<PublicTrade>) -> Self {
+        Self {
+            ts: event.exchange_time.timestamp_micros(),
+            price: event.kind.price,
+            qty: event.kind.amount,
+            side: event.kind.side,
+        }
+    }
+}
+
+impl TryFrom<(ExchangeId, &Instrument, TradeTick)> for MarketEvent<PublicTrade> {
+    type Error = DataError;
+
+    /// Reconstruct a [`PublicTrade`] [`MarketEvent`] from a [`TradeTick`] of the provided
+    /// exchange & [`Instrument`], with an empty `id` & the `received_time` set to the
+    /// `exchange_time`.
+    fn try_from(
+        (exchange, instrument, tick): (ExchangeId, &Instrument, TradeTick),
+    ) -> Result<Self, Self::Error> {
+        let exchange_time = DateTime::<Utc>::from_timestamp_micros(tick.ts).ok_or_else(|| {
+            DataError::InvalidTradeTick {
+                error: format!("timestamp {} is out of range", tick.ts),
+            }
+        })?;
+
+        Ok(Self {
+            exchange_time,
+            received_time: exchange_time,
+            exchange: exchange.into(),
+            instrument: Arc::new(instrument.clone()),
+            kind: PublicTrade {
+                id: String::new(),
+                price: tick.price,
+                amount: tick.qty,
+                side: tick.side,
+                conditions: vec![],
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_trade_fields() {
+        struct TestCase {
+            input: Option<TradeFields>,
+            expected_id: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: unscoped default populates every field
+                input: None,
+                expected_id: "1000000000",
+            },
+            TestCase {
+                // TC1: scoped default populates every field
+                input: Some(TradeFields::default()),
+                expected_id: "1000000000",
+            },
+            TestCase {
+                // TC2: scoped skipped id is left empty
+                input: Some(TradeFields { id: false }),
+                expected_id: "",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let id = || TradeFields::current().id(|| 1000000000_u64.to_string());
+            let actual = match test.input {
+                Some(fields) => fields.scope(id),
+                None => id(),
+            };
+            assert_eq!(actual, test.expected_id, "TC{index} failed");
+        }
+
+        // Scope ends with the closure
+        assert_eq!(TradeFields::current(), TradeFields::default());
+    }
+
+    #[test]
+    fn test_public_trade_quote_volume() {
+        let trade = PublicTrade {
+            id: "1".to_string(),
+            price: 20_000.0,
+            amount: 0.25,
+            side: Side::Sell,
+            conditions: vec![],
+        };
+        assert_eq!(trade.quote_volume(), 5_000.0);
+    }
+
+    #[test]
+    fn test_trade_tick_round_trip() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let exchange_time = DateTime::<Utc>::from_timestamp_micros(1_649_324_825_123_456).unwrap();
+        let event = MarketEvent {
+            exchange_time,
+            received_time: exchange_time + chrono::Duration::milliseconds(5),
+            exchange: ExchangeId::BinanceSpot.into(),
+            instrument: Arc::new(instrument.clone()),
+            kind: PublicTrade {
+                id: "1000000000".to_string(),
+                price: 20_000.5,
+                amount: 0.25,
+                side: Side::Sell,
+                conditions: vec![TradeCondition::Block],
+            },
+        };
+
+        let tick = TradeTick::from(&event);
+        assert_eq!(
+            tick,
+            TradeTick {
+                ts: 1_649_324_825_123_456,
+                price: 20_000.5,
+                qty: 0.25,
+                side: Side::Sell,
+            }
+        );
+
+        // Binary encoding is lossless
+        let bytes = tick.to_bytes();
+        assert_eq!(bytes.len(), TradeTick::SIZE);
+        assert_eq!(TradeTick::from_bytes(&bytes).unwrap(), tick);
+
+        // Lossy fields (id, conditions & received_time) are dropped
+        let actual =
+            MarketEvent::<PublicTrade>::try_from((ExchangeId::BinanceSpot, &instrument, tick))
+                .unwrap();
+        assert_eq!(actual.exchange_time, event.exchange_time);
+        assert_eq!(actual.received_time, event.exchange_time);
+        assert_eq!(actual.exchange, event.exchange);
+        assert_eq!(actual.instrument, event.instrument);
+        assert_eq!(
+            actual.kind,
+            PublicTrade {
+                id: String::new(),
+                conditions: vec![],
+                ..event.kind
+            }
+        );
+    }
+
+    #[test]
+    fn test_trade_tick_from_invalid_bytes() {
+        let tick = TradeTick {
+            ts: 0,
+            price: 1.0,
+            qty: 1.0,
+            side: Side::Buy,
+        };
+
+        // Truncated layout
+        assert!(matches!(
+            TradeTick::from_bytes(&tick.to_bytes()[..TradeTick::SIZE - 1]),
+            Err(DataError::InvalidTradeTick { .. })
+        ));
+
+        // Invalid side byte
+        let mut bytes = tick.to_bytes();
+        bytes[24] = 2;
+        assert!(matches!(
+            TradeTick::from_bytes(&bytes),
+            Err(DataError::InvalidTradeTick { .. })
+        ));
+
+        // Out of range timestamp
+        let tick = TradeTick {
+            ts: i64::MAX,
+            ..tick
+        };
+        assert!(matches!(
+            MarketEvent::<PublicTrade>::try_from((
+                ExchangeId::BinanceSpot,
+                &Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                tick
+            )),
+            Err(DataError::InvalidTradeTick { .. })
+        ));
+    }
 }
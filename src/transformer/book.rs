@@ -1,20 +1,42 @@
 use crate::{
+    clock,
     error::DataError,
     event::{MarketEvent, MarketIter},
-    exchange::Connector,
-    subscription::{book::OrderBook, Map, SubKind},
+    exchange::{subscription::ExchangeSub, Connector},
+    subscription::{
+        book::{Level, OrderBook, OrderBookSide, OrderBooksL2, OrderBooksTop, TopOfBook},
+        Map, SubKind, Subscription,
+    },
     transformer::ExchangeTransformer,
     Identifier,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    model::{instrument::Instrument, SubscriptionId},
+    model::{instrument::Instrument, Side, SubscriptionId},
     protocol::websocket::WsMessage,
     Transformer,
 };
+use chrono::{DateTime, Utc};
+use futures::{stream::FuturesUnordered, StreamExt};
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
-use tokio::sync::mpsc;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Debug, Formatter},
+    future::Future,
+    marker::PhantomData,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+use tokio::{
+    sync::{mpsc, Notify},
+    task::AbortHandle,
+};
+use tracing::warn;
+
+tokio::task_local! {
+    /// [`BookWatchdog`] of the current scope, see [`with_book_watchdog`].
+    static BOOK_WATCHDOG: Option<BookWatchdog>;
+}
 
 /// Defines how to apply a [`Self::Update`] to an [`Self::OrderBook`].
 #[async_trait]
@@ -25,11 +47,22 @@ where
     type OrderBook;
     type Update;
 
-    /// Initialises the [`InstrumentOrderBook`] for the provided [`Instrument`]. This often requires
-    /// a HTTP call to receive a starting [`OrderBook`] snapshot.
+    /// Determines if the starting [`OrderBook`] snapshot is sent over the WebSocket once
+    /// subscribed (eg/ Kraken, Okx & Bybit), such that [`Self::init`] starts from an empty
+    /// [`OrderBook`] awaiting it.
+    ///
+    /// Defaults to `false`, meaning [`Self::init`] fetches the snapshot (eg/ via HTTP).
+    const WS_SNAPSHOT: bool = false;
+
+    /// Initialises the [`InstrumentOrderBook`] for the provided interned [`Instrument`]. This often
+    /// requires a HTTP call to receive a starting [`OrderBook`] snapshot.
+    ///
+    /// If a `depth` is provided, the starting [`OrderBook`] snapshot should contain at least
+    /// `depth` [`Level`](crate::subscription::book::Level)s per side.
     async fn init<Exchange, Kind>(
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
-        instrument: Instrument,
+        instrument: Arc<Instrument>,
+        depth: Option<u16>,
     ) -> Result<InstrumentOrderBook<Self>, DataError>
     where
         Exchange: Connector + Send,
@@ -43,45 +76,545 @@ where
     ) -> Result<Option<Self::OrderBook>, DataError>;
 }
 
+/// Type tag of an exchange OrderBook message (eg/ Bybit & Kraken), communicating whether it is a
+/// full [`OrderBook`] snapshot, or an update (delta) to apply to the previous snapshot.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BookMessageKind {
+    Snapshot,
+    #[serde(alias = "delta")]
+    Update,
+}
+
+/// Applies type-tagged [`BookMessageKind`] messages to an [`OrderBook`], strictly branching on
+/// the message type.
+///
+/// Used by [`OrderBookUpdater`]s of type-tagged exchanges, since treating a snapshot as a delta
+/// (or vice versa) silently corrupts the reconstructed [`OrderBook`].
+///
+/// ### Notes
+/// - [`BookMessageKind::Snapshot`]s reset the [`OrderBook`] to the snapshot [`Level`]s.
+/// - [`BookMessageKind::Update`]s upsert their [`Level`]s into the current [`OrderBook`].
+/// - An [`BookMessageKind::Update`] received before any snapshot yields a terminal
+///   [`DataError::BookDesync`], so the [`MarketStream`](crate::MarketStream) is re-initialised
+///   and awaits a fresh snapshot.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct TaggedBookSync {
+    snapshotted: bool,
+}
+
+impl TaggedBookSync {
+    /// Determine if a [`BookMessageKind::Snapshot`] has been applied.
+    pub fn is_snapshotted(&self) -> bool {
+        self.snapshotted
+    }
+
+    /// Apply the bid & ask [`Level`]s of a [`BookMessageKind`] message to the [`OrderBook`].
+    ///
+    /// Use [`OrderBook::snapshot`] to generate a sorted [`OrderBook`] once applied.
+    pub fn apply<Bids, Asks, L>(
+        &mut self,
+        book: &mut OrderBook,
+        kind: BookMessageKind,
+        time: DateTime<Utc>,
+        bids: Bids,
+        asks: Asks,
+    ) -> Result<(), DataError>
+    where
+        Bids: IntoIterator<Item = L>,
+        Asks: IntoIterator<Item = L>,
+        L: Into<Level>,
+    {
+        match kind {
+            BookMessageKind::Snapshot => {
+                book.bids = OrderBookSide::new(Side::Buy, bids);
+                book.asks = OrderBookSide::new(Side::Sell, asks);
+                self.snapshotted = true;
+            }
+            BookMessageKind::Update if self.snapshotted => {
+                book.bids.upsert(bids);
+                book.asks.upsert(asks);
+            }
+            BookMessageKind::Update => return Err(DataError::BookDesync),
+        }
+
+        book.last_update_time = time;
+        Ok(())
+    }
+}
+
+/// IEEE CRC32 checksum of the provided bytes, used by [`OrderBookUpdater`]s of exchanges that
+/// validate the local [`OrderBook`] against a book checksum (eg/ Kraken & Okx).
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(u32::MAX, |crc, byte| {
+        (0..8).fold(crc ^ u32::from(*byte), |crc, _| match crc & 1 {
+            1 => (crc >> 1) ^ 0xEDB8_8320,
+            _ => crc >> 1,
+        })
+    })
+}
+
 /// [`OrderBook`] for an [`Instrument`] with an exchange specific [`OrderBookUpdater`] to define
 /// how to update it.
+///
+/// If a `depth` is requested, the full [`OrderBook`] is maintained, but each generated
+/// [`OrderBook`] snapshot is truncated to `depth` [`Level`](crate::subscription::book::Level)s
+/// per side.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
 pub struct InstrumentOrderBook<Updater> {
-    pub instrument: Instrument,
+    pub instrument: Arc<Instrument>,
     pub updater: Updater,
     pub book: OrderBook,
+    pub depth: Option<u16>,
+}
+
+/// Configuration of the stale [`OrderBook`] watchdog of a [`MultiBookTransformer`].
+///
+/// An [`OrderBook`] is stale once no update has been received for its [`Instrument`] within the
+/// `timeout`. If `resnapshot` is enabled, a stale [`OrderBook`] is re-snapshotted & the refreshed
+/// [`OrderBook`] is yielded. Otherwise, the stale [`OrderBook`] is only logged, since a quiet
+/// [`Instrument`] on a healthy connection is often just illiquid.
+///
+/// ### Notes
+/// - Stale [`OrderBook`]s are detected by a timer task, independently of the updates of any
+///   other [`Instrument`]. Timers start once the connection delivers its first update, and a
+///   connection that goes entirely silent is left to the [`Connector::idle_timeout`].
+/// - [`OrderBook`]s fetched by [`OrderBookUpdater::init`] are re-fetched, buffering the updates
+///   received whilst the snapshot is in flight & replaying them on top of it.
+/// - [`OrderBook`]s sent over the WebSocket (see [`OrderBookUpdater::WS_SNAPSHOT`]) are
+///   re-subscribed via the [`Connector::unsubscribe_requests`], so the exchange sends a fresh
+///   snapshot. They are only logged if the exchange does not support unsubscribing.
+/// - Refreshed [`OrderBook`]s are yielded alongside the next message of the connection.
+/// - Disabled by default. Enabled for every [`MultiBookTransformer`] constructed within
+///   [`with_book_watchdog`] (eg/ via a
+///   [`StreamBuilder::with_book_watchdog`](crate::streams::builder::StreamBuilder::with_book_watchdog)),
+///   or via [`MultiBookTransformer::with_watchdog`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BookWatchdog {
+    pub timeout: Duration,
+    pub resnapshot: bool,
+}
+
+impl BookWatchdog {
+    /// [`BookWatchdog`] of the current scope (see [`with_book_watchdog`]), if enabled.
+    pub fn current() -> Option<Self> {
+        BOOK_WATCHDOG.try_with(|watchdog| *watchdog).ok().flatten()
+    }
+}
+
+/// Run the provided future with the provided [`BookWatchdog`] as the [`BookWatchdog::current`],
+/// such that the [`MultiBookTransformer`]s of connections initialised within it use it.
+pub async fn with_book_watchdog<Fut>(watchdog: Option<BookWatchdog>, future: Fut) -> Fut::Output
+where
+    Fut: Future,
+{
+    BOOK_WATCHDOG.scope(watchdog, future).await
+}
+
+/// Event sent by the timer task of a [`BookWatchdog`] to its [`MultiBookTransformer`].
+enum WatchdogEvent<Updater> {
+    /// Re-snapshot of a stale [`OrderBook`] has started, so its updates must be buffered.
+    Resnapshotting(SubscriptionId),
+    /// Result of re-initialising a stale [`InstrumentOrderBook`].
+    Resnapshot(
+        SubscriptionId,
+        Result<InstrumentOrderBook<Updater>, DataError>,
+    ),
+}
+
+/// [`OrderBook`] watched by the timer task of a [`BookWatchdog`].
+struct WatchedBook {
+    subscription_id: SubscriptionId,
+    instrument: Arc<Instrument>,
+    depth: Option<u16>,
+    /// Requests that re-subscribe the [`OrderBook`], if its snapshot is sent over the WebSocket.
+    resubscribe: Vec<WsMessage>,
+}
+
+/// Last update [`Instant`] of each [`OrderBook`] watched by a [`BookWatchdog`] timer task.
+#[derive(Debug, Default)]
+struct WatchdogTimers {
+    last_updates: Mutex<HashMap<SubscriptionId, Instant>>,
+    /// Notified once the connection delivers its first update, which starts every timer.
+    first_update: Notify,
+    /// [`SubscriptionId`]s of [`OrderBook`]s removed from the connection, which are no longer
+    /// watched.
+    removed: Mutex<HashSet<SubscriptionId>>,
+}
+
+/// Transient [`BookWatchdog`] state of a [`MultiBookTransformer`].
+///
+/// The state is not part of the [`MultiBookTransformer`] identity: it is skipped by (de)serialisation,
+/// always compares equal, and clones without the timer task & in-flight re-snapshots.
+struct WatchdogState<Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    config: Option<BookWatchdog>,
+    ws_sink_tx: Option<mpsc::UnboundedSender<WsMessage>>,
+    resubscribe: HashMap<SubscriptionId, Vec<WsMessage>>,
+    timers: Arc<WatchdogTimers>,
+    buffered: HashMap<SubscriptionId, Vec<Updater::Update>>,
+    events_rx: Option<mpsc::UnboundedReceiver<WatchdogEvent<Updater>>>,
+    task: Option<AbortHandle>,
+}
+
+impl<Updater> WatchdogState<Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    fn new(
+        config: Option<BookWatchdog>,
+        ws_sink_tx: Option<mpsc::UnboundedSender<WsMessage>>,
+        resubscribe: HashMap<SubscriptionId, Vec<WsMessage>>,
+    ) -> Self {
+        Self {
+            config,
+            ws_sink_tx,
+            resubscribe,
+            timers: Arc::default(),
+            buffered: HashMap::new(),
+            events_rx: None,
+            task: None,
+        }
+    }
+
+    /// Record an update of the provided [`SubscriptionId`] for the timer task, if running.
+    fn record_update(&self, subscription_id: &SubscriptionId) {
+        if self.task.is_some() {
+            let mut last_updates = self
+                .timers
+                .last_updates
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            if last_updates.is_empty() {
+                self.timers.first_update.notify_one();
+            }
+            last_updates.insert(subscription_id.clone(), Instant::now());
+        }
+    }
+}
+
+impl<Updater> Default for WatchdogState<Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    fn default() -> Self {
+        Self::new(None, None, HashMap::new())
+    }
+}
+
+impl<Updater> Clone for WatchdogState<Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    fn clone(&self) -> Self {
+        Self::new(
+            self.config,
+            self.ws_sink_tx.clone(),
+            self.resubscribe.clone(),
+        )
+    }
+}
+
+impl<Updater> Drop for WatchdogState<Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+impl<Updater> PartialEq for WatchdogState<Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<Updater> Eq for WatchdogState<Updater> where Updater: OrderBookUpdater {}
+
+impl<Updater> Debug for WatchdogState<Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WatchdogState")
+            .field("config", &self.config)
+            .field("buffered", &self.buffered.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Detect the stale [`OrderBook`]s of a [`MultiBookTransformer`] as each [`BookWatchdog`]
+/// timeout elapses, re-snapshotting them if enabled.
+async fn run_watchdog<Exchange, Kind, Updater>(
+    config: BookWatchdog,
+    mut books: Vec<WatchedBook>,
+    ws_sink_tx: Option<mpsc::UnboundedSender<WsMessage>>,
+    timers: Arc<WatchdogTimers>,
+    events_tx: mpsc::UnboundedSender<WatchdogEvent<Updater>>,
+) where
+    Exchange: Connector + Send + 'static,
+    Kind: SubKind<Event = OrderBook> + Send + 'static,
+    Updater: OrderBookUpdater<OrderBook = Kind::Event> + Send + 'static,
+{
+    let mut resnapshots = FuturesUnordered::new();
+    let mut pending = HashSet::new();
+
+    loop {
+        let now = Instant::now();
+        let mut next_check = now + config.timeout;
+        let mut stale = Vec::new();
+        let started = {
+            let removed = timers
+                .removed
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            books.retain(|book| !removed.contains(&book.subscription_id));
+            drop(removed);
+
+            let mut last_updates = timers
+                .last_updates
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            // OrderBook timers start once the connection delivers its first update
+            if !last_updates.is_empty() {
+                for book in books.iter() {
+                    if pending.contains(&book.subscription_id) {
+                        continue;
+                    }
+
+                    let last_update = last_updates
+                        .entry(book.subscription_id.clone())
+                        .or_insert(now);
+                    let stale_at = *last_update + config.timeout;
+                    if stale_at <= now {
+                        *last_update = now;
+                        stale.push(book);
+                    } else {
+                        next_check = next_check.min(stale_at);
+                    }
+                }
+            }
+
+            !last_updates.is_empty()
+        };
+
+        for book in stale {
+            match ws_sink_tx.as_ref().filter(|_| config.resnapshot) {
+                Some(ws_sink_tx) if Updater::WS_SNAPSHOT => {
+                    if book.resubscribe.is_empty() {
+                        warn!(
+                            exchange = %Exchange::ID,
+                            instrument = %book.instrument,
+                            timeout = ?config.timeout,
+                            "OrderBook has not been updated within the watchdog timeout, but the \
+                            exchange does not support re-subscribing"
+                        );
+                        continue;
+                    }
+
+                    warn!(
+                        exchange = %Exchange::ID,
+                        instrument = %book.instrument,
+                        timeout = ?config.timeout,
+                        "OrderBook has not been updated within the watchdog timeout, re-subscribing"
+                    );
+                    for request in book.resubscribe.iter() {
+                        let _ = ws_sink_tx.send(request.clone());
+                    }
+                }
+                Some(ws_sink_tx) => {
+                    warn!(
+                        exchange = %Exchange::ID,
+                        instrument = %book.instrument,
+                        timeout = ?config.timeout,
+                        "OrderBook has not been updated within the watchdog timeout, re-snapshotting"
+                    );
+
+                    // Updates received from now on are buffered until the re-snapshot completes
+                    let subscription_id = book.subscription_id.clone();
+                    pending.insert(subscription_id.clone());
+                    if events_tx
+                        .send(WatchdogEvent::Resnapshotting(subscription_id.clone()))
+                        .is_err()
+                    {
+                        return;
+                    }
+
+                    let init = Updater::init::<Exchange, Kind>(
+                        ws_sink_tx.clone(),
+                        book.instrument.clone(),
+                        book.depth,
+                    );
+                    resnapshots.push(async move { (subscription_id, init.await) });
+                }
+                None => {
+                    warn!(
+                        exchange = %Exchange::ID,
+                        instrument = %book.instrument,
+                        timeout = ?config.timeout,
+                        "OrderBook has not been updated within the watchdog timeout"
+                    );
+                }
+            }
+        }
+
+        tokio::select! {
+            _ = timers.first_update.notified(), if !started => {}
+            _ = tokio::time::sleep_until(tokio::time::Instant::from_std(next_check)), if started => {}
+            Some((subscription_id, result)) = resnapshots.next(), if !resnapshots.is_empty() => {
+                pending.remove(&subscription_id);
+                timers
+                    .last_updates
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .insert(subscription_id.clone(), Instant::now());
+
+                if events_tx
+                    .send(WatchdogEvent::Resnapshot(subscription_id, result))
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        }
+    }
 }
 
 /// Standard generic [`ExchangeTransformer`] to translate exchange specific OrderBook types into
 /// normalised Barter OrderBook types. Requires an exchange specific [`OrderBookUpdater`]
 /// implementation.
+///
+/// Stale [`OrderBook`]s can be detected & re-snapshotted via a [`BookWatchdog`].
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
-pub struct MultiBookTransformer<Exchange, Kind, Updater> {
+pub struct MultiBookTransformer<Exchange, Kind, Updater>
+where
+    Updater: OrderBookUpdater,
+{
     pub book_map: Map<InstrumentOrderBook<Updater>>,
+    #[serde(skip)]
+    watchdog: WatchdogState<Updater>,
     phantom: PhantomData<(Exchange, Kind)>,
 }
 
 #[async_trait]
 impl<Exchange, Kind, Updater> ExchangeTransformer<Exchange, Kind>
     for MultiBookTransformer<Exchange, Kind, Updater>
+where
+    Exchange: Connector + Send + 'static,
+    Kind: SubKind<Event = OrderBook> + Send + 'static,
+    Updater: OrderBookUpdater<OrderBook = Kind::Event> + Send + 'static,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de> + Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        map: Map<Arc<Instrument>>,
+    ) -> Result<Self, DataError> {
+        Self::init(ws_sink_tx, map, |_| None, HashMap::new()).await
+    }
+
+    async fn from_subscriptions(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        map: Map<Arc<Instrument>>,
+        subscriptions: &[Subscription<Exchange, Kind>],
+    ) -> Result<Self, DataError>
+    where
+        Exchange: Sync,
+        Kind: Sync,
+    {
+        let resubscribe = resubscribe_requests::<Exchange, Kind, Updater>(subscriptions);
+
+        // Determine the OrderBook depth requested by the Subscription of each Instrument
+        let depth = |instrument: &Instrument| {
+            subscriptions
+                .iter()
+                .find(|subscription| &subscription.instrument == instrument)
+                .and_then(|subscription| subscription.kind.depth())
+        };
+
+        Self::init(ws_sink_tx, map, depth, resubscribe).await
+    }
+
+    fn reroute(&mut self, added: Map<Arc<Instrument>>, removed: &[SubscriptionId]) -> bool {
+        // Added OrderBooks require a snapshot, so the connection must be re-initialised
+        if !added.0.is_empty() {
+            return false;
+        }
+
+        self.remove(removed);
+        true
+    }
+}
+
+impl<Exchange, Kind, Updater> MultiBookTransformer<Exchange, Kind, Updater>
 where
     Exchange: Connector + Send,
     Kind: SubKind<Event = OrderBook> + Send,
     Updater: OrderBookUpdater<OrderBook = Kind::Event> + Send,
-    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
 {
-    async fn new(
+    /// Construct a new [`Self`] from already initialised [`InstrumentOrderBook`]s (eg/ seeded
+    /// from a recorded snapshot when replaying a recorded session).
+    ///
+    /// Without the connection, the [`BookWatchdog`] is disabled unless enabled via
+    /// [`Self::with_watchdog`], in which case stale [`OrderBook`]s are only logged.
+    pub fn from_books(book_map: Map<InstrumentOrderBook<Updater>>) -> Self {
+        Self {
+            book_map,
+            watchdog: WatchdogState::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<Exchange, Kind, Updater> MultiBookTransformer<Exchange, Kind, Updater>
+where
+    Exchange: Connector + Send + 'static,
+    Kind: SubKind<Event = OrderBook> + Send + 'static,
+    Updater: OrderBookUpdater<OrderBook = Kind::Event> + Send + 'static,
+{
+    /// Override the [`BookWatchdog`] of [`Self`] (see [`BookWatchdog::current`]), or disable it
+    /// with `None`.
+    ///
+    /// Intended to be called before any update is transformed, since it restarts the timer task
+    /// of the [`BookWatchdog`] within the current tokio runtime.
+    pub fn with_watchdog(mut self, watchdog: Option<BookWatchdog>) -> Self {
+        self.watchdog.config = watchdog;
+        self.start_watchdog();
+        self
+    }
+
+    /// Initialise the [`InstrumentOrderBook`] of every [`Instrument`] in the provided [`Map`],
+    /// at the depth determined by the provided `depth` function.
+    async fn init<F>(
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
-        map: Map<Instrument>,
-    ) -> Result<Self, DataError> {
+        map: Map<Arc<Instrument>>,
+        depth: F,
+        resubscribe: HashMap<SubscriptionId, Vec<WsMessage>>,
+    ) -> Result<Self, DataError>
+    where
+        F: Fn(&Instrument) -> Option<u16>,
+    {
         // Initialise InstrumentOrderBooks for all Subscriptions
         let (sub_ids, init_book_requests): (Vec<_>, Vec<_>) = map
             .0
             .into_iter()
             .map(|(sub_id, instrument)| {
+                let depth = depth(&instrument);
                 (
                     sub_id,
-                    Updater::init::<Exchange, Kind>(ws_sink_tx.clone(), instrument),
+                    Updater::init::<Exchange, Kind>(ws_sink_tx.clone(), instrument, depth),
                 )
             })
             .unzip();
@@ -95,21 +628,225 @@ where
         // Construct OrderBookMap if all requests successful
         let book_map = sub_ids
             .into_iter()
-            .zip(init_order_books.into_iter())
+            .zip(init_order_books)
             .collect::<Map<InstrumentOrderBook<Updater>>>();
 
-        Ok(Self {
-            book_map,
-            phantom: PhantomData::default(),
+        let mut transformer = Self::from_books(book_map);
+        transformer.watchdog =
+            WatchdogState::new(BookWatchdog::current(), Some(ws_sink_tx), resubscribe);
+        transformer.start_watchdog();
+        Ok(transformer)
+    }
+
+    /// (Re)start the timer task of the [`BookWatchdog`] of [`Self`], if enabled.
+    fn start_watchdog(&mut self) {
+        let watchdog = &mut self.watchdog;
+        if let Some(task) = watchdog.task.take() {
+            task.abort();
+        }
+        watchdog.events_rx = None;
+        watchdog.buffered.clear();
+
+        let Some(config) = watchdog.config else {
+            return;
+        };
+
+        let books = self
+            .book_map
+            .0
+            .iter()
+            .map(|(subscription_id, book)| WatchedBook {
+                subscription_id: subscription_id.clone(),
+                instrument: book.instrument.clone(),
+                depth: book.depth,
+                resubscribe: watchdog
+                    .resubscribe
+                    .get(subscription_id)
+                    .cloned()
+                    .unwrap_or_default(),
+            })
+            .collect();
+
+        let (events_tx, events_rx) = mpsc::unbounded_channel();
+        watchdog.timers = Arc::default();
+        let task = tokio::spawn(run_watchdog::<Exchange, Kind, Updater>(
+            config,
+            books,
+            watchdog.ws_sink_tx.clone(),
+            Arc::clone(&watchdog.timers),
+            events_tx,
+        ));
+
+        watchdog.events_rx = Some(events_rx);
+        watchdog.task = Some(task.abort_handle());
+    }
+
+    /// Stop maintaining the [`OrderBook`]s of the provided [`SubscriptionId`]s (eg/ once
+    /// unsubscribed over the open connection), so they are no longer watched by the
+    /// [`BookWatchdog`].
+    fn remove(&mut self, removed: &[SubscriptionId]) {
+        let mut unwatched = self
+            .watchdog
+            .timers
+            .removed
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        for subscription_id in removed {
+            self.book_map.0.remove(subscription_id);
+            self.watchdog.buffered.remove(subscription_id);
+            self.watchdog.resubscribe.remove(subscription_id);
+            unwatched.insert(subscription_id.clone());
+        }
+    }
+
+    /// Apply the [`WatchdogEvent`]s received from the timer task of the [`BookWatchdog`],
+    /// returning the refreshed [`MarketEvent<OrderBook>`]s of completed re-snapshots.
+    ///
+    /// The updates buffered whilst each snapshot was in flight are replayed on top of it, or on
+    /// top of the stale [`OrderBook`] if the re-snapshot failed.
+    fn resnapshots(
+        &mut self,
+        received_time: DateTime<Utc>,
+    ) -> Vec<Result<MarketEvent<OrderBook>, DataError>> {
+        let Some(events_rx) = self.watchdog.events_rx.as_mut() else {
+            return vec![];
+        };
+        let events = std::iter::from_fn(|| events_rx.try_recv().ok()).collect::<Vec<_>>();
+
+        let mut output = Vec::new();
+        for event in events {
+            let (subscription_id, result) = match event {
+                WatchdogEvent::Resnapshotting(subscription_id) => {
+                    if self.book_map.0.contains_key(&subscription_id) {
+                        self.watchdog.buffered.insert(subscription_id, Vec::new());
+                    }
+                    continue;
+                }
+                WatchdogEvent::Resnapshot(subscription_id, result) => (subscription_id, result),
+            };
+
+            // Discard re-snapshots of OrderBooks removed whilst the snapshot was in flight
+            if !self.book_map.0.contains_key(&subscription_id) {
+                self.watchdog.buffered.remove(&subscription_id);
+                continue;
+            }
+
+            match result {
+                Ok(book) => {
+                    output.extend(snapshot::<Exchange>(
+                        &book,
+                        book.book.clone(),
+                        received_time,
+                    ));
+                    self.book_map.0.insert(subscription_id.clone(), book);
+                }
+                Err(error) => output.push(Err(error)),
+            }
+
+            let buffered = self
+                .watchdog
+                .buffered
+                .remove(&subscription_id)
+                .unwrap_or_default();
+            for update in buffered {
+                output.extend(self.apply(&subscription_id, update, received_time));
+            }
+        }
+
+        output
+    }
+
+    /// Apply the update (snapshot or delta) to the [`OrderBook`] of the provided
+    /// [`SubscriptionId`], returning the generated [`MarketEvent<OrderBook>`] snapshot.
+    fn apply(
+        &mut self,
+        subscription_id: &SubscriptionId,
+        update: Updater::Update,
+        received_time: DateTime<Utc>,
+    ) -> Vec<Result<MarketEvent<OrderBook>, DataError>> {
+        // Retrieve the InstrumentOrderBook associated with this update (snapshot or delta)
+        let instrument_book = match self.book_map.find_mut(subscription_id) {
+            Ok(book) => book,
+            Err(unidentifiable) => return vec![Err(DataError::from(unidentifiable))],
+        };
+
+        // Apply update (snapshot or delta) to OrderBook & generate Market<OrderBook> snapshot
+        match instrument_book
+            .updater
+            .update(&mut instrument_book.book, update)
+        {
+            Ok(Some(book)) => snapshot::<Exchange>(instrument_book, book, received_time),
+            Ok(None) => vec![],
+            Err(error) => vec![Err(error)],
+        }
+    }
+}
+
+/// Generate the requests that re-subscribe each [`Subscription`] (unsubscribe, then subscribe),
+/// used by a [`BookWatchdog`] to re-snapshot [`OrderBook`]s sent over the WebSocket.
+///
+/// Empty if the [`OrderBookUpdater`] fetches its own snapshots, or the exchange does not support
+/// unsubscribing.
+fn resubscribe_requests<Exchange, Kind, Updater>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+) -> HashMap<SubscriptionId, Vec<WsMessage>>
+where
+    Exchange: Connector,
+    Updater: OrderBookUpdater,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    if !Updater::WS_SNAPSHOT {
+        return HashMap::new();
+    }
+
+    subscriptions
+        .iter()
+        .filter_map(|subscription| {
+            let exchange_sub =
+                ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription);
+            let subscription_id = exchange_sub.id();
+
+            let mut requests = Exchange::unsubscribe_requests(vec![exchange_sub]);
+            if requests.is_empty() {
+                return None;
+            }
+            requests.extend(Exchange::requests(vec![ExchangeSub::new(subscription)]));
+            Some((subscription_id, requests))
         })
+        .collect()
+}
+
+/// Generate the [`MarketEvent<OrderBook>`] of the provided [`OrderBook`] snapshot, truncated to the
+/// depth of the [`InstrumentOrderBook`].
+fn snapshot<Exchange>(
+    book: &InstrumentOrderBook<impl Sized>,
+    mut snapshot: OrderBook,
+    received_time: DateTime<Utc>,
+) -> Vec<Result<MarketEvent<OrderBook>, DataError>>
+where
+    Exchange: Connector,
+{
+    if let Some(depth) = book.depth {
+        snapshot.truncate(usize::from(depth));
     }
+    MarketIter::<OrderBook>::from((Exchange::ID, book.instrument.clone(), snapshot))
+        .0
+        .into_iter()
+        .map(|event| {
+            event.map(|event| MarketEvent {
+                received_time,
+                ..event
+            })
+        })
+        .collect()
 }
 
 impl<Exchange, Kind, Updater> Transformer for MultiBookTransformer<Exchange, Kind, Updater>
 where
-    Exchange: Connector,
-    Kind: SubKind<Event = OrderBook>,
-    Updater: OrderBookUpdater<OrderBook = Kind::Event>,
+    Exchange: Connector + Send + 'static,
+    Kind: SubKind<Event = OrderBook> + Send + 'static,
+    Updater: OrderBookUpdater<OrderBook = Kind::Event> + Send + 'static,
     Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
 {
     type Error = DataError;
@@ -118,32 +855,917 @@ where
     type OutputIter = Vec<Result<Self::Output, Self::Error>>;
 
     fn transform(&mut self, update: Self::Input) -> Self::OutputIter {
+        // Stamp the received_time before applying the update, so it excludes the time spent
+        // maintaining the OrderBook
+        let received_time = clock::received_time();
+
         // Determine if the update has an identifiable SubscriptionId
         let subscription_id = match update.id() {
             Some(subscription_id) => subscription_id,
             None => return vec![],
         };
 
-        // Retrieve the InstrumentOrderBook associated with this update (snapshot or delta)
-        let book = match self.book_map.find_mut(&subscription_id) {
-            Ok(book) => book,
-            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        // Yield the refreshed OrderBooks of any completed BookWatchdog re-snapshots first
+        let mut output = self.resnapshots(received_time);
+        self.watchdog.record_update(&subscription_id);
+
+        // Buffer updates received whilst the OrderBook is re-snapshotted, to replay on top of it
+        if let Some(buffered) = self.watchdog.buffered.get_mut(&subscription_id) {
+            buffered.push(update);
+            return output;
+        }
+
+        output.extend(self.apply(&subscription_id, update, received_time));
+        output
+    }
+}
+
+/// [`ExchangeTransformer`] that reconstructs the level 2 [`OrderBook`] of each [`Instrument`]
+/// using a [`MultiBookTransformer`], but only yields a lightweight [`TopOfBook`] each time the
+/// top of the [`OrderBook`] changes.
+///
+/// ### Notes
+/// One-sided or empty [`OrderBook`]s have no [`TopOfBook`], and are skipped.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TopOfBookTransformer<Exchange, Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    pub books: MultiBookTransformer<Exchange, OrderBooksL2, Updater>,
+    tops: HashMap<Arc<Instrument>, TopOfBook>,
+}
+
+impl<Exchange, Updater> TopOfBookTransformer<Exchange, Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    /// Construct a new [`Self`] that yields the [`TopOfBook`] of the [`OrderBook`]s maintained
+    /// by the provided [`MultiBookTransformer`].
+    pub fn from_books(books: MultiBookTransformer<Exchange, OrderBooksL2, Updater>) -> Self {
+        Self {
+            books,
+            tops: HashMap::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Exchange, Updater> ExchangeTransformer<Exchange, OrderBooksTop>
+    for TopOfBookTransformer<Exchange, Updater>
+where
+    Exchange: Connector + Send + 'static,
+    Updater: OrderBookUpdater<OrderBook = OrderBook> + Send + 'static,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de> + Send,
+    Subscription<Exchange, OrderBooksTop>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        map: Map<Arc<Instrument>>,
+    ) -> Result<Self, DataError> {
+        MultiBookTransformer::init(ws_sink_tx, map, |_| None, HashMap::new())
+            .await
+            .map(Self::from_books)
+    }
+
+    async fn from_subscriptions(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        map: Map<Arc<Instrument>>,
+        subscriptions: &[Subscription<Exchange, OrderBooksTop>],
+    ) -> Result<Self, DataError>
+    where
+        Exchange: Sync,
+    {
+        let resubscribe = resubscribe_requests::<Exchange, OrderBooksTop, Updater>(subscriptions);
+        MultiBookTransformer::init(ws_sink_tx, map, |_| None, resubscribe)
+            .await
+            .map(Self::from_books)
+    }
+
+    fn reroute(&mut self, added: Map<Arc<Instrument>>, removed: &[SubscriptionId]) -> bool {
+        // Added OrderBooks require a snapshot, so the connection must be re-initialised
+        if !added.0.is_empty() {
+            return false;
+        }
+
+        let instruments = removed
+            .iter()
+            .filter_map(|subscription_id| self.books.book_map.0.get(subscription_id))
+            .map(|book| book.instrument.clone())
+            .collect::<Vec<_>>();
+        self.books.remove(removed);
+        instruments.iter().for_each(|instrument| {
+            self.tops.remove(instrument);
+        });
+        true
+    }
+}
+
+impl<Exchange, Updater> Transformer for TopOfBookTransformer<Exchange, Updater>
+where
+    Exchange: Connector + Send + 'static,
+    Updater: OrderBookUpdater<OrderBook = OrderBook> + Send + 'static,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+{
+    type Error = DataError;
+    type Input = Updater::Update;
+    type Output = MarketEvent<TopOfBook>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, update: Self::Input) -> Self::OutputIter {
+        self.books
+            .transform(update)
+            .into_iter()
+            .filter_map(|result| {
+                let event = match result {
+                    Ok(event) => event,
+                    Err(error) => return Some(Err(error)),
+                };
+
+                // Skip one-sided or empty books
+                let top = event.kind.top()?;
+
+                // Only yield if the top of the OrderBook has changed
+                match self.tops.get_mut(&event.instrument) {
+                    Some(previous) if previous.eq_top(&top) => return None,
+                    Some(previous) => *previous = top,
+                    None => {
+                        self.tops.insert(event.instrument.clone(), top);
+                    }
+                }
+
+                Some(Ok(MarketEvent {
+                    exchange_time: event.exchange_time,
+                    received_time: event.received_time,
+                    exchange: event.exchange,
+                    instrument: event.instrument,
+                    kind: top,
+                }))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::spot::BinanceSpot,
+        streams::builder::validate,
+        subscription::{
+            book::{Level, OrderBookSide, OrderBooksL2Depth},
+            intern::intern,
+        },
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    /// Duration the [`MockUpdater`] takes to initialise the snapshot of a "slow" [`Instrument`].
+    const MOCK_SLOW_INIT: Duration = Duration::from_millis(200);
+
+    /// Every [`Instrument`] initialised by the [`MockUpdater`].
+    static MOCK_INITS: Mutex<Vec<Instrument>> = Mutex::new(Vec::new());
+
+    fn mock_inits(instrument: &Instrument) -> usize {
+        MOCK_INITS
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|init| *init == instrument)
+            .count()
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    /// [`OrderBookUpdater`] that initialises an [`OrderBook`] with ten [`Level`]s per side, and
+    /// generates a snapshot for every update after upserting its [`Level`]s. Updates containing a
+    /// negative price are rejected with a [`DataError::BookDesync`].
+    ///
+    /// Snapshots of "slow" [`Instrument`]s take [`MOCK_SLOW_INIT`] to initialise.
+    #[derive(Copy, Clone, Debug)]
+    struct MockUpdater;
+
+    #[derive(Debug, Deserialize)]
+    struct MockUpdate {
+        subscription_id: SubscriptionId,
+        bids: Vec<(f64, f64)>,
+        asks: Vec<(f64, f64)>,
+    }
+
+    impl MockUpdate {
+        fn new(subscription_id: &str, bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>) -> Self {
+            Self {
+                subscription_id: SubscriptionId::from(subscription_id),
+                bids,
+                asks,
+            }
+        }
+    }
+
+    impl Identifier<Option<SubscriptionId>> for MockUpdate {
+        fn id(&self) -> Option<SubscriptionId> {
+            Some(self.subscription_id.clone())
+        }
+    }
+
+    #[async_trait]
+    impl OrderBookUpdater for MockUpdater {
+        type OrderBook = OrderBook;
+        type Update = MockUpdate;
+
+        async fn init<Exchange, Kind>(
+            _: mpsc::UnboundedSender<WsMessage>,
+            instrument: Arc<Instrument>,
+            depth: Option<u16>,
+        ) -> Result<InstrumentOrderBook<Self>, DataError>
+        where
+            Exchange: Connector + Send,
+            Kind: Send,
+        {
+            MOCK_INITS
+                .lock()
+                .unwrap()
+                .push(Instrument::clone(&instrument));
+
+            if instrument.base.as_ref() == "slow" {
+                tokio::time::sleep(MOCK_SLOW_INIT).await;
+            }
+
+            let levels = |side| {
+                OrderBookSide::new(
+                    side,
+                    (1..=10).map(|price| Level::new(f64::from(price), 1.0)),
+                )
+            };
+
+            Ok(InstrumentOrderBook {
+                instrument,
+                updater: Self,
+                book: OrderBook {
+                    last_update_time: Utc::now(),
+                    bids: levels(Side::Buy),
+                    asks: levels(Side::Sell),
+                },
+                depth,
+            })
+        }
+
+        fn update(
+            &mut self,
+            book: &mut Self::OrderBook,
+            update: Self::Update,
+        ) -> Result<Option<Self::OrderBook>, DataError> {
+            if update
+                .bids
+                .iter()
+                .chain(&update.asks)
+                .any(|(price, _)| *price < 0.0)
+            {
+                return Err(DataError::BookDesync);
+            }
+            book.bids.upsert(update.bids);
+            book.asks.upsert(update.asks);
+            Ok(Some(book.snapshot()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_multi_book_transformer_per_instrument_depth() {
+        let subscription = |base: &str, depth: u16| {
+            Subscription::from((
+                BinanceSpot::default(),
+                base,
+                "usdt",
+                InstrumentKind::Spot,
+                OrderBooksL2Depth { depth },
+            ))
         };
 
-        // De-structure for ease
-        let InstrumentOrderBook {
-            instrument,
-            book,
-            updater,
-        } = book;
+        // Deep book for btc, shallow book for sol
+        let subscriptions = vec![subscription("btc", 10), subscription("sol", 5)];
+        assert!(validate(&subscriptions).is_ok());
 
-        // Apply update (snapshot or delta) to OrderBook & generate Market<OrderBook> snapshot
-        match updater.update(book, update) {
-            Ok(Some(book)) => {
-                MarketIter::<OrderBook>::from((Exchange::ID, instrument.clone(), book)).0
+        // Depths unsupported by the exchange are rejected
+        assert!(validate(&[subscription("btc", 7)]).is_err());
+
+        let map = subscriptions
+            .iter()
+            .map(|subscription| {
+                (
+                    SubscriptionId::from(subscription.instrument.base.as_ref()),
+                    intern(&subscription.instrument),
+                )
+            })
+            .collect::<Map<Arc<Instrument>>>();
+
+        let (ws_sink_tx, _) = mpsc::unbounded_channel();
+        let mut transformer = MultiBookTransformer::<
+            BinanceSpot,
+            OrderBooksL2Depth,
+            MockUpdater,
+        >::from_subscriptions(ws_sink_tx, map, &subscriptions)
+        .await
+        .unwrap();
+
+        struct TestCase {
+            input: &'static str,
+            expected_depth: usize,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: btc book is maintained at depth 10
+                input: "btc",
+                expected_depth: 10,
+            },
+            TestCase {
+                // TC1: sol book is maintained at depth 5
+                input: "sol",
+                expected_depth: 5,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer
+                .transform(MockUpdate::new(test.input, vec![], vec![]))
+                .remove(0)
+                .unwrap();
+
+            assert_eq!(
+                actual.kind.bids.levels.len(),
+                test.expected_depth,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                actual.kind.asks.levels.len(),
+                test.expected_depth,
+                "TC{index} failed"
+            );
+
+            // Best levels of each side are retained: bids descending, asks ascending
+            let prices = |side: &OrderBookSide| {
+                side.levels
+                    .iter()
+                    .map(|level| level.price)
+                    .collect::<Vec<_>>()
+            };
+            let expected_bids = (11 - test.expected_depth..=10)
+                .rev()
+                .map(|price| price as f64)
+                .collect::<Vec<_>>();
+            let expected_asks = (1..=test.expected_depth)
+                .map(|price| price as f64)
+                .collect::<Vec<_>>();
+            assert_eq!(prices(&actual.kind.bids), expected_bids, "TC{index} failed");
+            assert_eq!(prices(&actual.kind.asks), expected_asks, "TC{index} failed");
+        }
+
+        // Full OrderBook state is maintained beneath the truncated view
+        let full = transformer
+            .book_map
+            .find_mut(&SubscriptionId::from("sol"))
+            .unwrap();
+        assert_eq!(full.book.bids.levels.len(), 10);
+        assert_eq!(full.book.asks.levels.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_multi_book_transformer_reroute_removes_books() {
+        let btc = intern(&Instrument::from(("btc", "usdt", InstrumentKind::Spot)));
+        let eth = intern(&Instrument::from(("eth", "usdt", InstrumentKind::Spot)));
+
+        let (ws_sink_tx, _) = mpsc::unbounded_channel();
+        let mut transformer = MultiBookTransformer::<BinanceSpot, OrderBooksL2, MockUpdater>::new(
+            ws_sink_tx,
+            Map::from_iter([
+                (SubscriptionId::from("btc"), btc.clone()),
+                (SubscriptionId::from("eth"), eth.clone()),
+            ]),
+        )
+        .await
+        .unwrap();
+
+        // Added OrderBooks require a snapshot, so cannot be routed live
+        let added = Map::from_iter([(SubscriptionId::from("sol"), eth)]);
+        assert!(!ExchangeTransformer::<BinanceSpot, OrderBooksL2>::reroute(
+            &mut transformer,
+            added,
+            &[]
+        ));
+        assert_eq!(transformer.book_map.0.len(), 2);
+
+        // Removed OrderBooks are no longer maintained
+        assert!(ExchangeTransformer::<BinanceSpot, OrderBooksL2>::reroute(
+            &mut transformer,
+            Map::from_iter([]),
+            &[SubscriptionId::from("eth")]
+        ));
+        assert_eq!(
+            transformer.book_map.0.keys().collect::<Vec<_>>(),
+            vec![&SubscriptionId::from("btc")]
+        );
+        assert!(matches!(
+            transformer
+                .transform(MockUpdate::new("eth", vec![(11.0, 1.0)], vec![]))
+                .as_slice(),
+            [Err(DataError::Unidentifiable(_))]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_multi_book_transformer_errors() {
+        let (ws_sink_tx, _) = mpsc::unbounded_channel();
+        let mut transformer = MultiBookTransformer::<BinanceSpot, OrderBooksL2, MockUpdater>::new(
+            ws_sink_tx,
+            Map::from_iter([(
+                SubscriptionId::from("btc"),
+                intern(&Instrument::from(("btc", "usdt", InstrumentKind::Spot))),
+            )]),
+        )
+        .await
+        .unwrap();
+
+        // Update of an unknown SubscriptionId is yielded as DataError::Unidentifiable
+        match transformer
+            .transform(MockUpdate::new("eth", vec![(11.0, 1.0)], vec![]))
+            .as_slice()
+        {
+            [Err(DataError::Unidentifiable(subscription_id))] => {
+                assert_eq!(subscription_id, &SubscriptionId::from("eth"))
             }
-            Ok(None) => vec![],
-            Err(error) => vec![Err(error)],
+            other => panic!("expected DataError::Unidentifiable, got: {other:?}"),
         }
+
+        // Update rejected by the OrderBookUpdater is yielded as the OrderBookUpdater DataError
+        match transformer
+            .transform(MockUpdate::new("btc", vec![(-1.0, 1.0)], vec![]))
+            .as_slice()
+        {
+            [Err(DataError::BookDesync)] => {}
+            other => panic!("expected DataError::BookDesync, got: {other:?}"),
+        }
+
+        // Subsequent valid updates are still applied
+        assert!(matches!(
+            transformer
+                .transform(MockUpdate::new("btc", vec![(11.0, 1.0)], vec![]))
+                .as_slice(),
+            [Ok(_)]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_multi_book_transformer_stamps_received_time() {
+        let (ws_sink_tx, _) = mpsc::unbounded_channel();
+        let mut transformer = MultiBookTransformer::<BinanceSpot, OrderBooksL2, MockUpdater>::new(
+            ws_sink_tx,
+            Map::from_iter([(
+                SubscriptionId::from("btc"),
+                intern(&Instrument::from(("btc", "usdt", InstrumentKind::Spot))),
+            )]),
+        )
+        .await
+        .unwrap();
+
+        let before = Utc::now();
+        let actual = transformer
+            .transform(MockUpdate::new("btc", vec![(11.0, 1.0)], vec![]))
+            .remove(0)
+            .unwrap();
+        let after = Utc::now();
+
+        assert!(before <= actual.received_time && actual.received_time <= after);
+        assert_eq!(
+            actual.latency(),
+            (actual.received_time - actual.kind.last_update_time)
+                .to_std()
+                .ok()
+        );
+        assert!(actual.latency().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_multi_book_transformer_watchdog_resnapshots_quiet_book() {
+        struct TestCase {
+            quiet: &'static str,
+            resnapshot: bool,
+            expected_resnapshot: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: quiet OrderBook is re-snapshotted & the refreshed OrderBook is yielded
+                quiet: "quiet0",
+                resnapshot: true,
+                expected_resnapshot: true,
+            },
+            TestCase {
+                // TC1: quiet OrderBook is only logged if re-snapshotting is disabled
+                quiet: "quiet1",
+                resnapshot: false,
+                expected_resnapshot: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let active = Instrument::from(("active", "usdt", InstrumentKind::Spot));
+            let quiet = Instrument::from((test.quiet, "usdt", InstrumentKind::Spot));
+
+            let (ws_sink_tx, _) = mpsc::unbounded_channel();
+            let mut transformer =
+                MultiBookTransformer::<BinanceSpot, OrderBooksL2, MockUpdater>::new(
+                    ws_sink_tx,
+                    Map::from_iter([
+                        (SubscriptionId::from("active"), intern(&active)),
+                        (SubscriptionId::from("quiet"), intern(&quiet)),
+                    ]),
+                )
+                .await
+                .unwrap()
+                .with_watchdog(Some(BookWatchdog {
+                    timeout: Duration::from_millis(200),
+                    resnapshot: test.resnapshot,
+                }));
+            assert_eq!(mock_inits(&quiet), 1, "TC{index} failed");
+
+            // Only the active Instrument is updated, for longer than the watchdog timeout
+            let mut quiet_events = 0;
+            for _ in 0..15 {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                for event in
+                    transformer.transform(MockUpdate::new("active", vec![(11.0, 1.0)], vec![]))
+                {
+                    if *event.unwrap().instrument == quiet {
+                        quiet_events += 1;
+                    }
+                }
+            }
+
+            if test.expected_resnapshot {
+                assert!(mock_inits(&quiet) >= 2, "TC{index} failed");
+                assert!(quiet_events >= 1, "TC{index} failed");
+            } else {
+                assert_eq!(mock_inits(&quiet), 1, "TC{index} failed");
+                assert_eq!(quiet_events, 0, "TC{index} failed");
+            }
+        }
+
+        // The active Instrument is never re-snapshotted
+        let active = Instrument::from(("active", "usdt", InstrumentKind::Spot));
+        assert_eq!(mock_inits(&active), 2);
+    }
+
+    #[tokio::test]
+    async fn test_multi_book_transformer_watchdog_is_timer_driven() {
+        let lonely = Instrument::from(("lonely", "usdt", InstrumentKind::Spot));
+
+        let (ws_sink_tx, _) = mpsc::unbounded_channel();
+        let mut transformer = MultiBookTransformer::<BinanceSpot, OrderBooksL2, MockUpdater>::new(
+            ws_sink_tx,
+            Map::from_iter([(SubscriptionId::from("lonely"), intern(&lonely))]),
+        )
+        .await
+        .unwrap()
+        .with_watchdog(Some(BookWatchdog {
+            timeout: Duration::from_millis(50),
+            resnapshot: true,
+        }));
+
+        // Timers start once the connection delivers its first update
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(mock_inits(&lonely), 1);
+        transformer.transform(MockUpdate::new("lonely", vec![(11.0, 1.0)], vec![]));
+
+        // Only OrderBook goes quiet, and is re-snapshotted without any other message arriving
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        assert!(mock_inits(&lonely) >= 2);
+
+        // Refreshed OrderBook is yielded alongside the next message
+        let actual = transformer.transform(MockUpdate::new("lonely", vec![], vec![]));
+        assert!(actual.len() >= 2);
+        assert!(actual.iter().all(|event| event.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn test_multi_book_transformer_watchdog_replays_buffered_updates() {
+        let slow = Instrument::from(("slow", "usdt", InstrumentKind::Spot));
+
+        let (ws_sink_tx, _) = mpsc::unbounded_channel();
+        let mut transformer = MultiBookTransformer::<BinanceSpot, OrderBooksL2, MockUpdater>::new(
+            ws_sink_tx,
+            Map::from_iter([(SubscriptionId::from("slow"), intern(&slow))]),
+        )
+        .await
+        .unwrap()
+        .with_watchdog(Some(BookWatchdog {
+            timeout: Duration::from_millis(100),
+            resnapshot: true,
+        }));
+
+        // Stale OrderBook is re-snapshotted after the timeout
+        let actual = transformer.transform(MockUpdate::new("slow", vec![(11.0, 1.0)], vec![]));
+        assert_eq!(actual.len(), 1);
+        tokio::time::sleep(Duration::from_millis(150)).await;
+
+        // Update received whilst the snapshot is in flight is buffered
+        let actual = transformer.transform(MockUpdate::new("slow", vec![(12.0, 1.0)], vec![]));
+        assert!(actual.is_empty());
+        tokio::time::sleep(MOCK_SLOW_INIT).await;
+
+        // Refreshed OrderBook is yielded, followed by the replayed & latest updates on top of it
+        let bids = |event: &Result<MarketEvent<OrderBook>, DataError>| {
+            let event = event.as_ref().unwrap();
+            [11.0, 12.0, 13.0].map(|price| {
+                event
+                    .kind
+                    .bids
+                    .levels
+                    .iter()
+                    .any(|level| level.price == price)
+            })
+        };
+        let actual = transformer.transform(MockUpdate::new("slow", vec![(13.0, 1.0)], vec![]));
+        assert_eq!(
+            actual.iter().map(bids).collect::<Vec<_>>(),
+            vec![
+                [false, false, false],
+                [false, true, false],
+                [false, true, true],
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_multi_book_transformer_watchdog_resubscribes_ws_snapshots() {
+        /// [`MockUpdater`] whose snapshot is sent over the WebSocket.
+        #[derive(Copy, Clone, Debug)]
+        struct MockWsUpdater;
+
+        #[async_trait]
+        impl OrderBookUpdater for MockWsUpdater {
+            type OrderBook = OrderBook;
+            type Update = MockUpdate;
+            const WS_SNAPSHOT: bool = true;
+
+            async fn init<Exchange, Kind>(
+                _: mpsc::UnboundedSender<WsMessage>,
+                instrument: Arc<Instrument>,
+                depth: Option<u16>,
+            ) -> Result<InstrumentOrderBook<Self>, DataError>
+            where
+                Exchange: Connector + Send,
+                Kind: Send,
+            {
+                Ok(InstrumentOrderBook {
+                    instrument,
+                    updater: Self,
+                    book: OrderBook {
+                        last_update_time: Utc::now(),
+                        bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                        asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                    },
+                    depth,
+                })
+            }
+
+            fn update(
+                &mut self,
+                book: &mut Self::OrderBook,
+                update: Self::Update,
+            ) -> Result<Option<Self::OrderBook>, DataError> {
+                MockUpdater.update(book, update)
+            }
+        }
+
+        let subscription = Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            OrderBooksL2,
+        ));
+        let subscription_id = ExchangeSub::<
+            <BinanceSpot as Connector>::Channel,
+            <BinanceSpot as Connector>::Market,
+        >::new(&subscription)
+        .id();
+        let map = Map::from_iter([(subscription_id.clone(), intern(&subscription.instrument))]);
+
+        // BookWatchdog is configured by the scope the MultiBookTransformer is constructed in
+        let (ws_sink_tx, mut ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = with_book_watchdog(
+            Some(BookWatchdog {
+                timeout: Duration::from_millis(50),
+                resnapshot: true,
+            }),
+            MultiBookTransformer::<BinanceSpot, OrderBooksL2, MockWsUpdater>::from_subscriptions(
+                ws_sink_tx,
+                map,
+                &[subscription],
+            ),
+        )
+        .await
+        .unwrap();
+
+        transformer.transform(MockUpdate::new(
+            subscription_id.as_ref(),
+            vec![(11.0, 1.0)],
+            vec![],
+        ));
+
+        // Stale OrderBook is re-subscribed, so the exchange sends a fresh snapshot
+        let requests = tokio::time::timeout(Duration::from_secs(1), async {
+            let mut requests = Vec::new();
+            while requests.len() < 2 {
+                match ws_sink_rx.recv().await {
+                    Some(WsMessage::Text(request)) => requests.push(request),
+                    next => panic!("expected text request, got: {next:?}"),
+                }
+            }
+            requests
+        })
+        .await
+        .expect("OrderBook was not re-subscribed");
+
+        assert!(requests[0].contains(r#""method":"UNSUBSCRIBE""#));
+        assert!(requests[1].contains(r#""method":"SUBSCRIBE""#));
+        assert!(requests
+            .iter()
+            .all(|request| request.contains("btcusdt@depth")));
+    }
+
+    #[tokio::test]
+    async fn test_top_of_book_transformer_yields_top_changes() {
+        let map = || {
+            Map::from_iter([(
+                SubscriptionId::from("btc"),
+                intern(&Instrument::from(("btc", "usdt", InstrumentKind::Spot))),
+            )])
+        };
+
+        let (ws_sink_tx, _) = mpsc::unbounded_channel();
+        let mut tops = <TopOfBookTransformer<BinanceSpot, MockUpdater> as ExchangeTransformer<
+            BinanceSpot,
+            OrderBooksTop,
+        >>::new(ws_sink_tx.clone(), map())
+        .await
+        .unwrap();
+        let mut books =
+            MultiBookTransformer::<BinanceSpot, OrderBooksL2, MockUpdater>::new(ws_sink_tx, map())
+                .await
+                .unwrap();
+
+        struct TestCase {
+            input: fn() -> MockUpdate,
+            expected_change: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: initial top is yielded
+                input: || MockUpdate::new("btc", vec![], vec![]),
+                expected_change: true,
+            },
+            TestCase {
+                // TC1: update below the top is not yielded
+                input: || MockUpdate::new("btc", vec![(5.0, 3.0)], vec![(5.0, 3.0)]),
+                expected_change: false,
+            },
+            TestCase {
+                // TC2: best bid amount change is yielded
+                input: || MockUpdate::new("btc", vec![(10.0, 2.0)], vec![]),
+                expected_change: true,
+            },
+            TestCase {
+                // TC3: new best ask is yielded
+                input: || MockUpdate::new("btc", vec![], vec![(0.5, 4.0)]),
+                expected_change: true,
+            },
+            TestCase {
+                // TC4: removing a level below the top is not yielded
+                input: || MockUpdate::new("btc", vec![(9.0, 0.0)], vec![]),
+                expected_change: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = tops.transform((test.input)());
+            let book = books.transform((test.input)()).remove(0).unwrap();
+
+            match (actual.as_slice(), test.expected_change) {
+                ([Ok(actual)], true) => {
+                    // TopOfBook matches the top of the full OrderBook
+                    let expected = book.kind.top().unwrap();
+                    assert!(
+                        actual.kind.eq_top(&expected),
+                        "TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n"
+                    );
+                    assert_eq!(actual.instrument, book.instrument, "TC{index} failed");
+                }
+                ([], false) => {
+                    // Test passed
+                }
+                (actual, expected_change) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected change: {expected_change}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_tagged_book_sync() {
+        struct TestCase {
+            kind: BookMessageKind,
+            bids: Vec<(f64, f64)>,
+            asks: Vec<(f64, f64)>,
+            expected: Result<(Vec<Level>, Vec<Level>), DataError>,
+        }
+
+        let time = Utc::now();
+        let mut sync = TaggedBookSync::default();
+        let mut book = OrderBook {
+            last_update_time: time,
+            bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+            asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: update before any snapshot desyncs, leaving the book untouched
+                kind: BookMessageKind::Update,
+                bids: vec![(99.0, 1.0)],
+                asks: vec![(101.0, 1.0)],
+                expected: Err(DataError::BookDesync),
+            },
+            TestCase {
+                // TC1: snapshot resets the book
+                kind: BookMessageKind::Snapshot,
+                bids: vec![(99.0, 1.0), (98.0, 2.0)],
+                asks: vec![(101.0, 1.0), (102.0, 2.0)],
+                expected: Ok((
+                    vec![Level::new(99.0, 1.0), Level::new(98.0, 2.0)],
+                    vec![Level::new(101.0, 1.0), Level::new(102.0, 2.0)],
+                )),
+            },
+            TestCase {
+                // TC2: update after the snapshot applies deltas
+                kind: BookMessageKind::Update,
+                bids: vec![(99.0, 0.0), (99.5, 3.0)],
+                asks: vec![(102.0, 5.0)],
+                expected: Ok((
+                    vec![Level::new(99.5, 3.0), Level::new(98.0, 2.0)],
+                    vec![Level::new(101.0, 1.0), Level::new(102.0, 5.0)],
+                )),
+            },
+            TestCase {
+                // TC3: subsequent snapshot replaces (rather than merges into) the book
+                kind: BookMessageKind::Snapshot,
+                bids: vec![(97.0, 1.0)],
+                asks: vec![(103.0, 1.0)],
+                expected: Ok((vec![Level::new(97.0, 1.0)], vec![Level::new(103.0, 1.0)])),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = sync
+                .apply(&mut book, test.kind, time, test.bids, test.asks)
+                .map(|_| {
+                    let snapshot = book.snapshot();
+                    (snapshot.bids.levels, snapshot.asks.levels)
+                });
+
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => assert_eq!(actual, expected, "TC{index} failed"),
+                (Err(DataError::BookDesync), Err(DataError::BookDesync)) => {
+                    assert!(book.bids.levels.is_empty(), "TC{index} failed");
+                    assert!(book.asks.levels.is_empty(), "TC{index} failed");
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_de_book_message_kind() {
+        let actual =
+            serde_json::from_str::<Vec<BookMessageKind>>(r#"["snapshot", "update", "delta"]"#)
+                .unwrap();
+        assert_eq!(
+            actual,
+            vec![
+                BookMessageKind::Snapshot,
+                BookMessageKind::Update,
+                BookMessageKind::Update
+            ]
+        );
     }
 }
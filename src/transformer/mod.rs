@@ -1,12 +1,15 @@
 use crate::{
     error::DataError,
     event::MarketEvent,
-    subscription::{Map, SubKind},
+    subscription::{Map, SubKind, Subscription},
 };
 use async_trait::async_trait;
 use barter_integration::{
-    model::instrument::Instrument, protocol::websocket::WsMessage, Transformer,
+    model::{instrument::Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
 };
+use std::sync::Arc;
 use tokio::sync::mpsc;
 
 /// Generic OrderBook [`ExchangeTransformer`]s.
@@ -27,8 +30,39 @@ where
     /// Construct a new [`Self`].
     ///
     /// The [`mpsc::UnboundedSender`] can be used by [`Self`] to send messages back to the exchange.
+    /// It is the prioritised control channel of the connection (see
+    /// [`distribute_messages_to_exchange`](crate::distribute_messages_to_exchange)), so messages
+    /// such as custom application-level pongs are never queued behind backed up data messages.
     async fn new(
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
-        instrument_map: Map<Instrument>,
+        instrument_map: Map<Arc<Instrument>>,
     ) -> Result<Self, DataError>;
+
+    /// Construct a new [`Self`] for the actioned [`Subscription`]s, honouring any per
+    /// [`Subscription`] configuration (eg/ [`SubKind::depth`]).
+    ///
+    /// Defaults to [`Self::new`], ignoring the [`Subscription`]s.
+    async fn from_subscriptions(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Arc<Instrument>>,
+        _: &[Subscription<Exchange, Kind>],
+    ) -> Result<Self, DataError>
+    where
+        Exchange: Sync,
+        Kind: Sync,
+    {
+        Self::new(ws_sink_tx, instrument_map).await
+    }
+
+    /// Update the routing of [`Self`] once [`Subscription`]s are added & removed over the open
+    /// connection (see
+    /// [`MarketStream::update_subscriptions`](crate::MarketStream::update_subscriptions)),
+    /// routing messages of each `added` [`SubscriptionId`] to its [`Instrument`], and freeing any
+    /// state held for each `removed` [`SubscriptionId`].
+    ///
+    /// Returns `false` without updating [`Self`] if its routing cannot be updated live (eg/ an
+    /// added OrderBook requires a snapshot). Defaults to `false`.
+    fn reroute(&mut self, _added: Map<Arc<Instrument>>, _removed: &[SubscriptionId]) -> bool {
+        false
+    }
 }
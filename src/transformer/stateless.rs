@@ -3,7 +3,7 @@ use crate::{
     error::DataError,
     event::{MarketEvent, MarketIter},
     exchange::{Connector, ExchangeId},
-    subscription::{Map, SubKind},
+    subscription::{trade::TradeFields, Map, SubKind},
     Identifier,
 };
 use async_trait::async_trait;
@@ -13,16 +13,20 @@ use barter_integration::{
     Transformer,
 };
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc};
 use tokio::sync::mpsc;
 
 /// Standard generic stateless [`ExchangeTransformer`] to translate exchange specific types into
 /// normalised Barter types. Often used with
 /// [`PublicTrades`](crate::subscription::trade::PublicTrades) or
 /// [`OrderBooksL1`](crate::subscription::book::OrderBooksL1) streams.
+///
+/// Trades are normalised with the [`TradeFields`] of the scope the transformer is constructed in
+/// (see [`with_trade_fields`](crate::subscription::trade::with_trade_fields)).
 #[derive(Clone, Eq, PartialEq, Debug, Serialize)]
 pub struct StatelessTransformer<Exchange, Kind, Input> {
-    instrument_map: Map<Instrument>,
+    instrument_map: Map<Arc<Instrument>>,
+    fields: TradeFields,
     phantom: PhantomData<(Exchange, Kind, Input)>,
 }
 
@@ -33,17 +37,43 @@ where
     Exchange: Connector + Send,
     Kind: SubKind + Send,
     Input: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
-    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input)>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Arc<Instrument>, Input)>,
 {
     async fn new(
         _: mpsc::UnboundedSender<WsMessage>,
-        instrument_map: Map<Instrument>,
+        instrument_map: Map<Arc<Instrument>>,
     ) -> Result<Self, DataError> {
         Ok(Self {
             instrument_map,
-            phantom: PhantomData::default(),
+            fields: TradeFields::current(),
+            phantom: PhantomData,
         })
     }
+
+    fn reroute(&mut self, added: Map<Arc<Instrument>>, removed: &[SubscriptionId]) -> bool {
+        removed.iter().for_each(|subscription_id| {
+            self.remove(subscription_id);
+        });
+        added
+            .0
+            .into_iter()
+            .for_each(|(subscription_id, instrument)| self.insert(subscription_id, instrument));
+        true
+    }
+}
+
+impl<Exchange, Kind, Input> StatelessTransformer<Exchange, Kind, Input> {
+    /// Start routing messages of the provided [`SubscriptionId`] to the associated
+    /// [`Instrument`] (eg/ after subscribing over an open connection).
+    pub fn insert(&mut self, subscription_id: SubscriptionId, instrument: Arc<Instrument>) {
+        self.instrument_map.0.insert(subscription_id, instrument);
+    }
+
+    /// Stop routing messages of the provided [`SubscriptionId`], returning the [`Instrument`] it
+    /// was associated with.
+    pub fn remove(&mut self, subscription_id: &SubscriptionId) -> Option<Arc<Instrument>> {
+        self.instrument_map.0.remove(subscription_id)
+    }
 }
 
 impl<Exchange, Kind, Input> Transformer for StatelessTransformer<Exchange, Kind, Input>
@@ -51,7 +81,7 @@ where
     Exchange: Connector,
     Kind: SubKind,
     Input: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
-    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input)>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Arc<Instrument>, Input)>,
 {
     type Error = DataError;
     type Input = Input;
@@ -67,8 +97,12 @@ where
 
         // Find Instrument associated with Input and transform
         match self.instrument_map.find(&subscription_id) {
-            Ok(instrument) => MarketIter::<Kind::Event>::from((Exchange::ID, instrument, input)).0,
-            Err(unidentifiable) => vec![Err(DataError::Socket(unidentifiable))],
+            Ok(instrument) => {
+                self.fields
+                    .scope(|| MarketIter::<Kind::Event>::from((Exchange::ID, instrument, input)))
+                    .0
+            }
+            Err(unidentifiable) => vec![Err(DataError::from(unidentifiable))],
         }
     }
 }
@@ -0,0 +1,211 @@
+//! Golden tests replaying recorded exchange WebSocket sessions through each exchange
+//! [`Transformer`], asserting the normalised [`MarketEvent`]s match the recorded golden file.
+//!
+//! Each session lives in `tests/sessions/<exchange>/<kind>.*`:
+//! - `<kind>.frames.jsonl`: raw WebSocket text frames in the order they were received.
+//! - `<kind>.snapshot.json`: (optional) the HTTP OrderBook snapshot used to seed the session.
+//! - `<kind>.golden.json`: normalised outputs expected from replaying the frames.
+//!
+//! Regenerate the golden files after an intentional change with:
+//! `UPDATE_GOLDEN=1 cargo test --test recorded_sessions`
+
+use barter_data::{
+    event::MarketEvent,
+    exchange::{
+        binance::{
+            book::l2::BinanceOrderBookL2Snapshot,
+            channel::BinanceChannel,
+            spot::{l2::BinanceSpotBookUpdater, BinanceSpot},
+            trade::BinanceTrade,
+        },
+        subscription::ExchangeSub,
+    },
+    protocol::{WebSocketParser, WsFrame},
+    subscription::{
+        book::{OrderBook, OrderBooksL2},
+        intern::intern,
+        trade::{PublicTrade, PublicTrades},
+        Map,
+    },
+    transformer::{
+        book::{InstrumentOrderBook, MultiBookTransformer},
+        stateless::StatelessTransformer,
+        ExchangeTransformer,
+    },
+    Identifier,
+};
+use barter_integration::{
+    model::instrument::{kind::InstrumentKind, Instrument},
+    protocol::{websocket::WsMessage, StreamParser},
+    Transformer,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::Serialize;
+use std::{fs, path::PathBuf, sync::Arc};
+use tokio::sync::mpsc;
+
+/// Normalised output of replaying a single recorded frame.
+type Replayed<T> = Result<MarketEvent<T>, String>;
+
+/// Path of a recorded session file for the provided exchange & file name.
+fn session_path(exchange: &str, file: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/sessions")
+        .join(exchange)
+        .join(file)
+}
+
+/// Read a recorded session file for the provided exchange & file name.
+fn read_session(exchange: &str, file: &str) -> String {
+    let path = session_path(exchange, file);
+    fs::read_to_string(&path).unwrap_or_else(|error| panic!("failed to read {path:?}: {error}"))
+}
+
+/// Replay every recorded frame through the provided [`Transformer`], parsing each frame exactly
+/// as an [`ExchangeWsStream`](barter_data::ExchangeWsStream) would.
+///
+/// Non-deterministic fields (eg/ `received_time`) are normalised by the provided `normalise`
+/// function so the outputs can be compared against a golden file.
+fn replay<T, Event>(
+    transformer: &mut T,
+    frames: &str,
+    normalise: fn(&mut MarketEvent<Event>),
+) -> Vec<Replayed<Event>>
+where
+    T: Transformer<Output = MarketEvent<Event>>,
+    T::Error: ToString,
+    T::OutputIter: IntoIterator<Item = Result<T::Output, T::Error>>,
+{
+    frames
+        .lines()
+        .filter(|frame| !frame.trim().is_empty())
+        .flat_map(|frame| {
+            let message = Ok(WsFrame::from(WsMessage::Text(frame.to_owned())));
+            match WebSocketParser::parse::<T::Input>(message) {
+                Some(Ok(input)) => transformer
+                    .transform(input)
+                    .into_iter()
+                    .map(|output| output.map_err(|error| error.to_string()))
+                    .collect(),
+                Some(Err(error)) => vec![Err(error.to_string())],
+                None => vec![],
+            }
+        })
+        .map(|output| {
+            output.map(|mut event| {
+                normalise(&mut event);
+                event
+            })
+        })
+        .collect()
+}
+
+/// Assert the replayed outputs equal the golden file of the provided exchange & session kind,
+/// or re-write the golden file if the `UPDATE_GOLDEN` environment variable is set.
+fn assert_golden<Event>(exchange: &str, kind: &str, actual: &[Replayed<Event>])
+where
+    Event: Serialize,
+{
+    let actual = serde_json::to_value(actual).unwrap();
+    let path = session_path(exchange, &format!("{kind}.golden.json"));
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        let golden = serde_json::to_string_pretty(&actual).unwrap();
+        fs::write(&path, golden + "\n").unwrap();
+        return;
+    }
+
+    let golden = read_session(exchange, &format!("{kind}.golden.json"));
+    let expected = serde_json::from_str::<serde_json::Value>(&golden).unwrap();
+
+    assert_eq!(
+        actual, expected,
+        "{exchange} {kind} session diverged from {path:?}, re-run with UPDATE_GOLDEN=1 if intended"
+    );
+}
+
+/// Fixed timestamp used to normalise non-deterministic timestamps.
+fn epoch() -> DateTime<Utc> {
+    Utc.timestamp_opt(0, 0).unwrap()
+}
+
+fn normalise_trade(event: &mut MarketEvent<PublicTrade>) {
+    event.received_time = epoch();
+}
+
+fn normalise_book(event: &mut MarketEvent<OrderBook>) {
+    // OrderBook updates are timestamped on receipt, rather than by the exchange
+    event.received_time = epoch();
+    event.exchange_time = epoch();
+    event.kind.last_update_time = epoch();
+}
+
+#[tokio::test]
+async fn test_binance_spot_public_trades_session() {
+    let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+
+    let instrument_map = [
+        (
+            "BTCUSDT",
+            intern(&Instrument::from(("btc", "usdt", InstrumentKind::Spot))),
+        ),
+        (
+            "ETHUSDT",
+            intern(&Instrument::from(("eth", "usdt", InstrumentKind::Spot))),
+        ),
+    ]
+    .into_iter()
+    .map(|(market, instrument)| {
+        (
+            ExchangeSub::from((BinanceChannel::TRADES, market)).id(),
+            instrument,
+        )
+    })
+    .collect::<Map<Arc<Instrument>>>();
+
+    let mut transformer = StatelessTransformer::<BinanceSpot, PublicTrades, BinanceTrade>::new(
+        ws_sink_tx,
+        instrument_map,
+    )
+    .await
+    .unwrap();
+
+    let frames = read_session("binance_spot", "public_trades.frames.jsonl");
+    let actual = replay(&mut transformer, &frames, normalise_trade);
+
+    assert_golden("binance_spot", "public_trades", &actual);
+}
+
+#[tokio::test]
+async fn test_binance_spot_order_books_l2_session() {
+    // Seed the OrderBook from the recorded HTTP snapshot, rather than fetching a live snapshot
+    let snapshot = serde_json::from_str::<BinanceOrderBookL2Snapshot>(&read_session(
+        "binance_spot",
+        "order_books_l2.snapshot.json",
+    ))
+    .unwrap();
+
+    let book = InstrumentOrderBook {
+        instrument: intern(&Instrument::from(("btc", "usdt", InstrumentKind::Spot))),
+        updater: BinanceSpotBookUpdater::new(snapshot.last_update_id),
+        book: OrderBook::from(snapshot),
+        depth: None,
+    };
+
+    let book_map = [(
+        ExchangeSub::from((BinanceChannel::ORDER_BOOK_L2, "BTCUSDT")).id(),
+        book,
+    )]
+    .into_iter()
+    .collect::<Map<InstrumentOrderBook<BinanceSpotBookUpdater>>>();
+
+    let mut transformer =
+        MultiBookTransformer::<BinanceSpot, OrderBooksL2, BinanceSpotBookUpdater>::from_books(
+            book_map,
+        );
+
+    let frames = read_session("binance_spot", "order_books_l2.frames.jsonl");
+    let actual = replay(&mut transformer, &frames, normalise_book);
+
+    assert_golden("binance_spot", "order_books_l2", &actual);
+}
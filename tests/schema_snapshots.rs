@@ -0,0 +1,143 @@
+//! Wire-format stability tests serialising a canonical instance of each persisted model, and
+//! asserting the JSON matches the committed schema snapshot.
+//!
+//! Each snapshot lives in `tests/snapshots/<name>.json`. A failing test means the wire format of
+//! persisted data (eg/ recorded sessions) has changed. If the change is intentional, bump the
+//! crate version and regenerate the snapshots with:
+//! `UPDATE_SNAPSHOTS=1 cargo test --test schema_snapshots`
+
+use barter_data::{
+    event::MarketEvent,
+    exchange::binance::spot::BinanceSpot,
+    subscription::{
+        book::{Level, OrderBook, OrderBookSide},
+        candle::Candle,
+        trade::{PublicTrade, PublicTrades},
+        Subscription,
+    },
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Exchange, Side,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fmt::Debug, fs, path::PathBuf};
+
+/// Path of the committed schema snapshot with the provided name.
+fn snapshot_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{name}.json"))
+}
+
+/// Assert the canonical instance serialises to the committed schema snapshot (or re-write the
+/// snapshot if the `UPDATE_SNAPSHOTS` environment variable is set), and that the snapshot
+/// deserialises back into the canonical instance.
+fn assert_snapshot<T>(name: &str, canonical: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let actual = serde_json::to_value(canonical).unwrap();
+    let path = snapshot_path(name);
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        let snapshot = serde_json::to_string_pretty(&actual).unwrap();
+        fs::write(&path, snapshot + "\n").unwrap();
+        return;
+    }
+
+    let snapshot = fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("failed to read {path:?}: {error}"));
+    let expected = serde_json::from_str::<serde_json::Value>(&snapshot).unwrap();
+
+    assert_eq!(
+        actual, expected,
+        "{name} wire format diverged from {path:?}, bump the version & re-run with \
+         UPDATE_SNAPSHOTS=1 if intended"
+    );
+    assert_eq!(
+        &serde_json::from_value::<T>(expected).unwrap(),
+        canonical,
+        "{name} snapshot {path:?} no longer deserialises into the canonical instance"
+    );
+}
+
+/// Fixed timestamp of every canonical instance.
+fn time() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2023, 6, 1, 12, 30, 45).unwrap() + chrono::Duration::milliseconds(123)
+}
+
+fn market_event<T>(kind: T) -> MarketEvent<T> {
+    MarketEvent {
+        exchange_time: time(),
+        received_time: time() + chrono::Duration::milliseconds(5),
+        exchange: Exchange::from("binance_spot"),
+        instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)).into(),
+        kind,
+    }
+}
+
+#[test]
+fn test_public_trade_schema() {
+    assert_snapshot(
+        "public_trade",
+        &market_event(PublicTrade {
+            id: "1000000000".to_string(),
+            price: 27_100.5,
+            amount: 0.25,
+            side: Side::Buy,
+            conditions: vec![],
+        }),
+    );
+}
+
+#[test]
+fn test_order_book_schema() {
+    assert_snapshot(
+        "order_book",
+        &market_event(OrderBook {
+            last_update_time: time(),
+            bids: OrderBookSide::new(
+                Side::Buy,
+                vec![Level::new(27_100.0, 1.5), Level::new(27_099.5, 3.0)],
+            ),
+            asks: OrderBookSide::new(
+                Side::Sell,
+                vec![Level::new(27_100.5, 0.5), Level::new(27_101.0, 2.0)],
+            ),
+        }),
+    );
+}
+
+#[test]
+fn test_candle_schema() {
+    assert_snapshot(
+        "candle",
+        &market_event(Candle {
+            close_time: time(),
+            open: 27_000.0,
+            high: 27_150.0,
+            low: 26_950.0,
+            close: 27_100.5,
+            volume: 125.5,
+            quote_volume: Some(3_395_000.25),
+            trade_count: 4_210,
+            is_final: true,
+        }),
+    );
+}
+
+#[test]
+fn test_subscription_schema() {
+    assert_snapshot(
+        "subscription",
+        &Subscription::<BinanceSpot, PublicTrades>::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        )),
+    );
+}